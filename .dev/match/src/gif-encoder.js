@@ -0,0 +1,188 @@
+// Minimal animated GIF encoder for the viewer's clip export.
+// Frames are RGBA pixel arrays mapped onto a fixed 6x7x6 colour cube
+// (252 colours), so no per-frame quantization pass is needed.
+(function() {
+    var RED_LEVELS = 6;
+    var GREEN_LEVELS = 7;
+    var BLUE_LEVELS = 6;
+    var MAX_CODE = 4095;
+
+    function buildPalette() {
+        var palette = new Uint8Array(256 * 3);
+        var i = 0;
+        for (var r = 0; r < RED_LEVELS; r++) {
+            for (var g = 0; g < GREEN_LEVELS; g++) {
+                for (var b = 0; b < BLUE_LEVELS; b++) {
+                    palette[i++] = Math.round(r * 255 / (RED_LEVELS - 1));
+                    palette[i++] = Math.round(g * 255 / (GREEN_LEVELS - 1));
+                    palette[i++] = Math.round(b * 255 / (BLUE_LEVELS - 1));
+                }
+            }
+        }
+        return palette;
+    }
+
+    function ByteWriter() {
+        this.chunks = [];
+        this.buffer = new Uint8Array(65536);
+        this.length = 0;
+    }
+
+    ByteWriter.prototype.byte = function(value) {
+        if (this.length === this.buffer.length) {
+            this.chunks.push(this.buffer);
+            this.buffer = new Uint8Array(65536);
+            this.length = 0;
+        }
+        this.buffer[this.length++] = value;
+    };
+
+    ByteWriter.prototype.word = function(value) {
+        this.byte(value & 0xff);
+        this.byte((value >> 8) & 0xff);
+    };
+
+    ByteWriter.prototype.bytes = function(values) {
+        for (var i = 0; i < values.length; i++) this.byte(values[i]);
+    };
+
+    ByteWriter.prototype.string = function(text) {
+        for (var i = 0; i < text.length; i++) this.byte(text.charCodeAt(i));
+    };
+
+    ByteWriter.prototype.blob = function(type) {
+        return new Blob(this.chunks.concat([this.buffer.subarray(0, this.length)]), {type: type});
+    };
+
+    function mapPixels(rgba, count) {
+        var indices = new Uint8Array(count);
+        for (var i = 0, p = 0; i < count; i++, p += 4) {
+            var r = Math.round(rgba[p] * (RED_LEVELS - 1) / 255);
+            var g = Math.round(rgba[p + 1] * (GREEN_LEVELS - 1) / 255);
+            var b = Math.round(rgba[p + 2] * (BLUE_LEVELS - 1) / 255);
+            indices[i] = (r * GREEN_LEVELS + g) * BLUE_LEVELS + b;
+        }
+        return indices;
+    }
+
+    // Variable-width LZW with 8-bit roots, packed into 255-byte sub-blocks
+    function writeLzw(out, indices) {
+        var clearCode = 256;
+        var endCode = 257;
+        var codeSize = 9;
+        var nextCode = 258;
+        var table = new Map();
+
+        var block = new Uint8Array(255);
+        var blockLength = 0;
+        var bitBuffer = 0;
+        var bitCount = 0;
+
+        function flushBlock() {
+            out.byte(blockLength);
+            out.bytes(block.subarray(0, blockLength));
+            blockLength = 0;
+        }
+
+        function emit(code) {
+            bitBuffer |= code << bitCount;
+            bitCount += codeSize;
+            while (bitCount >= 8) {
+                block[blockLength++] = bitBuffer & 0xff;
+                if (blockLength === 255) flushBlock();
+                bitBuffer >>= 8;
+                bitCount -= 8;
+            }
+        }
+
+        out.byte(8);
+        emit(clearCode);
+
+        var prefix = indices[0];
+        for (var i = 1; i < indices.length; i++) {
+            var k = indices[i];
+            var key = prefix * 256 + k;
+            var code = table.get(key);
+            if (code !== undefined) {
+                prefix = code;
+                continue;
+            }
+            emit(prefix);
+            if (nextCode <= MAX_CODE) {
+                table.set(key, nextCode);
+                // The decoder widens one code later than the encoder adds it
+                if (nextCode === (1 << codeSize) && codeSize < 12) codeSize++;
+                nextCode++;
+            } else {
+                emit(clearCode);
+                table.clear();
+                codeSize = 9;
+                nextCode = 258;
+            }
+            prefix = k;
+        }
+        emit(prefix);
+        emit(endCode);
+
+        if (bitCount > 0) {
+            block[blockLength++] = bitBuffer & 0xff;
+            if (blockLength === 255) flushBlock();
+        }
+        if (blockLength > 0) flushBlock();
+        out.byte(0);
+    }
+
+    // delayCs: frame duration in hundredths of a second
+    function GifEncoder(width, height, delayCs) {
+        this.width = width;
+        this.height = height;
+        this.delayCs = delayCs;
+        this.out = new ByteWriter();
+
+        var out = this.out;
+        out.string('GIF89a');
+        out.word(width);
+        out.word(height);
+        out.byte(0xf7); // global colour table, 8 bits per channel, 256 entries
+        out.byte(0);
+        out.byte(0);
+        out.bytes(buildPalette());
+
+        // Loop forever
+        out.byte(0x21);
+        out.byte(0xff);
+        out.byte(11);
+        out.string('NETSCAPE2.0');
+        out.byte(3);
+        out.byte(1);
+        out.word(0);
+        out.byte(0);
+    }
+
+    GifEncoder.prototype.addFrame = function(rgba) {
+        var out = this.out;
+        out.byte(0x21);
+        out.byte(0xf9);
+        out.byte(4);
+        out.byte(0);
+        out.word(this.delayCs);
+        out.byte(0);
+        out.byte(0);
+
+        out.byte(0x2c);
+        out.word(0);
+        out.word(0);
+        out.word(this.width);
+        out.word(this.height);
+        out.byte(0);
+
+        writeLzw(out, mapPixels(rgba, this.width * this.height));
+    };
+
+    GifEncoder.prototype.finish = function() {
+        this.out.byte(0x3b);
+        return this.out.blob('image/gif');
+    };
+
+    window.GifEncoder = GifEncoder;
+})();
@@ -3,19 +3,27 @@ use core::club::player::Player;
 use core::club::player::PlayerPositionType;
 use core::club::team::tactics::{MatchTacticType, Tactics};
 use core::r#match::FootballEngine;
+use core::r#match::MatchEngineConfig;
+use core::r#match::MatchResultRaw;
+use core::r#match::MatchSnapshot;
 use core::r#match::MatchSquad;
+use core::r#match::ScenarioEditor;
 use core::r#match::player::MatchPlayer;
+use core::r#match::player::state::PlayerState;
+use core::r#match::player::statistics::MatchStatisticType;
 use core::staff_contract_mod::NaiveDate;
 use core::{
     AcademyGenerationContext, MatchRuntime, PeopleNameGeneratorData, PlayerGenerator, PlayerSkills,
+    Vector3,
 };
 use flate2::Compression;
+use flate2::read::GzDecoder;
 use flate2::write::GzEncoder;
 use rand::RngExt;
 use rayon::prelude::*;
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use std::io::Write;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// Random squad level range when no explicit level is passed. Covers the
@@ -168,6 +176,12 @@ fn random_level() -> u8 {
 }
 
 const MATCH_ID: &str = "dev-match-001";
+/// The two matches `compare` plays, tactic A then tactic B.
+const COMPARE_MATCH_IDS: [&str; 2] = ["compare-a", "compare-b"];
+/// The match `scenario` plays live; its page data marks the viewer live.
+const LIVE_MATCH_ID: &str = "live";
+/// Engine tick length, the live match's real-time step.
+const LIVE_TICK: std::time::Duration = std::time::Duration::from_millis(10);
 const LEAGUE_SLUG: &str = "dev";
 const CHUNK_DURATION_MS: u64 = 300_000;
 
@@ -227,12 +241,54 @@ struct GoalJson {
 }
 
 #[derive(Serialize)]
+struct CardJson {
+    player_id: u32,
+    time: u64,
+    is_red: bool,
+}
+
+#[derive(Serialize, Deserialize)]
 struct MetadataJson {
     chunk_count: usize,
     chunk_duration_ms: u64,
     total_duration_ms: u64,
 }
 
+#[derive(Serialize)]
+struct CompareMatchJson {
+    id: &'static str,
+    tactic: &'static str,
+    home_goals: u8,
+    away_goals: u8,
+    match_time_ms: u64,
+}
+
+#[derive(Deserialize)]
+struct ViewerQuery {
+    #[serde(rename = "match")]
+    match_id: Option<String>,
+}
+
+impl ViewerQuery {
+    /// The requested match, or the `viewer` match; anything but a plain
+    /// id falls back too, since it ends up in a file name.
+    fn match_id(&self) -> &str {
+        self.match_id
+            .as_deref()
+            .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_alphanumeric() || c == '-'))
+            .unwrap_or(MATCH_ID)
+    }
+}
+
+/// One viewer match in a single gzipped JSON file: `viewer --save <file>`
+/// writes it, `replay <file>` serves it again without replaying the match.
+#[derive(Serialize, Deserialize)]
+struct ReplayFile {
+    metadata: MetadataJson,
+    page_data: String,
+    chunks: Vec<serde_json::Value>,
+}
+
 /// Maps the user-facing `level` parameter (1..20) onto a target mean
 /// outfield skill the rest of the test rig calibrates around. Wraps the
 /// constants and the retargeting routine into one struct so the level→
@@ -378,6 +434,8 @@ fn generate_player(id: u32, position: PlayerPositionType, level: u8) -> Player {
         first_names: Vec::new(),
         last_names: Vec::new(),
         nicknames: Vec::new(),
+        first_name_weights: Vec::new(),
+        last_name_weights: Vec::new(),
     };
     // Anchor `now` on the 2026 season we're simulating; min/max ages 25-28
     // place every player on the adult plateau of the age curves
@@ -482,6 +540,8 @@ fn make_squad_simple(team_id: u32, level: u8) -> MatchSquad {
         free_kick_taker_id: None,
         selection_omissions: Vec::new(),
         coach_snapshot: None,
+        opposition_report: 0.0,
+        cohesion: None,
     }
 }
 
@@ -558,6 +618,8 @@ fn make_squad_viewer(
         free_kick_taker_id: None,
         selection_omissions: Vec::new(),
         coach_snapshot: None,
+        opposition_report: 0.0,
+        cohesion: None,
     };
 
     (squad, players_json)
@@ -709,7 +771,7 @@ fn team_stats(result: &core::r#match::MatchResultRaw, team_id: u32) -> TeamStats
     ts
 }
 
-fn save_gzip_json(path: &PathBuf, data: &[u8]) {
+fn save_gzip_json(path: &Path, data: &[u8]) {
     let file = std::fs::File::create(path)
         .unwrap_or_else(|e| panic!("failed to create {}: {}", path.display(), e));
     let mut encoder = GzEncoder::new(file, Compression::default());
@@ -796,6 +858,8 @@ fn league_squad(t: &LeagueTeam) -> MatchSquad {
         free_kick_taker_id: None,
         selection_omissions: Vec::new(),
         coach_snapshot: None,
+        opposition_report: 0.0,
+        cohesion: None,
     }
 }
 
@@ -1029,8 +1093,25 @@ fn run_league(n_teams: usize, rounds: usize, min_lvl: u8, max_lvl: u8) {
 /// the harness exposes no loose helper functions.
 struct Bench;
 
+/// One benchmarked match, written as a `--csv` row. Tick latencies are
+/// in microseconds.
+struct BenchRow {
+    seed: u64,
+    home_goals: u8,
+    away_goals: u8,
+    ticks: usize,
+    elapsed: std::time::Duration,
+    tick_mean_us: f64,
+    tick_p50_us: f64,
+    tick_p95_us: f64,
+    tick_p99_us: f64,
+    tick_max_us: f64,
+    /// Only counted with the `alloc-count` feature.
+    allocations: Option<u64>,
+}
+
 impl Bench {
-    fn run(n: usize, level: u8) {
+    fn run(n: usize, level: u8, first_seed: u64, csv: Option<&Path>) {
         let level = if level == 0 { 14 } else { level };
         let start = std::time::Instant::now();
         let mut checksum: u64 = 0;
@@ -1044,24 +1125,18 @@ impl Bench {
             alloc_count::ALLOC_CALLS.load(std::sync::atomic::Ordering::Relaxed),
             alloc_count::ALLOC_BYTES.load(std::sync::atomic::Ordering::Relaxed),
         );
+        let mut rows = Vec::with_capacity(n);
         for i in 0..n {
             let mut home = make_squad_calibrated(1, level);
             let mut away = make_squad_calibrated(2, level);
             Self::fix_squad_deterministic(&mut home);
             Self::fix_squad_deterministic(&mut away);
             // Distinct, deterministic seed per match (golden-ratio mix).
-            let seed = 0x9E37_79B9_7F4A_7C15u64.wrapping_mul(i as u64 + 1);
-            let result = FootballEngine::<840, 545>::play_seeded(
-                home,
-                away,
-                false,
-                false,
-                false,
-                Some(seed),
-            );
-            let score = result.score.as_ref().unwrap();
-            let h = score.home_team.get() as u64;
-            let a = score.away_team.get() as u64;
+            let seed = 0x9E37_79B9_7F4A_7C15u64.wrapping_mul(first_seed + i as u64);
+            let row = Self::play(home, away, seed);
+            let h = row.home_goals as u64;
+            let a = row.away_goals as u64;
+            rows.push(row);
             total_goals += h + a;
             checksum = checksum
                 .wrapping_mul(1_000_003)
@@ -1093,6 +1168,101 @@ impl Bench {
             total_goals as f64 / n.max(1) as f64,
             checksum
         );
+
+        let ticks: usize = rows.iter().map(|r| r.ticks).sum();
+        let tick_secs: f64 = rows.iter().map(|r| r.elapsed.as_secs_f64()).sum();
+        let mean = |f: fn(&BenchRow) -> f64| rows.iter().map(f).sum::<f64>() / n.max(1) as f64;
+        println!(
+            "TICKS total={} per_sec={:.0} mean_us={:.2} p50_us={:.2} p95_us={:.2} p99_us={:.2} (per-match percentiles averaged)",
+            ticks,
+            ticks as f64 / tick_secs.max(f64::EPSILON),
+            mean(|r| r.tick_mean_us),
+            mean(|r| r.tick_p50_us),
+            mean(|r| r.tick_p95_us),
+            mean(|r| r.tick_p99_us),
+        );
+
+        if let Some(path) = csv {
+            match Self::write_csv(path, level, &rows) {
+                Ok(()) => println!("CSV {}", path.display()),
+                Err(e) => eprintln!("failed to write {}: {}", path.display(), e),
+            }
+        }
+    }
+
+    /// Plays one match through the stepping API — the same state machine
+    /// as `play_seeded` — timing every tick.
+    fn play(home: MatchSquad, away: MatchSquad, seed: u64) -> BenchRow {
+        let mut engine = FootballEngine::<840, 545>::new();
+        engine.start(home, away, MatchEngineConfig::seeded(seed));
+
+        let mut ticks: Vec<u64> = Vec::with_capacity(1 << 20);
+        #[cfg(feature = "alloc-count")]
+        let allocs_before = alloc_count::ALLOC_CALLS.load(std::sync::atomic::Ordering::Relaxed);
+        let started = std::time::Instant::now();
+        loop {
+            let tick_started = std::time::Instant::now();
+            if !engine.tick() {
+                break;
+            }
+            ticks.push(tick_started.elapsed().as_nanos() as u64);
+        }
+        let elapsed = started.elapsed();
+        #[cfg(feature = "alloc-count")]
+        let allocations = Some(
+            alloc_count::ALLOC_CALLS.load(std::sync::atomic::Ordering::Relaxed) - allocs_before,
+        );
+        #[cfg(not(feature = "alloc-count"))]
+        let allocations = None;
+
+        let result = engine.finish().expect("benchmark match was not started");
+        let score = result.score.as_ref().unwrap();
+
+        ticks.sort_unstable();
+        let micros = |nanos: u64| nanos as f64 / 1_000.0;
+        let percentile = |p: f64| {
+            let idx = (ticks.len().saturating_sub(1) as f64 * p).round() as usize;
+            ticks.get(idx).copied().map_or(0.0, micros)
+        };
+        BenchRow {
+            seed,
+            home_goals: score.home_team.get(),
+            away_goals: score.away_team.get(),
+            ticks: ticks.len(),
+            elapsed,
+            tick_mean_us: micros(ticks.iter().sum()) / ticks.len().max(1) as f64,
+            tick_p50_us: percentile(0.50),
+            tick_p95_us: percentile(0.95),
+            tick_p99_us: percentile(0.99),
+            tick_max_us: ticks.last().copied().map_or(0.0, micros),
+            allocations,
+        }
+    }
+
+    fn write_csv(path: &Path, level: u8, rows: &[BenchRow]) -> std::io::Result<()> {
+        let mut out = String::from(
+            "seed,level,score,ticks,elapsed_ms,ticks_per_second,\
+             tick_mean_us,tick_p50_us,tick_p95_us,tick_p99_us,tick_max_us,allocations\n",
+        );
+        for r in rows {
+            out.push_str(&format!(
+                "{},{},{}-{},{},{:.1},{:.0},{:.2},{:.2},{:.2},{:.2},{:.2},{}\n",
+                r.seed,
+                level,
+                r.home_goals,
+                r.away_goals,
+                r.ticks,
+                r.elapsed.as_secs_f64() * 1_000.0,
+                r.ticks as f64 / r.elapsed.as_secs_f64().max(f64::EPSILON),
+                r.tick_mean_us,
+                r.tick_p50_us,
+                r.tick_p95_us,
+                r.tick_p99_us,
+                r.tick_max_us,
+                r.allocations.map(|a| a.to_string()).unwrap_or_default()
+            ));
+        }
+        std::fs::write(path, out)
     }
 
     /// Normalise the RNG-derived (non-skill) fields the engine reads during
@@ -1121,6 +1291,12 @@ fn print_usage() {
     eprintln!("Usage:");
     eprintln!("  dev_match                       open browser viewer (random squad levels)");
     eprintln!("  dev_match viewer [lvlA] [lvlB]  open browser viewer — levels random unless given");
+    eprintln!("                                      --save <file> also writes the replay to <file>");
+    eprintln!("  dev_match replay <file>         open browser viewer on a replay saved with --save");
+    eprintln!("  dev_match scenario [lvlA] [lvlB] [seed]  play a live match in the viewer, paused at kick-off;");
+    eprintln!("                                      drag players and the ball, set velocities and states, resume");
+    eprintln!("  dev_match compare <tacticA> <tacticB> [level] [seed]  same squads and seed, home tactic A vs B,");
+    eprintln!("                                      side by side (tactics as T442, T4231, ...)");
     eprintln!(
         "  dev_match stats [N] [lvlA] [lvlB]  run N matches headless; per-match random levels"
     );
@@ -1133,6 +1309,8 @@ fn print_usage() {
     eprintln!("  dev_match audit_engine_gap [N] [lvlA] [lvlB]  engine diagnostic: direct-skill matches at supplied gap");
     eprintln!("                                      bypasses generator; reveals engine-only response to skill gap");
     eprintln!("  dev_match subs [N] [level]      substitution-usage diagnostic: per-team subs distribution by result");
+    eprintln!("  dev_match bench [N] [level] [--seed=S] [--csv=FILE]  seeded timing benchmark; --csv writes per-match");
+    eprintln!("                                      tick rate, tick latency percentiles and allocations (alloc-count feature)");
     eprintln!();
     eprintln!(
         "Random level range: {}–{} inclusive.",
@@ -1213,6 +1391,8 @@ fn run_subs_experiment(n_matches: usize, level: u8) {
             free_kick_taker_id: None,
             selection_omissions: Vec::new(),
             coach_snapshot: None,
+            opposition_report: 0.0,
+            cohesion: None,
         }
     }
 
@@ -1327,13 +1507,61 @@ fn main() {
         "viewer" => {
             let level_a: Option<u8> = args.get(2).and_then(|s| s.parse().ok());
             let level_b: Option<u8> = args.get(3).and_then(|s| s.parse().ok());
-            run_viewer(level_a, level_b);
+            let save_path = args
+                .iter()
+                .position(|a| a == "--save")
+                .and_then(|i| args.get(i + 1))
+                .map(PathBuf::from);
+            run_viewer(level_a, level_b, save_path);
         }
+        // Side-by-side tactic A/B: same squads and seed, the home side's
+        // tactic switched.
+        "compare" => {
+            let tactics = (
+                args.get(2).and_then(|s| parse_tactic(s)),
+                args.get(3).and_then(|s| parse_tactic(s)),
+            );
+            let (Some(tactic_a), Some(tactic_b)) = tactics else {
+                eprintln!("compare needs two tactics, e.g. `compare T442 T433`\n");
+                print_usage();
+                std::process::exit(2);
+            };
+            let level: u8 = args.get(4).and_then(|s| s.parse().ok()).unwrap_or(14);
+            let seed: u64 = args.get(5).and_then(|s| s.parse().ok()).unwrap_or(1);
+            run_compare(tactic_a, tactic_b, level, seed);
+        }
+        // Live match held open on the server, edited from the viewer.
+        "scenario" => {
+            let level_a: Option<u8> = args.get(2).and_then(|s| s.parse().ok());
+            let level_b: Option<u8> = args.get(3).and_then(|s| s.parse().ok());
+            let seed: u64 = args.get(4).and_then(|s| s.parse().ok()).unwrap_or(1);
+            run_scenario(level_a, level_b, seed);
+        }
+        // Reopen a match saved with `viewer --save <file>`.
+        "replay" => match args.get(2) {
+            Some(path) => run_replay(Path::new(path)),
+            None => {
+                eprintln!("replay needs a file saved with `viewer --save <file>`\n");
+                print_usage();
+                std::process::exit(2);
+            }
+        },
         // Deterministic seeded timing + calibration-neutrality benchmark.
+        // `--seed=S` shifts the per-match seeds, `--csv=FILE` writes
+        // per-match tick rate, tick latency and allocation figures.
         "bench" => {
             let n: usize = args.get(2).and_then(|s| s.parse().ok()).unwrap_or(30);
             let level: u8 = args.get(3).and_then(|s| s.parse().ok()).unwrap_or(14);
-            Bench::run(n, level);
+            let first_seed: u64 = args
+                .iter()
+                .find_map(|a| a.strip_prefix("--seed="))
+                .and_then(|s| s.parse().ok())
+                .unwrap_or(1);
+            let csv = args
+                .iter()
+                .find_map(|a| a.strip_prefix("--csv="))
+                .map(PathBuf::from);
+            Bench::run(n, level, first_seed, csv.as_deref());
         }
         // Generator diagnostic: dumps mean outfield skills per level so
         // we can see whether `make_squad_simple(level)` actually responds
@@ -1546,6 +1774,8 @@ fn make_squad_calibrated(team_id: u32, level: u8) -> MatchSquad {
         free_kick_taker_id: None,
         selection_omissions: Vec::new(),
         coach_snapshot: None,
+        opposition_report: 0.0,
+        cohesion: None,
     }
 }
 
@@ -3576,7 +3806,7 @@ fn run_stats(n_matches: usize, level_a: Option<u8>, level_b: Option<u8>) {
     );
 }
 
-fn run_viewer(level_a: Option<u8>, level_b: Option<u8>) {
+fn run_viewer(level_a: Option<u8>, level_b: Option<u8>, save_path: Option<PathBuf>) {
     // Route `log::warn!` from core (notably the ball-stall snapshot) to
     // stderr. Override with `RUST_LOG=info` or `RUST_LOG=debug` for more.
     env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn"))
@@ -3612,6 +3842,255 @@ fn run_viewer(level_a: Option<u8>, level_b: Option<u8>) {
         elapsed.as_millis()
     );
 
+    write_viewer_match(MATCH_ID, &result, &players_json, save_path.as_deref());
+
+    open_viewer("/", None);
+}
+
+/// `compare <tacticA> <tacticB>`: plays the same squads twice with the
+/// same seed, the home side switching from tactic A to tactic B, and
+/// opens both side by side.
+fn run_compare(tactic_a: MatchTacticType, tactic_b: MatchTacticType, level: u8, seed: u64) {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn"))
+        .format_timestamp_millis()
+        .init();
+
+    MatchRuntime::set_events_mode(true);
+
+    let (home_squad, mut players_json) = make_squad_viewer(1, "Home FC", level, 0);
+    let (away_squad, away_players) = make_squad_viewer(2, "Away United", level, 11);
+    players_json.extend(away_players);
+
+    let mut matches = Vec::with_capacity(2);
+    for (match_id, tactic) in COMPARE_MATCH_IDS.into_iter().zip([tactic_a, tactic_b]) {
+        let mut home = home_squad.clone();
+        home.tactics = Tactics::new(tactic);
+
+        let result = FootballEngine::<840, 545>::play_seeded(
+            home,
+            away_squad.clone(),
+            true,
+            false,
+            false,
+            Some(seed),
+        );
+
+        let score = result.score.as_ref().unwrap();
+        let home_goals = score.home_team.get();
+        let away_goals = score.away_team.get();
+        println!(
+            "{:<18} {}:{}",
+            tactic.display_name(),
+            home_goals,
+            away_goals
+        );
+
+        write_viewer_match(match_id, &result, &players_json, None);
+        matches.push(CompareMatchJson {
+            id: match_id,
+            tactic: tactic.display_name(),
+            home_goals,
+            away_goals,
+            match_time_ms: result.match_time_ms,
+        });
+    }
+
+    let out_dir = PathBuf::from("match_results").join(LEAGUE_SLUG);
+    std::fs::write(
+        out_dir.join("compare_data.js"),
+        format!(
+            "const COMPARE_DATA={};",
+            serde_json::to_string(&matches).unwrap()
+        ),
+    )
+    .expect("failed to write compare data");
+
+    open_viewer("/compare", None);
+}
+
+fn parse_tactic(name: &str) -> Option<MatchTacticType> {
+    MatchTacticType::all().into_iter().find(|tactic| {
+        format!("{:?}", tactic).eq_ignore_ascii_case(name)
+            || tactic.display_name().eq_ignore_ascii_case(name)
+    })
+}
+
+/// `scenario`: starts a match paused at kick-off and serves it live. The
+/// viewer's edit mode moves players and the ball, sets velocities and
+/// states, then resumes play from there.
+fn run_scenario(level_a: Option<u8>, level_b: Option<u8>, seed: u64) {
+    env_logger::Builder::from_env(env_logger::Env::default().default_filter_or("warn"))
+        .format_timestamp_millis()
+        .init();
+
+    let level_a = level_a.unwrap_or_else(random_level);
+    let level_b = level_b.unwrap_or_else(random_level);
+
+    let (home_squad, mut players_json) = make_squad_viewer(1, "Home FC", level_a, 0);
+    let (away_squad, away_players) = make_squad_viewer(2, "Away United", level_b, 11);
+    players_json.extend(away_players);
+
+    println!(
+        "Live match (level {} vs level {}, seed {})",
+        level_a, level_b, seed
+    );
+
+    let out_dir = PathBuf::from("match_results").join(LEAGUE_SLUG);
+    std::fs::create_dir_all(&out_dir).expect("failed to create output dir");
+    let match_time_ms = 2 * core::r#match::MATCH_HALF_TIME_MS;
+    let metadata = MetadataJson {
+        chunk_count: 0,
+        chunk_duration_ms: CHUNK_DURATION_MS,
+        total_duration_ms: match_time_ms,
+    };
+    let page_data = format!(
+        "const MATCH_ID=\"{}\";const LIVE_MATCH=true;const MATCH_TIME_MS={};const GOALS_DATA=[];const CARDS_DATA=[];const PLAYERS_DATA={};const HOME_BG=\"#00307d\";const HOME_FG=\"#ffffff\";const AWAY_BG=\"#b33f00\";const AWAY_FG=\"#ffffff\";const HOME_GOALS=0;const AWAY_GOALS=0;",
+        LIVE_MATCH_ID,
+        match_time_ms,
+        serde_json::to_string(&players_json).unwrap(),
+    );
+    write_viewer_page(&out_dir, LIVE_MATCH_ID, &metadata, &page_data);
+
+    let (commands, receiver) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let mut engine = FootballEngine::<840, 545>::new();
+        engine.start(home_squad, away_squad, MatchEngineConfig::seeded(seed));
+        engine.pause();
+        run_live_match(engine, receiver);
+    });
+
+    open_viewer(&format!("/?match={}", LIVE_MATCH_ID), Some(commands));
+}
+
+/// One change to the live match, as posted by the viewer's edit mode.
+/// Positions and velocities are in engine units.
+#[derive(Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+enum ScenarioEdit {
+    MovePlayer { player_id: u32, x: f32, y: f32 },
+    SetPlayerVelocity { player_id: u32, x: f32, y: f32 },
+    SetPlayerState { player_id: u32, state: String },
+    GiveBall { player_id: u32 },
+    MoveBall { x: f32, y: f32, z: f32 },
+    SetBallVelocity { x: f32, y: f32, z: f32 },
+}
+
+#[derive(Deserialize)]
+struct StepRequest {
+    ticks: u32,
+}
+
+enum LiveCommand {
+    Snapshot,
+    Pause,
+    Resume,
+    Step(u32),
+    Edit(Vec<ScenarioEdit>),
+}
+
+/// A command for the live match and where its answer goes: the match
+/// after it, or why it was refused.
+type LiveRequest = (
+    LiveCommand,
+    tokio::sync::oneshot::Sender<Result<MatchSnapshot, String>>,
+);
+
+/// The live match's command queue, `None` when the viewer shows a
+/// finished match.
+type LiveHandle = Option<std::sync::mpsc::Sender<LiveRequest>>;
+
+/// Owns the live match: engine hooks aren't `Send`, so it stays on this
+/// thread, ticking in real time while unpaused and answering commands
+/// between ticks.
+fn run_live_match(
+    mut engine: FootballEngine<840, 545>,
+    commands: std::sync::mpsc::Receiver<LiveRequest>,
+) {
+    let mut clock = std::time::Instant::now();
+    loop {
+        match commands.recv_timeout(LIVE_TICK) {
+            Ok((command, reply)) => {
+                let _ = reply.send(apply_live_command(&mut engine, command));
+            }
+            Err(std::sync::mpsc::RecvTimeoutError::Timeout) => {}
+            Err(std::sync::mpsc::RecvTimeoutError::Disconnected) => return,
+        }
+
+        // Catch up with the wall clock, but never by more than a second
+        // after a stall.
+        let due = (clock.elapsed().as_millis() / LIVE_TICK.as_millis()) as u32;
+        clock += LIVE_TICK * due;
+        for _ in 0..due.min(100) {
+            if !engine.tick() {
+                break;
+            }
+        }
+    }
+}
+
+fn apply_live_command(
+    engine: &mut FootballEngine<840, 545>,
+    command: LiveCommand,
+) -> Result<MatchSnapshot, String> {
+    match command {
+        LiveCommand::Snapshot => {}
+        LiveCommand::Pause => engine.pause(),
+        LiveCommand::Resume => engine.resume(),
+        LiveCommand::Step(ticks) => {
+            engine.step(ticks);
+        }
+        LiveCommand::Edit(edits) => {
+            let mut editor = engine.edit().ok_or("no match in progress")?;
+            for edit in edits {
+                apply_scenario_edit(&mut editor, edit)?;
+            }
+        }
+    }
+    engine
+        .snapshot()
+        .ok_or_else(|| "no match in progress".to_string())
+}
+
+fn apply_scenario_edit(editor: &mut ScenarioEditor<'_>, edit: ScenarioEdit) -> Result<(), String> {
+    match edit {
+        ScenarioEdit::MovePlayer { player_id, x, y } => {
+            editor.move_player(player_id, Vector3::new(x, y, 0.0))
+        }
+        ScenarioEdit::SetPlayerVelocity { player_id, x, y } => {
+            editor.set_player_velocity(player_id, Vector3::new(x, y, 0.0))
+        }
+        ScenarioEdit::SetPlayerState { player_id, state } => {
+            let parsed = PlayerState::all()
+                .into_iter()
+                .find(|s| format!("{:?}", s) == state)
+                .ok_or_else(|| format!("unknown state {}", state))?;
+            editor.set_player_state(player_id, parsed)
+        }
+        ScenarioEdit::GiveBall { player_id } => editor.give_ball(player_id),
+        ScenarioEdit::MoveBall { x, y, z } => {
+            editor.move_ball(Vector3::new(x, y, z));
+            Ok(())
+        }
+        ScenarioEdit::SetBallVelocity { x, y, z } => {
+            editor.set_ball_velocity(Vector3::new(x, y, z));
+            Ok(())
+        }
+    }
+    .map_err(|e| e.to_string())
+}
+
+/// Writes a played match where the viewer serves it from: chunks,
+/// metadata and page globals under `match_results/dev/`, named after
+/// `match_id`. With `save_path` the same data also goes into one
+/// `ReplayFile`.
+fn write_viewer_match(
+    match_id: &str,
+    result: &MatchResultRaw,
+    players_json: &[PlayerJson],
+    save_path: Option<&Path>,
+) {
+    let score = result.score.as_ref().unwrap();
+
     let goals_json: Vec<GoalJson> = score
         .detail()
         .iter()
@@ -3623,6 +4102,19 @@ fn run_viewer(level_a: Option<u8>, level_b: Option<u8>) {
         })
         .collect();
 
+    let cards_json: Vec<CardJson> = score
+        .detail()
+        .iter()
+        .filter_map(|card| match card.stat_type {
+            MatchStatisticType::YellowCard | MatchStatisticType::RedCard => Some(CardJson {
+                player_id: card.player_id,
+                time: card.time,
+                is_red: card.stat_type == MatchStatisticType::RedCard,
+            }),
+            _ => None,
+        })
+        .collect();
+
     let out_dir = PathBuf::from("match_results").join(LEAGUE_SLUG);
     std::fs::create_dir_all(&out_dir).expect("failed to create output dir");
 
@@ -3636,7 +4128,7 @@ fn run_viewer(level_a: Option<u8>, level_b: Option<u8>) {
     chunks.par_iter().enumerate().for_each(|(idx, chunk)| {
         let chunk_data = serde_json::to_vec(chunk).expect("failed to serialize chunk");
         let raw_size = chunk_data.len();
-        let chunk_path = out_dir.join(format!("{}_chunk_{}.json.gz", MATCH_ID, idx));
+        let chunk_path = out_dir.join(format!("{}_chunk_{}.json.gz", match_id, idx));
         save_gzip_json(&chunk_path, &chunk_data);
         let gz_size = std::fs::metadata(&chunk_path)
             .map(|m| m.len() as usize)
@@ -3663,54 +4155,112 @@ fn run_viewer(level_a: Option<u8>, level_b: Option<u8>) {
         chunk_duration_ms: CHUNK_DURATION_MS,
         total_duration_ms: result.position_data.max_timestamp(),
     };
-    let metadata_path = out_dir.join(format!("{}_metadata.json", MATCH_ID));
-    std::fs::write(
-        &metadata_path,
-        serde_json::to_string_pretty(&metadata).unwrap(),
-    )
-    .expect("failed to write metadata");
-
     let page_data = format!(
-        "const MATCH_ID=\"{}\";const MATCH_TIME_MS={};const GOALS_DATA={};const PLAYERS_DATA={};const HOME_BG=\"#00307d\";const HOME_FG=\"#ffffff\";const AWAY_BG=\"#b33f00\";const AWAY_FG=\"#ffffff\";const HOME_GOALS={};const AWAY_GOALS={};",
-        MATCH_ID,
+        "const MATCH_ID=\"{}\";const MATCH_TIME_MS={};const GOALS_DATA={};const CARDS_DATA={};const PLAYERS_DATA={};const HOME_BG=\"#00307d\";const HOME_FG=\"#ffffff\";const AWAY_BG=\"#b33f00\";const AWAY_FG=\"#ffffff\";const HOME_GOALS={};const AWAY_GOALS={};",
+        match_id,
         result.match_time_ms,
         serde_json::to_string(&goals_json).unwrap(),
+        serde_json::to_string(&cards_json).unwrap(),
         serde_json::to_string(&players_json).unwrap(),
-        home_goals,
-        away_goals,
+        score.home_team.get(),
+        score.away_team.get(),
+    );
+    write_viewer_page(&out_dir, match_id, &metadata, &page_data);
+
+    if let Some(path) = save_path {
+        let replay = ReplayFile {
+            metadata,
+            page_data,
+            chunks: chunks
+                .iter()
+                .map(|chunk| serde_json::to_value(chunk).expect("failed to serialize chunk"))
+                .collect(),
+        };
+        save_gzip_json(
+            path,
+            &serde_json::to_vec(&replay).expect("failed to serialize replay"),
+        );
+        println!("Saved replay to {}", path.display());
+    }
+}
+
+/// `replay <file>`: unpacks a file written by `viewer --save` into the
+/// viewer's output directory and serves it.
+fn run_replay(path: &Path) {
+    let file = std::fs::File::open(path)
+        .unwrap_or_else(|e| panic!("failed to open {}: {}", path.display(), e));
+    let replay: ReplayFile = serde_json::from_reader(std::io::BufReader::new(GzDecoder::new(file)))
+        .unwrap_or_else(|e| panic!("failed to read replay {}: {}", path.display(), e));
+
+    let out_dir = PathBuf::from("match_results").join(LEAGUE_SLUG);
+    std::fs::create_dir_all(&out_dir).expect("failed to create output dir");
+
+    for (idx, chunk) in replay.chunks.iter().enumerate() {
+        let chunk_path = out_dir.join(format!("{}_chunk_{}.json.gz", MATCH_ID, idx));
+        save_gzip_json(
+            &chunk_path,
+            &serde_json::to_vec(chunk).expect("failed to serialize chunk"),
+        );
+    }
+    write_viewer_page(&out_dir, MATCH_ID, &replay.metadata, &replay.page_data);
+
+    println!(
+        "Loaded {} chunks from {}",
+        replay.chunks.len(),
+        path.display()
     );
-    std::fs::write(out_dir.join("page_data.js"), &page_data).expect("failed to write page data");
 
-    println!("\nStarting viewer at http://localhost:18001");
+    open_viewer("/", None);
+}
+
+/// Metadata and page globals the viewer reads next to the chunk files.
+fn write_viewer_page(out_dir: &Path, match_id: &str, metadata: &MetadataJson, page_data: &str) {
+    let metadata_path = out_dir.join(format!("{}_metadata.json", match_id));
+    std::fs::write(
+        &metadata_path,
+        serde_json::to_string_pretty(metadata).unwrap(),
+    )
+    .expect("failed to write metadata");
+    std::fs::write(out_dir.join(format!("{}_page_data.js", match_id)), page_data)
+        .expect("failed to write page data");
+}
+
+/// Serves the viewer, with the live match when `live` is set, and points
+/// the browser at `page`.
+fn open_viewer(page: &str, live: LiveHandle) {
+    let url = format!("http://localhost:18001{}", page);
+    println!("\nStarting viewer at {}", url);
 
     #[cfg(target_os = "windows")]
     {
         let _ = std::process::Command::new("cmd")
-            .args(["/C", "start", "http://localhost:18001"])
+            .args(["/C", "start", &url])
             .spawn();
     }
     #[cfg(target_os = "macos")]
     {
         let _ = std::process::Command::new("open")
-            .arg("http://localhost:18001")
+            .arg(&url)
             .spawn();
     }
     #[cfg(target_os = "linux")]
     {
         let _ = std::process::Command::new("xdg-open")
-            .arg("http://localhost:18001")
+            .arg(&url)
             .spawn();
     }
 
     let rt = tokio::runtime::Runtime::new().unwrap();
-    rt.block_on(serve());
+    rt.block_on(serve(live));
 }
 
-async fn serve() {
-    use axum::routing::get;
+async fn serve(live: LiveHandle) {
+    use axum::routing::{get, post};
 
     let app = axum::Router::new()
         .route("/", get(page_handler))
+        .route("/compare", get(compare_page_handler))
+        .route("/compare_data.js", get(compare_data_handler))
         .route("/api/match/{match_id}/metadata", get(metadata_handler))
         .route(
             "/api/match/{match_id}/chunk/{chunk_num}",
@@ -3718,7 +4268,15 @@ async fn serve() {
         )
         .route("/static/images/match/field.svg", get(field_svg_handler))
         .route("/js/pixi.min.js", get(pixi_handler))
-        .route("/match_data.js", get(data_handler));
+        .route("/js/gif-encoder.js", get(gif_encoder_handler))
+        .route("/match_data.js", get(data_handler))
+        .route("/api/live/snapshot", get(live_snapshot_handler))
+        .route("/api/live/states", get(live_states_handler))
+        .route("/api/live/pause", post(live_pause_handler))
+        .route("/api/live/resume", post(live_resume_handler))
+        .route("/api/live/step", post(live_step_handler))
+        .route("/api/live/edit", post(live_edit_handler))
+        .with_state(live);
 
     let listener = tokio::net::TcpListener::bind("0.0.0.0:18001")
         .await
@@ -3726,14 +4284,37 @@ async fn serve() {
     axum::serve(listener, app).await.unwrap();
 }
 
-async fn page_handler() -> axum::response::Html<String> {
-    axum::response::Html(include_str!("viewer.html").to_string())
+/// `?match=<id>` picks the match; `compare` embeds one viewer per match.
+async fn page_handler(
+    axum::extract::Query(query): axum::extract::Query<ViewerQuery>,
+) -> axum::response::Html<String> {
+    axum::response::Html(include_str!("viewer.html").replace(
+        "/match_data.js",
+        &format!("/match_data.js?match={}", query.match_id()),
+    ))
+}
+
+async fn compare_page_handler() -> axum::response::Html<&'static str> {
+    axum::response::Html(include_str!("compare.html"))
+}
+
+async fn compare_data_handler() -> impl axum::response::IntoResponse {
+    let path = PathBuf::from("match_results")
+        .join(LEAGUE_SLUG)
+        .join("compare_data.js");
+    let data = tokio::fs::read_to_string(&path).await.unwrap_or_default();
+    (
+        [(axum::http::header::CONTENT_TYPE, "application/javascript")],
+        data,
+    )
 }
 
-async fn data_handler() -> impl axum::response::IntoResponse {
+async fn data_handler(
+    axum::extract::Query(query): axum::extract::Query<ViewerQuery>,
+) -> impl axum::response::IntoResponse {
     let path = PathBuf::from("match_results")
         .join(LEAGUE_SLUG)
-        .join("page_data.js");
+        .join(format!("{}_page_data.js", query.match_id()));
     let data = tokio::fs::read_to_string(&path).await.unwrap_or_default();
     (
         [(axum::http::header::CONTENT_TYPE, "application/javascript")],
@@ -3778,6 +4359,64 @@ async fn chunk_handler(
     }
 }
 
+/// Runs `command` on the live match and answers with the match after it.
+async fn live_command(live: &LiveHandle, command: LiveCommand) -> axum::response::Response {
+    let Some(commands) = live else {
+        return (axum::http::StatusCode::NOT_FOUND, "no live match").into_response();
+    };
+    let (reply, answer) = tokio::sync::oneshot::channel();
+    if commands.send((command, reply)).is_err() {
+        return (axum::http::StatusCode::GONE, "live match stopped").into_response();
+    }
+    match answer.await {
+        Ok(Ok(snapshot)) => axum::Json(snapshot).into_response(),
+        Ok(Err(message)) => (axum::http::StatusCode::BAD_REQUEST, message).into_response(),
+        Err(_) => (axum::http::StatusCode::GONE, "live match stopped").into_response(),
+    }
+}
+
+async fn live_snapshot_handler(
+    axum::extract::State(live): axum::extract::State<LiveHandle>,
+) -> axum::response::Response {
+    live_command(&live, LiveCommand::Snapshot).await
+}
+
+/// Every player state by name, as `set_player_state` edits take them.
+async fn live_states_handler() -> axum::Json<Vec<String>> {
+    axum::Json(
+        PlayerState::all()
+            .iter()
+            .map(|state| format!("{:?}", state))
+            .collect(),
+    )
+}
+
+async fn live_pause_handler(
+    axum::extract::State(live): axum::extract::State<LiveHandle>,
+) -> axum::response::Response {
+    live_command(&live, LiveCommand::Pause).await
+}
+
+async fn live_resume_handler(
+    axum::extract::State(live): axum::extract::State<LiveHandle>,
+) -> axum::response::Response {
+    live_command(&live, LiveCommand::Resume).await
+}
+
+async fn live_step_handler(
+    axum::extract::State(live): axum::extract::State<LiveHandle>,
+    axum::Json(step): axum::Json<StepRequest>,
+) -> axum::response::Response {
+    live_command(&live, LiveCommand::Step(step.ticks)).await
+}
+
+async fn live_edit_handler(
+    axum::extract::State(live): axum::extract::State<LiveHandle>,
+    axum::Json(edits): axum::Json<Vec<ScenarioEdit>>,
+) -> axum::response::Response {
+    live_command(&live, LiveCommand::Edit(edits)).await
+}
+
 async fn field_svg_handler() -> impl axum::response::IntoResponse {
     let svg = include_str!("../../../src/web/assets/static/images/match/field.svg");
     ([(axum::http::header::CONTENT_TYPE, "image/svg+xml")], svg)
@@ -3790,3 +4429,10 @@ async fn pixi_handler() -> impl axum::response::IntoResponse {
         js.as_slice(),
     )
 }
+
+async fn gif_encoder_handler() -> impl axum::response::IntoResponse {
+    (
+        [(axum::http::header::CONTENT_TYPE, "application/javascript")],
+        include_str!("gif-encoder.js"),
+    )
+}
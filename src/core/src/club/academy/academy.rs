@@ -7,8 +7,9 @@ use crate::{
     Person, Player, PlayerCollection, PlayerFieldPositionGroup, PlayerPositionType, StaffCollection,
 };
 use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AcademyDevelopmentIdentity {
     Balanced,
     TechnicalSchool,
@@ -52,7 +53,7 @@ impl AcademyPlayerPhase {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AcademyPathwayPolicy {
     pub min_graduation_age: u8,
     /// 0..100 readiness threshold — see `pathway_readiness_score` for the
@@ -99,7 +100,7 @@ pub struct AcademyPipelineHealth {
     pub years_since_last_graduate: u16,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubAcademy {
     pub(super) settings: AcademySettings,
     pub(super) tuning: AcademyTuning,
@@ -712,7 +713,7 @@ mod tests {
         let names = PeopleNameGeneratorData {
             first_names: vec!["Test".to_string()],
             last_names: vec!["Player".to_string()],
-            nicknames: vec![],
+            ..Default::default()
         };
         let mut player =
             PlayerGenerator::generate(1, today, PlayerPositionType::MidfielderCenter, 10, &names);
@@ -248,7 +248,7 @@ mod tests {
         let names = PeopleNameGeneratorData {
             first_names: vec!["Test".into()],
             last_names: vec!["Prospect".into()],
-            nicknames: vec![],
+            ..Default::default()
         };
         let mut player =
             PlayerGenerator::generate(1, today, PlayerPositionType::MidfielderCenter, 10, &names);
@@ -413,7 +413,7 @@ mod tests {
         let names = PeopleNameGeneratorData {
             first_names: vec!["Old".into()],
             last_names: vec!["Prospect".into()],
-            nicknames: vec![],
+            ..Default::default()
         };
         let date = NaiveDate::from_ymd_opt(2026, 7, 1).unwrap();
         let mut player =
@@ -10,6 +10,8 @@
 //!                       short scale (norm, readiness threshold,
 //!                       sessions-per-phase, …).
 
+use serde::{Deserialize, Serialize};
+
 /// Half-open size bands the academy aims to keep itself inside, indexed
 /// by 1..10 pathway tier (index 0 unused). The pathway-review tick
 /// uses these as `players_count_range`, and the intake/backfill paths
@@ -31,7 +33,7 @@ pub const TARGET_ACADEMY_SIZE_BY_TIER: [(u8, u8); 11] = [
 /// One source of truth for academy-wide knobs. Cloned into the
 /// `ClubAcademy` at construction so per-club overrides remain possible
 /// later without touching the rest of the code.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AcademyTuning {
     /// Month (1..12) the annual intake fires.
     pub intake_month: u32,
@@ -20,12 +20,13 @@ use crate::transfers::pipeline::{TransferNeedPriority, TransferNeedReason};
 use chrono::Duration;
 use chrono::{Datelike, NaiveDate};
 use log::debug;
+use serde::{Deserialize, Serialize};
 
 /// Long-term club vision — the direction the board wants the manager to
 /// take the club. Drives expectations, recruitment preferences, and
 /// manager-board friction. Each item is advisory: the manager can ignore
 /// it but the board will judge them against it at season's end.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ClubVision {
     pub playing_style: VisionPlayingStyle,
     pub youth_focus: VisionYouthFocus,
@@ -46,7 +47,7 @@ pub struct ClubVision {
     pub review_frequency: ReviewFrequency,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum VisionPlayingStyle {
     #[default]
     Balanced,
@@ -58,7 +59,7 @@ pub enum VisionPlayingStyle {
     DirectPlay,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum VisionYouthFocus {
     #[default]
     Balanced,
@@ -68,7 +69,7 @@ pub enum VisionYouthFocus {
     SignExperienced,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum SigningPreference {
     #[default]
     Anyone,
@@ -80,7 +81,7 @@ pub enum SigningPreference {
     Marquee,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum FinancialStance {
     #[default]
     Balanced,
@@ -92,7 +93,7 @@ pub enum FinancialStance {
     Austerity,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LongTermGoal {
     WinLeague,
     WinDomesticCup,
@@ -105,7 +106,7 @@ pub enum LongTermGoal {
 /// Ownership personality — a simplified chairman archetype whose traits
 /// shape how the board actually exercises its powers. Two knobs, each
 /// with meaningful consequences downstream of board.simulate().
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ChairmanAmbition {
     #[default]
     Balanced,
@@ -118,7 +119,7 @@ pub enum ChairmanAmbition {
     Conservative,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ChairmanPatience {
     #[default]
     Medium,
@@ -128,7 +129,7 @@ pub enum ChairmanPatience {
     High,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChairmanProfile {
     pub ambition: ChairmanAmbition,
     pub patience: ChairmanPatience,
@@ -306,7 +307,7 @@ pub struct BoardDossierSummary {
     pub matches_watched: u16,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SeasonTargets {
     pub transfer_budget: i32,
     pub wage_budget: i32,
@@ -321,7 +322,7 @@ pub struct SeasonTargets {
 /// Board confidence in the current management (0-100).
 /// Drops when results are poor, recovers when exceeding expectations.
 /// At 0 — or after sustained Poor mood — the manager is sacked.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoardConfidence {
     pub level: i32,
 }
@@ -332,7 +333,7 @@ impl Default for BoardConfidence {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubBoard {
     pub mood: BoardMood,
     pub confidence: BoardConfidence,
@@ -374,6 +375,13 @@ pub struct ClubBoard {
     /// hire. Locked in when `manager_search_since` is set so it stays
     /// stable across the search window. Top clubs hold out longer.
     pub search_window_days: u16,
+    /// Day the board last dismissed a manager. Unlike
+    /// `manager_search_since` it survives the appointment, so the press
+    /// can still report the sacking after the seat is filled.
+    pub last_manager_sacked: Option<NaiveDate>,
+    /// Day the last permanent manager was installed — a free-agent
+    /// signing, a poach, or a caretaker confirmed in post.
+    pub last_manager_appointed: Option<NaiveDate>,
     /// Ownership archetype. Modulates budget size, sacking threshold,
     /// and long-term tolerance. Populated at club creation; stable for
     /// the lifetime of the chairman.
@@ -425,6 +433,8 @@ impl ClubBoard {
             manager_shortlist: Vec::new(),
             shortlist_built_at: None,
             search_window_days: 0,
+            last_manager_sacked: None,
+            last_manager_appointed: None,
             chairman: ChairmanProfile::new(),
             ownership: OwnershipModel::new(),
             pressure: BoardPressure::new(),
@@ -1011,6 +1021,11 @@ impl ClubBoard {
         } else {
             (16, 25)
         };
+        // A league squad cap overrides the board's own appetite.
+        let max_squad = board_ctx
+            .league_max_squad_size
+            .map_or(max_squad, |cap| max_squad.min(cap));
+        let min_squad = min_squad.min(max_squad);
 
         // Expected league position. Reputation sets the baseline, then the
         // owner's ambition and the long-term goal pull it up or down, and a
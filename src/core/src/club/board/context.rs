@@ -59,6 +59,8 @@ pub struct BoardContext {
     /// Division tier the main team plays in (1 = top flight). Scales the
     /// board's ambition and how much a finish "should" be celebrated.
     pub league_tier: u8,
+    /// Senior squad ceiling imposed by the league's rules, if any.
+    pub league_max_squad_size: Option<u8>,
     /// Average league points per match this season (0.0 when unknown).
     pub points_per_match: f32,
     /// Full-season goal difference so far.
@@ -113,6 +115,8 @@ pub struct BoardContext {
     pub facility_youth: FacilityLevel,
     pub facility_academy: FacilityLevel,
     pub facility_recruitment: FacilityLevel,
+    /// A stadium expansion is already under construction.
+    pub stadium_expansion_in_progress: bool,
 }
 
 impl BoardContext {
@@ -140,6 +144,7 @@ impl BoardContext {
             wage_budget_usage: 0.0,
             main_tactic: None,
             league_tier: 1,
+            league_max_squad_size: None,
             points_per_match: 0.0,
             goal_difference: 0,
             distance_to_relegation: 0,
@@ -158,6 +163,7 @@ impl BoardContext {
             facility_youth: FacilityLevel::Average,
             facility_academy: FacilityLevel::Average,
             facility_recruitment: FacilityLevel::Average,
+            stadium_expansion_in_progress: false,
         }
     }
 }
@@ -116,11 +116,13 @@ impl FacilityReview {
         // Stadium expansion is its own track. It's only justified by an
         // explicit board mandate at solid demand, OR by genuinely strong
         // sustained demand — a wealthy owner alone is *not* enough, so the
-        // board doesn't pour concrete on the back of one good month.
-        let wants_stadium = match vision.infrastructure_priority {
-            InfrastructurePriority::Stadium => ctx.attendance_ratio >= 1.1,
-            _ => owner.wealth >= 70 && ctx.attendance_ratio >= 1.2,
-        };
+        // board doesn't pour concrete on the back of one good month. One
+        // build at a time: a ground already under construction waits.
+        let wants_stadium = !ctx.stadium_expansion_in_progress
+            && match vision.infrastructure_priority {
+                InfrastructurePriority::Stadium => ctx.attendance_ratio >= 1.1,
+                _ => owner.wealth >= 70 && ctx.attendance_ratio >= 1.2,
+            };
         if wants_stadium {
             let cost = Self::cost_of(ctx, BoardFacility::Stadium);
             if cost <= capacity {
@@ -260,6 +262,25 @@ mod tests {
         )));
     }
 
+    #[test]
+    fn no_second_expansion_while_one_is_being_built() {
+        let mut ctx = rich_ctx();
+        ctx.attendance_ratio = 1.25;
+        ctx.stadium_expansion_in_progress = true;
+        let owner = OwnershipModel {
+            wealth: 85,
+            ..Default::default()
+        };
+        let decisions = FacilityReview::run(&ctx, &ClubVision::default(), &owner);
+        assert!(!decisions.iter().any(|d| matches!(
+            d,
+            BoardDecision::ApproveFacilityUpgrade {
+                facility: BoardFacility::Stadium,
+                ..
+            }
+        )));
+    }
+
     #[test]
     fn upgrade_cost_increases_with_level() {
         assert!(FacilityLevel::Average.upgrade_cost() < FacilityLevel::Superb.upgrade_cost());
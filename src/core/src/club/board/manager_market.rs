@@ -23,8 +23,8 @@ use crate::club::Club;
 use crate::club::StaffStub;
 use crate::club::Team;
 use crate::club::board::ClubBoard;
-use crate::club::staff::free_pool;
 use crate::club::staff::{StaffClubContract, StaffPosition, StaffStatus};
+use crate::club::staff::{former_players, free_pool};
 use crate::shared::fullname::FullName;
 use crate::utils::DateUtils;
 use crate::{SimulatorData, Staff, TeamType};
@@ -32,6 +32,7 @@ use chrono::Duration;
 use chrono::{Datelike, NaiveDate};
 use log::{debug, info};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 
 // ─── Shared types ──────────────────────────────────────────────────────
 
@@ -39,7 +40,7 @@ use rayon::prelude::*;
 /// approach pipeline that operates on it; for now only `FreeAgent`
 /// is reachable, but the variant exists so callers can pattern-match
 /// without breaking when slice C lands.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CandidateSource {
     FreeAgent,
     Employed { current_club_id: u32 },
@@ -48,7 +49,7 @@ pub enum CandidateSource {
 /// A ranked entry on a club's manager shortlist. `fit_score` is the
 /// composite ranking value; `target_salary` is what the candidate
 /// would expect to be offered (the board's actual offer may flex).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManagerCandidate {
     pub staff_id: u32,
     pub fit_score: i32,
@@ -77,7 +78,7 @@ struct EmployedCandidateRaw<'a> {
 /// Approaches are stored on `SimulatorData.pending_manager_approaches`
 /// — a global registry so cascade hires (poached source club starting
 /// its own search) can see the chain.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ApproachState {
     /// The requesting club has notified the source club. Awaiting
     /// permission-to-talk + compensation demand.
@@ -100,7 +101,7 @@ pub enum ApproachState {
 /// One in-flight pursuit of an employed manager. Stored on
 /// `SimulatorData.pending_manager_approaches` and ticked daily by the
 /// world-level manager-market phase.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManagerApproach {
     pub requesting_club_id: u32,
     pub source_club_id: u32,
@@ -457,6 +458,9 @@ impl ManagerSearch {
 pub struct ManagerCandidateScorer;
 
 impl ManagerCandidateScorer {
+    /// Reputation points each trophy adds to a coach's market tier.
+    pub const TROPHY_REPUTATION: u32 = 400;
+
     /// Days the search may run before the board confirms a hire (or
     /// falls back to the caretaker). Top clubs hunt longer because
     /// they're chasing big names; smaller clubs move faster because
@@ -503,10 +507,12 @@ impl ManagerCandidateScorer {
             + staff.staff_attributes.knowledge.tactical_knowledge as i32;
         let mut score = skill * 4; // 0..400
 
-        // Reputation tier match — we infer the candidate's tier from
-        // their composite skill since `Staff` doesn't carry an explicit
-        // reputation field. Wide miss in either direction drops score.
-        let candidate_tier = (skill * 100).min(10000) as u16;
+        // Reputation tier match — the candidate's tier is their
+        // composite skill lifted by the silverware on their CV, so a
+        // proven winner is pitched at bigger clubs than their raw
+        // attributes alone suggest. Wide miss in either direction drops
+        // score.
+        let candidate_tier = Self::managerial_reputation(staff);
         let gap = (candidate_tier as i32 - club_rep as i32).abs();
         score -= gap / 50; // a 1000-pt mismatch costs 20 points
 
@@ -536,6 +542,18 @@ impl ManagerCandidateScorer {
         Some(score)
     }
 
+    /// Reputation tier (0..10000) the market reads a coach at: composite
+    /// coaching skill plus `TROPHY_REPUTATION` per trophy won.
+    pub fn managerial_reputation(staff: &Staff) -> u16 {
+        let skill = staff.staff_attributes.coaching.tactical as u32
+            + staff.staff_attributes.mental.man_management as u32
+            + staff.staff_attributes.mental.motivating as u32
+            + staff.staff_attributes.coaching.mental as u32
+            + staff.staff_attributes.knowledge.tactical_knowledge as u32;
+        let honours = staff.trophies_won as u32 * Self::TROPHY_REPUTATION;
+        (skill * 100 + honours).min(10000) as u16
+    }
+
     /// Score an employed candidate. Currently uses the same
     /// skill-based scoring as free agents but with a small "approach
     /// friction" penalty so the board prefers a free agent of
@@ -573,7 +591,10 @@ impl ManagerCandidateScorer {
             1.0
         };
 
-        ((base as f32) * skill_mult * exp_mult) as u32
+        // Proven winners ask for more: +5% per trophy, capped at +50%.
+        let honours_mult = 1.0 + (staff.trophies_won as f32 * 0.05).min(0.5);
+
+        ((base as f32) * skill_mult * exp_mult * honours_mult) as u32
     }
 
     /// Multiplier on (annual salary × remaining contract years) the
@@ -801,7 +822,9 @@ impl ManagerMarketTick {
     /// The order is load-bearing:
     ///   1. Sacked / contract-lapsed staff must hit the free-agent
     ///      pool *before* shortlists refresh, otherwise the freshly-
-    ///      vacated seats look like they have no candidates.
+    ///      vacated seats look like they have no candidates. Players
+    ///      who retired since the last tick and stay in football join
+    ///      the pool at the same point, for the same reason.
     ///   2. Pool aging (satisfaction decay, retirement) runs before
     ///      shortlists so retiring coaches don't appear as candidates
     ///      this tick.
@@ -821,6 +844,7 @@ impl ManagerMarketTick {
     /// call sites scattered around the orchestrator.
     pub fn run(data: &mut SimulatorData, today: NaiveDate) {
         free_pool::harvest_expired_staff(data, today);
+        former_players::recruit_retired_players(data, today);
         free_pool::tick_free_agent_staff_pool(&mut data.free_agent_staff, today);
         ManagerSeatRepair::run(data, today);
         Self::refresh_shortlists(data);
@@ -1153,6 +1177,7 @@ impl ManagerMarketTick {
             // Fresh appointment — wipe chairman loyalty toward the
             // predecessor, clear all search state.
             club.board.chairman.manager_loyalty = 50;
+            club.board.last_manager_appointed = Some(today);
             ManagerSearch::clear(&mut club.board);
         }
     }
@@ -1450,6 +1475,9 @@ impl ManagerApproach {
             }
             // Clear requesting club's search state — the seat is filled.
             req.board.chairman.manager_loyalty = 50;
+            if signed {
+                req.board.last_manager_appointed = Some(today);
+            }
             ManagerSearch::clear(&mut req.board);
         }
 
@@ -1541,6 +1569,26 @@ mod tests {
         assert!(strong_score > weak_score);
     }
 
+    #[test]
+    fn trophies_lift_candidate_at_big_club() {
+        let today = NaiveDate::from_ymd_opt(2030, 6, 1).unwrap();
+        let unproven = coach(1, 45, today, 14);
+        let mut winner = coach(2, 45, today, 14);
+        winner.trophies_won = 4;
+
+        assert!(
+            ManagerCandidateScorer::managerial_reputation(&winner)
+                > ManagerCandidateScorer::managerial_reputation(&unproven)
+        );
+        let us = ManagerCandidateScorer::score_free_agent(&unproven, 9000, today).unwrap();
+        let ws = ManagerCandidateScorer::score_free_agent(&winner, 9000, today).unwrap();
+        assert!(ws > us, "silverware should close the gap to a top club");
+        assert!(
+            ManagerCandidateScorer::target_salary(&winner, 9000, today)
+                > ManagerCandidateScorer::target_salary(&unproven, 9000, today)
+        );
+    }
+
     #[test]
     fn very_old_candidate_takes_age_penalty() {
         let today = NaiveDate::from_ymd_opt(2030, 6, 1).unwrap();
@@ -1798,6 +1846,27 @@ mod tests {
         let club = data.club(1).unwrap();
         assert!(club.board.manager_search_since.is_none());
         assert!(club.board.manager_shortlist.is_empty());
+        assert!(
+            club.board.last_manager_appointed.is_none(),
+            "a refused appointment is not news"
+        );
+    }
+
+    #[test]
+    fn silverware_is_credited_to_the_manager() {
+        use crate::{Achievement, AchievementType};
+
+        let today = NaiveDate::from_ymd_opt(2030, 6, 1).unwrap();
+        let manager = coach_with_contract(100, today, StaffPosition::Manager, 200_000);
+        let mut club = make_club_with_main(1, vec![manager]);
+        let main = club.teams.main_mut().unwrap();
+
+        main.on_season_trophy(Achievement::new(AchievementType::LeagueTitle, today, 8));
+        main.on_season_trophy(Achievement::new(AchievementType::Promotion, today, 8));
+        main.on_season_trophy(Achievement::new(AchievementType::CupWin, today, 8));
+
+        let trophies = main.staffs.manager().map(|m| m.trophies_won);
+        assert_eq!(trophies, Some(2), "promotion is not silverware");
     }
 
     #[test]
@@ -1834,6 +1903,7 @@ mod tests {
         let main = club.teams.main().unwrap();
         assert!(main.staffs.iter().any(|s| s.id == 42));
         assert!(club.board.manager_search_since.is_none());
+        assert_eq!(club.board.last_manager_appointed, Some(today));
     }
 
     #[test]
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoardMood {
     pub state: BoardMoodState,
 }
@@ -11,7 +13,7 @@ impl BoardMood {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum BoardMoodState {
     Poor,
     Normal,
@@ -8,10 +8,12 @@
 //! sizing, transfer governance, facility approvals, pressure response, or
 //! takeover behaviour. Nothing is inert.
 
+use serde::{Deserialize, Serialize};
+
 /// Who owns the club. Each archetype biases governance differently:
 /// member-owned clubs answer to supporters, state-backed owners chase
 /// trophies regardless of cash, private equity obsesses over resale.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum OwnershipType {
     /// Fan/member owned (Socios model). Reacts hardest to supporter mood,
     /// allergic to debt and unpopular sales.
@@ -59,7 +61,7 @@ impl OwnershipType {
 
 /// Persistent ownership submodel. Knobs are 0-100 so they compose into
 /// smooth multipliers rather than hard switches.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OwnershipModel {
     pub ownership_type: OwnershipType,
     /// Spending power independent of current cash — a rich owner can
@@ -8,6 +8,7 @@
 //! lands on board confidence.
 
 use super::ownership::OwnershipType;
+use serde::{Deserialize, Serialize};
 
 /// Discrete events that spike supporter / media pressure for a tick.
 /// Surfaced by the season's narrative so the board reacts to *stories*,
@@ -53,7 +54,7 @@ impl SupporterEvent {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct BoardPressure {
     pub supporter_pressure: u8,
     pub media_pressure: u8,
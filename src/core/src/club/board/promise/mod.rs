@@ -5,8 +5,9 @@
 //! component score reads outstanding/kept/broken promises.
 
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PromiseType {
     TransferBudget,
     FacilityImprovement,
@@ -16,7 +17,7 @@ pub enum PromiseType {
     TitleChallenge,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum PromiseStatus {
     #[default]
     Active,
@@ -24,7 +25,7 @@ pub enum PromiseStatus {
     Broken,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BoardPromise {
     pub promise_type: PromiseType,
     pub created_at: NaiveDate,
@@ -69,7 +70,7 @@ impl BoardPromise {
 /// A small registry of the board's live promises. Wraps the vec so the
 /// resolution / trust-bookkeeping logic lives next to the data instead of
 /// leaking into the board's `simulate`.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PromiseLedger {
     promises: Vec<BoardPromise>,
 }
@@ -6,8 +6,9 @@
 //! keep working.
 
 use super::scoring::BoardComponentScores;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManagerRelationship {
     /// Trust earned through on-pitch results vs expectations.
     pub trust_results: u8,
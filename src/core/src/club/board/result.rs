@@ -5,7 +5,7 @@ use crate::club::facilities::FacilityLevel;
 use crate::club::player::behaviour_config::HappinessConfig;
 use crate::league::result::LeagueProcessAccess;
 use crate::{Club, HappinessEventType, Staff, StaffEventType, TeamType};
-use chrono::Datelike;
+use chrono::{Datelike, NaiveDate};
 use log::{debug, info};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -110,7 +110,7 @@ impl BoardResult {
             // separate percentage tweak here (that double-applied with the
             // decision amounts). `apply_decisions` is the single mutation
             // point for budgets, facility upgrades, and takeover injections.
-            Self::apply_decisions(&self.decisions, club);
+            Self::apply_decisions(&self.decisions, club, today);
 
             // Push the board's mood onto the manager's own job satisfaction —
             // a coach at a happy club feels secure, a coach under Poor mood
@@ -260,6 +260,7 @@ impl BoardResult {
                     .map(|t| t.reputation.world)
                     .unwrap_or(0);
                 manager_market::ManagerSearch::open(&mut club.board, today, club_rep);
+                club.board.last_manager_sacked = Some(today);
             }
         } // end of `club` mutable-borrow scope
 
@@ -280,7 +281,7 @@ impl BoardResult {
     /// transfer/wage budget adjustments, approved facility upgrades, and a
     /// takeover cash injection. Other variants (meetings, sackings, search,
     /// rumours, demands) are informational or handled by legacy fields.
-    fn apply_decisions(decisions: &[BoardDecision], club: &mut Club) {
+    fn apply_decisions(decisions: &[BoardDecision], club: &mut Club, today: NaiveDate) {
         for decision in decisions {
             match decision {
                 BoardDecision::IncreaseTransferBudget { amount, .. } => {
@@ -303,7 +304,7 @@ impl BoardResult {
                     }
                 }
                 BoardDecision::ApproveFacilityUpgrade { facility, cost } => {
-                    Self::apply_facility_upgrade(club, *facility, *cost);
+                    Self::apply_facility_upgrade(club, *facility, *cost, today);
                 }
                 BoardDecision::CompleteTakeover => {
                     // New owner injects cash proportional to the club's wage
@@ -330,26 +331,27 @@ impl BoardResult {
         }
     }
 
-    /// Bump the targeted facility one level (debiting the cost) or expand
-    /// the stadium's capacity proxy. Costs draw down cash via the finance
-    /// balance so the upgrade has a real budget consequence.
-    fn apply_facility_upgrade(club: &mut Club, facility: BoardFacility, cost: i64) {
+    /// Bump the targeted facility one level or break ground on a stadium
+    /// expansion, debiting the cost. Costs draw down cash via the finance
+    /// balance so the upgrade has a real budget consequence; an expansion
+    /// is paid up front and its seats open once construction completes.
+    fn apply_facility_upgrade(
+        club: &mut Club,
+        facility: BoardFacility,
+        cost: i64,
+        today: NaiveDate,
+    ) {
         let upgraded = match facility {
             BoardFacility::Training => Self::step_up(&mut club.facilities.training),
             BoardFacility::Youth => Self::step_up(&mut club.facilities.youth),
             BoardFacility::Academy => Self::step_up(&mut club.facilities.academy),
             BoardFacility::Recruitment => Self::step_up(&mut club.facilities.recruitment),
             BoardFacility::Stadium => {
-                match Self::expanded_attendance(club.facilities.average_attendance) {
-                    Some(next) => {
-                        club.facilities.average_attendance = next;
-                        true
-                    }
-                    // No real stadium/attendance model for this club — the
-                    // expansion is a news-only announcement, so we must NOT
-                    // debit cash for a change nothing can see.
-                    None => false,
-                }
+                // No attendance model (or a build already running) — the
+                // expansion is a news-only announcement, so we must NOT
+                // debit cash for a change nothing can see.
+                let capacity = club.stadium_capacity();
+                club.facilities.start_stadium_expansion(capacity, today)
             }
         };
         if upgraded {
@@ -369,39 +371,5 @@ impl BoardResult {
             false
         }
     }
-
-    /// Post-expansion average attendance for a stadium upgrade (~+15%), or
-    /// `None` when there's no stadium model to change. `average_attendance`
-    /// of 0 means "unmodelled" — expanding it would change nothing visible,
-    /// so the caller must not debit cash for it.
-    ///
-    /// TODO: when real stadium capacity is modelled, key this off capacity
-    /// rather than the average-attendance proxy.
-    fn expanded_attendance(current: u32) -> Option<u32> {
-        if current == 0 {
-            None
-        } else {
-            Some(current + (current / 7).max(1))
-        }
-    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn stadium_expansion_is_news_only_when_unmodelled() {
-        // No attendance model (0) → no state change → caller must not debit.
-        assert_eq!(BoardResult::expanded_attendance(0), None);
-    }
-
-    #[test]
-    fn stadium_expansion_grows_a_real_attendance() {
-        let next = BoardResult::expanded_attendance(28_000).expect("modelled stadium expands");
-        assert!(
-            next > 28_000,
-            "expansion should raise attendance, got {next}"
-        );
-    }
-}
@@ -9,6 +9,7 @@ use super::context::{BoardContext, FfpStatus};
 use super::promise::PromiseLedger;
 use super::strategy::SquadProfile;
 use super::{ClubVision, SeasonTargets, VisionYouthFocus};
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
 /// Where in the season we are. Early months are judged softly; the run-in
@@ -76,7 +77,7 @@ impl SeasonPhase {
 
 /// The four component scores from one board review. Roughly `[-40, 40]`
 /// each; positive = pleasing the board.
-#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 pub struct BoardComponentScores {
     pub sporting: f32,
     pub financial: f32,
@@ -6,9 +6,11 @@
 //! the board overrides recruitment, and review frequency controls how
 //! often confidence is re-judged.
 
+use serde::{Deserialize, Serialize};
+
 /// The kind of squad the board wants assembled. Read by transfer
 /// governance to bias which incoming players are welcomed or blocked.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum SquadProfile {
     #[default]
     Balanced,
@@ -26,7 +28,7 @@ pub enum SquadProfile {
 
 /// Where surplus money should go when the board funds infrastructure.
 /// Drives the yearly facility review's preference ordering.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum InfrastructurePriority {
     #[default]
     None,
@@ -39,7 +41,7 @@ pub enum InfrastructurePriority {
 /// How much rope the manager gets on football decisions. Combined with
 /// ownership interference to decide whether the director of football
 /// overrides the manager and how forgiving the sacking threshold is.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ManagerAutonomy {
     Low,
     #[default]
@@ -71,7 +73,7 @@ impl ManagerAutonomy {
 
 /// How often the board formally re-evaluates the manager. Quarterly /
 /// season-end boards ignore short-term wobbles between reviews.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum ReviewFrequency {
     #[default]
     Monthly,
@@ -11,8 +11,9 @@
 use super::context::{BoardContext, FfpStatus};
 use super::decision::BoardDecision;
 use super::ownership::{OwnershipModel, OwnershipType};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum TakeoverStatus {
     #[default]
     None,
@@ -21,7 +22,7 @@ pub enum TakeoverStatus {
     Completed,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TakeoverWatch {
     pub status: TakeoverStatus,
     /// Months the watch has spent in the current status.
@@ -40,10 +40,9 @@ fn ticket_base_price(rep: ReputationLevel) -> f64 {
     }
 }
 
-/// Stadium-capacity ceiling derived from reputation tier. Used to cap
-/// dynamic attendance so an in-form National-tier club isn't projected to
-/// pull Premier League gates. Replace with per-club capacity once the
-/// `ClubFacilities` ground-capacity field is plumbed in.
+/// Stadium-capacity estimate derived from reputation tier. Stands in for
+/// the ground's capacity when the club has none modelled, so an in-form
+/// National-tier club isn't projected to pull Premier League gates.
 fn stadium_capacity_for(rep: ReputationLevel) -> u32 {
     match rep {
         ReputationLevel::Elite => 55_000,
@@ -55,6 +54,15 @@ fn stadium_capacity_for(rep: ReputationLevel) -> u32 {
     }
 }
 
+/// Opponent pull on the gate from the 0.0–1.0 reputation scores: a visit
+/// from a bigger name sells extra seats, a lesser side leaves some empty.
+fn opponent_attendance_multiplier(home_reputation: f32, away_reputation: f32) -> f64 {
+    (1.0 + (away_reputation - home_reputation) as f64 * 0.5).clamp(0.9, 1.25)
+}
+
+/// Extra pull of a derby on the gate, on top of the opponent's reputation.
+const DERBY_ATTENDANCE_MULTIPLIER: f64 = 1.2;
+
 fn league_tier_of(ctx: &GlobalContext<'_>, _league_id: Option<u32>) -> u8 {
     ctx.club
         .as_ref()
@@ -111,11 +119,6 @@ impl Club {
             .as_ref()
             .map(|c| c.tv_revenue_multiplier)
             .unwrap_or(1.0);
-        let attendance_factor = ctx
-            .country
-            .as_ref()
-            .map(|c| c.stadium_attendance_factor)
-            .unwrap_or(1.0);
         let sponsorship_strength = ctx
             .country
             .as_ref()
@@ -169,8 +172,7 @@ impl Club {
         if let Some(team) = main_team {
             let rep = team.reputation.level();
             let league_id = team.league_id;
-            let (recent_wins_ratio, league_pos, total_teams) =
-                self.compute_team_form_and_position(&ctx);
+            let (league_pos, total_teams) = self.compute_team_position(&ctx);
 
             // TV: reputation base × country market × league tier × placement.
             // The reputation base is what a tier-1 club earns in a "world-
@@ -200,20 +202,13 @@ impl Club {
                 self.finance.balance.income_tv -= placement_premium;
             }
 
-            // Matchday: actual home matches this month × per-match gate.
+            // Matchday: every spectator the attendance model put through
+            // the gate at this month's home fixtures × the ticket price.
             let price_level = get_price_level(&ctx);
-            let base_attendance = self.facilities.average_attendance as f64;
-            let form_mult = self.facilities.dynamic_attendance_multiplier(
-                recent_wins_ratio,
-                league_pos,
-                total_teams,
-            ) as f64;
-            let stadium_capacity = stadium_capacity_for(rep) as f64;
-            let raw_attendance = base_attendance * attendance_factor as f64 * form_mult;
-            let attendance = raw_attendance.min(stadium_capacity).max(0.0) as i64;
             let ticket_price = (ticket_base_price(rep) * price_level) as i64;
-            let home_matches = self.finance.take_home_match_count() as i64;
-            let matchday_revenue = attendance * ticket_price * home_matches;
+            self.finance.take_home_match_count();
+            let attendance = self.finance.take_home_attendance() as i64;
+            let matchday_revenue = attendance * ticket_price;
             if matchday_revenue > 0 {
                 self.finance.balance.push_income_matchday(matchday_revenue);
             }
@@ -325,21 +320,56 @@ impl Club {
     /// club's main team. Form comes from the last ~5 matches in the team's
     /// `match_history`; league position rides through `ClubContext` —
     /// which the country simulation populates from the live table.
-    fn compute_team_form_and_position(&self, ctx: &GlobalContext<'_>) -> (f32, u16, u16) {
-        let wins_ratio = self
-            .teams
-            .main()
-            .map(|team| team.match_history.recent_wins_ratio(5))
-            .unwrap_or(0.5);
-
-        let (position, total) = ctx
-            .club
+    fn compute_team_position(&self, ctx: &GlobalContext<'_>) -> (u16, u16) {
+        ctx.club
             .as_ref()
             .map(|c| (c.league_position as u16, c.league_size as u16))
             .map(|(p, t)| if p == 0 || t == 0 { (10, 20) } else { (p, t) })
-            .unwrap_or((10, 20));
+            .unwrap_or((10, 20))
+    }
 
-        (wins_ratio, position, total)
+    /// Seats in the home ground — the modelled capacity when known,
+    /// otherwise the reputation-tier estimate.
+    pub fn stadium_capacity(&self) -> u32 {
+        if self.facilities.stadium_capacity > 0 {
+            return self.facilities.stadium_capacity;
+        }
+        self.teams
+            .main()
+            .map(|t| stadium_capacity_for(t.reputation.level()))
+            .unwrap_or(0)
+    }
+
+    /// Crowd for a single home fixture: the club's regular gate scaled by
+    /// the country's stadium culture, recent form, league position and the
+    /// opponent's pull — boosted for a derby — capped at the ground's
+    /// capacity. `league_position` is 1-indexed; pass `(10, 20)` when no
+    /// table is available.
+    pub fn match_attendance(
+        &self,
+        opponent_reputation: f32,
+        attendance_factor: f32,
+        league_position: u16,
+        total_teams: u16,
+        is_derby: bool,
+    ) -> u32 {
+        let Some(team) = self.teams.main() else {
+            return 0;
+        };
+        let form = self.facilities.dynamic_attendance_multiplier(
+            team.match_history.recent_wins_ratio(5),
+            league_position,
+            total_teams,
+        ) as f64;
+        let mut opponent =
+            opponent_attendance_multiplier(team.reputation.overall_score(), opponent_reputation);
+        if is_derby {
+            opponent *= DERBY_ATTENDANCE_MULTIPLIER;
+        }
+        let capacity = self.stadium_capacity() as f64;
+        let crowd =
+            self.facilities.average_attendance as f64 * attendance_factor as f64 * form * opponent;
+        crowd.min(capacity).max(0.0) as u32
     }
 }
 
@@ -593,7 +623,8 @@ mod excess_cash_tests {
 #[cfg(test)]
 mod helpers_tests {
     use super::{
-        league_tier_multiplier, placement_multiplier, stadium_capacity_for, tv_revenue_base,
+        league_tier_multiplier, opponent_attendance_multiplier, placement_multiplier,
+        stadium_capacity_for, tv_revenue_base,
     };
     use crate::ReputationLevel;
 
@@ -641,4 +672,14 @@ mod helpers_tests {
         );
         assert!(stadium_capacity_for(ReputationLevel::Amateur) >= 100);
     }
+
+    #[test]
+    fn bigger_opponents_draw_bigger_crowds() {
+        assert_eq!(opponent_attendance_multiplier(0.5, 0.5), 1.0);
+        assert!(opponent_attendance_multiplier(0.4, 0.9) > 1.0);
+        assert!(opponent_attendance_multiplier(0.9, 0.2) < 1.0);
+        // Bounded both ways — a minnow's visit never empties the ground.
+        assert_eq!(opponent_attendance_multiplier(1.0, 0.0), 0.9);
+        assert_eq!(opponent_attendance_multiplier(0.0, 1.0), 1.25);
+    }
 }
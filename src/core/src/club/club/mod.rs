@@ -1,9 +1,13 @@
 mod finances;
 mod graduation;
+mod preseason;
 mod squad;
 mod utilization;
 
+use serde::{Deserialize, Serialize};
+
 use graduation::graduation_salary;
+pub use preseason::{PreseasonFriendly, PreseasonTour};
 
 use crate::club::academy::ClubAcademy;
 use crate::club::board::{BoardContext, ClubBoard, FfpStatus};
@@ -17,7 +21,7 @@ use crate::utils::DateUtils;
 use crate::{ReputationLevel, TeamCollection, TeamType};
 use chrono::{Duration, NaiveDate};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ClubPhilosophy {
     /// Develop youth and sell for profit (Ajax, Benfica, Dortmund)
     DevelopAndSell,
@@ -29,7 +33,7 @@ pub enum ClubPhilosophy {
     Balanced,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubColors {
     pub background: String,
     pub foreground: String,
@@ -44,7 +48,7 @@ impl Default for ClubColors {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Club {
     pub id: u32,
     pub name: String,
@@ -70,6 +74,8 @@ pub struct Club {
     pub facilities: ClubFacilities,
 
     pub rivals: Vec<u32>,
+
+    pub preseason: PreseasonTour,
 }
 
 /// Aggregated best staff attribute scores across all teams at the club.
@@ -117,6 +123,7 @@ impl Club {
             philosophy,
             facilities,
             rivals: Vec::new(),
+            preseason: PreseasonTour::default(),
         }
     }
 
@@ -216,6 +223,7 @@ impl Club {
         board_ctx.league_size = league_sz;
         board_ctx.total_matches = total_matches;
         board_ctx.league_tier = league_tier.max(1);
+        board_ctx.league_max_squad_size = ctx.club.as_ref().and_then(|c| c.league_max_squad_size);
         board_ctx.trailing_annual_income = self.finance.trailing_annual_income(date);
         board_ctx.trailing_annual_outcome = self.finance.trailing_annual_outcome(date);
         board_ctx.ffp_status = if self.finance.is_ffp_breach(date) {
@@ -300,6 +308,7 @@ impl Club {
                             prev.league_matches_played,
                         )
                         .with_main_league_tier(prev.main_league_tier)
+                        .with_league_max_squad_size(prev.league_max_squad_size)
                         .with_reputations(
                             prev.main_team_reputation,
                             prev.main_team_world_reputation,
@@ -345,6 +354,14 @@ impl Club {
         }
 
         if ctx.simulation.is_month_beginning() {
+            if let Some(seats) = self.facilities.complete_stadium_expansion(date) {
+                log::info!(
+                    "Stadium expansion opened at {}: +{} seats (capacity {})",
+                    self.name,
+                    seats,
+                    self.facilities.stadium_capacity
+                );
+            }
             if self.teams.ensure_coach_state(date) {
                 self.transfer_plan.manager_review_until = Some(date + Duration::days(45));
             }
@@ -560,6 +577,7 @@ impl Club {
             wage_budget_usage,
             main_tactic,
             league_tier: 1,
+            league_max_squad_size: None,
             points_per_match,
             goal_difference,
             distance_to_relegation: 0,
@@ -578,6 +596,7 @@ impl Club {
             facility_youth: self.facilities.youth.clone(),
             facility_academy: self.facilities.academy.clone(),
             facility_recruitment: self.facilities.recruitment.clone(),
+            stadium_expansion_in_progress: self.facilities.stadium_expansion.is_some(),
         }
     }
 }
@@ -0,0 +1,41 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// One arranged pre-season friendly from this club's side of the fixture.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PreseasonFriendly {
+    pub date: NaiveDate,
+    pub opponent_club_id: u32,
+    pub home: bool,
+    pub played: bool,
+}
+
+/// The club's pre-season programme for the season starting on
+/// `season_start`. Rebuilt once per off-season; the country arranges the
+/// fixtures, the club only keeps its own side of each.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PreseasonTour {
+    pub season_start: Option<NaiveDate>,
+    /// How many friendlies the club wants this summer (3–5).
+    pub target: u8,
+    pub friendlies: Vec<PreseasonFriendly>,
+}
+
+impl PreseasonTour {
+    /// Drop last summer's programme and open one for `season_start`.
+    pub fn reset(&mut self, season_start: NaiveDate, target: u8) {
+        self.season_start = Some(season_start);
+        self.target = target;
+        self.friendlies.clear();
+    }
+
+    /// Unplayed friendly on `date`, if one is arranged.
+    pub fn take_due(&mut self, date: NaiveDate) -> Option<PreseasonFriendly> {
+        let friendly = self
+            .friendlies
+            .iter_mut()
+            .find(|f| f.date == date && !f.played)?;
+        friendly.played = true;
+        Some(friendly.clone())
+    }
+}
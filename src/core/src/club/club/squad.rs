@@ -1055,6 +1055,8 @@ mod trim_surplus_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
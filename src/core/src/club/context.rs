@@ -17,6 +17,8 @@ pub struct ClubContext<'c> {
     /// Tier of the main team's primary league (1 = top flight, 2 = second
     /// division, …). Drives the TV revenue ladder.
     pub main_league_tier: u8,
+    /// Senior squad ceiling set by the main team's league rules.
+    pub league_max_squad_size: Option<u8>,
     /// Best physiotherapy score on the club staff (0.0-1.0).
     /// Drives injury recovery speedup and preventive rest.
     pub medical_quality: f32,
@@ -70,6 +72,7 @@ impl<'c> ClubContext<'c> {
             total_league_matches: 0,
             league_matches_played: 0,
             main_league_tier: 1,
+            league_max_squad_size: None,
             medical_quality: 0.35,
             sports_science_quality: 0.35,
             youth_coaching_quality: 0.35,
@@ -118,6 +121,11 @@ impl<'c> ClubContext<'c> {
         self
     }
 
+    pub fn with_league_max_squad_size(mut self, cap: Option<u8>) -> Self {
+        self.league_max_squad_size = cap;
+        self
+    }
+
     pub fn with_staff_quality(
         mut self,
         medical: f32,
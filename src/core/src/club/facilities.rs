@@ -1,7 +1,10 @@
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+
 /// Facility quality levels for clubs.
 /// These affect training quality, youth development, and player generation.
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum FacilityLevel {
     Best,
     Exceptional,
@@ -101,7 +104,7 @@ impl Default for FacilityLevel {
 }
 
 /// Club-level facilities that affect training, youth development, and player generation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubFacilities {
     /// Quality of first-team training facilities
     pub training: FacilityLevel,
@@ -113,6 +116,19 @@ pub struct ClubFacilities {
     pub recruitment: FacilityLevel,
     /// Average match attendance
     pub average_attendance: u32,
+    /// Seated capacity of the home ground. 0 = not modelled; callers fall
+    /// back to a reputation-tier estimate (see `Club::stadium_capacity`).
+    pub stadium_capacity: u32,
+    /// Stand expansion currently under construction, if any.
+    pub stadium_expansion: Option<StadiumExpansion>,
+}
+
+/// A board-approved stadium expansion. The extra seats only open once
+/// construction finishes, so the investment pays back over seasons.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StadiumExpansion {
+    pub added_seats: u32,
+    pub completes_on: NaiveDate,
 }
 
 impl Default for ClubFacilities {
@@ -123,11 +139,66 @@ impl Default for ClubFacilities {
             academy: FacilityLevel::Average,
             recruitment: FacilityLevel::Average,
             average_attendance: 0,
+            stadium_capacity: 0,
+            stadium_expansion: None,
         }
     }
 }
 
 impl ClubFacilities {
+    /// Build time from board approval to the new stands opening — roughly
+    /// a season and a half.
+    pub const STADIUM_BUILD_DAYS: i64 = 540;
+
+    /// Smallest expansion worth breaking ground for.
+    const MIN_EXPANSION_SEATS: u32 = 500;
+
+    /// Break ground on a ~20% expansion of `current_capacity`. The capacity
+    /// is locked in on the facilities so a reputation-estimated ground
+    /// becomes a modelled one. Returns `false` when there's nothing to
+    /// expand — a build is already running, or the club has no attendance
+    /// model (0) and the extra seats would change nothing visible.
+    pub fn start_stadium_expansion(&mut self, current_capacity: u32, today: NaiveDate) -> bool {
+        if self.stadium_expansion.is_some() || self.average_attendance == 0 {
+            return false;
+        }
+        self.stadium_capacity = current_capacity;
+        self.stadium_expansion = Some(StadiumExpansion {
+            added_seats: (current_capacity / 5).max(Self::MIN_EXPANSION_SEATS),
+            completes_on: today + Duration::days(Self::STADIUM_BUILD_DAYS),
+        });
+        true
+    }
+
+    /// Open the new stands once construction has finished: capacity grows
+    /// and the bigger ground lifts the regular gate. Returns the seats
+    /// added, or `None` when nothing completed today.
+    pub fn complete_stadium_expansion(&mut self, today: NaiveDate) -> Option<u32> {
+        if self
+            .stadium_expansion
+            .as_ref()
+            .is_none_or(|e| e.completes_on > today)
+        {
+            return None;
+        }
+        let expansion = self.stadium_expansion.take()?;
+        self.stadium_capacity += expansion.added_seats;
+        if let Some(next) = Self::expanded_attendance(self.average_attendance) {
+            self.average_attendance = next;
+        }
+        Some(expansion.added_seats)
+    }
+
+    /// Post-expansion average attendance (~+15%), or `None` when there's
+    /// no attendance model to change (0 means "unmodelled").
+    pub(crate) fn expanded_attendance(current: u32) -> Option<u32> {
+        if current == 0 {
+            None
+        } else {
+            Some(current + (current / 7).max(1))
+        }
+    }
+
     /// Training quality multiplier (affects player development speed)
     pub fn training_multiplier(&self) -> f32 {
         self.training.multiplier()
@@ -181,3 +252,56 @@ impl ClubFacilities {
         (1.0 + form + position).clamp(0.65, 1.30)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stadium_expansion_is_news_only_when_unmodelled() {
+        // No attendance model (0) → no state change → caller must not debit.
+        assert_eq!(ClubFacilities::expanded_attendance(0), None);
+    }
+
+    #[test]
+    fn stadium_expansion_grows_a_real_attendance() {
+        let next = ClubFacilities::expanded_attendance(28_000).expect("modelled stadium expands");
+        assert!(
+            next > 28_000,
+            "expansion should raise attendance, got {next}"
+        );
+    }
+
+    #[test]
+    fn stadium_expansion_opens_seats_after_construction() {
+        let today = NaiveDate::from_ymd_opt(2030, 7, 1).unwrap();
+        let mut facilities = ClubFacilities {
+            average_attendance: 20_000,
+            ..ClubFacilities::default()
+        };
+
+        assert!(facilities.start_stadium_expansion(25_000, today));
+        assert!(
+            !facilities.start_stadium_expansion(25_000, today),
+            "one build at a time"
+        );
+        assert_eq!(facilities.stadium_capacity, 25_000);
+
+        let halfway = today + chrono::Duration::days(ClubFacilities::STADIUM_BUILD_DAYS / 2);
+        assert_eq!(facilities.complete_stadium_expansion(halfway), None);
+
+        let done = today + chrono::Duration::days(ClubFacilities::STADIUM_BUILD_DAYS);
+        assert_eq!(facilities.complete_stadium_expansion(done), Some(5_000));
+        assert_eq!(facilities.stadium_capacity, 30_000);
+        assert!(facilities.average_attendance > 20_000);
+        assert!(facilities.stadium_expansion.is_none());
+    }
+
+    #[test]
+    fn stadium_expansion_refused_without_attendance_model() {
+        let today = NaiveDate::from_ymd_opt(2030, 7, 1).unwrap();
+        let mut facilities = ClubFacilities::default();
+        assert!(!facilities.start_stadium_expansion(25_000, today));
+        assert!(facilities.stadium_expansion.is_none());
+    }
+}
@@ -7,8 +7,9 @@ use crate::{
 use chrono::Duration;
 use chrono::NaiveDate;
 use log::debug;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubFinances {
     pub balance: ClubFinancialBalance,
     pub history: ClubFinancialBalanceHistory,
@@ -21,11 +22,20 @@ pub struct ClubFinances {
     /// Home matches played this month — drives matchday revenue. Reset by
     /// the monthly tick, incremented when a home match concludes.
     pub home_matches_this_month: u32,
+    /// Spectators through the gate across this month's home matches.
+    /// Drained alongside `home_matches_this_month`.
+    pub home_attendance_this_month: u64,
+    /// Financial health as of the last month-beginning check. Persisted
+    /// so the transfer engine can read it between ticks.
+    pub distress_level: DistressLevel,
+    /// Regulator-imposed ban on registering new players, lifted on this
+    /// date. Set by an FFP transfer-ban sanction.
+    pub transfer_embargo_until: Option<NaiveDate>,
 }
 
 /// One amortization stream: a transfer fee spread across the contract
 /// length so each month the buying club's P&L recognises its share.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferObligation {
     pub monthly_amount: i64,
     pub months_remaining: u32,
@@ -33,7 +43,7 @@ pub struct TransferObligation {
 
 /// Severity of debt — drives the monthly interest rate and is consumed by
 /// the result-stage to decide how aggressively to cut budgets.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum DistressLevel {
     None,
     Distress,
@@ -51,6 +61,9 @@ impl ClubFinances {
             wage_budget: None,
             transfer_obligations: Vec::new(),
             home_matches_this_month: 0,
+            home_attendance_this_month: 0,
+            distress_level: DistressLevel::None,
+            transfer_embargo_until: None,
         }
     }
 
@@ -68,6 +81,9 @@ impl ClubFinances {
             wage_budget,
             transfer_obligations: Vec::new(),
             home_matches_this_month: 0,
+            home_attendance_this_month: 0,
+            distress_level: DistressLevel::None,
+            transfer_embargo_until: None,
         }
     }
 
@@ -77,6 +93,12 @@ impl ClubFinances {
         let club_id = ctx.club.as_ref().map(|c| c.id).unwrap_or(0);
         result = result.with_club(club_id);
 
+        let today = ctx.simulation.date.date();
+        if self.transfer_embargo_until.is_some_and(|until| today >= until) {
+            debug!("club: {}, finance: transfer embargo lifted", club_name);
+            self.transfer_embargo_until = None;
+        }
+
         if ctx.simulation.is_month_beginning() {
             debug!("club: {}, finance: start new month", club_name);
             // Distress check uses the trailing wage average — read it
@@ -87,6 +109,7 @@ impl ClubFinances {
             let level = classify_distress(self.balance.balance, avg_wages);
             result.is_in_distress = !matches!(level, DistressLevel::None);
             result.distress_level = level;
+            self.distress_level = level;
 
             self.start_new_month(club_name, ctx.simulation.date.date());
 
@@ -148,9 +171,13 @@ impl ClubFinances {
     }
 
     /// Schedule a home match for the current month. Called from the match
-    /// pipeline when a non-friendly home fixture concludes.
-    pub fn record_home_match(&mut self) {
+    /// pipeline when a non-friendly home fixture concludes, with the crowd
+    /// the attendance model put through the gate.
+    pub fn record_home_match(&mut self, attendance: u32) {
         self.home_matches_this_month = self.home_matches_this_month.saturating_add(1);
+        self.home_attendance_this_month = self
+            .home_attendance_this_month
+            .saturating_add(attendance as u64);
     }
 
     /// Pull and reset the month's home-match count. Used by
@@ -162,6 +189,14 @@ impl ClubFinances {
         n
     }
 
+    /// Pull and reset the month's total home attendance. Matchday revenue
+    /// is this figure times the ticket price.
+    pub fn take_home_attendance(&mut self) -> u64 {
+        let n = self.home_attendance_this_month;
+        self.home_attendance_this_month = 0;
+        n
+    }
+
     /// Tick all outstanding amortization streams: each charges one month's
     /// slice as `expense_amortization`. Streams that reach zero remaining
     /// months are dropped.
@@ -359,6 +394,40 @@ impl ClubFinances {
         }
     }
 
+    /// Bar the club from registering new players until `until`. A second
+    /// ban while one is running keeps whichever ends later.
+    pub fn impose_transfer_embargo(&mut self, until: NaiveDate) {
+        self.transfer_embargo_until = Some(match self.transfer_embargo_until {
+            Some(current) => current.max(until),
+            None => until,
+        });
+    }
+
+    pub fn is_transfer_embargoed(&self) -> bool {
+        self.transfer_embargo_until.is_some()
+    }
+
+    /// Spending power the AI transfer engine plans against. Reads the
+    /// configured transfer budget (30% of positive cash when none is
+    /// set), halved while in FFP breach. An insolvent club can't buy at
+    /// all — sale proceeds recycled into the budget stay frozen until the
+    /// balance recovers.
+    pub fn available_transfer_budget(&self, today: NaiveDate) -> f64 {
+        if self.distress_level == DistressLevel::Insolvency {
+            return 0.0;
+        }
+        let raw_budget = self
+            .transfer_budget
+            .as_ref()
+            .map(|b| b.amount)
+            .unwrap_or_else(|| (self.balance.balance.max(0) as f64) * 0.3);
+        if self.is_ffp_breach(today) {
+            raw_budget * 0.5
+        } else {
+            raw_budget
+        }
+    }
+
     /// Number of completed-month snapshots inside the trailing 365 days.
     /// Gates wealth policies that need a full year of revenue evidence
     /// (e.g. `ExcessCashDeployment`) so a freshly generated world doesn't
@@ -479,7 +548,7 @@ pub fn classify_distress(balance: i64, avg_monthly_wages: i64) -> DistressLevel
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubFinancialBalance {
     pub balance: i64,
     pub income: i64,
@@ -861,12 +930,14 @@ mod finance_tests {
     #[test]
     fn home_match_counter_records_and_resets() {
         let mut f = ClubFinances::new(0, vec![]);
-        f.record_home_match();
-        f.record_home_match();
+        f.record_home_match(30_000);
+        f.record_home_match(25_000);
         assert_eq!(f.home_matches_this_month, 2);
         let n = f.take_home_match_count();
         assert_eq!(n, 2);
         assert_eq!(f.home_matches_this_month, 0);
+        assert_eq!(f.take_home_attendance(), 55_000);
+        assert_eq!(f.home_attendance_this_month, 0);
     }
 
     #[test]
@@ -962,6 +1033,68 @@ mod finance_tests {
     }
 }
 
+#[cfg(test)]
+mod available_transfer_budget_tests {
+    use super::*;
+    use crate::shared::{Currency, CurrencyValue};
+
+    fn today() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 1, 1).unwrap()
+    }
+
+    #[test]
+    fn unset_budget_falls_back_to_share_of_cash() {
+        let f = ClubFinances::new(10_000_000, vec![]);
+        assert_eq!(f.available_transfer_budget(today()), 3_000_000.0);
+    }
+
+    #[test]
+    fn ffp_breach_halves_spending_power() {
+        let mut f = ClubFinances::with_budgets(
+            0,
+            vec![],
+            Some(CurrencyValue::new(8_000_000.0, Currency::Usd)),
+            None,
+        );
+        for m in 1..=12 {
+            let mut snap = ClubFinancialBalance::new(0);
+            snap.outcome = 2_000_000;
+            f.history
+                .add(NaiveDate::from_ymd_opt(2024, m, 1).unwrap(), snap);
+        }
+        assert!(f.is_ffp_breach(today()));
+        assert_eq!(f.available_transfer_budget(today()), 4_000_000.0);
+    }
+
+    #[test]
+    fn insolvent_club_cannot_spend_recycled_sale_income() {
+        let mut f = ClubFinances::new(-50_000_000, vec![]);
+        f.distress_level = DistressLevel::Insolvency;
+        f.add_transfer_income(10_000_000.0);
+        assert!(f.transfer_budget.is_some());
+        assert_eq!(f.available_transfer_budget(today()), 0.0);
+    }
+}
+
+#[cfg(test)]
+mod transfer_embargo_tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn overlapping_embargoes_keep_the_later_end() {
+        let mut f = ClubFinances::new(0, vec![]);
+        assert!(!f.is_transfer_embargoed());
+        f.impose_transfer_embargo(d(2027, 6, 1));
+        f.impose_transfer_embargo(d(2027, 1, 1));
+        assert!(f.is_transfer_embargoed());
+        assert_eq!(f.transfer_embargo_until, Some(d(2027, 6, 1)));
+    }
+}
+
 #[cfg(test)]
 mod transfer_cash_tests {
     use super::ClubFinances;
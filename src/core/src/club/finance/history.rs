@@ -1,8 +1,9 @@
 use crate::club::ClubFinancialBalance;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use std::collections::LinkedList;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubFinancialBalanceHistory {
     history: LinkedList<(NaiveDate, ClubFinancialBalance)>,
 }
@@ -127,7 +127,10 @@ impl ClubFinanceResult {
                 None => return,
             };
             for _ in 0..deals_to_sign {
-                if let Some(contract) = renewal_ctx.generate(date) {
+                let Some(kind) = club.finance.sponsorship.open_slot(reputation) else {
+                    break;
+                };
+                if let Some(contract) = renewal_ctx.generate(date, kind) {
                     club.finance
                         .sponsorship
                         .sponsorship_contracts
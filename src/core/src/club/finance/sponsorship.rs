@@ -1,8 +1,9 @@
 use crate::ReputationLevel;
 use crate::utils::FloatUtils;
 use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubSponsorship {
     pub sponsorship_contracts: Vec<ClubSponsorshipContract>,
 }
@@ -14,24 +15,48 @@ impl ClubSponsorship {
         }
     }
 
-    /// How many simultaneous sponsorship deals a club of this stature
-    /// sustains — kit supplier + shirt sponsor + secondary partners at the
-    /// top, a single local backer at the bottom. Commercial revenue is a
-    /// pillar of real club finance (roughly 40% of an elite club's income),
-    /// so the portfolio target is what keeps that pillar standing: the
-    /// monthly finance result signs new deals until the book reaches this
-    /// size and lets it shrink back by non-renewal when reputation falls.
-    pub fn target_portfolio_size(reputation: ReputationLevel) -> usize {
+    /// The commercial slots a club of this stature sells, in signing
+    /// order — shirt, kit supplier and stadium naming rights at the top,
+    /// a single shirt backer at the bottom.
+    pub fn portfolio_slots(reputation: ReputationLevel) -> &'static [SponsorshipKind] {
         match reputation {
-            ReputationLevel::Elite => 3,
-            ReputationLevel::Continental => 3,
-            ReputationLevel::National => 2,
-            ReputationLevel::Regional => 2,
-            ReputationLevel::Local => 1,
-            ReputationLevel::Amateur => 1,
+            ReputationLevel::Elite | ReputationLevel::Continental => &[
+                SponsorshipKind::Shirt,
+                SponsorshipKind::KitSupplier,
+                SponsorshipKind::StadiumNaming,
+            ],
+            ReputationLevel::National | ReputationLevel::Regional => {
+                &[SponsorshipKind::Shirt, SponsorshipKind::KitSupplier]
+            }
+            ReputationLevel::Local | ReputationLevel::Amateur => &[SponsorshipKind::Shirt],
         }
     }
 
+    /// How many simultaneous sponsorship deals a club of this stature
+    /// sustains — one per slot in [`Self::portfolio_slots`]. Commercial
+    /// revenue is a pillar of real club finance (roughly 40% of an elite
+    /// club's income), so the portfolio target is what keeps that pillar
+    /// standing: the monthly finance result signs new deals until the book
+    /// reaches this size and lets it shrink back by non-renewal when
+    /// reputation falls.
+    pub fn target_portfolio_size(reputation: ReputationLevel) -> usize {
+        Self::portfolio_slots(reputation).len()
+    }
+
+    /// First slot for this tier not already covered by a live contract.
+    /// `None` once the book holds every slot the club can sell.
+    pub fn open_slot(&self, reputation: ReputationLevel) -> Option<SponsorshipKind> {
+        Self::portfolio_slots(reputation)
+            .iter()
+            .copied()
+            .find(|kind| {
+                !self
+                    .sponsorship_contracts
+                    .iter()
+                    .any(|contract| contract.kind == *kind)
+            })
+    }
+
     fn drop_expired(&mut self, date: NaiveDate) -> u32 {
         let before = self.sponsorship_contracts.len();
         self.sponsorship_contracts
@@ -65,6 +90,41 @@ impl ClubSponsorship {
     }
 }
 
+/// What the sponsor buys. Drives the deal's share of the club's
+/// commercial value and how it is labelled on the finances page.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SponsorshipKind {
+    /// Front-of-shirt branding — the headline commercial asset.
+    Shirt,
+    /// Technical kit manufacturer.
+    KitSupplier,
+    /// Stadium naming rights.
+    StadiumNaming,
+}
+
+impl SponsorshipKind {
+    /// Multiplier on the tier's annual base value.
+    pub fn value_weight(self) -> f64 {
+        match self {
+            SponsorshipKind::Shirt => 1.2,
+            SponsorshipKind::KitSupplier => 1.0,
+            SponsorshipKind::StadiumNaming => 0.8,
+        }
+    }
+
+    pub fn as_i18n_key(self) -> &'static str {
+        match self {
+            SponsorshipKind::Shirt => "sponsorship_kind_shirt",
+            SponsorshipKind::KitSupplier => "sponsorship_kind_kit_supplier",
+            SponsorshipKind::StadiumNaming => "sponsorship_kind_stadium_naming",
+        }
+    }
+}
+
+/// Annual value from which a newly signed deal is announced as a
+/// headline commercial signing.
+pub const HEADLINE_SPONSORSHIP_ANNUAL: i32 = 20_000_000;
+
 /// Inputs that drive a freshly generated sponsorship contract: the club's
 /// reputation tier, its country sponsorship market, and a coarse view of
 /// recent on-pitch performance. Centralised so the renewal pass and the
@@ -130,10 +190,10 @@ impl SponsorRenewalContext {
     /// world is created — the monthly renewal pass keeps the book at the
     /// target size from then on.
     pub fn generate_initial_portfolio(&self, date: NaiveDate) -> Vec<ClubSponsorshipContract> {
-        let slots = ClubSponsorship::target_portfolio_size(self.reputation);
-        (0..slots)
-            .filter_map(|_| {
-                let mut contract = self.generate(date)?;
+        ClubSponsorship::portfolio_slots(self.reputation)
+            .iter()
+            .filter_map(|kind| {
+                let mut contract = self.generate(date, *kind)?;
                 let stagger_days = FloatUtils::random(0.0, 330.0) as i64;
                 contract.expiration += Duration::days(stagger_days);
                 Some(contract)
@@ -141,11 +201,17 @@ impl SponsorRenewalContext {
             .collect()
     }
 
-    pub fn generate(&self, date: NaiveDate) -> Option<ClubSponsorshipContract> {
+    pub fn generate(
+        &self,
+        date: NaiveDate,
+        kind: SponsorshipKind,
+    ) -> Option<ClubSponsorshipContract> {
         let market = self.market_strength.max(0.05) as f64;
         let perf = self.performance.multiplier() as f64;
         let randomness = FloatUtils::random(0.85, 1.15) as f64;
-        let annual = (Self::annual_base(self.reputation) * market * perf * randomness).max(0.0);
+        let annual =
+            (Self::annual_base(self.reputation) * kind.value_weight() * market * perf * randomness)
+                .max(0.0);
         if annual < 1.0 {
             return None;
         }
@@ -168,6 +234,7 @@ impl SponsorRenewalContext {
         let name = generate_sponsor_name(self.reputation);
         Some(ClubSponsorshipContract::new_with_start(
             name,
+            kind,
             annual as i32,
             date,
             expiration,
@@ -201,18 +268,25 @@ fn generate_sponsor_name(reputation: ReputationLevel) -> String {
     pool.get(idx).copied().unwrap_or("Sponsor").to_string()
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubSponsorshipContract {
     pub sponsor_name: String,
+    pub kind: SponsorshipKind,
     pub wage: i32,
     pub started: Option<NaiveDate>,
     expiration: NaiveDate,
 }
 
 impl ClubSponsorshipContract {
-    pub fn new(sponsor_name: String, wage: i32, expiration: NaiveDate) -> Self {
+    pub fn new(
+        sponsor_name: String,
+        kind: SponsorshipKind,
+        wage: i32,
+        expiration: NaiveDate,
+    ) -> Self {
         ClubSponsorshipContract {
             sponsor_name,
+            kind,
             wage,
             started: None,
             expiration,
@@ -221,12 +295,14 @@ impl ClubSponsorshipContract {
 
     pub fn new_with_start(
         sponsor_name: String,
+        kind: SponsorshipKind,
         wage: i32,
         started: NaiveDate,
         expiration: NaiveDate,
     ) -> Self {
         ClubSponsorshipContract {
             sponsor_name,
+            kind,
             wage,
             started: Some(started),
             expiration,
@@ -240,6 +316,10 @@ impl ClubSponsorshipContract {
     pub fn is_expired(&self, date: NaiveDate) -> bool {
         date >= self.expiration
     }
+
+    pub fn is_headline_deal(&self) -> bool {
+        self.wage >= HEADLINE_SPONSORSHIP_ANNUAL
+    }
 }
 
 #[cfg(test)]
@@ -291,6 +371,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn initial_portfolio_sells_each_slot_once() {
+        let ctx =
+            SponsorRenewalContext::new(ReputationLevel::Elite, 1.0, SponsorPerformance::MidTable);
+        let book = ctx.generate_initial_portfolio(d(2026, 7, 1));
+        let kinds: Vec<_> = book.iter().map(|c| c.kind).collect();
+        assert_eq!(
+            kinds,
+            ClubSponsorship::portfolio_slots(ReputationLevel::Elite)
+        );
+    }
+
+    #[test]
+    fn open_slot_fills_the_gap_left_by_an_expired_deal() {
+        let ctx = SponsorRenewalContext::new(
+            ReputationLevel::National,
+            1.0,
+            SponsorPerformance::MidTable,
+        );
+        let mut book = ClubSponsorship::new(Vec::new());
+        assert_eq!(
+            book.open_slot(ReputationLevel::National),
+            Some(SponsorshipKind::Shirt)
+        );
+        book.sponsorship_contracts
+            .push(ClubSponsorshipContract::new(
+                "Kit".to_string(),
+                SponsorshipKind::KitSupplier,
+                1_000_000,
+                d(2028, 1, 1),
+            ));
+        assert_eq!(
+            book.open_slot(ReputationLevel::National),
+            Some(SponsorshipKind::Shirt)
+        );
+        book.sponsorship_contracts
+            .push(ctx.generate(d(2026, 7, 1), SponsorshipKind::Shirt).unwrap());
+        assert_eq!(book.open_slot(ReputationLevel::National), None);
+        // A bigger club has a naming-rights slot still to sell.
+        assert_eq!(
+            book.open_slot(ReputationLevel::Elite),
+            Some(SponsorshipKind::StadiumNaming)
+        );
+    }
+
     #[test]
     fn deals_to_sign_replaces_expired_and_ramps_toward_target() {
         // All expired deals are replaced at once (book at target).
@@ -32,7 +32,8 @@ pub use status::*;
 pub use finance::{
     ClubFinanceContext, ClubFinanceResult, ClubFinances, ClubFinancialBalance,
     ClubFinancialBalanceHistory, ClubSponsorship, ClubSponsorshipContract, DistressLevel,
-    SponsorPerformance, SponsorRenewalContext, TransferObligation, classify_distress,
+    SponsorPerformance, SponsorRenewalContext, SponsorshipKind, TransferObligation,
+    classify_distress,
 };
 
 // Relations exports
@@ -266,17 +267,18 @@ pub use staff::contract as staff_contract_mod;
 // Team exports (except conflicting modules)
 pub use team::{
     Achievement, AchievementType, CoachingPhilosophy, FacilityQuality, FormationChange,
-    GoalkeepingGains, IndividualTrainingPlan, ManagerTalkResult, ManagerTalkType, MatchHistory,
-    MatchHistoryItem, MatchOutcome, MatchResultInfo, MatchTacticType, MentalGains,
-    PeriodizationPhase, PhysicalGains, PlayerBehaviourResult, PlayerRelationshipChangeResult,
-    RecommendationCategory, RecommendationPriority, ReputationLevel, ReputationRequirements,
-    ReputationTrend, RotationPreference, SkillType, SpecialInstruction, SquadAnalysis,
-    TACTICS_POSITIONS, TacticSelectionReason, TacticalDecisionEngine, TacticalDecisionResult,
-    TacticalFocus, TacticalRecommendation, TacticalStyle, Tactics, TacticsSelector, Team,
-    TeamBehaviour, TeamBehaviourResult, TeamBuilder, TeamCollection, TeamContext, TeamReputation,
-    TeamResult, TeamTraining, TeamTrainingResult, TeamType, TechnicalGains, TrainingEffects,
-    TrainingFacilities, TrainingFocus, TrainingIntensity, TrainingIntensityPreference,
-    TrainingSchedule, TrainingSession, TrainingType, TransferItem, Transfers, WeeklyTrainingPlan,
+    GoalkeepingGains, IndividualTrainingPlan, LineupError, ManagerTalkResult, ManagerTalkType,
+    MatchHistory, MatchHistoryItem, MatchOutcome, MatchResultInfo, MatchTacticType, MatchdayLineup,
+    MatchdayPlan, MentalGains, PeriodizationPhase, PhysicalGains, PlayerBehaviourResult,
+    PlayerRelationshipChangeResult, RecommendationCategory, RecommendationPriority,
+    ReputationLevel, ReputationRequirements, ReputationTrend, RotationPreference, SkillType,
+    SpecialInstruction, SquadAnalysis, SquadCohesion, TACTICS_POSITIONS, TacticSelectionReason,
+    TacticalDecisionEngine, TacticalDecisionResult, TacticalFocus, TacticalRecommendation,
+    TacticalStyle, Tactics, TacticsSelector, Team, TeamBehaviour, TeamBehaviourResult, TeamBuilder,
+    TeamCollection, TeamContext, TeamReputation, TeamResult, TeamTraining, TeamTrainingResult,
+    TeamType, TechnicalGains, TrainingEffects, TrainingFacilities, TrainingFocus,
+    TrainingIntensity, TrainingIntensityPreference, TrainingSchedule, TrainingSession,
+    TrainingType, TransferItem, Transfers, WeeklyTrainingPlan,
 };
 // Also export context module for those who want to import from it
 pub use team::behaviour;
@@ -2,6 +2,7 @@ use crate::Relations;
 use crate::shared::FullName;
 use crate::utils::DateUtils;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 pub trait Person {
     fn id(&self) -> u32;
@@ -52,7 +53,7 @@ pub struct PersonAttributes {
     pub dirtiness: f32,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PersonBehaviour {
     pub state: PersonBehaviourState,
 }
@@ -83,7 +84,7 @@ impl PersonBehaviour {
     }
 }
 
-#[derive(Copy, Clone, Debug, PartialEq, Default)]
+#[derive(Copy, Clone, Debug, PartialEq, Default, Serialize, Deserialize)]
 pub enum PersonBehaviourState {
     Poor,
     #[default]
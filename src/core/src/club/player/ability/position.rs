@@ -89,6 +89,36 @@ impl PlayerPositionType {
         }
     }
 
+    /// Inverse of `get_short_name`, ignoring case.
+    pub fn from_short_name(name: &str) -> Option<Self> {
+        let position = match name.to_ascii_uppercase().as_str() {
+            "GK" => PlayerPositionType::Goalkeeper,
+            "SW" => PlayerPositionType::Sweeper,
+            "DL" => PlayerPositionType::DefenderLeft,
+            "DCL" => PlayerPositionType::DefenderCenterLeft,
+            "DC" => PlayerPositionType::DefenderCenter,
+            "DCR" => PlayerPositionType::DefenderCenterRight,
+            "DR" => PlayerPositionType::DefenderRight,
+            "DM" => PlayerPositionType::DefensiveMidfielder,
+            "ML" => PlayerPositionType::MidfielderLeft,
+            "MCL" => PlayerPositionType::MidfielderCenterLeft,
+            "MC" => PlayerPositionType::MidfielderCenter,
+            "MCR" => PlayerPositionType::MidfielderCenterRight,
+            "MR" => PlayerPositionType::MidfielderRight,
+            "AML" => PlayerPositionType::AttackingMidfielderLeft,
+            "AMC" => PlayerPositionType::AttackingMidfielderCenter,
+            "AMR" => PlayerPositionType::AttackingMidfielderRight,
+            "WL" => PlayerPositionType::WingbackLeft,
+            "WR" => PlayerPositionType::WingbackRight,
+            "FL" => PlayerPositionType::ForwardLeft,
+            "FC" => PlayerPositionType::ForwardCenter,
+            "FR" => PlayerPositionType::ForwardRight,
+            "ST" => PlayerPositionType::Striker,
+            _ => return None,
+        };
+        Some(position)
+    }
+
     #[inline]
     pub fn is_goalkeeper(&self) -> bool {
         self.position_group() == PlayerFieldPositionGroup::Goalkeeper
@@ -184,7 +214,7 @@ impl PlayerPositionType {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerPositions {
     pub positions: Vec<PlayerPosition>,
 }
@@ -391,7 +421,7 @@ impl PlayerPositions {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerPosition {
     pub position: PlayerPositionType,
     pub level: u8,
@@ -401,6 +431,19 @@ pub struct PlayerPosition {
 mod tests {
     use super::*;
 
+    #[test]
+    fn short_name_round_trips() {
+        assert_eq!(
+            Some(PlayerPositionType::DefenderCenterLeft),
+            PlayerPositionType::from_short_name("dcl")
+        );
+        assert_eq!(
+            Some(PlayerPositionType::Striker),
+            PlayerPositionType::from_short_name(PlayerPositionType::Striker.get_short_name())
+        );
+        assert_eq!(None, PlayerPositionType::from_short_name("XX"));
+    }
+
     #[test]
     fn short_position_names_is_correct() {
         assert_eq!("GK", PlayerPositionType::Goalkeeper.get_short_name());
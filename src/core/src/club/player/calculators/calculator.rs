@@ -600,7 +600,7 @@ mod tests {
         let names = PeopleNameGeneratorData {
             first_names: vec!["T".to_string()],
             last_names: vec!["Tester".to_string()],
-            nicknames: Vec::new(),
+            ..Default::default()
         };
         let mut player = PlayerGenerator::generate(1, now, position, 100, &names);
 
@@ -1,6 +1,7 @@
 use crate::club::team::squad::SquadAssetClass;
 use crate::{ContractType, Person, Player, PlayerSquadStatus};
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 /// Why a player's contract ended and they entered the free-agent pool.
 ///
@@ -12,7 +13,7 @@ use chrono::NaiveDate;
 /// *displayed* release reason. The sweep falls back to [`Self::ContractExpired`]
 /// (no marker) or [`Self::MutualTermination`] (legacy `Frt` without an
 /// explicit reason) when no reason was recorded.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FreeAgentReleaseReason {
     /// Natural contract expiry — nobody tore anything up, the deal simply
     /// ran out. No exit path sets this explicitly; the sweep infers it
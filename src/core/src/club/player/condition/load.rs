@@ -28,6 +28,7 @@
 //!   sessions burn it back down.
 
 use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
 
 const DECAY_7: f32 = 6.0 / 7.0;
 const DECAY_30: f32 = 29.0 / 30.0;
@@ -68,7 +69,7 @@ pub const WORKLOAD_SPIKE_RATIO: f32 = 1.4;
 /// raw weekly minutes — used by selection / UI labels.
 pub const RECOVERY_DEBT_HEAVY: f32 = 350.0;
 
-#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
 pub struct PlayerLoad {
     /// Recency-weighted competitive minutes over the trailing ~7 days.
     pub minutes_last_7: f32,
@@ -3,8 +3,9 @@ use crate::TeamType;
 use chrono::Duration;
 use chrono::NaiveDateTime;
 pub use chrono::prelude::{DateTime, Datelike, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ContractType {
     PartTime,
     FullTime,
@@ -14,7 +15,7 @@ pub enum ContractType {
     Loan,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum PlayerSquadStatus {
     Invalid,
     NotYetSet,
@@ -145,14 +146,14 @@ impl PlayerSquadStatus {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PlayerTransferStatus {
     TransferListed,
     LoadListed,
     TransferAndLoadListed,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerClubContract {
     pub shirt_number: Option<u8>,
 
@@ -693,7 +694,7 @@ pub fn is_inert_clause(clause_type: &ContractClauseType) -> bool {
 }
 
 // Bonuses
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ContractBonusType {
     AppearanceFee,
     GoalFee,
@@ -710,7 +711,7 @@ pub enum ContractBonusType {
     LoyaltyBonus,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractBonus {
     pub value: i32,
     pub bonus_type: ContractBonusType,
@@ -723,7 +724,7 @@ impl ContractBonus {
 }
 
 // Clauses
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ContractClauseType {
     MinimumFeeRelease,
     RelegationFeeRelease,
@@ -747,7 +748,7 @@ pub enum ContractClauseType {
     OptionalContractExtensionByClub,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractClause {
     /// Single-number payload — release fee, percentage, or extension years
     /// depending on `bonus_type`. Kept for backward compatibility and as
@@ -3,11 +3,12 @@ use crate::club::player::player::Player;
 use crate::club::{PlayerCollectionResult, PlayerResult};
 use crate::context::GlobalContext;
 use crate::utils::Logging;
+use serde::{Deserialize, Serialize};
 use std::ops::Index;
 use std::slice::Iter;
 use std::slice::IterMut;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerCollection {
     pub players: Vec<Player>,
 }
@@ -42,11 +42,12 @@ use crate::{
 use chrono::Duration;
 use chrono::NaiveDate;
 use std::fmt::{Display, Formatter, Result};
+use serde::{Deserialize, Serialize};
 
 /// A sell-on promise owed to a previous seller on the next permanent sale.
 /// Stacks: a player can accumulate multiple obligations from different past
 /// clubs. Capped at 3 to prevent unbounded growth over long careers.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SellOnObligation {
     pub beneficiary_club_id: u32,
     pub percentage: f32,
@@ -56,7 +57,7 @@ pub struct SellOnObligation {
 /// tick at the team level and read by the desire / adaptation pipeline.
 /// Cleared when the player transfers — a new club rebuilds it on its
 /// next week's pre-tick.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SquadSocialView {
     /// Number of senior squad teammates who share the player's primary
     /// nationality (country_id match). Capped at u8::MAX.
@@ -75,7 +76,7 @@ impl SquadSocialView {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Player {
     //person data
     pub id: u32,
@@ -290,7 +291,7 @@ pub struct Player {
 /// the renderer can attribute the request to the right narrative axis,
 /// and `process_transfer_desire` keeps `Req` alive while at least one
 /// reason is unresolved.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TransferRequestReason {
     /// Behaviour band crossed `is_poor` — character / discipline issues.
     PoorBehaviour,
@@ -334,7 +335,7 @@ pub enum TransferRequestReason {
 /// verifier needs to decide whether the promise was kept — most use
 /// `baseline_apps` plus a per-kind threshold, but role / positional
 /// promises read additional state at verification time.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ManagerPromiseKind {
     /// "You'll play more" — kept if appearances since the promise meet
     /// the per-kind cadence (≥1 per ~10 days).
@@ -369,7 +370,7 @@ pub enum ManagerPromiseKind {
 /// during the window, and `credibility_at_creation` to scale how badly a
 /// broken promise hurts (cheap, off-the-cuff promises shouldn't tank
 /// morale the way a formal commitment does).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ManagerPromise {
     pub kind: ManagerPromiseKind,
     pub made_on: NaiveDate,
@@ -598,6 +599,20 @@ impl Player {
         self.generated
     }
 
+    /// True when the player's career began at the club he is rostered at
+    /// today — an academy product. Survives loans out (the origin row
+    /// never changes); falls back to "never transferred" for generated
+    /// kids with no recorded history yet.
+    pub fn is_club_trained(&self) -> bool {
+        match (
+            self.statistics_history.origin_team_slug(),
+            self.statistics_history.active_team_slug(),
+        ) {
+            (Some(origin), Some(active)) => origin == active,
+            _ => self.last_transfer_date.is_none(),
+        }
+    }
+
     /// Why this player's contract was cleared by a club-driven exit, if an
     /// exit path recorded one. `None` for a player still under contract or
     /// one whose deal lapsed naturally. The free-agent sweep reads this to
@@ -1462,7 +1477,7 @@ impl Person for Player {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PlayerPreferredFoot {
     Left,
     Right,
@@ -1470,7 +1485,7 @@ pub enum PlayerPreferredFoot {
 }
 
 /// Per-foot ownership on a 0-100 scale (100 = fully natural foot).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerFoots {
     pub left: u8,
     pub right: u8,
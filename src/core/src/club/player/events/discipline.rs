@@ -20,11 +20,27 @@ use crate::club::player::player::Player;
 /// next ban without losing the most recent card.
 pub const YELLOW_CARD_BAN_THRESHOLD: u8 = 5;
 
+/// Matches banned for a straight red (violent conduct / serious foul
+/// play). A red shown for a second yellow only carries the standard
+/// one-match ban.
+pub const DIRECT_RED_CARD_BAN_MATCHES: u8 = 3;
+
+/// Ban length for a sending-off. The engine records a second yellow as
+/// two yellows plus a red, so a red alongside fewer than two yellows
+/// was shown straight.
+pub fn red_card_ban_matches(yellow_cards: u8) -> u8 {
+    if yellow_cards >= 2 {
+        1
+    } else {
+        DIRECT_RED_CARD_BAN_MATCHES
+    }
+}
+
 impl Player {
     /// React to a finished match's disciplinary stats. `yellow_cards`
-    /// is the number of yellows received this match (1 normally;
-    /// already 0 if the second yellow was promoted to a red by the
-    /// engine), `red_cards` is 1 if the player was sent off.
+    /// is the number of yellows received this match (2 when the second
+    /// yellow was promoted to a red by the engine), `red_cards` is 1 if
+    /// the player was sent off.
     ///
     /// Returns the number of additional suspension matches added — 0
     /// if the cards didn't escalate to a ban this match.
@@ -35,15 +51,17 @@ impl Player {
         season_yellow_threshold: u8,
     ) -> u8 {
         let mut added: u8 = 0;
-        // Direct red or second-yellow → 1 match ban. Engine promotes a
-        // second yellow to a red, so a player with red_cards>0 cannot
-        // also have yellow_cards>0 in the same match — we treat the
-        // red as the only contributor here.
+        // Second yellow → 1 match ban, straight red → multi-match ban.
+        // The yellows that led to the red don't also count toward the
+        // season accumulation — the red is the only contributor here.
         if red_cards > 0 {
-            self.player_attributes.suspension_matches =
-                self.player_attributes.suspension_matches.saturating_add(1);
+            let ban = red_card_ban_matches(yellow_cards);
+            self.player_attributes.suspension_matches = self
+                .player_attributes
+                .suspension_matches
+                .saturating_add(ban);
             self.player_attributes.is_banned = true;
-            added = added.saturating_add(1);
+            added = added.saturating_add(ban);
             return added;
         }
         if yellow_cards == 0 {
@@ -119,12 +137,33 @@ mod tests {
     }
 
     #[test]
-    fn red_card_sets_one_match_suspension() {
+    fn straight_red_sets_multi_match_suspension() {
         let mut p = make_player();
         let added = p.on_match_disciplinary_result(0, 1, YELLOW_CARD_BAN_THRESHOLD);
+        assert_eq!(added, DIRECT_RED_CARD_BAN_MATCHES);
+        assert!(p.player_attributes.is_banned);
+        assert_eq!(
+            p.player_attributes.suspension_matches,
+            DIRECT_RED_CARD_BAN_MATCHES
+        );
+    }
+
+    #[test]
+    fn second_yellow_red_sets_one_match_suspension() {
+        let mut p = make_player();
+        let added = p.on_match_disciplinary_result(2, 1, YELLOW_CARD_BAN_THRESHOLD);
         assert_eq!(added, 1);
         assert!(p.player_attributes.is_banned);
         assert_eq!(p.player_attributes.suspension_matches, 1);
+        // The two yellows behind the red don't feed the season tally.
+        assert_eq!(p.player_attributes.yellow_card_running, 0);
+    }
+
+    #[test]
+    fn yellow_then_straight_red_is_still_a_straight_red() {
+        let mut p = make_player();
+        let added = p.on_match_disciplinary_result(1, 1, YELLOW_CARD_BAN_THRESHOLD);
+        assert_eq!(added, DIRECT_RED_CARD_BAN_MATCHES);
     }
 
     #[test]
@@ -158,7 +197,7 @@ mod tests {
     #[test]
     fn serving_match_decrements_and_clears_ban() {
         let mut p = make_player();
-        p.on_match_disciplinary_result(0, 1, YELLOW_CARD_BAN_THRESHOLD);
+        p.on_match_disciplinary_result(2, 1, YELLOW_CARD_BAN_THRESHOLD);
         assert_eq!(p.player_attributes.suspension_matches, 1);
         assert!(p.player_attributes.is_banned);
         p.serve_suspension_match();
@@ -178,10 +217,10 @@ mod tests {
     #[test]
     fn red_card_during_existing_ban_extends_it() {
         let mut p = make_player();
-        p.on_match_disciplinary_result(0, 1, YELLOW_CARD_BAN_THRESHOLD);
+        p.on_match_disciplinary_result(2, 1, YELLOW_CARD_BAN_THRESHOLD);
         assert_eq!(p.player_attributes.suspension_matches, 1);
         // Player got banned and somehow got another red — extend.
-        p.on_match_disciplinary_result(0, 1, YELLOW_CARD_BAN_THRESHOLD);
+        p.on_match_disciplinary_result(2, 1, YELLOW_CARD_BAN_THRESHOLD);
         assert_eq!(p.player_attributes.suspension_matches, 2);
     }
 
@@ -192,7 +231,7 @@ mod tests {
         for _ in 0..4 {
             p.on_match_disciplinary_result(1, 0, YELLOW_CARD_BAN_THRESHOLD);
         }
-        p.on_match_disciplinary_result(0, 1, YELLOW_CARD_BAN_THRESHOLD);
+        p.on_match_disciplinary_result(2, 1, YELLOW_CARD_BAN_THRESHOLD);
         assert_eq!(p.player_attributes.suspension_matches, 1);
         p.reset_season_disciplinary_state();
         assert_eq!(p.player_attributes.yellow_card_running, 0);
@@ -1035,27 +1035,20 @@ impl PlayerGenerator {
 
         // Generate name from country data
         let full_name = {
-            let first = if people_names.first_names.is_empty() {
-                String::from("Player")
-            } else {
-                people_names.first_names
-                    [IntegerUtils::random(0, people_names.first_names.len() as i32 - 1) as usize]
-                    .clone()
-            };
-            let last = if people_names.last_names.is_empty() {
-                format!("{}", IntegerUtils::random(1, 99999))
-            } else {
-                people_names.last_names
-                    [IntegerUtils::random(0, people_names.last_names.len() as i32 - 1) as usize]
-                    .clone()
-            };
+            let first = people_names
+                .random_first_name()
+                .map_or_else(|| String::from("Player"), str::to_string);
+            let last = people_names.random_last_name().map_or_else(
+                || format!("{}", IntegerUtils::random(1, 99999)),
+                str::to_string,
+            );
 
-            if !people_names.nicknames.is_empty() && IntegerUtils::random(0, 9) == 0 {
-                let nick = &people_names.nicknames
-                    [IntegerUtils::random(0, people_names.nicknames.len() as i32 - 1) as usize];
-                FullName::with_nickname(first, last, nick.clone())
-            } else {
-                FullName::new(first, last)
+            match people_names
+                .random_nickname()
+                .filter(|_| IntegerUtils::random(0, 9) == 0)
+            {
+                Some(nick) => FullName::with_nickname(first, last, nick.to_string()),
+                None => FullName::new(first, last),
             }
         };
 
@@ -1621,7 +1614,7 @@ mod academy_realism_tests {
         PeopleNameGeneratorData {
             first_names: vec!["A".into()],
             last_names: vec!["B".into()],
-            nicknames: vec![],
+            ..Default::default()
         }
     }
 
@@ -1,7 +1,8 @@
 use crate::PlayerFieldPositionGroup;
+use serde::{Deserialize, Serialize};
 
 /// What flavour of career-desire mood the player is signalling.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CareerDesireKind {
     ReturnHomeAfterPoorAdaptation,
     EuropeanCompetitionAmbition,
@@ -64,7 +65,7 @@ impl CareerDesireKind {
 /// Concrete signals the desire detector latched onto. Closed enum so the
 /// renderer copy stays bounded; emit sites push the atoms that justified
 /// the mood and the renderer surfaces the most informative one.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum CareerDesireEvidence {
     /// Player is at a club whose country sits on a different continent
     /// from the player's nationality.
@@ -184,7 +185,7 @@ impl CareerDesireEvidence {
 /// career-desire mood (return home / European / Libertadores). Filled
 /// in at emit time so the renderer can compose a contextual headline +
 /// reason instead of guessing from the event-type enum alone.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CareerDesireEventContext {
     pub kind: CareerDesireKind,
     /// Days at current club at emit time. 0 if unknown.
@@ -1,9 +1,10 @@
 use super::CareerDesireEvidence;
+use serde::{Deserialize, Serialize};
 
 /// Specific flavour of life-simulation request / mood. Kept closed so
 /// renderers can localise each category and tests can assert which
 /// bucket a particular detector emits.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LifeSimulationDesireKind {
     /// Family hasn't settled at the new country (schools, social
     /// network, isolation). Player asks for support / time off / move.
@@ -88,7 +89,7 @@ impl LifeSimulationDesireKind {
 /// Severity tier specific to life-simulation moods. Renderer can
 /// translate to Minor/Moderate/Strong/Acute copy independent of the
 /// generic HappinessEventSeverity tier.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LifeSimulationSeverity {
     Mild,
     Moderate,
@@ -110,7 +111,7 @@ impl LifeSimulationSeverity {
 /// What concretely triggered the desire/mood. Closed enum so emit
 /// sites pick the football-realistic cause. Renderer uses this for the
 /// "why now" framing.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LifeSimulationTrigger {
     FamilyAbroadStress,
     SchoolingProblem,
@@ -156,7 +157,7 @@ impl LifeSimulationTrigger {
 /// Structured payload for any [`LifeSimulationDesireKind`] event. The
 /// renderer reads `kind` first, then severity / trigger / evidence to
 /// fill in the headline and reason copy.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LifeSimulationDesireContext {
     pub kind: LifeSimulationDesireKind,
     pub severity: LifeSimulationSeverity,
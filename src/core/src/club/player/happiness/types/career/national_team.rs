@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum NationalTeamEventKind {
     FirstCallup,
     Recall,
@@ -35,7 +37,7 @@ impl NationalTeamEventKind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NationalTeamEventContext {
     pub kind: NationalTeamEventKind,
     pub country_id: Option<u32>,
@@ -1,8 +1,10 @@
+use serde::{Deserialize, Serialize};
+
 /// Identifies which trophy / public recognition the event represents.
 /// Maps 1:1 to the relevant `HappinessEventType` award variants and lets
 /// the renderer pick recognition-specific copy without re-deriving the
 /// kind from the event-type enum at render time.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RecognitionEventKind {
     PlayerOfTheWeek,
     YoungPlayerOfTheWeek,
@@ -50,7 +52,7 @@ impl RecognitionEventKind {
 /// margin behind the award, and who the closest contender was.
 /// All quantitative fields are `Option` so emit sites can attach what's
 /// available without forcing missing-data placeholders.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RecognitionEventContext {
     pub kind: RecognitionEventKind,
     pub league_id: Option<u32>,
@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 /// Football-realistic reason a training session swung positively or
 /// negatively. Closed enum so renderer copy stays bounded.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TrainingEventReason {
     SharpAfterBeingLeftOut,
     RespondedToCriticism,
@@ -43,7 +45,7 @@ impl TrainingEventReason {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TrainingEventEvidence {
     HighSessionPerformance,
     LowSessionPerformance,
@@ -116,7 +118,7 @@ impl TrainingEventEvidence {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrainingEventContext {
     pub reason: TrainingEventReason,
     pub session_performance: f32,
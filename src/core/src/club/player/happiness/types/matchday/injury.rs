@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InjuryRecoveryStage {
     ReturnedToFullTraining,
     FirstMinutesAfterInjury,
@@ -21,7 +23,7 @@ impl InjuryRecoveryStage {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum InjuryRecoveryEvidence {
     LongTermLayoff,
     ShortTermLayoff,
@@ -52,7 +54,7 @@ impl InjuryRecoveryEvidence {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InjuryRecoveryEventContext {
     pub stage: InjuryRecoveryStage,
     pub recovery_days_total: u16,
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MatchPerformanceKind {
     AnsweredCriticsWithPerformance,
     CostlyErrorUnderPressure,
@@ -37,7 +39,7 @@ impl MatchPerformanceKind {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MatchPerformanceEvidence {
     HighRating,
     LowRating,
@@ -82,7 +84,7 @@ impl MatchPerformanceEvidence {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchPerformanceEventContext {
     pub kind: MatchPerformanceKind,
     pub rating: Option<f32>,
@@ -1,7 +1,8 @@
 use super::SelectionRole;
 use crate::club::player::contract::PlayerSquadStatus;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RoleStatusKind {
     RoleClarifiedByManager,
     RoleUnclear,
@@ -36,7 +37,7 @@ impl RoleStatusKind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoleStatusEventContext {
     pub kind: RoleStatusKind,
     pub previous_status: Option<PlayerSquadStatus>,
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SeasonOutcomeKind {
     Relegated,
     RelegationFear,
@@ -20,7 +22,7 @@ impl SeasonOutcomeKind {
 /// remaining when the worry crystallised, so the renderer can describe
 /// "10th in the table, 4 points clear of the drop with 6 to play"
 /// instead of a generic "Relegation fear".
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SeasonOutcomeContext {
     pub kind: SeasonOutcomeKind,
     pub league_id: Option<u32>,
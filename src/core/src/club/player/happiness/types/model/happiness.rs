@@ -2,8 +2,9 @@ use super::super::HappinessEventContext;
 use super::HappinessEventType;
 use crate::club::player::behaviour_config::HappinessConfig;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerHappiness {
     pub morale: f32,
     pub factors: HappinessFactors,
@@ -96,7 +97,7 @@ pub struct PlayerHappiness {
     pub unhappy_streak: u8,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct HappinessFactors {
     pub playing_time: f32,
     pub salary_satisfaction: f32,
@@ -151,7 +152,7 @@ pub struct MoraleBreakdown {
     pub morale: f32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HappinessEvent {
     pub event_type: HappinessEventType,
     pub magnitude: f32,
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LeadershipEventKind {
     CaptaincyAwarded,
     CaptaincyRemoved,
@@ -39,7 +41,7 @@ impl LeadershipEventKind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeadershipEventContext {
     pub kind: LeadershipEventKind,
     pub partner_player_id: Option<u32>,
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MediaFanEventKind {
     InterviewCalmsSpeculation,
     InterviewFuelsSpeculation,
@@ -29,7 +31,7 @@ impl MediaFanEventKind {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MediaFanSource {
     LocalPress,
     NationalPress,
@@ -54,7 +56,7 @@ impl MediaFanSource {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MediaFanEventContext {
     pub kind: MediaFanEventKind,
     pub source: MediaFanSource,
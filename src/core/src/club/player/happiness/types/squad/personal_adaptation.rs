@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PersonalAdaptationKind {
     HomesicknessConcern,
     FamilySettled,
@@ -49,7 +51,7 @@ impl PersonalAdaptationKind {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PersonalAdaptationEventContext {
     pub kind: PersonalAdaptationKind,
     pub days_at_club: u32,
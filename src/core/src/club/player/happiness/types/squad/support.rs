@@ -1,6 +1,8 @@
+use serde::{Deserialize, Serialize};
+
 /// Where the support / approval came from. Drives the renderer's
 /// "who reacted" line and the headline variant.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SupportSource {
     Manager,
     /// Reserved for future use — captain / senior pro speech moments.
@@ -26,7 +28,7 @@ impl SupportSource {
 
 /// Where the moment played out. Drives setting-aware copy ("private
 /// chat", "in front of the home crowd", "in the dressing room").
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SupportSetting {
     PrivateTalk,
     TrainingGround,
@@ -54,7 +56,7 @@ impl SupportSetting {
 /// Why the reaction happened. Closed enum — adding a new trigger means
 /// adding renderer copy in every locale, so we want the surface to stay
 /// finite.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SupportTrigger {
     HighRating,
     PlayerOfMatch,
@@ -100,7 +102,7 @@ impl SupportTrigger {
 /// Render-safe mirror of `team_talks::MatchPhase` — kept here so the
 /// support context can carry the phase without dragging the team-talks
 /// module into the events / renderer crates.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SupportMatchPhase {
     PreMatch,
     HalfTime,
@@ -122,7 +124,7 @@ impl SupportMatchPhase {
 /// Render-safe mirror of `TeamTalkTone` / `InteractionTone`. Kept as a
 /// closed enum so the renderer can pick deterministic copy without
 /// importing the team-talks types.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum SupportTone {
     Praise,
     Criticise,
@@ -157,7 +159,7 @@ impl SupportTone {
 /// Every field is optional except the three classification axes
 /// (`source`, `setting`, `trigger`), so partial information is never a
 /// blocker — the renderer only references the fields it has.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SupportEventContext {
     pub source: SupportSource,
     pub setting: SupportSetting,
@@ -1,9 +1,11 @@
+use serde::{Deserialize, Serialize};
+
 /// Concrete football reason behind a `ConflictWithTeammate` emit.
 /// Closed enum — the renderer maps each to a localised headline + cause
 /// sentence so the user reads "Clashed with Edwards over training
 /// standards" instead of the generic "Had a disagreement with a
 /// teammate" line.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TeammateConflictReason {
     /// Difference in how seriously each player took training.
     TrainingStandards,
@@ -70,7 +72,7 @@ impl TeammateConflictReason {
 /// Where the conflict played out. Drives the "in the dressing room",
 /// "on the training ground", "in front of the cameras" copy variant so
 /// the same reason reads differently depending on the setting.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ConflictLocation {
     TrainingGround,
     DressingRoom,
@@ -97,7 +99,7 @@ impl ConflictLocation {
 /// the generic "argued with a teammate" filler. Optional fields are
 /// captured by the emit site when known and skipped otherwise — the
 /// renderer hides whatever is missing rather than fabricating it.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeammateConflictContext {
     pub reason: TeammateConflictReason,
     pub location: ConflictLocation,
@@ -1,6 +1,7 @@
 use crate::club::player::contract::PlayerSquadStatus;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ContractEventKind {
     OfferReceived,
     TalksOpened,
@@ -46,7 +47,7 @@ impl ContractEventKind {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ContractEventEvidence {
     AgentPressure,
     HighLoyalty,
@@ -125,7 +126,7 @@ impl ContractEventEvidence {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContractEventContext {
     pub kind: ContractEventKind,
     pub interested_club_id: Option<u32>,
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LoanEventKind {
     LoanListingAccepted,
     LoanDevelopmentProgress,
@@ -38,7 +40,7 @@ impl LoanEventKind {
 /// Why a parent club / player is pushing to recall a loan. Closed enum so
 /// the renderer copy stays bounded. The first implementation focuses on
 /// `InsufficientMinutes`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LoanConcernReason {
     InsufficientMinutes,
     WrongRole,
@@ -64,7 +66,7 @@ impl LoanConcernReason {
 /// Why a young player's loan is judged to be failing development. Several
 /// of these can be present at once — the emit site pushes every signal it
 /// observed and the renderer surfaces the strongest.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LoanDevelopmentConcernReason {
     InsufficientMinutes,
     WrongRole,
@@ -93,7 +95,7 @@ impl LoanDevelopmentConcernReason {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoanEventContext {
     pub kind: LoanEventKind,
     pub parent_club_id: Option<u32>,
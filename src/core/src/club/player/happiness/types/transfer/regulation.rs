@@ -1,4 +1,6 @@
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RegulationSlotKind {
     HomegrownQuota,
     NonEuQuota,
@@ -21,7 +23,7 @@ impl RegulationSlotKind {
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RegulationOutcomeKind {
     Omitted,
     Registered,
@@ -43,7 +45,7 @@ impl RegulationOutcomeKind {
 /// odd one out — so the renderer can say "left out of the senior 25
 /// to free a non-EU slot for the new signing" rather than "Squad
 /// registration omitted".
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegulationEventContext {
     pub outcome: RegulationOutcomeKind,
     pub slot_kind: RegulationSlotKind,
@@ -170,6 +170,34 @@ impl InjuryRecoverySpeed {
 }
 
 impl InjuryType {
+    /// Stable i18n token for the diagnosis shown on the player page.
+    pub fn as_i18n_key(&self) -> &'static str {
+        match self {
+            InjuryType::Bruise => "injury_type_bruise",
+            InjuryType::MinorKnock => "injury_type_minor_knock",
+            InjuryType::Cramp => "injury_type_cramp",
+            InjuryType::DeadLeg => "injury_type_dead_leg",
+            InjuryType::MinorConcussion => "injury_type_minor_concussion",
+            InjuryType::HamstringStrain => "injury_type_hamstring_strain",
+            InjuryType::CalfStrain => "injury_type_calf_strain",
+            InjuryType::AnkleSprain => "injury_type_ankle_sprain",
+            InjuryType::GroinStrain => "injury_type_groin_strain",
+            InjuryType::HipFlexorStrain => "injury_type_hip_flexor_strain",
+            InjuryType::QuadStrain => "injury_type_quad_strain",
+            InjuryType::BackSpasm => "injury_type_back_spasm",
+            InjuryType::TornMeniscus => "injury_type_torn_meniscus",
+            InjuryType::ShoulderDislocation => "injury_type_shoulder_dislocation",
+            InjuryType::StressFracture => "injury_type_stress_fracture",
+            InjuryType::MCLSprain => "injury_type_mcl_sprain",
+            InjuryType::LateralLigament => "injury_type_lateral_ligament",
+            InjuryType::HerniatedDisc => "injury_type_herniated_disc",
+            InjuryType::ACLTear => "injury_type_acl_tear",
+            InjuryType::BrokenLeg => "injury_type_broken_leg",
+            InjuryType::AchillesRupture => "injury_type_achilles_rupture",
+            InjuryType::PCLTear => "injury_type_pcl_tear",
+        }
+    }
+
     /// Returns (min_days, max_days) for this injury type
     pub fn duration_range(&self) -> (u16, u16) {
         match self {
@@ -42,18 +42,28 @@ fn log_rejection(player: &mut Player, proposal: &PlayerContractProposal, now: Na
 /// can converge. The club reads `player.pending_contract_ask` when building
 /// the next proposal; without this the negotiation is blind on the club
 /// side. Reason drives which lever the next offer should pull — wage,
-/// length, role, or release clause.
+/// length, role, or release clause. `market_wage` is the player's
+/// valuation of himself, when the handler has computed one.
 fn record_counter_offer(
     player: &mut Player,
     proposal: &PlayerContractProposal,
     now: NaiveDate,
     min_years: u8,
     reason: RejectionReason,
+    market_wage: Option<u32>,
 ) {
     let current_salary = player.contract.as_ref().map(|c| c.salary).unwrap_or(0);
     let agent = PlayerAgent::for_player(player);
 
-    let salary_anchor = proposal.salary.max(current_salary);
+    // When money was the sticking point the agent argues from the
+    // player's own market valuation (ability, age, reputation, status),
+    // not from the rejected figure — otherwise a lowball offer anchors
+    // the counter and the next proposal fails the same floor again.
+    let market_anchor = match reason {
+        RejectionReason::LowSalary | RejectionReason::NoSweetener => market_wage.unwrap_or(0),
+        _ => 0,
+    };
+    let salary_anchor = proposal.salary.max(current_salary).max(market_anchor);
     // Greedy agents push the next ask up sharply — but only when money was
     // actually the sticking point. A rejection over length, role, or a
     // missing clause must not ratchet the wage ask above an offer the
@@ -175,6 +185,7 @@ impl ProcessContractHandler {
                 now,
                 min_acceptable_years,
                 RejectionReason::ShortContract,
+                None,
             );
             Self::emit_rejected_contract_offer(player, &proposal, RejectionReason::ShortContract);
             log_rejection(player, &proposal, now);
@@ -199,6 +210,7 @@ impl ProcessContractHandler {
                     now,
                     min_acceptable_years,
                     RejectionReason::StatusBelowExpectation,
+                    None,
                 );
                 Self::emit_rejected_contract_offer(
                     player,
@@ -300,6 +312,7 @@ impl ProcessContractHandler {
                         now,
                         min_acceptable_years,
                         RejectionReason::LowSalary,
+                        Some(valuation.expected_wage),
                     );
                     Self::emit_rejected_contract_offer(
                         player,
@@ -322,6 +335,7 @@ impl ProcessContractHandler {
                             now,
                             min_acceptable_years,
                             RejectionReason::LowSalary,
+                            Some(valuation.expected_wage),
                         );
                         Self::emit_rejected_contract_offer(
                             player,
@@ -341,6 +355,7 @@ impl ProcessContractHandler {
                             now,
                             min_acceptable_years,
                             RejectionReason::LowSalary,
+                            Some(valuation.expected_wage),
                         );
                         Self::emit_rejected_contract_offer(
                             player,
@@ -415,7 +430,14 @@ impl ProcessContractHandler {
                         } else {
                             RejectionReason::LowSalary
                         };
-                        record_counter_offer(player, &proposal, now, min_acceptable_years, reason);
+                        record_counter_offer(
+                            player,
+                            &proposal,
+                            now,
+                            min_acceptable_years,
+                            reason,
+                            Some(valuation.expected_wage),
+                        );
                         if reason == RejectionReason::NoReleaseClause {
                             let demanded = player
                                 .pending_contract_ask
@@ -470,6 +492,7 @@ impl ProcessContractHandler {
                             now,
                             min_acceptable_years,
                             RejectionReason::LowSalary,
+                            Some(valuation.expected_wage),
                         );
                         Self::emit_rejected_contract_offer(
                             player,
@@ -545,7 +568,14 @@ impl ProcessContractHandler {
                     } else {
                         RejectionReason::AmbitionMismatch
                     };
-                    record_counter_offer(player, &proposal, now, min_acceptable_years, reason);
+                    record_counter_offer(
+                        player,
+                        &proposal,
+                        now,
+                        min_acceptable_years,
+                        reason,
+                        Some(valuation.expected_wage),
+                    );
                     Self::emit_rejected_contract_offer(player, &proposal, reason);
                     log_rejection(player, &proposal, now);
                 }
@@ -872,6 +902,43 @@ mod tests {
         assert_eq!(count_demanded(&p), 1, "90-day cooldown blocks the repeat");
     }
 
+    #[test]
+    fn salary_counter_offer_anchors_on_market_valuation() {
+        let now = d(2026, 5, 30);
+        let mut p = build(10.0, 10.0, 5_000);
+        p.contract = Some(crate::PlayerClubContract::new(40_000, d(2027, 6, 30)));
+        let lowball = make_proposal(50_000, 3);
+
+        record_counter_offer(
+            &mut p,
+            &lowball,
+            now,
+            2,
+            RejectionReason::LowSalary,
+            Some(200_000),
+        );
+        let ask = p.pending_contract_ask.as_ref().unwrap();
+        assert!(
+            ask.desired_salary >= 200_000,
+            "ask {} must start from the market valuation, not the lowball",
+            ask.desired_salary
+        );
+
+        // A length dispute leaves the wage alone even when a valuation
+        // is on hand.
+        record_counter_offer(
+            &mut p,
+            &lowball,
+            now,
+            4,
+            RejectionReason::ShortContract,
+            Some(200_000),
+        );
+        let ask = p.pending_contract_ask.as_ref().unwrap();
+        assert_eq!(ask.desired_salary, 50_000);
+        assert_eq!(ask.desired_years, 4);
+    }
+
     fn rejected_count(player: &Player) -> usize {
         player
             .happiness
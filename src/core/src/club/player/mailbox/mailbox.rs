@@ -1,19 +1,20 @@
 use crate::handlers::ProcessContractHandler;
 use crate::{Player, PlayerMailboxResult, PlayerResult, PlayerSquadStatus};
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerMessage {
     pub message_type: PlayerMessageType,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PlayerMessageType {
     ContractProposal(PlayerContractProposal),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerContractProposal {
     pub salary: u32,
     pub years: u8,
@@ -130,7 +131,7 @@ impl PlayerContractProposal {
 /// `desired_*` fields cover the headline terms; `demanded_*` carry the
 /// reason the player walked, so the AI can prioritise the right lever
 /// (better release clause vs. better base wage) on the next offer.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerContractAsk {
     pub desired_salary: u32,
     pub desired_years: u8,
@@ -146,7 +147,7 @@ pub struct PlayerContractAsk {
     pub rejection_reason: Option<RejectionReason>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum RejectionReason {
     LowSalary,
     ShortContract,
@@ -156,7 +157,7 @@ pub enum RejectionReason {
     AmbitionMismatch,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerMailbox {
     messages: VecDeque<PlayerMessage>,
 }
@@ -12,6 +12,7 @@ use crate::{
     PlayerHappiness, RoleStatusEventContext, RoleStatusKind,
 };
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use std::cmp::Reverse;
 
 /// Multi-axis reputation gap between the player and his current
@@ -148,7 +149,7 @@ pub struct SettlementRatingAdjustment {
 /// transient state (rather than having execution push events directly)
 /// means the player reacts to a new environment as part of his own
 /// processing, alongside happiness, language, integration, etc.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PendingSigning {
     pub previous_salary: Option<u32>,
     pub fee: f64,
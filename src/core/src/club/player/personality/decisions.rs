@@ -1,12 +1,13 @@
 use crate::utils::FormattingUtils;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerDecisionHistory {
     pub items: Vec<PlayerDecision>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerDecision {
     pub date: NaiveDate,
     pub movement: String,
@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// Player language proficiency system.
 ///
 /// Players have a native language from their home country and can learn
@@ -8,7 +10,7 @@
 /// - Star status (stars may resist learning — they don't "need" to)
 
 /// Languages in the football world.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Language {
     English,
     Spanish,
@@ -281,7 +283,7 @@ impl Language {
 }
 
 /// A player's proficiency in a specific language.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerLanguage {
     pub language: Language,
     /// 0 = no knowledge, 100 = fully fluent (native).
@@ -18,13 +18,14 @@
 //! coach, assistant, specialist).
 
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 /// Min rapport clamp (coach has lost the player).
 pub const RAPPORT_MIN: i16 = -50;
 /// Max rapport clamp (coach has the player's total trust).
 pub const RAPPORT_MAX: i16 = 100;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoachRapport {
     pub coach_id: u32,
     /// Rapport score, -50 to +100. 0 = neutral new relationship.
@@ -36,7 +37,7 @@ pub struct CoachRapport {
     pub shared_days: u32,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PlayerRapport {
     pub coaches: Vec<CoachRapport>,
 }
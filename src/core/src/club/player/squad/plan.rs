@@ -1,4 +1,5 @@
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 /// The club's strategic intent for a signed player.
 ///
@@ -7,7 +8,7 @@ use chrono::NaiveDate;
 /// being dumped before the club has properly evaluated them — a player must
 /// play enough games AND spend enough time before the club can decide they
 /// don't fit.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerPlan {
     /// What role the club envisioned when signing this player.
     pub role: PlayerPlanRole,
@@ -19,7 +20,7 @@ pub struct PlayerPlan {
     pub evaluation_months: u8,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum PlayerPlanRole {
     /// Signed to be a first-team starter right away (experienced, high fee).
     ImmediateStarter,
@@ -2,9 +2,10 @@ use super::ledger::{PlayerStatCompetitionKind, PlayerStatLedgerEntry};
 use super::types::{PlayerStatistics, SecondaryTeamStatistics, TeamInfo};
 use crate::league::Season;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use std::collections::HashSet;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerStatisticsHistory {
     /// Frozen history from completed seasons. Never modified after write.
     pub items: Vec<PlayerStatisticsHistoryItem>,
@@ -43,14 +44,14 @@ pub struct PlayerStatisticsHistory {
 /// frozen when the live per-spell cup bucket is about to be reset
 /// (transfer / loan / season end). Read back by the player history page
 /// to merge continental appearances into the season's league line.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContinentalSeasonStats {
     pub season_year: u16,
     pub team_slug: String,
     pub statistics: PlayerStatistics,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerStatisticsHistoryItem {
     pub season: Season,
     pub team_name: String,
@@ -64,7 +65,7 @@ pub struct PlayerStatisticsHistoryItem {
     pub seq_id: u32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CurrentSeasonEntry {
     pub team_name: String,
     pub team_slug: String,
@@ -15,12 +15,13 @@ use crate::continent::competitions::{
     CHAMPIONS_LEAGUE_SLUG, CONFERENCE_LEAGUE_SLUG, COPA_LIBERTADORES_SLUG, EUROPA_LEAGUE_SLUG,
 };
 use crate::league::Season;
+use serde::{Deserialize, Serialize};
 
 /// Discriminates the four "kinds" of competitive context a stat slice
 /// can belong to. Every renderable row is labelled with exactly one
 /// kind, independent of which database object the underlying records
 /// came from.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum PlayerStatCompetitionKind {
     /// Senior league appearances (Serie A, Premier League, …). The
     /// History page rolls continental-cup apps into these rows.
@@ -66,7 +67,7 @@ impl PlayerStatCompetitionKind {
 /// Immutable source record for a single stat slice. Storage appends
 /// these (with merge on collision); the projection groups them into
 /// render rows.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerStatLedgerEntry {
     /// Deterministic ordering token. Preserved purely so renderers can
     /// resolve ties between rows with the same `(season, team, kind)`;
@@ -1,4 +1,5 @@
 use crate::PlayerFieldPositionGroup;
+use serde::{Deserialize, Serialize};
 
 /// Info about a team context for recording history events.
 #[derive(Debug, Clone)]
@@ -10,7 +11,7 @@ pub struct TeamInfo {
     pub league_slug: String,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PlayerStatistics {
     pub played: u16,
     pub played_subs: u16,
@@ -54,7 +55,7 @@ pub struct PlayerStatistics {
 /// cups once they're modelled — instead of collapsing into a single
 /// hardcoded row. The rolled-up [`Player::cup_statistics`] aggregate is
 /// recomputed from these, so existing aggregate readers are unaffected.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CompetitionStatistics {
     /// Stable competition identifier — the match's `league_slug`
     /// (e.g. `"copa-libertadores"`, `"champions-league"`). The display
@@ -74,7 +75,7 @@ pub struct CompetitionStatistics {
 /// spell. The projection renders it directly for the in-progress season;
 /// the season-end snapshot freezes it into the canonical `season_ledger`
 /// like every other completed-season record.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SecondaryTeamStatistics {
     /// Season this slice belongs to (`Season::from_date(match_date)`),
     /// so a missed season-end can still freeze each slice under the right
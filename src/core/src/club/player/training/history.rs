@@ -1,7 +1,8 @@
 use crate::PlayerSkills;
 use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerTrainingHistory {
     records: Vec<TrainingRecord>,
 }
@@ -37,7 +38,7 @@ impl PlayerTrainingHistory {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TrainingRecord {
     date: NaiveDateTime,
     pub skills: PlayerSkills,
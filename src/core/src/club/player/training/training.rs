@@ -8,6 +8,7 @@ use crate::{
 };
 use chrono::NaiveDate;
 use chrono::{Datelike, NaiveDateTime};
+use serde::{Deserialize, Serialize};
 
 /// Inputs to the reason-picking logic. Keeps the function signature
 /// readable when the caller has 15+ booleans / scalars to pass.
@@ -31,7 +32,7 @@ struct ReasonInputs {
     session_type: TrainingType,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerTraining {
     /// Rolling average of actual training session quality (1.0-20.0).
     /// Measures execution quality, not just effort/personality.
@@ -20,6 +20,7 @@ use chrono::{Duration, NaiveDate};
 
 use crate::PlayerStatusType;
 use crate::club::player::player::Player;
+use serde::{Deserialize, Serialize};
 
 /// Statuses that advertise a signed player as available to the market.
 /// A player carrying any of these is "on the market" for the purposes of
@@ -40,7 +41,7 @@ pub const AVAILABILITY_STATUSES: [PlayerStatusType; 4] = [
 /// player still here?". Ordered by `rank`: a richer, closer-to-a-deal
 /// blocker outranks a coarse early-gate one when two are recorded on the
 /// same scan.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum AvailabilityBlockReason {
     /// Not enough time on the market yet to draw a conclusion — the
     /// player only just became available. A non-diagnosis sentinel.
@@ -126,7 +127,7 @@ impl AvailabilityBlockReason {
 /// player. Seeded the first time the circulation pass sees the player
 /// carrying an availability status; updated each weekly scan; dropped
 /// when he is no longer available (status cleared, or he changes club).
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AvailabilityMarketState {
     /// When the player first became available in the current sit. Anchors
     /// the staleness curve. Derived from the earliest active availability
@@ -14,6 +14,7 @@ use crate::club::player::player::Player;
 use crate::{Person, PlayerSquadStatus};
 use chrono::Duration;
 use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
 
 /// Why the matcher most recently passed over a free agent. One value
 /// per player, refreshed every time a country's matcher evaluates the
@@ -23,7 +24,7 @@ use chrono::{Datelike, NaiveDate};
 /// higher the rank, and the per-tick merge keeps only the
 /// highest-ranked reason so a near-miss isn't overwritten by a
 /// coarse early-gate rejection from another buyer.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum FreeAgentBlockReason {
     /// Nationality country could not be resolved — the snapshot
     /// fail-closed fallback (`u16::MAX` reference reputation) blocks
@@ -113,7 +114,7 @@ impl FreeAgentBlockReason {
 /// Snapshot of where the player came from and how the market has treated
 /// them since. Populated when the player enters the free-agent pool;
 /// updated by `on_offer_*` while they sit there; cleared on signing.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FreeAgentMarketState {
     pub free_since: NaiveDate,
 
@@ -356,7 +357,7 @@ pub struct FreeAgentStatusExplanation {
 /// Terms are primitives (plus the player-side [`PlayerSquadStatus`]) so
 /// the agreement carries no dependency on the country transfer module
 /// that prices and later executes it.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PreContractAgreement {
     pub to_club_id: u32,
     pub to_country_id: u32,
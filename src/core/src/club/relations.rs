@@ -29,7 +29,7 @@ pub struct ChemistryContext {
 }
 
 /// Enhanced Relations system with complex relationship dynamics
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Relations {
     /// Player relationships
     players: RelationStore<PlayerRelation>,
@@ -366,7 +366,7 @@ impl Relations {
 }
 
 /// Store for relationships of a specific type
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct RelationStore<T: Relationship> {
     relations: FxHashMap<u32, T>,
 }
@@ -435,7 +435,7 @@ trait Relationship {
 }
 
 /// Player relationship details
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerRelation {
     /// Relationship level (-100 to 100)
     pub level: f32,
@@ -698,7 +698,7 @@ impl Relationship for PlayerRelation {
 }
 
 /// Staff relationship details
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffRelation {
     /// Relationship level (-100 to 100)
     pub level: f32,
@@ -831,7 +831,7 @@ impl Relationship for StaffRelation {
 
 /// Group dynamics and cliques
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct GroupDynamics {
     groups: FxHashMap<GroupId, Group>,
     entity_groups: FxHashMap<u32, FxHashSet<GroupId>>,
@@ -959,7 +959,7 @@ impl GroupDynamics {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct Group {
     id: GroupId,
     members: FxHashSet<u32>,
@@ -979,7 +979,7 @@ impl Group {
 type GroupId = u32;
 
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 enum GroupType {
     Nationality,
     AgeGroup,
@@ -992,7 +992,7 @@ enum GroupType {
 /// coach_relationship, group_cohesion, conflict_level, turnover_penalty)
 /// blended into a single 0..100 chemistry score that downstream systems
 /// (training, match rating, selection) can read.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct TeamChemistry {
     overall: f32,
     factors: ChemistryFactors,
@@ -1240,7 +1240,7 @@ impl RelationDecay {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ChemistryFactors {
     pub player_harmony: f32,
     pub leadership_quality: f32,
@@ -1250,7 +1250,7 @@ pub struct ChemistryFactors {
 }
 
 /// Relationship history tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct RelationshipHistory {
     events: VecDeque<RelationshipEvent>,
     max_events: usize,
@@ -1278,7 +1278,7 @@ impl RelationshipHistory {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct RelationshipEvent {
     date: NaiveDate,
     subject_id: u32,
@@ -1288,7 +1288,7 @@ struct RelationshipEvent {
     new_value: f32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 enum SubjectType {
     Player,
     Staff,
@@ -1349,7 +1349,7 @@ impl RelationshipChange {
 }
 
 /// Mentorship types
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum MentorshipType {
     Mentor,
     Mentee,
@@ -1086,6 +1086,8 @@ mod tests {
                 relegation_spots: 0,
                 league_group: None,
                 split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
             },
             false,
         );
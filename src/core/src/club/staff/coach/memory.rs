@@ -17,6 +17,7 @@
 
 use crate::club::staff::CoachProfile;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// EMA coefficient for `recent_rating_ema` — half-life ~3 matches.
@@ -71,7 +72,7 @@ const INACTIVE_DECAY_PER_STEP: f32 = 0.25;
 /// Structured flags the coach attaches to a player. Not free text — every
 /// variant is a small, bounded signal a downstream decision can read by
 /// name. Encoded as a u32 bit-set so the memory record stays compact.
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct CoachMemoryFlags(u32);
 
 impl CoachMemoryFlags {
@@ -111,7 +112,7 @@ impl CoachMemoryFlags {
 /// inactivity window softens streaks and pulls EMAs back toward the
 /// long-form baseline, so a player who hasn't played for the coach in
 /// months isn't carrying a stale streak forward forever.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoachMemory {
     pub player_id: u32,
     pub matches_observed: u16,
@@ -236,7 +237,7 @@ impl Default for CoachMemory {
 /// Per-coach map of player memories. Lives on [`Staff`] and is
 /// updated at the league/match dispatch layer where the head coach
 /// for the side is known.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CoachMemoryStore {
     records: HashMap<u32, CoachMemory>,
 }
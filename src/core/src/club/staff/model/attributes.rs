@@ -1,6 +1,7 @@
 use crate::transfers::ScoutingRegion;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffAttributes {
     pub coaching: StaffCoaching,
     pub goalkeeping: StaffGoalkeeperCoaching,
@@ -10,7 +11,7 @@ pub struct StaffAttributes {
     pub medical: StaffMedical,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffCoaching {
     pub attacking: u8,
     pub defending: u8,
@@ -21,14 +22,14 @@ pub struct StaffCoaching {
     pub working_with_youngsters: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffGoalkeeperCoaching {
     pub distribution: u8,
     pub handling: u8,
     pub shot_stopping: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffMental {
     pub adaptability: u8,
     pub determination: u8,
@@ -37,7 +38,7 @@ pub struct StaffMental {
     pub motivating: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffKnowledge {
     pub judging_player_ability: u8,
     pub judging_player_potential: u8,
@@ -54,7 +55,7 @@ pub struct StaffKnowledge {
     pub region_familiarity: Vec<RegionFamiliarity>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RegionFamiliarity {
     pub region: ScoutingRegion,
     pub level: u8,
@@ -97,14 +98,14 @@ impl StaffKnowledge {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffDataAnalysis {
     pub judging_player_data: u8,
     pub judging_team_data: u8,
     pub presenting_data: u8,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffMedical {
     pub physiotherapy: u8,
     pub sports_science: u8,
@@ -1,11 +1,13 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoachFocus {
     pub technical_focus: Vec<TechnicalFocusType>,
     pub mental_focus: Vec<MentalFocusType>,
     pub physical_focus: Vec<PhysicalFocusType>,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum TechnicalFocusType {
     Corners,
     Crossing,
@@ -23,7 +25,7 @@ pub enum TechnicalFocusType {
     Technique,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum MentalFocusType {
     Aggression,
     Anticipation,
@@ -41,7 +43,7 @@ pub enum MentalFocusType {
     WorkRate,
 }
 
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub enum PhysicalFocusType {
     Acceleration,
     Agility,
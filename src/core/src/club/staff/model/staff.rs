@@ -12,16 +12,17 @@ use crate::{
 };
 use chrono::Weekday;
 use chrono::{Datelike, NaiveDate, NaiveDateTime, Timelike};
+use serde::{Deserialize, Serialize};
 use std::slice::Iter;
 use std::slice::IterMut;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffEvent {
     pub event_type: StaffEventType,
     pub days_ago: u16,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum StaffEventType {
     TrainingConducted,
     MatchObserved,
@@ -50,7 +51,7 @@ pub enum StaffEventType {
     BoardPresentation,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Staff {
     pub id: u32,
     pub full_name: FullName,
@@ -86,9 +87,15 @@ pub struct Staff {
     /// structured flags. Defaults to empty, so a freshly-generated
     /// coach behaves neutrally until enough observations accumulate.
     pub coach_memory: CoachMemoryStore,
+
+    /// Silverware won while in charge of a first team — league titles,
+    /// domestic cups and continental trophies. Follows the coach from
+    /// club to club and feeds the managerial reputation boards read
+    /// when drawing up a shortlist.
+    pub trophies_won: u16,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffCollection {
     pub staffs: Vec<Staff>,
 
@@ -423,6 +430,24 @@ impl StaffCollection {
             .unwrap_or(0)
     }
 
+    /// Quality (0.0-1.0) of the opposition report the backroom hands the
+    /// manager before a competitive fixture. The assistant manager writes
+    /// it from his reading of the game and of players; a data analyst
+    /// sharpens it. 0.0 when there is no assistant to write one.
+    pub fn opposition_report_quality(&self) -> f32 {
+        let Some(assistant) = self.find_by_position(StaffPosition::AssistantManager) else {
+            return 0.0;
+        };
+        let knowledge = &assistant.staff_attributes.knowledge;
+        let reading =
+            (knowledge.tactical_knowledge as f32 + knowledge.judging_player_ability as f32) / 40.0;
+        let analysis = self
+            .find_by_position(StaffPosition::DataAnalyst)
+            .map(|s| s.staff_attributes.data_analysis.judging_team_data as f32 / 20.0)
+            .unwrap_or(0.0);
+        (reading * 0.8 + analysis * 0.2).clamp(0.0, 1.0)
+    }
+
     /// Find the most relevant contracted staff member for `position`.
     /// "Relevant" is computed by `Staff::relevance_score_for` — a
     /// per-position blend of coaching / knowledge / medical attributes
@@ -457,7 +482,7 @@ impl StaffCollection {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffPerformance {
     pub training_effectiveness: f32,  // 0-1 multiplier
     pub player_development_rate: f32, // 0-1 multiplier
@@ -466,7 +491,7 @@ pub struct StaffPerformance {
     pub last_evaluation_date: Option<NaiveDate>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CoachingStyle {
     Authoritarian,    // Strict discipline, high demands
     Democratic,       // Collaborative, player input
@@ -507,6 +532,7 @@ impl Staff {
             recent_events: Vec::new(),
             specialization_days: [0; 4],
             coach_memory: CoachMemoryStore::new(),
+            trophies_won: 0,
         }
     }
 
@@ -1375,7 +1401,7 @@ pub enum RelationshipEvent {
     TrustBuilt,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StaffLicenseType {
     ContinentalPro,
     ContinentalA,
@@ -1405,7 +1431,7 @@ impl Default for CoachingStyle {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffTrainingSession {
     pub session_type: TrainingType,
     pub intensity: TrainingIntensity,
@@ -1453,6 +1479,24 @@ mod tests {
         assert_eq!(chosen.id, 2);
     }
 
+    #[test]
+    fn opposition_report_needs_an_assistant_and_improves_with_an_analyst() {
+        let mut assistant = make_contracted_staff(1, StaffPosition::AssistantManager);
+        assistant.staff_attributes.knowledge.tactical_knowledge = 16;
+        assistant.staff_attributes.knowledge.judging_player_ability = 14;
+        let mut analyst = make_contracted_staff(2, StaffPosition::DataAnalyst);
+        analyst.staff_attributes.data_analysis.judging_team_data = 18;
+
+        let nobody = StaffCollection::new(vec![make_contracted_staff(3, StaffPosition::Coach)]);
+        let alone = StaffCollection::new(vec![assistant.clone()]);
+        let backed = StaffCollection::new(vec![assistant, analyst]);
+
+        assert_eq!(nobody.opposition_report_quality(), 0.0);
+        assert!(alone.opposition_report_quality() > 0.5);
+        assert!(backed.opposition_report_quality() > alone.opposition_report_quality());
+        assert!(backed.opposition_report_quality() <= 1.0);
+    }
+
     #[test]
     fn goalkeeper_coach_selection_prefers_gk_specialism_over_outfield_skills() {
         // Both are GoalkeeperCoaches. One is a strong outfield coach but
@@ -95,6 +95,7 @@ impl StaffStub {
             recent_events: Vec::new(),
             specialization_days: [0; 4],
             coach_memory: CoachMemoryStore::new(),
+            trophies_won: 0,
         };
         staff
     }
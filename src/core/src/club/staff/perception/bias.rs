@@ -1,8 +1,9 @@
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 // ─── RecentMove ──────────────────────────────────────────────────────
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum RecentMoveType {
     DemotedToReserves,
     RecalledFromReserves,
@@ -12,7 +13,7 @@ pub enum RecentMoveType {
     SwappedOut,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct RecentMove {
     pub move_type: RecentMoveType,
     pub week: u32,
@@ -20,7 +21,7 @@ pub struct RecentMove {
 
 // ─── PlayerBias ─────────────────────────────────────────────────────
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerBias {
     pub quality_offset: f32,
     pub visibility: f32,
@@ -53,7 +54,7 @@ impl Default for PlayerBias {
 
 // ─── PlayerImpression ────────────────────────────────────────────────
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerImpression {
     pub player_id: u32,
     pub perceived_quality: f32,
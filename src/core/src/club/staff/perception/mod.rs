@@ -5,6 +5,8 @@ pub mod potential;
 pub mod profile;
 pub mod utils;
 
+use serde::{Deserialize, Serialize};
+
 // Re-export key types at module level
 pub use ability::{AbilityEstimator, DevelopmentFormEvidence};
 pub use bias::{PlayerBias, PlayerImpression, RecentMove, RecentMoveType};
@@ -26,7 +28,7 @@ pub(crate) mod state {
     pub type CoachDecisionState = super::CoachDecisionState;
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoachDecisionState {
     pub profile: CoachProfile,
     pub impressions: HashMap<u32, PlayerImpression>,
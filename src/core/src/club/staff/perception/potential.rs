@@ -395,7 +395,7 @@ mod tests {
         PeopleNameGeneratorData {
             first_names: vec!["Test".to_string()],
             last_names: vec!["Player".to_string()],
-            nicknames: Vec::new(),
+            ..Default::default()
         }
     }
 
@@ -1,10 +1,11 @@
 use crate::Staff;
 use crate::club::staff::CoachingStyle;
 use crate::club::staff::StaffCoaching;
+use serde::{Deserialize, Serialize};
 
 // ─── PerceptionLens ─────────────────────────────────────────────────
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PerceptionLens {
     pub technical_weight: f32,
     pub mental_weight: f32,
@@ -46,7 +47,7 @@ impl PerceptionLens {
 
 // ─── CoachProfile ────────────────────────────────────────────────────
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CoachProfile {
     pub judging_accuracy: f32,
     pub potential_accuracy: f32,
@@ -0,0 +1,136 @@
+//! Backroom hiring from the free-agent staff pool.
+//!
+//! The manager market only fills the head-coach seat. Every other role
+//! that feeds the simulation — the assistant's opposition report, the
+//! physio's recovery work, the goalkeeping and fitness coaches' training
+//! sessions — stays empty once its holder leaves unless someone hires a
+//! replacement. This monthly pass lets AI clubs do that: each club fills
+//! at most one missing first-team role with the best affordable free
+//! agent for it, richest clubs choosing first.
+
+use crate::club::staff::{StaffClubContract, StaffPosition, StaffStatus};
+use crate::club::{DistressLevel, Team};
+use crate::{SimulatorData, Staff};
+use chrono::{Datelike, NaiveDate};
+use log::debug;
+
+/// First-team roles an AI club keeps filled, in hiring priority.
+const BACKROOM_ROLES: [StaffPosition; 4] = [
+    StaffPosition::AssistantManager,
+    StaffPosition::Physio,
+    StaffPosition::GoalkeeperCoach,
+    StaffPosition::FitnessCoach,
+];
+
+/// Weakest `relevance_score_for` a club will hire into a role. Below
+/// this the role is better left empty than filled by a passenger.
+const MIN_ROLE_RELEVANCE: u32 = 150;
+
+/// Backroom contract length in years.
+const BACKROOM_CONTRACT_YEARS: i32 = 2;
+
+/// Annual salary a backroom hire asks at a club of `club_rep`
+/// (0..10000): a reputation-tiered base scaled by how well the
+/// candidate suits the role.
+pub fn backroom_salary(staff: &Staff, role: &StaffPosition, club_rep: u16) -> u32 {
+    let base = 15_000 + club_rep as u32 * 15;
+    let fit = (staff.relevance_score_for(role) as f32 / 400.0).clamp(0.5, 1.5);
+    (base as f32 * fit) as u32
+}
+
+/// Is `role` held on `team`? Medical roles count either physio seat.
+fn role_is_filled(team: &Team, role: &StaffPosition) -> bool {
+    team.staffs.iter().any(|s| {
+        s.contract
+            .as_ref()
+            .is_some_and(|c| c.position == *role || (role.is_medical() && c.position.is_medical()))
+    })
+}
+
+/// Fill missing first-team backroom roles from the free-agent staff pool.
+/// Called on the first of the month; clubs in financial distress don't hire.
+pub fn fill_backroom_vacancies(data: &mut SimulatorData, today: NaiveDate) {
+    let SimulatorData {
+        continents,
+        free_agent_staff,
+        ..
+    } = data;
+
+    let mut clubs: Vec<(u16, &mut Team)> = continents
+        .iter_mut()
+        .flat_map(|c| c.countries.iter_mut())
+        .flat_map(|country| country.clubs.iter_mut())
+        .filter(|club| club.finance.distress_level == DistressLevel::None)
+        .filter_map(|club| {
+            let team = club.teams.main_mut()?;
+            Some((team.reputation.world, team))
+        })
+        .collect();
+    clubs.sort_by_key(|(rep, _)| std::cmp::Reverse(*rep));
+
+    for (club_rep, team) in clubs {
+        if free_agent_staff.is_empty() {
+            break;
+        }
+        let Some(role) = BACKROOM_ROLES.iter().find(|r| !role_is_filled(team, r)) else {
+            continue;
+        };
+        let best = free_agent_staff
+            .iter()
+            .enumerate()
+            .map(|(idx, s)| (idx, s.relevance_score_for(role)))
+            .filter(|(_, score)| *score >= MIN_ROLE_RELEVANCE)
+            .max_by_key(|(_, score)| *score)
+            .map(|(idx, _)| idx);
+        let Some(idx) = best else {
+            continue;
+        };
+
+        let mut staff = free_agent_staff.remove(idx);
+        let salary = backroom_salary(&staff, role, club_rep);
+        let expires = today
+            .with_year(today.year() + BACKROOM_CONTRACT_YEARS)
+            .unwrap_or(today);
+        staff.contract = Some(StaffClubContract::new(
+            salary,
+            expires,
+            role.clone(),
+            StaffStatus::Active,
+        ));
+        staff.job_satisfaction = 70.0;
+        debug!(
+            "Backroom hire: staff {} joins team {} as {:?} ({}/y)",
+            staff.id, team.id, role, salary
+        );
+        team.staffs.push(staff);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StaffStub;
+
+    fn physio(id: u32, physiotherapy: u8) -> Staff {
+        let mut s = StaffStub::default();
+        s.id = id;
+        s.staff_attributes.medical.physiotherapy = physiotherapy;
+        s.staff_attributes.medical.sports_science = physiotherapy;
+        s
+    }
+
+    #[test]
+    fn better_suited_candidates_ask_for_more() {
+        let good = physio(1, 18);
+        let poor = physio(2, 10);
+        let role = StaffPosition::Physio;
+        assert!(backroom_salary(&good, &role, 5000) > backroom_salary(&poor, &role, 5000));
+        assert!(backroom_salary(&good, &role, 8000) > backroom_salary(&good, &role, 2000));
+    }
+
+    #[test]
+    fn weak_candidates_fall_below_the_hiring_bar() {
+        assert!(physio(1, 5).relevance_score_for(&StaffPosition::Physio) < MIN_ROLE_RELEVANCE);
+        assert!(physio(2, 12).relevance_score_for(&StaffPosition::Physio) >= MIN_ROLE_RELEVANCE);
+    }
+}
@@ -0,0 +1,242 @@
+//! Retired players moving into the dugout.
+//!
+//! Every retirement path (season-end, overdue, long free agency) parks
+//! the player in his country's `retired_players`. Once a day the manager
+//! market picks up the new arrivals there and turns a share of them into
+//! unattached coaches in the free-agent staff pool, so the pool keeps
+//! refilling over a long save instead of draining as the generated staff
+//! age out.
+//!
+//! A former player's staff profile is read off his playing one: the
+//! technical and defensive sides of his game become the matching
+//! coaching strands, leadership drives man-management and motivation,
+//! and a long career lifts the reading of the game scouts and managers
+//! need. Goalkeepers bring their own keeper-coaching.
+
+use crate::club::staff::free_pool::admit_to_pool;
+use crate::transfers::ScoutingRegion;
+use crate::utils::FloatUtils;
+use crate::{
+    Country, Player, SimulatorData, Staff, StaffAttributes, StaffCoaching, StaffDataAnalysis,
+    StaffGoalkeeperCoaching, StaffKnowledge, StaffLicenseType, StaffMedical, StaffMental,
+};
+use chrono::NaiveDate;
+use log::debug;
+
+/// Former-player staff ids live above the generated-staff range, keyed
+/// on the player id so a retiree maps to exactly one staff record.
+const FORMER_PLAYER_STAFF_ID_BASE: u32 = 800_000_000;
+
+/// Share of ordinary retirees who stay in the game; leadership,
+/// professionalism and an international career each add to it.
+const BASE_COACHING_CHANCE: f32 = 0.15;
+const MAX_COACHING_CHANCE: f32 = 0.6;
+
+/// Caps that mark an international career worth a head start on the
+/// coaching badges.
+const SENIOR_INTERNATIONAL_CAPS: u16 = 30;
+
+/// Chance that `player` takes up a coaching or scouting role on
+/// retirement.
+pub fn coaching_chance(player: &Player) -> f32 {
+    let leadership = player.skills.mental.leadership / 20.0;
+    let professionalism = player.attributes.professionalism / 20.0;
+    let capped = if player.player_attributes.international_apps >= 10 {
+        0.1
+    } else {
+        0.0
+    };
+    (BASE_COACHING_CHANCE + leadership * 0.25 + professionalism * 0.15 + capped)
+        .min(MAX_COACHING_CHANCE)
+}
+
+/// Build the unattached staff member `player` becomes. `region` is the
+/// scouting region he knows from his career.
+pub fn staff_from_player(player: &Player, region: ScoutingRegion) -> Staff {
+    let skills = &player.skills;
+    let t = &skills.technical;
+    let m = &skills.mental;
+    let gk = &skills.goalkeeping;
+
+    // Experience: a long career teaches what the legs no longer show.
+    let games: u32 = player
+        .statistics_history
+        .items
+        .iter()
+        .map(|h| h.statistics.total_games() as u32)
+        .sum();
+    let experience = (games as f32 / 150.0).min(3.0)
+        + (player.player_attributes.international_apps as f32 / 40.0).min(2.0);
+
+    let rate =
+        |v: f32| -> u8 { (v + FloatUtils::random(-1.5, 1.5)).round().clamp(1.0, 20.0) as u8 };
+    let read = |v: f32| rate(v * 0.8 + experience);
+
+    let is_keeper = player.position().is_goalkeeper();
+    let keeper = |v: f32| if is_keeper { rate(v) } else { rate(v * 0.3) };
+
+    let staff_attributes = StaffAttributes {
+        coaching: StaffCoaching {
+            attacking: read((t.finishing + t.dribbling + m.off_the_ball + t.crossing) / 4.0),
+            defending: read((t.tackling + t.marking + m.positioning + t.heading) / 4.0),
+            fitness: rate(skills.physical.natural_fitness * 0.6),
+            mental: read((m.determination + m.composure + m.concentration) / 3.0),
+            tactical: read((m.decisions + m.anticipation + m.positioning + m.teamwork) / 4.0),
+            technical: read((t.technique + t.first_touch + t.passing) / 3.0),
+            working_with_youngsters: rate((m.leadership + player.attributes.professionalism) / 2.0),
+        },
+        goalkeeping: StaffGoalkeeperCoaching {
+            distribution: keeper((gk.kicking + gk.throwing + gk.passing) / 3.0),
+            handling: keeper((gk.handling + gk.command_of_area) / 2.0),
+            shot_stopping: keeper((gk.reflexes + gk.one_on_ones) / 2.0),
+        },
+        mental: StaffMental {
+            adaptability: rate(player.attributes.adaptability),
+            determination: rate(m.determination),
+            discipline: rate(player.attributes.professionalism),
+            man_management: read((m.leadership + m.teamwork) / 2.0),
+            motivating: read((m.leadership + m.determination) / 2.0),
+        },
+        knowledge: StaffKnowledge {
+            judging_player_ability: read((m.vision + m.anticipation + m.decisions) / 3.0),
+            judging_player_potential: read((m.vision + m.anticipation) / 2.0 * 0.8),
+            tactical_knowledge: read((m.decisions + m.positioning + m.vision) / 3.0),
+            known_regions: vec![region],
+            region_familiarity: Vec::new(),
+        },
+        data_analysis: StaffDataAnalysis {
+            judging_player_data: rate(m.decisions * 0.5),
+            judging_team_data: rate(m.decisions * 0.5),
+            presenting_data: rate(m.teamwork * 0.4),
+        },
+        medical: StaffMedical {
+            physiotherapy: rate(3.0),
+            sports_science: rate(skills.physical.natural_fitness * 0.4),
+            non_player_tendencies: rate(m.teamwork * 0.5),
+        },
+    };
+
+    let license = if player.player_attributes.international_apps >= SENIOR_INTERNATIONAL_CAPS
+        || player.player_attributes.world_reputation >= 6000
+    {
+        StaffLicenseType::NationalA
+    } else if games >= 300 {
+        StaffLicenseType::NationalB
+    } else {
+        StaffLicenseType::NationalC
+    };
+
+    Staff::new(
+        FORMER_PLAYER_STAFF_ID_BASE.saturating_add(player.id),
+        player.full_name.clone(),
+        player.country_id,
+        player.birth_date,
+        staff_attributes,
+        None,
+        player.attributes,
+        license,
+        None,
+    )
+}
+
+/// Review every retiree who arrived since the last pass and move the
+/// ones who stay in football into the free-agent staff pool.
+pub fn recruit_retired_players(data: &mut SimulatorData, today: NaiveDate) -> usize {
+    let mut recruits: Vec<Staff> = Vec::new();
+
+    for continent in &mut data.continents {
+        for country in &mut continent.countries {
+            recruits.extend(review_new_retirees(country));
+        }
+    }
+
+    let n = recruits.len();
+    for staff in recruits {
+        debug!(
+            "Former player {} joins the staff market (id {})",
+            staff.full_name, staff.id
+        );
+        admit_to_pool(&mut data.free_agent_staff, staff, today);
+    }
+    n
+}
+
+fn review_new_retirees(country: &mut Country) -> Vec<Staff> {
+    let from = country
+        .retired_players_reviewed
+        .min(country.retired_players.len());
+    country.retired_players_reviewed = country.retired_players.len();
+    let region = ScoutingRegion::from_country(country.continent_id, &country.code);
+
+    country.retired_players[from..]
+        .iter()
+        .filter(|p| FloatUtils::random(0.0, 1.0) < coaching_chance(p))
+        .map(|p| staff_from_player(p, region))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::club::player::builder::PlayerBuilder;
+    use crate::shared::fullname::FullName;
+    use crate::utils::DateUtils;
+    use crate::{
+        PersonAttributes, PlayerAttributes, PlayerPosition, PlayerPositionType, PlayerPositions,
+        PlayerSkills,
+    };
+
+    fn veteran(position: PlayerPositionType) -> Player {
+        let attrs = PlayerAttributes {
+            international_apps: 45,
+            ..PlayerAttributes::default()
+        };
+        let mut skills = PlayerSkills::default();
+        skills.mental.leadership = 18.0;
+        skills.mental.decisions = 16.0;
+        skills.mental.teamwork = 16.0;
+        skills.goalkeeping.reflexes = 17.0;
+        skills.goalkeeping.one_on_ones = 17.0;
+        PlayerBuilder::new()
+            .id(7)
+            .full_name(FullName::new("Old".to_string(), "Pro".to_string()))
+            .birth_date(NaiveDate::from_ymd_opt(1990, 1, 1).unwrap())
+            .country_id(3)
+            .attributes(PersonAttributes::default())
+            .skills(skills)
+            .positions(PlayerPositions {
+                positions: vec![PlayerPosition {
+                    position,
+                    level: 20,
+                }],
+            })
+            .player_attributes(attrs)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn retiree_keeps_identity_and_enters_market_unattached() {
+        let player = veteran(PlayerPositionType::Goalkeeper);
+        let staff = staff_from_player(&player, ScoutingRegion::WesternEurope);
+        assert_eq!(staff.id, FORMER_PLAYER_STAFF_ID_BASE + 7);
+        assert_eq!(staff.birth_date, player.birth_date);
+        assert_eq!(staff.country_id, 3);
+        assert!(staff.contract.is_none());
+        assert!(matches!(staff.license, StaffLicenseType::NationalA));
+        assert!(staff.staff_attributes.goalkeeping.shot_stopping >= 14);
+        assert!(staff.staff_attributes.mental.man_management >= 10);
+        let today = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+        assert_eq!(DateUtils::age(staff.birth_date, today), 40);
+    }
+
+    #[test]
+    fn leaders_and_internationals_are_likelier_to_coach() {
+        let leader = veteran(PlayerPositionType::DefenderCenter);
+        let mut journeyman = veteran(PlayerPositionType::DefenderCenter);
+        journeyman.skills.mental.leadership = 4.0;
+        journeyman.player_attributes.international_apps = 0;
+        assert!(coaching_chance(&leader) > coaching_chance(&journeyman));
+        assert!(coaching_chance(&leader) <= MAX_COACHING_CHANCE);
+    }
+}
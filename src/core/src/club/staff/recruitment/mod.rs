@@ -1,5 +1,9 @@
+pub mod backroom;
+pub mod former_players;
 pub mod free_pool;
 pub mod transfer_resolution;
 
+pub use backroom::*;
+pub use former_players::*;
 pub use free_pool::*;
 pub use transfer_resolution::*;
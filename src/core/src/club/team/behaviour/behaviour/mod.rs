@@ -21,6 +21,7 @@ use chrono::Datelike;
 use chrono::NaiveDateTime;
 use chrono::Weekday;
 use log::debug;
+use serde::{Deserialize, Serialize};
 
 mod calculations;
 pub(crate) mod conflict_escalation;
@@ -36,7 +37,7 @@ mod partnerships;
 mod relationships;
 mod training_direction;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamBehaviour {
     last_full_update: Option<NaiveDateTime>,
     last_minor_update: Option<NaiveDateTime>,
@@ -211,9 +211,11 @@ impl TeamBehaviour {
             }
 
             let deficit = expected_by_now.saturating_sub(actual);
-            // Open the recall window for any meaningful shortfall.
+            // Open the recall window for any meaningful shortfall —
+            // pulling a later contractual recall date forward, since
+            // the borrower is not holding up its side of the deal.
             if let Some(loan) = player.contract_loan.as_mut() {
-                if loan.loan_recall_available_after.is_none() {
+                if loan.loan_recall_available_after.is_none_or(|d| d > today) {
                     loan.loan_recall_available_after = Some(today);
                 }
             }
@@ -1810,19 +1812,10 @@ impl TeamBehaviour {
         }
     }
 
-    /// True when the player's career began at the club he is rostered
-    /// at today — the academy-product check shared by the dressing-room
-    /// politics audits. Survives loans out (the origin row never
-    /// changes); falls back to "never transferred" for generated kids
-    /// with no recorded history yet.
+    /// The academy-product check shared by the dressing-room politics
+    /// audits.
     fn is_homegrown(player: &Player) -> bool {
-        match (
-            player.statistics_history.origin_team_slug(),
-            player.statistics_history.active_team_slug(),
-        ) {
-            (Some(origin), Some(active)) => origin == active,
-            _ => player.last_transfer_date.is_none(),
-        }
+        player.is_club_trained()
     }
 
     /// The arrival "came with weight": a serious fee on the current
@@ -3144,6 +3137,26 @@ mod tests {
         assert!(count(p, HappinessEventType::LackOfPlayingTime) >= 1);
     }
 
+    #[test]
+    fn failing_loan_pulls_contractual_recall_forward() {
+        let today = first_of_month(2026, 6);
+        let mut p = make_starved_loanee(today);
+        p.contract_loan
+            .as_mut()
+            .unwrap()
+            .loan_recall_available_after = Some(today + chrono::Duration::days(120));
+        let mut players = PlayerCollection::new(vec![p]);
+        TeamBehaviour::process_loan_playing_time_audit(&mut players, &month_ctx(today));
+        assert_eq!(
+            players.players[0]
+                .contract_loan
+                .as_ref()
+                .unwrap()
+                .loan_recall_available_after,
+            Some(today)
+        );
+    }
+
     #[test]
     fn recall_respects_cooldown() {
         let today = first_of_month(2026, 6);
@@ -17,7 +17,7 @@ use crate::club::player::behaviour_config::HappinessConfig;
 use crate::context::GlobalContext;
 use crate::{
     HappinessEventType, IndividualTrainingPlan, Player, PlayerCollection, PlayerFieldPositionGroup,
-    PlayerPositionType, SkillType, StaffCollection, TrainingFocus,
+    PlayerPosition, PlayerPositionType, SkillType, StaffCollection, TrainingFocus,
 };
 use chrono::{Datelike, NaiveDate};
 use std::cmp::Ordering;
@@ -37,6 +37,10 @@ impl TrainingDirection {
     /// Retraining tops out at solid-backup familiarity — the training
     /// pitch makes a usable option, not a natural.
     const RETRAIN_TARGET_LEVEL: u8 = 15;
+    /// Familiarity a brand-new position starts from when a plan targets
+    /// a role the player has never played — the first month on the
+    /// training pitch gives him the basics, not a usable option.
+    const RETRAIN_FOOTHOLD_LEVEL: u8 = 5;
     /// Plans lapse when they've run their course.
     const RETRAIN_MAX_DAYS: i64 = 240;
     const FITNESS_MAX_DAYS: i64 = 120;
@@ -212,18 +216,26 @@ impl TrainingDirection {
         for focus in &plan.focus_areas {
             match focus {
                 TrainingFocus::PositionRetraining(target) => {
-                    if let Some(entry) = player
+                    match player
                         .positions
                         .positions
                         .iter_mut()
                         .find(|p| p.position == *target)
                     {
-                        if entry.level < Self::RETRAIN_TARGET_LEVEL {
-                            entry.level += 1;
-                        }
-                        if entry.level >= Self::RETRAIN_TARGET_LEVEL {
-                            done = true;
+                        Some(entry) => {
+                            if entry.level < Self::RETRAIN_TARGET_LEVEL {
+                                entry.level += 1;
+                            }
+                            if entry.level >= Self::RETRAIN_TARGET_LEVEL {
+                                done = true;
+                            }
                         }
+                        // A role he has never played: the plan gives him
+                        // a foothold, later months build on it.
+                        None => player.positions.positions.push(PlayerPosition {
+                            position: *target,
+                            level: Self::RETRAIN_FOOTHOLD_LEVEL,
+                        }),
                     }
                     if age_days >= Self::RETRAIN_MAX_DAYS {
                         done = true;
@@ -501,9 +513,7 @@ mod tests {
     use super::*;
     use crate::club::player::builder::PlayerBuilder;
     use crate::shared::fullname::FullName;
-    use crate::{
-        PersonAttributes, PlayerAttributes, PlayerPosition, PlayerPositions, PlayerSkills,
-    };
+    use crate::{PersonAttributes, PlayerAttributes, PlayerPositions, PlayerSkills};
 
     fn player_with_positions(id: u32, positions: Vec<(PlayerPositionType, u8)>) -> Player {
         PlayerBuilder::new()
@@ -604,4 +614,40 @@ mod tests {
             .level;
         assert_eq!(level, 15, "retraining tops out at solid-backup familiarity");
     }
+
+    #[test]
+    fn retraining_into_an_unplayed_position_adds_it_gradually() {
+        let mut p = player_with_positions(1, vec![(PlayerPositionType::Striker, 20)]);
+        let start = NaiveDate::from_ymd_opt(2026, 6, 1).unwrap();
+        p.individual_training = Some(IndividualTrainingPlan {
+            player_id: 1,
+            focus_areas: vec![TrainingFocus::PositionRetraining(
+                PlayerPositionType::MidfielderCenter,
+            )],
+            intensity_modifier: 1.0,
+            special_instructions: Vec::new(),
+            started: Some(start),
+        });
+        let level = |p: &Player| {
+            p.positions
+                .positions
+                .iter()
+                .find(|e| e.position == PlayerPositionType::MidfielderCenter)
+                .map(|e| e.level)
+        };
+
+        assert_eq!(level(&p), None);
+        assert!(!TrainingDirection::progress(&mut p, start));
+        assert_eq!(level(&p), Some(5), "first month gives a foothold");
+        assert!(!TrainingDirection::progress(
+            &mut p,
+            NaiveDate::from_ymd_opt(2026, 7, 1).unwrap()
+        ));
+        assert_eq!(level(&p), Some(6));
+        assert_eq!(
+            p.position(),
+            PlayerPositionType::Striker,
+            "a raw new role never displaces the natural position"
+        );
+    }
 }
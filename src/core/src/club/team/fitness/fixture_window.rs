@@ -1,11 +1,12 @@
 use chrono::Duration;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 /// Cached competitive-fixture window for this team. Populated by the
 /// league/country pipeline before `Team::simulate` runs so training
 /// can read real calendar distance to the next match. Friendlies are
 /// excluded — they do not earn the same MD-1 / MD-2 protection.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TeamFixtureWindow {
     /// Date this window was last refreshed. Lets training tell the
     /// difference between "no fixtures because there are none" and
@@ -15,6 +16,10 @@ pub struct TeamFixtureWindow {
     pub upcoming: Vec<NaiveDate>,
     /// Up to four most recent competitive match dates, newest first.
     pub recent: Vec<NaiveDate>,
+    /// Earliest upcoming fixture the club treats as a big one — a derby
+    /// or a domestic cup semi-final / final. Lets selection rest key
+    /// players in the routine game before it.
+    pub next_big_match: Option<NaiveDate>,
 }
 
 impl TeamFixtureWindow {
@@ -26,6 +31,27 @@ impl TeamFixtureWindow {
         self.recent.iter().copied().find(|d| *d <= today)
     }
 
+    /// The window as it will read on `day` if nothing refreshes it first:
+    /// upcoming fixtures before `day` have been played by then and move to
+    /// `recent`. Only for laying out days ahead (the training page); the
+    /// daily tick reads the freshly populated window as is.
+    pub fn as_of(&self, day: NaiveDate) -> TeamFixtureWindow {
+        let (played, upcoming): (Vec<NaiveDate>, Vec<NaiveDate>) =
+            self.upcoming.iter().copied().partition(|d| *d < day);
+        let recent = played
+            .into_iter()
+            .rev()
+            .chain(self.recent.iter().copied())
+            .take(4)
+            .collect();
+        TeamFixtureWindow {
+            refreshed: self.refreshed,
+            upcoming,
+            recent,
+            next_big_match: self.next_big_match.filter(|d| *d >= day),
+        }
+    }
+
     /// Number of fixtures (recent or upcoming) within `days` calendar
     /// days of `today`. Drives the double-match-week dampener.
     pub fn fixtures_within(&self, today: NaiveDate, days: i64) -> u8 {
@@ -45,3 +71,45 @@ impl TeamFixtureWindow {
         (r + u).min(u8::MAX as usize) as u8
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 10, day).unwrap()
+    }
+
+    #[test]
+    fn previous_before_reads_recent_fixtures() {
+        let window = TeamFixtureWindow {
+            refreshed: Some(d(10)),
+            upcoming: vec![d(14)],
+            recent: vec![d(7), d(3)],
+            next_big_match: None,
+        };
+        assert_eq!(window.previous_before(d(10)), Some(d(7)));
+        assert_eq!(window.previous_before(d(5)), Some(d(3)));
+    }
+
+    #[test]
+    fn as_of_moves_passed_upcoming_fixtures_to_recent() {
+        let window = TeamFixtureWindow {
+            refreshed: Some(d(10)),
+            upcoming: vec![d(11), d(14)],
+            recent: vec![d(7)],
+            next_big_match: None,
+        };
+        // The live window only knows what has been played so far.
+        assert_eq!(window.previous_before(d(12)), Some(d(7)));
+        // Laying out the week ahead: the day after the 11th is MD+1.
+        let ahead = window.as_of(d(12));
+        assert_eq!(ahead.previous_before(d(12)), Some(d(11)));
+        assert_eq!(ahead.next_after(d(12)), Some(d(14)));
+        assert_eq!(window.as_of(d(16)).previous_before(d(16)), Some(d(14)));
+        // A fixture on the day itself is the next match, not the last.
+        let match_day = window.as_of(d(11));
+        assert_eq!(match_day.previous_before(d(11)), Some(d(7)));
+        assert_eq!(match_day.next_after(d(11)), Some(d(11)));
+    }
+}
@@ -0,0 +1,169 @@
+//! On-pitch cohesion read from the team's own match history.
+//!
+//! Two things make a side play like a side: players who have started
+//! alongside each other, and a shape they have run week after week.
+//! Both are already recorded on every [`MatchHistoryItem`] (the starting
+//! XI and the starting tactic), so cohesion is derived on demand rather
+//! than carried as separate state. A new signing has no shared starts,
+//! so the pairs around them start cold and the XI's continuity drops
+//! until they have played themselves in.
+
+use super::{MatchHistory, MatchHistoryItem};
+use crate::MatchTacticType;
+use serde::{Deserialize, Serialize};
+
+/// Most recent matches that count toward shared starts.
+const PAIR_WINDOW: usize = 20;
+
+/// Most recent matches read for shape stability and XI continuity.
+const SHAPE_WINDOW: usize = 10;
+
+/// Cohesion of one selected XI, handed to the match engine on the
+/// [`MatchSquad`](crate::r#match::MatchSquad).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SquadCohesion {
+    /// Team-level tactical familiarity (0..1) — feeds the engine's
+    /// `TacticalFamiliarity`.
+    pub tactical_familiarity: f32,
+    /// `(player_a, player_b, starts)` for every pair in the XI: how many
+    /// of the last [`PAIR_WINDOW`] matches both started.
+    pub shared_starts: Vec<(u32, u32, u8)>,
+}
+
+impl MatchHistory {
+    /// Cohesion of `starting_eleven` lining up in `tactic`. `None` when
+    /// the team has no recorded matches yet — the engine then keeps its
+    /// neutral defaults rather than treating every side as strangers on
+    /// the opening day.
+    pub fn cohesion(
+        &self,
+        starting_eleven: &[u32],
+        tactic: MatchTacticType,
+    ) -> Option<SquadCohesion> {
+        let recent: Vec<&MatchHistoryItem> = self
+            .items()
+            .iter()
+            .rev()
+            .filter(|m| !m.starting_eleven.is_empty())
+            .take(PAIR_WINDOW)
+            .collect();
+        if recent.is_empty() {
+            return None;
+        }
+
+        let started =
+            |m: &MatchHistoryItem, id: u32| m.starting_eleven.iter().any(|(p, _)| *p == id);
+
+        let mut shared_starts = Vec::new();
+        for (i, &a) in starting_eleven.iter().enumerate() {
+            for &b in &starting_eleven[i + 1..] {
+                let starts = recent
+                    .iter()
+                    .filter(|m| started(m, a) && started(m, b))
+                    .count();
+                shared_starts.push((a, b, starts.min(u8::MAX as usize) as u8));
+            }
+        }
+
+        let shape_window = &recent[..recent.len().min(SHAPE_WINDOW)];
+        let window = shape_window.len() as f32;
+        let same_shape = shape_window
+            .iter()
+            .filter(|m| m.tactic_started.or(m.tactic_used) == Some(tactic))
+            .count() as f32;
+        let continuity = if starting_eleven.is_empty() {
+            0.0
+        } else {
+            let starts: usize = starting_eleven
+                .iter()
+                .map(|&id| shape_window.iter().filter(|m| started(m, id)).count())
+                .sum();
+            starts as f32 / (window * starting_eleven.len() as f32)
+        };
+
+        Some(SquadCohesion {
+            tactical_familiarity: tactical_familiarity(same_shape / window, continuity),
+            shared_starts,
+        })
+    }
+}
+
+/// Familiarity from the share of recent matches started in this shape
+/// and the share of recent starts the XI already made. A settled XI in
+/// its usual shape lands around 0.8; a new shape with half the side
+/// freshly signed sits below 0.4.
+fn tactical_familiarity(shape_stability: f32, continuity: f32) -> f32 {
+    (0.2 + 0.3 * shape_stability.clamp(0.0, 1.0) + 0.35 * continuity.clamp(0.0, 1.0))
+        .clamp(0.0, 1.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PlayerPositionType;
+    use crate::r#match::TeamScore;
+    use chrono::NaiveDate;
+
+    fn played(xi: &[u32], tactic: MatchTacticType) -> MatchHistoryItem {
+        let date = NaiveDate::from_ymd_opt(2025, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap();
+        MatchHistoryItem::new(
+            date,
+            2,
+            (
+                TeamScore::new_with_score(1, 1),
+                TeamScore::new_with_score(2, 1),
+            ),
+        )
+        .with_tactic_summary(Some(tactic), Some(tactic), None)
+        .with_starting_eleven(
+            xi.iter()
+                .map(|&id| (id, PlayerPositionType::MidfielderCenter))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn empty_history_has_no_cohesion() {
+        let h = MatchHistory::new();
+        assert!(h.cohesion(&[1, 2, 3], MatchTacticType::T442).is_none());
+    }
+
+    #[test]
+    fn settled_xi_in_usual_shape_is_more_familiar_than_new_signings() {
+        let regulars: Vec<u32> = (1..=11).collect();
+        let mut h = MatchHistory::new();
+        for _ in 0..10 {
+            h.add(played(&regulars, MatchTacticType::T442));
+        }
+
+        let settled = h.cohesion(&regulars, MatchTacticType::T442).unwrap();
+        let rebuilt: Vec<u32> = (1..=6).chain(20..25).collect();
+        let new_signings = h.cohesion(&rebuilt, MatchTacticType::T442).unwrap();
+        let new_shape = h.cohesion(&regulars, MatchTacticType::T433).unwrap();
+
+        assert!(settled.tactical_familiarity > new_signings.tactical_familiarity);
+        assert!(settled.tactical_familiarity > new_shape.tactical_familiarity);
+        let overhaul = h.cohesion(&rebuilt, MatchTacticType::T433).unwrap();
+        assert!(overhaul.tactical_familiarity < 0.4);
+    }
+
+    #[test]
+    fn shared_starts_count_matches_both_players_started() {
+        let mut h = MatchHistory::new();
+        h.add(played(&[1, 2, 3], MatchTacticType::T442));
+        h.add(played(&[1, 2, 4], MatchTacticType::T442));
+        let c = h.cohesion(&[1, 2, 4], MatchTacticType::T442).unwrap();
+        let starts = |a, b| {
+            c.shared_starts
+                .iter()
+                .find(|(x, y, _)| (*x, *y) == (a, b))
+                .map(|(_, _, n)| *n)
+        };
+        assert_eq!(starts(1, 2), Some(2));
+        assert_eq!(starts(1, 4), Some(1));
+        assert_eq!(starts(2, 4), Some(1));
+    }
+}
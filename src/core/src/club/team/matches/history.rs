@@ -2,11 +2,12 @@ use crate::MatchTacticType;
 use crate::PlayerPositionType;
 use crate::r#match::TeamScore;
 use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
 const DEFAULT_MATCH_LIST_SIZE: usize = 10;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchHistory {
     items: Vec<MatchHistoryItem>,
 }
@@ -66,7 +67,7 @@ impl MatchHistory {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchHistoryItem {
     pub date: NaiveDateTime,
     pub rival_team_id: u32,
@@ -1,3 +1,5 @@
+mod cohesion;
 mod history;
 
+pub use cohesion::*;
 pub use history::*;
@@ -1,6 +1,6 @@
-use crate::club::team::TeamFixtureWindow;
 use crate::club::team::behaviour::TeamBehaviour;
 use crate::club::team::squad_life::social_snapshot::TeamSocialSnapshot;
+use crate::club::team::{MatchdayPlan, TeamFixtureWindow};
 use crate::{
     MatchHistory, PlayerCollection, StaffCollection, Tactics, Team, TeamReputation, TeamType,
     TrainingSchedule, Transfers,
@@ -122,6 +122,7 @@ impl TeamBuilder {
             vice_captain_id: None,
             social_snapshot: TeamSocialSnapshot::default(),
             league_reputation: 0,
+            matchday_plan: MatchdayPlan::default(),
         })
     }
 }
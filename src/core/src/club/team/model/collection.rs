@@ -8,10 +8,11 @@ use crate::{HappinessEventType, PlayerStatusType, Team, TeamResult, TeamType};
 use chrono::NaiveDate;
 use rayon::iter::IntoParallelRefMutIterator;
 use rayon::iter::ParallelIterator;
+use serde::{Deserialize, Serialize};
 use std::slice::Iter;
 use std::slice::IterMut;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamCollection {
     pub teams: Vec<Team>,
     pub coach_state: Option<CoachDecisionState>,
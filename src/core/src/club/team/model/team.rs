@@ -1,7 +1,7 @@
 use crate::club::team::behaviour::TeamBehaviour;
 use crate::club::team::{
     Achievement, CaptaincyAssigner, ChemistryContextBuilder, CompetitionType, MatchOutcome,
-    MatchResultInfo, MentorshipProcessor, PreventiveRestPass, SquadSocialViewBuilder,
+    MatchResultInfo, MatchdayPlan, MentorshipProcessor, PreventiveRestPass, SquadSocialViewBuilder,
     SquadStatusUpdater, TeamBuilder, TeamCoachingScores, TeamFixtureWindow, TeamSocialDebug,
     TeamSocialSnapshot, TeamType,
 };
@@ -13,9 +13,10 @@ use crate::{
     TransferItem, Transfers,
 };
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use std::borrow::Cow;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Team {
     pub id: u32,
     pub league_id: Option<u32>,
@@ -65,6 +66,10 @@ pub struct Team {
     /// for league-less squads (U18/U19, some reserves); consumers derive
     /// a fallback from the club's main league.
     pub league_reputation: u16,
+
+    /// Lineup and tactic set by the user for a club they control; empty
+    /// for every AI-run team. Applied by `get_enhanced_match_squad`.
+    pub matchday_plan: MatchdayPlan,
 }
 
 impl Team {
@@ -295,7 +300,13 @@ impl Team {
 
     /// Record a season-end trophy/promotion/qualification event, feeding
     /// the reputation model so title wins stick to the club for years.
+    /// Silverware is also credited to whoever is in the dugout, so the
+    /// manager carries the honour to their next club.
     pub fn on_season_trophy(&mut self, achievement: Achievement) {
+        let silverware = achievement.is_silverware();
+        if let Some(manager) = self.staffs.manager_mut().filter(|_| silverware) {
+            manager.trophies_won = manager.trophies_won.saturating_add(1);
+        }
         self.reputation.process_achievement(achievement);
     }
 }
@@ -1,7 +1,8 @@
+use serde::{Deserialize, Serialize};
 use std::fmt;
 use std::str::FromStr;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum TeamType {
     Main = 0,
     B = 1,
@@ -140,6 +141,21 @@ impl TeamType {
         }
     }
 
+    /// Offset added to the parent (Main team's) league id to give the
+    /// synthetic sub-league this squad plays in. `None` for Main and for
+    /// B / Second, which play in real divisions of their own.
+    pub fn sub_league_offset(&self) -> Option<u32> {
+        match self {
+            TeamType::U18 => Some(100000),
+            TeamType::U19 => Some(110000),
+            TeamType::U20 => Some(120000),
+            TeamType::U21 => Some(130000),
+            TeamType::U23 => Some(140000),
+            TeamType::Reserve => Some(150000),
+            TeamType::Main | TeamType::B | TeamType::Second => None,
+        }
+    }
+
     /// Youth team progression order: U18 → U19 → U20 → U21 → U23
     pub const YOUTH_PROGRESSION: &'static [TeamType] = &[
         TeamType::U18,
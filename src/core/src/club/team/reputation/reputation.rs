@@ -1,8 +1,9 @@
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use std::collections::VecDeque;
 
 /// Enhanced TeamReputation with dynamic updates and history tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TeamReputation {
     /// Local/regional reputation (0-10000)
     pub home: u16,
@@ -289,7 +290,7 @@ impl TeamReputation {
 }
 
 /// Reputation momentum tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct ReputationMomentum {
     current: f32,
     history: VecDeque<f32>,
@@ -334,7 +335,7 @@ impl ReputationMomentum {
 }
 
 /// Historical reputation tracking
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct ReputationHistory {
     snapshots: VecDeque<(NaiveDate, ReputationSnapshot)>,
     max_snapshots: usize,
@@ -390,7 +391,7 @@ impl ReputationHistory {
 }
 
 #[allow(dead_code)]
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 struct ReputationSnapshot {
     home: u16,
     national: u16,
@@ -400,7 +401,7 @@ struct ReputationSnapshot {
 
 /// Factors affecting reputation
 #[allow(dead_code)]
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 struct ReputationFactors {
     achievements: Vec<Achievement>,
     star_players_signed: u8,
@@ -426,7 +427,7 @@ impl ReputationFactors {
 /// Reputation level categories. Declared low → high so the derived `Ord`
 /// ranks `Amateur < Local < … < Elite` — callers compare tiers directly
 /// (e.g. a borrower below a loanee's parent tier).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum ReputationLevel {
     Amateur,
     Local,
@@ -499,7 +500,7 @@ pub enum ReputationTrend {
 }
 
 /// Achievement that affects reputation
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Achievement {
     achievement_type: AchievementType,
     date: NaiveDate,
@@ -516,6 +517,17 @@ impl Achievement {
         }
     }
 
+    /// Actual silverware — a title or a cup lifted, as opposed to a
+    /// promotion or a qualification spot.
+    pub fn is_silverware(&self) -> bool {
+        matches!(
+            self.achievement_type,
+            AchievementType::LeagueTitle
+                | AchievementType::CupWin
+                | AchievementType::ContinentalTrophy
+        )
+    }
+
     fn reputation_boost(&self) -> (u16, u16, u16) {
         match self.achievement_type {
             AchievementType::LeagueTitle => (500, 1000, 800),
@@ -542,7 +554,7 @@ impl Achievement {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum AchievementType {
     LeagueTitle,
     CupWin,
@@ -1,8 +1,9 @@
 use crate::club::staff::{CoachMatchSnapshot, CoachProfile, CoachStrategy};
 use crate::club::team::MatchdayLeadership;
+use crate::club::team::MatchdayLineup;
 use crate::r#match::squad::{CoachStrategyForSelection, PlayerSelectionResult};
 use crate::r#match::{MatchPlayer, MatchSquad, SelectionContext, SquadSelector};
-use crate::{Player, Staff, Tactics, TacticsSelector, Team};
+use crate::{Player, Staff, TacticSelectionReason, Tactics, TacticsSelector, Team};
 use chrono::NaiveDate;
 use std::cmp::Ordering;
 use std::collections::HashMap;
@@ -48,6 +49,8 @@ impl Team {
             free_kick_taker_id,
             selection_omissions: squad_result.omissions,
             coach_snapshot,
+            opposition_report: 0.0,
+            cohesion: None,
         }
     }
 
@@ -94,6 +97,8 @@ impl Team {
             free_kick_taker_id,
             selection_omissions: squad_result.omissions,
             coach_snapshot,
+            opposition_report: 0.0,
+            cohesion: None,
         }
     }
 
@@ -108,9 +113,11 @@ impl Team {
 
         // Pick the match tactic before selecting players. Otherwise the XI
         // can be built for the default shape and then validated against a
-        // late opponent-aware counter shape.
+        // late opponent-aware counter shape. A user-set tactic is final.
         let head_coach_tac = head_coach.staff_attributes.knowledge.tactical_knowledge;
-        let final_tactics = if let (Some(opp), true) = (ctx.opponent_tactic, head_coach_tac >= 14) {
+        let final_tactics = if let Some(tactic) = self.matchday_plan.tactic {
+            Tactics::with_reason(tactic, TacticSelectionReason::CoachPreference, 1.0)
+        } else if let (Some(opp), true) = (ctx.opponent_tactic, head_coach_tac >= 14) {
             let roster: Vec<&Player> = self.players.players();
             TacticsSelector::select_counter_tactic(&opp, &roster)
         } else {
@@ -120,19 +127,26 @@ impl Team {
                 .unwrap_or_else(|| TacticsSelector::select(self, head_coach))
         };
 
-        // Use squad selection with reserve pool for the final match tactic.
-        let squad_result = SquadSelector::select_with_tactics_context(
-            self,
-            head_coach,
-            reserve_players,
-            &final_tactics,
-            ctx,
-        );
+        // The user's XI while every chosen starter is still fit; otherwise
+        // squad selection with reserve pool for the final match tactic.
+        let (squad_result, lineup) = match self.planned_selection(&final_tactics, ctx.is_friendly) {
+            Some((result, lineup)) => (result, Some(lineup)),
+            None => (
+                SquadSelector::select_with_tactics_context(
+                    self,
+                    head_coach,
+                    reserve_players,
+                    &final_tactics,
+                    ctx,
+                ),
+                None,
+            ),
+        };
 
         // Step 5: Validate squad selection
         self.validate_squad_selection(&squad_result, &final_tactics);
 
-        let (captain_id, vice_captain_id) = MatchdayLeadership::from_match_squad_at(
+        let (mut captain_id, vice_captain_id) = MatchdayLeadership::from_match_squad_at(
             self.captain_id,
             self.vice_captain_id,
             &squad_result.main_squad,
@@ -140,8 +154,28 @@ impl Team {
         );
 
         let coach_snapshot = MatchCoachSnapshot::for_selection_context(head_coach, ctx);
-        let penalty_taker_id = self.select_penalty_taker(&squad_result.main_squad);
-        let free_kick_taker_id = self.select_free_kick_taker(&squad_result.main_squad);
+        let mut penalty_taker_id = self.select_penalty_taker(&squad_result.main_squad);
+        let mut free_kick_taker_id = self.select_free_kick_taker(&squad_result.main_squad);
+        if let Some(lineup) = lineup {
+            let main = &squad_result.main_squad;
+            captain_id = MatchdayLineup::starter(main, lineup.captain_id).or(captain_id);
+            penalty_taker_id =
+                MatchdayLineup::starter(main, lineup.penalty_taker_id).or(penalty_taker_id);
+            free_kick_taker_id =
+                MatchdayLineup::starter(main, lineup.free_kick_taker_id).or(free_kick_taker_id);
+        }
+        let opposition_report = if ctx.is_friendly {
+            0.0
+        } else {
+            self.staffs.opposition_report_quality()
+        };
+        let cohesion = if ctx.is_friendly {
+            None
+        } else {
+            let starting_eleven: Vec<u32> = squad_result.main_squad.iter().map(|p| p.id).collect();
+            self.match_history
+                .cohesion(&starting_eleven, final_tactics.tactic_type)
+        };
 
         MatchSquad {
             team_id: self.id,
@@ -155,6 +189,8 @@ impl Team {
             free_kick_taker_id,
             selection_omissions: squad_result.omissions,
             coach_snapshot,
+            opposition_report,
+            cohesion,
         }
     }
 
@@ -0,0 +1,419 @@
+use crate::r#match::MatchPlayer;
+use crate::r#match::squad::PlayerSelectionResult;
+use crate::r#match::squad::selection::helpers::{
+    DEFAULT_BENCH_SIZE, DEFAULT_SQUAD_SIZE, PlayerAvailability,
+};
+use crate::{MatchTacticType, Player, PlayerStatusType, TacticSelectionReason, Tactics, Team};
+use log::debug;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::fmt;
+
+/// Matchday setup chosen by the user for a club they control. Either half
+/// may be unset: a tactic without a lineup lets the head coach pick the
+/// players for the user's shape, and no plan at all leaves every call to
+/// the coach. Read by `Team::get_enhanced_match_squad` each competitive
+/// match until it is changed or cleared.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct MatchdayPlan {
+    pub tactic: Option<MatchTacticType>,
+    pub lineup: Option<MatchdayLineup>,
+}
+
+impl MatchdayPlan {
+    pub fn is_empty(&self) -> bool {
+        self.tactic.is_none() && self.lineup.is_none()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MatchdayLineup {
+    /// Starters in formation-slot order: `starting_eleven[i]` plays
+    /// `tactics.positions()[i]`, so slot 0 is the goalkeeper.
+    pub starting_eleven: Vec<u32>,
+    pub substitutes: Vec<u32>,
+    pub captain_id: Option<u32>,
+    pub penalty_taker_id: Option<u32>,
+    pub free_kick_taker_id: Option<u32>,
+}
+
+/// Why a lineup breaks the squad rules.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineupError {
+    StartingElevenSize(usize),
+    BenchTooLarge(usize),
+    DuplicatePlayer(u32),
+    NotInSquad(u32),
+    NotRegistered(u32),
+    Unavailable(u32),
+    NoGoalkeeper,
+    NotStarting(u32),
+}
+
+impl fmt::Display for LineupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            LineupError::StartingElevenSize(count) => write!(
+                f,
+                "starting XI needs {} players, got {}",
+                DEFAULT_SQUAD_SIZE, count
+            ),
+            LineupError::BenchTooLarge(count) => write!(
+                f,
+                "bench allows at most {} players, got {}",
+                DEFAULT_BENCH_SIZE, count
+            ),
+            LineupError::DuplicatePlayer(id) => write!(f, "player {} is picked twice", id),
+            LineupError::NotInSquad(id) => write!(f, "player {} is not in the squad", id),
+            LineupError::NotRegistered(id) => {
+                write!(f, "player {} is not registered to play", id)
+            }
+            LineupError::Unavailable(id) => {
+                write!(f, "player {} is injured, banned, away or unfit", id)
+            }
+            LineupError::NoGoalkeeper => write!(f, "the first starter must be a goalkeeper"),
+            LineupError::NotStarting(id) => {
+                write!(f, "player {} must be in the starting XI for that role", id)
+            }
+        }
+    }
+}
+
+impl MatchdayLineup {
+    /// Check the lineup against `team`'s squad rules: a full XI with a
+    /// goalkeeper in slot 0, a bench no bigger than the matchday limit,
+    /// every player on the roster, registered and fit for a competitive
+    /// game, nobody picked twice, and captain / set-piece takers starting.
+    pub fn validate(&self, team: &Team) -> Result<(), LineupError> {
+        if self.starting_eleven.len() != DEFAULT_SQUAD_SIZE {
+            return Err(LineupError::StartingElevenSize(self.starting_eleven.len()));
+        }
+        if self.substitutes.len() > DEFAULT_BENCH_SIZE {
+            return Err(LineupError::BenchTooLarge(self.substitutes.len()));
+        }
+
+        let mut picked = HashSet::new();
+        for &id in self.starting_eleven.iter().chain(&self.substitutes) {
+            if !picked.insert(id) {
+                return Err(LineupError::DuplicatePlayer(id));
+            }
+            let player = team.players.find(id).ok_or(LineupError::NotInSquad(id))?;
+            if player.statuses.has(PlayerStatusType::Unr) {
+                return Err(LineupError::NotRegistered(id));
+            }
+            if !PlayerAvailability::is_available(player, false) {
+                return Err(LineupError::Unavailable(id));
+            }
+        }
+
+        let keeper = team.players.find(self.starting_eleven[0]);
+        if !keeper.is_some_and(|p| p.positions.is_goalkeeper()) {
+            return Err(LineupError::NoGoalkeeper);
+        }
+
+        for id in [
+            self.captain_id,
+            self.penalty_taker_id,
+            self.free_kick_taker_id,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            if !self.starting_eleven.contains(&id) {
+                return Err(LineupError::NotStarting(id));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Resolve the lineup against the roster on matchday. `None` when a
+    /// starter has since been injured, banned, called up, sold or, for a
+    /// competitive game, left off the registered squad — the coach then
+    /// picks the XI instead. Bench players who dropped out are simply
+    /// left off.
+    pub(crate) fn resolve<'a>(
+        &self,
+        team: &'a Team,
+        is_friendly: bool,
+    ) -> Option<(Vec<&'a Player>, Vec<&'a Player>)> {
+        let available = |id: &u32| {
+            team.players
+                .find(*id)
+                .filter(|p| is_friendly || !p.statuses.has(PlayerStatusType::Unr))
+                .filter(|p| PlayerAvailability::is_available(p, is_friendly))
+        };
+
+        let starters: Vec<&Player> = self.starting_eleven.iter().filter_map(available).collect();
+        if starters.len() != self.starting_eleven.len() {
+            return None;
+        }
+        let substitutes = self.substitutes.iter().filter_map(available).collect();
+
+        Some((starters, substitutes))
+    }
+}
+
+impl MatchdayLineup {
+    /// The starter the user named for a role, if they named one.
+    pub(crate) fn starter(main_squad: &[MatchPlayer], id: Option<u32>) -> Option<MatchPlayer> {
+        let id = id?;
+        main_squad.iter().find(|p| p.id == id).cloned()
+    }
+}
+
+impl Team {
+    /// The user's lineup as a selection for `tactics`: starters in slot
+    /// order, bench players at their natural positions. `None` when no
+    /// lineup is set or a starter is no longer available.
+    pub(crate) fn planned_selection(
+        &self,
+        tactics: &Tactics,
+        is_friendly: bool,
+    ) -> Option<(PlayerSelectionResult, &MatchdayLineup)> {
+        let lineup = self.matchday_plan.lineup.as_ref()?;
+        let Some((starters, bench)) = lineup.resolve(self, is_friendly) else {
+            debug!(
+                "planned XI for team {} ({}) is no longer available, coach picks",
+                self.id, self.name
+            );
+            return None;
+        };
+
+        let main_squad = starters
+            .iter()
+            .zip(tactics.positions())
+            .map(|(player, position)| MatchPlayer::from_player(self.id, player, *position, false))
+            .collect();
+        let substitutes = bench
+            .iter()
+            .map(|player| MatchPlayer::from_player(self.id, player, player.position(), false))
+            .collect();
+
+        Some((
+            PlayerSelectionResult {
+                main_squad,
+                substitutes,
+                omissions: Vec::new(),
+            },
+            lineup,
+        ))
+    }
+
+    /// Set the user's lineup after checking it against the squad rules.
+    pub fn set_matchday_lineup(&mut self, lineup: MatchdayLineup) -> Result<(), LineupError> {
+        lineup.validate(self)?;
+        self.matchday_plan.lineup = Some(lineup);
+        Ok(())
+    }
+
+    /// Set the user's formation. Also becomes the team's standing tactic
+    /// so training and the tactics page follow the user's shape.
+    pub fn set_matchday_tactic(&mut self, tactic: MatchTacticType) {
+        self.matchday_plan.tactic = Some(tactic);
+        self.tactics = Some(Tactics::with_reason(
+            tactic,
+            TacticSelectionReason::CoachPreference,
+            1.0,
+        ));
+    }
+
+    /// Hand lineup and tactics back to the head coach.
+    pub fn clear_matchday_plan(&mut self) {
+        self.matchday_plan = MatchdayPlan::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::club::player::builder::PlayerBuilder;
+    use crate::r#match::SelectionContext;
+    use crate::shared::fullname::FullName;
+    use crate::{
+        PersonAttributes, PlayerAttributes, PlayerCollection, PlayerPosition, PlayerPositionType,
+        PlayerPositions, PlayerSkills, StaffCollection, TeamReputation, TeamType, TrainingSchedule,
+    };
+    use chrono::{NaiveDate, NaiveTime};
+
+    fn player(id: u32, position: PlayerPositionType) -> Player {
+        let attributes = PlayerAttributes {
+            condition: 10000,
+            ..PlayerAttributes::default()
+        };
+        PlayerBuilder::new()
+            .id(id)
+            .full_name(FullName::new("Test".to_string(), format!("Player{}", id)))
+            .birth_date(NaiveDate::from_ymd_opt(1998, 1, 1).unwrap())
+            .country_id(1)
+            .attributes(PersonAttributes::default())
+            .skills(PlayerSkills::default())
+            .positions(PlayerPositions {
+                positions: vec![PlayerPosition {
+                    position,
+                    level: 20,
+                }],
+            })
+            .player_attributes(attributes)
+            .build()
+            .unwrap()
+    }
+
+    fn team() -> Team {
+        let mut players = vec![player(1, PlayerPositionType::Goalkeeper)];
+        players.extend((2..=20).map(|id| player(id, PlayerPositionType::MidfielderCenter)));
+        Team::builder()
+            .id(1)
+            .league_id(None)
+            .club_id(1)
+            .name("Test".to_string())
+            .slug("test".to_string())
+            .team_type(TeamType::Main)
+            .players(PlayerCollection::new(players))
+            .staffs(StaffCollection::new(Vec::new()))
+            .reputation(TeamReputation::new(100, 100, 100))
+            .training_schedule(TrainingSchedule::new(
+                NaiveTime::from_hms_opt(10, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(17, 0, 0).unwrap(),
+            ))
+            .build()
+            .unwrap()
+    }
+
+    fn lineup() -> MatchdayLineup {
+        MatchdayLineup {
+            starting_eleven: (1..=11).collect(),
+            substitutes: (12..=18).collect(),
+            captain_id: Some(5),
+            penalty_taker_id: Some(9),
+            free_kick_taker_id: None,
+        }
+    }
+
+    #[test]
+    fn valid_lineup_is_stored() {
+        let mut team = team();
+        team.set_matchday_lineup(lineup()).unwrap();
+        assert_eq!(team.matchday_plan.lineup, Some(lineup()));
+    }
+
+    #[test]
+    fn lineup_breaking_squad_rules_is_rejected() {
+        let team = team();
+
+        let mut short = lineup();
+        short.starting_eleven.pop();
+        assert_eq!(
+            short.validate(&team),
+            Err(LineupError::StartingElevenSize(10))
+        );
+
+        let mut long_bench = lineup();
+        long_bench.substitutes.push(19);
+        assert_eq!(
+            long_bench.validate(&team),
+            Err(LineupError::BenchTooLarge(8))
+        );
+
+        let mut duplicate = lineup();
+        duplicate.substitutes[0] = 3;
+        assert_eq!(
+            duplicate.validate(&team),
+            Err(LineupError::DuplicatePlayer(3))
+        );
+
+        let mut stranger = lineup();
+        stranger.substitutes[0] = 99;
+        assert_eq!(stranger.validate(&team), Err(LineupError::NotInSquad(99)));
+
+        let mut no_keeper = lineup();
+        no_keeper.starting_eleven.swap(0, 1);
+        assert_eq!(no_keeper.validate(&team), Err(LineupError::NoGoalkeeper));
+
+        let mut benched_captain = lineup();
+        benched_captain.captain_id = Some(12);
+        assert_eq!(
+            benched_captain.validate(&team),
+            Err(LineupError::NotStarting(12))
+        );
+    }
+
+    #[test]
+    fn planned_lineup_and_tactic_are_used_on_matchday() {
+        let mut team = team();
+        team.set_matchday_tactic(MatchTacticType::T433);
+        team.set_matchday_lineup(lineup()).unwrap();
+
+        let squad = team.get_enhanced_match_squad(&[], &SelectionContext::default());
+
+        assert_eq!(squad.tactics.tactic_type, MatchTacticType::T433);
+        let starters: Vec<u32> = squad.main_squad.iter().map(|p| p.id).collect();
+        assert_eq!(starters, lineup().starting_eleven);
+        let bench: Vec<u32> = squad.substitutes.iter().map(|p| p.id).collect();
+        assert_eq!(bench, lineup().substitutes);
+        assert_eq!(squad.captain_id.map(|p| p.id), Some(5));
+        assert_eq!(squad.penalty_taker_id.map(|p| p.id), Some(9));
+    }
+
+    #[test]
+    fn coach_picks_when_a_planned_starter_gets_injured() {
+        let mut team = team();
+        team.set_matchday_lineup(lineup()).unwrap();
+        team.players
+            .find_mut(7)
+            .unwrap()
+            .player_attributes
+            .is_injured = true;
+
+        let squad = team.get_enhanced_match_squad(&[], &SelectionContext::default());
+
+        assert!(squad.main_squad.iter().all(|p| p.id != 7));
+        assert_eq!(squad.main_squad.len(), DEFAULT_SQUAD_SIZE);
+    }
+
+    #[test]
+    fn coach_picks_when_a_planned_player_is_deregistered() {
+        let mut team = team();
+        team.set_matchday_lineup(lineup()).unwrap();
+        let date = NaiveDate::from_ymd_opt(2026, 9, 1).unwrap();
+        for id in [7, 12] {
+            let player = team.players.find_mut(id).unwrap();
+            player.statuses.add(date, PlayerStatusType::Unr);
+        }
+
+        assert!(lineup().resolve(&team, false).is_none());
+        assert!(
+            team.planned_selection(&Tactics::new(MatchTacticType::T442), false)
+                .is_none()
+        );
+
+        // Registration only binds competitive games.
+        let (starters, bench) = lineup().resolve(&team, true).unwrap();
+        assert!(starters.iter().any(|p| p.id == 7));
+        assert!(bench.iter().any(|p| p.id == 12));
+
+        // A deregistered substitute is left off the bench.
+        team.players
+            .find_mut(7)
+            .unwrap()
+            .statuses
+            .remove(PlayerStatusType::Unr);
+        let (_, bench) = lineup().resolve(&team, false).unwrap();
+        assert!(bench.iter().all(|p| p.id != 12));
+    }
+
+    #[test]
+    fn injured_player_cannot_be_picked() {
+        let mut team = team();
+        team.players
+            .find_mut(4)
+            .unwrap()
+            .player_attributes
+            .is_injured = true;
+        assert_eq!(
+            team.set_matchday_lineup(lineup()),
+            Err(LineupError::Unavailable(4))
+        );
+        assert!(team.matchday_plan.is_empty());
+    }
+}
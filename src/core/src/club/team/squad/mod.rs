@@ -1,6 +1,7 @@
 mod asset_protection;
 mod contract_renewal;
 mod match_squad;
+mod matchday_plan;
 mod move_guard;
 mod satisfaction;
 
@@ -8,6 +9,7 @@ pub use asset_protection::{
     SquadAssetClass, SquadAssetContext, SquadAssetProtection, SquadEvidenceContext,
 };
 pub use contract_renewal::{ContractRenewalManager, WageStructureSnapshot};
+pub use matchday_plan::{LineupError, MatchdayLineup, MatchdayPlan};
 pub use satisfaction::SquadSatisfaction;
 
 pub(crate) use move_guard::MainSquadMoveGuard;
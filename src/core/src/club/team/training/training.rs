@@ -1,6 +1,7 @@
 use crate::club::player::language::Language;
 use crate::club::player::training::result::PlayerTrainingResult;
 use crate::club::staff::CoachPlayerBond;
+use crate::club::team::TeamFixtureWindow;
 use crate::{
     ChangeType, ConflictLocation, HappinessEventCause, HappinessEventContext,
     HappinessEventEvidence, HappinessEventFollowUp, HappinessEventScope, HappinessEventSeverity,
@@ -10,6 +11,7 @@ use crate::{
 };
 use chrono::Duration;
 use chrono::{Datelike, NaiveDate, NaiveDateTime, Weekday};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// Deterministic pseudo-random roll in `[0.0, 1.0)` for an unordered pair
@@ -101,20 +103,40 @@ impl TeamTraining {
         Self::train_with_facilities(team, date, facility_quality)
     }
 
-    fn train_with_facilities(
-        team: &mut Team,
-        date: NaiveDateTime,
-        facility_quality: f32,
-    ) -> TeamTrainingResult {
-        let mut result = TeamTrainingResult::new();
+    /// Sessions the team runs on `date`, computed from the real
+    /// fixture window. The daily training tick executes exactly this
+    /// list. Empty on match days and rest days.
+    pub fn sessions_on(team: &Team, date: NaiveDateTime) -> Vec<TrainingSession> {
+        Self::plan_with_window(team, date, &team.fixture_window)
+            .sessions
+            .remove(&date.weekday())
+            .unwrap_or_default()
+    }
 
-        // Check if it's training time
-        if !team.training_schedule.is_time(date) {
-            return result;
-        }
+    /// `sessions_on` for a day ahead of the simulation date, reading the
+    /// fixture window as it will stand then (`TeamFixtureWindow::as_of`)
+    /// so the days after a coming match plan as MD+1 / MD+2.
+    pub fn planned_sessions_on(team: &Team, date: NaiveDateTime) -> Vec<TrainingSession> {
+        let window = team.fixture_window.as_of(date.date());
+        Self::plan_with_window(team, date, &window)
+            .sessions
+            .remove(&date.weekday())
+            .unwrap_or_default()
+    }
+
+    /// The plan built around `date`: periodization phase from the
+    /// calendar, MD-relative sessions from the cached fixture window,
+    /// and the coach's philosophy. Only the `date` weekday slot is
+    /// fixture-aware — see `WeeklyTrainingPlan::generate_for_date`.
+    pub fn weekly_plan(team: &Team, date: NaiveDateTime) -> WeeklyTrainingPlan {
+        Self::plan_with_window(team, date, &team.fixture_window)
+    }
 
-        // Get the current training plan
-        let current_weekday = date.weekday();
+    fn plan_with_window(
+        team: &Team,
+        date: NaiveDateTime,
+        window: &TeamFixtureWindow,
+    ) -> WeeklyTrainingPlan {
         let coach = team.staffs.training_coach(&team.team_type);
 
         // Determine periodization phase based on season progress
@@ -126,8 +148,8 @@ impl TeamTraining {
         // is available — keeping unit tests with no scheduled fixtures
         // running cleanly.
         let today = date.date();
-        let next_match_date = team.fixture_window.next_after(today);
-        let prev_match_date = team.fixture_window.previous_before(today).or_else(|| {
+        let next_match_date = window.next_after(today);
+        let prev_match_date = window.previous_before(today).or_else(|| {
             team.match_history
                 .items()
                 .iter()
@@ -136,26 +158,39 @@ impl TeamTraining {
                 .map(|m| m.date.date())
         });
         let recent_matches = if next_match_date.is_some() || prev_match_date.is_some() {
-            team.fixture_window.fixtures_within(today, 7) + Self::matches_last_14_days(team, date)
+            window.fixtures_within(today, 7) + Self::matches_last_14_days(team, date)
         } else {
             Self::matches_last_14_days(team, date)
         };
-        let weekly_plan = WeeklyTrainingPlan::generate_for_date(
+        WeeklyTrainingPlan::generate_for_date(
             today,
             prev_match_date,
             next_match_date,
             recent_matches,
             phase,
             &Self::get_coach_philosophy(coach),
-        );
+        )
+    }
+
+    fn train_with_facilities(
+        team: &mut Team,
+        date: NaiveDateTime,
+        facility_quality: f32,
+    ) -> TeamTrainingResult {
+        let mut result = TeamTrainingResult::new();
+
+        // Check if it's training time
+        if !team.training_schedule.is_time(date) {
+            return result;
+        }
+
+        let coach = team.staffs.training_coach(&team.team_type);
 
         // Execute today's training sessions
-        if let Some(sessions) = weekly_plan.sessions.get(&current_weekday) {
-            for session in sessions {
-                let session_results =
-                    Self::execute_training_session(team, coach, session, date, facility_quality);
-                result.player_results.extend(session_results);
-            }
+        for session in &Self::sessions_on(team, date) {
+            let session_results =
+                Self::execute_training_session(team, coach, session, date, facility_quality);
+            result.player_results.extend(session_results);
         }
 
         // Apply team cohesion effects
@@ -754,7 +789,7 @@ impl TeamTraining {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Default)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub enum TrainingType {
     // Physical Training
     #[default]
@@ -797,6 +832,39 @@ pub enum TrainingType {
     Rehabilitation,
 }
 
+impl TrainingType {
+    /// Stable i18n token for the training page.
+    pub fn as_i18n_key(&self) -> &'static str {
+        match self {
+            TrainingType::Endurance => "training_type_endurance",
+            TrainingType::Strength => "training_type_strength",
+            TrainingType::Speed => "training_type_speed",
+            TrainingType::Agility => "training_type_agility",
+            TrainingType::Recovery => "training_type_recovery",
+            TrainingType::BallControl => "training_type_ball_control",
+            TrainingType::Passing => "training_type_passing",
+            TrainingType::Shooting => "training_type_shooting",
+            TrainingType::Crossing => "training_type_crossing",
+            TrainingType::SetPieces => "training_type_set_pieces",
+            TrainingType::Positioning => "training_type_positioning",
+            TrainingType::TeamShape => "training_type_team_shape",
+            TrainingType::PressingDrills => "training_type_pressing_drills",
+            TrainingType::TransitionPlay => "training_type_transition_play",
+            TrainingType::SetPiecesDefensive => "training_type_set_pieces_defensive",
+            TrainingType::Concentration => "training_type_concentration",
+            TrainingType::DecisionMaking => "training_type_decision_making",
+            TrainingType::Leadership => "training_type_leadership",
+            TrainingType::GoalkeeperTraining => "training_type_goalkeeper_training",
+            TrainingType::MatchPreparation => "training_type_match_preparation",
+            TrainingType::VideoAnalysis => "training_type_video_analysis",
+            TrainingType::OpponentSpecific => "training_type_opponent_specific",
+            TrainingType::RestDay => "training_type_rest_day",
+            TrainingType::LightRecovery => "training_type_light_recovery",
+            TrainingType::Rehabilitation => "training_type_rehabilitation",
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct TrainingSession {
     pub session_type: TrainingType,
@@ -806,7 +874,7 @@ pub struct TrainingSession {
     pub participants: Vec<u32>, // Player IDs
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TrainingIntensity {
     VeryLight, // 20-40% max effort - recovery sessions
     Light,     // 40-60% max effort - technical work
@@ -815,6 +883,18 @@ pub enum TrainingIntensity {
     VeryHigh,  // 90-100% max effort - match simulation
 }
 
+impl TrainingIntensity {
+    pub fn as_i18n_key(&self) -> &'static str {
+        match self {
+            TrainingIntensity::VeryLight => "training_intensity_very_light",
+            TrainingIntensity::Light => "training_intensity_light",
+            TrainingIntensity::Moderate => "training_intensity_moderate",
+            TrainingIntensity::High => "training_intensity_high",
+            TrainingIntensity::VeryHigh => "training_intensity_very_high",
+        }
+    }
+}
+
 // ============== Weekly Training Schedule ==============
 
 #[derive(Debug, Clone)]
@@ -833,6 +913,18 @@ pub enum PeriodizationPhase {
     OffSeason,   // Rest and light maintenance
 }
 
+impl PeriodizationPhase {
+    pub fn as_i18n_key(&self) -> &'static str {
+        match self {
+            PeriodizationPhase::PreSeason => "training_phase_pre_season",
+            PeriodizationPhase::EarlySeason => "training_phase_early_season",
+            PeriodizationPhase::MidSeason => "training_phase_mid_season",
+            PeriodizationPhase::LateSeason => "training_phase_late_season",
+            PeriodizationPhase::OffSeason => "training_phase_off_season",
+        }
+    }
+}
+
 impl WeeklyTrainingPlan {
     /// Generate a realistic weekly training plan based on match schedule.
     /// Backwards-compatible shim — internally it calls the
@@ -1553,7 +1645,7 @@ impl GoalkeepingGains {
 
 // ============== Individual Player Training Plans ==============
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IndividualTrainingPlan {
     pub player_id: u32,
     pub focus_areas: Vec<TrainingFocus>,
@@ -1565,7 +1657,7 @@ pub struct IndividualTrainingPlan {
     pub started: Option<NaiveDate>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TrainingFocus {
     WeakFootImprovement,
     PositionRetraining(PlayerPositionType),
@@ -1575,7 +1667,7 @@ pub enum TrainingFocus {
     MentalDevelopment,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SkillType {
     FreeKicks,
     Penalties,
@@ -1586,7 +1678,7 @@ pub enum SkillType {
     Dribbling,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum SpecialInstruction {
     ExtraGymWork,
     DietProgram,
@@ -1,9 +1,10 @@
 use crate::shared::CurrencyValue;
 use rustc_hash::FxHashSet;
+use serde::{Deserialize, Serialize};
 
 const DEFAULT_TRANSFER_LIST_SIZE: usize = 10;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Transfers {
     items: Vec<TransferItem>,
 }
@@ -65,7 +66,7 @@ impl Transfers {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferItem {
     pub player_id: u32,
     pub amount: CurrencyValue,
@@ -5,10 +5,11 @@ use crate::continent::Continent;
 use crate::continent::national::{
     CompetitionPhase, NationalCompetitionConfig, NationalCompetitionPhase, NationalTeamCompetition,
 };
+use serde::{Deserialize, Serialize};
 
 /// Manages global-scope competitions (e.g. World Cup) at the SimulatorData level.
 /// Qualifying runs per-continent; the tournament is assembled here from all zones.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GlobalCompetitions {
     pub configs: Vec<NationalCompetitionConfig>,
     pub tournaments: Vec<NationalTeamCompetition>,
@@ -1,6 +1,6 @@
 use super::{
     CHAMPIONS_LEAGUE_ID, CompetitionStage, CompetitionTier, ContinentalMatch,
-    ContinentalMatchResult, GroupTable, KnockoutTie,
+    ContinentalMatchResult, GroupDraw, GroupTable, KnockoutTie,
 };
 use crate::continent::ContinentalRankings;
 use crate::r#match::squad::selection::model::MatchSelectionGameModel;
@@ -8,13 +8,14 @@ use crate::r#match::{Match, MatchResult, SelectionCompetition, SelectionContext}
 use crate::{Club, MatchRuntime};
 use chrono::{Datelike, NaiveDate};
 use log::{debug, info};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub const CHAMPIONS_LEAGUE_SLUG: &str = "champions-league";
 
 // ─── Main Champions League struct ───────────────────────────────────
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ChampionsLeague {
     pub participating_clubs: Vec<u32>,
     pub current_stage: CompetitionStage,
@@ -48,7 +49,8 @@ impl ChampionsLeague {
     pub fn conduct_draw(
         &mut self,
         clubs: &[u32],
-        _rankings: &ContinentalRankings,
+        rankings: &ContinentalRankings,
+        club_countries: &HashMap<u32, u32>,
         date: NaiveDate,
     ) {
         if clubs.len() < 8 {
@@ -64,19 +66,17 @@ impl ChampionsLeague {
         self.participating_clubs = clubs[..count].to_vec();
         self.season_year = date.year() as u16;
 
-        // Create groups: distribute clubs round-robin into 8 groups
+        // Create groups: seeded pots, country-protected draw
         let num_groups = (count / 4).max(1).min(8);
-        self.groups = (0..num_groups)
-            .map(|g| {
-                let team_ids: Vec<u32> = (0..4)
-                    .filter_map(|i| {
-                        let idx = g + i * num_groups;
-                        self.participating_clubs.get(idx).copied()
-                    })
-                    .collect();
-                GroupTable::new(&team_ids)
-            })
-            .collect();
+        self.groups = GroupDraw::draw(
+            &self.participating_clubs,
+            num_groups,
+            rankings,
+            club_countries,
+        )
+        .iter()
+        .map(|team_ids| GroupTable::new(team_ids))
+        .collect();
 
         // Generate group stage fixtures (6 matchdays)
         self.matches.clear();
@@ -1,6 +1,6 @@
 use super::{
     COPA_LIBERTADORES_ID, CompetitionStage, CompetitionTier, ContinentalMatch,
-    ContinentalMatchResult, GroupTable, KnockoutTie,
+    ContinentalMatchResult, GroupDraw, GroupTable, KnockoutTie,
 };
 use crate::Club;
 use crate::MatchRuntime;
@@ -9,6 +9,7 @@ use crate::r#match::squad::selection::model::MatchSelectionGameModel;
 use crate::r#match::{Match, MatchResult, SelectionCompetition, SelectionContext};
 use chrono::{Datelike, NaiveDate};
 use log::{debug, info};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 pub const COPA_LIBERTADORES_SLUG: &str = "copa-libertadores";
@@ -21,7 +22,7 @@ pub const COPA_LIBERTADORES_SLUG: &str = "copa-libertadores";
 // South-American leagues and scheduled on a Thursday cadence shifted a
 // day off the UEFA midweek dates so the two continents don't collide.
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CopaLibertadores {
     pub participating_clubs: Vec<u32>,
     pub current_stage: CompetitionStage,
@@ -55,7 +56,8 @@ impl CopaLibertadores {
     pub fn conduct_draw(
         &mut self,
         clubs: &[u32],
-        _rankings: &ContinentalRankings,
+        rankings: &ContinentalRankings,
+        club_countries: &HashMap<u32, u32>,
         date: NaiveDate,
     ) {
         if clubs.len() < 8 {
@@ -71,19 +73,17 @@ impl CopaLibertadores {
         self.participating_clubs = clubs[..count].to_vec();
         self.season_year = date.year() as u16;
 
-        // Create groups: distribute clubs round-robin into 8 groups
+        // Create groups: seeded pots, country-protected draw
         let num_groups = (count / 4).max(1).min(8);
-        self.groups = (0..num_groups)
-            .map(|g| {
-                let team_ids: Vec<u32> = (0..4)
-                    .filter_map(|i| {
-                        let idx = g + i * num_groups;
-                        self.participating_clubs.get(idx).copied()
-                    })
-                    .collect();
-                GroupTable::new(&team_ids)
-            })
-            .collect();
+        self.groups = GroupDraw::draw(
+            &self.participating_clubs,
+            num_groups,
+            rankings,
+            club_countries,
+        )
+        .iter()
+        .map(|team_ids| GroupTable::new(team_ids))
+        .collect();
 
         // Generate group stage fixtures (6 matchdays). Thursday cadence,
         // one day after the UEFA midweek slate.
@@ -731,6 +731,7 @@ mod lifecycle_tests {
         copa.conduct_draw(
             &thirty_two_clubs(),
             &ContinentalRankings::new(),
+            &HashMap::new(),
             draw_date(),
         );
 
@@ -751,6 +752,7 @@ mod lifecycle_tests {
         copa.conduct_draw(
             &thirty_two_clubs(),
             &ContinentalRankings::new(),
+            &HashMap::new(),
             draw_date(),
         );
 
@@ -774,6 +776,7 @@ mod lifecycle_tests {
         copa.conduct_draw(
             &thirty_two_clubs(),
             &ContinentalRankings::new(),
+            &HashMap::new(),
             draw_date(),
         );
         copa.generate_knockout_fixtures(draw_date().year());
@@ -851,6 +854,7 @@ mod lifecycle_tests {
         copa.conduct_draw(
             &thirty_two_clubs(),
             &ContinentalRankings::new(),
+            &HashMap::new(),
             draw_date(),
         );
         copa.generate_knockout_fixtures(draw_date().year());
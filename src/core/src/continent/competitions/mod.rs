@@ -5,6 +5,8 @@ mod europa_league;
 mod super_cup;
 mod types;
 
+use serde::{Deserialize, Serialize};
+
 pub use champions_league::*;
 pub use conference_league::*;
 pub use copa_libertadores::*;
@@ -12,7 +14,7 @@ pub use europa_league::*;
 pub use super_cup::*;
 pub use types::*;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContinentalCompetitions {
     pub champions_league: ChampionsLeague,
     pub europa_league: EuropaLeague,
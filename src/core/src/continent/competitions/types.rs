@@ -1,4 +1,8 @@
+use crate::continent::ContinentalRankings;
+use crate::utils::random::engine::RandomEngine;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Reserved league_id values for continental competitions.
 /// Used in match result processing to identify competition type.
@@ -7,7 +11,7 @@ pub const EUROPA_LEAGUE_ID: u32 = 900_000_002;
 pub const CONFERENCE_LEAGUE_ID: u32 = 900_000_003;
 pub const COPA_LIBERTADORES_ID: u32 = 900_000_004;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CompetitionStage {
     NotStarted,
     Qualifying,
@@ -19,7 +23,7 @@ pub enum CompetitionStage {
     Final,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContinentalMatch {
     pub home_team: u32,
     pub away_team: u32,
@@ -48,12 +52,12 @@ pub enum CompetitionTier {
 
 // ─── Shared group / knockout types for all continental competitions ──
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct GroupTable {
     pub rows: Vec<GroupRow>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupRow {
     pub team_id: u32,
     pub played: u8,
@@ -145,7 +149,84 @@ impl GroupTable {
     }
 }
 
-#[derive(Debug, Clone)]
+/// Seeded group-stage draw shared by the continental competitions.
+pub struct GroupDraw;
+
+impl GroupDraw {
+    /// Split `clubs` into `num_groups` groups of four. Clubs are seeded by
+    /// continental club coefficient (qualification order breaks ties) and
+    /// drawn pot by pot, so every group gets one club from each pot. Each
+    /// pot is shuffled from the sim RNG, so the groups differ season to
+    /// season. A club is kept out of any group already holding a side from
+    /// its country; when no arrangement of a pot avoids every clash, the
+    /// pot fills the open groups in draw order rather than stalling the
+    /// draw. Clubs beyond four per group sit the group stage out.
+    pub fn draw(
+        clubs: &[u32],
+        num_groups: usize,
+        rankings: &ContinentalRankings,
+        club_countries: &HashMap<u32, u32>,
+    ) -> Vec<Vec<u32>> {
+        let coefficient = |club_id: u32| {
+            rankings
+                .club_rankings
+                .iter()
+                .find(|(id, _)| *id == club_id)
+                .map(|(_, points)| *points)
+                .unwrap_or(0.0)
+        };
+
+        let mut seeded = clubs.to_vec();
+        seeded.sort_by(|a, b| coefficient(*b).total_cmp(&coefficient(*a)));
+        seeded.truncate(num_groups * 4);
+
+        let mut groups: Vec<Vec<u32>> = vec![Vec::new(); num_groups];
+        for (pot, pot_clubs) in seeded.chunks_mut(num_groups.max(1)).enumerate() {
+            RandomEngine::shuffle(pot_clubs);
+            if Self::place_pot(pot_clubs, pot, &mut groups, club_countries) {
+                continue;
+            }
+            for &club_id in pot_clubs.iter() {
+                if let Some(g) = groups.iter().position(|g| g.len() == pot) {
+                    groups[g].push(club_id);
+                }
+            }
+        }
+
+        groups
+    }
+
+    /// Give every club of the pot its own open group (one still at `depth`
+    /// clubs) with no compatriot in it, backtracking when a later club is
+    /// left with only clashing groups. Returns `false`, with `groups`
+    /// untouched, when no such arrangement exists.
+    fn place_pot(
+        pot_clubs: &[u32],
+        depth: usize,
+        groups: &mut [Vec<u32>],
+        club_countries: &HashMap<u32, u32>,
+    ) -> bool {
+        let Some((&club_id, rest)) = pot_clubs.split_first() else {
+            return true;
+        };
+        let country = club_countries.get(&club_id);
+        for g in 0..groups.len() {
+            let clashes = country.is_some()
+                && groups[g].iter().any(|o| club_countries.get(o) == country);
+            if groups[g].len() != depth || clashes {
+                continue;
+            }
+            groups[g].push(club_id);
+            if Self::place_pot(rest, depth, groups, club_countries) {
+                return true;
+            }
+            groups[g].pop();
+        }
+        false
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnockoutTie {
     pub home_team: u32,
     pub away_team: u32,
@@ -321,4 +402,70 @@ mod tests {
         tie.record_leg2_with_shootout(2, 2, Some((5, 4)));
         assert_eq!(tie.winner, Some(1));
     }
+
+    #[test]
+    fn group_draw_keeps_same_country_clubs_apart() {
+        // 16 clubs, four per country, drawn into four groups.
+        let clubs: Vec<u32> = (1..=16).collect();
+        let club_countries: HashMap<u32, u32> = clubs.iter().map(|&c| (c, (c - 1) % 4)).collect();
+
+        let groups = GroupDraw::draw(&clubs, 4, &ContinentalRankings::new(), &club_countries);
+
+        assert_eq!(groups.len(), 4);
+        for group in &groups {
+            assert_eq!(group.len(), 4);
+            let mut countries: Vec<u32> = group.iter().map(|c| club_countries[c]).collect();
+            countries.sort_unstable();
+            countries.dedup();
+            assert_eq!(countries.len(), 4, "country clash in group {group:?}");
+        }
+    }
+
+    #[test]
+    fn group_draw_spreads_top_seeds_across_groups() {
+        let clubs: Vec<u32> = (1..=8).collect();
+        let mut rankings = ContinentalRankings::new();
+        // Clubs 7 and 8 carry the best coefficients.
+        rankings.update_club_ranking(7, 90.0);
+        rankings.update_club_ranking(8, 80.0);
+
+        let groups = GroupDraw::draw(&clubs, 2, &rankings, &HashMap::new());
+
+        let mut top_seeds = [groups[0][0], groups[1][0]];
+        top_seeds.sort_unstable();
+        assert_eq!(top_seeds, [7, 8]);
+    }
+
+    #[test]
+    fn group_draw_varies_with_the_seed_without_country_clashes() {
+        // Five countries over 16 clubs, so each pot mixes nations.
+        let clubs: Vec<u32> = (1..=16).collect();
+        let club_countries: HashMap<u32, u32> = clubs.iter().map(|&c| (c, c % 5)).collect();
+
+        let mut outcomes = Vec::new();
+        for seed in 1..=8u64 {
+            RandomEngine::set_seed(seed);
+            let mut groups =
+                GroupDraw::draw(&clubs, 4, &ContinentalRankings::new(), &club_countries);
+            for group in &groups {
+                assert_eq!(group.len(), 4);
+                let mut countries: Vec<u32> = group.iter().map(|c| club_countries[c]).collect();
+                countries.sort_unstable();
+                countries.dedup();
+                assert_eq!(countries.len(), 4, "seed {seed}: country clash in {group:?}");
+            }
+            for group in groups.iter_mut() {
+                group.sort_unstable();
+            }
+            groups.sort();
+            outcomes.push(groups);
+        }
+
+        outcomes.sort();
+        outcomes.dedup();
+        assert!(
+            outcomes.len() > 1,
+            "every seed produced the same group compositions"
+        );
+    }
 }
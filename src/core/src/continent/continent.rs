@@ -12,6 +12,7 @@ use crate::utils::Logging;
 use log::debug;
 use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
 use rayon::prelude::{IntoParallelRefIterator, IntoParallelRefMutIterator};
+use serde::{Deserialize, Serialize};
 use std::ops::Range;
 
 /// Reserved continent ids used to scope continental club competitions.
@@ -21,7 +22,7 @@ use std::ops::Range;
 pub const CONTINENT_EUROPE_ID: u32 = 1;
 pub const CONTINENT_SOUTH_AMERICA_ID: u32 = 3;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Continent {
     pub id: u32,
     pub name: String,
@@ -63,6 +64,20 @@ pub struct ContinentBuildOutput<'gc> {
 }
 
 impl Continent {
+    /// This continent with no countries; see `SimulatorData::without_countries`.
+    pub fn without_countries(&self) -> Continent {
+        Continent {
+            id: self.id,
+            name: self.name.clone(),
+            countries: Vec::new(),
+            continental_competitions: self.continental_competitions.clone(),
+            continental_rankings: self.continental_rankings.clone(),
+            regulations: self.regulations.clone(),
+            economic_zone: self.economic_zone.clone(),
+            national_team_competitions: self.national_team_competitions.clone(),
+        }
+    }
+
     pub fn new(
         id: u32,
         name: String,
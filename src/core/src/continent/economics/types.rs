@@ -1,6 +1,7 @@
 use crate::continent::ContinentalRankings;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EconomicZone {
     pub tv_rights_pool: f64,
     pub sponsorship_value: f64,
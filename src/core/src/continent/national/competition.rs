@@ -3,9 +3,10 @@ use log::info;
 
 use super::config::*;
 use super::schedule;
+use serde::{Deserialize, Serialize};
 
 /// Phase of a national team competition cycle
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum CompetitionPhase {
     NotStarted,
     Qualifying,
@@ -16,7 +17,7 @@ pub enum CompetitionPhase {
 }
 
 /// A qualifying group for World Cup or European Championship qualifying
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct QualifyingGroup {
     pub id: u8,
     pub team_country_ids: Vec<u32>,
@@ -113,7 +114,7 @@ impl QualifyingGroup {
 }
 
 /// Standing of a team within a qualifying group
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupStanding {
     pub country_id: u32,
     pub played: u8,
@@ -145,7 +146,7 @@ impl GroupStanding {
 }
 
 /// A single fixture in a qualifying group
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GroupFixture {
     pub matchday: u8,
     pub date: NaiveDate,
@@ -155,14 +156,14 @@ pub struct GroupFixture {
 }
 
 /// Result of a group stage or qualifying fixture
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FixtureResult {
     pub home_score: u8,
     pub away_score: u8,
 }
 
 /// A knockout bracket round
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnockoutBracket {
     pub round: KnockoutRound,
     pub fixtures: Vec<KnockoutFixture>,
@@ -194,7 +195,7 @@ impl KnockoutBracket {
 }
 
 /// Knockout round type
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum KnockoutRound {
     RoundOf16,
     QuarterFinals,
@@ -204,7 +205,7 @@ pub enum KnockoutRound {
 }
 
 /// A single knockout fixture
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnockoutFixture {
     pub date: NaiveDate,
     pub home_country_id: u32,
@@ -213,7 +214,7 @@ pub struct KnockoutFixture {
 }
 
 /// Result of a knockout match, including potential penalty winner
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnockoutResult {
     pub home_score: u8,
     pub away_score: u8,
@@ -236,7 +237,7 @@ impl KnockoutResult {
 
 /// Generic national team competition replacing both WorldCupCompetition and EuropeanChampionship.
 /// Driven entirely by NationalCompetitionConfig from the database.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NationalTeamCompetition {
     pub config: NationalCompetitionConfig,
     pub cycle_year: u16,
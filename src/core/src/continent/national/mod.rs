@@ -3,6 +3,8 @@ pub mod config;
 pub mod schedule;
 pub mod world;
 
+use serde::{Deserialize, Serialize};
+
 pub use competition::*;
 pub use config::*;
 
@@ -24,7 +26,7 @@ impl NationalCompetitionPhase {
 }
 
 /// Manages all national team competitions at the continent level
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct NationalTeamCompetitions {
     pub competition_configs: Vec<NationalCompetitionConfig>,
     pub competitions: Vec<NationalTeamCompetition>,
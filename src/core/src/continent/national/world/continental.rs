@@ -288,6 +288,7 @@ impl WorldNationalCompetitions {
             score,
             details: Some(raw),
             friendly: false,
+            attendance: 0,
         })
     }
 }
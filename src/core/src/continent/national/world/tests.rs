@@ -280,6 +280,8 @@ fn synth_match_result(home_score: u8, away_score: u8, scorer_id: Option<u32>) ->
         final_home_tactic: None,
         final_away_tactic: None,
         shape_change_minute: None,
+        home_possession_ticks: 0,
+        away_possession_ticks: 0,
     }
 }
 
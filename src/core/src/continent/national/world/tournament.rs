@@ -101,5 +101,6 @@ pub fn apply_global_tournament_result(
         score,
         details: Some(raw.clone()),
         friendly: false,
+        attendance: 0,
     }
 }
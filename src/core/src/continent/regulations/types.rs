@@ -1,6 +1,7 @@
 use crate::continent::{ContinentalRankings, EconomicZone};
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ContinentalRegulations {
     pub ffp_rules: FinancialFairPlayRules,
     pub foreign_player_limits: ForeignPlayerLimits,
@@ -31,7 +32,7 @@ impl ContinentalRegulations {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FinancialFairPlayRules {
     pub max_deficit: f64,
     pub monitoring_period_years: u8,
@@ -57,7 +58,7 @@ impl FinancialFairPlayRules {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ForeignPlayerLimits {
     pub max_non_eu_players: Option<u8>,
     pub homegrown_minimum: u8,
@@ -72,7 +73,7 @@ impl ForeignPlayerLimits {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct YouthRequirements {
     pub minimum_academy_investment: f64,
     pub minimum_youth_squad_size: u8,
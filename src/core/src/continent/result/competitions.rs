@@ -76,20 +76,37 @@ impl ContinentResult {
         let (cl_clubs, el_clubs, conf_clubs, copa_clubs, which) = clubs;
 
         if let Some(continent) = data.continent_mut(continent_id) {
+            // Country lookup for draw protection — clubs from the same
+            // country are kept apart in the group stage.
+            let club_countries: HashMap<u32, u32> = continent
+                .countries
+                .iter()
+                .flat_map(|country| country.clubs.iter().map(|club| (club.id, country.id)))
+                .collect();
             match which {
                 0 => {
                     debug!("Champions League draw: {} qualified clubs", cl_clubs.len());
                     continent
                         .continental_competitions
                         .champions_league
-                        .conduct_draw(&cl_clubs, &continent.continental_rankings, date);
+                        .conduct_draw(
+                            &cl_clubs,
+                            &continent.continental_rankings,
+                            &club_countries,
+                            date,
+                        );
                 }
                 1 => {
                     debug!("Europa League draw: {} qualified clubs", el_clubs.len());
                     continent
                         .continental_competitions
                         .europa_league
-                        .conduct_draw(&el_clubs, &continent.continental_rankings, date);
+                        .conduct_draw(
+                            &el_clubs,
+                            &continent.continental_rankings,
+                            &club_countries,
+                            date,
+                        );
                 }
                 2 => {
                     debug!(
@@ -99,7 +116,12 @@ impl ContinentResult {
                     continent
                         .continental_competitions
                         .conference_league
-                        .conduct_draw(&conf_clubs, &continent.continental_rankings, date);
+                        .conduct_draw(
+                            &conf_clubs,
+                            &continent.continental_rankings,
+                            &club_countries,
+                            date,
+                        );
                 }
                 3 => {
                     debug!(
@@ -109,7 +131,12 @@ impl ContinentResult {
                     continent
                         .continental_competitions
                         .copa_libertadores
-                        .conduct_draw(&copa_clubs, &continent.continental_rankings, date);
+                        .conduct_draw(
+                            &copa_clubs,
+                            &continent.continental_rankings,
+                            &club_countries,
+                            date,
+                        );
                 }
                 _ => {}
             }
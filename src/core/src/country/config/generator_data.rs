@@ -1,7 +1,9 @@
+use crate::utils::IntegerUtils;
+use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 
 // Update CountryGeneratorData and PeopleNameGeneratorData as per original
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct CountryGeneratorData {
     /// `Arc` so the per-tick `GlobalContext` name pools (see
     /// `CountryContext::people_names`) are shared, not deep-cloned, when
@@ -10,30 +12,102 @@ pub struct CountryGeneratorData {
 }
 
 impl CountryGeneratorData {
-    pub fn new(first_names: Vec<String>, last_names: Vec<String>, nicknames: Vec<String>) -> Self {
+    pub fn new(people_names: PeopleNameGeneratorData) -> Self {
         CountryGeneratorData {
-            people_names: Arc::new(PeopleNameGeneratorData {
-                first_names,
-                last_names,
-                nicknames,
-            }),
+            people_names: Arc::new(people_names),
         }
     }
 
     pub fn empty() -> Self {
         CountryGeneratorData {
-            people_names: Arc::new(PeopleNameGeneratorData {
-                first_names: Vec::new(),
-                last_names: Vec::new(),
-                nicknames: Vec::new(),
-            }),
+            people_names: Arc::new(PeopleNameGeneratorData::default()),
         }
     }
 }
 
-#[derive(Clone)]
+/// A country's name pools. Names are kept as written, diacritics and
+/// all; slugs and search fold them to ASCII.
+#[derive(Clone, Default, Serialize, Deserialize)]
 pub struct PeopleNameGeneratorData {
     pub first_names: Vec<String>,
     pub last_names: Vec<String>,
     pub nicknames: Vec<String>,
+    /// How common each of `first_names` is relative to the others. Empty,
+    /// or not one weight per name, draws them all equally often.
+    #[serde(default)]
+    pub first_name_weights: Vec<u16>,
+    #[serde(default)]
+    pub last_name_weights: Vec<u16>,
+}
+
+impl PeopleNameGeneratorData {
+    pub fn random_first_name(&self) -> Option<&str> {
+        weighted_pick(&self.first_names, &self.first_name_weights)
+    }
+
+    pub fn random_last_name(&self) -> Option<&str> {
+        weighted_pick(&self.last_names, &self.last_name_weights)
+    }
+
+    pub fn random_nickname(&self) -> Option<&str> {
+        weighted_pick(&self.nicknames, &[])
+    }
+}
+
+fn weighted_pick<'a>(names: &'a [String], weights: &[u16]) -> Option<&'a str> {
+    if names.is_empty() {
+        return None;
+    }
+    let total: i32 = weights.iter().map(|&w| i32::from(w)).sum();
+    if weights.len() != names.len() || total == 0 {
+        let idx = IntegerUtils::random(0, names.len() as i32) as usize;
+        return Some(&names[idx]);
+    }
+
+    let mut roll = IntegerUtils::random(0, total);
+    for (name, &weight) in names.iter().zip(weights) {
+        roll -= i32::from(weight);
+        if roll < 0 {
+            return Some(name);
+        }
+    }
+    names.last().map(String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pool(weights: Vec<u16>) -> PeopleNameGeneratorData {
+        PeopleNameGeneratorData {
+            first_names: vec!["Jan".to_string(), "Čeněk".to_string(), "Zdeněk".to_string()],
+            first_name_weights: weights,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn weights_set_how_often_each_name_is_drawn() {
+        let names = pool(vec![0, 9, 1]);
+        let drawn: Vec<&str> = (0..2000)
+            .map(|_| names.random_first_name().unwrap())
+            .collect();
+        let cenek = drawn.iter().filter(|&&n| n == "Čeněk").count();
+
+        assert!(!drawn.contains(&"Jan"));
+        assert!(cenek > 1600, "Čeněk drawn {cenek} times of 2000");
+        assert!(drawn.contains(&"Zdeněk"));
+    }
+
+    #[test]
+    fn missing_or_mismatched_weights_draw_every_name() {
+        for weights in [vec![], vec![5], vec![0, 0, 0]] {
+            let names = pool(weights);
+            let drawn: Vec<&str> = (0..300)
+                .map(|_| names.random_first_name().unwrap())
+                .collect();
+            assert!(["Jan", "Čeněk", "Zdeněk"].iter().all(|n| drawn.contains(n)));
+        }
+        assert_eq!(PeopleNameGeneratorData::default().random_last_name(), None);
+    }
 }
@@ -1,4 +1,6 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CountrySettings {
     pub pricing: CountryPricing,
     pub skin_colors: SkinColorDistribution,
@@ -13,7 +15,7 @@ impl Default for CountrySettings {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CountryPricing {
     pub price_level: f32,
 }
@@ -24,7 +26,7 @@ impl Default for CountryPricing {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct SkinColorDistribution {
     pub white: u8,
     pub black: u8,
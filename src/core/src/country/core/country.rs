@@ -22,6 +22,7 @@ use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterato
 use rayon::prelude::IntoParallelRefMutIterator;
 
 use crate::SimulationResult;
+use crate::SimulationProgress;
 use crate::Team;
 use crate::country::{
     CountryEconomicFactors, CountryGeneratorData, CountryRegulations, CountrySettings,
@@ -34,6 +35,8 @@ use crate::league::LeagueResult;
 use crate::league::LeagueTableResult;
 use crate::league::LeagueTableRow;
 use std::collections::HashMap;
+use std::time::Instant;
+use serde::{Deserialize, Serialize};
 
 /// State stashed between [`Country::simulate_build`] and
 /// [`Country::simulate_process`] for one tick. The build pass mutates
@@ -84,7 +87,7 @@ fn standing_rows(rows: &[LeagueTableRow]) -> Vec<StandingRow> {
         .collect()
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct Country {
     pub id: u32,
     pub code: String,
@@ -125,6 +128,10 @@ pub struct Country {
     pub regulations: CountryRegulations,
 
     pub retired_players: Vec<Player>,
+    /// How far into `retired_players` the staff market has looked for
+    /// former players taking up coaching. `retired_players` only grows,
+    /// so everything past this index is a new retiree.
+    pub retired_players_reviewed: usize,
 
     /// `start_year` of the most recent season for which the per-player
     /// statistics snapshot has fired. `None` before the first
@@ -160,6 +167,20 @@ impl Default for SeasonDates {
 }
 
 impl SeasonDates {
+    /// First day of the next season on or after `date`.
+    pub fn next_start(&self, date: NaiveDate) -> NaiveDate {
+        let start = |year: i32| {
+            NaiveDate::from_ymd_opt(year, self.start_month as u32, self.start_day as u32)
+                .unwrap_or_else(|| NaiveDate::from_ymd_opt(year, 8, 1).unwrap())
+        };
+        let this_year = start(date.year());
+        if this_year >= date {
+            this_year
+        } else {
+            start(date.year() + 1)
+        }
+    }
+
     /// Check if the given date is the season end day.
     pub fn is_season_end(&self, date: NaiveDate) -> bool {
         date.day() as u8 == self.end_day && date.month() as u8 == self.end_month
@@ -320,6 +341,8 @@ impl Country {
             .map(|c| (c.id, c.league_id))
             .collect();
 
+        self.postpone_league_clashes(ctx.simulation.date.date());
+
         let mut all_matches: Vec<Match> = Vec::new();
         let mut pending_leagues: Vec<Option<LeaguePendingState>> =
             Vec::with_capacity(self.leagues.leagues.len());
@@ -337,7 +360,14 @@ impl Country {
                 &league_team_ids,
                 league.reputation,
             );
+            let started = Instant::now();
             let output = league.simulate_build(&self.clubs, &league_ctx);
+            SimulationProgress::record_league_time(
+                league.id,
+                &league.slug,
+                started.elapsed(),
+                true,
+            );
             all_matches.extend(output.matches);
             pending_leagues.push(output.pending);
             if let Some(r) = output.immediate {
@@ -456,7 +486,14 @@ impl Country {
                 let r = by_league.remove(&league.id).unwrap_or_default();
                 let league_ctx =
                     ctx.with_league(league.id, league.slug.clone(), &[], league.reputation);
+                let started = Instant::now();
                 let lr = league.simulate_process(r, p, &self.clubs, &league_ctx, current_date);
+                SimulationProgress::record_league_time(
+                    league.id,
+                    &league.slug,
+                    started.elapsed(),
+                    false,
+                );
                 league_results.push(lr);
             }
         }
@@ -547,7 +584,7 @@ impl Country {
         // match store) stays in `CountryResult::process` until that
         // work is sharded too.
         let current_date = ctx.simulation.date.date();
-        CountryResult::simulate_media_coverage(self, &league_results);
+        CountryResult::simulate_media_coverage(self, &league_results, current_date);
         // End-of-period must run BEFORE downstream Phase C work that
         // reads player rosters — it retires players and triggers
         // season awards. Order-equivalent to its old position in Phase
@@ -811,6 +848,8 @@ impl Country {
     fn simulate_clubs(&mut self, ctx: &GlobalContext<'_>) -> Vec<ClubResult> {
         // Build team_id → (position, league_size, total_matches, matches_played, tier, league_rep)
         let mut team_league_info: HashMap<u32, (u8, u8, u8, u8, u8, u16)> = HashMap::new();
+        // team_id → the league's squad-size ceiling, where one is set.
+        let mut team_squad_cap: HashMap<u32, u8> = HashMap::new();
         for league in &self.leagues.leagues {
             if league.friendly {
                 continue;
@@ -824,6 +863,9 @@ impl Country {
             let tier = league.settings.tier.max(1);
             let league_rep = league.reputation;
             for (pos, row) in league.table.rows.iter().enumerate() {
+                if let Some(cap) = league.rules.max_squad_size {
+                    team_squad_cap.insert(row.team_id, cap);
+                }
                 team_league_info.insert(
                     row.team_id,
                     (
@@ -860,6 +902,11 @@ impl Country {
                     .and_then(|t| team_league_info.get(&t.id))
                     .copied()
                     .unwrap_or((0, 0, 0, 0, 1, 0));
+                let squad_cap = club
+                    .teams
+                    .main()
+                    .and_then(|t| team_squad_cap.get(&t.id))
+                    .copied();
 
                 let (main_blended_rep, main_world_rep) = club
                     .teams
@@ -885,6 +932,7 @@ impl Country {
                                 league_info.3,
                             )
                             .with_main_league_tier(league_info.4)
+                            .with_league_max_squad_size(squad_cap)
                             .with_reputations(
                                 main_blended_rep,
                                 main_world_rep,
@@ -899,6 +947,33 @@ impl Country {
             .collect()
     }
 
+    /// Postpone today's league fixtures that fall in an international
+    /// break or too close to one side's domestic cup tie, re-slotting
+    /// them on a free midweek. Cup ties keep their date — the league
+    /// programme is the one with room to move.
+    fn postpone_league_clashes(&mut self, today: NaiveDate) {
+        use std::collections::HashMap;
+        let mut cup_dates: HashMap<u32, Vec<NaiveDate>> = HashMap::new();
+        if let Some(cup) = self.domestic_cup.as_ref() {
+            for item in cup.league.schedule.tours.iter().flat_map(|t| &t.items) {
+                if item.result.is_none() {
+                    let d = item.date.date();
+                    cup_dates.entry(item.home_team_id).or_default().push(d);
+                    cup_dates.entry(item.away_team_id).or_default().push(d);
+                }
+            }
+        }
+        for league in self.leagues.leagues.iter_mut().filter(|l| !l.friendly) {
+            let season_end = league.settings.season_end_after(today);
+            let moved = league
+                .schedule
+                .postpone_clashes(today, season_end, &cup_dates);
+            if moved > 0 {
+                debug!("{}: {} fixture(s) postponed on {}", league.name, moved, today);
+            }
+        }
+    }
+
     /// Walk every league's schedule and write each team's next four
     /// upcoming + last four recent competitive fixture dates into
     /// `Team::fixture_window`. Skips friendly leagues — those don't
@@ -908,23 +983,45 @@ impl Country {
         use std::collections::HashMap;
         let mut upcoming_map: HashMap<u32, Vec<NaiveDate>> = HashMap::new();
         let mut recent_map: HashMap<u32, Vec<NaiveDate>> = HashMap::new();
+        let mut big_match_map: HashMap<u32, NaiveDate> = HashMap::new();
+        let club_of: HashMap<u32, &Club> = self
+            .clubs
+            .iter()
+            .flat_map(|c| c.teams.teams.iter().map(move |t| (t.id, c)))
+            .collect();
+        let is_derby = |home: u32, away: u32| match (club_of.get(&home), club_of.get(&away)) {
+            (Some(h), Some(a)) => h.is_rival(a.id) || a.is_rival(h.id),
+            _ => false,
+        };
         // Cup ties count toward fixture congestion just like league games,
         // so fold the cup's bracket into the same window maps.
-        let cup_schedule = self.domestic_cup.as_ref().map(|c| &c.league.schedule);
+        let cup_schedule = self
+            .domestic_cup
+            .as_ref()
+            .map(|c| (&c.league.schedule, true));
         let schedules = self
             .leagues
             .leagues
             .iter()
             .filter(|l| !l.friendly)
-            .map(|l| &l.schedule)
+            .map(|l| (&l.schedule, false))
             .chain(cup_schedule);
-        for schedule in schedules {
+        for (schedule, is_cup) in schedules {
             for tour in &schedule.tours {
+                // A cup round with two ties or fewer is a semi-final or
+                // the final.
+                let late_cup_round = is_cup && tour.items.len() <= 2;
                 for item in &tour.items {
                     let d = item.date.date();
                     if d > today && item.result.is_none() {
                         upcoming_map.entry(item.home_team_id).or_default().push(d);
                         upcoming_map.entry(item.away_team_id).or_default().push(d);
+                        if late_cup_round || is_derby(item.home_team_id, item.away_team_id) {
+                            for team_id in [item.home_team_id, item.away_team_id] {
+                                let next = big_match_map.entry(team_id).or_insert(d);
+                                *next = (*next).min(d);
+                            }
+                        }
                     } else if d <= today && item.result.is_some() {
                         recent_map.entry(item.home_team_id).or_default().push(d);
                         recent_map.entry(item.away_team_id).or_default().push(d);
@@ -943,6 +1040,7 @@ impl Country {
                 team.fixture_window.refreshed = Some(today);
                 team.fixture_window.upcoming = up;
                 team.fixture_window.recent = rec;
+                team.fixture_window.next_big_match = big_match_map.remove(&team.id);
             }
         }
     }
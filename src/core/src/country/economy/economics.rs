@@ -1,4 +1,6 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CountryEconomicFactors {
     pub gdp_growth: f32,
     pub inflation_rate: f32,
@@ -1,9 +1,14 @@
 use crate::Club;
 use crate::league::LeagueResult;
 use crate::utils::IntegerUtils;
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone)]
+/// How long a headline sponsorship signing stays in the news cycle.
+const COMMERCIAL_DEAL_NEWS_DAYS: i64 = 7;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MediaCoverage {
     pub intensity: f32,
     pub trending_stories: Vec<MediaStory>,
@@ -34,11 +39,29 @@ impl MediaCoverage {
         self.intensity = (self.intensity * 0.9 + 0.1).min(1.0);
     }
 
-    pub fn generate_weekly_stories(&mut self, clubs: &[Club]) {
+    pub fn generate_weekly_stories(&mut self, clubs: &[Club], date: NaiveDate) {
         self.trending_stories.clear();
 
+        // Big commercial signings make the news for the week after the
+        // ink dries.
+        let deal_cutoff = date - Duration::days(COMMERCIAL_DEAL_NEWS_DAYS);
+
         // Generate stories based on club performance, transfers, etc.
         for club in clubs {
+            let fresh_headline_deal = club
+                .finance
+                .sponsorship
+                .sponsorship_contracts
+                .iter()
+                .any(|c| c.is_headline_deal() && c.started.is_some_and(|s| s > deal_cutoff));
+            if fresh_headline_deal {
+                self.trending_stories.push(MediaStory {
+                    club_id: club.id,
+                    story_type: StoryType::CommercialDeal,
+                    intensity: 0.6,
+                });
+            }
+
             if IntegerUtils::random(0, 100) > 80 {
                 self.trending_stories.push(MediaStory {
                     club_id: club.id,
@@ -50,18 +73,20 @@ impl MediaCoverage {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MediaStory {
     pub club_id: u32,
     pub story_type: StoryType,
     pub intensity: f32,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum StoryType {
     TransferRumor,
     ManagerPressure,
     PlayerControversy,
     SuccessStory,
     CrisisStory,
+    /// A headline sponsorship deal was signed.
+    CommercialDeal,
 }
@@ -31,6 +31,24 @@ impl NationalTeam {
             .any(|(m, start, end)| month == *m && day >= *start && day <= *end)
     }
 
+    /// International breaks as (first day, last day) that overlap
+    /// `from..=until`, in date order.
+    pub fn breaks_between(from: NaiveDate, until: NaiveDate) -> Vec<(NaiveDate, NaiveDate)> {
+        let mut breaks: Vec<(NaiveDate, NaiveDate)> = (from.year()..=until.year())
+            .flat_map(|year| {
+                BREAK_WINDOWS.iter().filter_map(move |(m, start, end)| {
+                    Some((
+                        NaiveDate::from_ymd_opt(year, *m, *start)?,
+                        NaiveDate::from_ymd_opt(year, *m, *end)?,
+                    ))
+                })
+            })
+            .filter(|(start, end)| *end >= from && *start <= until)
+            .collect();
+        breaks.sort();
+        breaks
+    }
+
     pub fn is_tournament_start(date: NaiveDate) -> bool {
         date.month() == TOURNAMENT_WINDOW.0 && date.day() == TOURNAMENT_WINDOW.1
     }
@@ -17,6 +17,8 @@ mod synthetic;
 mod types;
 mod world_status;
 
+use serde::{Deserialize, Serialize};
+
 pub use callup::{
     NationalCallupConstraints, NationalEligibilityIssue, NationalSquadStage,
     NationalTournamentRequirements,
@@ -36,7 +38,7 @@ use chrono::NaiveDate;
 use log::debug;
 use std::collections::HashSet;
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct NationalTeam {
     pub country_id: u32,
     pub country_name: String,
@@ -90,8 +92,8 @@ impl NationalTeam {
         DEFAULT_STAFF_ROLES
             .iter()
             .map(|&role| {
-                let first_name = Self::random_name(&names.first_names);
-                let last_name = Self::random_name(&names.last_names);
+                let first_name = names.random_first_name().unwrap_or("Unknown").to_string();
+                let last_name = names.random_last_name().unwrap_or("Unknown").to_string();
                 let birth_year = IntegerUtils::random(1960, 1990);
 
                 NationalTeamStaffMember {
@@ -105,17 +107,6 @@ impl NationalTeam {
             .collect()
     }
 
-    fn random_name(names: &[String]) -> String {
-        if names.is_empty() {
-            return "Unknown".to_string();
-        }
-        let idx = IntegerUtils::random(0, names.len() as i32) as usize;
-        names
-            .get(idx)
-            .cloned()
-            .unwrap_or_else(|| "Unknown".to_string())
-    }
-
     /// Unified iterator over squad picks: real players first (squad
     /// list), then synthetic depth players (from generated_squad).
     /// UI code should call this so synthetic players appear in the
@@ -458,6 +449,8 @@ impl NationalTeam {
             // memory yet — the match engine falls back to the legacy
             // (memory-less) substitution scoring for these fixtures.
             coach_snapshot: None,
+            opposition_report: 0.0,
+            cohesion: None,
         }
     }
 
@@ -560,6 +553,8 @@ impl NationalTeam {
             free_kick_taker_id: None,
             selection_omissions: Vec::new(),
             coach_snapshot: None,
+            opposition_report: 0.0,
+            cohesion: None,
         }
     }
 }
@@ -4,12 +4,13 @@
 
 use crate::{Player, PlayerFieldPositionGroup, PlayerPositionType, TeamType};
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 /// Which national-team level a squad / competition / call-up belongs to.
 /// Senior is the established full international side; Under21 is the
 /// parallel youth side selected from a separate (younger) candidate pool
 /// with its own caps, schedule, and match-day statuses.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
 pub enum NationalTeamLevel {
     #[default]
     Senior,
@@ -30,7 +31,7 @@ impl NationalTeamLevel {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct NationalTeamStaffMember {
     pub first_name: String,
     pub last_name: String,
@@ -39,7 +40,7 @@ pub struct NationalTeamStaffMember {
     pub birth_year: i32,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum NationalTeamStaffRole {
     Manager,
     AssistantManager,
@@ -60,7 +61,7 @@ impl NationalTeamStaffRole {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct NationalSquadPlayer {
     pub player_id: u32,
     pub club_id: u32,
@@ -83,7 +84,7 @@ pub enum SquadPick<'a> {
 /// Why a player was selected for the national squad.
 /// Surfaces in the squad UI and in debug logs so call-ups are auditable
 /// instead of looking arbitrary.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum CallUpReason {
     /// High ability and world reputation — the manager always picks them.
     KeyPlayer,
@@ -143,7 +144,7 @@ impl CallUpReason {
     }
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct NationalTeamFixture {
     pub date: NaiveDate,
     pub opponent_country_id: u32,
@@ -154,7 +155,7 @@ pub struct NationalTeamFixture {
     pub result: Option<NationalTeamMatchResult>,
 }
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct NationalTeamMatchResult {
     pub home_score: u8,
     pub away_score: u8,
@@ -6,6 +6,7 @@ use crate::club::player::behaviour_config::HappinessConfig;
 use crate::club::player::events::TransferCompletion;
 use crate::club::team::reputation::{Achievement, AchievementType};
 use crate::club::team::squad::{ContractRenewalManager, WageStructureSnapshot};
+use crate::league::PromotionPlayoff;
 use crate::simulator::SimulatorData;
 use crate::utils::{DateUtils, FormattingUtils, IntegerUtils};
 use crate::{
@@ -247,7 +248,14 @@ impl CountryResult {
                 if promo_slots > 0 {
                     // Non-title promoted clubs (positions 2..=promo_slots).
                     // Champion already handled above with the dual emit.
-                    for row in table.iter().take(promo_slots).skip(1) {
+                    // With a promotion playoff the last place isn't decided
+                    // by the table, so only the automatic places count here.
+                    let automatic_slots = if league.settings.promotion_playoff_spots >= 2 {
+                        promo_slots - 1
+                    } else {
+                        promo_slots
+                    };
+                    for row in table.iter().take(automatic_slots).skip(1) {
                         trophies.push((row.team_id, AchievementType::Promotion));
                         events.push((row.team_id, HappinessEventType::PromotionCelebration, 1.0));
                     }
@@ -1879,6 +1887,16 @@ impl CountryResult {
         tier1_id: u32,
         tier1_tier: u8,
     ) -> Option<(u32, u8)> {
+        // An explicit link in the league data wins over tier pairing.
+        if let Some(target) = leagues
+            .iter()
+            .find(|l| l.id == tier1_id)
+            .and_then(|l| l.settings.relegation_league_id)
+            .and_then(|id| leagues.iter().find(|l| l.id == id))
+        {
+            return Some((target.id, target.settings.promotion_spots));
+        }
+
         let lower_tier = tier1_tier + 1;
         let mut candidates: Vec<&crate::league::League> = leagues
             .iter()
@@ -1932,6 +1950,44 @@ impl CountryResult {
         Some((first.id, first.settings.promotion_spots))
     }
 
+    /// The `count` sides going up from `league_id`. Normally the top of
+    /// the final table; a league with `promotion_playoff_spots` sends the
+    /// top `count - 1` up automatically and hands the last place to the
+    /// winner of a match-engine playoff among the next sides down.
+    fn promoted_teams(
+        country: &Country,
+        league_id: u32,
+        count: usize,
+        date: NaiveDate,
+    ) -> Vec<u32> {
+        let Some(league) = country.leagues.leagues.iter().find(|l| l.id == league_id) else {
+            return Vec::new();
+        };
+        let Some(table) = league.final_table.as_ref() else {
+            return Vec::new();
+        };
+        let playoff_spots = league.settings.promotion_playoff_spots as usize;
+        if playoff_spots < 2 || count == 0 {
+            return table.iter().take(count).map(|r| r.team_id).collect();
+        }
+
+        let automatic = count - 1;
+        let mut promoted: Vec<u32> = table.iter().take(automatic).map(|r| r.team_id).collect();
+        let entrants: Vec<u32> = table
+            .iter()
+            .skip(automatic)
+            .take(playoff_spots)
+            .map(|r| r.team_id)
+            .collect();
+        if let Some(winner) =
+            PromotionPlayoff::resolve(&entrants, &country.clubs, league.id, &league.slug, date)
+        {
+            info!("Promotion playoff winner in {}: team {}", league.name, winner);
+            promoted.push(winner);
+        }
+        promoted
+    }
+
     fn process_promotion_relegation(country: &mut Country, date: NaiveDate) {
         // Collect league info: (league_id, tier, relegation_spots, promotion_spots)
         let league_info: Vec<(u32, u8, u8, u8)> = country
@@ -2008,14 +2064,7 @@ impl CountryResult {
                 })
                 .unwrap_or_default();
 
-            let promoted_candidates: Vec<u32> = country
-                .leagues
-                .leagues
-                .iter()
-                .find(|l| l.id == tier2_id)
-                .and_then(|l| l.final_table.as_ref())
-                .map(|table| table.iter().take(nominal_swap).map(|r| r.team_id).collect())
-                .unwrap_or_default();
+            let promoted_candidates = Self::promoted_teams(country, tier2_id, nominal_swap, date);
 
             // Must balance: never relegate more than we promote (or vice versa)
             // or the top league silently shrinks each season.
@@ -2247,6 +2296,14 @@ impl CountryResult {
                 })
                 .unwrap_or_default();
 
+            let sub_league_ids: Vec<u32> = country
+                .leagues
+                .leagues
+                .iter()
+                .filter(|l| l.friendly)
+                .map(|l| l.id)
+                .collect();
+
             // Swap league_ids on teams and move sub-teams to matching friendly league
             for club in &mut country.clubs {
                 let mut new_main_league_id: Option<u32> = None;
@@ -2380,19 +2437,19 @@ impl CountryResult {
                     club.finance.balance.push_expense_player_wages(bonus_total);
                 }
 
-                // Move sub-teams to the matching youth league of the new main league
+                // Move sub-teams to the matching sub-league of the new main
+                // league. B / Second play in real divisions and stay put; a
+                // squad whose sub-league doesn't exist at the new level keeps
+                // playing where it was.
                 if let Some(new_league_id) = new_main_league_id {
                     for team in &mut club.teams.teams {
-                        if team.team_type != TeamType::Main {
-                            let type_offset = match team.team_type {
-                                TeamType::U18 => 100000,
-                                TeamType::U19 => 110000,
-                                TeamType::U20 => 120000,
-                                TeamType::U21 => 130000,
-                                TeamType::U23 => 140000,
-                                _ => 200000, // B/Reserve teams use generic friendly league
-                            };
-                            team.league_id = Some(new_league_id + type_offset);
+                        let target = team
+                            .team_type
+                            .sub_league_offset()
+                            .map(|offset| new_league_id + offset)
+                            .filter(|id| sub_league_ids.contains(id));
+                        if target.is_some() {
+                            team.league_id = target;
                         }
                     }
                 }
@@ -2585,6 +2642,8 @@ mod tests {
                 relegation_spots: 3,
                 league_group: None,
                 split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
             },
             false,
         );
@@ -2632,6 +2691,8 @@ mod tests {
                     playoff: None,
                 }),
                 split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
             },
             false,
         )
@@ -2771,6 +2832,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn explicit_relegation_link_overrides_tier_pairing() {
+        let mut top = league_with_group(10, 1, 0, 3, None, "");
+        top.settings.relegation_league_id = Some(30);
+        let leagues = vec![
+            top,
+            league_with_group(20, 2, 2, 0, None, ""),
+            league_with_group(30, 2, 3, 0, None, ""),
+        ];
+        assert_eq!(
+            CountryResult::paired_promotion_league(&leagues, 10, 1),
+            Some((30, 3))
+        );
+    }
+
     fn build_country(clubs: Vec<Club>, leagues: Vec<League>) -> Country {
         Country::builder()
             .id(1)
@@ -3186,6 +3262,8 @@ mod tests {
                 relegation_spots: rel,
                 league_group: None,
                 split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
             },
             false,
         );
@@ -3334,6 +3412,62 @@ mod tests {
         }
     }
 
+    #[test]
+    fn promoted_club_sub_teams_follow_into_existing_sub_leagues() {
+        // Club 20 wins promotion from tier 2 with a U19, a U21 and a B
+        // side. Only the U19 has a sub-league at tier 1.
+        let sub_team = |id: u32, league_id: u32, team_type: TeamType| {
+            let mut team = make_simple_team(id, 20, league_id);
+            team.team_type = team_type;
+            team
+        };
+        let sub_league = |id: u32| {
+            let mut league = make_league_with_settings(id, 99, 0, 0, Vec::new());
+            league.friendly = true;
+            league.final_table = None;
+            league
+        };
+        let clubs = vec![
+            make_club(10, vec![make_simple_team(10, 10, 1)]),
+            make_club(
+                20,
+                vec![
+                    make_simple_team(20, 20, 2),
+                    sub_team(201, 110_002, TeamType::U19),
+                    sub_team(202, 130_002, TeamType::U21),
+                    sub_team(203, 2, TeamType::B),
+                ],
+            ),
+        ];
+        let leagues = vec![
+            make_league_with_settings(1, 1, 0, 1, vec![(10, 30, 20)]),
+            make_league_with_settings(2, 2, 1, 0, vec![(20, 30, 80)]),
+            sub_league(110_001),
+            sub_league(110_002),
+            sub_league(130_002),
+        ];
+        let mut country = build_country(clubs, leagues);
+
+        CountryResult::process_promotion_relegation(&mut country, d(2032, 6, 1));
+
+        let league_of = |team_id: u32| {
+            country
+                .clubs
+                .iter()
+                .flat_map(|c| c.teams.iter())
+                .find(|t| t.id == team_id)
+                .and_then(|t| t.league_id)
+        };
+        assert_eq!(league_of(20), Some(1));
+        assert_eq!(league_of(201), Some(110_001));
+        // No U21 sub-league at tier 1: the side keeps its fixtures.
+        assert_eq!(league_of(202), Some(130_002));
+        // B teams play in a real division and don't move with the club.
+        assert_eq!(league_of(203), Some(2));
+        // The relegated club had no sub-teams to move.
+        assert_eq!(league_of(10), Some(2));
+    }
+
     // ── Parent-side loan renewals ─────────────────────────────────
 
     /// Build a player with a permanent contract and an active loan
@@ -3609,6 +3743,8 @@ mod tests {
             relegation_spots: 0,
             league_group: None,
             split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
         let mut league = League::new(
             cup_id,
@@ -3828,6 +3964,8 @@ mod tests {
             relegation_spots: 0,
             league_group: None,
             split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
         let mut league_cup = League::new(
             800_000_004,
@@ -4054,6 +4192,7 @@ mod tests {
                 away_shootout: 0,
             },
             friendly: false,
+            attendance: 0,
         };
         cup.league.matches.push(mr, final_date);
 
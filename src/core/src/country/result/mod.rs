@@ -116,11 +116,15 @@ impl CountryResult {
             // via Self::process_player_retirements when the season ends.
 
             // Apply per-country squad-registration rules — foreign-player
-            // limits drop the weakest non-domestic surplus, and the
+            // limits and the named list drop the surplus, and the
             // omitted players receive the SquadRegistrationOmitted
-            // happiness event. Runs once on the season-start tick so
-            // the registration is stable for the rest of the campaign.
+            // happiness event. Re-filed at each registration deadline
+            // below; stable in between.
             Self::enforce_squad_registration(data, self.country_id, current_date);
+        } else if Self::is_registration_deadline(data, country_id, current_date) {
+            Self::enforce_squad_registration(data, country_id, current_date);
+        } else {
+            Self::hold_late_arrivals(data, country_id, current_date);
         }
 
         // Phase 2: Club result processing was driven from
@@ -1,13 +1,223 @@
 use super::CountryResult;
 use crate::Country;
-use chrono::NaiveDate;
+use crate::club::PreseasonFriendly;
+use crate::league::schedule::calendar;
+use crate::utils::IntegerUtils;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use std::collections::HashMap;
+
+/// How far before the season opener the friendly programme starts.
+const PRESEASON_WINDOW_DAYS: i64 = 35;
+/// Clear days kept between the last friendly and the opener.
+const OPENER_REST_DAYS: i64 = 4;
+/// Widest reputation gap (0..10000 market score) for a sensible friendly.
+const FRIENDLY_REPUTATION_BAND: u16 = 2500;
+/// Gap at which the bigger side is a draw: the host sells a paying crowd
+/// and the visitor collects an appearance fee.
+const PRESTIGE_GAP: u16 = 1500;
+/// Match readiness (0–20) a friendly restores, on top of daily training.
+const FRIENDLY_READINESS_GAIN: f32 = 2.0;
+
+/// One side of the friendly-arranging pass.
+#[derive(Debug, Clone)]
+pub(crate) struct FriendlySeeker {
+    pub club_id: u32,
+    pub reputation: u16,
+    pub target: u8,
+    pub booked: Vec<NaiveDate>,
+    pub opponents: Vec<u32>,
+}
+
+impl FriendlySeeker {
+    fn needs(&self) -> bool {
+        self.booked.len() < self.target as usize
+    }
+
+    fn is_free(&self, date: NaiveDate) -> bool {
+        !self
+            .booked
+            .iter()
+            .any(|b| calendar::violates_rest(*b, date))
+    }
+}
+
+/// Pair clubs into friendlies over `slots`. Clubs take turns (strongest
+/// first) booking one fixture per pass against the closest-reputation
+/// opponent that still wants games, so every club fills its programme
+/// evenly rather than the first few hoovering up the calendar. The lower
+/// reputation side hosts — big clubs tour. Returns `(date, home, away)`.
+pub(crate) fn plan_preseason_friendlies(
+    seekers: &mut [FriendlySeeker],
+    slots: &[NaiveDate],
+) -> Vec<(NaiveDate, u32, u32)> {
+    seekers.sort_by(|a, b| {
+        b.reputation
+            .cmp(&a.reputation)
+            .then(a.club_id.cmp(&b.club_id))
+    });
+    let mut fixtures = Vec::new();
+    loop {
+        let mut booked_any = false;
+        for i in 0..seekers.len() {
+            if !seekers[i].needs() {
+                continue;
+            }
+            let own = &seekers[i];
+            let mut candidates: Vec<usize> = (0..seekers.len())
+                .filter(|&j| j != i)
+                .filter(|&j| {
+                    let other = &seekers[j];
+                    other.needs()
+                        && !own.opponents.contains(&other.club_id)
+                        && own.reputation.abs_diff(other.reputation) <= FRIENDLY_REPUTATION_BAND
+                })
+                .collect();
+            candidates.sort_by_key(|&j| (own.reputation.abs_diff(seekers[j].reputation), j));
+
+            let booking = candidates.into_iter().find_map(|j| {
+                slots
+                    .iter()
+                    .find(|d| seekers[i].is_free(**d) && seekers[j].is_free(**d))
+                    .map(|d| (j, *d))
+            });
+            let Some((j, date)) = booking else {
+                continue;
+            };
+
+            let (home, away) = if seekers[j].reputation < seekers[i].reputation {
+                (j, i)
+            } else {
+                (i, j)
+            };
+            fixtures.push((date, seekers[home].club_id, seekers[away].club_id));
+            let (a, b) = (seekers[i].club_id, seekers[j].club_id);
+            seekers[i].booked.push(date);
+            seekers[i].opponents.push(b);
+            seekers[j].booked.push(date);
+            seekers[j].opponents.push(a);
+            booked_any = true;
+        }
+        if !booked_any {
+            return fixtures;
+        }
+    }
+}
+
+/// Saturdays and Wednesdays in the run-up to `season_start` that a
+/// friendly may use, starting no earlier than `from`.
+pub(crate) fn preseason_slots(from: NaiveDate, season_start: NaiveDate) -> Vec<NaiveDate> {
+    let first = from.max(season_start - Duration::days(PRESEASON_WINDOW_DAYS));
+    let last = season_start - Duration::days(OPENER_REST_DAYS);
+    let mut slots = Vec::new();
+    let mut date = first;
+    while date <= last {
+        if matches!(date.weekday(), Weekday::Sat | Weekday::Wed) && !calendar::is_blocked_date(date)
+        {
+            slots.push(date);
+        }
+        date += Duration::days(1);
+    }
+    slots
+}
 
 impl CountryResult {
     pub(crate) fn simulate_preseason_activities(country: &mut Country, date: NaiveDate) {
+        Self::arrange_preseason_friendlies(country, date);
+        Self::play_preseason_friendlies(country, date);
         Self::run_training_camps(country, date);
         Self::run_preseason_conditioning(country, date);
     }
 
+    /// Once per off-season, every club with a senior side books 3–5
+    /// friendlies against domestic opponents of comparable standing.
+    fn arrange_preseason_friendlies(country: &mut Country, date: NaiveDate) {
+        let season_start = country.season_dates().next_start(date);
+        if country
+            .clubs
+            .iter()
+            .all(|c| c.preseason.season_start == Some(season_start))
+        {
+            return;
+        }
+
+        let mut seekers: Vec<FriendlySeeker> = Vec::new();
+        for club in &mut country.clubs {
+            if club.preseason.season_start == Some(season_start) {
+                continue;
+            }
+            club.preseason
+                .reset(season_start, IntegerUtils::random(3, 6) as u8);
+            if let Some(team) = club.teams.main() {
+                seekers.push(FriendlySeeker {
+                    club_id: club.id,
+                    reputation: team.reputation.market_value_score(),
+                    target: club.preseason.target,
+                    booked: Vec::new(),
+                    opponents: Vec::new(),
+                });
+            }
+        }
+
+        let slots = preseason_slots(date + Duration::days(1), season_start);
+        for (day, home, away) in plan_preseason_friendlies(&mut seekers, &slots) {
+            for (club_id, opponent_club_id, is_home) in [(home, away, true), (away, home, false)] {
+                if let Some(club) = country.clubs.iter_mut().find(|c| c.id == club_id) {
+                    club.preseason.friendlies.push(PreseasonFriendly {
+                        date: day,
+                        opponent_club_id,
+                        home: is_home,
+                        played: false,
+                    });
+                }
+            }
+        }
+    }
+
+    /// Today's friendlies: minutes against real opposition rebuild match
+    /// sharpness faster than training alone, and a prestige fixture puts
+    /// money in the bank — a paying crowd for the host, an appearance fee
+    /// for the visiting draw.
+    fn play_preseason_friendlies(country: &mut Country, date: NaiveDate) {
+        let reputations: HashMap<u32, u16> = country
+            .clubs
+            .iter()
+            .filter_map(|c| {
+                c.teams
+                    .main()
+                    .map(|t| (c.id, t.reputation.market_value_score()))
+            })
+            .collect();
+
+        for club in &mut country.clubs {
+            let Some(friendly) = club.preseason.take_due(date) else {
+                continue;
+            };
+            let Some(team) = club.teams.main_mut() else {
+                continue;
+            };
+            for player in &mut team.players.players {
+                if player.player_attributes.is_injured {
+                    continue;
+                }
+                player.skills.physical.match_readiness =
+                    (player.skills.physical.match_readiness + FRIENDLY_READINESS_GAIN).min(20.0);
+            }
+
+            let own = reputations.get(&club.id).copied().unwrap_or(0);
+            let opponent = reputations
+                .get(&friendly.opponent_club_id)
+                .copied()
+                .unwrap_or(0);
+            if friendly.home && opponent >= own.saturating_add(PRESTIGE_GAP) {
+                club.finance.record_home_match(prestige_crowd(opponent));
+            } else if !friendly.home && own >= opponent.saturating_add(PRESTIGE_GAP) {
+                club.finance
+                    .balance
+                    .push_income_matchday(appearance_fee(own));
+            }
+        }
+    }
+
     /// Training camps: boost player condition and match readiness during off-season.
     /// Simulates daily training sessions that prepare players for the new season.
     fn run_training_camps(country: &mut Country, _date: NaiveDate) {
@@ -56,3 +266,66 @@ impl CountryResult {
         }
     }
 }
+
+/// Crowd a visiting big club draws to a pre-season friendly.
+fn prestige_crowd(visitor_reputation: u16) -> u32 {
+    (visitor_reputation as u32 * 3).min(40_000)
+}
+
+/// Fee a big club charges to tour a smaller ground.
+fn appearance_fee(reputation: u16) -> i64 {
+    reputation as i64 * 25
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn seeker(club_id: u32, reputation: u16, target: u8) -> FriendlySeeker {
+        FriendlySeeker {
+            club_id,
+            reputation,
+            target,
+            booked: Vec::new(),
+            opponents: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn slots_stop_short_of_the_opener() {
+        let slots = preseason_slots(d(2026, 6, 1), d(2026, 8, 15));
+        assert!(slots.iter().all(|s| *s >= d(2026, 7, 11)));
+        assert!(slots.iter().all(|s| *s <= d(2026, 8, 11)));
+        assert!(slots.len() >= 8);
+    }
+
+    #[test]
+    fn every_club_books_a_programme_against_comparable_sides() {
+        let slots = preseason_slots(d(2026, 6, 1), d(2026, 8, 15));
+        let mut seekers: Vec<FriendlySeeker> = (1..=8)
+            .map(|id| seeker(id, 2000 + id as u16 * 250, 3 + (id % 3) as u8))
+            .collect();
+        let fixtures = plan_preseason_friendlies(&mut seekers, &slots);
+
+        for s in &seekers {
+            assert!(s.booked.len() >= 3, "club {}", s.club_id);
+            assert!(s.booked.len() <= s.target as usize, "club {}", s.club_id);
+            let mut dates = s.booked.clone();
+            dates.sort();
+            assert!(
+                dates
+                    .windows(2)
+                    .all(|p| !calendar::violates_rest(p[0], p[1]))
+            );
+        }
+        let rep = |id: u32| 2000 + id as u16 * 250;
+        for (_, home, away) in &fixtures {
+            assert!(rep(*home).abs_diff(rep(*away)) <= FRIENDLY_REPUTATION_BAND);
+            assert!(rep(*home) <= rep(*away), "smaller side hosts");
+        }
+    }
+}
@@ -1,59 +1,56 @@
-//! Per-season squad registration enforcement.
+//! Squad registration enforcement.
 //!
-//! Runs once on the season-start tick. For each club in the country,
-//! we apply the country's `foreign_player_limit` to the main team's
-//! roster — the weakest excess foreigners get marked as
-//! Unregistered (`PlayerStatusType::Unr`) and receive the
-//! `SquadRegistrationOmitted` happiness event so the player feed
-//! reflects the snub.
+//! Runs on the season-start tick and again at each registration
+//! deadline — the day after a transfer window shuts. For each club in
+//! the country we re-file the main team's registered squad: the
+//! foreign-player limit drops the weakest excess foreigners, and the
+//! named-list rule keeps the list to its size with homegrown slots held
+//! back. Players left off are marked Unregistered
+//! (`PlayerStatusType::Unr`) and receive the `SquadRegistrationOmitted`
+//! happiness event; players back on the list lose the mark. Between
+//! deadlines, late arrivals (free agents) wait for the next one.
 //!
-//! Salary cap and homegrown requirements are surfaced as warnings via
-//! the `CountryRegulations` helpers but not yet auto-fixed at the
-//! squad level — they tie into transfer-time enforcement and the FFP
-//! lifecycle, both of which live closer to the financial pipeline.
+//! Salary cap is surfaced via the `CountryRegulations` helpers but not
+//! yet auto-fixed at the squad level — it ties into transfer-time
+//! enforcement and the FFP lifecycle, which live closer to the
+//! financial pipeline.
 
 use super::CountryResult;
 use crate::club::HappinessEventType;
 use crate::simulator::SimulatorData;
+use crate::transfers::TransferWindowManager;
 use crate::{
     Player, PlayerStatusType, RegulationEventContext, RegulationOutcomeKind, RegulationSlotKind,
 };
-use chrono::NaiveDate;
+use chrono::{Duration, NaiveDate};
 use log::debug;
 use rayon::prelude::*;
 
 impl CountryResult {
-    /// Walk every club in `country_id`'s main team and drop the
-    /// weakest foreign surplus from the registered squad. The omitted
-    /// player gets:
-    ///   * `PlayerStatusType::Unr` added to their statuses (squad
-    ///     selection already filters Unr out via the existing status
-    ///     gate).
-    ///   * A `SquadRegistrationOmitted` happiness event so the
-    ///     feedback is durable in the player history.
+    /// Re-file every club's main-team registration. Omitted players
+    /// get:
+    ///   * `PlayerStatusType::Unr` added to their statuses (matchday
+    ///     squad selection leaves Unr players out of competitive games).
+    ///   * A `SquadRegistrationOmitted` happiness event the first time
+    ///     they drop off, so the feedback is durable in the player
+    ///     history.
     pub(super) fn enforce_squad_registration(
         data: &mut SimulatorData,
         country_id: u32,
         date: NaiveDate,
     ) {
-        // Snapshot the country's foreign-limit configuration up front
-        // so we can release the read borrow before mutating clubs.
-        let (foreign_limit, club_country_id) = match data.country(country_id) {
-            Some(c) => (c.regulations.foreign_player_limit, c.id),
-            None => return,
+        let Some(country) = data.country_mut(country_id) else {
+            return;
         };
-        if foreign_limit.is_none() {
+        if !country.regulations.has_squad_rules() {
             // No rule configured — nothing to enforce.
             return;
         }
 
-        let Some(country) = data.country_mut(country_id) else {
-            return;
-        };
-
         // Split the country borrow: regulations are shared (`&`) across
         // workers, clubs get the mutable iter so each rayon worker
         // mutates only its own club's main-team roster.
+        let club_country_id = country.id;
         let regulations = &country.regulations;
         country.clubs.par_iter_mut().for_each(|club| {
             // Only the main team is registered with the league. Reserve /
@@ -62,28 +59,35 @@ impl CountryResult {
                 return;
             };
             let player_refs: Vec<&Player> = main_team.players.players.iter().collect();
-            let omitted_ids = regulations.omitted_for_foreign_limit(&player_refs, club_country_id);
+            let mut omitted = regulations.omitted_for_foreign_limit(&player_refs, club_country_id);
+            let foreign_omitted = omitted.len();
+            for id in regulations.omitted_for_squad_list(&player_refs, club_country_id, date) {
+                if !omitted.contains(&id) {
+                    omitted.push(id);
+                }
+            }
             drop(player_refs);
 
-            if omitted_ids.is_empty() {
-                return;
-            }
             debug!(
-                "📋 Squad registration: club {} omits {} foreign players",
+                "📋 Squad registration: club {} omits {} players",
                 club.id,
-                omitted_ids.len()
+                omitted.len()
             );
             for player in &mut main_team.players.players {
-                if !omitted_ids.contains(&player.id) {
+                if !omitted.contains(&player.id) {
+                    player.statuses.remove(PlayerStatusType::Unr);
                     continue;
                 }
-                if !player.statuses.has(PlayerStatusType::Unr) {
-                    player.statuses.add(date, PlayerStatusType::Unr);
+                if player.statuses.has(PlayerStatusType::Unr) {
+                    continue;
                 }
-                let ctx = RegulationEventContext::new(
-                    RegulationOutcomeKind::Omitted,
-                    RegulationSlotKind::NonEuQuota,
-                );
+                player.statuses.add(date, PlayerStatusType::Unr);
+                let slot = if omitted[..foreign_omitted].contains(&player.id) {
+                    RegulationSlotKind::NonEuQuota
+                } else {
+                    RegulationSlotKind::SeniorSquadCap
+                };
+                let ctx = RegulationEventContext::new(RegulationOutcomeKind::Omitted, slot);
                 player.on_registration_event(
                     HappinessEventType::SquadRegistrationOmitted,
                     ctx,
@@ -91,8 +95,62 @@ impl CountryResult {
                 );
             }
         });
-        // Suppress unused-variable warning when the path stays generic.
-        let _ = foreign_limit;
+    }
+
+    /// Between deadlines the named list is locked: a player who joined
+    /// after the last window shut (a free agent, typically) sits out
+    /// competitive games until the next deadline files him.
+    pub(super) fn hold_late_arrivals(data: &mut SimulatorData, country_id: u32, date: NaiveDate) {
+        let Some(country) = data.country_mut(country_id) else {
+            return;
+        };
+        if country.regulations.registered_squad_size.is_none() {
+            return;
+        }
+        let windows = TransferWindowManager::for_country(country, date);
+        if windows.is_window_open(country_id, date) {
+            return;
+        }
+        let Some(window) = windows.windows.get(&country_id) else {
+            return;
+        };
+        let Some(last_deadline) = [window.summer_window.1, window.winter_window.1]
+            .into_iter()
+            .filter(|end| *end < date)
+            .max()
+        else {
+            return;
+        };
+
+        for club in &mut country.clubs {
+            let Some(main_team) = club.teams.main_mut() else {
+                continue;
+            };
+            for player in &mut main_team.players.players {
+                let late = player
+                    .last_transfer_date
+                    .is_some_and(|joined| joined > last_deadline);
+                if late && !player.statuses.has(PlayerStatusType::Unr) {
+                    player.statuses.add(date, PlayerStatusType::Unr);
+                }
+            }
+        }
+    }
+
+    /// True on a registration deadline: the day after one of the
+    /// country's transfer windows closes.
+    pub(super) fn is_registration_deadline(
+        data: &SimulatorData,
+        country_id: u32,
+        date: NaiveDate,
+    ) -> bool {
+        let Some(country) = data.country(country_id) else {
+            return false;
+        };
+        let yesterday = date - Duration::days(1);
+        TransferWindowManager::for_country(country, yesterday)
+            .current_window_dates(country_id, yesterday)
+            .is_some_and(|(_, end)| end == yesterday)
     }
 }
 
@@ -203,6 +261,8 @@ mod tests {
                 relegation_spots: 0,
                 league_group: None,
                 split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
             },
             false,
         );
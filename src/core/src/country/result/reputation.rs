@@ -19,11 +19,15 @@ impl CountryResult {
         }
     }
 
-    pub(crate) fn simulate_media_coverage(country: &mut Country, league_results: &[LeagueResult]) {
+    pub(crate) fn simulate_media_coverage(
+        country: &mut Country,
+        league_results: &[LeagueResult],
+        date: NaiveDate,
+    ) {
         country.media_coverage.update_from_results(league_results);
         country
             .media_coverage
-            .generate_weekly_stories(&country.clubs);
+            .generate_weekly_stories(&country.clubs, date);
     }
 
     pub(crate) fn update_country_reputation(country: &mut Country) {
@@ -1,4 +1,4 @@
-use super::types::{DeferredTransfer, can_club_accept_player};
+use super::types::{DeferredTransfer, can_club_accept_player, signing_restriction};
 use crate::club::Person;
 use crate::club::player::calculators::WageCalculator;
 use crate::club::player::events::{LoanCompletion, TransferCompletion};
@@ -470,6 +470,20 @@ pub(crate) fn execute_transfer(
             return false;
         }
     }
+    // Nationality rules of the buying country: the final say for every
+    // path, including cross-country deals the medical couldn't check.
+    let restriction = data.player(player_id).and_then(|player| {
+        let country = data.country(buying_country_id)?;
+        let club = country.clubs.iter().find(|c| c.id == buying_club_id)?;
+        signing_restriction(country, club, player)
+    });
+    if let Some(restriction) = restriction {
+        debug!(
+            "Blocked by signing rules: player {} to club {} ({:?})",
+            player_id, buying_club_id, restriction
+        );
+        return false;
+    }
     // Sell-on payouts whose beneficiary lives outside the transacting
     // country (the player was originally bought from abroad, then resold
     // domestically). The within-country executor debits the seller and
@@ -1815,11 +1829,7 @@ fn build_loan_contract(
     )
     .with_loan_match_fee(match_fee)
     .with_loan_wage_contribution(contribution_pct)
-    .with_loan_recall(
-        loan_end
-            .checked_sub_signed(Duration::days(90))
-            .unwrap_or(loan_end),
-    )
+    .with_loan_recall(loan_recall_date(signing_date, loan_end))
     .with_loan_min_appearances(min_apps);
 
     if let Some((future_fee, obligation)) = loan_future_fee {
@@ -1829,6 +1839,20 @@ fn build_loan_contract(
     contract
 }
 
+/// First date the parent may exercise the recall clause. Season-long
+/// loans open it at the midpoint of the spell — the January window — so
+/// a parent hit by an injury crisis can bring the player back while the
+/// season is still live (`process_loan_recalls`). Short spells fall back
+/// to 90 days before the end; never earlier than the signing itself.
+fn loan_recall_date(signing_date: NaiveDate, loan_end: NaiveDate) -> NaiveDate {
+    let half_spell = Duration::days((loan_end - signing_date).num_days().max(0) / 2);
+    let midpoint = signing_date + half_spell;
+    let late_window = loan_end
+        .checked_sub_signed(Duration::days(90))
+        .unwrap_or(loan_end);
+    midpoint.min(late_window).max(signing_date)
+}
+
 fn return_player_to_selling_country(
     data: &mut SimulatorData,
     selling_country_id: u32,
@@ -2401,6 +2425,8 @@ mod country_pair_execution_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             )
@@ -2800,6 +2826,8 @@ mod development_pathway_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
@@ -2881,6 +2909,8 @@ mod development_pathway_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
@@ -2947,6 +2977,8 @@ mod development_pathway_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
@@ -3612,6 +3644,8 @@ mod loan_history_source_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             )
@@ -3817,3 +3851,28 @@ mod loan_history_source_tests {
         );
     }
 }
+
+#[cfg(test)]
+mod loan_recall_clause_tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn season_loan_recall_opens_in_january() {
+        let recall = loan_recall_date(d(2026, 7, 15), d(2027, 6, 30));
+        assert_eq!(recall, d(2027, 1, 6));
+    }
+
+    #[test]
+    fn short_loan_keeps_the_late_window() {
+        // January-to-June loan: 90 days before the end comes before
+        // the midpoint. A two-month spell floors at the signing date.
+        let recall = loan_recall_date(d(2027, 1, 31), d(2027, 6, 30));
+        assert_eq!(recall, d(2027, 4, 1));
+        let short = loan_recall_date(d(2027, 3, 1), d(2027, 5, 1));
+        assert_eq!(short, d(2027, 3, 1));
+    }
+}
@@ -7,7 +7,9 @@ use super::free_agent_depth::{
 use super::free_agent_market_calc::{
     BuyerRoleFit, FreeAgentMarketCalculator, FreeAgentOfferPricing,
 };
-use super::types::{TransferActivitySummary, can_club_accept_player, find_player_in_country};
+use super::types::{
+    TransferActivitySummary, can_club_accept_player, find_player_in_country, signing_restriction,
+};
 use crate::club::player::contract::RENEWAL_OFFERED_LABEL;
 use crate::club::player::mailbox::handlers::contract_proposal::ProcessContractHandler;
 use crate::club::player::transfer::{FreeAgentBlockReason, MarketStage};
@@ -3096,6 +3098,21 @@ pub(crate) fn execute_global_free_agent_signing(
             None => return false,
         };
 
+    // Pre-check 3: the buying country's nationality rules.
+    let restricted = data
+        .country(signing.buying_country_id)
+        .and_then(|country| {
+            let club = country
+                .clubs
+                .iter()
+                .find(|c| c.id == signing.buying_club_id)?;
+            signing_restriction(country, club, &data.free_agents[player_idx])
+        })
+        .is_some();
+    if restricted {
+        return false;
+    }
+
     // All pre-checks passed — take the player out of the pool.
     let mut player = data.free_agents.swap_remove(player_idx);
 
@@ -3316,6 +3333,8 @@ mod emergency_fill_tests {
                         relegation_spots: 0,
                         league_group: None,
                         split_season: false,
+                        promotion_playoff_spots: 0,
+                        relegation_league_id: None,
                     },
                     false,
                 )]))
@@ -3408,6 +3427,8 @@ mod emergency_fill_tests {
                         relegation_spots: 0,
                         league_group: None,
                         split_season: false,
+                        promotion_playoff_spots: 0,
+                        relegation_league_id: None,
                     },
                     false,
                 )]))
@@ -6079,6 +6100,8 @@ mod expiry_renewal_tests {
                         relegation_spots: 0,
                         league_group: None,
                         split_season: false,
+                        promotion_playoff_spots: 0,
+                        relegation_league_id: None,
                     },
                     false,
                 )]))
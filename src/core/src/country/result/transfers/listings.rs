@@ -48,6 +48,7 @@ impl CountryResult {
     ) {
         let mut listings_to_add: Vec<PendingListing> = Vec::new();
         let price_level = country.settings.pricing.price_level;
+        let market_index = country.transfer_market.market_index;
         let window_mgr = TransferWindowManager::for_country(country, date);
         let current_window = window_mgr.current_window_dates(country.id, date);
 
@@ -99,7 +100,7 @@ impl CountryResult {
                             player,
                             club,
                             date,
-                            price_level,
+                            market_index.price_level(price_level, player),
                             league_reputation,
                             club_reputation,
                         );
@@ -200,7 +201,7 @@ impl CountryResult {
                         player,
                         club,
                         date,
-                        price_level,
+                        market_index.price_level(price_level, player),
                         league_reputation,
                         club_reputation,
                     );
@@ -1395,6 +1396,8 @@ mod tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
@@ -9,12 +9,13 @@ mod negotiations;
 mod pre_contract;
 pub(crate) mod settlement;
 pub(crate) mod types;
+mod user_negotiations;
 
 use super::CountryResult;
 use crate::Country;
 use crate::club::player::transfer::FreeAgentBlockReason;
 use crate::simulator::SimulatorData;
-use crate::transfers::TransferWindowManager;
+use crate::transfers::{MarketIndex, TransferCalendar, TransferWindowManager};
 use crate::transfers::pipeline::{PipelineProcessor, PlayerSummary};
 use chrono::NaiveDate;
 use config::TransferConfig;
@@ -37,6 +38,8 @@ use types::TransferActivitySummary;
 /// or `data.free_agents` stay serial.
 pub struct DeferredTransferOps {
     pub country_id: u32,
+    /// True while clubs may open negotiations — the registration window
+    /// or the pre-open talks band before it.
     pub window_open: bool,
     /// Per-country sweep targets — every domestic signing in Phase A
     /// needs `cleanup_player_transfer_interest` to run against *every*
@@ -149,6 +152,10 @@ impl CountryResult {
         let mut summary = TransferActivitySummary::new();
         let window_manager = TransferWindowManager::for_country(country, current_date);
         let window_open = window_manager.is_window_open(country_id, current_date);
+        // Clubs start talking a fortnight before registration opens; deals
+        // agreed in that band are held at the medical until the opening.
+        let talks_open =
+            window_open || TransferCalendar::days_to_opening(country, current_date).is_some();
         let config = TransferConfig::default();
 
         // Filter foreign players from the pre-built world snapshot.
@@ -156,7 +163,7 @@ impl CountryResult {
         // world pool minus its own players (three `String`s per summary),
         // which dominated the per-country transfer pass. References into
         // the shared snapshot cost nothing.
-        let foreign_players: Vec<&PlayerSummary> = if window_open {
+        let foreign_players: Vec<&PlayerSummary> = if talks_open {
             world_pool
                 .iter()
                 .filter(|s| s.country_id != country_id)
@@ -167,18 +174,22 @@ impl CountryResult {
 
         let completed_before = summary.completed_transfers;
         let mut ops = DeferredTransferOps::empty(country_id);
-        ops.window_open = window_open;
+        ops.window_open = talks_open;
         ops.completed_before = completed_before;
 
         // Detect the open→closed transition BEFORE syncing the flag —
         // the market's stored flag still holds yesterday's state.
         let window_just_closed = country.transfer_market.transfer_window_open && !window_open;
+        let window_just_opened = !country.transfer_market.transfer_window_open && window_open;
 
         // Sync market's window flag. Listings and negotiations survive
         // the close (the listing rows are the durable "still for sale"
         // clock); the just-closed beat below tells unsold listed
         // players their limbo is real until the next window.
         country.transfer_market.check_transfer_window(window_open);
+        if window_just_opened {
+            MarketIndex::recalibrate(country, current_date);
+        }
         if window_just_closed {
             Self::emit_window_close_limbo(country, current_date);
         }
@@ -233,8 +244,8 @@ impl CountryResult {
         // pre-contract is legal year-round inside the six-month window.
         PreContractManager::stage(country, current_date, &config);
 
-        if window_open {
-            debug!("Transfer window is OPEN - simulating pipeline-driven market activity");
+        if talks_open {
+            debug!("Transfer market is OPEN - simulating pipeline-driven market activity");
             Self::list_players_from_pipeline(country, current_date, &mut summary);
             PipelineProcessor::evaluate_squads(country, current_date);
             PipelineProcessor::generate_staff_recommendations(country, current_date);
@@ -477,7 +488,11 @@ impl CountryResult {
         let deferred_transfers = if let Some(country) = data.country_mut(country_id) {
             // Sync market's window flag. On open→closed transitions this cancels
             // any stranded listings and expires pending negotiations.
+            let window_just_opened = !country.transfer_market.transfer_window_open && window_open;
             country.transfer_market.check_transfer_window(window_open);
+            if window_just_opened {
+                MarketIndex::recalibrate(country, current_date);
+            }
 
             // Resolve pending negotiations — returns all completed transfers for deferred execution
             let outcomes = Self::resolve_pending_negotiations(country, current_date, &mut summary);
@@ -764,6 +779,8 @@ mod side_channel_tests {
                         relegation_spots: 0,
                         league_group: None,
                         split_season: false,
+                        promotion_playoff_spots: 0,
+                        relegation_league_id: None,
                     },
                     false,
                 )]))
@@ -3,7 +3,7 @@ use super::free_agent_market_calc::BuyerRoleFit;
 use super::free_agents::{EmergencySignedTerms, GlobalFreeAgentSigning};
 use super::types::{
     DeferredTransfer, NegotiationData, TransferActivitySummary, find_player_in_country,
-    find_player_in_country_mut,
+    find_player_in_country_mut, signing_restriction,
 };
 use crate::club::player::agent::PlayerAgent;
 use crate::club::player::calculators::{
@@ -15,13 +15,14 @@ use crate::club::player::events::transfer_social::{
 use crate::club::team::squad::{SquadAssetClass, SquadAssetProtection};
 use crate::country::result::CountryResult;
 use crate::transfers::NegotiationStatus;
+use crate::transfers::TransferCalendar;
 use crate::transfers::TransferListingStatus;
 use crate::transfers::TransferListingType;
 use crate::transfers::TransferRoutePolicy;
 use crate::transfers::TransferWindowManager;
 use crate::transfers::market::TransferListingOrigin;
 use crate::transfers::negotiation::{
-    NegotiationPhase, NegotiationRejectionReason, TransferNegotiation,
+    NegotiationPhase, NegotiationRejectionReason, TransferNegotiation, UserNegotiationStep,
 };
 use crate::transfers::offer::{PersonalTermsOffer, PromisedSquadStatus, TransferClause};
 use crate::transfers::pipeline::plausibility::{
@@ -213,6 +214,10 @@ impl CountryResult {
                 None => continue,
             };
 
+            if Self::resolve_user_negotiation(country, neg_id, &neg_data, date) {
+                continue;
+            }
+
             match neg_data.phase {
                 NegotiationPhase::InitialApproach { .. } => {
                     Self::resolve_initial_approach(country, neg_id, &neg_data, date);
@@ -759,6 +764,9 @@ impl CountryResult {
                 })
                 .unwrap_or(true);
 
+            let user_buyer = country
+                .transfer_market
+                .is_user_club(neg_data.buying_club_id);
             let mut buyer_walks = false;
             if let Some(negotiation) = country.transfer_market.negotiations.get_mut(&neg_id) {
                 let reservation_mult = seller_reservation.clamp(1.0, 1.55);
@@ -770,6 +778,14 @@ impl CountryResult {
                 if let Some(f) = &floor {
                     target = target.max(f.min_fee);
                 }
+                if user_buyer {
+                    // The user's club runs its own escalation: park the
+                    // talks with the seller's price on the table.
+                    negotiation.user_step = Some(UserNegotiationStep::AwaitingUserBid {
+                        fee: FormattingUtils::round_fee(target),
+                    });
+                    return;
+                }
                 let escalation = 0.45 + urgency * 0.25;
                 let current_amount = negotiation.current_offer.base_fee.amount;
                 let mut new_amount = FormattingUtils::round_fee(
@@ -1247,6 +1263,21 @@ impl CountryResult {
         if round < MAX_WAGE_ROUNDS {
             if let Some((reservation, offered)) = wage_negotiable {
                 if offered < reservation * 0.98 {
+                    if country
+                        .transfer_market
+                        .is_user_club(neg_data.buying_club_id)
+                    {
+                        // The user decides whether to meet the wage demand.
+                        if let Some(negotiation) =
+                            country.transfer_market.negotiations.get_mut(&neg_id)
+                        {
+                            negotiation.user_step =
+                                Some(UserNegotiationStep::AwaitingUserContract {
+                                    wage: reservation.round() as u32,
+                                });
+                        }
+                        return;
+                    }
                     let improved = (offered + (reservation - offered) * 0.6).min(reservation);
                     let improved = improved.round() as u32;
                     if improved as f64 > offered {
@@ -1298,6 +1329,19 @@ impl CountryResult {
         // and the club-to-club execution path don't apply.
         let is_pool_free_agent = !is_foreign && neg_data.selling_club_id == 0;
 
+        // Pre-window talks: terms can be agreed before the window opens,
+        // but the move can't be registered yet. Hold the deal at the
+        // medical until opening day instead of completing or rejecting
+        // it. Out-of-contract signings keep their window-independent path.
+        let days_to_opening = TransferCalendar::days_to_opening(country, date)
+            .filter(|_| !is_pool_free_agent && !Self::signs_free_agent(country, neg_id));
+        if let Some(days) = days_to_opening {
+            if let Some(negotiation) = country.transfer_market.negotiations.get_mut(&neg_id) {
+                negotiation.phase_expiry = date + chrono::Duration::days(days);
+            }
+            return;
+        }
+
         // Cross-country route policy: the plausibility evaluator does not
         // run on cross-country negotiations (it operates inside one
         // country's clubs), so a Russia ↔ Ukraine bid that survived the
@@ -1384,6 +1428,30 @@ impl CountryResult {
                 );
                 return;
             }
+
+            // Permit paperwork is filed at the medical: a non-bloc player
+            // the federation won't clear (or a buyer out of slots) ends
+            // the deal here. Foreign moves are held at execution, where
+            // the player is reachable.
+            let refused = country
+                .clubs
+                .iter()
+                .find(|c| c.id == neg_data.buying_club_id)
+                .zip(find_player_in_country(country, neg_data.player_id))
+                .and_then(|(club, player)| signing_restriction(country, club, player))
+                .is_some();
+            if refused {
+                if let Some(negotiation) = country.transfer_market.negotiations.get_mut(&neg_id) {
+                    negotiation.reject_with_reason(NegotiationRejectionReason::WorkPermitRefused);
+                }
+                PipelineProcessor::on_negotiation_resolved(
+                    country,
+                    neg_data.buying_club_id,
+                    neg_data.player_id,
+                    false,
+                );
+                return;
+            }
         }
 
         let is_injured = if is_foreign {
@@ -1475,28 +1543,21 @@ impl CountryResult {
             // registering weeks past the deadline. Loans and free
             // transfers (out-of-contract players) keep their
             // window-independent paths.
-            if !neg_data.is_loan && !country.transfer_market.transfer_window_open {
-                let signs_free_agent = country
-                    .transfer_market
-                    .negotiations
-                    .get(&neg_id)
-                    .and_then(|n| country.transfer_market.listings.get(n.listing_id as usize))
-                    .map(|l| l.listing_type == TransferListingType::EndOfContract)
-                    .unwrap_or(false);
-                if !signs_free_agent {
-                    if let Some(negotiation) = country.transfer_market.negotiations.get_mut(&neg_id)
-                    {
-                        negotiation.reject_with_reason(NegotiationRejectionReason::WindowClosed);
-                    }
-                    Self::reopen_listing_for_player(country, neg_data.player_id);
-                    PipelineProcessor::on_negotiation_resolved(
-                        country,
-                        neg_data.buying_club_id,
-                        neg_data.player_id,
-                        false,
-                    );
-                    return;
+            if !neg_data.is_loan
+                && !country.transfer_market.transfer_window_open
+                && !Self::signs_free_agent(country, neg_id)
+            {
+                if let Some(negotiation) = country.transfer_market.negotiations.get_mut(&neg_id) {
+                    negotiation.reject_with_reason(NegotiationRejectionReason::WindowClosed);
                 }
+                Self::reopen_listing_for_player(country, neg_data.player_id);
+                PipelineProcessor::on_negotiation_resolved(
+                    country,
+                    neg_data.buying_club_id,
+                    neg_data.player_id,
+                    false,
+                );
+                return;
             }
 
             // Reserve the agreed fee against the buyer's transfer budget the
@@ -1704,6 +1765,17 @@ impl CountryResult {
         }
     }
 
+    /// The negotiation signs an out-of-contract player off an
+    /// end-of-contract listing — a free transfer, legal outside windows.
+    fn signs_free_agent(country: &Country, neg_id: u32) -> bool {
+        country
+            .transfer_market
+            .negotiations
+            .get(&neg_id)
+            .and_then(|n| country.transfer_market.listings.get(n.listing_id as usize))
+            .is_some_and(|l| l.listing_type == TransferListingType::EndOfContract)
+    }
+
     pub(crate) fn calculate_player_importance(
         country: &Country,
         player_id: u32,
@@ -1778,7 +1850,7 @@ impl CountryResult {
     /// True when the offer amount meets a release clause on the player's
     /// current contract. Caller must only consult this for domestic
     /// transfers — we don't carry the foreign contract into NegotiationData.
-    fn clause_triggers_sale(country: &Country, neg_data: &NegotiationData) -> bool {
+    pub(super) fn clause_triggers_sale(country: &Country, neg_data: &NegotiationData) -> bool {
         let player = match find_player_in_country(country, neg_data.player_id) {
             Some(p) => p,
             None => return false,
@@ -1823,6 +1895,7 @@ impl CountryResult {
             seller,
             player,
             neg_data.asking_price.max(neg_data.offer_amount),
+            neg_data.offer_amount,
             neg_data.is_loan,
             neg_data.is_unsolicited,
             date,
@@ -1860,6 +1933,7 @@ impl CountryResult {
             seller,
             player,
             neg_data.asking_price.max(neg_data.offer_amount),
+            neg_data.offer_amount,
             neg_data.is_loan,
             neg_data.is_unsolicited,
             date,
@@ -1873,8 +1947,7 @@ impl CountryResult {
     /// Callers in the country-result transfer flow have a `&Country` in
     /// scope and prefer this.
     pub(crate) fn deadline_urgency_for(country: &Country, date: NaiveDate) -> f32 {
-        let mgr = TransferWindowManager::for_country(country, date);
-        Self::deadline_urgency_from_manager(&mgr, country.id, date)
+        TransferWindowManager::for_country(country, date).deadline_urgency(country.id, date)
     }
 
     /// How close are we to the transfer window slamming shut? 0 when at
@@ -1886,27 +1959,7 @@ impl CountryResult {
     /// reference is in scope.
     #[allow(dead_code)]
     pub(crate) fn deadline_urgency(country_id: u32, date: NaiveDate) -> f32 {
-        let mgr = TransferWindowManager::new();
-        Self::deadline_urgency_from_manager(&mgr, country_id, date)
-    }
-
-    fn deadline_urgency_from_manager(
-        mgr: &TransferWindowManager,
-        country_id: u32,
-        date: NaiveDate,
-    ) -> f32 {
-        let (_, end) = match mgr.current_window_dates(country_id, date) {
-            Some(w) => w,
-            None => return 0.0,
-        };
-        let days_left = (end - date).num_days();
-        if days_left >= 14 {
-            0.0
-        } else if days_left <= 1 {
-            1.0
-        } else {
-            1.0 - (days_left as f32 - 1.0) / 13.0
-        }
+        TransferWindowManager::new().deadline_urgency(country_id, date)
     }
 }
 
@@ -2025,7 +2078,10 @@ impl SellerFeeFloor {
         let market_value = PlayerValueCalculator::calculate(
             player,
             date,
-            country.settings.pricing.price_level,
+            country
+                .transfer_market
+                .market_index
+                .price_level(country.settings.pricing.price_level, player),
             league_rep,
             club_rep,
         );
@@ -2540,6 +2596,8 @@ mod development_pathway_protection_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
@@ -2665,6 +2723,43 @@ mod development_pathway_protection_tests {
             "owner-listed development loan must bypass the protection gate"
         );
     }
+
+    /// Terms agreed in the fortnight before the window opens can't be
+    /// registered yet: the deal waits at the medical until opening day
+    /// rather than completing early or collapsing.
+    #[test]
+    fn pre_window_deal_is_held_at_medical_until_opening() {
+        let mut country = ProtectionFixtures::world(false);
+        let talks_day = ProtectionFixtures::d(2026, 5, 25);
+        country
+            .transfer_market
+            .negotiations
+            .get_mut(&ProtectionFixtures::NEG_ID)
+            .unwrap()
+            .advance_to_medical(talks_day);
+        let neg_data = ProtectionFixtures::neg_data(&country);
+        let mut summary = TransferActivitySummary::new();
+        let mut outcomes = NegotiationOutcomes {
+            deferred: Vec::new(),
+            free_agent_signings: Vec::new(),
+            free_agent_rejected_ids: Vec::new(),
+        };
+
+        CountryResult::resolve_medical(
+            &mut country,
+            1,
+            ProtectionFixtures::NEG_ID,
+            &neg_data,
+            talks_day,
+            &mut summary,
+            &mut outcomes,
+        );
+
+        let neg = &country.transfer_market.negotiations[&ProtectionFixtures::NEG_ID];
+        assert_eq!(neg.status, NegotiationStatus::Pending);
+        assert_eq!(neg.phase_expiry, ProtectionFixtures::d(2026, 6, 1));
+        assert!(outcomes.deferred.is_empty());
+    }
 }
 
 /// Regression coverage for the underpriced-important-player sale (the
@@ -2804,6 +2899,8 @@ mod seller_fee_floor_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
@@ -1,7 +1,10 @@
 use crate::transfers::market::TransferListingOrigin;
 use crate::transfers::negotiation::NegotiationPhase;
 use crate::transfers::offer::{PersonalTermsOffer, TransferClause};
-use crate::{Club, Country, Player, PlayerPositionType};
+use crate::{
+    Club, Country, CountryRegulations, Player, PlayerPositionType, SigningEligibility,
+    SigningRestriction,
+};
 
 #[allow(dead_code)]
 #[derive(Debug, Clone)]
@@ -171,7 +174,36 @@ pub(crate) fn can_club_accept_player(club: &Club) -> bool {
     // is not guaranteed to be first in the collection, and counting the
     // wrong squad would gate the cap against a reserve/B roster.
     let main_squad = club.teams.main().map(|t| t.players.len()).unwrap_or(0);
-    main_squad < max_squad
+    // A club under a regulator's transfer embargo can't register anyone,
+    // whatever room the squad has.
+    !club.finance.is_transfer_embargoed() && main_squad < max_squad
+}
+
+/// The buying country's nationality rules applied to `player` joining
+/// `club`: work permit, then the non-bloc slots on the main squad.
+pub(crate) fn signing_restriction(
+    country: &Country,
+    club: &Club,
+    player: &Player,
+) -> Option<SigningRestriction> {
+    let regulations = &country.regulations;
+    if !regulations.has_signing_rules() {
+        return None;
+    }
+    let held = club
+        .teams
+        .main()
+        .map(|t| {
+            let players: Vec<&Player> = t.players.players.iter().collect();
+            CountryRegulations::non_bloc_count(&players, country.id, country.continent_id)
+        })
+        .unwrap_or(0);
+    regulations.signing_restriction(
+        &SigningEligibility::of(player),
+        country.id,
+        country.continent_id,
+        held,
+    )
 }
 
 pub(crate) fn find_player_in_country(country: &Country, player_id: u32) -> Option<&Player> {
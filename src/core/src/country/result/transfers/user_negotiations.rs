@@ -0,0 +1,296 @@
+//! Daily side of negotiations that involve the user's club. A bid for one
+//! of the user's players stops at the seller decision until the user
+//! answers, and a user counter is answered here by the AI buyer. The
+//! user's own bids run through the regular resolver, which parks them
+//! when the seller names a price or the player asks for more money.
+
+use super::types::NegotiationData;
+use crate::Country;
+use crate::country::result::CountryResult;
+use crate::transfers::negotiation::{
+    NegotiationPhase, NegotiationRejectionReason, UserNegotiationStep,
+};
+use crate::transfers::pipeline::PipelineProcessor;
+use crate::utils::FormattingUtils;
+use chrono::NaiveDate;
+
+/// Highest multiple of the asking price an AI buyer pays to meet a user
+/// counter — the same ceiling its own escalation stops at.
+const BUYER_COUNTER_CEILING: f64 = 1.55;
+
+/// Share of the gap to the user's demand an AI buyer closes when it can't
+/// meet the demand outright.
+const BUYER_COUNTER_STEP: f64 = 0.5;
+
+const MAX_CLUB_ROUNDS: u8 = 3;
+
+impl CountryResult {
+    /// Run today's tick for a negotiation the user's club takes part in.
+    /// Returns `true` when the regular phase resolver must leave it alone.
+    pub(super) fn resolve_user_negotiation(
+        country: &mut Country,
+        neg_id: u32,
+        neg_data: &NegotiationData,
+        date: NaiveDate,
+    ) -> bool {
+        if country.transfer_market.user_club_ids.is_empty() {
+            return false;
+        }
+        let user_step = match country.transfer_market.negotiations.get(&neg_id) {
+            Some(negotiation) => negotiation.user_step.clone(),
+            None => return false,
+        };
+
+        match user_step {
+            Some(UserNegotiationStep::AwaitingBuyer { fee }) => {
+                Self::answer_user_counter(country, neg_id, neg_data, fee, date);
+                true
+            }
+            Some(_) => true,
+            None => {
+                let seller_decision = matches!(
+                    neg_data.phase,
+                    NegotiationPhase::InitialApproach { .. }
+                        | NegotiationPhase::ClubNegotiation { .. }
+                );
+                // A triggered release clause is a forced sale: the user
+                // gets no say, exactly as an AI seller doesn't.
+                let forced_sale = neg_data.selling_country_id.is_none()
+                    && Self::clause_triggers_sale(country, neg_data);
+                if !country
+                    .transfer_market
+                    .is_user_club(neg_data.selling_club_id)
+                    || !seller_decision
+                    || forced_sale
+                {
+                    return false;
+                }
+                if let Some(negotiation) = country.transfer_market.negotiations.get_mut(&neg_id) {
+                    negotiation.user_step = Some(UserNegotiationStep::AwaitingUserResponse);
+                }
+                true
+            }
+        }
+    }
+
+    /// The AI buyer's answer to a fee the user demanded for their player:
+    /// meet it when it is affordable and within reason, otherwise improve
+    /// the bid part of the way and hand it back, and walk away once the
+    /// rounds are spent.
+    fn answer_user_counter(
+        country: &mut Country,
+        neg_id: u32,
+        neg_data: &NegotiationData,
+        demanded_fee: f64,
+        date: NaiveDate,
+    ) {
+        let anchor = neg_data.asking_price.max(neg_data.offer_amount);
+        let budget = country
+            .clubs
+            .iter()
+            .find(|c| c.id == neg_data.buying_club_id)
+            .and_then(|c| {
+                c.finance
+                    .transfer_budget
+                    .as_ref()
+                    .map(|b| b.amount.max(0.0))
+            })
+            .unwrap_or(f64::MAX);
+        let reach = (anchor * BUYER_COUNTER_CEILING).min(budget);
+        let round = match neg_data.phase {
+            NegotiationPhase::ClubNegotiation { round, .. } => round,
+            _ => 1,
+        };
+
+        let Some(negotiation) = country.transfer_market.negotiations.get_mut(&neg_id) else {
+            return;
+        };
+
+        if demanded_fee <= reach {
+            let mut offer = negotiation.current_offer.clone();
+            offer.base_fee.amount = demanded_fee;
+            offer.offered_date = date;
+            negotiation.counter_offer(offer);
+            negotiation.user_step = None;
+            negotiation.advance_to_personal_terms(date);
+            return;
+        }
+
+        let current = neg_data.offer_amount;
+        let improved = FormattingUtils::round_fee(
+            current + (reach.min(demanded_fee) - current).max(0.0) * BUYER_COUNTER_STEP,
+        );
+        if round < MAX_CLUB_ROUNDS && improved > current {
+            let mut offer = negotiation.current_offer.clone();
+            offer.base_fee.amount = improved;
+            offer.offered_date = date;
+            negotiation.counter_offer(offer);
+            negotiation.advance_club_negotiation_round(date);
+            negotiation.user_step = Some(UserNegotiationStep::AwaitingUserResponse);
+            return;
+        }
+
+        negotiation.user_step = None;
+        negotiation.reject_with_reason(NegotiationRejectionReason::AskingPriceTooHigh);
+        Self::reopen_listing_for_player(country, neg_data.player_id);
+        PipelineProcessor::on_negotiation_resolved(
+            country,
+            neg_data.buying_club_id,
+            neg_data.player_id,
+            false,
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::types::TransferActivitySummary;
+    use super::*;
+    use crate::club::academy::ClubAcademy;
+    use crate::league::{DayMonthPeriod, League, LeagueCollection, LeagueSettings};
+    use crate::shared::{Currency, CurrencyValue, Location};
+    use crate::transfers::market::{TransferListing, TransferListingType};
+    use crate::transfers::negotiation::{NegotiationStatus, TransferNegotiation};
+    use crate::transfers::offer::TransferOffer;
+    use crate::{Club, ClubColors, ClubFacilities, ClubFinances, ClubStatus, TeamCollection};
+
+    const USER_CLUB: u32 = 1;
+    const BUYER_CLUB: u32 = 2;
+    const PLAYER: u32 = 100;
+
+    fn d(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, day).unwrap()
+    }
+
+    fn club(id: u32) -> Club {
+        Club::new(
+            id,
+            format!("Club {}", id),
+            Location::new(1),
+            ClubFinances::new(1_000_000, Vec::new()),
+            ClubAcademy::new(3),
+            ClubStatus::Professional,
+            ClubColors::default(),
+            TeamCollection::new(Vec::new()),
+            ClubFacilities::default(),
+        )
+    }
+
+    fn country() -> Country {
+        let league = League::new(
+            1,
+            "L".to_string(),
+            "l".to_string(),
+            1,
+            500,
+            LeagueSettings {
+                season_starting_half: DayMonthPeriod::new(1, 8, 31, 12),
+                season_ending_half: DayMonthPeriod::new(1, 1, 31, 5),
+                tier: 1,
+                promotion_spots: 0,
+                relegation_spots: 0,
+                league_group: None,
+                split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
+            },
+            false,
+        );
+        let mut country = Country::builder()
+            .id(1)
+            .code("en".to_string())
+            .slug("england".to_string())
+            .name("England".to_string())
+            .continent_id(1)
+            .leagues(LeagueCollection::new(vec![league]))
+            .clubs(vec![club(USER_CLUB), club(BUYER_CLUB)])
+            .build()
+            .unwrap();
+
+        country.transfer_market.user_club_ids = vec![USER_CLUB];
+        country.transfer_market.listings.push(TransferListing::new(
+            PLAYER,
+            USER_CLUB,
+            0,
+            CurrencyValue::new(1_000_000.0, Currency::Usd),
+            d(1),
+            TransferListingType::Transfer,
+        ));
+        let offer = TransferOffer::new(
+            CurrencyValue::new(1_000_000.0, Currency::Usd),
+            BUYER_CLUB,
+            d(1),
+        );
+        let negotiation = TransferNegotiation::new(
+            1,
+            PLAYER,
+            0,
+            USER_CLUB,
+            BUYER_CLUB,
+            offer,
+            d(1),
+            0.5,
+            0.5,
+            25,
+            0.5,
+        );
+        country.transfer_market.negotiations.insert(1, negotiation);
+        country
+    }
+
+    fn tick(country: &mut Country, date: NaiveDate) {
+        let mut summary = TransferActivitySummary::new();
+        CountryResult::resolve_pending_negotiations(country, date, &mut summary);
+    }
+
+    fn negotiation(country: &Country) -> &TransferNegotiation {
+        &country.transfer_market.negotiations[&1]
+    }
+
+    #[test]
+    fn bid_for_user_player_waits_for_the_user() {
+        let mut country = country();
+
+        tick(&mut country, d(10));
+        tick(&mut country, d(20));
+
+        let n = negotiation(&country);
+        assert_eq!(n.user_step, Some(UserNegotiationStep::AwaitingUserResponse));
+        assert_eq!(n.status, NegotiationStatus::Pending);
+        assert!(matches!(n.phase, NegotiationPhase::InitialApproach { .. }));
+    }
+
+    #[test]
+    fn buyer_meets_a_reasonable_user_counter() {
+        let mut country = country();
+        let n = country.transfer_market.negotiations.get_mut(&1).unwrap();
+        n.advance_to_club_negotiation(d(5));
+        n.user_step = Some(UserNegotiationStep::AwaitingBuyer { fee: 1_400_000.0 });
+
+        tick(&mut country, d(10));
+
+        let n = negotiation(&country);
+        assert_eq!(n.current_offer.base_fee.amount, 1_400_000.0);
+        assert!(n.user_step.is_none());
+        assert!(matches!(n.phase, NegotiationPhase::PersonalTerms { .. }));
+    }
+
+    #[test]
+    fn buyer_walks_from_an_excessive_counter_in_the_last_round() {
+        let mut country = country();
+        let n = country.transfer_market.negotiations.get_mut(&1).unwrap();
+        n.advance_to_club_negotiation(d(5));
+        n.advance_club_negotiation_round(d(5));
+        n.advance_club_negotiation_round(d(5));
+        n.user_step = Some(UserNegotiationStep::AwaitingBuyer { fee: 5_000_000.0 });
+
+        tick(&mut country, d(10));
+
+        let n = negotiation(&country);
+        assert_eq!(n.status, NegotiationStatus::Rejected);
+        assert_eq!(
+            n.rejection_reason,
+            Some(NegotiationRejectionReason::AskingPriceTooHigh)
+        );
+    }
+}
@@ -1,7 +1,8 @@
 use chrono::NaiveDate;
 use log::debug;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct InternationalCompetition {
     pub name: String,
     pub competition_type: CompetitionType,
@@ -16,7 +17,7 @@ impl InternationalCompetition {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum CompetitionType {
     ChampionsLeague,
     EuropaLeague,
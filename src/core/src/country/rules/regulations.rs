@@ -1,9 +1,55 @@
-use crate::club::Player;
+use crate::club::{Person, Player};
+use crate::transfers::TransferWindowRules;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// Players younger than this register freely, outside the named list.
+pub const REGISTRATION_EXEMPT_AGE: u8 = 21;
+
+/// Ability at which a governing body waives the caps threshold — the
+/// exceptional-talent route to a work permit.
+pub const WORK_PERMIT_EXEMPT_ABILITY: u8 = 150;
+
+/// Why a country's rules refuse a club a signing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SigningRestriction {
+    /// Too few international caps to earn a work permit.
+    WorkPermitRefused,
+    /// The club already holds as many non-bloc players as it may.
+    NonEuQuotaFull,
+}
+
+/// The nationality facts signing rules read off a player.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SigningEligibility {
+    pub country_id: u32,
+    /// 0 when unresolved; such players are never restricted.
+    pub continent_id: u32,
+    pub international_apps: u16,
+    pub current_ability: u8,
+}
+
+impl SigningEligibility {
+    pub fn of(player: &Player) -> Self {
+        SigningEligibility {
+            country_id: player.country_id,
+            continent_id: player.nationality_continent_id,
+            international_apps: player.player_attributes.international_apps,
+            current_ability: player.player_attributes.current_ability,
+        }
+    }
+
+    /// True for a player from outside the signing country's continent
+    /// — the "non-EU" import the permit and slot rules are about.
+    pub fn is_non_bloc(&self, country_id: u32, continent_id: u32) -> bool {
+        self.country_id != country_id && self.continent_id != 0 && self.continent_id != continent_id
+    }
+}
 
 /// Per-country competition rules. Each field is `None` to mean
 /// "rule disabled / not enforced" — the simulator must opt in by
 /// populating these via the country builder.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CountryRegulations {
     /// Maximum non-domestic players allowed in a squad. `None` means
     /// no limit (typical for top-five European leagues post-Bosman).
@@ -12,9 +58,24 @@ pub struct CountryRegulations {
     /// country's pricing unit). `None` = no cap.
     pub salary_cap: Option<f64>,
     /// Minimum number of homegrown-eligible players required in the
-    /// registered squad. "Homegrown" is approximated as
+    /// registered squad. "Homegrown" is approximated as club-trained or
     /// `player.country_id == club.country_id`. `None` = no requirement.
     pub homegrown_requirements: Option<u8>,
+    /// Of the homegrown slots, how many only club-trained players fill.
+    pub club_trained_requirements: Option<u8>,
+    /// Size of the named senior list a club files at each registration
+    /// deadline (25 in UEFA-style rules). Under-age players sit outside
+    /// it. `None` = no list; every rostered player is registered.
+    pub registered_squad_size: Option<u8>,
+    /// International caps a player from outside the continent needs for
+    /// a work permit. `None` = no permit system.
+    pub work_permit_min_caps: Option<u16>,
+    /// Most players from outside the continent a club may hold in its
+    /// senior squad. `None` = unrestricted.
+    pub non_eu_player_limit: Option<u8>,
+    /// Registration window dates set by the federation. `None` = the
+    /// built-in calendar for the country code.
+    pub transfer_windows: Option<TransferWindowRules>,
     pub ffp_enabled: bool, // Financial Fair Play
 }
 
@@ -24,6 +85,11 @@ impl CountryRegulations {
             foreign_player_limit: None,
             salary_cap: None,
             homegrown_requirements: None,
+            club_trained_requirements: None,
+            registered_squad_size: None,
+            work_permit_min_caps: None,
+            non_eu_player_limit: None,
+            transfer_windows: None,
             ffp_enabled: false,
         }
     }
@@ -62,6 +128,103 @@ impl CountryRegulations {
             .collect()
     }
 
+    /// Decide which senior players stay off the named list. The club
+    /// files its strongest seniors, but homegrown slots it can't fill
+    /// with homegrown players stay empty rather than go to imports.
+    /// Strongest-first under nested caps (non-club-trained ⊇
+    /// non-homegrown) always yields the best legal list. Returns the
+    /// player ids that are NOT registered.
+    pub fn omitted_for_squad_list(
+        &self,
+        players: &[&Player],
+        club_country_id: u32,
+        today: NaiveDate,
+    ) -> Vec<u32> {
+        let Some(size) = self.registered_squad_size.map(usize::from) else {
+            return Vec::new();
+        };
+        let homegrown_min = self.homegrown_requirements.unwrap_or(0) as usize;
+        let club_trained_min = self.club_trained_requirements.unwrap_or(0) as usize;
+        let max_not_homegrown = size.saturating_sub(homegrown_min);
+        let max_not_club_trained = size.saturating_sub(club_trained_min);
+
+        let mut seniors: Vec<&Player> = players
+            .iter()
+            .copied()
+            .filter(|p| p.age(today) >= REGISTRATION_EXEMPT_AGE)
+            .collect();
+        seniors.sort_by(|a, b| {
+            b.player_attributes
+                .current_ability
+                .cmp(&a.player_attributes.current_ability)
+                .then(a.id.cmp(&b.id))
+        });
+
+        let (mut listed, mut not_homegrown, mut not_club_trained) = (0, 0, 0);
+        let mut omitted = Vec::new();
+        for player in seniors {
+            let club_trained = player.is_club_trained();
+            let homegrown = club_trained || player.country_id == club_country_id;
+            if listed >= size
+                || (!homegrown && not_homegrown >= max_not_homegrown)
+                || (!club_trained && not_club_trained >= max_not_club_trained)
+            {
+                omitted.push(player.id);
+                continue;
+            }
+            listed += 1;
+            not_homegrown += usize::from(!homegrown);
+            not_club_trained += usize::from(!club_trained);
+        }
+        omitted
+    }
+
+    /// Whether a club of this country, already holding `non_bloc_held`
+    /// players from outside `continent_id`, may sign `candidate`.
+    /// Nationals and players from the same continent always pass.
+    pub fn signing_restriction(
+        &self,
+        candidate: &SigningEligibility,
+        country_id: u32,
+        continent_id: u32,
+        non_bloc_held: usize,
+    ) -> Option<SigningRestriction> {
+        if !candidate.is_non_bloc(country_id, continent_id) {
+            return None;
+        }
+        if self.work_permit_min_caps.is_some_and(|caps| {
+            candidate.international_apps < caps
+                && candidate.current_ability < WORK_PERMIT_EXEMPT_ABILITY
+        }) {
+            return Some(SigningRestriction::WorkPermitRefused);
+        }
+        if self
+            .non_eu_player_limit
+            .is_some_and(|limit| non_bloc_held >= limit as usize)
+        {
+            return Some(SigningRestriction::NonEuQuotaFull);
+        }
+        None
+    }
+
+    /// Players in `players` that count against the non-bloc slots.
+    pub fn non_bloc_count(players: &[&Player], country_id: u32, continent_id: u32) -> usize {
+        players
+            .iter()
+            .filter(|p| SigningEligibility::of(p).is_non_bloc(country_id, continent_id))
+            .count()
+    }
+
+    /// True when any signing rule is set.
+    pub fn has_signing_rules(&self) -> bool {
+        self.work_permit_min_caps.is_some() || self.non_eu_player_limit.is_some()
+    }
+
+    /// True when any rule that shapes a club's registered squad is set.
+    pub fn has_squad_rules(&self) -> bool {
+        self.foreign_player_limit.is_some() || self.registered_squad_size.is_some()
+    }
+
     /// Count of homegrown-eligible players currently in `players`.
     /// Helper for the registration view; doesn't enforce — merely
     /// reports the gap so callers can decide what to do.
@@ -90,7 +253,6 @@ mod tests {
     use crate::club::player::builder::PlayerBuilder;
     use crate::shared::fullname::FullName;
     use crate::{PersonAttributes, PlayerAttributes, PlayerPositions, PlayerSkills};
-    use chrono::NaiveDate;
 
     fn make_player(id: u32, country_id: u32, ability: u8) -> Player {
         let mut attrs = PlayerAttributes::default();
@@ -161,6 +323,77 @@ mod tests {
         assert_eq!(regs.homegrown_count(&refs, 1), 2);
     }
 
+    #[test]
+    fn squad_list_keeps_homegrown_slots_for_homegrown_players() {
+        let mut regs = CountryRegulations::new();
+        regs.registered_squad_size = Some(3);
+        regs.homegrown_requirements = Some(1);
+        let today = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+        let mut players: Vec<Player> = (1..=4).map(|i| make_player(i, 99, 150 - i as u8)).collect();
+        players.push(make_player(5, 1, 60));
+        let mut kid = make_player(6, 99, 40);
+        kid.birth_date = NaiveDate::from_ymd_opt(2012, 1, 1).unwrap();
+        for p in &mut players {
+            p.last_transfer_date = Some(today);
+        }
+        players.push(kid);
+        let refs: Vec<&Player> = players.iter().collect();
+        // Two best imports, the weak local in the reserved slot; the
+        // under-age kid sits outside the list.
+        let mut omitted = regs.omitted_for_squad_list(&refs, 1, today);
+        omitted.sort();
+        assert_eq!(omitted, vec![3, 4]);
+    }
+
+    #[test]
+    fn no_squad_list_means_no_omissions() {
+        let regs = CountryRegulations::new();
+        let players: Vec<Player> = (1..=30).map(|i| make_player(i, 99, 100)).collect();
+        let refs: Vec<&Player> = players.iter().collect();
+        let today = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+        assert!(regs.omitted_for_squad_list(&refs, 1, today).is_empty());
+    }
+
+    #[test]
+    fn work_permit_needs_caps_unless_exceptional() {
+        let mut regs = CountryRegulations::new();
+        regs.work_permit_min_caps = Some(10);
+        let mut candidate = SigningEligibility::of(&make_player(1, 99, 120));
+        candidate.continent_id = 3;
+        assert_eq!(
+            regs.signing_restriction(&candidate, 1, 1, 0),
+            Some(SigningRestriction::WorkPermitRefused)
+        );
+        candidate.international_apps = 12;
+        assert_eq!(regs.signing_restriction(&candidate, 1, 1, 0), None);
+        candidate.international_apps = 0;
+        candidate.current_ability = WORK_PERMIT_EXEMPT_ABILITY;
+        assert_eq!(regs.signing_restriction(&candidate, 1, 1, 0), None);
+        // Same-continent imports never need a permit.
+        candidate.current_ability = 100;
+        candidate.continent_id = 1;
+        assert_eq!(regs.signing_restriction(&candidate, 1, 1, 0), None);
+    }
+
+    #[test]
+    fn non_eu_slots_fill_up() {
+        let mut regs = CountryRegulations::new();
+        regs.non_eu_player_limit = Some(2);
+        let mut players: Vec<Player> = (1..=3).map(|i| make_player(i, 99, 100)).collect();
+        players[0].nationality_continent_id = 3;
+        players[1].nationality_continent_id = 3;
+        players[2].nationality_continent_id = 1;
+        let refs: Vec<&Player> = players.iter().collect();
+        let held = CountryRegulations::non_bloc_count(&refs, 1, 1);
+        assert_eq!(held, 2);
+        let candidate = SigningEligibility::of(&players[0]);
+        assert_eq!(
+            regs.signing_restriction(&candidate, 1, 1, held),
+            Some(SigningRestriction::NonEuQuotaFull)
+        );
+        assert_eq!(regs.signing_restriction(&candidate, 1, 1, held - 1), None);
+    }
+
     #[test]
     fn salary_cap_exceeded_returns_false_when_no_cap() {
         let regs = CountryRegulations::new();
@@ -27,12 +27,13 @@ use std::collections::HashMap;
 use crate::PlayerFieldPositionGroup;
 use crate::r#match::MatchResult;
 use crate::r#match::engine::result::PlayerMatchEndStats;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
 
 /// One historical award entry. The denormalised name/club fields let the
 /// UI render past awards without re-resolving entities that may have moved
 /// or retired since the week the award was given.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerOfTheWeekAward {
     pub week_end_date: NaiveDate,
     pub player_id: u32,
@@ -50,7 +51,7 @@ pub struct PlayerOfTheWeekAward {
 
 /// Per-league award archive. Bounded — we cap at three full seasons so the
 /// in-memory cost stays predictable even on long saves.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PlayerOfTheWeekHistory {
     items: Vec<PlayerOfTheWeekAward>,
     last_award_week: Option<NaiveDate>,
@@ -367,6 +368,7 @@ mod tests {
                 away_shootout: 0,
             },
             friendly: false,
+            attendance: 0,
         }
     }
 
@@ -1,8 +1,9 @@
 use crate::context::GlobalContext;
 use crate::league::{League, LeagueResult};
-use crate::{Club, Logging};
+use crate::{Club, Logging, SimulationProgress};
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone)]
+#[derive(Clone, Serialize, Deserialize)]
 pub struct LeagueCollection {
     pub leagues: Vec<League>,
 }
@@ -31,7 +32,7 @@ impl LeagueCollection {
                 let message = &format!("simulate league: {}", &league.name);
                 let league_slug = String::from(&league.slug);
 
-                Logging::estimate_result(
+                let result = Logging::estimate_result(
                     || {
                         league.simulate(
                             clubs,
@@ -44,7 +45,9 @@ impl LeagueCollection {
                         )
                     },
                     message,
-                )
+                );
+                SimulationProgress::record_league();
+                result
             })
             .collect()
     }
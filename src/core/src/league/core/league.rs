@@ -2,16 +2,17 @@ use crate::MatchRuntime;
 use crate::context::{GlobalContext, SimulationContext};
 use crate::league::{
     LeagueAwards, LeagueBuildOutput, LeagueDynamics, LeagueMilestones, LeaguePendingState,
-    LeagueRegulations, LeagueResult, LeagueStatistics, LeagueTable, LeagueTableRow, MatchStorage,
-    PlayerOfTheWeekHistory, Schedule, ScheduleItem,
+    LeagueRegulations, LeagueResult, LeagueRules, LeagueSeasonRecord, LeagueStatistics,
+    LeagueTable, LeagueTableRow, MatchStorage, PlayerOfTheWeekHistory, Schedule, ScheduleItem,
 };
 use crate::r#match::MatchResult;
 use crate::{Club, PlayerFieldPositionGroup, PlayerStatistics, Team};
 use chrono::Duration;
 use chrono::{Datelike, NaiveDate};
 use log::debug;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct League {
     pub id: u32,
     pub name: String,
@@ -29,6 +30,8 @@ pub struct League {
     pub split_first_table: Option<Vec<LeagueTableRow>>,
     pub dynamics: LeagueDynamics,
     pub regulations: LeagueRegulations,
+    /// Scoring, tie-break and squad rules configured for this division.
+    pub rules: LeagueRules,
     pub statistics: LeagueStatistics,
     pub milestones: LeagueMilestones,
     pub friendly: bool,
@@ -36,9 +39,11 @@ pub struct League {
     pub financials: LeagueFinancials,
     pub player_of_week: PlayerOfTheWeekHistory,
     pub awards: LeagueAwards,
+    /// Completed seasons, oldest first. Appended at each season end.
+    pub history: Vec<LeagueSeasonRecord>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct LeagueFinancials {
     pub prize_pool: i64,
     pub tv_deal_total: i64,
@@ -94,6 +99,7 @@ impl League {
             split_first_table: None,
             dynamics: LeagueDynamics::new(),
             regulations: LeagueRegulations::new(),
+            rules: LeagueRules::default(),
             statistics: LeagueStatistics::new(),
             milestones: LeagueMilestones::new(),
             friendly,
@@ -101,6 +107,7 @@ impl League {
             financials,
             player_of_week: PlayerOfTheWeekHistory::new(),
             awards: LeagueAwards::default(),
+            history: Vec::new(),
         }
     }
 
@@ -146,7 +153,7 @@ impl League {
             schedule_result.generated && self.table.rows.iter().any(|r| r.played > 0);
 
         if schedule_result.generated {
-            self.table = LeagueTable::new(&league_teams);
+            self.table = LeagueTable::for_rules(&league_teams, &self.rules);
             self.matches = MatchStorage::new();
             self.split_first_table = None;
             debug!("📊 League table reset for new season: {}", self.name);
@@ -205,6 +212,7 @@ impl League {
 
         let mut result = LeagueResult::with_match_result(self.id, table_result, match_results);
         result.new_season_started = new_season_started;
+        result.ffp_sanctions = std::mem::take(&mut self.regulations.sanctions_to_enforce);
         result
     }
 
@@ -367,7 +375,7 @@ impl League {
         }
         let team_ids: Vec<u32> = self.table.rows.iter().map(|r| r.team_id).collect();
         self.split_first_table = Some(self.table.rows.clone());
-        self.table = LeagueTable::new(&team_ids);
+        self.table = LeagueTable::for_rules(&team_ids, &self.rules);
         debug!(
             "📊 Split season: first-stage table frozen and reset for {}",
             self.name
@@ -403,7 +411,7 @@ impl League {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
 pub struct DayMonthPeriod {
     pub from_day: u8,
     pub from_month: u8,
@@ -422,7 +430,7 @@ impl DayMonthPeriod {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeagueSettings {
     pub season_starting_half: DayMonthPeriod,
     pub season_ending_half: DayMonthPeriod,
@@ -435,10 +443,19 @@ pub struct LeagueSettings {
     /// half), each a single round-robin with its own table, playoff and
     /// champion. Relegation reads the annual aggregate across both.
     pub split_season: bool,
+    /// Table places that play off for the last promotion spot. When > 0,
+    /// only `promotion_spots - 1` go up automatically; the next
+    /// `promotion_playoff_spots` sides contest a knockout for the final
+    /// place (Championship: 3 spots, 4 playoff sides → 1st–2nd up, 3rd–6th
+    /// play off).
+    pub promotion_playoff_spots: u8,
+    /// League this division's relegated clubs drop into. `None` pairs it
+    /// with the next tier down (see `paired_promotion_league`).
+    pub relegation_league_id: Option<u32>,
 }
 
 /// Identifies a league as one group within a larger competition.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeagueGroup {
     pub name: String,
     pub competition: String,
@@ -453,7 +470,7 @@ pub struct LeagueGroup {
 
 /// Configuration for a grouped competition's end-of-season playoff. See
 /// [`crate::league::LeaguePlayoff`].
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeaguePlayoffConfig {
     /// Top N of each group's table that enter the knockout bracket.
     pub qualifiers_per_group: u8,
@@ -469,7 +486,7 @@ pub struct LeaguePlayoffConfig {
 }
 
 /// Bracket shape for a grouped competition's playoff.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PlayoffFormat {
     /// Generic single-elimination: group seeds interleaved into one field,
     /// re-paired strongest-vs-weakest each round, byes to top seeds.
@@ -501,6 +518,17 @@ impl LeagueSettings {
         (NaiveDate::day(&date) as u8) == season_starting_date.from_day
             && (date.month() as u8) == season_starting_date.from_month
     }
+
+    /// The season's closing day as seen from `date`: the next occurrence
+    /// of `season_ending_half`'s end on or after it.
+    pub fn season_end_after(&self, date: NaiveDate) -> NaiveDate {
+        let end = &self.season_ending_half;
+        let on = |year: i32| NaiveDate::from_ymd_opt(year, end.to_month as u32, end.to_day as u32);
+        on(date.year())
+            .filter(|d| *d >= date)
+            .or_else(|| on(date.year() + 1))
+            .unwrap_or(date)
+    }
 }
 
 #[cfg(test)]
@@ -517,6 +545,8 @@ mod split_season_tests {
             relegation_spots: 1,
             league_group: None,
             split_season: true,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
         League::new(
             1,
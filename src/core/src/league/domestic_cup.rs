@@ -24,6 +24,7 @@ use crate::league::{
 use crate::r#match::{MatchResult, MatchResultOutcome};
 use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use log::debug;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
 /// One completed edition's result, captured at the instant the next
@@ -31,7 +32,7 @@ use std::collections::{HashMap, HashSet};
 /// intact). Powers the cup's History tab — a roll of honour of past
 /// champions. We store team ids, not names, so a club that is later
 /// renamed still resolves correctly when the page is rendered.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CupHistoryEntry {
     /// Calendar year the winning edition's round one was drawn in —
     /// the same anchor as [`DomesticCup::season_start_year`].
@@ -68,7 +69,7 @@ impl DomesticCupPrizes {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DomesticCup {
     /// The cup is run through a `League` flagged `is_cup = true`. Reusing
     /// `League` means the cup inherits match execution, result processing,
@@ -644,6 +645,8 @@ mod tests {
             relegation_spots: 0,
             league_group: None,
             split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
         let mut league = League::new(
             800_000_999,
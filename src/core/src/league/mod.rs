@@ -2,6 +2,7 @@ pub mod awards;
 pub mod core;
 pub mod domestic_cup;
 pub mod playoff;
+pub mod promotion_playoff;
 pub mod result;
 pub mod rules;
 pub mod schedule;
@@ -23,6 +24,7 @@ pub use playoff::{
     CROSS_BRACKET, GroupStanding, LeaguePlayoff, PlayoffRoundLabel, PlayoffSeries, PlayoffStage,
     StandingRow,
 };
+pub use promotion_playoff::PromotionPlayoff;
 pub use result::*;
 pub use rules::*;
 pub use schedule::*;
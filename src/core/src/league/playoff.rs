@@ -44,6 +44,7 @@ use crate::league::{
 use crate::r#match::{MatchResult, Score};
 use chrono::{Datelike, Duration, NaiveDate, NaiveDateTime, NaiveTime};
 use log::debug;
+use serde::{Deserialize, Serialize};
 use std::collections::{HashMap, HashSet};
 
 /// One team's line in a group standings snapshot — enough to seed a
@@ -77,7 +78,7 @@ pub struct GroupStanding {
 }
 
 /// Which slice of the season feeds this playoff.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PlayoffStage {
     /// The whole regular season (MLS Cup, Serie C, …).
     FullSeason,
@@ -107,7 +108,7 @@ pub const CROSS_BRACKET: usize = usize::MAX;
 /// One knockout tie — a single game or a best-of-N series. Games are
 /// materialised as `ScheduleItem`s on the playoff's inner league; the
 /// series tracks how many each side has won so far.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayoffSeries {
     /// 1-based round within the edition (wild card = 1 for MLS).
     pub round: u8,
@@ -152,7 +153,7 @@ impl PlayoffSeries {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeaguePlayoff {
     /// The bracket is run through a `League` flagged `is_cup = true`, so it
     /// inherits match execution, result processing, stat routing and
@@ -1102,6 +1103,8 @@ mod tests {
                 playoff: None,
             }),
             split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         }
     }
 
@@ -1202,6 +1205,7 @@ mod tests {
                     score: s,
                     details: None,
                     friendly: false,
+                    attendance: 0,
                 };
                 pf.apply_game_result(&mr);
                 played += 1;
@@ -0,0 +1,168 @@
+//! Season-end promotion playoff (Championship-style). The sides just
+//! below the automatic promotion places contest a single-game knockout
+//! through the match engine: the better seed hosts every round, extra
+//! time and penalties settle draws, and the winner takes the division's
+//! last promotion place. Configured per league via
+//! `LeagueSettings::promotion_playoff_spots`.
+
+use crate::r#match::{Match, SelectionCompetition, SelectionContext};
+use crate::{Club, MatchRuntime, Team};
+use chrono::NaiveDate;
+use log::info;
+
+pub struct PromotionPlayoff;
+
+impl PromotionPlayoff {
+    /// Play the bracket out and return the winner. `entrants` are team ids
+    /// best seed first. A tie whose squad can't be built (team missing
+    /// from `clubs`) goes to the host so the bracket can't stall.
+    pub fn resolve(
+        entrants: &[u32],
+        clubs: &[Club],
+        league_id: u32,
+        league_slug: &str,
+        date: NaiveDate,
+    ) -> Option<u32> {
+        let mut alive = entrants.to_vec();
+        while alive.len() > 1 {
+            let (byes, ties) = Self::pair_round(&alive);
+
+            let mut built: Vec<usize> = Vec::new();
+            let mut matches: Vec<Match> = Vec::new();
+            for (idx, &(home, away)) in ties.iter().enumerate() {
+                if let Some(m) = Self::build_match(home, away, clubs, league_id, league_slug, date)
+                {
+                    built.push(idx);
+                    matches.push(m);
+                }
+            }
+
+            // Unplayable ties default to the host.
+            let mut winners: Vec<u32> = ties.iter().map(|&(home, _)| home).collect();
+            let results = MatchRuntime::engine_pool().play(matches);
+            for (idx, result) in built.into_iter().zip(results.iter()) {
+                let (home, away) = ties[idx];
+                let score = &result.score;
+                let (home_goals, away_goals) = if score.home_team.team_id == home {
+                    (score.home_team.get(), score.away_team.get())
+                } else {
+                    (score.away_team.get(), score.home_team.get())
+                };
+                let (home_pens, away_pens) = if score.home_team.team_id == home {
+                    (score.home_shootout, score.away_shootout)
+                } else {
+                    (score.away_shootout, score.home_shootout)
+                };
+                if (away_goals, away_pens) > (home_goals, home_pens) {
+                    winners[idx] = away;
+                }
+                info!(
+                    "Promotion playoff (league {}): {} {}-{} {} → {} advances",
+                    league_id, home, home_goals, away_goals, away, winners[idx]
+                );
+            }
+
+            alive = byes;
+            alive.extend(winners);
+        }
+        alive.first().copied()
+    }
+
+    /// One round of the bracket: `(byes, ties)`. When the field isn't a
+    /// power of two the top seeds skip the round; the rest pair best
+    /// against worst with the better seed at home.
+    pub fn pair_round(seeds: &[u32]) -> (Vec<u32>, Vec<(u32, u32)>) {
+        let bye_count = seeds.len().next_power_of_two() - seeds.len();
+        let byes = seeds[..bye_count].to_vec();
+        let rest = &seeds[bye_count..];
+        let ties = (0..rest.len() / 2)
+            .map(|i| (rest[i], rest[rest.len() - 1 - i]))
+            .collect();
+        (byes, ties)
+    }
+
+    fn build_match(
+        home_id: u32,
+        away_id: u32,
+        clubs: &[Club],
+        league_id: u32,
+        league_slug: &str,
+        date: NaiveDate,
+    ) -> Option<Match> {
+        let (home_club, home_team) = Self::find_team(clubs, home_id)?;
+        let (away_club, away_team) = Self::find_team(clubs, away_id)?;
+
+        let selection_ctx = |opponent: &Team| SelectionContext {
+            is_friendly: false,
+            date,
+            match_importance: 1.0,
+            philosophy: None,
+            opponent_tactic: opponent.tactics.as_ref().map(|t| t.tactic_type),
+            competition: SelectionCompetition::League,
+            game_model: None,
+            ..SelectionContext::default()
+        };
+        let home_squad = home_team.get_enhanced_match_squad(
+            &home_club.get_force_selected_players(),
+            &selection_ctx(away_team),
+        );
+        let away_squad = away_team.get_enhanced_match_squad(
+            &away_club.get_force_selected_players(),
+            &selection_ctx(home_team),
+        );
+
+        Some(Match::make_knockout(
+            format!("po_{}_{}_{}", date.format("%Y%m%d"), home_id, away_id),
+            league_id,
+            league_slug,
+            home_squad,
+            away_squad,
+        ))
+    }
+
+    fn find_team(clubs: &[Club], team_id: u32) -> Option<(&Club, &Team)> {
+        clubs.iter().find_map(|club| {
+            club.teams
+                .iter()
+                .find(|t| t.id == team_id)
+                .map(|team| (club, team))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn four_team_playoff_pairs_best_against_worst() {
+        let (byes, ties) = PromotionPlayoff::pair_round(&[3, 4, 5, 6]);
+        assert!(byes.is_empty());
+        assert_eq!(ties, vec![(3, 6), (4, 5)]);
+    }
+
+    #[test]
+    fn odd_field_gives_top_seed_a_bye() {
+        let (byes, ties) = PromotionPlayoff::pair_round(&[3, 4, 5]);
+        assert_eq!(byes, vec![3]);
+        assert_eq!(ties, vec![(4, 5)]);
+    }
+
+    #[test]
+    fn lone_entrant_wins_without_a_match() {
+        let date = NaiveDate::from_ymd_opt(2030, 6, 1).unwrap();
+        assert_eq!(
+            PromotionPlayoff::resolve(&[7], &[], 1, "test", date),
+            Some(7)
+        );
+    }
+
+    #[test]
+    fn missing_teams_advance_the_host() {
+        let date = NaiveDate::from_ymd_opt(2030, 6, 1).unwrap();
+        assert_eq!(
+            PromotionPlayoff::resolve(&[3, 4, 5, 6], &[], 1, "test", date),
+            Some(3)
+        );
+    }
+}
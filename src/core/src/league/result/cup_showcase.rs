@@ -33,7 +33,7 @@ use crate::transfers::pipeline::scouting_config::ScoutingConfig;
 use crate::transfers::pipeline::{KnownPlayerMemory, ScoutMonitoringSource, TransferRequestStatus};
 use crate::transfers::window::PlayerValuationCalculator;
 use crate::utils::IntegerUtils;
-use crate::{PlayerFieldPositionGroup, PlayerPositionType};
+use crate::{PersonAttributes, PlayerFieldPositionGroup, PlayerPositionType};
 
 // ============================================================
 // Tuning constants
@@ -407,6 +407,7 @@ struct ShowcaseCandidate {
     estimated_value: f64,
     is_injured: bool,
     determination: f32,
+    personality: PersonAttributes,
     contract_months: i16,
     world_reputation: i16,
     is_famous: bool,
@@ -423,6 +424,7 @@ struct MonitorAction {
     estimated_value: f64,
     is_injured: bool,
     determination: f32,
+    personality: PersonAttributes,
     age: u8,
     contract_months: i16,
     world_reputation: i16,
@@ -548,6 +550,7 @@ impl LeagueResult {
                 None => return,
             };
             let price_level = country.settings.pricing.price_level;
+            let market_index = country.transfer_market.market_index;
             let weaker_club = match country.clubs.iter().find(|c| c.id == weaker_club_id) {
                 Some(c) => c,
                 None => return,
@@ -645,7 +648,7 @@ impl LeagueResult {
                 let estimated_value = PlayerValuationCalculator::calculate_value_with_price_level(
                     player,
                     now,
-                    price_level,
+                    market_index.price_level(price_level, player),
                     seller_league_rep,
                     seller_club_rep,
                 )
@@ -671,6 +674,7 @@ impl LeagueResult {
                     estimated_value,
                     is_injured: player.player_attributes.is_injured,
                     determination: player.skills.mental.determination,
+                    personality: player.attributes,
                     contract_months,
                     world_reputation: player.player_attributes.world_reputation,
                     is_famous: player.player_attributes.world_reputation >= FAME_DISCOVERY_CEILING,
@@ -752,6 +756,7 @@ impl LeagueResult {
                             estimated_value: cand.estimated_value,
                             is_injured: cand.is_injured,
                             determination: cand.determination,
+                            personality: cand.personality,
                             age: cand.age,
                             contract_months: cand.contract_months,
                             world_reputation: cand.world_reputation,
@@ -794,6 +799,7 @@ impl LeagueResult {
                     action.contract_months,
                     action.world_reputation,
                     buyer_world_rep,
+                    &action.personality,
                 );
                 // Repeat showcases bypass the first-match confidence cap and
                 // ramp toward ReportReady — read the live row to find out.
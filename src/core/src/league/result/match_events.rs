@@ -27,6 +27,7 @@ use crate::r#match::engine::rating::RatingContext;
 use crate::r#match::engine::result::MatchResultRaw;
 use crate::r#match::player::statistics::MatchStatisticType;
 use crate::r#match::{FieldSquad, MatchResult};
+use crate::transfers::MarketIndex;
 use crate::transfers::pipeline::KnownPlayerMemory;
 use crate::transfers::window::PlayerValuationCalculator;
 use crate::{MatchSelectionContext, SelectionOmissionReason};
@@ -251,10 +252,15 @@ impl LeagueResult {
                 Some(id) => id,
                 None => continue,
             };
-            let current_price_level = data
+            let (current_price_level, current_market_index) = data
                 .country(current_country_id)
-                .map(|c| c.settings.pricing.price_level)
-                .unwrap_or(1.0);
+                .map(|c| {
+                    (
+                        c.settings.pricing.price_level,
+                        c.transfer_market.market_index,
+                    )
+                })
+                .unwrap_or((1.0, MarketIndex::default()));
 
             let appeared: Vec<u32> = side
                 .main
@@ -331,7 +337,7 @@ impl LeagueResult {
                 let estimated_value = PlayerValuationCalculator::calculate_value_with_price_level(
                     player,
                     date,
-                    current_price_level,
+                    current_market_index.price_level(current_price_level, player),
                     host_league_rep,
                     host_club_rep,
                 )
@@ -2779,6 +2785,8 @@ mod canonical_rating_tests {
                 final_home_tactic: None,
                 final_away_tactic: None,
                 shape_change_minute: None,
+                home_possession_ticks: 0,
+                away_possession_ticks: 0,
             }
         }
     }
@@ -11,17 +11,25 @@ pub use data_access::{
 
 pub use types::*;
 
-use crate::league::LeagueTableResult;
+use crate::league::{FFPCase, FFPSanction, LeagueTableResult};
 use crate::r#match::MatchResult;
 use crate::r#match::TeamScore;
 use crate::simulator::SimulatorData;
 use crate::{MatchHistoryItem, SimulationResult};
+use chrono::Duration;
+
+/// Length of the registration ban handed down by an FFP transfer-ban
+/// sanction.
+const FFP_TRANSFER_EMBARGO_DAYS: i64 = 365;
 
 pub struct LeagueResult {
     pub league_id: u32,
     pub table_result: LeagueTableResult,
     pub match_results: Option<Vec<MatchResult>>,
     pub new_season_started: bool,
+    /// FFP cases resolved today whose fines / embargoes still have to
+    /// be applied to the clubs.
+    pub ffp_sanctions: Vec<FFPCase>,
 }
 
 impl LeagueResult {
@@ -31,6 +39,7 @@ impl LeagueResult {
             table_result,
             match_results: None,
             new_season_started: false,
+            ffp_sanctions: Vec::new(),
         }
     }
 
@@ -44,10 +53,12 @@ impl LeagueResult {
             table_result,
             match_results: Some(match_results),
             new_season_started: false,
+            ffp_sanctions: Vec::new(),
         }
     }
 
     pub fn process(self, data: &mut SimulatorData, result: &mut SimulationResult) {
+        Self::enforce_ffp_sanctions(&self.ffp_sanctions, data);
         if let Some(match_results) = self.match_results {
             for mut match_result in match_results {
                 Self::process_match_results(&mut match_result, data);
@@ -68,6 +79,7 @@ impl LeagueResult {
         ctx: &mut CountryProcessCtx<'_>,
         out_match_results: &mut Vec<MatchResult>,
     ) {
+        Self::enforce_ffp_sanctions(&self.ffp_sanctions, ctx);
         if let Some(match_results) = self.match_results {
             for mut match_result in match_results {
                 Self::process_match_results(&mut match_result, ctx);
@@ -82,6 +94,29 @@ impl LeagueResult {
         Self::process_match_results(result, data);
     }
 
+    /// Apply the club-side half of resolved FFP cases: fines hit the
+    /// ledger as an expense, transfer bans embargo registrations for
+    /// [`FFP_TRANSFER_EMBARGO_DAYS`]. Point deductions were already
+    /// applied to the table when the case resolved.
+    fn enforce_ffp_sanctions<D: data_access::LeagueProcessAccess>(
+        sanctions: &[FFPCase],
+        data: &mut D,
+    ) {
+        let today = data.date().date();
+        for case in sanctions {
+            let Some(club) = data.club_mut(case.club_id) else {
+                continue;
+            };
+            match case.sanction {
+                FFPSanction::Fine(amount) => club.finance.balance.push_outcome(amount as i64),
+                FFPSanction::TransferBan => club
+                    .finance
+                    .impose_transfer_embargo(today + Duration::days(FFP_TRANSFER_EMBARGO_DAYS)),
+                FFPSanction::Warning | FFPSanction::PointDeduction(_) => {}
+            }
+        }
+    }
+
     fn process_match_results<D: data_access::LeagueProcessAccess>(
         result: &mut MatchResult,
         data: &mut D,
@@ -97,15 +132,23 @@ impl LeagueResult {
 
         let home_team_id = result.score.home_team.team_id;
         let away_team_id = result.score.away_team.team_id;
-        // Credit a home match against the club's matchday counter so the
-        // monthly finance pass can scale the gate by actual fixtures
-        // rather than a hardcoded `* 2`. Friendlies don't draw paying
-        // crowds for the model, so they're skipped.
+        // Work out the crowd and credit it against the club's matchday
+        // counter so the monthly finance pass can bill the gate by actual
+        // fixtures. Friendlies don't draw paying crowds for the model, so
+        // they're skipped.
         if !result.friendly {
             let home_club_id = data.team(home_team_id).map(|t| t.club_id);
             if let Some(club_id) = home_club_id {
+                let attendance = Self::home_attendance(
+                    data,
+                    result.league_id,
+                    club_id,
+                    home_team_id,
+                    away_team_id,
+                );
+                result.attendance = attendance;
                 if let Some(home_club) = data.club_mut(club_id) {
-                    home_club.finance.record_home_match();
+                    home_club.finance.record_home_match(attendance);
                 }
             }
         }
@@ -175,4 +218,54 @@ impl LeagueResult {
 
         Self::process_match_events(result, data);
     }
+
+    /// Crowd for a competitive home fixture. Reads the home side's league
+    /// position from the competition's table (mid-table when the
+    /// competition has none, e.g. cups), the visitor's reputation, whether
+    /// the clubs are rivals and the country's stadium attendance factor.
+    fn home_attendance<D: data_access::LeagueProcessAccess>(
+        data: &D,
+        league_id: u32,
+        home_club_id: u32,
+        home_team_id: u32,
+        away_team_id: u32,
+    ) -> u32 {
+        let opponent_reputation = data
+            .team(away_team_id)
+            .map(|t| t.reputation.overall_score())
+            .unwrap_or(0.0);
+        let away_club_id = data.team(away_team_id).map(|t| t.club_id);
+        let attendance_factor = data
+            .country_by_club(home_club_id)
+            .map(|c| c.economic_factors.stadium_attendance_factor)
+            .unwrap_or(1.0);
+        let (position, total) = data
+            .league(league_id)
+            .and_then(|league| {
+                let rows = league.table.get();
+                rows.iter()
+                    .position(|row| row.team_id == home_team_id)
+                    .map(|idx| (idx as u16 + 1, rows.len() as u16))
+            })
+            .unwrap_or((10, 20));
+        // Either club listing the other as a rival makes it a derby.
+        let is_derby = away_club_id.is_some_and(|away_club_id| {
+            data.club(home_club_id)
+                .is_some_and(|c| c.is_rival(away_club_id))
+                || data
+                    .club(away_club_id)
+                    .is_some_and(|c| c.is_rival(home_club_id))
+        });
+        data.club(home_club_id)
+            .map(|club| {
+                club.match_attendance(
+                    opponent_reputation,
+                    attendance_factor,
+                    position,
+                    total,
+                    is_derby,
+                )
+            })
+            .unwrap_or(0)
+    }
 }
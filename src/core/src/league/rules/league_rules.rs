@@ -0,0 +1,109 @@
+use crate::Player;
+use crate::league::TieBreakPolicy;
+use serde::{Deserialize, Serialize};
+
+/// Points awarded per result. Three for a win is the modern default; the
+/// field exists for historical two-point seasons and experimental formats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PointsSystem {
+    pub win: u8,
+    pub draw: u8,
+    pub loss: u8,
+}
+
+impl Default for PointsSystem {
+    fn default() -> Self {
+        PointsSystem {
+            win: 3,
+            draw: 1,
+            loss: 0,
+        }
+    }
+}
+
+/// Competition rules a country sets for one division: how the table is
+/// scored and ordered, and the squad restrictions its clubs play under.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LeagueRules {
+    pub points: PointsSystem,
+    pub tie_break: TieBreakPolicy,
+    /// Most players of a foreign nationality a club may name in a
+    /// matchday squad. `None` = unrestricted.
+    pub foreign_player_limit: Option<u8>,
+    /// Ceiling on a club's senior squad. Caps the board's own squad-size
+    /// target, which every signing path checks. `None` = board decides.
+    pub max_squad_size: Option<u8>,
+}
+
+impl LeagueRules {
+    /// Players a club may field under the foreign-player limit: every
+    /// national of `country_id` plus the strongest foreigners up to the
+    /// limit. `None` when the league sets no limit.
+    pub fn registered_player_ids(&self, players: &[&Player], country_id: u32) -> Option<Vec<u32>> {
+        let limit = self.foreign_player_limit? as usize;
+
+        let mut foreigners: Vec<&Player> = players
+            .iter()
+            .copied()
+            .filter(|p| p.country_id != country_id)
+            .collect();
+        foreigners.sort_by(|a, b| {
+            b.player_attributes
+                .current_ability
+                .cmp(&a.player_attributes.current_ability)
+                .then(a.id.cmp(&b.id))
+        });
+
+        let mut registered: Vec<u32> = players
+            .iter()
+            .filter(|p| p.country_id == country_id)
+            .map(|p| p.id)
+            .collect();
+        registered.extend(foreigners.iter().take(limit).map(|p| p.id));
+        Some(registered)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{PeopleNameGeneratorData, PlayerGenerator, PlayerPositionType};
+    use chrono::NaiveDate;
+
+    fn player(id: u32, country_id: u32, ability: u8) -> Player {
+        let names = PeopleNameGeneratorData {
+            first_names: vec!["Test".to_string()],
+            last_names: vec!["Player".to_string()],
+            ..Default::default()
+        };
+        let date = NaiveDate::from_ymd_opt(2030, 1, 1).unwrap();
+        let mut p =
+            PlayerGenerator::generate(country_id, date, PlayerPositionType::Striker, 15, &names);
+        p.id = id;
+        p.country_id = country_id;
+        p.player_attributes.current_ability = ability;
+        p
+    }
+
+    #[test]
+    fn no_limit_registers_nobody_explicitly() {
+        let p = player(1, 9, 100);
+        assert_eq!(LeagueRules::default().registered_player_ids(&[&p], 1), None);
+    }
+
+    #[test]
+    fn foreign_limit_keeps_the_strongest_foreigners() {
+        let rules = LeagueRules {
+            foreign_player_limit: Some(1),
+            ..LeagueRules::default()
+        };
+        let local = player(1, 1, 60);
+        let weak = player(2, 9, 80);
+        let strong = player(3, 8, 140);
+        let mut ids = rules
+            .registered_player_ids(&[&local, &weak, &strong], 1)
+            .unwrap();
+        ids.sort();
+        assert_eq!(ids, vec![1, 3]);
+    }
+}
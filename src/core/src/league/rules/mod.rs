@@ -1,3 +1,5 @@
+mod league_rules;
 mod regulations;
 
+pub use league_rules::*;
 pub use regulations::*;
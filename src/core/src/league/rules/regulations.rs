@@ -1,11 +1,12 @@
 use crate::Club;
-use crate::club::player::events::discipline::YELLOW_CARD_BAN_THRESHOLD;
+use crate::club::player::events::discipline::{YELLOW_CARD_BAN_THRESHOLD, red_card_ban_matches};
 use crate::league::LeagueTable;
 use crate::r#match::MatchResult;
 use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeagueRegulations {
     /// player_id → matches still to serve. Mirrors the same counter on
     /// `Player.player_attributes.suspension_matches`; the league copy is
@@ -20,6 +21,10 @@ pub struct LeagueRegulations {
     pub ffp_cases: Vec<FFPCase>,
     /// Concluded FFP cases. Kept around for the financial-history page.
     pub ffp_history: Vec<FFPCase>,
+    /// Cases resolved at a hearing whose club-side effects (fine,
+    /// transfer embargo) haven't been applied yet. The league only sees
+    /// clubs read-only, so these ride out on the day's `LeagueResult`.
+    pub sanctions_to_enforce: Vec<FFPCase>,
     pub pending_cases: Vec<DisciplinaryCase>,
     /// Yellow-card threshold for an accumulation ban. Default follows the
     /// FA / FIFA five-yellows rule but the field is configurable per
@@ -33,7 +38,7 @@ pub struct LeagueRegulations {
     pub ffp_thresholds: FFPThresholds,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FFPThresholds {
     /// Rolling deficit (annual) above which a club is warned but not
     /// sanctioned.
@@ -85,6 +90,7 @@ impl LeagueRegulations {
             yellow_card_accumulation: HashMap::new(),
             ffp_cases: Vec::new(),
             ffp_history: Vec::new(),
+            sanctions_to_enforce: Vec::new(),
             pending_cases: Vec::new(),
             yellow_card_ban_threshold: YELLOW_CARD_BAN_THRESHOLD,
             ffp_thresholds: FFPThresholds::default(),
@@ -92,10 +98,11 @@ impl LeagueRegulations {
     }
 
     /// Walk a finished match's player_stats and update the league's
-    /// disciplinary tracking. Direct reds (and second yellows promoted
-    /// to reds by the engine) trigger a 1-match ban. Single yellows
-    /// accumulate toward `yellow_card_ban_threshold`; crossing it
-    /// triggers a 1-match ban and rolls the counter past the threshold.
+    /// disciplinary tracking. Direct reds trigger a multi-match ban;
+    /// second yellows promoted to reds by the engine trigger a 1-match
+    /// ban. Single yellows accumulate toward `yellow_card_ban_threshold`;
+    /// crossing it triggers a 1-match ban and rolls the counter past the
+    /// threshold.
     /// Returns the suspensions to apply to players.
     pub fn process_disciplinary_actions(&mut self, result: &MatchResult) -> DisciplinaryActions {
         let mut actions = DisciplinaryActions::default();
@@ -105,14 +112,14 @@ impl LeagueRegulations {
 
         for (pid, stats) in &details.player_stats {
             let pid = *pid;
-            // The engine promotes a second yellow into a red, so a
-            // player flagged with `red_cards > 0` should never also
-            // carry `yellow_cards > 0` in the same match. Treat the
-            // red as the only contributor.
+            // The engine promotes a second yellow into a red, keeping
+            // both yellows on the sheet. Treat the red as the only
+            // contributor; its length depends on how it was shown.
             if stats.red_cards > 0 {
+                let ban = red_card_ban_matches(u8::try_from(stats.yellow_cards).unwrap_or(u8::MAX));
                 let entry = self.suspended_players.entry(pid).or_insert(0);
-                *entry = entry.saturating_add(1);
-                actions.new_suspensions.push((pid, 1));
+                *entry = entry.saturating_add(ban);
+                actions.new_suspensions.push((pid, ban));
                 continue;
             }
             if stats.yellow_cards == 0 {
@@ -152,12 +159,11 @@ impl LeagueRegulations {
         }
     }
 
-    /// True when the club's rolling deficit (outcome - income, taken
-    /// over the full ledger horizon already on the balance) exceeds
-    /// the warning band. Detection is country-FFP-gated by the
-    /// caller.
-    pub fn check_ffp_violation(&self, club: &Club) -> bool {
-        self.club_rolling_deficit(club) > self.ffp_thresholds.warning
+    /// True when the club's rolling deficit (outcome - income over the
+    /// trailing twelve months plus the month in progress) exceeds the
+    /// warning band. Detection is country-FFP-gated by the caller.
+    pub fn check_ffp_violation(&self, club: &Club, today: NaiveDate) -> bool {
+        self.club_rolling_deficit(club, today) > self.ffp_thresholds.warning
     }
 
     /// Open a new FFP case if this club is over the warning band and
@@ -165,7 +171,7 @@ impl LeagueRegulations {
     /// case. Idempotent — calling twice on the same matchday only
     /// opens one case.
     pub fn maybe_open_ffp_case(&mut self, club: &Club, today: NaiveDate) {
-        let deficit = self.club_rolling_deficit(club);
+        let deficit = self.club_rolling_deficit(club, today);
         if deficit <= self.ffp_thresholds.warning {
             return;
         }
@@ -237,13 +243,15 @@ impl LeagueRegulations {
         due
     }
 
-    fn club_rolling_deficit(&self, club: &Club) -> i64 {
-        // Existing balance is a running aggregate of revenue and
-        // expense over the ledger horizon. We treat (outcome - income)
-        // as the deficit signal — same as the previous implementation,
-        // but read from the balance struct so a future "trailing 12-
-        // month" rebuild only changes one place.
-        club.finance.balance.outcome - club.finance.balance.income
+    fn club_rolling_deficit(&self, club: &Club, today: NaiveDate) -> i64 {
+        // The live balance only carries the month in progress — it is
+        // cleared into the history snapshots at every month start — so
+        // the rolling window is the trailing twelve completed months
+        // plus whatever the current month has booked so far.
+        let finance = &club.finance;
+        let trailing =
+            finance.trailing_annual_outcome(today) - finance.trailing_annual_income(today);
+        trailing + finance.balance.outcome - finance.balance.income
     }
 
     fn escalate_sanction(&self, deficit: i64) -> FFPSanction {
@@ -268,7 +276,7 @@ impl LeagueRegulations {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FFPCase {
     pub club_id: u32,
     pub violation_type: FFPViolationType,
@@ -290,14 +298,14 @@ pub struct FFPCase {
 /// introduce a divergent type.
 pub type FFPViolation = FFPCase;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FFPViolationType {
     ExcessiveDeficit,
     UnpaidDebts,
     FalseAccounting,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum FFPSanction {
     Warning,
     Fine(u32),
@@ -305,7 +313,7 @@ pub enum FFPSanction {
     TransferBan,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DisciplinaryCase {
     pub player_id: u32,
     pub incident_type: String,
@@ -316,6 +324,7 @@ pub struct DisciplinaryCase {
 mod tests {
     use super::*;
     use crate::PlayerFieldPositionGroup;
+    use crate::club::player::events::discipline::DIRECT_RED_CARD_BAN_MATCHES;
     use crate::r#match::engine::result::{FieldSquad, MatchResultRaw};
     use crate::r#match::result::ResultMatchPositionData;
     use crate::r#match::{PlayerMatchEndStats, Score, TeamScore};
@@ -339,6 +348,8 @@ mod tests {
             final_home_tactic: None,
             final_away_tactic: None,
             shape_change_minute: None,
+            home_possession_ticks: 0,
+            away_possession_ticks: 0,
         };
         MatchResult {
             id: "test".to_string(),
@@ -355,6 +366,7 @@ mod tests {
             },
             details: Some(raw),
             friendly: false,
+            attendance: 0,
         }
     }
 
@@ -405,14 +417,32 @@ mod tests {
     }
 
     #[test]
-    fn red_card_returns_one_match_suspension() {
+    fn straight_red_returns_multi_match_suspension() {
         let mut regs = LeagueRegulations::new();
         let mut stats = HashMap::new();
         stats.insert(42u32, end_stats(0, 1));
         let result = make_match_with_stats(stats);
         let actions = regs.process_disciplinary_actions(&result);
+        assert_eq!(
+            actions.new_suspensions,
+            vec![(42u32, DIRECT_RED_CARD_BAN_MATCHES)]
+        );
+        assert_eq!(
+            regs.suspended_players.get(&42),
+            Some(&DIRECT_RED_CARD_BAN_MATCHES)
+        );
+    }
+
+    #[test]
+    fn second_yellow_red_returns_one_match_suspension() {
+        let mut regs = LeagueRegulations::new();
+        let mut stats = HashMap::new();
+        stats.insert(42u32, end_stats(2, 1));
+        let result = make_match_with_stats(stats);
+        let actions = regs.process_disciplinary_actions(&result);
         assert_eq!(actions.new_suspensions, vec![(42u32, 1)]);
         assert_eq!(regs.suspended_players.get(&42), Some(&1));
+        assert!(!regs.yellow_card_accumulation.contains_key(&42));
     }
 
     #[test]
@@ -475,8 +505,8 @@ mod tests {
             TeamCollection::new(Vec::new()),
             ClubFacilities::default(),
         );
-        // The deficit signal is `outcome - income` per
-        // club_rolling_deficit. Set the balance to encode that.
+        // No history yet, so the rolling deficit is just the month in
+        // progress — encode it as `outcome - income` on the balance.
         if deficit > 0 {
             club.finance.balance.outcome = deficit;
             club.finance.balance.income = 0;
@@ -551,6 +581,24 @@ mod tests {
         assert_eq!(regs.ffp_history.len(), 1);
     }
 
+    #[test]
+    fn rolling_deficit_counts_completed_months() {
+        let mut regs = LeagueRegulations::new();
+        let mut club = make_club_with_deficit(10_000_000);
+        // Ten loss-making months already in the books push the trailing
+        // twelve months past the warning band even though the current
+        // month alone is well inside it.
+        for m in 3..=12 {
+            let mut snap = crate::ClubFinancialBalance::new(0);
+            snap.outcome = 5_000_000;
+            club.finance.history.add(d(2031, m, 1), snap);
+        }
+        assert!(regs.check_ffp_violation(&club, d(2032, 1, 15)));
+        regs.maybe_open_ffp_case(&club, d(2032, 1, 15));
+        assert_eq!(regs.ffp_cases.len(), 1);
+        assert_eq!(regs.ffp_cases[0].recorded_deficit, 60_000_000);
+    }
+
     #[test]
     fn ffp_case_does_not_reopen_inside_cooldown_window() {
         use crate::league::LeagueTable;
@@ -0,0 +1,146 @@
+//! Calendar rules shared by fixture generation and rescheduling: which
+//! dates a league may use, when a midweek round is needed, and how much
+//! rest a team is owed between competitive fixtures.
+
+use crate::NationalTeam;
+use crate::utils::DateUtils;
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+
+/// Fewest clear days between two competitive fixtures for one team. A
+/// Wednesday–Saturday turnaround (two clear days) is the tightest a plan
+/// may go; squad selection treats that turnaround as congestion.
+pub const MIN_REST_DAYS: i64 = 2;
+
+/// How far a postponed fixture may slide looking for a free midweek.
+const RESCHEDULE_HORIZON_DAYS: i64 = 120;
+
+/// Days a league can't stage a fixture: the international breaks, when
+/// clubs lose their internationals to national-team duty.
+pub fn is_blocked_date(date: NaiveDate) -> bool {
+    NationalTeam::is_in_break(date)
+}
+
+/// True when `a` and `b` sit closer together than the rest rule allows.
+pub fn violates_rest(a: NaiveDate, b: NaiveDate) -> bool {
+    (a - b).num_days().abs() <= MIN_REST_DAYS
+}
+
+/// True when a team coming off `previous` into `next` is on the minimum
+/// turnaround — the fixture is legal but the legs are heavy.
+pub fn is_short_rest(previous: NaiveDate, next: NaiveDate) -> bool {
+    (next - previous).num_days() <= MIN_REST_DAYS + 1
+}
+
+/// Plan `rounds` matchdays between `start` and `end`. Every open weekend
+/// is used first; when the window holds fewer weekends than rounds,
+/// midweek rounds (the Wednesday after a weekend round) are spread evenly
+/// across the season. If even that can't fit the programme, weekly rounds
+/// run on past `end` rather than drop fixtures.
+pub fn plan_match_days(start: NaiveDate, end: NaiveDate, rounds: usize) -> Vec<NaiveDate> {
+    let mut weekends: Vec<NaiveDate> = Vec::new();
+    let mut date = DateUtils::next_saturday(start);
+    while date <= end {
+        if !is_blocked_date(date) {
+            weekends.push(date);
+        }
+        date += Duration::days(7);
+    }
+
+    if weekends.len() >= rounds {
+        weekends.truncate(rounds);
+        return weekends;
+    }
+
+    let midweeks: Vec<NaiveDate> = weekends
+        .iter()
+        .map(|d| *d + Duration::days(4))
+        .filter(|d| *d <= end && !is_blocked_date(*d))
+        .collect();
+    let needed = rounds - weekends.len();
+    let mut days = weekends;
+    if needed >= midweeks.len() {
+        days.extend(midweeks);
+    } else {
+        let step = midweeks.len() as f64 / needed as f64;
+        days.extend((0..needed).map(|i| midweeks[((i as f64 + 0.5) * step) as usize]));
+    }
+    days.sort_unstable();
+
+    let mut next = days.last().map_or(DateUtils::next_saturday(start), |d| {
+        DateUtils::next_saturday(*d + Duration::days(1))
+    });
+    while days.len() < rounds {
+        if !is_blocked_date(next) {
+            days.push(next);
+        }
+        next += Duration::days(7);
+    }
+    days
+}
+
+/// Earliest midweek after `from` (and no later than `until`) that is
+/// open on the calendar and keeps every date in `busy` outside the rest
+/// window. `None` when the horizon is exhausted.
+pub fn next_free_midweek(
+    from: NaiveDate,
+    until: NaiveDate,
+    busy: &[NaiveDate],
+) -> Option<NaiveDate> {
+    let last = until.min(from + Duration::days(RESCHEDULE_HORIZON_DAYS));
+    let mut date = from + Duration::days(1);
+    while date <= last {
+        if matches!(date.weekday(), Weekday::Tue | Weekday::Wed)
+            && !is_blocked_date(date)
+            && !busy.iter().any(|b| violates_rest(*b, date))
+        {
+            return Some(date);
+        }
+        date += Duration::days(1);
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn weekend_plan_skips_international_breaks() {
+        let days = plan_match_days(d(2026, 8, 1), d(2027, 5, 31), 36);
+        assert_eq!(days.len(), 36);
+        assert!(days.iter().all(|day| !is_blocked_date(*day)));
+        assert!(days.iter().all(|day| day.weekday() == Weekday::Sat));
+    }
+
+    #[test]
+    fn short_window_adds_spread_midweek_rounds() {
+        let days = plan_match_days(d(2026, 8, 1), d(2026, 12, 20), 26);
+        assert_eq!(days.len(), 26);
+        assert!(days.iter().any(|day| day.weekday() == Weekday::Wed));
+        assert!(days.iter().all(|day| *day <= d(2026, 12, 20)));
+        for pair in days.windows(2) {
+            assert!(!violates_rest(pair[0], pair[1]), "{:?}", pair);
+        }
+    }
+
+    #[test]
+    fn overfull_programme_runs_past_the_window() {
+        let days = plan_match_days(d(2026, 8, 1), d(2026, 10, 1), 20);
+        assert_eq!(days.len(), 20);
+        assert!(days.last().unwrap() > &d(2026, 10, 1));
+        assert!(days.windows(2).all(|p| p[0] < p[1]));
+    }
+
+    #[test]
+    fn free_midweek_respects_rest_around_busy_dates() {
+        // Saturday 2026-10-24; a cup tie on Wednesday the 28th blocks the
+        // 27th/28th, so the first free slot is the following Tuesday.
+        let busy = [d(2026, 10, 24), d(2026, 10, 28), d(2026, 10, 31)];
+        let slot = next_free_midweek(d(2026, 10, 24), d(2027, 5, 31), &busy);
+        assert_eq!(slot, Some(d(2026, 11, 3)));
+    }
+}
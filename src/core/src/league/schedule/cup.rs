@@ -129,9 +129,9 @@ pub fn cup_champion(tours: &[ScheduleTour], round_one_field: &[u32]) -> Option<u
 }
 
 /// Snap `date` forward to the next Wednesday. Cup ties are midweek so they
-/// never collide with the Saturday league programme (the round-robin
-/// scheduler always lands on Saturdays), which keeps a team off two
-/// fixtures on the same day without any cross-competition date bookkeeping.
+/// stay clear of the weekend league programme; a midweek league round that
+/// lands too close to a tie is the one postponed (see
+/// `Schedule::postpone_clashes`).
 pub fn next_midweek(date: NaiveDate) -> NaiveDate {
     let mut d = date;
     while d.weekday() != Weekday::Wed {
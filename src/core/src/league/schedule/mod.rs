@@ -1,3 +1,4 @@
+pub mod calendar;
 pub mod cup;
 pub mod result;
 pub mod round;
@@ -1,7 +1,7 @@
+use crate::league::schedule::calendar;
 use crate::league::{
     LeagueSettings, ScheduleError, ScheduleGenerator, ScheduleItem, ScheduleTour, Season,
 };
-use crate::utils::DateUtils;
 use chrono::Duration;
 use chrono::NaiveDate;
 use chrono::prelude::*;
@@ -39,40 +39,68 @@ impl ScheduleGenerator for RoundSchedule {
             ScheduleError::from_str("team_len is empty");
         }
 
-        let season_year_start = season.start_year;
-
-        let current_date = DateUtils::next_saturday(
-            NaiveDate::from_ymd_opt(
-                season_year_start as i32,
-                league_settings.season_starting_half.from_month as u32,
-                league_settings.season_starting_half.from_day as u32,
-            )
-            .unwrap(),
-        );
-
-        let current_date_time =
-            NaiveDateTime::new(current_date, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
+        let season_year_start = season.start_year as i32;
+        let starting = &league_settings.season_starting_half;
+        let ending = &league_settings.season_ending_half;
+
+        let season_start = NaiveDate::from_ymd_opt(
+            season_year_start,
+            starting.from_month as u32,
+            starting.from_day as u32,
+        )
+        .unwrap();
+        // The closing day falls in the following calendar year whenever
+        // it comes earlier in the year than the opening day.
+        let end_year =
+            if (ending.to_month, ending.to_day) < (starting.from_month, starting.from_day) {
+                season_year_start + 1
+            } else {
+                season_year_start
+            };
+        let season_end =
+            NaiveDate::from_ymd_opt(end_year, ending.to_month as u32, ending.to_day as u32)
+                .unwrap_or(season_start + Duration::days(300));
 
         let tours_count = (teams_len * teams_len - teams_len) / (teams_len / 2);
+        let rounds = if teams_len.is_multiple_of(2) {
+            teams_len.saturating_sub(1) * 2
+        } else {
+            teams_len * 2
+        };
 
         // Split seasons (Apertura/Clausura) restart the mirrored second
         // round-robin on the second tournament's own opening weekend
-        // instead of running straight through the mid-year break.
+        // instead of running straight through the mid-year break; each
+        // tournament is planned inside its own window.
         let second_half_start = if league_settings.split_season {
-            let start_month = league_settings.season_starting_half.from_month;
-            let second = &league_settings.season_ending_half;
-            let year = if second.from_month >= start_month {
-                season_year_start as i32
+            let year = if ending.from_month >= starting.from_month {
+                season_year_start
             } else {
-                season_year_start as i32 + 1
+                season_year_start + 1
             };
-            NaiveDate::from_ymd_opt(year, second.from_month as u32, second.from_day as u32)
-                .map(DateUtils::next_saturday)
-                .map(|d| NaiveDateTime::new(d, NaiveTime::from_hms_opt(0, 0, 0).unwrap()))
+            NaiveDate::from_ymd_opt(year, ending.from_month as u32, ending.from_day as u32)
         } else {
             None
         };
 
+        let match_days = match second_half_start {
+            Some(second_start) if second_start > season_start => {
+                let mut days = calendar::plan_match_days(
+                    season_start,
+                    second_start - Duration::days(1),
+                    rounds / 2,
+                );
+                let first_end = days.last().copied().unwrap_or(season_start);
+                days.extend(calendar::plan_match_days(
+                    second_start.max(first_end + Duration::days(1)),
+                    season_end,
+                    rounds - rounds / 2,
+                ));
+                days
+            }
+            _ => calendar::plan_match_days(season_start, season_end, rounds),
+        };
+
         let mut result = Vec::with_capacity(tours_count);
 
         result.extend(generate_tours(
@@ -80,8 +108,7 @@ impl ScheduleGenerator for RoundSchedule {
             String::from(league_slug),
             teams,
             tours_count,
-            current_date_time,
-            second_half_start,
+            &match_days,
         ));
 
         Ok(result)
@@ -93,8 +120,7 @@ fn generate_tours(
     league_slug: String,
     teams: &[u32],
     tours_count: usize,
-    mut current_date: NaiveDateTime,
-    second_half_start: Option<NaiveDateTime>,
+    match_days: &[NaiveDate],
 ) -> Vec<ScheduleTour> {
     if teams.len() < 2 {
         return Vec::new();
@@ -130,15 +156,13 @@ fn generate_tours(
 
     let mut games_offset = 0;
     for tour_idx in 0..rounds_to_emit {
-        // Second tournament of a split season: jump to its own window
-        // (but never backwards, in case the halves overlap in config).
-        if tour_idx == rounds_to_emit / 2 {
-            if let Some(second_start) = second_half_start {
-                if second_start > current_date {
-                    current_date = second_start;
-                }
-            }
-        }
+        // Planned matchday, or a week past the last one should the plan
+        // come up short.
+        let day = match_days.get(tour_idx).copied().unwrap_or_else(|| {
+            let last = match_days.last().copied().unwrap_or_default();
+            last + Duration::days(7 * (tour_idx + 1 - match_days.len()) as i64)
+        });
+        let current_date = NaiveDateTime::new(day, NaiveTime::from_hms_opt(0, 0, 0).unwrap());
         let mut tour = ScheduleTour::new((tour_idx + 1) as u8, games_per_round);
 
         for game_idx in 0..games_per_round {
@@ -163,7 +187,6 @@ fn generate_tours(
         }
 
         games_offset += games_per_round;
-        current_date += Duration::days(7);
 
         result.push(tour);
     }
@@ -277,6 +300,8 @@ mod tests {
             relegation_spots: 0,
             league_group: None,
             split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
         let tours = RoundSchedule::new()
             .generate(1, "t", Season::new(2026), &teams, &settings)
@@ -336,6 +361,8 @@ mod tests {
             relegation_spots: 0,
             league_group: None,
             split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
         let tours = RoundSchedule::new()
             .generate(1, "t", Season::new(2026), &teams, &settings)
@@ -394,6 +421,8 @@ mod tests {
             relegation_spots: 0,
             league_group: None,
             split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
         let tours = RoundSchedule::new()
             .generate(1, "t", Season::new(2026), &teams, &settings)
@@ -450,6 +479,8 @@ mod tests {
             relegation_spots: 1,
             league_group: None,
             split_season: true,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
         let tours = RoundSchedule::new()
             .generate(1, "t", Season::new(2026), &teams, &settings)
@@ -513,6 +544,8 @@ mod tests {
             relegation_spots: 0,
             league_group: None,
             split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
 
         let schedule_tours = schedule
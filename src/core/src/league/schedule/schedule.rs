@@ -1,22 +1,25 @@
 use crate::context::GlobalContext;
 use crate::league::round::RoundSchedule;
+use crate::league::schedule::calendar;
 use crate::league::{LeagueMatch, LeagueSettings, ScheduleGenerator, ScheduleResult, Season};
 use crate::r#match::Score;
 use chrono::{Datelike, NaiveDate, NaiveDateTime};
-use log::error;
+use log::{debug, error};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Schedule {
     pub tours: Vec<ScheduleTour>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleTour {
     pub num: u8,
     pub items: Vec<ScheduleItem>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScheduleItem {
     pub id: String,
 
@@ -121,6 +124,88 @@ impl Schedule {
             .collect()
     }
 
+    /// Move today's unplayed fixtures that can't go ahead: the date falls
+    /// in an international break, or either side has another competitive
+    /// fixture in `commitments` (team_id → dates) inside the rest window.
+    /// Each is re-slotted on the first free midweek up to `season_end`
+    /// that keeps both sides rested against this schedule and their
+    /// commitments. A fixture with no free slot is left in place. Returns
+    /// the number of fixtures moved.
+    pub fn postpone_clashes(
+        &mut self,
+        today: NaiveDate,
+        season_end: NaiveDate,
+        commitments: &HashMap<u32, Vec<NaiveDate>>,
+    ) -> usize {
+        let blocked_today = calendar::is_blocked_date(today);
+        let clashes = |team_id: u32| {
+            commitments
+                .get(&team_id)
+                .is_some_and(|dates| dates.iter().any(|d| calendar::violates_rest(*d, today)))
+        };
+
+        let to_move: Vec<(usize, usize)> = self
+            .tours
+            .iter()
+            .enumerate()
+            .flat_map(|(t, tour)| {
+                tour.items
+                    .iter()
+                    .enumerate()
+                    .map(move |(i, item)| (t, i, item))
+            })
+            .filter(|(_, _, item)| item.result.is_none() && item.date.date() == today)
+            .filter(|(_, _, item)| {
+                blocked_today || clashes(item.home_team_id) || clashes(item.away_team_id)
+            })
+            .map(|(t, i, _)| (t, i))
+            .collect();
+
+        let mut moved = 0;
+        for (t, i) in to_move {
+            let (home, away) = {
+                let item = &self.tours[t].items[i];
+                (item.home_team_id, item.away_team_id)
+            };
+            let mut busy: Vec<NaiveDate> = self
+                .tours
+                .iter()
+                .flat_map(|tour| &tour.items)
+                .filter(|item| item.result.is_none() && item.date.date() > today)
+                .filter(|item| {
+                    [item.home_team_id, item.away_team_id]
+                        .iter()
+                        .any(|id| *id == home || *id == away)
+                })
+                .map(|item| item.date.date())
+                .collect();
+            for team_id in [home, away] {
+                if let Some(dates) = commitments.get(&team_id) {
+                    busy.extend(dates.iter().copied());
+                }
+            }
+
+            let Some(new_day) = calendar::next_free_midweek(today, season_end, &busy) else {
+                continue;
+            };
+            let item = &self.tours[t].items[i];
+            debug!(
+                "Postponed {} v {} from {} to {}",
+                home, away, today, new_day
+            );
+            self.tours[t].items[i] = ScheduleItem::new(
+                item.league_id,
+                item.league_slug.clone(),
+                home,
+                away,
+                new_day.and_time(item.date.time()),
+                None,
+            );
+            moved += 1;
+        }
+        moved
+    }
+
     pub fn update_match_result(&mut self, id: &str, score: &Score) {
         let mut _updated = false;
 
@@ -373,4 +458,69 @@ mod tests {
         };
         assert_eq!(schedule_tour.end_date(), item2.date.date());
     }
+
+    fn fixture(home: u32, away: u32, date: NaiveDate) -> ScheduleItem {
+        ScheduleItem::new(
+            1,
+            "slug".to_string(),
+            home,
+            away,
+            date.and_hms_opt(0, 0, 0).unwrap(),
+            None,
+        )
+    }
+
+    #[test]
+    fn fixture_clashing_with_a_cup_tie_moves_to_a_free_midweek() {
+        let saturday = NaiveDate::from_ymd_opt(2026, 10, 24).unwrap();
+        let next_saturday = NaiveDate::from_ymd_opt(2026, 10, 31).unwrap();
+        let mut schedule = Schedule {
+            tours: vec![
+                ScheduleTour {
+                    num: 1,
+                    items: vec![fixture(1, 2, saturday), fixture(3, 4, saturday)],
+                },
+                ScheduleTour {
+                    num: 2,
+                    items: vec![fixture(2, 1, next_saturday)],
+                },
+            ],
+        };
+        // Team 1 has a cup tie the next day.
+        let commitments =
+            HashMap::from([(1u32, vec![NaiveDate::from_ymd_opt(2026, 10, 25).unwrap()])]);
+        let season_end = NaiveDate::from_ymd_opt(2027, 5, 31).unwrap();
+
+        assert_eq!(
+            schedule.postpone_clashes(saturday, season_end, &commitments),
+            1
+        );
+
+        let moved = &schedule.tours[0].items[0];
+        // Tue 27th sits inside the rest window of the 25th, so Wed 28th.
+        assert_eq!(
+            moved.date.date(),
+            NaiveDate::from_ymd_opt(2026, 10, 28).unwrap()
+        );
+        assert_eq!(moved.id, format!("{}_1_2", moved.date.date()));
+        assert_eq!(schedule.tours[0].items[1].date.date(), saturday);
+    }
+
+    #[test]
+    fn fixture_inside_an_international_break_is_postponed() {
+        let in_break = NaiveDate::from_ymd_opt(2026, 10, 10).unwrap();
+        let mut schedule = Schedule {
+            tours: vec![ScheduleTour {
+                num: 1,
+                items: vec![fixture(1, 2, in_break)],
+            }],
+        };
+        let season_end = NaiveDate::from_ymd_opt(2027, 5, 31).unwrap();
+        assert_eq!(
+            schedule.postpone_clashes(in_break, season_end, &HashMap::new()),
+            1
+        );
+        let new_date = schedule.tours[0].items[0].date.date();
+        assert!(new_date > NaiveDate::from_ymd_opt(2026, 10, 17).unwrap());
+    }
 }
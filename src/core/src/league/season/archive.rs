@@ -0,0 +1,109 @@
+use crate::league::{LeagueSettings, LeagueTableRow};
+use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
+
+/// One completed league season, frozen at season end before the table is
+/// reset for the next campaign. Powers the league History tab and the
+/// club Hall of Fame. Team and player ids, not names, so a renamed club
+/// or a retired scorer still resolves when the page is rendered.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LeagueSeasonRecord {
+    /// Calendar year the season kicked off in.
+    pub season_start_year: i32,
+    /// Final standings — the annual aggregate for split seasons.
+    pub table: Vec<LeagueTableRow>,
+    /// League top scorer as `(player_id, goals)`.
+    pub top_scorer: Option<(u32, u16)>,
+}
+
+impl LeagueSeasonRecord {
+    /// Start year of the season ending on `season_end`. Autumn-spring
+    /// leagues (the end month falls on or before the start month) began
+    /// the previous calendar year.
+    pub fn start_year_for(settings: &LeagueSettings, season_end: NaiveDate) -> i32 {
+        let wraps =
+            settings.season_ending_half.to_month <= settings.season_starting_half.from_month;
+        if wraps {
+            season_end.year() - 1
+        } else {
+            season_end.year()
+        }
+    }
+
+    pub fn champion_team_id(&self) -> Option<u32> {
+        self.table.first().map(|r| r.team_id)
+    }
+
+    pub fn runner_up_team_id(&self) -> Option<u32> {
+        self.table.get(1).map(|r| r.team_id)
+    }
+
+    /// 1-indexed final position of `team_id`, if it played the season.
+    pub fn position_of(&self, team_id: u32) -> Option<usize> {
+        self.table
+            .iter()
+            .position(|r| r.team_id == team_id)
+            .map(|idx| idx + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::league::DayMonthPeriod;
+
+    fn settings(start_month: u8, end_month: u8) -> LeagueSettings {
+        LeagueSettings {
+            season_starting_half: DayMonthPeriod::new(1, start_month, 31, 12),
+            season_ending_half: DayMonthPeriod::new(1, 1, 30, end_month),
+            tier: 1,
+            promotion_spots: 0,
+            relegation_spots: 0,
+            league_group: None,
+            split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
+        }
+    }
+
+    fn row(team_id: u32) -> LeagueTableRow {
+        LeagueTableRow {
+            team_id,
+            played: 0,
+            win: 0,
+            draft: 0,
+            lost: 0,
+            goal_scored: 0,
+            goal_concerned: 0,
+            points: 0,
+            points_deduction: 0,
+        }
+    }
+
+    #[test]
+    fn autumn_spring_seasons_start_the_year_before_they_end() {
+        let end = NaiveDate::from_ymd_opt(2031, 5, 30).unwrap();
+        assert_eq!(
+            LeagueSeasonRecord::start_year_for(&settings(8, 5), end),
+            2030
+        );
+        let end = NaiveDate::from_ymd_opt(2031, 11, 30).unwrap();
+        assert_eq!(
+            LeagueSeasonRecord::start_year_for(&settings(3, 11), end),
+            2031
+        );
+    }
+
+    #[test]
+    fn reads_finishers_from_the_final_table() {
+        let record = LeagueSeasonRecord {
+            season_start_year: 2030,
+            table: vec![row(7), row(3), row(9)],
+            top_scorer: None,
+        };
+        assert_eq!(record.champion_team_id(), Some(7));
+        assert_eq!(record.runner_up_team_id(), Some(3));
+        assert_eq!(record.position_of(9), Some(3));
+        assert_eq!(record.position_of(1), None);
+    }
+}
@@ -1,5 +1,7 @@
+mod archive;
 mod phase;
 mod season;
 
+pub use archive::*;
 pub use phase::*;
 pub use season::*;
@@ -1,11 +1,15 @@
 use crate::club::staff::perception::{AbilityEstimator, DevelopmentFormEvidence};
 use crate::context::GlobalContext;
-use crate::league::{League, LeagueDynamics, LeagueMatch, LeagueMatchResultResult, LeagueTable};
+use crate::league::schedule::calendar;
+use crate::league::{
+    League, LeagueDynamics, LeagueMatch, LeagueMatchResultResult, LeagueRules, LeagueTable,
+};
 use crate::r#match::MatchSquad;
 use crate::r#match::squad::selection::model::MatchSelectionGameModel;
 use crate::r#match::{Match, MatchResult, SelectionCompetition, SelectionContext};
 use crate::{
-    Club, ClubPhilosophy, MatchRuntime, Person, Player, PlayerFieldPositionGroup, Team, TeamType,
+    Club, ClubPhilosophy, MatchRuntime, Person, Player, PlayerFieldPositionGroup, PlayerStatusType,
+    Team, TeamType,
 };
 use chrono::Duration;
 use chrono::{Datelike, NaiveDate};
@@ -121,6 +125,7 @@ impl League {
                     ctx,
                     &self.dynamics,
                     &self.table,
+                    (&self.rules, self.country_id),
                     friendly,
                     knockout,
                     is_cup,
@@ -182,6 +187,7 @@ impl League {
         ctx: &GlobalContext<'_>,
         dynamics: &LeagueDynamics,
         table: &LeagueTable,
+        (rules, country_id): (&LeagueRules, u32),
         friendly: bool,
         knockout: bool,
         is_cup: bool,
@@ -267,6 +273,19 @@ impl League {
             )
         };
 
+        // A side on the minimum turnaround from its previous fixture (a
+        // cup tie or midweek round) carries that load into this one, so
+        // the short rest counts as one more congested fixture.
+        let short_rest = |team: &Team| -> u8 {
+            team.fixture_window
+                .previous_before(date - Duration::days(1))
+                .is_some_and(|prev| calendar::is_short_rest(prev, date)) as u8
+        };
+        let upcoming_fixtures = (
+            upcoming_fixtures.0 + short_rest(home_team),
+            upcoming_fixtures.1 + short_rest(away_team),
+        );
+
         // Fixture congestion tilt: if a team has another competitive
         // fixture within the next 5 days, dampen this match's importance
         // for them so the rotation/development logic kicks in. Applied
@@ -350,32 +369,57 @@ impl League {
         // environment carries the venue, and congestion mirrors the same
         // upcoming-fixture count the importance dampener uses. Friendlies
         // skip it — the rotation selector never reads the model.
-        if !friendly {
-            // Rivalry read for the derby classification — either club
-            // listing the other as a rival makes the fixture a derby.
-            let is_derby = match (
+        // Rivalry read for the derby classification — either club listing
+        // the other as a rival makes the fixture a derby. A friendly
+        // between rivals is still just a friendly.
+        let is_derby = !friendly
+            && match (
                 lookup.club(home_team.club_id),
                 lookup.club(away_team.club_id),
             ) {
                 (Some(h), Some(a)) => h.is_rival(a.id) || a.is_rival(h.id),
                 _ => false,
             };
-            home_ctx.game_model = Some(MatchSelectionGameModel::build_for_fixture(
+        if !friendly {
+            let mut home_model = MatchSelectionGameModel::build_for_fixture(
                 &home_ctx,
                 home_team,
                 away_team,
                 true,
                 upcoming_fixtures.0,
                 is_derby,
-            ));
-            away_ctx.game_model = Some(MatchSelectionGameModel::build_for_fixture(
+            );
+            let mut away_model = MatchSelectionGameModel::build_for_fixture(
                 &away_ctx,
                 away_team,
                 home_team,
                 false,
                 upcoming_fixtures.1,
                 is_derby,
-            ));
+            );
+            // Registration: the club's whole roster is registered so
+            // reserve call-ups stay eligible, minus players the country's
+            // squad list left off (Unr) and the foreigners that don't
+            // fit under the league's cap.
+            let registration = |team: &Team| {
+                lookup.club(team.club_id).and_then(|club| {
+                    let roster: Vec<&Player> =
+                        club.teams.iter().flat_map(|t| t.players.players()).collect();
+                    let players: Vec<&Player> = roster
+                        .iter()
+                        .copied()
+                        .filter(|p| !p.statuses.has(PlayerStatusType::Unr))
+                        .collect();
+                    rules.registered_player_ids(&players, country_id).or_else(|| {
+                        (players.len() < roster.len())
+                            .then(|| players.iter().map(|p| p.id).collect())
+                    })
+                })
+            };
+            home_model.competition_rules.registered_player_ids = registration(home_team);
+            away_model.competition_rules.registered_player_ids = registration(away_team);
+            home_ctx.game_model = Some(home_model);
+            away_ctx.game_model = Some(away_model);
         }
 
         let (mut home_squad, mut away_squad) = if friendly {
@@ -444,7 +488,7 @@ impl League {
         Self::apply_psychological_factors_static(&mut home_squad, home_momentum, home_pressure);
         Self::apply_psychological_factors_static(&mut away_squad, away_momentum, away_pressure);
 
-        if knockout {
+        let mut game = if knockout {
             Match::make_knockout(
                 scheduled_match.id.clone(),
                 scheduled_match.league_id,
@@ -461,7 +505,9 @@ impl League {
                 away_squad,
                 friendly,
             )
-        }
+        };
+        game.is_derby = is_derby;
+        game
     }
 
     /// Collect available reserve players from the same club.
@@ -1182,7 +1228,7 @@ mod tests {
         PeopleNameGeneratorData {
             first_names: vec!["Test".to_string()],
             last_names: vec!["Keeper".to_string()],
-            nicknames: Vec::new(),
+            ..Default::default()
         }
     }
 
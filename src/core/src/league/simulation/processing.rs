@@ -1,10 +1,10 @@
 use crate::Club;
 use crate::context::GlobalContext;
-use crate::league::League;
 use crate::league::awards::{
     AwardAggregator, MonthlyAwardSelector, SeasonAwardSelector, SeasonAwardsSnapshot,
     TeamOfTheWeekSelector,
 };
+use crate::league::{League, LeagueSeasonRecord};
 use crate::r#match::MatchResult;
 use crate::utils::DateUtils;
 use chrono::Duration;
@@ -171,10 +171,12 @@ impl League {
 
         // Resolve any cases whose hearing landed in the past — applies
         // point deductions to the live table and archives the case
-        // exactly once.
-        let _ = self
+        // exactly once. Fines and embargoes need mutable clubs, so they
+        // are queued for the result stage.
+        let resolved = self
             .regulations
             .resolve_due_ffp_cases(today, &mut self.table);
+        self.regulations.sanctions_to_enforce.extend(resolved);
 
         self.regulations.process_pending_cases(today);
     }
@@ -233,11 +235,20 @@ impl League {
         // Split seasons freeze the ANNUAL aggregate (Apertura + Clausura)
         // — that's the table that drives prizes, qualification and
         // relegation in those competitions.
-        self.final_table = Some(if self.settings.split_season {
+        let final_table = if self.settings.split_season {
             self.annual_table_rows()
         } else {
             self.table.rows.clone()
+        };
+
+        // Archive the season before anything is reset, so long saves keep
+        // a browsable record of every finished campaign.
+        self.history.push(LeagueSeasonRecord {
+            season_start_year: LeagueSeasonRecord::start_year_for(&self.settings, current_date),
+            table: final_table.clone(),
+            top_scorer: self.season_top_scorer(current_date),
         });
+        self.final_table = Some(final_table);
 
         self.dynamics.reset_for_new_season();
         self.statistics.archive_season_stats();
@@ -247,6 +258,19 @@ impl League {
         self.regulations.pending_cases.clear();
     }
 
+    /// Most league goals this season from the match log, as
+    /// `(player_id, goals)`. Ties go to the player with fewer games.
+    fn season_top_scorer(&self, current_date: NaiveDate) -> Option<(u32, u16)> {
+        AwardAggregator::aggregate(self.matches.iter_in_range(
+            current_date - Duration::days(366),
+            current_date + Duration::days(1),
+        ))
+        .into_iter()
+        .filter(|(_, agg)| agg.goals > 0)
+        .max_by_key(|(id, agg)| (agg.goals, std::cmp::Reverse(agg.matches_played), *id))
+        .map(|(id, agg)| (id, agg.goals as u16))
+    }
+
     /// Compute the per-league `SeasonAwardsSnapshot` from this season's
     /// matches, statistics, and clubs. Read-only; does not mutate league
     /// state. Called during `process_season_end` before stats archive.
@@ -2,9 +2,10 @@ use crate::league::LeagueTable;
 use crate::r#match::{MatchResult, MatchResultOutcome, Score};
 use chrono::Weekday;
 use log::debug;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeagueDynamics {
     pub team_momentum: HashMap<u32, f32>,
     pub team_streaks: HashMap<u32, TeamStreak>,
@@ -197,26 +198,26 @@ impl LeagueDynamics {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TeamStreak {
     pub winning_streak: u8,
     pub losing_streak: u8,
     pub unbeaten_streak: u8,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TitleRace {
     pub leader_id: u32,
     pub gap_to_second: i8,
     pub contenders: Vec<u32>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct RelegationBattle {
     pub teams_in_danger: Vec<u32>,
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct EuropeanRace {
     pub teams_in_contention: Vec<u32>,
 }
@@ -1,8 +1,9 @@
 use crate::league::{LeagueStatistics, LeagueTable};
 use chrono::{Datelike, NaiveDate};
 use log::debug;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeagueMilestones {
     pub all_time_records: AllTimeRecords,
     pub season_milestones: Vec<Milestone>,
@@ -114,7 +115,7 @@ impl LeagueMilestones {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AllTimeRecords {
     pub most_points_in_season: (u32, u8),
     pub most_goals_in_season: (u32, i32),
@@ -124,7 +125,7 @@ pub struct AllTimeRecords {
     pub longest_unbeaten_streak: (u32, u8),
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Milestone {
     pub milestone_type: MilestoneType,
     pub team_id: u32,
@@ -132,7 +133,7 @@ pub struct Milestone {
     pub matches_played: u8,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum MilestoneType {
     TitleWon,
     RelegationConfirmed,
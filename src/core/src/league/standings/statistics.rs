@@ -2,9 +2,10 @@ use crate::Club;
 use crate::league::LeagueTable;
 use crate::r#match::MatchResult;
 use log::debug;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeagueStatistics {
     pub total_goals: u32,
     pub total_matches: u32,
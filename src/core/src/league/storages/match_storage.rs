@@ -1,6 +1,7 @@
 use crate::r#match::MatchResult;
 use chrono::Duration;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 use std::collections::{BTreeMap, HashMap};
 
 /// Default retention window — three completed seasons. Long enough for anyrt
@@ -9,7 +10,7 @@ use std::collections::{BTreeMap, HashMap};
 /// on multi-decade saves.
 pub const DEFAULT_RETENTION_DAYS: i64 = 365 * 3 + 1;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MatchStorage {
     results: HashMap<String, MatchResult>,
     /// Secondary index: date → match ids recorded that day. Used to drop
@@ -144,6 +145,7 @@ mod tests {
             home_team_id: 0,
             away_team_id: 0,
             friendly: false,
+            attendance: 0,
         }
     }
 
@@ -1,7 +1,10 @@
 use crate::context::GlobalContext;
-use crate::league::LeagueTableResult;
+use crate::league::{LeagueRules, LeagueTableResult, PointsSystem};
 use crate::r#match::MatchResult;
+use serde::{Deserialize, Serialize};
 use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::str::FromStr;
 
 /// Configurable tie-break order for the league table. Each variant is a
 /// concrete sort key derived from a row; they are compared in the order
@@ -10,10 +13,11 @@ use std::cmp::Ordering;
 /// last key keeps sorts deterministic across re-runs without leaving the
 /// outcome dependent on insertion order.
 ///
-/// `HeadToHead` is reserved as a public hook: a future implementation
-/// may carry the per-pair record on the table itself; for now the
-/// comparator returns `Ordering::Equal` so the chain falls through.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// `HeadToHead` ranks the sides still level on every earlier key by a
+/// mini-table of the matches between them (points, then goal difference,
+/// then goals). The table builds that mini-table when it re-sorts; a bare
+/// `compare` without one falls through to the next key.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TieBreakRule {
     Points,
     GoalDifference,
@@ -23,7 +27,47 @@ pub enum TieBreakRule {
     TeamId,
 }
 
-#[derive(Debug, Clone)]
+impl FromStr for TieBreakRule {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "points" => Ok(TieBreakRule::Points),
+            "goal_difference" => Ok(TieBreakRule::GoalDifference),
+            "goals_scored" => Ok(TieBreakRule::GoalsScored),
+            "wins" => Ok(TieBreakRule::Wins),
+            "head_to_head" => Ok(TieBreakRule::HeadToHead),
+            "team_id" => Ok(TieBreakRule::TeamId),
+            _ => Err(format!("unknown tie-break rule: {}", s)),
+        }
+    }
+}
+
+/// A side's record in the mini-table of matches between tied teams.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HeadToHeadRecord {
+    pub points: u16,
+    pub goals_for: i32,
+    pub goals_against: i32,
+}
+
+impl HeadToHeadRecord {
+    fn add(&mut self, other: &HeadToHeadRecord) {
+        self.points += other.points;
+        self.goals_for += other.goals_for;
+        self.goals_against += other.goals_against;
+    }
+
+    fn key(&self) -> (u16, i32, i32) {
+        (
+            self.points,
+            self.goals_for - self.goals_against,
+            self.goals_for,
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TieBreakPolicy {
     pub rules: Vec<TieBreakRule>,
 }
@@ -41,17 +85,46 @@ impl TieBreakPolicy {
         }
     }
 
+    /// Build a policy from a configured order. `TeamId` is appended when
+    /// missing so the sort stays deterministic; an empty list falls back
+    /// to the FIFA chain.
+    pub fn from_rules(mut rules: Vec<TieBreakRule>) -> Self {
+        if rules.is_empty() {
+            return Self::fifa_default();
+        }
+        if !rules.contains(&TieBreakRule::TeamId) {
+            rules.push(TieBreakRule::TeamId);
+        }
+        TieBreakPolicy { rules }
+    }
+
     /// Compare two rows: `a < b` means `a` ranks higher (sorts first).
     /// All non-id keys descend (more is better); team_id ascends so the
     /// numerically smaller id wins the otherwise-tied bucket.
     pub fn compare(&self, a: &LeagueTableRow, b: &LeagueTableRow) -> Ordering {
+        self.compare_with(a, b, &HashMap::new())
+    }
+
+    /// `compare` with a head-to-head mini-table (team_id → record among
+    /// the tied sides). Teams absent from it compare equal on that key.
+    pub fn compare_with(
+        &self,
+        a: &LeagueTableRow,
+        b: &LeagueTableRow,
+        head_to_head: &HashMap<u32, HeadToHeadRecord>,
+    ) -> Ordering {
         for rule in &self.rules {
             let ord = match rule {
                 TieBreakRule::Points => b.effective_points().cmp(&a.effective_points()),
                 TieBreakRule::GoalDifference => b.goal_difference().cmp(&a.goal_difference()),
                 TieBreakRule::GoalsScored => b.goal_scored.cmp(&a.goal_scored),
                 TieBreakRule::Wins => b.win.cmp(&a.win),
-                TieBreakRule::HeadToHead => Ordering::Equal,
+                TieBreakRule::HeadToHead => {
+                    match (head_to_head.get(&a.team_id), head_to_head.get(&b.team_id)) {
+                        (Some(ha), Some(hb)) => hb.key().cmp(&ha.key()),
+                        _ => Ordering::Equal,
+                    }
+                }
                 TieBreakRule::TeamId => a.team_id.cmp(&b.team_id),
             };
             if ord != Ordering::Equal {
@@ -60,6 +133,19 @@ impl TieBreakPolicy {
         }
         Ordering::Equal
     }
+
+    /// The keys ahead of `HeadToHead` — the sides level on all of them
+    /// form the groups a mini-table is built for. `None` when the policy
+    /// doesn't use head-to-head at all.
+    fn head_to_head_prefix(&self) -> Option<TieBreakPolicy> {
+        let idx = self
+            .rules
+            .iter()
+            .position(|r| *r == TieBreakRule::HeadToHead)?;
+        Some(TieBreakPolicy {
+            rules: self.rules[..idx].to_vec(),
+        })
+    }
 }
 
 impl Default for TieBreakPolicy {
@@ -68,10 +154,14 @@ impl Default for TieBreakPolicy {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeagueTable {
     pub rows: Vec<LeagueTableRow>,
     pub tie_break: TieBreakPolicy,
+    pub points_system: PointsSystem,
+    /// (team_id, opponent_id) → the team's aggregate record in this
+    /// season's meetings with that opponent. Feeds `HeadToHead`.
+    pub head_to_head: HashMap<(u32, u32), HeadToHeadRecord>,
 }
 
 impl LeagueTable {
@@ -79,6 +169,8 @@ impl LeagueTable {
         LeagueTable {
             rows: Self::generate_for_teams(teams),
             tie_break: TieBreakPolicy::fifa_default(),
+            points_system: PointsSystem::default(),
+            head_to_head: HashMap::new(),
         }
     }
 
@@ -86,6 +178,18 @@ impl LeagueTable {
         LeagueTable {
             rows: Self::generate_for_teams(teams),
             tie_break: policy,
+            points_system: PointsSystem::default(),
+            head_to_head: HashMap::new(),
+        }
+    }
+
+    /// Fresh table scored and ordered under a league's configured rules.
+    pub fn for_rules(teams: &[u32], rules: &LeagueRules) -> Self {
+        LeagueTable {
+            rows: Self::generate_for_teams(teams),
+            tie_break: rules.tie_break.clone(),
+            points_system: rules.points,
+            head_to_head: HashMap::new(),
         }
     }
 
@@ -126,32 +230,36 @@ impl LeagueTable {
     }
 
     fn winner(&mut self, team_id: u32, goal_scored: u8, goal_concerned: u8) {
+        let points = self.points_system.win;
         let team = self.get_team_mut(team_id);
 
         team.played += 1;
         team.win += 1;
         team.goal_scored += goal_scored as i32;
         team.goal_concerned += goal_concerned as i32;
-        team.points += 3;
+        team.points += points;
     }
 
     fn looser(&mut self, team_id: u32, goal_scored: u8, goal_concerned: u8) {
+        let points = self.points_system.loss;
         let team = self.get_team_mut(team_id);
 
         team.played += 1;
         team.lost += 1;
         team.goal_scored += goal_scored as i32;
         team.goal_concerned += goal_concerned as i32;
+        team.points += points;
     }
 
     fn draft(&mut self, team_id: u32, goal_scored: u8, goal_concerned: u8) {
+        let points = self.points_system.draw;
         let team = self.get_team_mut(team_id);
 
         team.played += 1;
         team.draft += 1;
         team.goal_scored += goal_scored as i32;
         team.goal_concerned += goal_concerned as i32;
-        team.points += 1;
+        team.points += points;
     }
 
     /// Apply a one-shot points deduction to a team. Tracked separately
@@ -174,11 +282,58 @@ impl LeagueTable {
     /// row directly and want to refresh the standings.
     pub fn resort(&mut self) {
         let policy = self.tie_break.clone();
-        self.rows.sort_by(|a, b| policy.compare(a, b));
+        let head_to_head = self.head_to_head_standings(&policy);
+        self.rows
+            .sort_by(|a, b| policy.compare_with(a, b, &head_to_head));
+    }
+
+    /// Mini-table records for every group of sides level on the keys ahead
+    /// of `HeadToHead`, counting only the matches inside each group. Empty
+    /// when the policy has no head-to-head key.
+    fn head_to_head_standings(&self, policy: &TieBreakPolicy) -> HashMap<u32, HeadToHeadRecord> {
+        let mut standings = HashMap::new();
+        let Some(prefix) = policy.head_to_head_prefix() else {
+            return standings;
+        };
+
+        let mut rows: Vec<&LeagueTableRow> = self.rows.iter().collect();
+        rows.sort_by(|a, b| prefix.compare(a, b));
+        for group in rows.chunk_by(|a, b| prefix.compare(a, b) == Ordering::Equal) {
+            if group.len() < 2 {
+                continue;
+            }
+            for row in group {
+                let mut record = HeadToHeadRecord::default();
+                for opponent in group.iter().filter(|o| o.team_id != row.team_id) {
+                    if let Some(meetings) = self.head_to_head.get(&(row.team_id, opponent.team_id))
+                    {
+                        record.add(meetings);
+                    }
+                }
+                standings.insert(row.team_id, record);
+            }
+        }
+        standings
+    }
+
+    fn record_meeting(&mut self, team_id: u32, opponent_id: u32, scored: u8, conceded: u8) {
+        let points = match scored.cmp(&conceded) {
+            Ordering::Greater => self.points_system.win,
+            Ordering::Equal => self.points_system.draw,
+            Ordering::Less => self.points_system.loss,
+        };
+        let record = self.head_to_head.entry((team_id, opponent_id)).or_default();
+        record.points += points as u16;
+        record.goals_for += scored as i32;
+        record.goals_against += conceded as i32;
     }
 
     pub fn update_from_results(&mut self, match_result: &[MatchResult]) {
         for result in match_result {
+            let (home, away) = (&result.score.home_team, &result.score.away_team);
+            self.record_meeting(home.team_id, away.team_id, home.get(), away.get());
+            self.record_meeting(away.team_id, home.team_id, away.get(), home.get());
+
             match Ord::cmp(&result.score.home_team.get(), &result.score.away_team.get()) {
                 Ordering::Equal => {
                     self.draft(
@@ -227,7 +382,7 @@ impl LeagueTable {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LeagueTableRow {
     pub team_id: u32,
     pub played: u8,
@@ -263,6 +418,8 @@ impl Default for LeagueTable {
         LeagueTable {
             rows: Vec::new(),
             tie_break: TieBreakPolicy::fifa_default(),
+            points_system: PointsSystem::default(),
+            head_to_head: HashMap::new(),
         }
     }
 }
@@ -319,6 +476,7 @@ mod tests {
             },
             details: None,
             friendly: false,
+            attendance: 0,
         }];
 
         table.update_from_results(&match_results);
@@ -373,6 +531,7 @@ mod tests {
             },
             details: None,
             friendly: false,
+            attendance: 0,
         }];
 
         table.update_from_results(&match_results);
@@ -435,6 +594,7 @@ mod tests {
             },
             details: None,
             friendly: false,
+            attendance: 0,
         }];
 
         table.update_from_results(&match_results);
@@ -532,4 +692,67 @@ mod tests {
         assert_eq!(table.rows[1].team_id, 1);
         assert_eq!(table.rows[2].team_id, 3);
     }
+
+    fn result(home: u32, home_goals: u8, away: u32, away_goals: u8) -> MatchResult {
+        MatchResult {
+            league_id: 0,
+            id: format!("{}-{}", home, away),
+            league_slug: "slug".to_string(),
+            home_team_id: home,
+            away_team_id: away,
+            score: Score {
+                home_team: TeamScore::new_with_score(home, home_goals),
+                away_team: TeamScore::new_with_score(away, away_goals),
+                details: vec![],
+                home_shootout: 0,
+                away_shootout: 0,
+            },
+            details: None,
+            friendly: false,
+            attendance: 0,
+        }
+    }
+
+    #[test]
+    fn configured_points_system_scores_results() {
+        let rules = LeagueRules {
+            points: PointsSystem {
+                win: 2,
+                draw: 1,
+                loss: 0,
+            },
+            ..LeagueRules::default()
+        };
+        let mut table = LeagueTable::for_rules(&[1, 2, 3], &rules);
+        table.update_from_results(&[result(1, 2, 2, 0), result(3, 1, 1, 1)]);
+        let points = |id: u32| table.rows.iter().find(|r| r.team_id == id).unwrap().points;
+        assert_eq!(points(1), 3);
+        assert_eq!(points(2), 0);
+        assert_eq!(points(3), 1);
+    }
+
+    #[test]
+    fn head_to_head_outranks_goal_difference_when_configured() {
+        let rules = LeagueRules {
+            tie_break: TieBreakPolicy::from_rules(vec![
+                TieBreakRule::Points,
+                TieBreakRule::HeadToHead,
+                TieBreakRule::GoalDifference,
+            ]),
+            ..LeagueRules::default()
+        };
+        let results = [result(1, 0, 2, 1), result(1, 5, 3, 0), result(2, 0, 4, 1)];
+
+        // Teams 1, 2 and 4 finish level on 3 points. Team 1 has by far the
+        // best goal difference but lost its only game inside the group.
+        let mut table = LeagueTable::for_rules(&[1, 2, 3, 4], &rules);
+        table.update_from_results(&results);
+        let order: Vec<u32> = table.rows.iter().map(|r| r.team_id).collect();
+        assert_eq!(order, vec![4, 2, 1, 3]);
+
+        let mut fifa = LeagueTable::new(&[1, 2, 3, 4]);
+        fifa.update_from_results(&results);
+        let order: Vec<u32> = fifa.rows.iter().map(|r| r.team_id).collect();
+        assert_eq!(order, vec![1, 4, 2, 3]);
+    }
 }
@@ -35,7 +35,8 @@ pub use country::{
     NationalSquadPlayer, NationalSquadStage,
     NationalTeam, NationalTeamFixture, NationalTeamLevel, NationalTeamMatchResult,
     NationalTeamStaffMember, NationalTeamStaffRole, NationalTournamentRequirements,
-    PeopleNameGeneratorData, SkinColorDistribution, SquadPick, StoryType,
+    PeopleNameGeneratorData, SigningEligibility, SigningRestriction, SkinColorDistribution,
+    SquadPick, StoryType,
 };
 pub use nalgebra::*;
 pub use simulator::*;
@@ -138,6 +139,7 @@ pub use club::{
     LifeSimulationDesireKind,
     LifeSimulationSeverity,
     LifeSimulationTrigger,
+    LineupError,
     LiveCupSlice,
     LoanConcernReason,
     LoanDevelopmentConcernReason,
@@ -159,6 +161,8 @@ pub use club::{
     MatchResultInfo,
     MatchSelectionContext,
     MatchTacticType,
+    MatchdayLineup,
+    MatchdayPlan,
     MediaFanEventContext,
     MediaFanEventKind,
     MediaFanSource,
@@ -239,6 +243,8 @@ pub use club::{
     PlayerUtils,
     PlayerValueCalculator,
     PositionWeights,
+    PreseasonFriendly,
+    PreseasonTour,
     PositiveHappiness,
     PrivateTalkReason,
     PrivateTalkRequestContext,
@@ -284,9 +290,12 @@ pub use club::{
     SpecialInstruction,
     SponsorPerformance,
     SponsorRenewalContext,
+    SponsorshipKind,
     SquadAnalysis,
+    SquadCohesion,
     SquadBuildingPolicy,
     SquadPhase,
+    StadiumExpansion,
     // Staff exports
     Staff,
     StaffAttributes,
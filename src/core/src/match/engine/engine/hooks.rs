@@ -0,0 +1,128 @@
+//! Engine hooks — a trait-based extension point on the match loop.
+//!
+//! External crates and the dev tools attach observers (statistics
+//! collectors, trace writers) or experimental decision overrides
+//! through `EngineHook` instead of patching the engine. Hooks are
+//! registered on an `EngineHooks` set and handed to
+//! `FootballEngine::play_with_hooks`; the plain `play*` entry points run
+//! with an empty set.
+//!
+//! Cost when unused: every call site checks `EngineHooks::is_active()`
+//! (a single bool) before touching the hook list, so production matches
+//! pay one predictable branch per tick.
+
+use crate::r#match::events::{Event, EventCollection};
+use crate::r#match::{MatchContext, MatchField, MatchState};
+
+/// Callbacks invoked by the match loop. Every method has an empty
+/// default so an implementor only overrides what it needs.
+pub trait EngineHook: Send {
+    /// Called at the start of every live tick, before ball physics and
+    /// player AI run. Dead-ball ticks (post-goal celebration window) are
+    /// skipped, matching the engine itself. Mutable access lets an
+    /// experimental hook override positions, velocities or player state
+    /// ahead of the tick.
+    fn on_tick_start(&mut self, _field: &mut MatchField, _context: &mut MatchContext) {}
+
+    /// Called for every top-level event produced by a tick, before it
+    /// is dispatched. Follow-up events raised by the dispatcher itself
+    /// (goal-reset cascades, deflections) are not re-reported.
+    fn on_event(&mut self, _event: &Event, _field: &MatchField, _context: &MatchContext) {}
+
+    /// Called when the match state machine moves to a new period.
+    /// `previous` is `None` for the transition out of `Initial`.
+    fn on_state_change(
+        &mut self,
+        _previous: Option<MatchState>,
+        _next: MatchState,
+        _context: &MatchContext,
+    ) {
+    }
+}
+
+/// Lets a caller register `&mut collector` and read its totals back
+/// once the match returns, without boxing or shared ownership.
+impl<H: EngineHook + ?Sized> EngineHook for &mut H {
+    fn on_tick_start(&mut self, field: &mut MatchField, context: &mut MatchContext) {
+        (**self).on_tick_start(field, context)
+    }
+
+    fn on_event(&mut self, event: &Event, field: &MatchField, context: &MatchContext) {
+        (**self).on_event(event, field, context)
+    }
+
+    fn on_state_change(
+        &mut self,
+        previous: Option<MatchState>,
+        next: MatchState,
+        context: &MatchContext,
+    ) {
+        (**self).on_state_change(previous, next, context)
+    }
+}
+
+/// Ordered set of hooks. Hooks run in registration order.
+#[derive(Default)]
+pub struct EngineHooks<'a> {
+    hooks: Vec<Box<dyn EngineHook + 'a>>,
+}
+
+impl<'a> EngineHooks<'a> {
+    pub fn new() -> Self {
+        EngineHooks { hooks: Vec::new() }
+    }
+
+    /// Builder-style registration.
+    pub fn with<H: EngineHook + 'a>(mut self, hook: H) -> Self {
+        self.add(hook);
+        self
+    }
+
+    pub fn add<H: EngineHook + 'a>(&mut self, hook: H) {
+        self.hooks.push(Box::new(hook));
+    }
+
+    pub fn len(&self) -> usize {
+        self.hooks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.hooks.is_empty()
+    }
+
+    /// Hot-path guard for the tick loop.
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        !self.hooks.is_empty()
+    }
+
+    pub(crate) fn tick_start(&mut self, field: &mut MatchField, context: &mut MatchContext) {
+        for hook in self.hooks.iter_mut() {
+            hook.on_tick_start(field, context);
+        }
+    }
+
+    pub(crate) fn events(
+        &mut self,
+        events: &EventCollection,
+        field: &MatchField,
+        context: &MatchContext,
+    ) {
+        for event in events.iter() {
+            for hook in self.hooks.iter_mut() {
+                hook.on_event(event, field, context);
+            }
+        }
+    }
+
+    pub(crate) fn state_change(
+        &mut self,
+        previous: Option<MatchState>,
+        next: MatchState,
+        context: &MatchContext,
+    ) {
+        for hook in self.hooks.iter_mut() {
+            hook.on_state_change(previous, next, context);
+        }
+    }
+}
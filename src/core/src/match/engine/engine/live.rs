@@ -0,0 +1,432 @@
+//! Stepping API — pause / resume / single-step a match in progress.
+//!
+//! The one-shot `play*` entry points run a match to completion inside
+//! a single call. Viewers and debugging tools instead need to hold a
+//! match open between frames: `FootballEngine::start` builds the match
+//! and parks it on the engine, after which the caller drives it with
+//! `tick` (honours `pause`/`resume`), `step` and `run_until` (always
+//! advance), inspects it through `field`/`context`/`snapshot`, and
+//! collects the normal `MatchResultRaw` with `finish`.
+//!
+//! The driver replays exactly the state machine of `play_with_hooks`,
+//! so a started match ticked to the end yields the same result as the
+//! one-shot call for the same seed.
+
+use super::*;
+use crate::r#match::TransitionSource;
+use crate::r#match::engine::context::MatchEngineConfig;
+use crate::r#match::player::state::PlayerState;
+use nalgebra::Vector3;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// A match held open on the engine between calls.
+pub(super) struct LiveMatch {
+    field: MatchField,
+    context: MatchContext,
+    state_manager: StateManager,
+    position_data: ResultMatchPositionData,
+    /// Loop state of the period in progress; `None` between periods.
+    period: Option<PeriodLoop>,
+    hooks: EngineHooks<'static>,
+    paused: bool,
+    finished: bool,
+    ticks: u64,
+}
+
+/// Serializable point-in-time view of a live match — what a paused
+/// viewer shows or persists. Inspection data only: the engine's full
+/// internal state (AI memories, RNG, tactical windows) is not captured,
+/// so a snapshot cannot be resumed into a running match.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchSnapshot {
+    pub state: MatchState,
+    pub paused: bool,
+    pub ticks: u64,
+    pub total_match_time_ms: u64,
+    pub period_time_ms: u64,
+    pub home_team_id: u32,
+    pub away_team_id: u32,
+    pub home_goals: u8,
+    pub away_goals: u8,
+    pub ball: BallSnapshot,
+    pub players: Vec<PlayerSnapshot>,
+    pub substitutions: Vec<SubstitutionSnapshot>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BallSnapshot {
+    pub position: Vector3<f32>,
+    pub velocity: Vector3<f32>,
+    pub owner: Option<u32>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PlayerSnapshot {
+    pub id: u32,
+    pub team_id: u32,
+    pub position: Vector3<f32>,
+    pub velocity: Vector3<f32>,
+    pub condition: i16,
+    pub state: String,
+    pub is_sent_off: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubstitutionSnapshot {
+    pub team_id: u32,
+    pub player_out_id: u32,
+    pub player_in_id: u32,
+    pub match_time_ms: u64,
+}
+
+impl<const W: usize, const H: usize> FootballEngine<W, H> {
+    /// Build a match and park it on the engine, ready to be stepped.
+    /// Replaces any match already in progress.
+    pub fn start(
+        &mut self,
+        left_squad: MatchSquad,
+        right_squad: MatchSquad,
+        config: MatchEngineConfig,
+    ) {
+        self.start_with_hooks(left_squad, right_squad, config, EngineHooks::new());
+    }
+
+    /// `start` with engine hooks attached for the lifetime of the match.
+    pub fn start_with_hooks(
+        &mut self,
+        left_squad: MatchSquad,
+        right_squad: MatchSquad,
+        config: MatchEngineConfig,
+        hooks: EngineHooks<'static>,
+    ) {
+        let (field, context, position_data) = Self::prepare_match(left_squad, right_squad, &config);
+        self.live = Some(Box::new(LiveMatch {
+            field,
+            context,
+            state_manager: StateManager::new(),
+            position_data,
+            period: None,
+            hooks,
+            paused: false,
+            finished: false,
+            ticks: 0,
+        }));
+    }
+
+    pub fn is_started(&self) -> bool {
+        self.live.is_some()
+    }
+
+    /// `true` once the final whistle (or shootout) has been played.
+    /// Also `true` when no match was started.
+    pub fn is_finished(&self) -> bool {
+        self.live.as_ref().is_none_or(|live| live.finished)
+    }
+
+    pub fn pause(&mut self) {
+        if let Some(live) = self.live.as_mut() {
+            live.paused = true;
+        }
+    }
+
+    pub fn resume(&mut self) {
+        if let Some(live) = self.live.as_mut() {
+            live.paused = false;
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.live.as_ref().is_some_and(|live| live.paused)
+    }
+
+    /// Per-frame driver: plays one tick unless the match is paused or
+    /// over. Returns whether a tick was played.
+    pub fn tick(&mut self) -> bool {
+        match self.live.as_mut() {
+            Some(live) if !live.paused => Self::advance(live),
+            _ => false,
+        }
+    }
+
+    /// Play up to `n_ticks` ticks regardless of the pause flag — the
+    /// single-step control of a paused viewer. Returns the number of
+    /// ticks actually played (fewer when the match ends first).
+    pub fn step(&mut self, n_ticks: u32) -> u32 {
+        let Some(live) = self.live.as_mut() else {
+            return 0;
+        };
+        let mut played = 0;
+        while played < n_ticks && Self::advance(live) {
+            played += 1;
+        }
+        played
+    }
+
+    /// Play ticks until `condition` holds (checked after every tick) or
+    /// the match ends, regardless of the pause flag. Returns `true` when
+    /// the condition was met.
+    pub fn run_until<F>(&mut self, mut condition: F) -> bool
+    where
+        F: FnMut(&MatchField, &MatchContext) -> bool,
+    {
+        let Some(live) = self.live.as_mut() else {
+            return false;
+        };
+        while Self::advance(live) {
+            if condition(&live.field, &live.context) {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Edit the held match in place; the changes take effect from the
+    /// next tick. `None` when nothing was started.
+    pub fn edit(&mut self) -> Option<ScenarioEditor<'_>> {
+        self.live.as_deref_mut().map(|live| ScenarioEditor { live })
+    }
+
+    pub fn field(&self) -> Option<&MatchField> {
+        self.live.as_ref().map(|live| &live.field)
+    }
+
+    pub fn context(&self) -> Option<&MatchContext> {
+        self.live.as_ref().map(|live| &live.context)
+    }
+
+    pub fn snapshot(&self) -> Option<MatchSnapshot> {
+        let live = self.live.as_ref()?;
+        let field = &live.field;
+        let context = &live.context;
+        Some(MatchSnapshot {
+            state: context.state.match_state,
+            paused: live.paused,
+            ticks: live.ticks,
+            total_match_time_ms: context.total_match_time,
+            period_time_ms: context.time.time,
+            home_team_id: context.score.home_team.team_id,
+            away_team_id: context.score.away_team.team_id,
+            home_goals: context.score.home_team.get(),
+            away_goals: context.score.away_team.get(),
+            ball: BallSnapshot {
+                position: field.ball.position,
+                velocity: field.ball.velocity,
+                owner: field.ball.current_owner,
+            },
+            players: field
+                .players
+                .iter()
+                .map(|p| PlayerSnapshot {
+                    id: p.id,
+                    team_id: p.team_id,
+                    position: p.position,
+                    velocity: p.velocity,
+                    condition: p.player_attributes.condition,
+                    state: format!("{:?}", p.state),
+                    is_sent_off: p.is_sent_off,
+                })
+                .collect(),
+            substitutions: context
+                .substitutions
+                .iter()
+                .map(|s| SubstitutionSnapshot {
+                    team_id: s.team_id,
+                    player_out_id: s.player_out_id,
+                    player_in_id: s.player_in_id,
+                    match_time_ms: s.match_time,
+                })
+                .collect(),
+        })
+    }
+
+    /// Play the rest of the match (ignoring the pause flag) and return
+    /// its result. Clears the engine; `None` when nothing was started.
+    pub fn finish(&mut self) -> Option<MatchResultRaw> {
+        let mut live = self.live.take()?;
+        while Self::advance(&mut live) {}
+        let LiveMatch {
+            field,
+            context,
+            position_data,
+            ..
+        } = *live;
+        Some(Self::build_result(field, context, position_data))
+    }
+
+    /// Play exactly one live tick, walking the state machine across
+    /// period boundaries (half time, extra time, shootout) as needed.
+    /// Returns `false` once the match is over.
+    fn advance(live: &mut LiveMatch) -> bool {
+        if live.finished {
+            return false;
+        }
+
+        loop {
+            if let Some(period) = live.period.as_mut() {
+                let ticked = Self::period_tick(
+                    period,
+                    &mut live.field,
+                    &mut live.context,
+                    &mut live.position_data,
+                    &mut live.hooks,
+                );
+                if ticked {
+                    live.ticks += 1;
+                    return true;
+                }
+                live.period = None;
+                StateManager::handle_state_finish(
+                    &mut live.context,
+                    &mut live.field,
+                    PlayMatchStateResult::default(),
+                );
+            }
+
+            let context = &live.context;
+            let Some(state) = live.state_manager.next(&context.score, context.is_knockout) else {
+                live.finished = true;
+                return false;
+            };
+
+            let previous = live.context.state.match_state;
+            live.context.state.set(state);
+            if live.hooks.is_active() {
+                let previous = (previous != MatchState::Initial).then_some(previous);
+                live.hooks.state_change(previous, state, &live.context);
+            }
+
+            if state == MatchState::PenaltyShootout {
+                Self::run_penalty_shootout(&mut live.field, &mut live.context);
+                StateManager::handle_state_finish(
+                    &mut live.context,
+                    &mut live.field,
+                    PlayMatchStateResult::default(),
+                );
+            } else {
+                live.period = Some(Self::new_period_loop(
+                    &live.field,
+                    &live.context,
+                    &live.position_data,
+                ));
+            }
+        }
+    }
+}
+
+/// Why a scenario edit was refused.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScenarioError {
+    UnknownPlayer(u32),
+    SentOff(u32),
+}
+
+impl fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScenarioError::UnknownPlayer(id) => write!(f, "player {id} is not on the pitch"),
+            ScenarioError::SentOff(id) => write!(f, "player {id} has been sent off"),
+        }
+    }
+}
+
+impl std::error::Error for ScenarioError {}
+
+/// Mutable view of a live match for setting up a scenario, from
+/// `FootballEngine::edit`. Edits write the field directly — the engine
+/// has no notion of them — and the tick caches are refreshed when the
+/// editor is dropped.
+pub struct ScenarioEditor<'a> {
+    live: &'a mut LiveMatch,
+}
+
+impl ScenarioEditor<'_> {
+    fn player(&mut self, player_id: u32) -> Result<&mut MatchPlayer, ScenarioError> {
+        let player = self
+            .live
+            .field
+            .players
+            .iter_mut()
+            .find(|p| p.id == player_id)
+            .ok_or(ScenarioError::UnknownPlayer(player_id))?;
+        if player.is_sent_off {
+            return Err(ScenarioError::SentOff(player_id));
+        }
+        Ok(player)
+    }
+
+    /// Put a player at `position`, standing still. A player carrying the
+    /// ball takes it along.
+    pub fn move_player(
+        &mut self,
+        player_id: u32,
+        position: Vector3<f32>,
+    ) -> Result<(), ScenarioError> {
+        let player = self.player(player_id)?;
+        player.position = position;
+        player.velocity = Vector3::zeros();
+        let ball = &mut self.live.field.ball;
+        if ball.current_owner == Some(player_id) {
+            ball.position = Vector3::new(position.x, position.y, 0.0);
+        }
+        Ok(())
+    }
+
+    pub fn set_player_velocity(
+        &mut self,
+        player_id: u32,
+        velocity: Vector3<f32>,
+    ) -> Result<(), ScenarioError> {
+        self.player(player_id)?.velocity = velocity;
+        Ok(())
+    }
+
+    /// Enter `state` as a fresh transition, its timers counting from now.
+    pub fn set_player_state(
+        &mut self,
+        player_id: u32,
+        state: PlayerState,
+    ) -> Result<(), ScenarioError> {
+        self.player(player_id)?
+            .transition_to(state, TransitionSource::Scenario);
+        Ok(())
+    }
+
+    /// Put the ball loose at `position`. Any owner loses it and a pass or
+    /// shot in flight is forgotten.
+    pub fn move_ball(&mut self, position: Vector3<f32>) {
+        let ball = &mut self.live.field.ball;
+        if let Some(owner) = ball.current_owner.take() {
+            ball.previous_owner = Some(owner);
+        }
+        ball.ownership_duration = 0;
+        ball.clear_open_play_metadata();
+        ball.position = position;
+    }
+
+    pub fn set_ball_velocity(&mut self, velocity: Vector3<f32>) {
+        self.live.field.ball.velocity = velocity;
+    }
+
+    /// Hand the ball to a player at their feet.
+    pub fn give_ball(&mut self, player_id: u32) -> Result<(), ScenarioError> {
+        let position = self.player(player_id)?.position;
+        let ball = &mut self.live.field.ball;
+        ball.clear_open_play_metadata();
+        ball.previous_owner = ball.current_owner;
+        ball.current_owner = Some(player_id);
+        ball.ownership_duration = 0;
+        ball.position = Vector3::new(position.x, position.y, 0.0);
+        ball.velocity = Vector3::zeros();
+        Ok(())
+    }
+}
+
+impl Drop for ScenarioEditor<'_> {
+    fn drop(&mut self) {
+        let live = &mut *self.live;
+        if let Some(period) = live.period.as_mut() {
+            period.tick_ctx.update(&live.field, &live.context.players);
+        }
+    }
+}
@@ -17,9 +17,9 @@ use crate::r#match::player::statistics::MatchStatisticType;
 use crate::r#match::player::strategies::players::ops::skill_composites as sc;
 use crate::r#match::result::ResultMatchPositionData;
 use crate::r#match::{
-    CoachInstruction, GameTickContext, MatchContext, MatchPlayer, MatchResultRaw, MatchSquad,
-    MatchState, PenaltyShootoutKick, Score, StateManager, SubstitutionInfo, TacticalRefreshInputs,
-    TeamTacticalState,
+    BallZone, CoachInstruction, GameTickContext, MatchContext, MatchPlayer, MatchResultRaw,
+    MatchSquad, MatchState, PenaltyShootoutKick, Score, StateManager, SubstitutionInfo,
+    TacticalRefreshInputs, TeamTacticalState,
 };
 use crate::{MatchRuntime, PlayerFieldPositionGroup};
 #[cfg(feature = "match-logs")]
@@ -246,7 +246,58 @@ pub(super) struct RollingMetricsInput {
     pub cum_dangerous_turnovers: u32,
 }
 
-pub struct FootballEngine<const W: usize, const H: usize> {}
+/// Loop-carried state of one playing period (half / extra time): the
+/// evaluation cadences, substitution and medical timers, and the
+/// transition-trigger snapshots. Lifted out of `play_inner` so the
+/// period can be advanced one tick at a time by the stepping API.
+pub(super) struct PeriodLoop {
+    prof_on: bool,
+    next_sub_time_ms: u64,
+    sub_times_initialized: bool,
+    et_bonus_granted: bool,
+    // Medical (forced-injury) pass scheduling — independent of the
+    // discretionary sub timer, re-armed at the start of each period.
+    next_medical_time_ms: u64,
+    medical_period: Option<MatchState>,
+    tick_ctx: GameTickContext,
+    events: EventCollection,
+    tick_parity: u32,
+    coach_eval_counter: u32,
+    tactical_eval_counter: u32,
+    transition_window_remaining: u32,
+    // Snapshots used to detect transition triggers between refresh
+    // points without a per-tick walk over players.
+    last_owner_id: Option<u32>,
+    last_possession_team: Option<u32>,
+    last_home_score: u8,
+    last_away_score: u8,
+    last_home_instruction: CoachInstruction,
+    last_away_instruction: CoachInstruction,
+    last_home_zone: BallZone,
+    last_away_zone: BallZone,
+    next_position_record_ms: u64,
+    track_positions: bool,
+}
+
+impl PeriodLoop {
+    // Tactical refresh uses an adaptive cadence: BASE during stable
+    // play, TRANSITION right after possession swings / set-piece
+    // restarts / goals / coach-instruction changes / ball entering
+    // or leaving the attacking third. Each "transition trigger"
+    // opens a TRANSITION_WINDOW_TICKS window during which the
+    // cheaper TRANSITION interval is used.
+    const BASE_TACTICAL_INTERVAL_TICKS: u32 = 25;
+    const TRANSITION_TACTICAL_INTERVAL_TICKS: u32 = 10;
+    const TRANSITION_WINDOW_TICKS: u32 = 40;
+}
+
+/// Match engine. The one-shot `play*` entry points are associated
+/// functions and leave the value untouched; the stepping API (`start`,
+/// `tick`, `step`, `run_until`, `finish` — see `live.rs`) parks a match
+/// in progress on the engine value between calls.
+pub struct FootballEngine<const W: usize, const H: usize> {
+    live: Option<Box<LiveMatch>>,
+}
 
 impl<const W: usize, const H: usize> Default for FootballEngine<W, H> {
     fn default() -> Self {
@@ -254,15 +305,25 @@ impl<const W: usize, const H: usize> Default for FootballEngine<W, H> {
     }
 }
 
+mod hooks;
+mod live;
 pub mod phase_prof;
 mod positions;
 mod run;
 mod shape;
 mod shootout;
 mod tick;
+mod training;
 mod types;
 
 use crate::r#match::TeamSkillAggregates;
+pub use hooks::*;
+use live::LiveMatch;
+pub use live::{
+    BallSnapshot, MatchSnapshot, PlayerSnapshot, ScenarioEditor, ScenarioError,
+    SubstitutionSnapshot,
+};
+pub use training::*;
 pub use types::*;
 
 #[cfg(test)]
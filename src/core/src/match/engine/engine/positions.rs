@@ -1,8 +1,10 @@
 use super::*;
+use crate::r#match::Space;
 use crate::r#match::defenders::states::DefenderState;
 use crate::r#match::forwarders::states::ForwardState;
 use crate::r#match::midfielders::states::MidfielderState;
 use crate::r#match::player::state::PlayerState;
+use crate::r#match::result::PassLaneSample;
 
 /// Reduced-cadence (LOD) distance gate: players further than this from
 /// the ball — in a passive movement state, while the ball is owned —
@@ -19,6 +21,11 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
     /// Record positions every 30ms (every 3rd tick) instead of every 10ms.
     pub(super) const POSITION_RECORD_INTERVAL_MS: u64 = 30;
 
+    /// Ball carrier pass lanes are sampled every 150ms (every 5th
+    /// position sample) — often enough to follow a carrier scanning
+    /// for a pass without the replay doubling in size.
+    const PASS_LANE_RECORD_INTERVAL_MS: u64 = 150;
+
     #[inline]
     pub fn write_match_positions(
         field: &mut MatchField,
@@ -74,6 +81,63 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         });
 
         match_data.add_ball_positions(timestamp, field.ball.position);
+
+        if track_events {
+            Self::write_pass_events(field, timestamp, match_data);
+        }
+    }
+
+    /// Replay debugging data for passes: completions of recorded passes
+    /// and the carrier's `has_clear_pass` verdict for every teammate.
+    fn write_pass_events(
+        field: &MatchField,
+        timestamp: u64,
+        match_data: &mut ResultMatchPositionData,
+    ) {
+        let ball = &field.ball;
+        if let (Some(passer_id), Some(receiver_id)) = (
+            ball.last_completed_pass_passer_id,
+            ball.last_completed_pass_receiver_id,
+        ) {
+            match_data.complete_pass(
+                ball.last_completed_pass_tick,
+                timestamp,
+                passer_id,
+                receiver_id,
+            );
+        }
+
+        if !timestamp.is_multiple_of(Self::PASS_LANE_RECORD_INTERVAL_MS) {
+            return;
+        }
+        let carrier = match ball
+            .current_owner
+            .and_then(|owner| field.players.iter().find(|p| p.id == owner))
+        {
+            Some(carrier) => carrier,
+            None => return,
+        };
+
+        let space = Space::from(field);
+        let mut sample = PassLaneSample {
+            timestamp,
+            carrier_id: carrier.id,
+            clear: Vec::new(),
+            blocked: Vec::new(),
+        };
+        for teammate in field
+            .players
+            .iter()
+            .filter(|p| p.team_id == carrier.team_id && p.id != carrier.id && !p.is_sent_off)
+        {
+            let distance = (teammate.position - carrier.position).magnitude();
+            if space.is_lane_clear(carrier.position, teammate.position, distance) {
+                sample.clear.push(teammate.id);
+            } else {
+                sample.blocked.push(teammate.id);
+            }
+        }
+        match_data.add_pass_lanes(sample);
     }
 
     // ───────────────────────────────────────────────────────────────────────
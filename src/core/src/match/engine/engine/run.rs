@@ -1,11 +1,16 @@
 use super::phase_prof::PhaseProf;
 use super::*;
+use crate::r#match::InferenceTelemetry;
+use crate::r#match::engine::chemistry::TacticalFamiliarity;
 use crate::r#match::engine::context::MatchEngineConfig;
 use crate::r#match::engine::rating::{RatingExpectationContext, TeamRatingSummary};
 
+/// Effective-skill lift from a perfect opposition report (quality 1.0).
+const OPPOSITION_REPORT_EDGE: f32 = 0.015;
+
 impl<const W: usize, const H: usize> FootballEngine<W, H> {
     pub fn new() -> Self {
-        FootballEngine {}
+        FootballEngine { live: None }
     }
 
     #[allow(unreachable_code)]
@@ -52,12 +57,37 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
     /// context after construction. Required by the calibration harness
     /// to run a real rainy match or a strict-referee fixture, and by
     /// any replay test that needs exact-seed control over today's
-    /// date.
-    #[allow(unreachable_code)]
+    /// date. While training data is recorded, a `TrainingRecorder` is
+    /// attached and its rows appended once the match is over.
     pub fn play_with_config(
         left_squad: MatchSquad,
         right_squad: MatchSquad,
         config: MatchEngineConfig,
+    ) -> MatchResultRaw {
+        if !TrainingData::is_recording() {
+            return Self::play_with_hooks(left_squad, right_squad, config, &mut EngineHooks::new());
+        }
+        let mut recorder = TrainingRecorder::new();
+        let result = Self::play_with_hooks(
+            left_squad,
+            right_squad,
+            config,
+            &mut EngineHooks::new().with(&mut recorder),
+        );
+        TrainingData::append(&recorder);
+        result
+    }
+
+    /// `play_with_config` with engine hooks attached. Hooks observe
+    /// every live tick, every top-level event and every period change
+    /// (see `EngineHook`); an empty set is equivalent to
+    /// `play_with_config`.
+    #[allow(unreachable_code)]
+    pub fn play_with_hooks(
+        left_squad: MatchSquad,
+        right_squad: MatchSquad,
+        config: MatchEngineConfig,
+        hooks: &mut EngineHooks<'_>,
     ) -> MatchResultRaw {
         // Profiling shortcut — see the `match-stub` feature in
         // `core/Cargo.toml`. Skips the simulation entirely and returns
@@ -65,11 +95,49 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         // IDs) for the surrounding pipeline to run.
         #[cfg(feature = "match-stub")]
         {
-            let _ = &config;
+            let _ = (&config, &hooks);
             return Self::play_stub(left_squad, right_squad);
         }
 
         PhaseProf::init_from_env();
+        let (mut field, mut context, mut match_position_data) =
+            Self::prepare_match(left_squad, right_squad, &config);
+        let mut state_manager = StateManager::new();
+
+        while let Some(state) = state_manager.next(&context.score, context.is_knockout) {
+            let previous = context.state.match_state;
+            context.state.set(state);
+            if hooks.is_active() {
+                let previous = (previous != MatchState::Initial).then_some(previous);
+                hooks.state_change(previous, state, &context);
+            }
+
+            let play_state_result = match state {
+                MatchState::PenaltyShootout => {
+                    Self::run_penalty_shootout(&mut field, &mut context);
+                    PlayMatchStateResult::default()
+                }
+                _ => Self::play_inner(&mut field, &mut context, &mut match_position_data, hooks),
+            };
+
+            StateManager::handle_state_finish(&mut context, &mut field, play_state_result);
+        }
+
+        let result = Self::build_result(field, context, match_position_data);
+        if PhaseProf::enabled() {
+            PhaseProf::report_and_reset("match");
+        }
+        result
+    }
+
+    /// Build the field, context and recording buffer for a fresh match
+    /// and place the kickoff. Shared by the one-shot `play*` entry
+    /// points and the stepping API (`FootballEngine::start`).
+    pub(super) fn prepare_match(
+        left_squad: MatchSquad,
+        right_squad: MatchSquad,
+        config: &MatchEngineConfig,
+    ) -> (MatchField, MatchContext, ResultMatchPositionData) {
         let score = Score::new(left_squad.team_id, right_squad.team_id);
 
         // Snapshot starting tactics by team-id BEFORE the squads move
@@ -77,10 +145,14 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         // team on the left side, so left == home / right == away here.
         let starting_home_tactic = Some(left_squad.tactics.tactic_type);
         let starting_away_tactic = Some(right_squad.tactics.tactic_type);
+        let home_report = left_squad.opposition_report;
+        let away_report = right_squad.opposition_report;
+        let home_cohesion = left_squad.cohesion.clone();
+        let away_cohesion = right_squad.cohesion.clone();
 
         let players = MatchPlayerCollection::from_squads(&left_squad, &right_squad);
 
-        let mut match_position_data = if !config.match_recordings {
+        let match_position_data = if !config.match_recordings {
             ResultMatchPositionData::empty()
         } else if MatchRuntime::events_mode() {
             ResultMatchPositionData::new_with_tracking()
@@ -90,7 +162,7 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
 
         let mut field = MatchField::new(W, H, left_squad, right_squad);
 
-        let mut context = MatchContext::new_with_config(&field, players, score, &config);
+        let mut context = MatchContext::new_with_config(&field, players, score, config);
         // Stash the starting tactics inside the context's match plan so
         // `build_result` can read them — no extra parameters threaded
         // through the state machine.
@@ -118,6 +190,26 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         context
             .chemistry
             .seed_from_roster(&chemistry_roster, field_h);
+        // Cohesion from the clubs' match history: pairs who have started
+        // together recently are warmer than the positional baseline, new
+        // signings colder, and a settled XI in its usual shape presses in
+        // sync. Squads without history keep the neutral defaults.
+        if let Some(cohesion) = &home_cohesion {
+            context
+                .chemistry
+                .apply_shared_starts(&cohesion.shared_starts);
+            context.tactical_familiarity_home = TacticalFamiliarity {
+                score: cohesion.tactical_familiarity,
+            };
+        }
+        if let Some(cohesion) = &away_cohesion {
+            context
+                .chemistry
+                .apply_shared_starts(&cohesion.shared_starts);
+            context.tactical_familiarity_away = TacticalFamiliarity {
+                score: cohesion.tactical_familiarity,
+            };
+        }
 
         // Home-crowd arousal — the play-quality half of home advantage.
         // Stamped once on every match player (starters AND bench, so
@@ -137,12 +229,18 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
             .clamp(0.0, 1.0);
         let home_arousal = 1.0 + 0.12 * home_edge;
         let away_arousal = 1.0 - 0.07 * home_edge;
+        // The assistant manager's opposition report rides on the same
+        // multiplier: a side that knows what's coming reads the game a
+        // touch sooner. At most +1.5% for a perfect report — a nudge,
+        // well under the crowd edge.
+        let home_prep = 1.0 + OPPOSITION_REPORT_EDGE * home_report.clamp(0.0, 1.0);
+        let away_prep = 1.0 + OPPOSITION_REPORT_EDGE * away_report.clamp(0.0, 1.0);
         let home_team_id = field.home_team_id;
         for p in field.players.iter_mut().chain(field.substitutes.iter_mut()) {
             p.crowd_arousal = if p.team_id == home_team_id {
-                home_arousal
+                home_arousal * home_prep
             } else {
-                away_arousal
+                away_arousal * away_prep
             };
         }
 
@@ -150,33 +248,13 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
             context.enable_logging();
         }
 
-        let mut state_manager = StateManager::new();
-
         // Match kickoff — home team (playing Left in the first half)
         // starts the game with possession on the centre spot. Without
         // this the ball sits at centre until the emergency chaser
         // override fires, producing a ~14-second dead patch.
         assign_kickoff(&mut field, PlayerSide::Left);
 
-        while let Some(state) = state_manager.next(&context.score, context.is_knockout) {
-            context.state.set(state);
-
-            let play_state_result = match state {
-                MatchState::PenaltyShootout => {
-                    Self::run_penalty_shootout(&mut field, &mut context);
-                    PlayMatchStateResult::default()
-                }
-                _ => Self::play_inner(&mut field, &mut context, &mut match_position_data),
-            };
-
-            StateManager::handle_state_finish(&mut context, &mut field, play_state_result);
-        }
-
-        let result = Self::build_result(field, context, match_position_data);
-        if PhaseProf::enabled() {
-            PhaseProf::report_and_reset("match");
-        }
-        result
+        (field, context, match_position_data)
     }
 
     /// Stub match: skips the whole simulation and returns a 0-0
@@ -235,6 +313,8 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         result.starting_home_tactic = context.starting_home_tactic;
         result.starting_away_tactic = context.starting_away_tactic;
         result.shape_change_minute = context.first_shape_change_minute;
+        result.home_possession_ticks = context.home_possession_ticks;
+        result.away_possession_ticks = context.away_possession_ticks;
 
         // Copy substitution records to result
         for sub_record in &context.substitutions {
@@ -697,45 +777,19 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         field: &mut MatchField,
         context: &mut MatchContext,
         match_data: &mut ResultMatchPositionData,
+        hooks: &mut EngineHooks<'_>,
     ) -> PlayMatchStateResult {
-        let result = PlayMatchStateResult::default();
-        let prof_on = PhaseProf::enabled();
-
-        let mut next_sub_time_ms: u64 = 0;
-        let mut sub_times_initialized = false;
-        let mut et_bonus_granted = false;
-        // Medical (forced-injury) pass scheduling — independent of the
-        // discretionary sub timer, re-armed at the start of each period.
-        let mut next_medical_time_ms: u64 = 0;
-        let mut medical_period: Option<MatchState> = None;
-
-        let mut tick_ctx = GameTickContext::new(field, &context.players);
-        let mut events = EventCollection::with_capacity(10);
-
-        let mut tick_parity: u32 = 0;
-        let mut coach_eval_counter: u32 = 0;
-        let mut tactical_eval_counter: u32 = 0;
-        // Tactical refresh uses an adaptive cadence: BASE during stable
-        // play, TRANSITION right after possession swings / set-piece
-        // restarts / goals / coach-instruction changes / ball entering
-        // or leaving the attacking third. Each "transition trigger"
-        // opens a TRANSITION_WINDOW_TICKS window during which the
-        // cheaper TRANSITION interval is used.
-        const BASE_TACTICAL_INTERVAL_TICKS: u32 = 25;
-        const TRANSITION_TACTICAL_INTERVAL_TICKS: u32 = 10;
-        const TRANSITION_WINDOW_TICKS: u32 = 40;
-        let mut transition_window_remaining: u32 = TRANSITION_WINDOW_TICKS;
-        // Snapshots used to detect transition triggers between refresh
-        // points without a per-tick walk over players.
-        let mut last_owner_id: Option<u32> = field.ball.current_owner;
-        let mut last_possession_team: Option<u32> = last_owner_id
-            .and_then(|id| field.players.iter().find(|p| p.id == id).map(|p| p.team_id));
-        let mut last_home_score: u8 = context.score.home_team.get();
-        let mut last_away_score: u8 = context.score.away_team.get();
-        let mut last_home_instruction = context.coach_home.instruction;
-        let mut last_away_instruction = context.coach_away.instruction;
-        let mut last_home_zone = context.tactical_home.ball_zone;
-        let mut last_away_zone = context.tactical_away.ball_zone;
+        let mut period = Self::new_period_loop(field, context, match_data);
+        while Self::period_tick(&mut period, field, context, match_data, hooks) {}
+        PlayMatchStateResult::default()
+    }
+
+    pub(super) fn new_period_loop(
+        field: &MatchField,
+        context: &MatchContext,
+        match_data: &ResultMatchPositionData,
+    ) -> PeriodLoop {
+        let last_owner_id = field.ball.current_owner;
         // Position recording cursor — replaces the per-tick
         // `timestamp % POSITION_RECORD_INTERVAL_MS == 0` check. Round
         // the starting timestamp UP to the next multiple of the
@@ -743,241 +797,302 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         // 30 ms cadence (the loop increments time *before* the body,
         // so we never see `t == 0`).
         let initial_t = context.total_match_time;
-        let mut next_position_record_ms: u64 =
-            (initial_t / Self::POSITION_RECORD_INTERVAL_MS + 1) * Self::POSITION_RECORD_INTERVAL_MS;
-        let track_positions = match_data.is_tracking_positions();
-
-        while context.increment_time() {
-            // Post-goal dead time: only the match clock advances while
-            // the players celebrate / walk back / wait for the restart
-            // whistle. No ball physics, no AI, no events, no coach
-            // evals — the world is already reset and frozen in
-            // formation, so skipping the tick body IS the celebration.
-            // See `MatchContext::dead_ball_until_ms` for why this pause
-            // is load-bearing (it consumed the post-goal hot window
-            // that made goals beget goals).
-            if context.total_match_time < context.dead_ball_until_ms {
-                continue;
-            }
+        PeriodLoop {
+            prof_on: PhaseProf::enabled(),
+            next_sub_time_ms: 0,
+            sub_times_initialized: false,
+            et_bonus_granted: false,
+            next_medical_time_ms: 0,
+            medical_period: None,
+            tick_ctx: GameTickContext::new(field, &context.players),
+            events: EventCollection::with_capacity(10),
+            tick_parity: 0,
+            coach_eval_counter: 0,
+            tactical_eval_counter: 0,
+            transition_window_remaining: PeriodLoop::TRANSITION_WINDOW_TICKS,
+            last_owner_id,
+            last_possession_team: last_owner_id
+                .and_then(|id| field.players.iter().find(|p| p.id == id).map(|p| p.team_id)),
+            last_home_score: context.score.home_team.get(),
+            last_away_score: context.score.away_team.get(),
+            last_home_instruction: context.coach_home.instruction,
+            last_away_instruction: context.coach_away.instruction,
+            last_home_zone: context.tactical_home.ball_zone,
+            last_away_zone: context.tactical_away.ball_zone,
+            next_position_record_ms: (initial_t / Self::POSITION_RECORD_INTERVAL_MS + 1)
+                * Self::POSITION_RECORD_INTERVAL_MS,
+            track_positions: match_data.is_tracking_positions(),
+        }
+    }
 
-            tick_parity += 1;
-            coach_eval_counter += 1;
-            tactical_eval_counter += 1;
-            if transition_window_remaining > 0 {
-                transition_window_remaining -= 1;
-            }
+    /// Advance the current period by one clock tick. Returns `false`
+    /// once the period clock has run out — the caller then hands control
+    /// back to the state machine. `play_inner` loops this to completion;
+    /// `FootballEngine::step` drives it one tick at a time.
+    pub(super) fn period_tick(
+        period: &mut PeriodLoop,
+        field: &mut MatchField,
+        context: &mut MatchContext,
+        match_data: &mut ResultMatchPositionData,
+        hooks: &mut EngineHooks<'_>,
+    ) -> bool {
+        if !context.increment_time() {
+            return false;
+        }
 
-            // Coach evaluates every 500 ticks (~5 seconds of match time)
-            if coach_eval_counter >= 500 {
-                coach_eval_counter = 0;
-                let prof_t = prof_on.then(Instant::now);
-                Self::evaluate_coaches(field, context);
-                // Once every coach-eval slice, also probe for situational
-                // formation overrides — the manager swap to a chasing /
-                // protecting shape based on score and minute. Cheap: a
-                // single match arm and an equality check against the
-                // current type per side.
-                Self::evaluate_situational_shape(field, &mut *context);
-                if let Some(t) = prof_t {
-                    PhaseProf::add(PhaseProf::P_COACH, t.elapsed().as_nanos() as u64);
-                }
-                // Condition-trajectory sampling for the dev harness —
-                // average condition per position group per 15-min band.
-                // Rides the coach cadence so it costs one 22-player walk
-                // every 5 sim-seconds, match-logs builds only.
-                #[cfg(feature = "match-logs")]
-                {
-                    use crate::r#match::player::strategies::players::ops::forward_shot_decision::time_band_diag;
-                    use std::sync::atomic::Ordering;
-                    let band =
-                        time_band_diag::band_for_minute((context.total_match_time / 60_000) as u32);
-                    for p in field.players.iter().filter(|p| !p.is_sent_off) {
-                        let group = match p.tactical_position.current_position.position_group() {
-                            crate::PlayerFieldPositionGroup::Goalkeeper => 0,
-                            crate::PlayerFieldPositionGroup::Defender => 1,
-                            crate::PlayerFieldPositionGroup::Midfielder => 2,
-                            crate::PlayerFieldPositionGroup::Forward => 3,
-                        };
-                        time_band_diag::COND_SUM_BY_BAND_GROUP[band][group].fetch_add(
-                            p.player_attributes.condition.max(0) as u64,
-                            Ordering::Relaxed,
-                        );
-                        time_band_diag::COND_N_BY_BAND_GROUP[band][group]
-                            .fetch_add(1, Ordering::Relaxed);
-                    }
-                }
-            }
+        // Post-goal dead time: only the match clock advances while
+        // the players celebrate / walk back / wait for the restart
+        // whistle. No ball physics, no AI, no events, no coach
+        // evals — the world is already reset and frozen in
+        // formation, so skipping the tick body IS the celebration.
+        // See `MatchContext::dead_ball_until_ms` for why this pause
+        // is load-bearing (it consumed the post-goal hot window
+        // that made goals beget goals).
+        if context.total_match_time < context.dead_ball_until_ms {
+            return true;
+        }
 
-            // Team-level tactical state (phase, possession timers, line
-            // height) used a fixed 10-tick cadence. Adaptive cadence:
-            // stable possession uses BASE (25 ticks), while a 40-tick
-            // window after any transition trigger drops to TRANSITION
-            // (10 ticks) so phase/line-height/transition windows still
-            // resolve crisply when the game state actually shifts.
-            //
-            // Triggers (each cheap, no per-tick player walks):
-            //   • possession owner team changed
-            //   • score changed (goal scored — handled via reset path)
-            //   • coach instruction changed for either side
-            //   • ball zone moved into / out of attacking third for
-            //     either side
-            //
-            // Set-piece restarts are covered indirectly: kickoff /
-            // corner / goal kick all reassign the ball owner, which
-            // flips `last_possession_team` and re-opens the window.
-            //
-            // Cheap fast path: most ticks have the same `current_owner`
-            // as the previous tick (passes/dribbles span many ticks).
-            // Only re-resolve `team_id` via a 22-element scan when the
-            // raw id actually changed since the last evaluation.
-            let raw_owner = field.ball.current_owner;
-            let current_owner_team = if raw_owner == last_owner_id {
-                last_possession_team
-            } else {
-                last_owner_id = raw_owner;
-                raw_owner
-                    .and_then(|id| field.players.iter().find(|p| p.id == id).map(|p| p.team_id))
-            };
-            let possession_changed =
-                current_owner_team != last_possession_team && current_owner_team.is_some();
-            let home_score_now = context.score.home_team.get();
-            let away_score_now = context.score.away_team.get();
-            let score_changed =
-                home_score_now != last_home_score || away_score_now != last_away_score;
-            let home_instr_now = context.coach_home.instruction;
-            let away_instr_now = context.coach_away.instruction;
-            let instr_changed =
-                home_instr_now != last_home_instruction || away_instr_now != last_away_instruction;
-            let home_zone_now = context.tactical_home.ball_zone;
-            let away_zone_now = context.tactical_away.ball_zone;
-            // Attacking-third entry/exit on either side.
-            use crate::r#match::BallZone;
-            let zone_changed = matches!(home_zone_now, BallZone::AttackingThird)
-                != matches!(last_home_zone, BallZone::AttackingThird)
-                || matches!(away_zone_now, BallZone::AttackingThird)
-                    != matches!(last_away_zone, BallZone::AttackingThird);
-            if possession_changed || score_changed || instr_changed || zone_changed {
-                transition_window_remaining = TRANSITION_WINDOW_TICKS;
-                if possession_changed {
-                    last_possession_team = current_owner_team;
-                }
-                if score_changed {
-                    last_home_score = home_score_now;
-                    last_away_score = away_score_now;
-                }
-                if instr_changed {
-                    last_home_instruction = home_instr_now;
-                    last_away_instruction = away_instr_now;
-                }
-                if zone_changed {
-                    last_home_zone = home_zone_now;
-                    last_away_zone = away_zone_now;
-                }
-            }
+        if hooks.is_active() {
+            hooks.tick_start(field, context);
+        }
+        InferenceTelemetry::end_tick();
 
-            let tactical_interval = if transition_window_remaining > 0 {
-                TRANSITION_TACTICAL_INTERVAL_TICKS
-            } else {
-                BASE_TACTICAL_INTERVAL_TICKS
-            };
-            if tactical_eval_counter >= tactical_interval {
-                let interval = tactical_eval_counter;
-                tactical_eval_counter = 0;
-                let prof_t = prof_on.then(Instant::now);
-                Self::refresh_tactical_states(field, context, interval);
-                if let Some(t) = prof_t {
-                    PhaseProf::add(PhaseProf::P_TACTICAL, t.elapsed().as_nanos() as u64);
+        period.tick_parity += 1;
+        period.coach_eval_counter += 1;
+        period.tactical_eval_counter += 1;
+        if period.transition_window_remaining > 0 {
+            period.transition_window_remaining -= 1;
+        }
+
+        // Coach evaluates every 500 ticks (~5 seconds of match time)
+        if period.coach_eval_counter >= 500 {
+            period.coach_eval_counter = 0;
+            let prof_t = period.prof_on.then(Instant::now);
+            Self::evaluate_coaches(field, context);
+            // Once every coach-eval slice, also probe for situational
+            // formation overrides — the manager swap to a chasing /
+            // protecting shape based on score and minute. Cheap: a
+            // single match arm and an equality check against the
+            // current type per side.
+            Self::evaluate_situational_shape(field, &mut *context);
+            if let Some(t) = prof_t {
+                PhaseProf::add(PhaseProf::P_COACH, t.elapsed().as_nanos() as u64);
+            }
+            // Condition-trajectory sampling for the dev harness —
+            // average condition per position group per 15-min band.
+            // Rides the coach cadence so it costs one 22-player walk
+            // every 5 sim-seconds, match-logs builds only.
+            #[cfg(feature = "match-logs")]
+            {
+                use crate::r#match::player::strategies::players::ops::forward_shot_decision::time_band_diag;
+                use std::sync::atomic::Ordering;
+                let band =
+                    time_band_diag::band_for_minute((context.total_match_time / 60_000) as u32);
+                for p in field.players.iter().filter(|p| !p.is_sent_off) {
+                    let group = match p.tactical_position.current_position.position_group() {
+                        crate::PlayerFieldPositionGroup::Goalkeeper => 0,
+                        crate::PlayerFieldPositionGroup::Defender => 1,
+                        crate::PlayerFieldPositionGroup::Midfielder => 2,
+                        crate::PlayerFieldPositionGroup::Forward => 3,
+                    };
+                    time_band_diag::COND_SUM_BY_BAND_GROUP[band][group].fetch_add(
+                        p.player_attributes.condition.max(0) as u64,
+                        Ordering::Relaxed,
+                    );
+                    time_band_diag::COND_N_BY_BAND_GROUP[band][group]
+                        .fetch_add(1, Ordering::Relaxed);
                 }
-                // refresh_tactical_states may have repointed
-                // ball_zone — re-snapshot to avoid spuriously
-                // re-triggering the window on the next tick.
-                last_home_zone = context.tactical_home.ball_zone;
-                last_away_zone = context.tactical_away.ball_zone;
             }
+        }
 
-            // Full tick: ball + player AI + events
-            // Light tick: ball + player movement only (no AI re-evaluation)
-            if tick_parity & 1 == 0 {
-                Self::game_tick_light(field, context, match_data, &mut tick_ctx, &mut events);
-            } else {
-                Self::game_tick_inner(field, context, match_data, &mut tick_ctx, &mut events);
+        // Team-level tactical state (phase, possession timers, line
+        // height) used a fixed 10-tick cadence. Adaptive cadence:
+        // stable possession uses BASE (25 ticks), while a 40-tick
+        // window after any transition trigger drops to TRANSITION
+        // (10 ticks) so phase/line-height/transition windows still
+        // resolve crisply when the game state actually shifts.
+        //
+        // Triggers (each cheap, no per-tick player walks):
+        //   • possession owner team changed
+        //   • score changed (goal scored — handled via reset path)
+        //   • coach instruction changed for either side
+        //   • ball zone moved into / out of attacking third for
+        //     either side
+        //
+        // Set-piece restarts are covered indirectly: kickoff /
+        // corner / goal kick all reassign the ball owner, which
+        // flips `last_possession_team` and re-opens the window.
+        //
+        // Cheap fast path: most ticks have the same `current_owner`
+        // as the previous tick (passes/dribbles span many ticks).
+        // Only re-resolve `team_id` via a 22-element scan when the
+        // raw id actually changed since the last evaluation.
+        let raw_owner = field.ball.current_owner;
+        let current_owner_team = if raw_owner == period.last_owner_id {
+            period.last_possession_team
+        } else {
+            period.last_owner_id = raw_owner;
+            raw_owner.and_then(|id| field.players.iter().find(|p| p.id == id).map(|p| p.team_id))
+        };
+        let possession_changed =
+            current_owner_team != period.last_possession_team && current_owner_team.is_some();
+        match current_owner_team.or(period.last_possession_team) {
+            Some(team_id) if team_id == context.field_home_team_id => {
+                context.home_possession_ticks += 1;
+            }
+            Some(_) => context.away_possession_ticks += 1,
+            None => {}
+        }
+        let home_score_now = context.score.home_team.get();
+        let away_score_now = context.score.away_team.get();
+        let score_changed =
+            home_score_now != period.last_home_score || away_score_now != period.last_away_score;
+        let home_instr_now = context.coach_home.instruction;
+        let away_instr_now = context.coach_away.instruction;
+        let instr_changed = home_instr_now != period.last_home_instruction
+            || away_instr_now != period.last_away_instruction;
+        let home_zone_now = context.tactical_home.ball_zone;
+        let away_zone_now = context.tactical_away.ball_zone;
+        // Attacking-third entry/exit on either side.
+        let zone_changed = matches!(home_zone_now, BallZone::AttackingThird)
+            != matches!(period.last_home_zone, BallZone::AttackingThird)
+            || matches!(away_zone_now, BallZone::AttackingThird)
+                != matches!(period.last_away_zone, BallZone::AttackingThird);
+        if possession_changed || score_changed || instr_changed || zone_changed {
+            period.transition_window_remaining = PeriodLoop::TRANSITION_WINDOW_TICKS;
+            if possession_changed {
+                period.last_possession_team = current_owner_team;
+            }
+            if score_changed {
+                period.last_home_score = home_score_now;
+                period.last_away_score = away_score_now;
+            }
+            if instr_changed {
+                period.last_home_instruction = home_instr_now;
+                period.last_away_instruction = away_instr_now;
+            }
+            if zone_changed {
+                period.last_home_zone = home_zone_now;
+                period.last_away_zone = away_zone_now;
             }
+        }
 
-            // Replay-position recording, gated by a cursor instead of
-            // a per-tick modulo. Same 30 ms cadence as before; just one
-            // u64 comparison + add per tick when nothing is being
-            // tracked (the dominant production case).
-            if track_positions && context.total_match_time >= next_position_record_ms {
-                Self::write_match_positions(field, context.total_match_time, match_data);
-                next_position_record_ms += Self::POSITION_RECORD_INTERVAL_MS;
+        let tactical_interval = if period.transition_window_remaining > 0 {
+            PeriodLoop::TRANSITION_TACTICAL_INTERVAL_TICKS
+        } else {
+            PeriodLoop::BASE_TACTICAL_INTERVAL_TICKS
+        };
+        if period.tactical_eval_counter >= tactical_interval {
+            let interval = period.tactical_eval_counter;
+            period.tactical_eval_counter = 0;
+            let prof_t = period.prof_on.then(Instant::now);
+            Self::refresh_tactical_states(field, context, interval);
+            if let Some(t) = prof_t {
+                PhaseProf::add(PhaseProf::P_TACTICAL, t.elapsed().as_nanos() as u64);
             }
+            // refresh_tactical_states may have repointed
+            // ball_zone — re-snapshot to avoid spuriously
+            // re-triggering the window on the next tick.
+            period.last_home_zone = context.tactical_home.ball_zone;
+            period.last_away_zone = context.tactical_away.ball_zone;
+        }
 
-            // Forced medical substitutions run in ANY playing period —
-            // real football replaces an injured player whenever it
-            // happens, first half included. The pass owns the in-match
-            // injury roll; first check lands 3-8 minutes into each
-            // period, then every 6-14 minutes.
-            let medical_enabled = matches!(
-                context.state.match_state,
-                MatchState::FirstHalf | MatchState::SecondHalf | MatchState::ExtraTime
+        // Full tick: ball + player AI + events
+        // Light tick: ball + player movement only (no AI re-evaluation)
+        if period.tick_parity & 1 == 0 {
+            Self::game_tick_light(
+                field,
+                context,
+                match_data,
+                &mut period.tick_ctx,
+                &mut period.events,
+                hooks,
             );
-            if medical_enabled {
-                if medical_period != Some(context.state.match_state) {
-                    medical_period = Some(context.state.match_state);
-                    next_medical_time_ms =
-                        context.time.time + context.rng.range_u64(3, 8) * 60 * 1000;
-                }
-                if context.time.time >= next_medical_time_ms {
-                    Substitutions::process_medical(field, context);
-                    next_medical_time_ms =
-                        context.time.time + context.rng.range_u64(6, 14) * 60 * 1000;
-                }
+        } else {
+            Self::game_tick_inner(
+                field,
+                context,
+                match_data,
+                &mut period.tick_ctx,
+                &mut period.events,
+                hooks,
+            );
+        }
+
+        // Replay-position recording, gated by a cursor instead of
+        // a per-tick modulo. Same 30 ms cadence as before; just one
+        // u64 comparison + add per tick when nothing is being
+        // tracked (the dominant production case).
+        if period.track_positions && context.total_match_time >= period.next_position_record_ms {
+            Self::write_match_positions(field, context.total_match_time, match_data);
+            period.next_position_record_ms += Self::POSITION_RECORD_INTERVAL_MS;
+        }
+
+        // Forced medical substitutions run in ANY playing period —
+        // real football replaces an injured player whenever it
+        // happens, first half included. The pass owns the in-match
+        // injury roll; first check lands 3-8 minutes into each
+        // period, then every 6-14 minutes.
+        let medical_enabled = matches!(
+            context.state.match_state,
+            MatchState::FirstHalf | MatchState::SecondHalf | MatchState::ExtraTime
+        );
+        if medical_enabled {
+            if period.medical_period != Some(context.state.match_state) {
+                period.medical_period = Some(context.state.match_state);
+                period.next_medical_time_ms =
+                    context.time.time + context.rng.range_u64(3, 8) * 60 * 1000;
             }
+            if context.time.time >= period.next_medical_time_ms {
+                Substitutions::process_medical(field, context);
+                period.next_medical_time_ms =
+                    context.time.time + context.rng.range_u64(6, 14) * 60 * 1000;
+            }
+        }
 
-            // Discretionary substitutions allowed from the second half
-            // onwards, plus extra time when we reach it in a knockout
-            // tie. First-half subs in real football are reactive
-            // (injuries) — the medical pass above owns those. ET gets
-            // one bonus sub on entry (FIFA rule).
-            let subs_enabled = matches!(
-                context.state.match_state,
-                MatchState::SecondHalf | MatchState::ExtraTime
-            );
+        // Discretionary substitutions allowed from the second half
+        // onwards, plus extra time when we reach it in a knockout
+        // tie. First-half subs in real football are reactive
+        // (injuries) — the medical pass above owns those. ET gets
+        // one bonus sub on entry (FIFA rule).
+        let subs_enabled = matches!(
+            context.state.match_state,
+            MatchState::SecondHalf | MatchState::ExtraTime
+        );
 
-            if subs_enabled {
-                // Grant the ET bonus once — bumps the cap by 1 for both
-                // sides — but only when the active rule set allows it.
-                // Friendlies (cap = usize::MAX) skip the increment.
-                if context.state.match_state == MatchState::ExtraTime
-                    && !et_bonus_granted
-                    && context.allow_extra_time_extra_sub
-                {
-                    if context.max_substitutions_per_team < usize::MAX {
-                        context.max_substitutions_per_team += 1;
-                    }
-                    et_bonus_granted = true;
-                    // Reset the next-sub timer for the new period.
-                    sub_times_initialized = false;
+        if subs_enabled {
+            // Grant the ET bonus once — bumps the cap by 1 for both
+            // sides — but only when the active rule set allows it.
+            // Friendlies (cap = usize::MAX) skip the increment.
+            if context.state.match_state == MatchState::ExtraTime
+                && !period.et_bonus_granted
+                && context.allow_extra_time_extra_sub
+            {
+                if context.max_substitutions_per_team < usize::MAX {
+                    context.max_substitutions_per_team += 1;
                 }
+                period.et_bonus_granted = true;
+                // Reset the next-sub timer for the new period.
+                period.sub_times_initialized = false;
+            }
 
-                if !sub_times_initialized {
-                    next_sub_time_ms = context.rng.range_u64(10, 20) * 60 * 1000;
-                    sub_times_initialized = true;
-                }
+            if !period.sub_times_initialized {
+                period.next_sub_time_ms = context.rng.range_u64(10, 20) * 60 * 1000;
+                period.sub_times_initialized = true;
+            }
 
-                let period_time = context.time.time;
-                if period_time >= next_sub_time_ms {
-                    // Deterministic "today" — captured at context
-                    // construction. Used only for the youth-protection
-                    // sub branch, where the comparison is age <= 17.
-                    let today = context.today;
-                    let per_pass_cap = context.max_substitutions_per_pass;
-                    process_substitutions(field, context, per_pass_cap, today);
-                    next_sub_time_ms = period_time + context.rng.range_u64(5, 15) * 60 * 1000;
-                }
+            let period_time = context.time.time;
+            if period_time >= period.next_sub_time_ms {
+                // Deterministic "today" — captured at context
+                // construction. Used only for the youth-protection
+                // sub branch, where the comparison is age <= 17.
+                let today = context.today;
+                let per_pass_cap = context.max_substitutions_per_pass;
+                process_substitutions(field, context, per_pass_cap, today);
+                period.next_sub_time_ms = period_time + context.rng.range_u64(5, 15) * 60 * 1000;
             }
         }
 
-        result
+        true
     }
 }
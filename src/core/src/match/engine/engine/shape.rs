@@ -453,6 +453,8 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
             home_skills: home_skill_aggregates,
             away_skills: away_skill_aggregates,
             home_edge: context.environment.crowd_intensity * context.environment.home_advantage,
+            home_familiarity: context.tactical_familiarity_home,
+            away_familiarity: context.tactical_familiarity_away,
         };
         TeamTacticalState::refresh(
             &mut context.tactical_home,
@@ -3,13 +3,24 @@ use crate::PlayerSkills;
 use crate::club::player::builder::PlayerBuilder;
 use crate::r#match::MatchCoach;
 use crate::r#match::MatchPlayer;
+use crate::r#match::engine::context::MatchEngineConfig;
 use crate::shared::fullname::FullName;
 use crate::{
-    PersonAttributes, PlayerAttributes, PlayerPosition, PlayerPositionType, PlayerPositions,
+    MatchTacticType, PersonAttributes, PlayerAttributes, PlayerPosition, PlayerPositionType,
+    PlayerPositions, Tactics,
 };
 use chrono::NaiveDate;
 
 fn build_test_player(skill_fill: f32, position: PlayerPositionType) -> MatchPlayer {
+    build_test_player_with_id(1, 1, skill_fill, position)
+}
+
+fn build_test_player_with_id(
+    id: u32,
+    team_id: u32,
+    skill_fill: f32,
+    position: PlayerPositionType,
+) -> MatchPlayer {
     let mut attrs = PlayerAttributes::default();
     attrs.condition = 9000;
     attrs.jadedness = 0;
@@ -49,7 +60,7 @@ fn build_test_player(skill_fill: f32, position: PlayerPositionType) -> MatchPlay
     skills.goalkeeping.reflexes = skill_fill;
     skills.goalkeeping.handling = skill_fill;
     let player = PlayerBuilder::new()
-        .id(1)
+        .id(id)
         .full_name(FullName::new("T".to_string(), "P".to_string()))
         .birth_date(NaiveDate::from_ymd_opt(2000, 1, 1).unwrap())
         .country_id(1)
@@ -64,7 +75,7 @@ fn build_test_player(skill_fill: f32, position: PlayerPositionType) -> MatchPlay
         .player_attributes(attrs)
         .build()
         .unwrap();
-    MatchPlayer::from_player(1, &player, position, false)
+    MatchPlayer::from_player(team_id, &player, position, false)
 }
 
 #[test]
@@ -285,3 +296,345 @@ fn rolling_metrics_zero_pressures_returns_neutral_press_rate() {
     );
     assert!((m.press_success_rate_last_10 - 0.5).abs() < 1e-4);
 }
+
+// ─── Engine hooks ──────────────────────────────────────────────────────
+
+use crate::r#match::ball::events::BallEvent;
+use crate::r#match::events::{Event, EventCollection};
+
+#[derive(Default)]
+struct CountingHook {
+    states: Vec<(Option<MatchState>, MatchState)>,
+}
+
+impl EngineHook for CountingHook {
+    fn on_state_change(
+        &mut self,
+        previous: Option<MatchState>,
+        next: MatchState,
+        _context: &MatchContext,
+    ) {
+        self.states.push((previous, next));
+    }
+}
+
+#[test]
+fn event_collection_iter_preserves_inline_then_overflow_order() {
+    let mut events = EventCollection::new();
+    events.add_ball_event(BallEvent::Claimed(1));
+    events.add_ball_event(BallEvent::Gained(2));
+    events.add_ball_event(BallEvent::Claimed(3));
+
+    let ids: Vec<u32> = events
+        .iter()
+        .map(|e| match e {
+            Event::BallEvent(BallEvent::Claimed(id)) | Event::BallEvent(BallEvent::Gained(id)) => {
+                *id
+            }
+            _ => 0,
+        })
+        .collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+    // Borrowing iteration must not consume the collection.
+    assert!(events.has_events());
+    assert_eq!(events.drain().count(), 3);
+}
+
+#[test]
+fn engine_hooks_observe_state_changes_of_a_played_match() {
+    let mut collector = CountingHook::default();
+    {
+        let mut hooks = EngineHooks::new().with(&mut collector);
+        assert!(hooks.is_active());
+        assert_eq!(hooks.len(), 1);
+        FootballEngine::<840, 545>::play_with_hooks(
+            build_test_squad(1),
+            build_test_squad(2),
+            MatchEngineConfig::seeded(7),
+            &mut hooks,
+        );
+    }
+    // The borrow ends with the hook set, so the collector is readable
+    // again after the match.
+    assert_eq!(
+        collector.states,
+        vec![
+            (None, MatchState::FirstHalf),
+            (Some(MatchState::FirstHalf), MatchState::HalfTime),
+            (Some(MatchState::HalfTime), MatchState::SecondHalf),
+        ]
+    );
+    assert!(!EngineHooks::new().is_active());
+}
+
+// ─── Stepping API ──────────────────────────────────────────────────────
+
+fn build_test_squad(team_id: u32) -> MatchSquad {
+    let tactics = Tactics::new(MatchTacticType::T442);
+    let main_squad = tactics
+        .positions()
+        .iter()
+        .enumerate()
+        .map(|(i, pos)| build_test_player_with_id(team_id * 100 + i as u32, team_id, 12.0, *pos))
+        .collect();
+    MatchSquad {
+        team_id,
+        team_name: format!("Team{}", team_id),
+        tactics,
+        main_squad,
+        substitutes: vec![],
+        captain_id: None,
+        vice_captain_id: None,
+        penalty_taker_id: None,
+        free_kick_taker_id: None,
+        selection_omissions: vec![],
+        coach_snapshot: None,
+        opposition_report: 0.0,
+        cohesion: None,
+    }
+}
+
+fn started_engine() -> FootballEngine<840, 545> {
+    let config = MatchEngineConfig {
+        seed: Some(7),
+        ..MatchEngineConfig::default()
+    };
+    let mut engine = FootballEngine::<840, 545>::new();
+    engine.start(build_test_squad(1), build_test_squad(2), config);
+    engine
+}
+
+/// Records every top-level event with the match time it fired at into a
+/// shared log, so a `'static` hook set can still be read after the match.
+struct EventLog(std::sync::Arc<std::sync::Mutex<Vec<(u64, String)>>>);
+
+impl EngineHook for EventLog {
+    fn on_event(&mut self, event: &Event, _field: &MatchField, context: &MatchContext) {
+        let description = match event {
+            Event::BallEvent(e) => format!("{:?}", e),
+            Event::PlayerEvent(e) => format!("{:?}", e),
+        };
+        self.0
+            .lock()
+            .unwrap()
+            .push((context.total_match_time, description));
+    }
+}
+
+/// What both runs must agree on: the score, the goal timeline and the
+/// substitutions.
+#[derive(Debug, PartialEq)]
+struct ResultSummary {
+    home_goals: u8,
+    away_goals: u8,
+    goals: Vec<(u32, u64, bool)>,
+    substitutions: Vec<(u32, u32, u64)>,
+}
+
+impl ResultSummary {
+    fn of(result: &MatchResultRaw) -> Self {
+        let score = result.score.as_ref().unwrap();
+        ResultSummary {
+            home_goals: score.home_team.get(),
+            away_goals: score.away_team.get(),
+            goals: score
+                .detail()
+                .iter()
+                .map(|goal| (goal.player_id, goal.time, goal.is_auto_goal))
+                .collect(),
+            substitutions: result
+                .substitutions
+                .iter()
+                .map(|sub| (sub.player_out_id, sub.player_in_id, sub.match_time_ms))
+                .collect(),
+        }
+    }
+}
+
+#[test]
+fn stepping_to_the_end_matches_the_one_shot_result() {
+    use std::sync::{Arc, Mutex};
+
+    // Recording is process-wide and a parallel test may switch it on
+    // mid-run, which would change the logged events of one run only.
+    TrainingData::record_for_tests();
+    // Building a player rolls its traits, so both runs share one build.
+    let (home, away) = (build_test_squad(1), build_test_squad(2));
+
+    let one_shot_events = Arc::new(Mutex::new(Vec::new()));
+    let one_shot = FootballEngine::<840, 545>::play_with_hooks(
+        home.clone(),
+        away.clone(),
+        MatchEngineConfig::seeded(7),
+        &mut EngineHooks::new().with(EventLog(one_shot_events.clone())),
+    );
+
+    let stepped_events = Arc::new(Mutex::new(Vec::new()));
+    let mut engine = FootballEngine::<840, 545>::new();
+    engine.start_with_hooks(
+        home,
+        away,
+        MatchEngineConfig::seeded(7),
+        EngineHooks::new().with(EventLog(stepped_events.clone())),
+    );
+    while !engine.is_finished() {
+        engine.step(1_000);
+    }
+    let stepped = engine.finish().unwrap();
+
+    assert_eq!(ResultSummary::of(&stepped), ResultSummary::of(&one_shot));
+    assert_eq!(stepped.match_time_ms, one_shot.match_time_ms);
+    assert_eq!(stepped.additional_time_ms, one_shot.additional_time_ms);
+    let one_shot_events = one_shot_events.lock().unwrap();
+    assert!(!one_shot_events.is_empty());
+    assert_eq!(*stepped_events.lock().unwrap(), *one_shot_events);
+}
+
+#[test]
+fn step_plays_requested_ticks_into_first_half() {
+    let mut engine = started_engine();
+    assert!(engine.is_started());
+    assert!(!engine.is_finished());
+
+    assert_eq!(engine.step(50), 50);
+    let snapshot = engine.snapshot().unwrap();
+    assert_eq!(snapshot.state, MatchState::FirstHalf);
+    assert_eq!(snapshot.ticks, 50);
+    assert_eq!(snapshot.players.len(), 22);
+    assert!(snapshot.total_match_time_ms > 0);
+}
+
+#[test]
+fn pause_blocks_tick_but_not_step() {
+    let mut engine = started_engine();
+    engine.pause();
+    assert!(engine.is_paused());
+    assert!(!engine.tick());
+    assert_eq!(engine.snapshot().unwrap().ticks, 0);
+
+    assert_eq!(engine.step(1), 1);
+    engine.resume();
+    assert!(engine.tick());
+    assert_eq!(engine.snapshot().unwrap().ticks, 2);
+}
+
+#[test]
+fn run_until_stops_on_condition() {
+    let mut engine = started_engine();
+    assert!(engine.run_until(|_, context| context.total_match_time >= 5_000));
+    let time = engine.context().unwrap().total_match_time;
+    assert!((5_000..5_100).contains(&time), "stopped at {time}");
+}
+
+#[test]
+fn snapshot_round_trips_through_json() {
+    let mut engine = started_engine();
+    engine.step(10);
+    let snapshot = engine.snapshot().unwrap();
+    let json = serde_json::to_string(&snapshot).unwrap();
+    let back: MatchSnapshot = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.ticks, snapshot.ticks);
+    assert_eq!(back.players.len(), snapshot.players.len());
+    assert_eq!(back.state, snapshot.state);
+}
+
+#[test]
+fn unstarted_engine_is_inert() {
+    let mut engine = FootballEngine::<840, 545>::new();
+    assert!(engine.is_finished());
+    assert_eq!(engine.step(10), 0);
+    assert!(!engine.tick());
+    assert!(engine.snapshot().is_none());
+    assert!(engine.finish().is_none());
+}
+
+#[test]
+fn scenario_edits_hold_into_the_next_tick() {
+    use crate::r#match::goalkeepers::states::state::GoalkeeperState;
+    use crate::r#match::player::state::PlayerState;
+    use nalgebra::Vector3;
+
+    let mut engine = started_engine();
+    engine.step(50);
+    let mut edit = engine.edit().unwrap();
+    assert_eq!(
+        edit.move_player(9_999, Vector3::zeros()),
+        Err(ScenarioError::UnknownPlayer(9_999))
+    );
+    edit.move_player(110, Vector3::new(700.0, 270.0, 0.0))
+        .unwrap();
+    edit.give_ball(110).unwrap();
+    edit.set_player_state(200, PlayerState::Goalkeeper(GoalkeeperState::Diving))
+        .unwrap();
+    drop(edit);
+
+    let snapshot = engine.snapshot().unwrap();
+    assert_eq!(snapshot.ball.owner, Some(110));
+    assert_eq!(snapshot.ball.position, Vector3::new(700.0, 270.0, 0.0));
+    let keeper = snapshot.players.iter().find(|p| p.id == 200).unwrap();
+    assert!(keeper.state.contains("Diving"), "{}", keeper.state);
+    assert_eq!(
+        engine
+            .field()
+            .unwrap()
+            .players
+            .iter()
+            .find(|p| p.id == 200)
+            .unwrap()
+            .in_state_time,
+        0
+    );
+
+    let mut edit = engine.edit().unwrap();
+    edit.move_ball(Vector3::new(420.0, 100.0, 0.0));
+    edit.set_ball_velocity(Vector3::new(5.0, 0.0, 0.0));
+    drop(edit);
+    assert_eq!(engine.snapshot().unwrap().ball.owner, None);
+    assert_eq!(engine.step(1), 1);
+    assert!(engine.snapshot().unwrap().ball.position.x > 420.0);
+}
+
+// ─── Training data ─────────────────────────────────────────────────────
+
+/// Lends a recorder to a live match, whose hooks must be `'static`.
+struct SharedRecorder(std::sync::Arc<std::sync::Mutex<TrainingRecorder>>);
+
+impl EngineHook for SharedRecorder {
+    fn on_tick_start(&mut self, field: &mut MatchField, context: &mut MatchContext) {
+        self.0.lock().unwrap().on_tick_start(field, context)
+    }
+
+    fn on_event(&mut self, event: &Event, field: &MatchField, context: &MatchContext) {
+        self.0.lock().unwrap().on_event(event, field, context)
+    }
+}
+
+#[test]
+fn recorder_labels_passes_with_their_outcome() {
+    TrainingData::record_for_tests();
+    let recorder = std::sync::Arc::new(std::sync::Mutex::new(TrainingRecorder::new()));
+    let config = MatchEngineConfig {
+        seed: Some(7),
+        ..MatchEngineConfig::default()
+    };
+    let mut engine = FootballEngine::<840, 545>::new();
+    engine.start_with_hooks(
+        build_test_squad(1),
+        build_test_squad(2),
+        config,
+        EngineHooks::new().with(SharedRecorder(recorder.clone())),
+    );
+    engine.step(6_000);
+
+    let recorder = recorder.lock().unwrap();
+    let passes = recorder.passes();
+    assert!(passes.iter().any(|p| p.success), "no completed pass");
+    assert!(passes.iter().any(|p| !p.success), "no failed pass");
+    for pass in passes {
+        let f = &pass.features;
+        assert!(f.target_distance > 0.0 && f.goal_distance.is_finite());
+        assert!((0.0..=1.0).contains(&f.condition));
+        assert!(pass.shot_type.is_none());
+    }
+    assert!(recorder.shots().iter().all(|s| s.shot_type.is_some()));
+}
@@ -21,7 +21,14 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         tick_ctx: &mut GameTickContext,
     ) {
         let mut events = EventCollection::with_capacity(10);
-        Self::game_tick_inner(field, context, match_data, tick_ctx, &mut events);
+        Self::game_tick_inner(
+            field,
+            context,
+            match_data,
+            tick_ctx,
+            &mut events,
+            &mut EngineHooks::new(),
+        );
         // Keep this public single-tick wrapper self-contained — the
         // play_inner loop now gates position recording with a cursor
         // (`next_position_record_ms`) for efficiency, but external
@@ -37,6 +44,7 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         match_data: &mut ResultMatchPositionData,
         tick_ctx: &mut GameTickContext,
         events: &mut EventCollection,
+        hooks: &mut EngineHooks<'_>,
     ) {
         events.clear();
 
@@ -76,6 +84,9 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         }
 
         if events.has_events() {
+            if hooks.is_active() {
+                hooks.events(events, field, context);
+            }
             EventDispatcher::dispatch(events, field, context, match_data, true);
             handle_goal_reset(field, context);
         }
@@ -91,6 +102,7 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
         match_data: &mut ResultMatchPositionData,
         tick_ctx: &mut GameTickContext,
         events: &mut EventCollection,
+        hooks: &mut EngineHooks<'_>,
     ) {
         let prof_on = PhaseProf::enabled();
 
@@ -127,6 +139,10 @@ impl<const W: usize, const H: usize> FootballEngine<W, H> {
             PhaseProf::add(PhaseProf::P_PLAYERS, t.elapsed().as_nanos() as u64);
         }
 
+        if hooks.is_active() {
+            hooks.events(events, field, context);
+        }
+
         let t = prof_on.then(Instant::now);
         EventDispatcher::dispatch(events, field, context, match_data, true);
         handle_goal_reset(field, context);
@@ -0,0 +1,298 @@
+//! Training data — labelled decisions logged from simulated matches.
+//!
+//! While recording is on (`TrainingData::start`), every pass and shot
+//! event carries the deciding player's `DecisionFeatures` (see
+//! `match::features`). A `TrainingRecorder` hook follows
+//! each decision until the engine resolves it — the pass is received by
+//! a teammate or lost, the shot goes in or not — and the labelled rows
+//! are appended to `passes.csv` and `shots.csv` once the match is over.
+//!
+//! Decisions the engine never acts on (an event the dispatcher rejects)
+//! and decisions still open at the final whistle are dropped, so every
+//! row has a real outcome. Recording is off by default; the builders
+//! then skip feature extraction and the event contexts carry `None`.
+
+use crate::r#match::events::Event;
+use crate::r#match::player::events::PlayerEvent;
+use crate::r#match::player::strategies::players::ShotType;
+use crate::r#match::{
+    DecisionFeatures, EngineHook, MatchContext, MatchField, StateProcessingContext,
+};
+use nalgebra::Vector3;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// One resolved decision.
+#[derive(Debug, Clone)]
+pub struct TrainingRow {
+    pub player_id: u32,
+    pub features: DecisionFeatures,
+    pub reason: &'static str,
+    /// Set for shots only.
+    pub shot_type: Option<ShotType>,
+    /// Pass completed to a teammate / shot scored.
+    pub success: bool,
+}
+
+struct OpenDecision {
+    row: TrainingRow,
+    tick: u64,
+    /// The engine took the decision on — the ball carries it.
+    launched: bool,
+    /// Shots: the shooter's team goals when the shot was taken.
+    goals_before: u8,
+}
+
+enum Resolution {
+    Open,
+    Done(bool),
+    Dropped,
+}
+
+/// Engine hook pairing each recorded decision with its outcome.
+/// `play_with_config` attaches one per match while recording; dev tools
+/// can attach their own through `play_with_hooks` and read the rows.
+#[derive(Default)]
+pub struct TrainingRecorder {
+    open_pass: Option<OpenDecision>,
+    open_shot: Option<OpenDecision>,
+    passes: Vec<TrainingRow>,
+    shots: Vec<TrainingRow>,
+}
+
+impl TrainingRecorder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn passes(&self) -> &[TrainingRow] {
+        &self.passes
+    }
+
+    pub fn shots(&self) -> &[TrainingRow] {
+        &self.shots
+    }
+
+    fn resolve_pass(&mut self, field: &MatchField) {
+        let Some(open) = self.open_pass.as_mut() else {
+            return;
+        };
+        let passer = open.row.player_id;
+        let ball = &field.ball;
+        let resolution = if ball.pending_pass_passer == Some(passer) {
+            open.launched = true;
+            Resolution::Open
+        } else if ball.last_completed_pass_passer_id == Some(passer)
+            && ball.last_completed_pass_tick >= open.tick
+        {
+            Resolution::Done(true)
+        } else if open.launched {
+            Resolution::Done(false)
+        } else {
+            Resolution::Dropped
+        };
+        Self::settle(&mut self.open_pass, &mut self.passes, resolution);
+    }
+
+    fn resolve_shot(&mut self, field: &MatchField, context: &MatchContext) {
+        let Some(open) = self.open_shot.as_mut() else {
+            return;
+        };
+        let shooter = open.row.player_id;
+        let resolution = if team_goals(shooter, field, context) > open.goals_before {
+            Resolution::Done(true)
+        } else if field.ball.last_shot_shooter_id == Some(shooter) {
+            open.launched = true;
+            Resolution::Open
+        } else if open.launched {
+            Resolution::Done(false)
+        } else {
+            Resolution::Dropped
+        };
+        Self::settle(&mut self.open_shot, &mut self.shots, resolution);
+    }
+
+    fn settle(
+        open: &mut Option<OpenDecision>,
+        rows: &mut Vec<TrainingRow>,
+        resolution: Resolution,
+    ) {
+        match resolution {
+            Resolution::Open => {}
+            Resolution::Dropped => *open = None,
+            Resolution::Done(success) => {
+                if let Some(mut decision) = open.take() {
+                    decision.row.success = success;
+                    rows.push(decision.row);
+                }
+            }
+        }
+    }
+}
+
+fn team_goals(player_id: u32, field: &MatchField, context: &MatchContext) -> u8 {
+    let team_id = field
+        .players
+        .iter()
+        .find(|p| p.id == player_id)
+        .map(|p| p.team_id);
+    if team_id == Some(context.score.home_team.team_id) {
+        context.score.home_team.get()
+    } else if team_id == Some(context.score.away_team.team_id) {
+        context.score.away_team.get()
+    } else {
+        0
+    }
+}
+
+impl EngineHook for TrainingRecorder {
+    fn on_tick_start(&mut self, field: &mut MatchField, context: &mut MatchContext) {
+        self.resolve_pass(field);
+        self.resolve_shot(field, context);
+    }
+
+    fn on_event(&mut self, event: &Event, field: &MatchField, context: &MatchContext) {
+        let Event::PlayerEvent(event) = event else {
+            return;
+        };
+        match event {
+            PlayerEvent::PassTo(pass) => {
+                let Some(features) = pass.features.as_deref() else {
+                    return;
+                };
+                // A new pass settles the previous one first.
+                self.resolve_pass(field);
+                self.open_pass = Some(OpenDecision {
+                    row: TrainingRow {
+                        player_id: pass.from_player_id,
+                        features: *features,
+                        reason: pass.reason,
+                        shot_type: None,
+                        success: false,
+                    },
+                    tick: context.current_tick(),
+                    launched: false,
+                    goals_before: 0,
+                });
+            }
+            PlayerEvent::Shoot(shot) => {
+                let Some(features) = shot.features.as_deref() else {
+                    return;
+                };
+                self.resolve_shot(field, context);
+                self.open_shot = Some(OpenDecision {
+                    row: TrainingRow {
+                        player_id: shot.from_player_id,
+                        features: *features,
+                        reason: shot.reason,
+                        shot_type: Some(shot.shot_type),
+                        success: false,
+                    },
+                    tick: context.current_tick(),
+                    launched: false,
+                    goals_before: team_goals(shot.from_player_id, field, context),
+                });
+            }
+            _ => {}
+        }
+    }
+}
+
+struct TrainingFiles {
+    passes: BufWriter<File>,
+    shots: BufWriter<File>,
+}
+
+static TRAINING_FILES: OnceLock<Mutex<TrainingFiles>> = OnceLock::new();
+static RECORDING: AtomicBool = AtomicBool::new(false);
+static MATCH_SEQ: AtomicU64 = AtomicU64::new(0);
+
+/// Process-wide training-data output.
+pub struct TrainingData;
+
+impl TrainingData {
+    /// Start appending labelled decisions of every match played through
+    /// `play_with_config` to `passes.csv` and `shots.csv` in `directory`,
+    /// creating it as needed. Files that already hold rows keep them.
+    /// First call wins, as with the match dispatcher.
+    pub fn start(directory: &Path) -> io::Result<()> {
+        fs::create_dir_all(directory)?;
+        let files = TrainingFiles {
+            passes: open_table(&directory.join("passes.csv"), "completed")?,
+            shots: open_table(&directory.join("shots.csv"), "shot_type,scored")?,
+        };
+        if TRAINING_FILES.set(Mutex::new(files)).is_ok() {
+            RECORDING.store(true, Ordering::SeqCst);
+        }
+        Ok(())
+    }
+
+    pub fn is_recording() -> bool {
+        RECORDING.load(Ordering::Relaxed)
+    }
+
+    /// Features of a decision aimed at `target`, for the event context to
+    /// carry — `None` while nothing is recorded, so matches don't pay for
+    /// the extraction.
+    pub fn capture(
+        ctx: &StateProcessingContext,
+        target: Vector3<f32>,
+        force: f32,
+    ) -> Option<Box<DecisionFeatures>> {
+        Self::is_recording().then(|| Box::new(DecisionFeatures::extract(ctx, target, force)))
+    }
+
+    /// Write one finished match's rows. A failed write is logged rather
+    /// than failing the match.
+    pub(crate) fn append(recorder: &TrainingRecorder) {
+        let Some(files) = TRAINING_FILES.get() else {
+            return;
+        };
+        let match_no = MATCH_SEQ.fetch_add(1, Ordering::Relaxed);
+        let mut files = files.lock().unwrap_or_else(|e| e.into_inner());
+        let written = write_rows(&mut files.passes, match_no, &recorder.passes)
+            .and_then(|_| write_rows(&mut files.shots, match_no, &recorder.shots));
+        if let Err(e) = written {
+            log::warn!("training data: {e}");
+        }
+    }
+
+    /// Feature capture without an output directory, for engine tests.
+    #[cfg(test)]
+    pub(crate) fn record_for_tests() {
+        RECORDING.store(true, Ordering::SeqCst);
+    }
+}
+
+fn open_table(path: &Path, outcome_columns: &str) -> io::Result<BufWriter<File>> {
+    let file = OpenOptions::new().create(true).append(true).open(path)?;
+    let empty = file.metadata()?.len() == 0;
+    let mut out = BufWriter::new(file);
+    if empty {
+        writeln!(
+            out,
+            "match,player_id,{},reason,{outcome_columns}",
+            DecisionFeatures::NAMES.join(",")
+        )?;
+        out.flush()?;
+    }
+    Ok(out)
+}
+
+fn write_rows(out: &mut BufWriter<File>, match_no: u64, rows: &[TrainingRow]) -> io::Result<()> {
+    for row in rows {
+        write!(out, "{match_no},{},", row.player_id)?;
+        for value in row.features.to_array() {
+            write!(out, "{value},")?;
+        }
+        write!(out, "\"{}\",", row.reason.replace('"', "\"\""))?;
+        if let Some(shot_type) = row.shot_type {
+            write!(out, "{shot_type:?},")?;
+        }
+        writeln!(out, "{}", u8::from(row.success))?;
+    }
+    out.flush()
+}
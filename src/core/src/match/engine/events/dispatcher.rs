@@ -130,6 +130,17 @@ impl EventCollection {
         self.overflow.clear();
     }
 
+    /// Borrowing iterator in insertion order (inline first, then
+    /// overflow) — used by engine hooks to observe events without
+    /// consuming them ahead of dispatch.
+    pub fn iter(&self) -> impl Iterator<Item = &Event> {
+        self.inline
+            .iter()
+            .take(self.inline_len as usize)
+            .filter_map(|slot| slot.as_ref())
+            .chain(self.overflow.iter())
+    }
+
     /// Move every event out of the collection, leaving it empty once
     /// the returned iterator is fully consumed (or dropped).
     pub fn drain(&mut self) -> EventDrain<'_> {
@@ -199,6 +199,10 @@ pub struct MatchContext {
     /// summary can show the moment the manager pivoted. `None` while
     /// no shape change has happened yet.
     pub first_shape_change_minute: Option<u8>,
+    /// Live-play ticks each side has had the ball, for the match
+    /// report's possession split.
+    pub home_possession_ticks: u64,
+    pub away_possession_ticks: u64,
     /// Tactics each team started the match with. Captured by
     /// `FootballEngine::play` from the kickoff `MatchSquad` so the
     /// result can show "started 4-4-2 → finished 4-3-3" without the
@@ -369,6 +373,8 @@ impl MatchContext {
             last_shape_change_tick: u64::MAX,
             dead_ball_until_ms: 0,
             first_shape_change_minute: None,
+            home_possession_ticks: 0,
+            away_possession_ticks: 0,
             starting_home_tactic: None,
             starting_away_tactic: None,
             home_skill_aggregates: TeamSkillAggregates::neutral(),
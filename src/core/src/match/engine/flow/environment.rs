@@ -133,6 +133,16 @@ impl EnvModifiers {
 }
 
 impl MatchEnvironment {
+    /// A derby: full rivalry intensity in front of a packed, louder
+    /// crowd. Weather, pitch and importance stay neutral.
+    pub fn derby() -> Self {
+        MatchEnvironment {
+            crowd_intensity: 0.8,
+            derby_intensity: 1.0,
+            ..Default::default()
+        }
+    }
+
     pub fn modifiers(&self) -> EnvModifiers {
         weather_modifiers(self.weather).combine(pitch_modifiers(self.pitch))
     }
@@ -305,4 +315,13 @@ mod tests {
         assert_eq!(env.match_importance, 1.0);
         assert_eq!(env.derby_intensity, 0.0);
     }
+
+    #[test]
+    fn derby_is_louder_and_fully_intense() {
+        let derby = MatchEnvironment::derby();
+        let neutral = MatchEnvironment::default();
+        assert_eq!(derby.derby_intensity, 1.0);
+        assert!(derby.crowd_intensity > neutral.crowd_intensity);
+        assert_eq!(derby.match_importance, neutral.match_importance);
+    }
 }
@@ -234,6 +234,13 @@ pub struct MatchResultRaw {
     /// shape during the match. Stored as the marker the web view uses
     /// to label a chip with "shifted at min X".
     pub shape_change_minute: Option<u8>,
+    /// Engine ticks each side had the ball; a loose ball stays with the
+    /// side that last owned it. Both zero on results recorded before
+    /// possession was tracked.
+    #[serde(default)]
+    pub home_possession_ticks: u64,
+    #[serde(default)]
+    pub away_possession_ticks: u64,
 }
 
 impl Clone for MatchResultRaw {
@@ -255,6 +262,8 @@ impl Clone for MatchResultRaw {
             final_home_tactic: self.final_home_tactic,
             final_away_tactic: self.final_away_tactic,
             shape_change_minute: self.shape_change_minute,
+            home_possession_ticks: self.home_possession_ticks,
+            away_possession_ticks: self.away_possession_ticks,
         }
     }
 }
@@ -278,6 +287,8 @@ impl MatchResultRaw {
             final_home_tactic: None,
             final_away_tactic: None,
             shape_change_minute: None,
+            home_possession_ticks: 0,
+            away_possession_ticks: 0,
         }
     }
 
@@ -299,7 +310,19 @@ impl MatchResultRaw {
             final_home_tactic: self.final_home_tactic,
             final_away_tactic: self.final_away_tactic,
             shape_change_minute: self.shape_change_minute,
+            home_possession_ticks: self.home_possession_ticks,
+            away_possession_ticks: self.away_possession_ticks,
+        }
+    }
+
+    /// Home side's share of possession in `0.0..=1.0`, or `None` when
+    /// the match carries no possession record.
+    pub fn home_possession_share(&self) -> Option<f32> {
+        let total = self.home_possession_ticks + self.away_possession_ticks;
+        if total == 0 {
+            return None;
         }
+        Some(self.home_possession_ticks as f32 / total as f32)
     }
 
     pub fn write_team_players(
@@ -573,6 +596,10 @@ pub struct MatchResult {
     pub details: Option<MatchResultRaw>,
     pub score: Score,
     pub friendly: bool,
+    /// Crowd at the home ground. Zero for friendlies and fixtures the
+    /// attendance model doesn't cover.
+    #[serde(default)]
+    pub attendance: u32,
 }
 
 impl MatchResult {
@@ -590,6 +617,7 @@ impl MatchResult {
             },
             score: self.score.clone(),
             friendly: self.friendly,
+            attendance: self.attendance,
         }
     }
 }
@@ -605,6 +633,7 @@ impl From<&LeagueMatch> for MatchResult {
             score: Score::new(m.home_team_id, m.away_team_id),
             details: None,
             friendly: false,
+            attendance: 0,
         }
     }
 }
@@ -1,4 +1,4 @@
-use crate::r#match::StateProcessingContext;
+use crate::r#match::{DecisionFeatures, StateProcessingContext, TrainingData};
 use nalgebra::Vector3;
 
 #[derive(Debug, Clone)]
@@ -8,6 +8,8 @@ pub struct PassingEventContext {
     pub pass_target: Vector3<f32>,
     pub pass_force: f32,
     pub reason: &'static str,
+    /// Set while training data is recorded (see `TrainingData`).
+    pub features: Option<Box<DecisionFeatures>>,
 }
 
 impl PassingEventContext {
@@ -61,15 +63,18 @@ impl PassingEventBuilder {
 
     pub fn build(self, ctx: &StateProcessingContext) -> PassingEventContext {
         let to_player_id = self.to_player_id.unwrap();
+        let pass_target = ctx.tick_context.positions.players.position(to_player_id);
+        let pass_force = self
+            .pass_force
+            .unwrap_or_else(|| ctx.player().pass_teammate_power(to_player_id));
 
         PassingEventContext {
             from_player_id: self.from_player_id.unwrap(),
             to_player_id,
-            pass_target: ctx.tick_context.positions.players.position(to_player_id),
-            pass_force: self
-                .pass_force
-                .unwrap_or_else(|| ctx.player().pass_teammate_power(to_player_id)),
+            pass_target,
+            pass_force,
             reason: self.reason.unwrap_or("No reason specified"),
+            features: TrainingData::capture(ctx, pass_target, pass_force),
         }
     }
 }
@@ -1,5 +1,5 @@
-use crate::r#match::StateProcessingContext;
 use crate::r#match::player::strategies::players::ShotType;
+use crate::r#match::{DecisionFeatures, StateProcessingContext, TrainingData};
 use nalgebra::Vector3;
 
 #[derive(Debug, Clone)]
@@ -10,6 +10,8 @@ pub struct ShootingEventContext {
     pub reason: &'static str,
     pub tick: u64,
     pub shot_type: ShotType,
+    /// Set while training data is recorded (see `TrainingData`).
+    pub features: Option<Box<DecisionFeatures>>,
 }
 
 impl ShootingEventContext {
@@ -62,13 +64,17 @@ impl ShootingEventBuilder {
     }
 
     pub fn build(self, ctx: &StateProcessingContext) -> ShootingEventContext {
+        let target = self.target.unwrap();
+        let force = ctx.player().shoot_goal_power();
+
         ShootingEventContext {
             from_player_id: self.from_player_id.unwrap(),
-            target: self.target.unwrap(),
-            force: ctx.player().shoot_goal_power(),
+            target,
+            force,
             reason: self.reason.unwrap_or("No reason specified"),
             tick: ctx.current_tick(),
             shot_type: self.shot_type.unwrap_or(ShotType::FootOpenPlay),
+            features: TrainingData::capture(ctx, target, force as f32),
         }
     }
 }
@@ -161,7 +161,8 @@ pub struct MatchPlayer {
     /// `RefereeProfile::home_bias`. Flows through every skill-mediated
     /// action via `effective_skill`, so it shifts duels, passing,
     /// saves and finishing continuously instead of dialling any single
-    /// outcome.
+    /// outcome. The same stamp also carries the small pre-match edge from
+    /// the side's opposition report (`MatchSquad::opposition_report`).
     pub crowd_arousal: f32,
 
     /// Memo for `skills.max_speed_with_condition(condition)` keyed on
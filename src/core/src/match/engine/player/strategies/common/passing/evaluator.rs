@@ -7,7 +7,8 @@ use crate::r#match::engine::psychology::Psychology;
 use crate::r#match::player::strategies::players::ops::skill_composites as sc;
 use crate::r#match::player::strategies::players::skills::SkillCurve;
 use crate::r#match::{
-    BallSideZone, GamePhase, MatchPlayer, MatchPlayerLite, PlayerSide, StateProcessingContext,
+    BallSideZone, DecisionFeatures, GamePhase, InferenceModel, InferenceTelemetry, MatchPlayer,
+    MatchPlayerLite, PassSuccessModel, PlayerSide, StateProcessingContext,
 };
 
 /// Comprehensive pass evaluation result
@@ -115,11 +116,24 @@ impl PassEvaluator {
             .get(passer.id, receiver.id)
             .map(|chem| chemistry_modifiers(chem).one_touch_pass_bonus)
             .unwrap_or(0.0);
-        let success_probability =
+        let mut success_probability =
             (raw_success_probability + env_delta + psych_delta + chemistry_delta).clamp(0.1, 0.99);
 
         // Calculate risk level (inverse of some success factors)
-        let risk_level = Self::calculate_risk_level(&factors);
+        let mut risk_level = Self::calculate_risk_level(&factors);
+
+        // Learned risk term: with a pass model installed, its completion
+        // prediction for this exact pass pulls both numbers toward what
+        // simulated matches actually produced. Features come from the
+        // same extractor the training data was written with.
+        if let Some(model) = PassSuccessModel::active() {
+            let force = ctx.player().pass_teammate_power(receiver.id);
+            let learned = InferenceTelemetry::time(InferenceModel::PassSuccess, || {
+                model.predict(&DecisionFeatures::extract(ctx, receiver.position, force))
+            });
+            success_probability = model.blend(success_probability, learned).clamp(0.1, 0.99);
+            risk_level = model.blend(risk_level, 1.0 - learned).clamp(0.0, 1.0);
+        }
 
         // Calculate expected value considering success probability and tactical value
         let expected_value = success_probability * tactical_value;
@@ -430,18 +430,13 @@ impl<'p> PlayerOperationsImpl<'p> {
     pub fn has_clear_pass(&self, player_id: u32) -> bool {
         let player_position = self.ctx.player.position;
         let target_player_position = self.ctx.tick_context.positions.players.position(player_id);
-        let direction_to_player = (target_player_position - player_position).normalize();
-
         let distance_to_player = self.distance_to_player(player_id);
 
-        let ray_cast_result = self.ctx.tick_context.space.cast_ray(
+        self.ctx.tick_context.space.is_lane_clear(
             player_position,
-            direction_to_player,
+            target_player_position,
             distance_to_player,
-            false,
-        );
-
-        ray_cast_result.is_none()
+        )
     }
 
     /// Can this player physically strike the ball right now? Gated by
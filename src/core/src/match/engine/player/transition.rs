@@ -29,6 +29,8 @@ pub enum TransitionSource {
     /// A set-piece teleport forced a state (the corner centre-back
     /// push-up that lands directly in `AttackingCorner`).
     SetPiece,
+    /// A scenario edit on a paused live match (`FootballEngine::edit`).
+    Scenario,
 }
 
 impl TransitionSource {
@@ -41,6 +43,7 @@ impl TransitionSource {
             TransitionSource::Reset => "reset",
             TransitionSource::Substitution => "substitution",
             TransitionSource::SetPiece => "set_piece",
+            TransitionSource::Scenario => "scenario",
         }
     }
 
@@ -54,6 +57,7 @@ impl TransitionSource {
             TransitionSource::Reset => "gray60",
             TransitionSource::Substitution => "green4",
             TransitionSource::SetPiece => "orange3",
+            TransitionSource::Scenario => "purple",
         }
     }
 }
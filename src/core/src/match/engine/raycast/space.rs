@@ -109,6 +109,13 @@ impl Space {
 
         closest_hit
     }
+
+    /// Lane check behind `has_clear_pass`: nothing but players lies
+    /// within `distance` along the line from `from` towards `to`.
+    pub fn is_lane_clear(&self, from: Vector3<f32>, to: Vector3<f32>, distance: f32) -> bool {
+        let direction = (to - from).normalize();
+        self.cast_ray(from, direction, distance, false).is_none()
+    }
 }
 
 pub struct RaycastHit<T: Collider> {
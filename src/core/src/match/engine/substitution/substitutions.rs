@@ -1503,6 +1503,8 @@ mod tests {
             free_kick_taker_id: None,
             selection_omissions: vec![],
             coach_snapshot: None,
+            opposition_report: 0.0,
+            cohesion: None,
         }
     }
 
@@ -280,6 +280,25 @@ impl ChemistryMap {
             }
         }
     }
+
+    /// Shift already-seeded pairs by how often the two have started
+    /// together recently — `(player_a, player_b, shared_starts)`. Pairs
+    /// that were never seeded (e.g. a bench player) are left alone.
+    pub fn apply_shared_starts(&mut self, pairs: &[(u32, u32, u8)]) {
+        for &(a, b, starts) in pairs {
+            if let Some(score) = self.get(a, b) {
+                self.set(a, b, score + shared_starts_adjustment(starts));
+            }
+        }
+    }
+}
+
+/// Chemistry shift from recent shared starts. A pair that has never
+/// started together takes the same -0.05 as a new arrival in
+/// `initial_chemistry`; it climbs past neutral after a handful of games
+/// and levels off near +0.12 for an established partnership.
+pub fn shared_starts_adjustment(starts: u8) -> f32 {
+    -0.05 + 0.17 * (1.0 - (-(starts as f32) / 8.0).exp())
 }
 
 /// Tactical familiarity for a team (0..1). Default 0.65 — recent tactic
@@ -317,6 +336,14 @@ impl TacticalFamiliarity {
     pub fn build_up_patience_bonus(&self) -> f32 {
         self.score.clamp(0.0, 1.0) * 0.06
     }
+
+    /// Press intensity multiplier for how well the side's pressing
+    /// triggers are synchronised. 1.0 at the default familiarity; a
+    /// fully drilled side presses ~10% harder, a side still learning
+    /// its shape up to ~20% softer.
+    pub fn press_sync_multiplier(&self) -> f32 {
+        1.0 + (self.score.clamp(0.0, 1.0) - TacticalFamiliarity::default().score) * 0.3
+    }
 }
 
 /// Modifiers a chemistry score applies to specific pair-level events.
@@ -509,4 +536,22 @@ mod tests {
         assert!(strong.offside_trap_risk() < weak.offside_trap_risk());
         assert!(strong.build_up_patience_bonus() > weak.build_up_patience_bonus());
     }
+
+    #[test]
+    fn tactical_familiarity_scales_press_around_default() {
+        assert_eq!(TacticalFamiliarity::default().press_sync_multiplier(), 1.0);
+        assert!(TacticalFamiliarity { score: 0.95 }.press_sync_multiplier() > 1.0);
+        assert!(TacticalFamiliarity { score: 0.30 }.press_sync_multiplier() < 1.0);
+    }
+
+    #[test]
+    fn shared_starts_warm_seeded_pairs_and_chill_strangers() {
+        let mut m = ChemistryMap::default();
+        m.set(1, 2, 0.60);
+        m.set(1, 3, 0.60);
+        m.apply_shared_starts(&[(1, 2, 20), (1, 3, 0), (1, 99, 20)]);
+        assert!(m.get(1, 2).unwrap() > 0.65);
+        assert!(m.get(1, 3).unwrap() < 0.60);
+        assert_eq!(m.get(1, 99), None);
+    }
 }
@@ -15,6 +15,7 @@
 //! makes the calculator boundary obvious from a `cargo doc` view.
 
 use crate::Tactics;
+use crate::r#match::engine::chemistry::TacticalFamiliarity;
 use crate::r#match::{MatchField, PlayerSide};
 
 /// The team's high-level game phase. Recomputed from ball position,
@@ -190,6 +191,10 @@ pub struct TacticalRefreshInputs<'a> {
     /// the play-quality half of home advantage (the referee
     /// marginal-call half lives in `RefereeProfile::home_bias`).
     pub home_edge: f32,
+    /// Per-team tactical familiarity — scales how well the press is
+    /// synchronised (see `TacticalFamiliarity::press_sync_multiplier`).
+    pub home_familiarity: TacticalFamiliarity,
+    pub away_familiarity: TacticalFamiliarity,
 }
 
 /// Team-level tactical context, shared across all eleven players. Cheap
@@ -574,6 +579,14 @@ impl TeamTacticalState {
             Self::press_skill_adjustment(home.press_intensity, inputs.home_skills.press_quality);
         away.press_intensity =
             Self::press_skill_adjustment(away.press_intensity, inputs.away_skills.press_quality);
+        // Pressing as a unit needs drilled triggers: a settled side in
+        // its usual shape closes together, a reshuffled one leaves gaps.
+        home.press_intensity = (home.press_intensity
+            * inputs.home_familiarity.press_sync_multiplier())
+        .clamp(0.0, 1.0);
+        away.press_intensity = (away.press_intensity
+            * inputs.away_familiarity.press_sync_multiplier())
+        .clamp(0.0, 1.0);
 
         home.compactness_target =
             Self::compute_compactness(home_compact, home.phase, home.game_management_intensity);
@@ -0,0 +1,210 @@
+//! Decision features — a fixed vector describing what a player saw when
+//! choosing a pass or a shot, taken from their `StateProcessingContext`.
+//!
+//! Training-data export (`TrainingData`) writes exactly this vector, in
+//! `DecisionFeatures::NAMES` order, and anything scoring a decision at
+//! runtime should read it through `to_array`, so a model never sees
+//! features computed differently from the ones it was trained on. Add a
+//! feature by adding a field, its name and its slot in `to_array`
+//! together; `FEATURE_COUNT` keeps the three in step.
+
+use crate::r#match::StateProcessingContext;
+use nalgebra::Vector3;
+
+pub const FEATURE_COUNT: usize = 19;
+
+/// Distances are in field units, skills on the 1–20 scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DecisionFeatures {
+    /// Match minute, fractional.
+    pub minute: f32,
+    /// Own goals minus the opponent's.
+    pub score_diff: f32,
+    /// From the player to the centre of the opponent goal.
+    pub goal_distance: f32,
+    /// Angle between the line to the opponent goal's centre and the
+    /// pitch's long axis, in radians — 0 straight in front of goal.
+    pub goal_angle: f32,
+    /// Capped at `OPPONENT_SCAN` when nobody is close.
+    pub nearest_opponent: f32,
+    pub opponents_within_10: f32,
+    pub teammates_within_20: f32,
+    pub passing: f32,
+    pub vision: f32,
+    pub technique: f32,
+    pub finishing: f32,
+    pub long_shots: f32,
+    pub composure: f32,
+    pub decisions: f32,
+    /// 0.0–1.0.
+    pub condition: f32,
+    /// From the player to the pass or shot target.
+    pub target_distance: f32,
+    /// How much closer to the opponent goal the target is.
+    pub target_progress: f32,
+    /// Opponents within 5 units of the target.
+    pub target_pressure: f32,
+    /// Pass or shot power as the engine computes it.
+    pub force: f32,
+}
+
+/// Radius searched for `nearest_opponent`.
+const OPPONENT_SCAN: f32 = 50.0;
+
+impl DecisionFeatures {
+    pub const NAMES: [&'static str; FEATURE_COUNT] = [
+        "minute",
+        "score_diff",
+        "goal_distance",
+        "goal_angle",
+        "nearest_opponent",
+        "opponents_within_10",
+        "teammates_within_20",
+        "passing",
+        "vision",
+        "technique",
+        "finishing",
+        "long_shots",
+        "composure",
+        "decisions",
+        "condition",
+        "target_distance",
+        "target_progress",
+        "target_pressure",
+        "force",
+    ];
+
+    /// Features of the player in `ctx` playing the ball to `target` with
+    /// `force`.
+    pub fn extract(ctx: &StateProcessingContext, target: Vector3<f32>, force: f32) -> Self {
+        let player = ctx.player;
+        let goal = ctx.player().goal_position();
+        let to_goal = goal - player.position;
+        let goal_distance = to_goal.magnitude();
+        let players = ctx.players();
+        let opponents = players.opponents();
+        let nearest_opponent = opponents
+            .nearby_raw(OPPONENT_SCAN)
+            .map(|(_, distance)| distance)
+            .fold(OPPONENT_SCAN, f32::min);
+
+        let home = ctx.context.score.home_team.get() as f32;
+        let away = ctx.context.score.away_team.get() as f32;
+        let score_diff = if player.team_id == ctx.context.field_home_team_id {
+            home - away
+        } else {
+            away - home
+        };
+
+        let skills = &player.skills;
+        DecisionFeatures {
+            minute: ctx.context.total_match_time as f32 / 60_000.0,
+            score_diff,
+            goal_distance,
+            goal_angle: to_goal.y.abs().atan2(to_goal.x.abs()),
+            nearest_opponent,
+            opponents_within_10: opponents.nearby_raw(10.0).count() as f32,
+            teammates_within_20: players.teammates().nearby_ids(20.0).count() as f32,
+            passing: skills.technical.passing,
+            vision: skills.mental.vision,
+            technique: skills.technical.technique,
+            finishing: skills.technical.finishing,
+            long_shots: skills.technical.long_shots,
+            composure: skills.mental.composure,
+            decisions: skills.mental.decisions,
+            condition: player.player_attributes.condition as f32 / 10_000.0,
+            target_distance: (target - player.position).magnitude(),
+            target_progress: goal_distance - (goal - target).magnitude(),
+            target_pressure: opponents.nearby_at(target, 5.0).count() as f32,
+            force,
+        }
+    }
+
+    /// The vector in `NAMES` order.
+    pub fn to_array(&self) -> [f32; FEATURE_COUNT] {
+        [
+            self.minute,
+            self.score_diff,
+            self.goal_distance,
+            self.goal_angle,
+            self.nearest_opponent,
+            self.opponents_within_10,
+            self.teammates_within_20,
+            self.passing,
+            self.vision,
+            self.technique,
+            self.finishing,
+            self.long_shots,
+            self.composure,
+            self.decisions,
+            self.condition,
+            self.target_distance,
+            self.target_progress,
+            self.target_pressure,
+            self.force,
+        ]
+    }
+
+    /// Inverse of `to_array`, for vectors read back from a dataset.
+    pub fn from_array(values: [f32; FEATURE_COUNT]) -> Self {
+        let [
+            minute,
+            score_diff,
+            goal_distance,
+            goal_angle,
+            nearest_opponent,
+            opponents_within_10,
+            teammates_within_20,
+            passing,
+            vision,
+            technique,
+            finishing,
+            long_shots,
+            composure,
+            decisions,
+            condition,
+            target_distance,
+            target_progress,
+            target_pressure,
+            force,
+        ] = values;
+        DecisionFeatures {
+            minute,
+            score_diff,
+            goal_distance,
+            goal_angle,
+            nearest_opponent,
+            opponents_within_10,
+            teammates_within_20,
+            passing,
+            vision,
+            technique,
+            finishing,
+            long_shots,
+            composure,
+            decisions,
+            condition,
+            target_distance,
+            target_progress,
+            target_pressure,
+            force,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn array_slots_follow_the_names() {
+        let values: [f32; FEATURE_COUNT] = std::array::from_fn(|i| i as f32);
+        let features = DecisionFeatures::from_array(values);
+
+        assert_eq!(features.to_array(), values);
+        let slot = |name: &str| DecisionFeatures::NAMES.iter().position(|n| *n == name);
+        assert_eq!(slot("goal_angle"), Some(features.goal_angle as usize));
+        assert_eq!(slot("condition"), Some(features.condition as usize));
+        assert_eq!(slot("force"), Some(features.force as usize));
+    }
+}
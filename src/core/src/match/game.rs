@@ -1,4 +1,4 @@
-use super::engine::FootballEngine;
+use super::engine::{FootballEngine, MatchEngineConfig, MatchEnvironment};
 use crate::MatchRuntime;
 use crate::r#match::{MatchResult, MatchSquad};
 use log::debug;
@@ -14,6 +14,9 @@ pub struct Match {
     /// Knockout-format match — if level after 90 min, play extra time;
     /// if still level, resolve on penalties.
     pub is_knockout: bool,
+    /// The clubs are rivals — the engine plays it with derby intensity
+    /// (more fouls and cards, a louder crowd).
+    pub is_derby: bool,
 }
 
 impl Match {
@@ -33,6 +36,7 @@ impl Match {
             away_squad,
             is_friendly,
             is_knockout: false,
+            is_derby: false,
         }
     }
 
@@ -51,6 +55,7 @@ impl Match {
             away_squad,
             is_friendly: false,
             is_knockout: true,
+            is_derby: false,
         }
     }
 
@@ -79,13 +84,19 @@ impl Match {
         let away_team_name = String::from(&self.away_squad.team_name);
 
         let match_recordings = MatchRuntime::recordings_mode() && !self.is_friendly;
-        let match_result = FootballEngine::<840, 545>::play(
-            self.home_squad,
-            self.away_squad,
+        let config = MatchEngineConfig {
+            environment: if self.is_derby {
+                MatchEnvironment::derby()
+            } else {
+                MatchEnvironment::default()
+            },
+            is_friendly: self.is_friendly,
+            is_knockout: self.is_knockout,
             match_recordings,
-            self.is_friendly,
-            self.is_knockout,
-        );
+            ..Default::default()
+        };
+        let match_result =
+            FootballEngine::<840, 545>::play_with_config(self.home_squad, self.away_squad, config);
 
         let score = match_result.score.as_ref().expect("no score");
 
@@ -118,6 +129,7 @@ impl Match {
             score: score.clone(),
             details: Some(match_result),
             friendly: self.is_friendly,
+            attendance: 0,
         }
     }
 }
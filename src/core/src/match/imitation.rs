@@ -0,0 +1,394 @@
+//! Imitation data — state-action pairs read back from recorded match
+//! replays, and a behaviour-cloning model fitted on them.
+//!
+//! A replay (the `position_data` the match store writes) holds every
+//! player's and the ball's position track and, for matches played with
+//! event recording on, each player's state changes. Every
+//! `SAMPLE_INTERVAL_MS` a player on the pitch yields one pair: what they
+//! could see (`REPLAY_FEATURE_NAMES`) against what they did — the state
+//! they were in and the velocity they moved off with.
+//!
+//! `StateCloningModel` is a softmax regression from those features to
+//! the state, the simplest model that reproduces the current AI's state
+//! choices; the velocity is kept in the pairs for regressors trained
+//! elsewhere.
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+pub const REPLAY_FEATURE_COUNT: usize = 9;
+
+pub const REPLAY_FEATURE_NAMES: [&str; REPLAY_FEATURE_COUNT] = [
+    "minute", "x", "y", "speed", "ball_x", "ball_y", "ball_z", "ball_dx", "ball_dy",
+];
+
+/// One pair per player per this much match time.
+const SAMPLE_INTERVAL_MS: u64 = 1_000;
+/// A longer gap between two samples means the player was off the pitch
+/// (see the heartbeat in `ResultMatchPositionData`).
+const MAX_SAMPLE_GAP_MS: u64 = 1_000;
+
+const EPOCHS: usize = 30;
+const BATCH: usize = 256;
+const LEARNING_RATE: f32 = 0.2;
+
+#[derive(Debug, Clone)]
+pub struct StateActionPair {
+    pub player_id: u32,
+    pub timestamp: u64,
+    pub features: [f32; REPLAY_FEATURE_COUNT],
+    pub state: String,
+    /// Field units per second, from this sample to the next.
+    pub velocity: (f32, f32),
+}
+
+/// (timestamp, x, y, z) of a recorded track.
+type Track = Vec<(u64, f32, f32, f32)>;
+
+fn track(value: &Value) -> Track {
+    let Some(items) = value.as_array() else {
+        return Vec::new();
+    };
+    items
+        .iter()
+        .filter_map(|item| {
+            let item = item.as_array()?;
+            let number = |i: usize| item.get(i).and_then(Value::as_f64);
+            Some((
+                number(0)? as u64,
+                number(1)? as f32,
+                number(2)? as f32,
+                number(3).unwrap_or(0.0) as f32,
+            ))
+        })
+        .collect()
+}
+
+/// The last entry of `items` at or before `timestamp`.
+fn at<T>(items: &[T], timestamp: u64, ts: impl Fn(&T) -> u64) -> Option<&T> {
+    let after = items.partition_point(|item| ts(item) <= timestamp);
+    after.checked_sub(1).map(|idx| &items[idx])
+}
+
+/// Every state-action pair of one replay. Fails when the replay was
+/// recorded without player states.
+pub fn state_actions(replay: &Value) -> Result<Vec<StateActionPair>, String> {
+    let states = replay
+        .get("states")
+        .and_then(Value::as_object)
+        .filter(|states| !states.is_empty())
+        .ok_or("the replay has no player states; record matches with --match-events")?;
+    let ball = track(&replay["ball"]);
+    let players = replay
+        .get("players")
+        .and_then(Value::as_object)
+        .ok_or("the replay has no player tracks")?;
+
+    let mut pairs = Vec::new();
+    for (id, positions) in players {
+        let Ok(player_id) = id.parse::<u32>() else {
+            continue;
+        };
+        let changes: Vec<(u64, &str)> = states
+            .get(id)
+            .and_then(Value::as_array)
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| Some((entry.get(0)?.as_u64()?, entry.get(1)?.as_str()?)))
+            .collect();
+        let positions = track(positions);
+
+        let mut next_sample = 0;
+        for window in positions.windows(3) {
+            let [(t0, x0, y0, _), (t, x, y, _), (t1, x1, y1, _)] =
+                [window[0], window[1], window[2]];
+            let gaps_ok = t - t0 <= MAX_SAMPLE_GAP_MS && t1 - t <= MAX_SAMPLE_GAP_MS;
+            if t < next_sample || !gaps_ok || t1 == t || t == t0 {
+                continue;
+            }
+            let (Some(&(_, state)), Some(&(_, bx, by, bz))) =
+                (at(&changes, t, |c| c.0), at(&ball, t, |b| b.0))
+            else {
+                continue;
+            };
+            next_sample = t + SAMPLE_INTERVAL_MS;
+
+            let per_second = |from: u64, to: u64| 1_000.0 / (to - from) as f32;
+            let speed = ((x - x0).powi(2) + (y - y0).powi(2)).sqrt() * per_second(t0, t);
+            pairs.push(StateActionPair {
+                player_id,
+                timestamp: t,
+                features: [t as f32 / 60_000.0, x, y, speed, bx, by, bz, bx - x, by - y],
+                state: state.to_string(),
+                velocity: ((x1 - x) * per_second(t, t1), (y1 - y) * per_second(t, t1)),
+            });
+        }
+    }
+    pairs.sort_by_key(|pair| (pair.timestamp, pair.player_id));
+    Ok(pairs)
+}
+
+/// Write `pairs` as CSV, one row per pair.
+pub fn write_state_actions(path: &Path, pairs: &[StateActionPair]) -> io::Result<()> {
+    let mut out = String::new();
+    out.push_str("player_id,timestamp,");
+    out.push_str(&REPLAY_FEATURE_NAMES.join(","));
+    out.push_str(",state,velocity_x,velocity_y\n");
+    for pair in pairs {
+        let features: Vec<String> = pair.features.iter().map(f32::to_string).collect();
+        out.push_str(&format!(
+            "{},{},{},\"{}\",{},{}\n",
+            pair.player_id,
+            pair.timestamp,
+            features.join(","),
+            pair.state.replace('"', "\"\""),
+            pair.velocity.0,
+            pair.velocity.1
+        ));
+    }
+    fs::write(path, out)
+}
+
+/// The role part of a recorded state name — `Goalkeeper` for
+/// `Goalkeeper: Standing`.
+pub fn state_role(state: &str) -> &str {
+    state.split_once(": ").map_or("", |(role, _)| role)
+}
+
+/// Indices of the states in `states` that belong to `role`.
+fn role_states(states: &[String], role: &str) -> Vec<usize> {
+    (0..states.len())
+        .filter(|&k| state_role(&states[k]) == role)
+        .collect()
+}
+
+/// Softmax regression from replay features to the state a player is in,
+/// choosing only among the states of the player's role (the engine never
+/// puts a goalkeeper in a forward state).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StateCloningModel {
+    pub features: Vec<String>,
+    pub states: Vec<String>,
+    pub means: Vec<f32>,
+    pub scales: Vec<f32>,
+    /// One row of `features.len()` weights per state.
+    pub weights: Vec<Vec<f32>>,
+    pub bias: Vec<f32>,
+}
+
+/// How well a model reproduces a set of pairs.
+#[derive(Debug, Clone, Copy)]
+pub struct CloningFit {
+    pub samples: usize,
+    pub states: usize,
+    /// Share of pairs whose state the model predicts.
+    pub accuracy: f32,
+    /// What always guessing the most common state of each role scores.
+    pub baseline: f32,
+}
+
+impl StateCloningModel {
+    /// Fit on `pairs` by mini-batch gradient descent on standardized
+    /// features, visiting the pairs in a fixed order.
+    pub fn train(pairs: &[StateActionPair]) -> Self {
+        let mut states: Vec<String> = pairs.iter().map(|p| p.state.clone()).collect();
+        states.sort();
+        states.dedup();
+        let index: HashMap<&str, usize> = states
+            .iter()
+            .enumerate()
+            .map(|(i, s)| (s.as_str(), i))
+            .collect();
+        let labels: Vec<usize> = pairs.iter().map(|p| index[p.state.as_str()]).collect();
+        // Per state, the states of its role.
+        let rivals: Vec<Vec<usize>> = states
+            .iter()
+            .map(|state| role_states(&states, state_role(state)))
+            .collect();
+
+        let n = pairs.len().max(1) as f32;
+        let mut means = [0.0f32; REPLAY_FEATURE_COUNT];
+        for pair in pairs {
+            for (mean, value) in means.iter_mut().zip(&pair.features) {
+                *mean += value / n;
+            }
+        }
+        let mut scales = [0.0f32; REPLAY_FEATURE_COUNT];
+        for pair in pairs {
+            for i in 0..REPLAY_FEATURE_COUNT {
+                scales[i] += (pair.features[i] - means[i]).powi(2) / n;
+            }
+        }
+        for scale in scales.iter_mut() {
+            *scale = if *scale > 1e-12 { scale.sqrt() } else { 1.0 };
+        }
+
+        let mut model = StateCloningModel {
+            features: REPLAY_FEATURE_NAMES.iter().map(|s| s.to_string()).collect(),
+            means: means.to_vec(),
+            scales: scales.to_vec(),
+            weights: vec![vec![0.0; REPLAY_FEATURE_COUNT]; states.len()],
+            bias: vec![0.0; states.len()],
+            states,
+        };
+        let inputs: Vec<[f32; REPLAY_FEATURE_COUNT]> = pairs
+            .iter()
+            .map(|p| model.standardize(&p.features))
+            .collect();
+
+        for _ in 0..EPOCHS {
+            for batch in (0..inputs.len()).collect::<Vec<_>>().chunks(BATCH) {
+                let mut grad = vec![[0.0f32; REPLAY_FEATURE_COUNT]; model.states.len()];
+                let mut grad_bias = vec![0.0f32; model.states.len()];
+                for &i in batch {
+                    for (k, p) in model.probabilities(&inputs[i], &rivals[labels[i]]) {
+                        let error = p - f32::from(u8::from(k == labels[i]));
+                        for (g, x) in grad[k].iter_mut().zip(&inputs[i]) {
+                            *g += error * x;
+                        }
+                        grad_bias[k] += error;
+                    }
+                }
+                let step = LEARNING_RATE / batch.len() as f32;
+                for k in 0..model.states.len() {
+                    for (w, g) in model.weights[k].iter_mut().zip(&grad[k]) {
+                        *w -= step * g;
+                    }
+                    model.bias[k] -= step * grad_bias[k];
+                }
+            }
+        }
+        model
+    }
+
+    fn standardize(&self, features: &[f32; REPLAY_FEATURE_COUNT]) -> [f32; REPLAY_FEATURE_COUNT] {
+        std::array::from_fn(|i| (features[i] - self.means[i]) / self.scales[i])
+    }
+
+    /// (state index, probability) over `candidates`.
+    fn probabilities(
+        &self,
+        x: &[f32; REPLAY_FEATURE_COUNT],
+        candidates: &[usize],
+    ) -> Vec<(usize, f32)> {
+        let logits: Vec<f32> = candidates
+            .iter()
+            .map(|&k| {
+                let dot: f32 = self.weights[k].iter().zip(x).map(|(w, x)| w * x).sum();
+                dot + self.bias[k]
+            })
+            .collect();
+        let max = logits.iter().copied().fold(f32::MIN, f32::max);
+        let exp: Vec<f32> = logits.iter().map(|z| (z - max).exp()).collect();
+        let total: f32 = exp.iter().sum();
+        candidates
+            .iter()
+            .zip(exp)
+            .map(|(&k, e)| (k, e / total))
+            .collect()
+    }
+
+    /// The state the model expects a player of `role` with these features
+    /// to be in; `None` for a role it never saw.
+    pub fn predict(&self, role: &str, features: &[f32; REPLAY_FEATURE_COUNT]) -> Option<&str> {
+        let candidates = role_states(&self.states, role);
+        let (best, _) = self
+            .probabilities(&self.standardize(features), &candidates)
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))?;
+        Some(self.states[best].as_str())
+    }
+
+    pub fn fit(&self, pairs: &[StateActionPair]) -> CloningFit {
+        let n = pairs.len().max(1) as f32;
+        let right = pairs
+            .iter()
+            .filter(|p| self.predict(state_role(&p.state), &p.features) == Some(p.state.as_str()))
+            .count();
+        let mut counts: HashMap<&str, usize> = HashMap::new();
+        for pair in pairs {
+            *counts.entry(pair.state.as_str()).or_default() += 1;
+        }
+        let mut most_common: HashMap<&str, usize> = HashMap::new();
+        for (state, count) in &counts {
+            let best = most_common.entry(state_role(state)).or_default();
+            *best = (*best).max(*count);
+        }
+        CloningFit {
+            samples: pairs.len(),
+            states: counts.len(),
+            accuracy: right as f32 / n,
+            baseline: most_common.values().sum::<usize>() as f32 / n,
+        }
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// Player 1 chases a ball to their right until it rolls past at
+    /// t=10 s, then turns back: running before, returning after.
+    fn replay() -> Value {
+        let positions: Vec<Value> = (0..=40)
+            .map(|i| {
+                let t = i * 500;
+                let x = if t <= 10_000 {
+                    100.0 + i as f32 * 2.0
+                } else {
+                    140.0 - (i - 20) as f32 * 2.0
+                };
+                json!([t, x, 50.0])
+            })
+            .collect();
+        json!({
+            "ball": [[0, 160.0, 50.0], [10_000, 60.0, 50.0, 1.5]],
+            "players": { "1": positions, "2": [[0, 10.0, 10.0]] },
+            "states": { "1": [[0, "Forward: Running"], [10_000, "Forward: Returning"]] }
+        })
+    }
+
+    #[test]
+    fn replays_become_pairs_of_what_players_saw_and_did() {
+        let pairs = state_actions(&replay()).unwrap();
+
+        // One per second from t=0.5 s (the first sample with a
+        // predecessor) to t=19.5 s, and none for the player without states.
+        assert_eq!(pairs.len(), 20);
+        assert!(pairs.iter().all(|p| p.player_id == 1));
+        let first = &pairs[0];
+        assert_eq!(
+            (first.timestamp, first.state.as_str()),
+            (500, "Forward: Running")
+        );
+        assert_eq!(first.features[7], 160.0 - 102.0);
+        assert_eq!(first.velocity, (4.0, 0.0));
+        let late = pairs.iter().find(|p| p.timestamp == 12_500).unwrap();
+        assert_eq!(late.state, "Forward: Returning");
+        assert_eq!(late.features[6], 1.5);
+        assert_eq!(late.velocity, (-4.0, 0.0));
+
+        assert!(state_actions(&json!({ "players": {}, "ball": [] })).is_err());
+    }
+
+    #[test]
+    fn cloning_model_reproduces_the_recorded_states() {
+        let pairs = state_actions(&replay()).unwrap();
+        let model = StateCloningModel::train(&pairs);
+        let fit = model.fit(&pairs);
+
+        assert_eq!(fit.states, 2);
+        assert!(fit.accuracy > 0.9, "{fit:?}");
+        assert_eq!(model.predict("Goalkeeper", &pairs[0].features), None);
+    }
+}
@@ -0,0 +1,205 @@
+//! Inference telemetry — how long each learned model takes per call and
+//! how many calls one engine tick makes, so a model that grows too
+//! expensive for the tick budget shows up on `/metrics` straight away.
+//!
+//! Models wrap their runtime calls in `InferenceTelemetry::time`. Calls
+//! are counted per thread — a match plays on a single thread — and
+//! `period_tick` flushes the count into the calls-per-tick histogram at
+//! the start of each tick. Nothing is measured until a model is
+//! installed, so matches without one pay a single atomic load per tick.
+
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::time::Instant;
+
+/// Upper bounds of the per-call latency buckets, in seconds.
+pub const INFERENCE_LATENCY_BUCKETS: &[f64] =
+    &[1e-6, 2.5e-6, 5e-6, 1e-5, 2.5e-5, 5e-5, 1e-4, 2.5e-4, 1e-3];
+/// Upper bounds of the calls-per-tick buckets.
+pub const INFERENCE_CALLS_BUCKETS: &[f64] = &[0.0, 1.0, 2.0, 5.0, 10.0, 20.0, 50.0, 100.0];
+
+const LATENCY_SLOTS: usize = INFERENCE_LATENCY_BUCKETS.len();
+const CALLS_SLOTS: usize = INFERENCE_CALLS_BUCKETS.len();
+
+/// A learned model the engine consults at runtime.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InferenceModel {
+    PassSuccess,
+}
+
+impl InferenceModel {
+    pub const ALL: [InferenceModel; 1] = [InferenceModel::PassSuccess];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            InferenceModel::PassSuccess => "pass_success",
+        }
+    }
+
+    fn slot(self) -> usize {
+        self as usize
+    }
+}
+
+/// Lock-free histogram with fixed bounds; values above the last bound
+/// only count towards `count` and `sum`.
+struct AtomicHistogram<const N: usize> {
+    bounds: &'static [f64],
+    counts: [AtomicU64; N],
+    /// `f64` bits of the sum of observations.
+    sum: AtomicU64,
+    count: AtomicU64,
+}
+
+impl<const N: usize> AtomicHistogram<N> {
+    const fn new(bounds: &'static [f64]) -> Self {
+        AtomicHistogram {
+            bounds,
+            counts: [const { AtomicU64::new(0) }; N],
+            sum: AtomicU64::new(0),
+            count: AtomicU64::new(0),
+        }
+    }
+
+    fn observe(&self, value: f64) {
+        if let Some(idx) = self.bounds.iter().position(|b| value <= *b) {
+            self.counts[idx].fetch_add(1, Ordering::Relaxed);
+        }
+        let _ = self
+            .sum
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+                Some((f64::from_bits(bits) + value).to_bits())
+            });
+        self.count.fetch_add(1, Ordering::Relaxed);
+    }
+
+    fn snapshot(&self) -> HistogramSnapshot {
+        HistogramSnapshot {
+            bounds: self.bounds,
+            counts: self
+                .counts
+                .iter()
+                .map(|c| c.load(Ordering::Relaxed))
+                .collect(),
+            sum: f64::from_bits(self.sum.load(Ordering::Relaxed)),
+            count: self.count.load(Ordering::Relaxed),
+        }
+    }
+}
+
+struct ModelCounters {
+    latency: AtomicHistogram<LATENCY_SLOTS>,
+    calls_per_tick: AtomicHistogram<CALLS_SLOTS>,
+}
+
+static MODELS: [ModelCounters; 1] = [ModelCounters {
+    latency: AtomicHistogram::new(INFERENCE_LATENCY_BUCKETS),
+    calls_per_tick: AtomicHistogram::new(INFERENCE_CALLS_BUCKETS),
+}];
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    static TICK_CALLS: [Cell<u32>; 1] = const { [Cell::new(0)] };
+}
+
+/// Per-bucket counts (not cumulative), as `/metrics` needs them.
+#[derive(Debug, Clone, PartialEq)]
+pub struct HistogramSnapshot {
+    pub bounds: &'static [f64],
+    pub counts: Vec<u64>,
+    pub sum: f64,
+    pub count: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct ModelTelemetry {
+    pub model: InferenceModel,
+    /// Seconds per call.
+    pub latency: HistogramSnapshot,
+    pub calls_per_tick: HistogramSnapshot,
+}
+
+/// Process-wide inference counters.
+pub struct InferenceTelemetry;
+
+impl InferenceTelemetry {
+    /// Start measuring; called when a model is installed.
+    pub fn enable() {
+        ENABLED.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_enabled() -> bool {
+        ENABLED.load(Ordering::Relaxed)
+    }
+
+    /// Run one call of `model`, timing and counting it.
+    pub fn time<T>(model: InferenceModel, call: impl FnOnce() -> T) -> T {
+        let started = Instant::now();
+        let result = call();
+        MODELS[model.slot()]
+            .latency
+            .observe(started.elapsed().as_secs_f64());
+        TICK_CALLS.with(|calls| {
+            let calls = &calls[model.slot()];
+            calls.set(calls.get().saturating_add(1));
+        });
+        result
+    }
+
+    /// Close the tick that just ran on this thread: its call counts go
+    /// into the calls-per-tick histograms.
+    pub(crate) fn end_tick() {
+        if !Self::is_enabled() {
+            return;
+        }
+        TICK_CALLS.with(|calls| {
+            for model in InferenceModel::ALL {
+                let count = calls[model.slot()].replace(0);
+                MODELS[model.slot()].calls_per_tick.observe(count as f64);
+            }
+        });
+    }
+
+    /// Counters of every model, installed or not.
+    pub fn snapshot() -> Vec<ModelTelemetry> {
+        InferenceModel::ALL
+            .iter()
+            .map(|&model| ModelTelemetry {
+                model,
+                latency: MODELS[model.slot()].latency.snapshot(),
+                calls_per_tick: MODELS[model.slot()].calls_per_tick.snapshot(),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn timed_calls_land_in_both_histograms() {
+        let before = InferenceTelemetry::snapshot().remove(0);
+        InferenceTelemetry::enable();
+        InferenceTelemetry::end_tick();
+        for _ in 0..3 {
+            assert_eq!(
+                InferenceTelemetry::time(InferenceModel::PassSuccess, || 7),
+                7
+            );
+        }
+        InferenceTelemetry::end_tick();
+        let after = InferenceTelemetry::snapshot().remove(0);
+
+        // Other tests may make calls on their own threads concurrently.
+        assert!(after.latency.count >= before.latency.count + 3);
+        assert!(after.calls_per_tick.count > before.calls_per_tick.count);
+        let three_calls = INFERENCE_CALLS_BUCKETS
+            .iter()
+            .position(|b| *b >= 3.0)
+            .unwrap();
+        assert!(
+            after.calls_per_tick.counts[three_calls] > before.calls_per_tick.counts[three_calls]
+        );
+    }
+}
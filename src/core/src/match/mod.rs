@@ -4,9 +4,13 @@ pub mod logs;
 pub mod calibration;
 pub mod dispatch;
 pub mod engine;
+pub mod features;
 
 pub mod game;
+pub mod imitation;
+pub mod inference;
 
+pub mod pass_model;
 pub mod pool;
 
 pub mod result;
@@ -17,7 +21,11 @@ pub mod state;
 
 pub use dispatch::*;
 pub use engine::*;
+pub use features::*;
 pub use game::*;
+pub use imitation::*;
+pub use inference::*;
+pub use pass_model::*;
 pub use pool::*;
 
 pub use result::*;
@@ -0,0 +1,309 @@
+//! Learned pass-success model — a logistic regression over
+//! `DecisionFeatures`, trained on the `passes.csv` that `TrainingData`
+//! writes and consulted by `PassEvaluator` once installed.
+//!
+//! The heuristic success probability stays in charge: the model's
+//! prediction is blended in with the model file's `weight`, so a model
+//! nudges pass choice rather than replacing the hand-tuned factors. The
+//! file records the feature names it was trained on and is refused when
+//! they no longer match `DecisionFeatures::NAMES`.
+
+use crate::r#match::{DecisionFeatures, FEATURE_COUNT, InferenceTelemetry};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+/// Default share of the model's prediction in the blended probability.
+const DEFAULT_WEIGHT: f32 = 0.5;
+const EPOCHS: usize = 300;
+const LEARNING_RATE: f32 = 0.5;
+/// L2 penalty on the weights.
+const RIDGE: f32 = 1e-4;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PassSuccessModel {
+    /// Feature names in input order, as trained.
+    pub features: Vec<String>,
+    /// Per-feature standardization.
+    pub means: Vec<f32>,
+    pub scales: Vec<f32>,
+    pub weights: Vec<f32>,
+    pub bias: f32,
+    /// Share of the prediction in `PassEvaluator`'s success probability,
+    /// 0.0–1.0.
+    pub weight: f32,
+}
+
+/// How well a model fits a set of labelled passes.
+#[derive(Debug, Clone, Copy)]
+pub struct ModelFit {
+    pub samples: usize,
+    pub log_loss: f32,
+    /// Share of passes whose outcome the model calls right at 0.5.
+    pub accuracy: f32,
+    /// Share of completed passes in the data.
+    pub base_rate: f32,
+}
+
+static PASS_MODEL: OnceLock<PassSuccessModel> = OnceLock::new();
+
+impl PassSuccessModel {
+    /// Fit on `samples` of (features, completed) by full-batch gradient
+    /// descent on standardized features.
+    pub fn train(samples: &[(DecisionFeatures, bool)]) -> Self {
+        let n = samples.len().max(1) as f32;
+        let rows: Vec<[f32; FEATURE_COUNT]> = samples.iter().map(|(f, _)| f.to_array()).collect();
+
+        let mut means = [0.0f32; FEATURE_COUNT];
+        for row in &rows {
+            for (mean, value) in means.iter_mut().zip(row) {
+                *mean += value / n;
+            }
+        }
+        let mut scales = [0.0f32; FEATURE_COUNT];
+        for row in &rows {
+            for i in 0..FEATURE_COUNT {
+                scales[i] += (row[i] - means[i]).powi(2) / n;
+            }
+        }
+        // A constant feature keeps scale 1 and so contributes nothing.
+        for scale in scales.iter_mut() {
+            *scale = if *scale > 1e-12 { scale.sqrt() } else { 1.0 };
+        }
+
+        let inputs: Vec<[f32; FEATURE_COUNT]> = rows
+            .iter()
+            .map(|row| std::array::from_fn(|i| (row[i] - means[i]) / scales[i]))
+            .collect();
+        let labels: Vec<f32> = samples
+            .iter()
+            .map(|(_, ok)| f32::from(u8::from(*ok)))
+            .collect();
+
+        let mut weights = [0.0f32; FEATURE_COUNT];
+        let mut bias = 0.0f32;
+        for _ in 0..EPOCHS {
+            let mut grad = [0.0f32; FEATURE_COUNT];
+            let mut grad_bias = 0.0f32;
+            for (x, y) in inputs.iter().zip(&labels) {
+                let error = sigmoid(dot(&weights, x) + bias) - y;
+                for (g, xi) in grad.iter_mut().zip(x) {
+                    *g += error * xi;
+                }
+                grad_bias += error;
+            }
+            for (w, g) in weights.iter_mut().zip(&grad) {
+                *w -= LEARNING_RATE * (g / n + RIDGE * *w);
+            }
+            bias -= LEARNING_RATE * grad_bias / n;
+        }
+
+        PassSuccessModel {
+            features: DecisionFeatures::NAMES
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
+            means: means.to_vec(),
+            scales: scales.to_vec(),
+            weights: weights.to_vec(),
+            bias,
+            weight: DEFAULT_WEIGHT,
+        }
+    }
+
+    /// Probability that a pass with these features is completed.
+    pub fn predict(&self, features: &DecisionFeatures) -> f32 {
+        let x = features.to_array();
+        let z = (0..FEATURE_COUNT)
+            .map(|i| (x[i] - self.means[i]) / self.scales[i] * self.weights[i])
+            .sum::<f32>()
+            + self.bias;
+        sigmoid(z)
+    }
+
+    /// `heuristic` moved `weight` of the way toward `learned`.
+    pub fn blend(&self, heuristic: f32, learned: f32) -> f32 {
+        heuristic + (learned - heuristic) * self.weight
+    }
+
+    pub fn fit(&self, samples: &[(DecisionFeatures, bool)]) -> ModelFit {
+        let n = samples.len().max(1) as f32;
+        let (mut loss, mut right, mut completed) = (0.0f32, 0usize, 0usize);
+        for (features, ok) in samples {
+            let p = self.predict(features).clamp(1e-6, 1.0 - 1e-6);
+            loss -= if *ok { p.ln() } else { (1.0 - p).ln() };
+            right += usize::from((p >= 0.5) == *ok);
+            completed += usize::from(*ok);
+        }
+        ModelFit {
+            samples: samples.len(),
+            log_loss: loss / n,
+            accuracy: right as f32 / n,
+            base_rate: completed as f32 / n,
+        }
+    }
+
+    pub fn read(path: &Path) -> Result<Self, String> {
+        let text = fs::read_to_string(path).map_err(|e| e.to_string())?;
+        let model: PassSuccessModel = serde_json::from_str(&text).map_err(|e| e.to_string())?;
+        if model.features != DecisionFeatures::NAMES {
+            return Err(format!(
+                "trained on features [{}], the engine has [{}]",
+                model.features.join(", "),
+                DecisionFeatures::NAMES.join(", ")
+            ));
+        }
+        let sizes = [model.means.len(), model.scales.len(), model.weights.len()];
+        if sizes.iter().any(|&len| len != FEATURE_COUNT) {
+            return Err("feature vectors of the wrong length".to_string());
+        }
+        // `predict` divides by every scale.
+        if let Some(scale) = model
+            .scales
+            .iter()
+            .find(|scale| !scale.is_finite() || **scale == 0.0)
+        {
+            return Err(format!(
+                "feature scale {scale} is not a finite non-zero number"
+            ));
+        }
+        let finite = |values: &[f32]| values.iter().all(|v| v.is_finite());
+        if !finite(&model.means) || !finite(&model.weights) || !model.bias.is_finite() {
+            return Err("means, weights and bias must be finite numbers".to_string());
+        }
+        if !(0.0..=1.0).contains(&model.weight) {
+            return Err(format!("weight {} is outside 0.0-1.0", model.weight));
+        }
+        Ok(model)
+    }
+
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json + "\n")
+    }
+
+    /// Make `model` the one `PassEvaluator` consults. First call wins,
+    /// as with the match dispatcher.
+    pub fn install(model: PassSuccessModel) {
+        if PASS_MODEL.set(model).is_ok() {
+            InferenceTelemetry::enable();
+        }
+    }
+
+    pub fn active() -> Option<&'static PassSuccessModel> {
+        PASS_MODEL.get()
+    }
+}
+
+/// The labelled passes of a `passes.csv` written by `TrainingData`.
+/// Columns are found by name, so extra columns are ignored.
+pub fn read_pass_samples(path: &Path) -> Result<Vec<(DecisionFeatures, bool)>, String> {
+    let mut reader = csv::Reader::from_path(path).map_err(|e| e.to_string())?;
+    let header = reader.headers().map_err(|e| e.to_string())?.clone();
+    let column = |name: &str| {
+        header
+            .iter()
+            .position(|h| h == name)
+            .ok_or_else(|| format!("no `{name}` column"))
+    };
+    let slots = DecisionFeatures::NAMES
+        .iter()
+        .map(|name| column(name))
+        .collect::<Result<Vec<_>, _>>()?;
+    let completed = column("completed")?;
+
+    let mut samples = Vec::new();
+    for record in reader.records() {
+        let cells = record.map_err(|e| e.to_string())?;
+        let line = cells.position().map_or(0, |p| p.line());
+        let number = |slot: usize| -> Result<f32, String> {
+            cells
+                .get(slot)
+                .and_then(|cell| cell.trim().parse().ok())
+                .ok_or_else(|| format!("row {line}: bad value in `{}`", &header[slot]))
+        };
+        let mut values = [0.0f32; FEATURE_COUNT];
+        for (value, &slot) in values.iter_mut().zip(&slots) {
+            *value = number(slot)?;
+        }
+        samples.push((
+            DecisionFeatures::from_array(values),
+            number(completed)? > 0.5,
+        ));
+    }
+    Ok(samples)
+}
+
+fn sigmoid(z: f32) -> f32 {
+    1.0 / (1.0 + (-z).exp())
+}
+
+fn dot(a: &[f32; FEATURE_COUNT], b: &[f32; FEATURE_COUNT]) -> f32 {
+    a.iter().zip(b).map(|(x, y)| x * y).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Passes succeed when the target is free of opponents.
+    fn samples() -> Vec<(DecisionFeatures, bool)> {
+        (0..400)
+            .map(|i| {
+                let mut values = [1.0f32; FEATURE_COUNT];
+                let pressure = (i % 4) as f32;
+                values[17] = pressure;
+                values[15] = 20.0 + (i % 7) as f32;
+                (DecisionFeatures::from_array(values), pressure < 1.5)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn learns_that_pressure_at_the_target_costs_passes() {
+        let data = samples();
+        let model = PassSuccessModel::train(&data);
+        let fit = model.fit(&data);
+
+        assert!(fit.accuracy > 0.95, "{fit:?}");
+        assert!(model.weights[17] < 0.0);
+        let (free, marked) = (&data[0].0, &data[3].0);
+        assert!(model.predict(free) > 0.8 && model.predict(marked) < 0.2);
+    }
+
+    #[test]
+    fn reads_rows_written_by_training_data() {
+        let path =
+            std::env::temp_dir().join(format!("open-football-passes-{}.csv", std::process::id()));
+        let values: Vec<String> = (0..FEATURE_COUNT).map(|i| i.to_string()).collect();
+        let csv = format!(
+            "match,player_id,{},reason,completed\n0,7,{},\"A, \"\"B\"\"\",1\n",
+            DecisionFeatures::NAMES.join(","),
+            values.join(",")
+        );
+        fs::write(&path, csv).unwrap();
+
+        let samples = read_pass_samples(&path).unwrap();
+        let _ = fs::remove_file(&path);
+        assert_eq!(samples.len(), 1);
+        assert!(samples[0].1);
+        assert_eq!(samples[0].0.force, (FEATURE_COUNT - 1) as f32);
+    }
+
+    #[test]
+    fn refuses_a_model_that_would_divide_by_zero() {
+        let path =
+            std::env::temp_dir().join(format!("open-football-model-{}.json", std::process::id()));
+        let mut model = PassSuccessModel::train(&samples());
+        model.write(&path).unwrap();
+        assert!(PassSuccessModel::read(&path).is_ok());
+
+        model.scales[3] = 0.0;
+        model.write(&path).unwrap();
+        let err = PassSuccessModel::read(&path).unwrap_err();
+        let _ = fs::remove_file(&path);
+        assert!(err.contains("scale"), "{err}");
+    }
+}
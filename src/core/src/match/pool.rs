@@ -1,3 +1,4 @@
+use crate::SimulationProgress;
 use crate::r#match::engine::FootballEngine;
 use crate::r#match::{Match, MatchDispatcherRegistry, MatchResult, MatchResultRaw, MatchSquad};
 use rayon::ThreadPool;
@@ -34,8 +35,16 @@ impl MatchPlayEnginePool {
     /// process the rest. Functionally identical to the local branch of
     /// `play()`; existing call sites should keep calling `play()`.
     pub fn play_local(&self, matches: Vec<Match>) -> Vec<MatchResult> {
-        self.pool
-            .install(|| matches.into_par_iter().map(|m| m.play()).collect())
+        self.pool.install(|| {
+            matches
+                .into_par_iter()
+                .map(|m| {
+                    let result = m.play();
+                    SimulationProgress::record_matches(1);
+                    result
+                })
+                .collect()
+        })
     }
 
     /// Squad-only counterpart to [`play_local`]. Same semantics —
@@ -50,6 +59,7 @@ impl MatchPlayEnginePool {
                 .map(|(idx, home, away, is_knockout)| {
                     let result =
                         FootballEngine::<840, 545>::play(home, away, false, false, is_knockout);
+                    SimulationProgress::record_matches(1);
                     (idx, result)
                 })
                 .collect()
@@ -62,7 +72,9 @@ impl MatchPlayEnginePool {
     /// installed via `MatchDispatcherRegistry::set`, the pool first
     /// offers the work to the dispatcher. On `Ok` the dispatcher fully
     /// claims the batch (no local execution); on `Err` it hands the
-    /// input back and the pool runs the local rayon path.
+    /// input back and the pool runs the local rayon path. Locally played
+    /// matches tick [`SimulationProgress`] here; a dispatcher reports the
+    /// ones it sends to remote workers itself.
     pub fn play(&self, matches: Vec<Match>) -> Vec<MatchResult> {
         let matches = match MatchDispatcherRegistry::try_get() {
             Some(dispatcher) => match dispatcher.dispatch_league(matches) {
@@ -71,8 +83,16 @@ impl MatchPlayEnginePool {
             },
             None => matches,
         };
-        self.pool
-            .install(|| matches.into_par_iter().map(|m| m.play()).collect())
+        self.pool.install(|| {
+            matches
+                .into_par_iter()
+                .map(|m| {
+                    let result = m.play();
+                    SimulationProgress::record_matches(1);
+                    result
+                })
+                .collect()
+        })
     }
 
     /// Play raw squad-vs-squad matches through the pool (for national team / international matches).
@@ -111,6 +131,7 @@ impl MatchPlayEnginePool {
                 .map(|(idx, home, away, is_knockout)| {
                     let result =
                         FootballEngine::<840, 545>::play(home, away, false, false, is_knockout);
+                    SimulationProgress::record_matches(1);
                     (idx, result)
                 })
                 .collect()
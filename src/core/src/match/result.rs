@@ -10,6 +10,10 @@ pub struct PassEventData {
     pub timestamp: u64,
     pub from_player_id: u32,
     pub to_player_id: u32,
+    /// When the intended receiver took the ball; `None` for passes that
+    /// were intercepted, went loose or out.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<u64>,
 }
 
 impl PassEventData {
@@ -18,10 +22,35 @@ impl PassEventData {
             timestamp,
             from_player_id,
             to_player_id,
+            completed_at: None,
         }
     }
 }
 
+/// The ball carrier's `has_clear_pass` verdict for each teammate at one
+/// moment. Serializes as `[timestamp, carrier_id, [clear ids], [blocked ids]]`.
+#[derive(Debug, Clone)]
+pub struct PassLaneSample {
+    pub timestamp: u64,
+    pub carrier_id: u32,
+    pub clear: Vec<u32>,
+    pub blocked: Vec<u32>,
+}
+
+impl Serialize for PassLaneSample {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut seq = serializer.serialize_seq(Some(4))?;
+        seq.serialize_element(&self.timestamp)?;
+        seq.serialize_element(&self.carrier_id)?;
+        seq.serialize_element(&self.clear)?;
+        seq.serialize_element(&self.blocked)?;
+        seq.end()
+    }
+}
+
 #[derive(Debug, Clone, Serialize)]
 pub struct MatchEventData {
     pub timestamp: u64,
@@ -132,6 +161,11 @@ pub struct ResultMatchPositionData {
     players: HashMap<u32, Vec<ResultPositionDataItem>>,
     passes: Vec<PassEventData>,
     events: Vec<MatchEventData>,
+    /// Ball carrier pass-lane samples — only populated when track_events is true.
+    lanes: Vec<PassLaneSample>,
+    /// Tick of the last completion matched to a pass, so one completion
+    /// is stamped once however many samples see it.
+    last_completed_pass_tick: u64,
     /// Per-player state changes — only populated when track_events is true.
     player_states: HashMap<u32, Vec<PlayerStateEntry>>,
     /// Fast dedup: last recorded state compact ID per player (avoids String allocation)
@@ -149,7 +183,7 @@ impl Serialize for ResultMatchPositionData {
     {
         let has_states = self.track_events && !self.player_states.is_empty();
         let field_count =
-            2 + if self.track_events { 2 } else { 0 } + if has_states { 1 } else { 0 };
+            2 + if self.track_events { 3 } else { 0 } + if has_states { 1 } else { 0 };
         let mut map = serializer.serialize_map(Some(field_count))?;
 
         map.serialize_entry("ball", &self.ball)?;
@@ -158,6 +192,7 @@ impl Serialize for ResultMatchPositionData {
         if self.track_events {
             map.serialize_entry("passes", &self.passes)?;
             map.serialize_entry("events", &self.events)?;
+            map.serialize_entry("lanes", &self.lanes)?;
         }
 
         if has_states {
@@ -175,6 +210,8 @@ impl ResultMatchPositionData {
             players: HashMap::with_capacity(44),
             passes: Vec::new(),
             events: Vec::new(),
+            lanes: Vec::new(),
+            last_completed_pass_tick: 0,
             player_states: HashMap::new(),
             last_state_ids: HashMap::new(),
             track_events: false,
@@ -188,6 +225,8 @@ impl ResultMatchPositionData {
             players: HashMap::with_capacity(44),
             passes: Vec::new(),
             events: Vec::new(),
+            lanes: Vec::new(),
+            last_completed_pass_tick: 0,
             player_states: HashMap::with_capacity(44),
             last_state_ids: HashMap::with_capacity(44),
             track_events: true,
@@ -201,6 +240,8 @@ impl ResultMatchPositionData {
             players: HashMap::new(),
             passes: Vec::new(),
             events: Vec::new(),
+            lanes: Vec::new(),
+            last_completed_pass_tick: 0,
             player_states: HashMap::new(),
             last_state_ids: HashMap::new(),
             track_events: false,
@@ -282,6 +323,8 @@ impl ResultMatchPositionData {
                 players: HashMap::new(),
                 passes: Vec::new(),
                 events: Vec::new(),
+                lanes: Vec::new(),
+                last_completed_pass_tick: 0,
                 player_states: HashMap::new(),
                 last_state_ids: HashMap::new(),
                 track_events: self.track_events,
@@ -325,6 +368,13 @@ impl ResultMatchPositionData {
                     .cloned()
                     .collect();
 
+                chunk.lanes = self
+                    .lanes
+                    .iter()
+                    .filter(|lane| lane.timestamp >= start_time && lane.timestamp < end_time)
+                    .cloned()
+                    .collect();
+
                 // Filter player states: include last state before chunk start + states in window
                 for (player_id, states) in &self.player_states {
                     let mut chunk_states = Vec::new();
@@ -538,6 +588,36 @@ impl ResultMatchPositionData {
         }
     }
 
+    /// Stamp the pass `from_player_id` last made to `to_player_id` as
+    /// completed. `tick` is the engine tick of the completion; a tick
+    /// already seen is ignored.
+    pub fn complete_pass(
+        &mut self,
+        tick: u64,
+        timestamp: u64,
+        from_player_id: u32,
+        to_player_id: u32,
+    ) {
+        if !self.track_events || tick == self.last_completed_pass_tick {
+            return;
+        }
+        self.last_completed_pass_tick = tick;
+        if let Some(pass) =
+            self.passes.iter_mut().rev().find(|pass| {
+                pass.from_player_id == from_player_id && pass.to_player_id == to_player_id
+            })
+        {
+            pass.completed_at.get_or_insert(timestamp);
+        }
+    }
+
+    /// Add a ball carrier pass-lane sample (only if event tracking is enabled)
+    pub fn add_pass_lanes(&mut self, sample: PassLaneSample) {
+        if self.track_events {
+            self.lanes.push(sample);
+        }
+    }
+
     /// Record a player state change. Uses a cheap integer ID for fast dedup,
     /// only allocating the display String when the state actually changed.
     pub fn add_player_state(
@@ -615,3 +695,29 @@ impl VectorExtensions for Vector3<f32> {
         diff.dot(&diff).sqrt()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completions_stamp_the_latest_matching_pass_once() {
+        let mut data = ResultMatchPositionData::new_with_tracking();
+        data.add_pass_event(1_000, 7, 11);
+        data.add_pass_event(4_000, 7, 11);
+        data.complete_pass(420, 4_600, 7, 11);
+        // Later samples still see the same completion snapshot.
+        data.complete_pass(420, 4_630, 7, 11);
+        data.add_pass_lanes(PassLaneSample {
+            timestamp: 4_650,
+            carrier_id: 11,
+            clear: vec![7],
+            blocked: vec![9],
+        });
+
+        let json = serde_json::to_value(&data).unwrap();
+        assert!(json["passes"][0].get("completed_at").is_none());
+        assert_eq!(json["passes"][1]["completed_at"], 4_600);
+        assert_eq!(json["lanes"][0], serde_json::json!([4_650, 11, [7], [9]]));
+    }
+}
@@ -34,6 +34,12 @@ const COHESION_SWAP_MAX_PASSES: usize = 2;
 /// Minimum position fit (0..20 scale) a cohesion-swap candidate must clear.
 const COHESION_SWAP_MIN_POSITION_FIT: f32 = 12.0;
 
+/// Pre-big-match rest tuning (see `fixture_plan_adjustment`). Key players
+/// are rested in a routine fixture at most `BIG_MATCH_REST_DAYS` before a
+/// derby or late cup round, by up to `BIG_MATCH_REST_PENALTY` of slot score.
+const BIG_MATCH_REST_DAYS: u8 = 4;
+const BIG_MATCH_REST_PENALTY: f32 = 3.0;
+
 /// Read-only scoring inputs every selector step needs, bundled so the call
 /// chain isn't a wall of positional arguments. All fields are borrows or small
 /// `Copy` values, so the context is cheap to pass around. The competitive
@@ -1171,6 +1177,7 @@ impl SelectionScoringContext<'_> {
             + self.role_duty_adjustment(player, slot)
             + self.eligibility_rule_penalty(player)
             + self.medical_caution_adjustment(player)
+            + self.fixture_plan_adjustment(player)
             + self.loan_match_fee_start(player)
             + self.promise_pull_adjustment(player, true)
     }
@@ -1265,6 +1272,57 @@ impl SelectionScoringContext<'_> {
         -base * (model.coach_policy.medical_caution - 0.5).max(0.0) * 0.6
     }
 
+    /// Look past today's fixture. When games are stacking up the usual
+    /// workload penalty bites harder, and a routine game a few days
+    /// before a derby or a late cup round rests the key players the
+    /// coach wants fresh for it — more so the closer the big match and
+    /// the more disciplined the coach's rotation. Returns 0.0 with no
+    /// game model.
+    fn fixture_plan_adjustment(&self, player: &Player) -> f32 {
+        let Some(model) = self.game_model else {
+            return 0.0;
+        };
+        if self.is_friendly || self.honors_force(player) {
+            return 0.0;
+        }
+        let congestion =
+            self.engine.fatigue_penalty(player, false) * model.squad_state.fixture_congestion * 0.5;
+
+        let today_is_big = matches!(
+            model.match_type,
+            MatchTypeSignal::TitleRace
+                | MatchTypeSignal::RelegationSixPointer
+                | MatchTypeSignal::Derby
+                | MatchTypeSignal::CupKnockout
+                | MatchTypeSignal::CupFinal
+                | MatchTypeSignal::ContinentalKnockout
+        );
+        let rest_for_big_match = match model.squad_state.days_to_big_match {
+            Some(days)
+                if days <= BIG_MATCH_REST_DAYS
+                    && !today_is_big
+                    && !player.positions.is_goalkeeper()
+                    && Self::is_key_player(player) =>
+            {
+                let urgency = 1.0 - (days.max(1) - 1) as f32 / BIG_MATCH_REST_DAYS as f32;
+                let discipline = 0.5 + model.coach_policy.rotation_discipline * 0.5;
+                -BIG_MATCH_REST_PENALTY * urgency * discipline * (1.0 - self.match_importance)
+            }
+            _ => 0.0,
+        };
+
+        congestion + rest_for_big_match
+    }
+
+    fn is_key_player(player: &Player) -> bool {
+        player.contract.as_ref().is_some_and(|c| {
+            matches!(
+                c.squad_status,
+                PlayerSquadStatus::KeyPlayer | PlayerSquadStatus::FirstTeamRegular
+            )
+        })
+    }
+
     /// Resolve the duty for a slot from the formation context. The
     /// current `Tactics` model doesn't carry per-slot duties yet, so
     /// the helper infers a sensible default from the slot position.
@@ -1340,16 +1398,7 @@ impl SelectionScoringContext<'_> {
 
     fn policy_starting_adjustment(&self, player: &Player) -> f32 {
         let age = DateUtils::age(player.birth_date, self.date);
-        let is_key_player = player
-            .contract
-            .as_ref()
-            .map(|c| {
-                matches!(
-                    c.squad_status,
-                    PlayerSquadStatus::KeyPlayer | PlayerSquadStatus::FirstTeamRegular
-                )
-            })
-            .unwrap_or(false);
+        let is_key_player = Self::is_key_player(player);
         let is_development_age = age <= 21;
         let idle = player.player_attributes.days_since_last_match as f32;
         // Use position-weighted physical_load so a 90-min wingback gets
@@ -418,6 +418,10 @@ pub struct SquadStateProfile {
     pub fixture_congestion: f32,
     /// 0..1 medical staff quality (drives recurrence-risk tolerance).
     pub medical_quality: f32,
+    /// Days until the club's next big fixture (derby, cup semi-final or
+    /// final), when one falls inside the fixture window. The selector
+    /// rests key players in a routine game shortly before it.
+    pub days_to_big_match: Option<u8>,
 }
 
 impl SquadStateProfile {
@@ -430,6 +434,7 @@ impl SquadStateProfile {
             depth,
             fixture_congestion: 0.0,
             medical_quality,
+            days_to_big_match: None,
         }
     }
 
@@ -562,6 +567,12 @@ impl MatchSelectionGameModel {
         model.environmental_profile.is_home = is_home;
         model.squad_state.fixture_congestion =
             SquadStateProfile::congestion_from_upcoming(upcoming_fixtures);
+        model.squad_state.days_to_big_match = own_team
+            .fixture_window
+            .next_big_match
+            .map(|d| (d - ctx.date).num_days())
+            .filter(|days| *days > 0)
+            .map(|days| days.min(u8::MAX as i64) as u8);
         model
     }
 
@@ -585,6 +596,7 @@ impl Default for MatchSelectionGameModel {
                 depth: 0.5,
                 fixture_congestion: 0.0,
                 medical_quality: 0.5,
+                days_to_big_match: None,
             },
             coach_policy: CoachSelectionPolicy::neutral(),
         }
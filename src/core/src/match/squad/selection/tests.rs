@@ -18,7 +18,7 @@ fn test_names() -> PeopleNameGeneratorData {
     PeopleNameGeneratorData {
         first_names: vec!["Test".to_string()],
         last_names: vec!["Player".to_string()],
-        nicknames: Vec::new(),
+        ..Default::default()
     }
 }
 
@@ -1225,6 +1225,46 @@ fn final_starts_strongest_outfield_xi() {
     );
 }
 
+#[test]
+fn routine_league_game_rests_key_players_before_a_big_match() {
+    // Same routine league fixture twice; the second sits a day before a
+    // derby. The coach should hold more established starters back for it.
+    let staff = generate_test_staff();
+    // Fresh stars with fixed skills, so neither workload nor a random
+    // skill roll decides who starts.
+    let mut roster = paired_cup_roster();
+    for p in roster.iter_mut() {
+        FixtureSkills::stamp(p, p.player_attributes.current_ability as f32 / 10.0);
+        if p.id % 2 == 1 {
+            p.load.physical_load_7 = 0.0;
+            p.player_attributes.days_since_last_match = 7;
+        }
+    }
+    let team = cup_team(roster);
+    let with_model = |days_to_big_match: Option<u8>| {
+        let mut ctx = SelectionContext {
+            match_importance: 0.5,
+            ..SelectionContext::default()
+        };
+        let mut model = MatchSelectionGameModel::build(&ctx, &staff, 22);
+        model.squad_state.days_to_big_match = days_to_big_match;
+        model.coach_policy.rotation_discipline = 1.0;
+        ctx.game_model = Some(model);
+        ctx
+    };
+
+    let routine = SquadSelector::select_with_context(&team, &staff, &[], &with_model(None));
+    let before_derby = SquadSelector::select_with_context(&team, &staff, &[], &with_model(Some(1)));
+
+    assert_eq!(before_derby.main_squad.len(), 11);
+    assert!(
+        non_established_starter_count(&before_derby) > non_established_starter_count(&routine),
+        "key players should be rested the day before a big match: {} vs {}",
+        non_established_starter_count(&before_derby),
+        non_established_starter_count(&routine)
+    );
+}
+
 #[test]
 fn force_selected_established_not_swapped_in_cup_rotation() {
     // Stronger version of the existing force-selection test: even after
@@ -1,7 +1,7 @@
-use crate::Tactics;
 use crate::club::staff::CoachMatchSnapshot;
 use crate::r#match::MatchPlayer;
 use crate::r#match::squad::OmittedPlayer;
+use crate::{SquadCohesion, Tactics};
 
 #[derive(Debug, Clone)]
 pub struct MatchSquad {
@@ -28,4 +28,14 @@ pub struct MatchSquad {
     /// stand up a real club — the substitution path falls back to
     /// the legacy (memory-less) scoring in that case.
     pub coach_snapshot: Option<CoachMatchSnapshot>,
+    /// Quality (0..1) of the assistant manager's opposition report for
+    /// this fixture — see `StaffCollection::opposition_report_quality`.
+    /// Worth a small effective-skill edge at kick-off. 0.0 for rotation,
+    /// friendly, national-team and test squads.
+    pub opposition_report: f32,
+    /// How well this XI knows each other and its shape, read from the
+    /// team's match history — see `MatchHistory::cohesion`. `None` for
+    /// rotation, friendly, national-team and test squads, which play
+    /// at the engine's neutral familiarity.
+    pub cohesion: Option<SquadCohesion>,
 }
@@ -1,7 +1,8 @@
 use deunicode::deunicode;
+use serde::{Deserialize, Serialize};
 use std::fmt::{Display, Formatter, Result};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FullName {
     pub first_name: String,
     pub last_name: String,
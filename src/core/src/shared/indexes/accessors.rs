@@ -848,6 +848,7 @@ impl SimulatorData {
     pub fn rebuild_indexes(&mut self) {
         if let Some(mut indexes) = self.indexes.take() {
             indexes.refresh_player_indexes(self);
+            indexes.player_search.refresh(self);
             self.indexes = Some(indexes);
         }
     }
@@ -855,11 +856,18 @@ impl SimulatorData {
     /// Rebuild indexes only if a transfer actually moved a player today.
     /// Resets `dirty_player_index` after a successful refresh so the next
     /// tick starts clean. Walking the world every day is wasteful — this
-    /// is the cheap default the orchestrator should call.
+    /// is the cheap default the orchestrator should call. The player
+    /// search rows are also re-read once they are a week old, since values
+    /// and ability drift without any transfer.
     pub fn rebuild_indexes_if_dirty(&mut self) {
         if self.dirty_player_index {
             self.rebuild_indexes();
             self.dirty_player_index = false;
+        } else if let Some(mut indexes) = self.indexes.take() {
+            if indexes.player_search.is_stale(self.date.date()) {
+                indexes.player_search.refresh(self);
+            }
+            self.indexes = Some(indexes);
         }
     }
 
@@ -1350,6 +1358,7 @@ impl SimulatorData {
                                     sell_club,
                                     player,
                                     estimated_value,
+                                    0.0,
                                     false,
                                     true,
                                     date,
@@ -2286,6 +2295,8 @@ mod interested_clubs_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
@@ -1,4 +1,5 @@
 pub mod accessors;
+pub mod player_search;
 pub mod simulator_indexes;
 
 pub use accessors::{
@@ -7,4 +8,8 @@ pub use accessors::{
     PlayerMonitoringDetail, RecruitmentMeetingRow, ScoutWorkloadRow, ScoutingAssignmentRow,
     ScoutingReportRow, ScoutingSummary, ShadowReportRow, StaffMonitoringRow, TransferRequestRow,
 };
+pub use player_search::{
+    PlayerSearchCursor, PlayerSearchIndex, PlayerSearchPage, PlayerSearchQuery, PlayerSearchRow,
+    PlayerSearchSort,
+};
 pub use simulator_indexes::*;
@@ -0,0 +1,431 @@
+//! World-wide player search. Every contracted player and free agent gets
+//! one compact row, and the rows are pre-sorted once per sort key so a
+//! search walks an ordered slice, filters in place and stops as soon as
+//! a page is full. Pages are addressed with a keyset cursor — the sort
+//! key and id of the last row served — so paging stays stable while the
+//! index is rebuilt underneath it.
+
+use crate::club::staff::perception::AbilityEstimator;
+use crate::utils::DateUtils;
+use crate::{Country, PlayerPositionType, SimulatorData};
+use chrono::{Datelike, NaiveDate};
+use rayon::prelude::*;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+/// Days after which values and ability levels are re-read even when no
+/// transfer marked the indexes dirty.
+const STALE_AFTER_DAYS: i64 = 7;
+
+#[derive(Clone, Debug)]
+pub struct PlayerSearchRow {
+    pub player_id: u32,
+    /// `None` for free agents.
+    pub club_id: Option<u32>,
+    pub team_id: Option<u32>,
+    pub position: PlayerPositionType,
+    pub nationality_id: u32,
+    pub birth_date: NaiveDate,
+    pub value: f64,
+    pub contract_expiry: Option<NaiveDate>,
+    /// Coach-observable level on the 1..200 scale — never the hidden CA.
+    pub ability_level: u8,
+}
+
+impl PlayerSearchRow {
+    /// Ability on the half-star scale the pages render: 0..=10 halves.
+    pub fn ability_half_stars(&self) -> u8 {
+        (((self.ability_level as f32 / 200.0) * 10.0).round() as u8).min(10)
+    }
+
+    fn sort_key(&self, sort: PlayerSearchSort) -> i64 {
+        match sort {
+            PlayerSearchSort::Ability => self.ability_level as i64,
+            PlayerSearchSort::Value => self.value.round() as i64,
+            // Younger players are born later, so ascending age is
+            // descending birth date.
+            PlayerSearchSort::Age => -(self.birth_date.num_days_from_ce() as i64),
+            PlayerSearchSort::ContractExpiry => self
+                .contract_expiry
+                .map(|d| d.num_days_from_ce() as i64)
+                .unwrap_or(i64::MAX),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlayerSearchSort {
+    #[default]
+    Ability,
+    Value,
+    Age,
+    ContractExpiry,
+}
+
+impl PlayerSearchSort {
+    const ALL: [PlayerSearchSort; 4] = [
+        PlayerSearchSort::Ability,
+        PlayerSearchSort::Value,
+        PlayerSearchSort::Age,
+        PlayerSearchSort::ContractExpiry,
+    ];
+
+    fn index(self) -> usize {
+        match self {
+            PlayerSearchSort::Ability => 0,
+            PlayerSearchSort::Value => 1,
+            PlayerSearchSort::Age => 2,
+            PlayerSearchSort::ContractExpiry => 3,
+        }
+    }
+}
+
+/// Position of the last row of a page inside its sort order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerSearchCursor {
+    pub key: i64,
+    pub player_id: u32,
+}
+
+impl Display for PlayerSearchCursor {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.key, self.player_id)
+    }
+}
+
+impl FromStr for PlayerSearchCursor {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (key, player_id) = s.rsplit_once('_').ok_or(())?;
+        Ok(PlayerSearchCursor {
+            key: key.parse().map_err(|_| ())?,
+            player_id: player_id.parse().map_err(|_| ())?,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PlayerSearchQuery {
+    pub position: Option<PlayerPositionType>,
+    pub min_age: Option<u8>,
+    pub max_age: Option<u8>,
+    pub nationality_id: Option<u32>,
+    pub min_value: Option<f64>,
+    pub max_value: Option<f64>,
+    /// Contracts running out on or before this date; free agents match.
+    pub contract_expires_before: Option<NaiveDate>,
+    /// Half stars, 0..=10.
+    pub min_half_stars: Option<u8>,
+    pub max_half_stars: Option<u8>,
+    pub sort: PlayerSearchSort,
+    pub descending: bool,
+    pub cursor: Option<PlayerSearchCursor>,
+    pub limit: usize,
+}
+
+impl PlayerSearchQuery {
+    fn matches(&self, row: &PlayerSearchRow, today: NaiveDate) -> bool {
+        let age = DateUtils::age(row.birth_date, today);
+        let stars = row.ability_half_stars();
+        self.position.is_none_or(|p| row.position == p)
+            && self.min_age.is_none_or(|min| age >= min)
+            && self.max_age.is_none_or(|max| age <= max)
+            && self
+                .nationality_id
+                .is_none_or(|id| row.nationality_id == id)
+            && self.min_value.is_none_or(|min| row.value >= min)
+            && self.max_value.is_none_or(|max| row.value <= max)
+            && self
+                .contract_expires_before
+                .is_none_or(|by| row.contract_expiry.is_none_or(|d| d <= by))
+            && self.min_half_stars.is_none_or(|min| stars >= min)
+            && self.max_half_stars.is_none_or(|max| stars <= max)
+    }
+}
+
+pub struct PlayerSearchPage<'a> {
+    pub rows: Vec<&'a PlayerSearchRow>,
+    /// Set when more matching rows follow this page.
+    pub next_cursor: Option<PlayerSearchCursor>,
+}
+
+#[derive(Clone)]
+pub struct PlayerSearchIndex {
+    rows: Vec<PlayerSearchRow>,
+    /// Row offsets sorted ascending by `(sort key, player id)`, one list
+    /// per `PlayerSearchSort`.
+    orders: [Vec<u32>; 4],
+    built_on: Option<NaiveDate>,
+}
+
+impl PlayerSearchIndex {
+    pub fn new() -> Self {
+        PlayerSearchIndex {
+            rows: Vec::new(),
+            orders: Default::default(),
+            built_on: None,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.rows.is_empty()
+    }
+
+    pub fn is_stale(&self, today: NaiveDate) -> bool {
+        self.built_on
+            .is_none_or(|built| (today - built).num_days() >= STALE_AFTER_DAYS)
+    }
+
+    pub fn refresh(&mut self, data: &SimulatorData) {
+        let today = data.date.date();
+
+        // Shard per country, not per continent — same balancing reason as
+        // `refresh_player_indexes`.
+        let countries: Vec<&Country> = data
+            .continents
+            .iter()
+            .flat_map(|continent| continent.countries.iter())
+            .collect();
+        let mut rows: Vec<PlayerSearchRow> = countries
+            .par_iter()
+            .flat_map_iter(|country| Self::country_rows(country, today))
+            .collect();
+
+        rows.extend(data.free_agents.iter().filter_map(|player| {
+            Some(PlayerSearchRow {
+                player_id: player.id,
+                club_id: None,
+                team_id: None,
+                position: player.positions.primary()?,
+                nationality_id: player.country_id,
+                birth_date: player.birth_date,
+                value: player.value(today, 0, 0),
+                contract_expiry: None,
+                ability_level: AbilityEstimator::observable_level(player),
+            })
+        }));
+
+        self.set_rows(rows, today);
+    }
+
+    fn country_rows(country: &Country, today: NaiveDate) -> Vec<PlayerSearchRow> {
+        let league_reputation: HashMap<u32, u16> = country
+            .leagues
+            .leagues
+            .iter()
+            .map(|l| (l.id, l.reputation))
+            .collect();
+
+        let mut rows = Vec::new();
+        for club in &country.clubs {
+            for team in club.teams.iter() {
+                let league_rep = team
+                    .league_id
+                    .and_then(|id| league_reputation.get(&id).copied())
+                    .unwrap_or(0);
+                let club_rep = team.reputation.market_value_score();
+                for player in team.players.players() {
+                    let Some(position) = player.positions.primary() else {
+                        continue;
+                    };
+                    rows.push(PlayerSearchRow {
+                        player_id: player.id,
+                        club_id: Some(club.id),
+                        team_id: Some(team.id),
+                        position,
+                        nationality_id: player.country_id,
+                        birth_date: player.birth_date,
+                        value: player.value(today, league_rep, club_rep),
+                        contract_expiry: player.contract().map(|c| c.expiration),
+                        ability_level: AbilityEstimator::observable_level(player),
+                    });
+                }
+            }
+        }
+        rows
+    }
+
+    fn set_rows(&mut self, rows: Vec<PlayerSearchRow>, today: NaiveDate) {
+        for sort in PlayerSearchSort::ALL {
+            let mut order: Vec<u32> = (0..rows.len() as u32).collect();
+            order.sort_unstable_by_key(|&i| {
+                let row = &rows[i as usize];
+                (row.sort_key(sort), row.player_id)
+            });
+            self.orders[sort.index()] = order;
+        }
+        self.rows = rows;
+        self.built_on = Some(today);
+    }
+
+    /// One page of rows matching `query`, in the requested order, starting
+    /// after `query.cursor`.
+    pub fn search(&self, query: &PlayerSearchQuery, today: NaiveDate) -> PlayerSearchPage<'_> {
+        let sort = query.sort;
+        let order = &self.orders[sort.index()];
+        let key_of = |i: u32| {
+            let row = &self.rows[i as usize];
+            (row.sort_key(sort), row.player_id)
+        };
+
+        let candidates: Box<dyn Iterator<Item = &u32>> = match (query.descending, query.cursor) {
+            (false, None) => Box::new(order.iter()),
+            (true, None) => Box::new(order.iter().rev()),
+            (false, Some(c)) => {
+                let start = order.partition_point(|&i| key_of(i) <= (c.key, c.player_id));
+                Box::new(order[start..].iter())
+            }
+            (true, Some(c)) => {
+                let end = order.partition_point(|&i| key_of(i) < (c.key, c.player_id));
+                Box::new(order[..end].iter().rev())
+            }
+        };
+
+        let mut matching = candidates
+            .map(|&i| &self.rows[i as usize])
+            .filter(|row| query.matches(row, today));
+
+        let rows: Vec<&PlayerSearchRow> = matching.by_ref().take(query.limit).collect();
+        let next_cursor = match (rows.last(), matching.next()) {
+            (Some(last), Some(_)) => Some(PlayerSearchCursor {
+                key: last.sort_key(sort),
+                player_id: last.player_id,
+            }),
+            _ => None,
+        };
+
+        PlayerSearchPage { rows, next_cursor }
+    }
+}
+
+impl Default for PlayerSearchIndex {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    fn row(player_id: u32, born: i32, value: f64, ability_level: u8) -> PlayerSearchRow {
+        PlayerSearchRow {
+            player_id,
+            club_id: Some(1),
+            team_id: Some(1),
+            position: if player_id.is_multiple_of(2) {
+                PlayerPositionType::Striker
+            } else {
+                PlayerPositionType::Goalkeeper
+            },
+            nationality_id: if player_id < 5 { 10 } else { 20 },
+            birth_date: d(born, 1, 1),
+            value,
+            contract_expiry: Some(d(2027 + (player_id % 3) as i32, 6, 30)),
+            ability_level,
+        }
+    }
+
+    fn index() -> PlayerSearchIndex {
+        let rows = (1..=10)
+            .map(|id| {
+                row(
+                    id,
+                    2000 + id as i32,
+                    id as f64 * 1_000_000.0,
+                    60 + id as u8 * 10,
+                )
+            })
+            .collect();
+        let mut index = PlayerSearchIndex::new();
+        index.set_rows(rows, d(2026, 8, 1));
+        index
+    }
+
+    fn ids(page: &PlayerSearchPage<'_>) -> Vec<u32> {
+        page.rows.iter().map(|r| r.player_id).collect()
+    }
+
+    #[test]
+    fn filters_combine() {
+        let index = index();
+        let query = PlayerSearchQuery {
+            position: Some(PlayerPositionType::Striker),
+            nationality_id: Some(20),
+            max_value: Some(8_000_000.0),
+            limit: 10,
+            ..Default::default()
+        };
+
+        let page = index.search(&query, d(2026, 8, 1));
+
+        assert_eq!(ids(&page), vec![6, 8]);
+        assert!(page.next_cursor.is_none());
+    }
+
+    #[test]
+    fn age_filter_uses_today() {
+        let index = index();
+        let query = PlayerSearchQuery {
+            max_age: Some(21),
+            sort: PlayerSearchSort::Age,
+            limit: 10,
+            ..Default::default()
+        };
+
+        let page = index.search(&query, d(2026, 8, 1));
+
+        assert_eq!(ids(&page), vec![10, 9, 8, 7, 6, 5]);
+    }
+
+    #[test]
+    fn cursor_pages_through_descending_order_without_gaps() {
+        let index = index();
+        let mut query = PlayerSearchQuery {
+            sort: PlayerSearchSort::Value,
+            descending: true,
+            limit: 4,
+            ..Default::default()
+        };
+
+        let mut seen = Vec::new();
+        loop {
+            let page = index.search(&query, d(2026, 8, 1));
+            seen.extend(ids(&page));
+            match page.next_cursor {
+                Some(cursor) => {
+                    let text = cursor.to_string();
+                    query.cursor = Some(text.parse().unwrap());
+                }
+                None => break,
+            }
+        }
+
+        assert_eq!(seen, (1..=10).rev().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn star_filter_uses_half_stars() {
+        let index = index();
+        let query = PlayerSearchQuery {
+            min_half_stars: Some(7),
+            limit: 10,
+            ..Default::default()
+        };
+
+        let page = index.search(&query, d(2026, 8, 1));
+
+        // Levels 130..=160 round to 7 or 8 half stars.
+        assert_eq!(ids(&page), vec![7, 8, 9, 10]);
+    }
+}
@@ -1,3 +1,4 @@
+use super::PlayerSearchIndex;
 use crate::SimulatorData;
 use crate::continent::Continent;
 use crate::country::Country;
@@ -24,6 +25,11 @@ pub struct SimulatorDataIndexes {
     pub team_positions: HashMap<u32, (u32, u32, u32, u32)>,
     pub club_positions: HashMap<u32, (u32, u32, u32)>,
     pub league_positions: HashMap<u32, (u32, u32, u32)>,
+
+    /// Pre-sorted rows behind the world-wide player search. Rebuilt with
+    /// the player indexes and at least weekly, so values lag by days at
+    /// most.
+    pub player_search: PlayerSearchIndex,
 }
 
 impl SimulatorDataIndexes {
@@ -40,6 +46,7 @@ impl SimulatorDataIndexes {
             team_positions: HashMap::new(),
             club_positions: HashMap::new(),
             league_positions: HashMap::new(),
+            player_search: PlayerSearchIndex::new(),
         }
     }
 
@@ -72,6 +79,8 @@ impl SimulatorDataIndexes {
         for shard in shards {
             self.merge_shard(shard);
         }
+
+        self.player_search.refresh(data);
     }
 
     /// Populate this shard with every entity inside `continent`. Mirrors
@@ -177,6 +186,7 @@ impl SimulatorDataIndexes {
             team_positions,
             club_positions,
             league_positions,
+            player_search: _,
         } = shard;
         self.league_indexes.extend(league_indexes);
         self.club_indexes.extend(club_indexes);
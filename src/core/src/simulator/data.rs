@@ -1,8 +1,11 @@
 use super::country_info::CountryInfo;
+use super::managers::Managers;
+use super::news::NewsFeed;
 use super::seeding::{
     ClubSeedingContext, build_league_lookup, club_has_players_needing_seed,
     team_has_players_needing_seed, team_ids_for_league,
 };
+use super::watchlist::Watchlist;
 use crate::NationalSelectionPolicy;
 use crate::NationalTeam;
 use crate::PlayerSquadStatus;
@@ -11,6 +14,7 @@ use crate::club::player::calculators::{FreeAgentReleaseReason, WageCalculator};
 use crate::club::staff::perception::PotentialEstimator;
 use crate::competitions::GlobalCompetitions;
 use crate::continent::Continent;
+use crate::country::Country;
 use crate::country::result::transfers::GlobalFreeAgentSummary;
 use crate::country::result::transfers::free_agent_market_calc::FreeAgentMarketCalculator;
 use crate::league::{LeagueTable, MatchStorage};
@@ -22,10 +26,14 @@ use crate::utils::random::engine as rng_engine;
 use crate::{Person, Player, Staff};
 use chrono::{Duration, NaiveDate, NaiveDateTime};
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::collections::HashSet;
 
-#[derive(Clone)]
+/// The whole world, as written to a save slot. Fields added to it or to
+/// any stored entity should carry `#[serde(default)]` so saves made before
+/// the field existed still load.
+#[derive(Clone, Serialize, Deserialize)]
 pub struct SimulatorData {
     pub continents: Vec<Continent>,
 
@@ -33,6 +41,8 @@ pub struct SimulatorData {
 
     pub transfer_pool: TransferPool<Player>,
 
+    /// Lookup indexes, rebuilt from the world after a load.
+    #[serde(skip)]
     pub indexes: Option<SimulatorDataIndexes>,
 
     /// Set to true whenever a transfer moves a player between clubs. Checked
@@ -54,7 +64,9 @@ pub struct SimulatorData {
     /// either resolving in a signing (with cascade) or being rejected.
     pub pending_manager_approaches: Vec<ManagerApproach>,
 
-    pub watchlist: Vec<u32>,
+    /// Players the user is tracking, with the value and contract they
+    /// had when added.
+    pub watchlist: Watchlist,
 
     pub global_competitions: GlobalCompetitions,
 
@@ -70,6 +82,7 @@ pub struct SimulatorData {
     /// Reset (`= None`) at the end of each `simulate_with` tick;
     /// readers fall back to a local rebuild when the cache is `None`
     /// so test paths and one-off callers still work.
+    #[serde(skip)]
     pub daily_world_player_pool: Option<Vec<PlayerSummary>>,
 
     /// Per-tick scratch cache: snapshot of every globally-pooled free
@@ -79,6 +92,7 @@ pub struct SimulatorData {
     /// player's `free_agent_state` (idempotent on repeat with the same
     /// date) and walks every free agent. Crate-private because the
     /// snapshot type is internal to the country/result module.
+    #[serde(skip)]
     pub(crate) daily_global_free_agents: Option<Vec<GlobalFreeAgentSummary>>,
 
     /// Monthly free-agent market flow counters (signed from the global
@@ -90,13 +104,25 @@ pub struct SimulatorData {
     /// `FreeAgentMarketAuditor::log_pool_stats` reads them on the first of
     /// each month before the caller `reset`s them.
     pub free_agent_flow: FreeAgentFlowCounters,
+
+    /// World news feed — transfers, injuries, results and manager changes
+    /// filed daily by `NewsDesk`. Part of the game state, so it lives and
+    /// dies with the save.
+    pub news: NewsFeed,
+
+    /// Human managers and the clubs they control. Only a managed club's
+    /// teams accept lineup and tactic changes from the team management
+    /// endpoints and only its transfer decisions wait for its manager;
+    /// every other club is left to the AI. Change a manager's club through
+    /// [`SimulatorData::set_manager_club`].
+    pub managers: Managers,
 }
 
 /// Monthly free-agent market flow counters. Distinguishes the routes a
 /// player leaves or enters the pool by, so a long run's diagnostics log
 /// can tell apart "saved by a pre-contract" from "signed off the open
 /// pool" from "still leaking into long-term free agency".
-#[derive(Debug, Default, Clone, Copy)]
+#[derive(Debug, Default, Clone, Copy, Serialize, Deserialize)]
 pub struct FreeAgentFlowCounters {
     /// Signed out of the cross-country global pool (`data.free_agents`).
     pub signed_from_global_pool: u32,
@@ -185,20 +211,18 @@ impl SimulatorData {
             free_agents: Vec::new(),
             free_agent_staff: Vec::new(),
             pending_manager_approaches: Vec::new(),
-            watchlist: Vec::new(),
+            watchlist: Watchlist::default(),
             global_competitions,
             country_info,
             match_store: MatchStorage::new(),
             daily_world_player_pool: None,
             daily_global_free_agents: None,
             free_agent_flow: FreeAgentFlowCounters::default(),
+            news: NewsFeed::new(),
+            managers: Managers::default(),
         };
 
-        let mut indexes = SimulatorDataIndexes::new();
-
-        indexes.refresh(&data);
-
-        data.indexes = Some(indexes);
+        data.restore_indexes();
 
         data.init_league_tables();
         data.seed_player_histories();
@@ -207,6 +231,59 @@ impl SimulatorData {
         data
     }
 
+    /// A copy of the world with every continent's countries left out, for
+    /// stores that keep countries apart from the rest;
+    /// [`SimulatorData::restore_countries`] puts them back. Indexes and
+    /// per-tick caches are not copied.
+    pub fn without_countries(&self) -> SimulatorData {
+        SimulatorData {
+            continents: self
+                .continents
+                .iter()
+                .map(Continent::without_countries)
+                .collect(),
+            date: self.date,
+            transfer_pool: self.transfer_pool.clone(),
+            indexes: None,
+            dirty_player_index: self.dirty_player_index,
+            free_agents: self.free_agents.clone(),
+            free_agent_staff: self.free_agent_staff.clone(),
+            pending_manager_approaches: self.pending_manager_approaches.clone(),
+            watchlist: self.watchlist.clone(),
+            global_competitions: self.global_competitions.clone(),
+            country_info: self.country_info.clone(),
+            match_store: self.match_store.clone(),
+            daily_world_player_pool: None,
+            daily_global_free_agents: None,
+            free_agent_flow: self.free_agent_flow,
+            news: self.news.clone(),
+            managers: self.managers.clone(),
+        }
+    }
+
+    /// Return countries stored apart to their continents, keeping the
+    /// order they are given in. A country of an unknown continent is
+    /// dropped.
+    pub fn restore_countries(&mut self, countries: impl IntoIterator<Item = Country>) {
+        for country in countries {
+            if let Some(continent) = self
+                .continents
+                .iter_mut()
+                .find(|c| c.id == country.continent_id)
+            {
+                continent.countries.push(country);
+            }
+        }
+    }
+
+    /// Build the lookup indexes from scratch. Saves skip them, so a
+    /// deserialized world calls this before it is served.
+    pub fn restore_indexes(&mut self) {
+        let mut indexes = SimulatorDataIndexes::new();
+        indexes.refresh(self);
+        self.indexes = Some(indexes);
+    }
+
     /// Populate `Player.nationality_continent_id` from `country_info` for
     /// every player on every roster + retired + national-team + free-agent
     /// pool. Called once at construction time after `country_info` is
@@ -351,7 +428,7 @@ impl SimulatorData {
                     }
                     let team_ids = team_ids_for_league(clubs, league.id);
                     if !team_ids.is_empty() {
-                        league.table = LeagueTable::new(&team_ids);
+                        league.table = LeagueTable::for_rules(&team_ids, &league.rules);
                     }
                 }
             });
@@ -1082,6 +1159,8 @@ mod free_agent_release_reason_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
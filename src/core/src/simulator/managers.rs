@@ -0,0 +1,206 @@
+//! Human managers playing in the world. Each manager is identified by a
+//! random session token and controls at most one club; a club has at most
+//! one manager. Clubs without a manager are left to the AI. The registry
+//! is part of the save, so a manager's session survives a reload.
+
+use super::SimulatorData;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Manager {
+    pub id: u32,
+    pub name: String,
+    /// Session token the web layer keeps in the manager's cookie.
+    pub token: String,
+    pub club_id: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ManagerError {
+    ManagerNotFound,
+    ClubNotFound,
+    /// The club is controlled by another manager.
+    ClubTaken,
+}
+
+impl fmt::Display for ManagerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManagerError::ManagerNotFound => write!(f, "manager not found"),
+            ManagerError::ClubNotFound => write!(f, "club not found"),
+            ManagerError::ClubTaken => write!(f, "club is managed by another manager"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Managers {
+    managers: Vec<Manager>,
+    next_id: u32,
+}
+
+impl Managers {
+    pub fn get(&self, manager_id: u32) -> Option<&Manager> {
+        self.managers.iter().find(|m| m.id == manager_id)
+    }
+
+    /// The manager holding session `token`; empty tokens never match.
+    pub fn by_token(&self, token: &str) -> Option<&Manager> {
+        if token.is_empty() {
+            return None;
+        }
+        self.managers.iter().find(|m| m.token == token)
+    }
+
+    /// The manager controlling `club_id`, if any.
+    pub fn of_club(&self, club_id: u32) -> Option<&Manager> {
+        self.managers.iter().find(|m| m.club_id == Some(club_id))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Manager> {
+        self.managers.iter()
+    }
+
+    /// Clubs controlled by a manager.
+    pub fn club_ids(&self) -> Vec<u32> {
+        self.managers.iter().filter_map(|m| m.club_id).collect()
+    }
+
+    fn register(&mut self, name: String, token: String) -> &Manager {
+        self.next_id += 1;
+        self.managers.push(Manager {
+            id: self.next_id,
+            name,
+            token,
+            club_id: None,
+        });
+        self.managers.last().unwrap()
+    }
+
+    fn get_mut(&mut self, manager_id: u32) -> Option<&mut Manager> {
+        self.managers.iter_mut().find(|m| m.id == manager_id)
+    }
+}
+
+fn new_token() -> String {
+    format!("{:032x}", rand::random::<u128>())
+}
+
+impl SimulatorData {
+    /// Whether a human manager controls `club_id`.
+    pub fn is_user_club(&self, club_id: u32) -> bool {
+        self.managers.of_club(club_id).is_some()
+    }
+
+    /// Add a manager without a club and return it with a fresh session
+    /// token.
+    pub fn register_manager(&mut self, name: &str) -> &Manager {
+        let mut token = new_token();
+        while self.managers.by_token(&token).is_some() {
+            token = new_token();
+        }
+        self.managers.register(name.trim().to_string(), token)
+    }
+
+    /// Hand `club_id` to the manager (or their club back to the AI with
+    /// `None`). Fails when another manager controls the club. The club the
+    /// manager leaves loses its matchday plans.
+    pub fn set_manager_club(
+        &mut self,
+        manager_id: u32,
+        club_id: Option<u32>,
+    ) -> Result<(), ManagerError> {
+        let manager = self
+            .managers
+            .get(manager_id)
+            .ok_or(ManagerError::ManagerNotFound)?;
+        let previous = manager.club_id;
+        if previous == club_id {
+            return Ok(());
+        }
+        if let Some(id) = club_id {
+            if self.club(id).is_none() {
+                return Err(ManagerError::ClubNotFound);
+            }
+            if self.managers.of_club(id).is_some() {
+                return Err(ManagerError::ClubTaken);
+            }
+        }
+
+        if let Some(manager) = self.managers.get_mut(manager_id) {
+            manager.club_id = club_id;
+        }
+        self.release_club(previous);
+        Ok(())
+    }
+
+    /// Remove the manager; their club goes back to the AI.
+    pub fn remove_manager(&mut self, manager_id: u32) -> Result<(), ManagerError> {
+        let index = self
+            .managers
+            .managers
+            .iter()
+            .position(|m| m.id == manager_id)
+            .ok_or(ManagerError::ManagerNotFound)?;
+        let manager = self.managers.managers.remove(index);
+        self.release_club(manager.club_id);
+        Ok(())
+    }
+
+    /// Clear the matchday plans of a club that just lost its manager and
+    /// mirror the managed clubs into every transfer market. Negotiations
+    /// parked for a club no manager controls go back to the AI resolver.
+    fn release_club(&mut self, club_id: Option<u32>) {
+        if let Some(club) = club_id.and_then(|id| self.club_mut(id)) {
+            for team in club.teams.iter_mut() {
+                team.clear_matchday_plan();
+            }
+        }
+
+        let user_club_ids = self.managers.club_ids();
+        for continent in &mut self.continents {
+            for country in &mut continent.countries {
+                let market = &mut country.transfer_market;
+                market.user_club_ids = user_club_ids.clone();
+                for negotiation in market.negotiations.values_mut() {
+                    let involves_user = user_club_ids.iter().any(|&id| {
+                        negotiation.buying_club_id == id || negotiation.selling_club_id == id
+                    });
+                    if !involves_user {
+                        negotiation.user_step = None;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn register_hands_out_distinct_ids_and_tokens() {
+        let mut managers = Managers::default();
+        let first = managers.register("Alex".to_string(), new_token()).clone();
+        let second = managers.register("Sam".to_string(), new_token()).clone();
+
+        assert_ne!(first.id, second.id);
+        assert_ne!(first.token, second.token);
+        assert_eq!(first.token.len(), 32);
+        assert_eq!(managers.by_token(&second.token).unwrap().id, second.id);
+        assert!(managers.by_token("").is_none());
+    }
+
+    #[test]
+    fn of_club_finds_the_controlling_manager() {
+        let mut managers = Managers::default();
+        let id = managers.register("Alex".to_string(), new_token()).id;
+        managers.get_mut(id).unwrap().club_id = Some(7);
+
+        assert_eq!(managers.of_club(7).map(|m| m.id), Some(id));
+        assert!(managers.of_club(8).is_none());
+        assert_eq!(managers.club_ids(), vec![7]);
+    }
+}
@@ -2,17 +2,26 @@ mod awards;
 mod country_info;
 mod data;
 mod loan_wages;
+mod managers;
 mod matchday;
+mod news;
+mod progress;
 mod result;
 mod seeding;
+mod watchlist;
 
 pub use country_info::CountryInfo;
 pub use data::{FreeAgentFlowCounters, SimulatorData};
+pub use managers::{Manager, ManagerError, Managers};
 pub use matchday::WorldMatchdayResult;
+pub use news::{NewsArg, NewsDesk, NewsDraft, NewsFeed, NewsItem, NewsKind};
+pub use progress::{LeagueTiming, ProgressCounters, SimulationProgress};
 pub use result::SimulationResult;
+pub use watchlist::{Watchlist, WatchlistEntry};
 
 use crate::club::board::manager_market;
 use crate::club::player::development::CoachingEffect;
+use crate::club::staff::backroom;
 use crate::competitions::simulation::GlobalCompetitionSimulator;
 use crate::config::SimulatorConfig;
 use crate::context::{GlobalContext, SimulationContext};
@@ -336,6 +345,11 @@ impl FootballSimulator {
         let today = data.date.date();
         manager_market::ManagerMarketTick::run(data, today);
 
+        // File the day's news. After the manager market so the
+        // day's appointments are in; every other source (transfers,
+        // injuries, results, sackings) has settled by now.
+        NewsDesk::publish(data, today);
+
         // Phase D2: parent-side loan wage settlement. Per-club monthly
         // finance runs inside Phase A and bills the borrower for the
         // loan contract; the parent club still owes the residual share
@@ -350,6 +364,10 @@ impl FootballSimulator {
             // with a deterministic hard bound so unlucky rolls can't
             // strand anyone in the pool for multiple seasons.
             data.process_free_agent_retirements(today);
+            // AI clubs refill empty backroom seats (assistant, physio,
+            // goalkeeping and fitness coaches) from the free-agent staff
+            // pool, after the manager market has had first pick.
+            backroom::fill_backroom_vacancies(data, today);
             // Monthly visibility into the long tail: one debug line per
             // 12-month-plus free agent explaining why they're unsigned
             // (no-op unless debug logging is enabled).
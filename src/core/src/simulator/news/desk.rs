@@ -0,0 +1,275 @@
+use super::{NewsArg, NewsDraft, NewsKind};
+use crate::shared::fullname::FullName;
+use crate::transfers::TransferType;
+use crate::{Country, SimulatorData};
+use chrono::{Duration, NaiveDate};
+use std::collections::HashSet;
+
+/// Smallest fee that makes a signing a story on its own.
+const NOTABLE_TRANSFER_FEE: f64 = 2_000_000.0;
+
+/// Injuries shorter than this are routine and stay off the front page.
+const NOTABLE_INJURY_DAYS: u16 = 28;
+
+/// Winning margin that turns a result into a headline.
+const NOTABLE_WINNING_MARGIN: u8 = 4;
+
+/// Daily pass that turns the day's simulation events into news items.
+pub struct NewsDesk;
+
+impl NewsDesk {
+    /// File today's stories on `data.news` and trim the feed. Runs after
+    /// the manager market so the day's appointments are visible.
+    pub fn publish(data: &mut SimulatorData, today: NaiveDate) {
+        let mut drafts: Vec<NewsDraft> = Vec::new();
+        let mut injured_now: HashSet<u32> = HashSet::new();
+        let mut injury_drafts: Vec<NewsDraft> = Vec::new();
+
+        for continent in &data.continents {
+            for country in &continent.countries {
+                Self::transfers(country, today, &mut drafts);
+                Self::manager_changes(country, today, &mut drafts);
+                Self::injuries(country, &mut injured_now, &mut injury_drafts);
+            }
+        }
+        Self::results(data, today, &mut drafts);
+
+        for draft in injury_drafts {
+            let Some(player_id) = draft.player_id else {
+                continue;
+            };
+            if data.news.injury_is_news(player_id) {
+                drafts.push(draft);
+            }
+        }
+        data.news.forget_recovered(&injured_now);
+
+        for draft in drafts {
+            data.news.publish(today, draft);
+        }
+        data.news.trim(today);
+    }
+
+    /// Fee-paying signings completed today, bought by this country's
+    /// clubs. A fee above every earlier one on the country's books is a
+    /// national record.
+    fn transfers(country: &Country, today: NaiveDate, drafts: &mut Vec<NewsDraft>) {
+        let history = &country.transfer_market.transfer_history;
+        let mut record = history
+            .iter()
+            .filter(|t| t.transfer_date < today)
+            .map(|t| t.fee.amount)
+            .fold(0.0, f64::max);
+
+        for t in history.iter().filter(|t| t.transfer_date == today) {
+            if matches!(t.transfer_type, TransferType::Loan(_)) {
+                continue;
+            }
+            let fee = t.fee.amount;
+            let is_record = record > 0.0 && fee > record;
+            record = record.max(fee);
+            if !is_record && fee < NOTABLE_TRANSFER_FEE {
+                continue;
+            }
+            drafts.push(NewsDraft {
+                kind: if is_record {
+                    NewsKind::RecordTransfer
+                } else {
+                    NewsKind::Transfer
+                },
+                country_id: country.id,
+                club_id: Some(t.to_club_id),
+                player_id: Some(t.player_id),
+                args: vec![
+                    ("player", NewsArg::Text(t.player_name.clone())),
+                    ("from", NewsArg::Text(t.from_team_name.clone())),
+                    ("to", NewsArg::Text(t.to_team_name.clone())),
+                    ("fee", NewsArg::Money(fee)),
+                ],
+            });
+        }
+    }
+
+    fn manager_changes(country: &Country, today: NaiveDate, drafts: &mut Vec<NewsDraft>) {
+        for club in &country.clubs {
+            if club.board.last_manager_sacked == Some(today) {
+                drafts.push(NewsDraft {
+                    kind: NewsKind::ManagerSacked,
+                    country_id: country.id,
+                    club_id: Some(club.id),
+                    player_id: None,
+                    args: vec![("club", NewsArg::Text(club.name.clone()))],
+                });
+            }
+            if club.board.last_manager_appointed == Some(today) {
+                let manager = club
+                    .teams
+                    .main()
+                    .and_then(|t| t.staffs.manager())
+                    .map(|s| display_name(&s.full_name))
+                    .unwrap_or_default();
+                drafts.push(NewsDraft {
+                    kind: NewsKind::ManagerAppointed,
+                    country_id: country.id,
+                    club_id: Some(club.id),
+                    player_id: None,
+                    args: vec![
+                        ("club", NewsArg::Text(club.name.clone())),
+                        ("manager", NewsArg::Text(manager)),
+                    ],
+                });
+            }
+        }
+    }
+
+    /// First-team players currently out for a long spell. Every injured
+    /// first-teamer is noted in `injured_now`; the feed decides which are
+    /// new.
+    fn injuries(country: &Country, injured_now: &mut HashSet<u32>, drafts: &mut Vec<NewsDraft>) {
+        for club in &country.clubs {
+            let Some(team) = club.teams.main() else {
+                continue;
+            };
+            for player in team.players.players() {
+                let attrs = &player.player_attributes;
+                if !attrs.is_injured {
+                    continue;
+                }
+                injured_now.insert(player.id);
+                if attrs.injury_days_remaining < NOTABLE_INJURY_DAYS {
+                    continue;
+                }
+                let Some(injury) = attrs.injury_type else {
+                    continue;
+                };
+                drafts.push(NewsDraft {
+                    kind: NewsKind::Injury,
+                    country_id: country.id,
+                    club_id: Some(club.id),
+                    player_id: Some(player.id),
+                    args: vec![
+                        ("player", NewsArg::Text(display_name(&player.full_name))),
+                        ("club", NewsArg::Text(club.name.clone())),
+                        ("injury", NewsArg::I18n(injury.as_i18n_key())),
+                        (
+                            "weeks",
+                            NewsArg::Number(attrs.injury_days_remaining.div_ceil(7) as u32),
+                        ),
+                    ],
+                });
+            }
+        }
+    }
+
+    /// Today's competitive thrashings, filed under the winner's country.
+    fn results(data: &SimulatorData, today: NaiveDate, drafts: &mut Vec<NewsDraft>) {
+        for result in data
+            .match_store
+            .iter_in_range(today, today + Duration::days(1))
+        {
+            if result.friendly {
+                continue;
+            }
+            let home_goals = result.score.home_team.get();
+            let away_goals = result.score.away_team.get();
+            if home_goals.abs_diff(away_goals) < NOTABLE_WINNING_MARGIN {
+                continue;
+            }
+            let (Some(home), Some(away)) = (
+                data.team(result.home_team_id),
+                data.team(result.away_team_id),
+            ) else {
+                continue;
+            };
+            let winner = if home_goals > away_goals { home } else { away };
+            let Some(country) = data.country_by_club(winner.club_id) else {
+                continue;
+            };
+            drafts.push(NewsDraft {
+                kind: NewsKind::Result,
+                country_id: country.id,
+                club_id: Some(winner.club_id),
+                player_id: None,
+                args: vec![
+                    ("home", NewsArg::Text(home.name.clone())),
+                    ("away", NewsArg::Text(away.name.clone())),
+                    ("home_goals", NewsArg::Number(home_goals as u32)),
+                    ("away_goals", NewsArg::Number(away_goals as u32)),
+                ],
+            });
+        }
+    }
+}
+
+fn display_name(name: &FullName) -> String {
+    let first = name.display_first_name();
+    let last = name.display_last_name();
+    if first.is_empty() {
+        last.to_string()
+    } else {
+        format!("{} {}", first, last)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::academy::ClubAcademy;
+    use crate::league::LeagueCollection;
+    use crate::shared::Location;
+    use crate::{Club, ClubColors, ClubFacilities, ClubFinances, ClubStatus, TeamCollection};
+
+    fn club(id: u32, sacked: Option<NaiveDate>, appointed: Option<NaiveDate>) -> Club {
+        let mut club = Club::new(
+            id,
+            format!("Club{}", id),
+            Location::new(1),
+            ClubFinances::new(1_000_000, Vec::new()),
+            ClubAcademy::new(3),
+            ClubStatus::Professional,
+            ClubColors::default(),
+            TeamCollection::new(Vec::new()),
+            ClubFacilities::default(),
+        );
+        club.board.last_manager_sacked = sacked;
+        club.board.last_manager_appointed = appointed;
+        club
+    }
+
+    #[test]
+    fn manager_changes_are_news_on_the_day() {
+        let today = NaiveDate::from_ymd_opt(2026, 10, 12).unwrap();
+        let yesterday = Some(today - Duration::days(1));
+        let country = Country::builder()
+            .id(1)
+            .code("en".to_string())
+            .slug("england".to_string())
+            .name("England".to_string())
+            .continent_id(1)
+            .leagues(LeagueCollection::new(Vec::new()))
+            .clubs(vec![
+                club(1, Some(today), Some(today)),
+                club(2, yesterday, None),
+                club(3, None, None),
+            ])
+            .build()
+            .unwrap();
+
+        let mut drafts = Vec::new();
+        NewsDesk::manager_changes(&country, today, &mut drafts);
+
+        let stories: Vec<(Option<u32>, NewsKind)> =
+            drafts.iter().map(|d| (d.club_id, d.kind)).collect();
+        assert_eq!(
+            stories,
+            vec![
+                (Some(1), NewsKind::ManagerSacked),
+                (Some(1), NewsKind::ManagerAppointed),
+            ]
+        );
+        assert_eq!(
+            drafts[0].args,
+            vec![("club", NewsArg::Text("Club1".to_string()))]
+        );
+    }
+}
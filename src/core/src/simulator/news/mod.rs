@@ -0,0 +1,230 @@
+//! World news feed.
+//!
+//! The simulation already records what happened — completed transfers on
+//! each country's market, injuries on the players, results in the match
+//! store, sackings and appointments on the boards. Once a day, after the
+//! manager market has run, [`NewsDesk`] reads the day's events off that
+//! state and files them as dated [`NewsItem`]s on `SimulatorData::news`.
+//!
+//! Items carry no rendered text. A headline is an i18n key plus named
+//! arguments captured at publish time (names, fees, scorelines), so the
+//! web layer renders it in the reader's language and a story still reads
+//! right after the player moves on or retires.
+
+mod desk;
+
+pub use desk::NewsDesk;
+
+use crate::utils::StaticStr;
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashSet, VecDeque};
+
+/// How long a story stays in the feed.
+const NEWS_RETENTION_DAYS: i64 = 365;
+
+/// Hard cap on stored items, whatever the retention window says — a
+/// busy world can file a few hundred stories a week.
+const MAX_NEWS_ITEMS: usize = 5000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NewsKind {
+    Transfer,
+    RecordTransfer,
+    Injury,
+    Result,
+    ManagerSacked,
+    ManagerAppointed,
+}
+
+impl NewsKind {
+    /// i18n key of the headline template. Placeholders are the argument
+    /// names in braces, e.g. `{player}`.
+    pub fn as_i18n_key(&self) -> &'static str {
+        match self {
+            NewsKind::Transfer => "news_transfer",
+            NewsKind::RecordTransfer => "news_record_transfer",
+            NewsKind::Injury => "news_injury",
+            NewsKind::Result => "news_result",
+            NewsKind::ManagerSacked => "news_manager_sacked",
+            NewsKind::ManagerAppointed => "news_manager_appointed",
+        }
+    }
+
+    /// Short, stable tag for feeds and CSS.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NewsKind::Transfer => "transfer",
+            NewsKind::RecordTransfer => "record",
+            NewsKind::Injury => "injury",
+            NewsKind::Result => "result",
+            NewsKind::ManagerSacked => "sacking",
+            NewsKind::ManagerAppointed => "appointment",
+        }
+    }
+}
+
+/// One headline argument. Text is shown as-is; `I18n` is itself a key
+/// the renderer translates (an injury diagnosis); `Money` is formatted
+/// by the renderer.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NewsArg {
+    Text(String),
+    I18n(#[serde(deserialize_with = "crate::utils::deserialize_static_str")] StaticStr),
+    Money(f64),
+    Number(u32),
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NewsItem {
+    pub id: u32,
+    pub date: NaiveDate,
+    pub kind: NewsKind,
+    /// Country whose press runs the story.
+    pub country_id: u32,
+    /// Club the story is about (buyer, injured player's club, winner,
+    /// sacking club).
+    pub club_id: Option<u32>,
+    pub player_id: Option<u32>,
+    #[serde(deserialize_with = "crate::utils::deserialize_static_keyed")]
+    pub args: Vec<(&'static str, NewsArg)>,
+}
+
+impl NewsItem {
+    pub fn arg(&self, name: &str) -> Option<&NewsArg> {
+        self.args.iter().find(|(n, _)| *n == name).map(|(_, a)| a)
+    }
+}
+
+/// Story fields before the feed assigns an id and date.
+#[derive(Debug, Clone)]
+pub struct NewsDraft {
+    pub kind: NewsKind,
+    pub country_id: u32,
+    pub club_id: Option<u32>,
+    pub player_id: Option<u32>,
+    pub args: Vec<(&'static str, NewsArg)>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct NewsFeed {
+    /// Oldest first.
+    items: VecDeque<NewsItem>,
+    next_id: u32,
+    /// Players whose current injury has already been reported, so a
+    /// long layoff makes the news once. Cleared as they recover.
+    reported_injuries: HashSet<u32>,
+}
+
+impl NewsFeed {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn publish(&mut self, date: NaiveDate, draft: NewsDraft) -> u32 {
+        self.next_id += 1;
+        let id = self.next_id;
+        self.items.push_back(NewsItem {
+            id,
+            date,
+            kind: draft.kind,
+            country_id: draft.country_id,
+            club_id: draft.club_id,
+            player_id: draft.player_id,
+            args: draft.args,
+        });
+        id
+    }
+
+    /// Newest first.
+    pub fn latest(&self) -> impl Iterator<Item = &NewsItem> {
+        self.items.iter().rev()
+    }
+
+    pub fn find(&self, id: u32) -> Option<&NewsItem> {
+        self.items.iter().find(|i| i.id == id)
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Drop stories past the retention window, then the oldest beyond
+    /// the hard cap.
+    pub fn trim(&mut self, today: NaiveDate) {
+        let cutoff = today - Duration::days(NEWS_RETENTION_DAYS);
+        while self.items.front().is_some_and(|i| i.date < cutoff) {
+            self.items.pop_front();
+        }
+        while self.items.len() > MAX_NEWS_ITEMS {
+            self.items.pop_front();
+        }
+    }
+
+    /// Record the injured players seen today. Returns `true` when
+    /// `player_id`'s injury had not been reported yet.
+    fn injury_is_news(&mut self, player_id: u32) -> bool {
+        self.reported_injuries.insert(player_id)
+    }
+
+    fn forget_recovered(&mut self, still_injured: &HashSet<u32>) {
+        self.reported_injuries
+            .retain(|id| still_injured.contains(id));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    fn draft() -> NewsDraft {
+        NewsDraft {
+            kind: NewsKind::Result,
+            country_id: 1,
+            club_id: Some(10),
+            player_id: None,
+            args: vec![("home", NewsArg::Text("Home FC".to_string()))],
+        }
+    }
+
+    #[test]
+    fn latest_is_newest_first_with_increasing_ids() {
+        let mut feed = NewsFeed::new();
+        let a = feed.publish(d(2030, 1, 1), draft());
+        let b = feed.publish(d(2030, 1, 2), draft());
+        assert!(b > a);
+        let ids: Vec<u32> = feed.latest().map(|i| i.id).collect();
+        assert_eq!(ids, vec![b, a]);
+        assert_eq!(
+            feed.find(a).and_then(|i| i.arg("home")),
+            Some(&NewsArg::Text("Home FC".to_string()))
+        );
+    }
+
+    #[test]
+    fn trim_drops_stories_past_retention() {
+        let mut feed = NewsFeed::new();
+        feed.publish(d(2030, 1, 1), draft());
+        feed.publish(d(2030, 12, 1), draft());
+        feed.trim(d(2031, 2, 1));
+        assert_eq!(feed.len(), 1);
+        assert_eq!(feed.latest().next().unwrap().date, d(2030, 12, 1));
+    }
+
+    #[test]
+    fn an_injury_is_reported_once_until_recovery() {
+        let mut feed = NewsFeed::new();
+        assert!(feed.injury_is_news(7));
+        assert!(!feed.injury_is_news(7));
+        feed.forget_recovered(&HashSet::new());
+        assert!(feed.injury_is_news(7));
+    }
+}
@@ -0,0 +1,135 @@
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+/// Cumulative count of league simulation passes since process start.
+static LEAGUES_PROCESSED: AtomicU64 = AtomicU64::new(0);
+
+/// Cumulative count of matches played by the engine pool since process
+/// start, local and dispatched to workers alike.
+static MATCHES_SIMULATED: AtomicU64 = AtomicU64::new(0);
+
+/// Cumulative processing time per league id since process start.
+static LEAGUE_TIMINGS: LazyLock<Mutex<HashMap<u32, LeagueTiming>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Process-global work counters for a running simulation tick. A day is
+/// one opaque `FootballSimulator::simulate` call, so a frontend watching
+/// a long run reads these to show movement inside the day. Counters only
+/// grow; take [`SimulationProgress::counters`] at the start of a run and
+/// measure against it with [`ProgressCounters::since`].
+pub struct SimulationProgress;
+
+impl SimulationProgress {
+    pub fn counters() -> ProgressCounters {
+        ProgressCounters {
+            leagues_processed: LEAGUES_PROCESSED.load(Ordering::Relaxed),
+            matches_simulated: MATCHES_SIMULATED.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Record one league's daily pass.
+    pub fn record_league() {
+        LEAGUES_PROCESSED.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record `count` finished matches.
+    pub fn record_matches(count: usize) {
+        MATCHES_SIMULATED.fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    /// Add `elapsed` to the league's processing time. A daily pass is
+    /// timed in two halves around the shared match batch; only the first
+    /// half passes `new_pass`.
+    pub fn record_league_time(league_id: u32, slug: &str, elapsed: Duration, new_pass: bool) {
+        let Ok(mut timings) = LEAGUE_TIMINGS.lock() else {
+            return;
+        };
+        let timing = timings.entry(league_id).or_insert_with(|| LeagueTiming {
+            league_id,
+            slug: slug.to_string(),
+            passes: 0,
+            total: Duration::ZERO,
+        });
+        timing.total += elapsed;
+        if new_pass {
+            timing.passes += 1;
+        }
+    }
+
+    /// Processing time of every league timed so far, by league id.
+    pub fn league_timings() -> Vec<LeagueTiming> {
+        let mut timings: Vec<LeagueTiming> = LEAGUE_TIMINGS
+            .lock()
+            .map(|t| t.values().cloned().collect())
+            .unwrap_or_default();
+        timings.sort_by_key(|t| t.league_id);
+        timings
+    }
+}
+
+/// Cumulative time spent in one league's daily passes, match engine time
+/// excluded.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LeagueTiming {
+    pub league_id: u32,
+    pub slug: String,
+    pub passes: u64,
+    pub total: Duration,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ProgressCounters {
+    pub leagues_processed: u64,
+    pub matches_simulated: u64,
+}
+
+impl ProgressCounters {
+    /// Work done between `baseline` and `self`.
+    pub fn since(&self, baseline: ProgressCounters) -> ProgressCounters {
+        ProgressCounters {
+            leagues_processed: self
+                .leagues_processed
+                .saturating_sub(baseline.leagues_processed),
+            matches_simulated: self
+                .matches_simulated
+                .saturating_sub(baseline.matches_simulated),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counters_measure_work_since_baseline() {
+        let baseline = SimulationProgress::counters();
+        SimulationProgress::record_league();
+        SimulationProgress::record_matches(3);
+        let done = SimulationProgress::counters().since(baseline);
+        // Other tests tick the same process-global counters concurrently.
+        assert!(done.leagues_processed >= 1);
+        assert!(done.matches_simulated >= 3);
+        assert_eq!(
+            baseline.since(SimulationProgress::counters()),
+            ProgressCounters::default()
+        );
+    }
+
+    #[test]
+    fn league_time_adds_both_halves_to_one_pass() {
+        let id = u32::MAX - 7;
+        SimulationProgress::record_league_time(id, "test-league", Duration::from_millis(3), true);
+        SimulationProgress::record_league_time(id, "test-league", Duration::from_millis(2), false);
+
+        let timing = SimulationProgress::league_timings()
+            .into_iter()
+            .find(|t| t.league_id == id)
+            .unwrap();
+        assert_eq!(timing.slug, "test-league");
+        assert_eq!(timing.passes, 1);
+        assert_eq!(timing.total, Duration::from_millis(5));
+    }
+}
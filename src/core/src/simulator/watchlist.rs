@@ -0,0 +1,144 @@
+//! The user's watchlist (shortlist) of transfer targets. Each entry keeps
+//! the player's market value and contract from the day they were added,
+//! so the watchlist page can show how both have moved since. The list is
+//! part of the save and is never cleared between transfer windows.
+
+use super::SimulatorData;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct WatchlistEntry {
+    pub player_id: u32,
+    pub added_on: NaiveDate,
+    pub value_when_added: f64,
+    /// `None` when the player had no club contract (free agent).
+    pub contract_expiry_when_added: Option<NaiveDate>,
+    pub salary_when_added: Option<u32>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Watchlist {
+    entries: Vec<WatchlistEntry>,
+}
+
+impl Watchlist {
+    pub fn contains(&self, player_id: u32) -> bool {
+        self.entries.iter().any(|e| e.player_id == player_id)
+    }
+
+    pub fn get(&self, player_id: u32) -> Option<&WatchlistEntry> {
+        self.entries.iter().find(|e| e.player_id == player_id)
+    }
+
+    /// Entries in the order they were added.
+    pub fn entries(&self) -> &[WatchlistEntry] {
+        &self.entries
+    }
+
+    /// Adds `entry` unless the player is already listed, in which case the
+    /// original baseline is kept. Returns whether the entry was added.
+    pub fn add(&mut self, entry: WatchlistEntry) -> bool {
+        if self.contains(entry.player_id) {
+            return false;
+        }
+        self.entries.push(entry);
+        true
+    }
+
+    /// Returns whether the player was listed.
+    pub fn remove(&mut self, player_id: u32) -> bool {
+        let before = self.entries.len();
+        self.entries.retain(|e| e.player_id != player_id);
+        self.entries.len() != before
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl SimulatorData {
+    /// Market value of an active player, priced against their club and
+    /// league reputation; free agents are priced without either. `None`
+    /// for retired or unknown players.
+    pub fn player_market_value(&self, player_id: u32) -> Option<f64> {
+        let today = self.date.date();
+        if let Some((player, team)) = self.player_with_team(player_id) {
+            let league_reputation = team
+                .league_id
+                .and_then(|id| self.league(id))
+                .map(|l| l.reputation)
+                .unwrap_or(0);
+            return Some(player.value(
+                today,
+                league_reputation,
+                team.reputation.market_value_score(),
+            ));
+        }
+        self.free_agents
+            .iter()
+            .find(|p| p.id == player_id)
+            .map(|p| p.value(today, 0, 0))
+    }
+
+    /// Put `player_id` on the watchlist with today's value and contract as
+    /// the baseline. Returns `false` for retired or unknown players; a
+    /// player already listed keeps their original baseline.
+    pub fn add_to_watchlist(&mut self, player_id: u32) -> bool {
+        let Some(value) = self.player_market_value(player_id) else {
+            return false;
+        };
+        let contract = self.player(player_id).and_then(|p| p.contract.as_ref());
+        let entry = WatchlistEntry {
+            player_id,
+            added_on: self.date.date(),
+            value_when_added: value,
+            contract_expiry_when_added: contract.map(|c| c.expiration),
+            salary_when_added: contract.map(|c| c.salary),
+        };
+        self.watchlist.add(entry);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(player_id: u32, value: f64) -> WatchlistEntry {
+        WatchlistEntry {
+            player_id,
+            added_on: NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+            value_when_added: value,
+            contract_expiry_when_added: None,
+            salary_when_added: None,
+        }
+    }
+
+    #[test]
+    fn re_adding_keeps_the_original_baseline() {
+        let mut watchlist = Watchlist::default();
+        assert!(watchlist.add(entry(7, 1_000_000.0)));
+        assert!(!watchlist.add(entry(7, 5_000_000.0)));
+
+        assert_eq!(watchlist.len(), 1);
+        assert_eq!(watchlist.get(7).unwrap().value_when_added, 1_000_000.0);
+    }
+
+    #[test]
+    fn remove_reports_whether_the_player_was_listed() {
+        let mut watchlist = Watchlist::default();
+        watchlist.add(entry(7, 1.0));
+        watchlist.add(entry(9, 1.0));
+
+        assert!(watchlist.remove(7));
+        assert!(!watchlist.remove(7));
+        assert!(!watchlist.contains(7));
+        assert!(watchlist.contains(9));
+    }
+}
@@ -1,4 +1,5 @@
 use crate::shared::CurrencyValue;
+use crate::transfers::market_index::MarketIndex;
 use crate::transfers::negotiation::{NegotiationStatus, TransferNegotiation};
 use crate::transfers::offer::TransferOffer;
 use crate::transfers::{CompletedTransfer, TransferType};
@@ -6,8 +7,9 @@ use chrono::Duration;
 use chrono::{Datelike, NaiveDate};
 use rustc_hash::{FxHashMap, FxHashSet};
 use std::collections::HashMap;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferMarket {
     pub listings: Vec<TransferListing>,
     pub negotiations: HashMap<u32, TransferNegotiation>,
@@ -21,6 +23,14 @@ pub struct TransferMarket {
     /// at country tick time. Empty for clubs that haven't bought
     /// anyone with installment-style deals.
     pub pending_clauses: Vec<PendingTransferClause>,
+    /// Budget inflation and positional scarcity, recalibrated as each
+    /// window opens. Applied on top of the country's price level.
+    pub market_index: MarketIndex,
+    /// Clubs human managers control, mirrored from `SimulatorData` so the
+    /// daily resolver can park those clubs' decisions for their managers.
+    /// Foreign bids live in the buyer's market, so every country carries
+    /// the full list.
+    pub user_club_ids: Vec<u32>,
 }
 
 /// A future financial obligation arising from a clause that fires
@@ -34,7 +44,7 @@ pub struct TransferMarket {
 /// `TransferClause` enum. Resolving an installment is a date check;
 /// resolving an appearance / goal / promotion bonus is a counter check
 /// the caller threads in.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PendingTransferClause {
     /// Unique id within this market — used for cancellation / debug.
     pub id: u32,
@@ -69,7 +79,7 @@ pub struct PendingTransferClause {
     pub created_on: Option<NaiveDate>,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum ClauseTrigger {
     /// Fire on a specific calendar date. The settler compares
     /// `today >= scheduled_date` and `fires_so_far < max_fires`.
@@ -87,7 +97,7 @@ pub enum ClauseTrigger {
     Promotion,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferListing {
     pub player_id: u32,
     pub club_id: u32,
@@ -110,14 +120,14 @@ pub struct TransferListing {
     pub origin: TransferListingOrigin,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransferListingType {
     Transfer,
     Loan,
     EndOfContract,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TransferListingOrigin {
     /// Selling club listed the player for permanent transfer.
     SellerListed,
@@ -132,7 +142,7 @@ pub enum TransferListingOrigin {
     SyntheticUnsolicited,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransferListingStatus {
     Available,
     InNegotiation,
@@ -208,6 +218,8 @@ impl TransferMarket {
             transfer_history: Vec::new(),
             next_negotiation_id: 1,
             pending_clauses: Vec::new(),
+            market_index: MarketIndex::default(),
+            user_club_ids: Vec::new(),
         }
     }
 
@@ -761,6 +773,11 @@ impl TransferMarket {
         }
     }
 
+    /// Whether a human manager controls `club_id`.
+    pub fn is_user_club(&self, club_id: u32) -> bool {
+        self.user_club_ids.contains(&club_id)
+    }
+
     /// Check if a specific player already has an active negotiation from a given buyer.
     pub fn has_active_negotiation_for(&self, player_id: u32, buying_club_id: u32) -> bool {
         self.negotiations.values().any(|n| {
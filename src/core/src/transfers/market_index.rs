@@ -0,0 +1,202 @@
+//! Market-side pricing conditions for one country.
+//!
+//! `PlayerValueCalculator` prices the player: ability, age, potential,
+//! contract, form, reputation and the seller's league and club. What it
+//! can't see is the market the player is sold into — how much money the
+//! country's clubs have to spend this window, and whether buyers are
+//! queueing for centre-backs while strikers sit unsold on the list.
+//!
+//! [`MarketIndex`] carries those two signals and is recalibrated each
+//! time a window opens:
+//!
+//! * **Inflation** follows the country's total transfer budget against
+//!   the budget seen at the first calibration. Budgets come from board
+//!   targets set off revenue, not from player values, so the index moves
+//!   with the league's money rather than feeding back on its own fees.
+//! * **Scarcity** compares, per position group, open transfer requests
+//!   (demand) with players listed for sale (supply).
+//!
+//! Both move halfway toward their new target per window and stay inside
+//! fixed bands, so fees drift over seasons instead of jumping.
+
+use crate::transfers::TransferRequestStatus;
+use crate::transfers::market::{TransferListingStatus, TransferListingType};
+use crate::{Country, Player, PlayerFieldPositionGroup};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+const GROUPS: usize = PlayerFieldPositionGroup::COUNT;
+
+/// Bounds on the budget-driven inflation multiplier.
+const INFLATION_MIN: f32 = 0.75;
+const INFLATION_MAX: f32 = 1.5;
+
+/// Bounds on a position group's scarcity multiplier.
+const SCARCITY_MIN: f32 = 0.85;
+const SCARCITY_MAX: f32 = 1.2;
+
+/// Pseudo-count added to both sides of the demand/supply ratio so a
+/// group with one request and no listings isn't priced at the ceiling.
+const SCARCITY_PRIOR: f32 = 4.0;
+
+/// Share of the gap to the new target closed at each recalibration.
+const SMOOTHING: f32 = 0.5;
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MarketIndex {
+    /// Country-wide fee multiplier. 1.0 at the first calibration.
+    pub inflation: f32,
+    /// Per position-group multiplier, indexed by
+    /// [`PlayerFieldPositionGroup::index`].
+    pub scarcity: [f32; GROUPS],
+    /// Total transfer budget seen at the first calibration — the anchor
+    /// inflation is measured against. Zero until then.
+    baseline_budget: f64,
+    pub recalibrated_on: Option<NaiveDate>,
+}
+
+impl Default for MarketIndex {
+    fn default() -> Self {
+        MarketIndex {
+            inflation: 1.0,
+            scarcity: [1.0; GROUPS],
+            baseline_budget: 0.0,
+            recalibrated_on: None,
+        }
+    }
+}
+
+impl MarketIndex {
+    /// Market multiplier for `player`: inflation times the scarcity of
+    /// the player's position group.
+    pub fn factor_for(&self, player: &Player) -> f32 {
+        self.inflation * self.scarcity[player.position().position_group().index()]
+    }
+
+    /// `base_price_level` (the country's static pricing) adjusted for
+    /// current market conditions — the value to hand to
+    /// `PlayerValuationCalculator::calculate_value_with_price_level`.
+    pub fn price_level(&self, base_price_level: f32, player: &Player) -> f32 {
+        base_price_level * self.factor_for(player)
+    }
+
+    /// Re-read the country's budgets, requests and listings and move the
+    /// index toward them. Called when a transfer window opens.
+    pub fn recalibrate(country: &mut Country, today: NaiveDate) {
+        let total_budget: f64 = country
+            .clubs
+            .iter()
+            .filter_map(|c| c.finance.transfer_budget.as_ref())
+            .map(|b| b.amount.max(0.0))
+            .sum();
+
+        let mut demand = [0u32; GROUPS];
+        for club in &country.clubs {
+            for request in &club.transfer_plan.transfer_requests {
+                if matches!(
+                    request.status,
+                    TransferRequestStatus::Fulfilled | TransferRequestStatus::Abandoned
+                ) {
+                    continue;
+                }
+                demand[request.position.position_group().index()] += 1;
+            }
+        }
+
+        let mut supply = [0u32; GROUPS];
+        for listing in &country.transfer_market.listings {
+            if listing.listing_type != TransferListingType::Transfer
+                || !matches!(
+                    listing.status,
+                    TransferListingStatus::Available | TransferListingStatus::InNegotiation
+                )
+            {
+                continue;
+            }
+            if let Some(player) = country.player(listing.player_id) {
+                supply[player.position().position_group().index()] += 1;
+            }
+        }
+
+        country
+            .transfer_market
+            .market_index
+            .update(total_budget, &demand, &supply, today);
+    }
+
+    fn update(
+        &mut self,
+        total_budget: f64,
+        demand: &[u32; GROUPS],
+        supply: &[u32; GROUPS],
+        today: NaiveDate,
+    ) {
+        if self.baseline_budget <= 0.0 {
+            self.baseline_budget = total_budget;
+        }
+        if self.baseline_budget > 0.0 {
+            let target = ((total_budget / self.baseline_budget).sqrt() as f32)
+                .clamp(INFLATION_MIN, INFLATION_MAX);
+            self.inflation = smooth(self.inflation, target).clamp(INFLATION_MIN, INFLATION_MAX);
+        }
+
+        for group in 0..GROUPS {
+            let ratio =
+                (demand[group] as f32 + SCARCITY_PRIOR) / (supply[group] as f32 + SCARCITY_PRIOR);
+            let target = ratio.powf(0.25).clamp(SCARCITY_MIN, SCARCITY_MAX);
+            self.scarcity[group] =
+                smooth(self.scarcity[group], target).clamp(SCARCITY_MIN, SCARCITY_MAX);
+        }
+
+        self.recalibrated_on = Some(today);
+    }
+}
+
+fn smooth(current: f32, target: f32) -> f32 {
+    current + (target - current) * SMOOTHING
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn first_calibration_anchors_inflation_at_neutral() {
+        let mut index = MarketIndex::default();
+        index.update(50_000_000.0, &[0; GROUPS], &[0; GROUPS], d(2030, 7, 1));
+        assert_eq!(index.inflation, 1.0);
+        assert_eq!(index.recalibrated_on, Some(d(2030, 7, 1)));
+    }
+
+    #[test]
+    fn richer_league_inflates_gradually_within_bounds() {
+        let mut index = MarketIndex::default();
+        index.update(50_000_000.0, &[0; GROUPS], &[0; GROUPS], d(2030, 7, 1));
+        index.update(200_000_000.0, &[0; GROUPS], &[0; GROUPS], d(2031, 1, 1));
+        let after_one = index.inflation;
+        assert!(after_one > 1.0 && after_one < INFLATION_MAX);
+        for _ in 0..20 {
+            index.update(200_000_000.0, &[0; GROUPS], &[0; GROUPS], d(2031, 7, 1));
+        }
+        assert!(index.inflation > after_one);
+        assert!(index.inflation <= INFLATION_MAX);
+    }
+
+    #[test]
+    fn scarce_positions_price_up_and_glutted_ones_down() {
+        let mut index = MarketIndex::default();
+        // Ten clubs chasing defenders, few on the list; forwards the
+        // other way round.
+        let demand = [0, 10, 2, 1];
+        let supply = [0, 1, 2, 12];
+        index.update(1.0, &demand, &supply, d(2030, 7, 1));
+        assert_eq!(index.scarcity[0], 1.0);
+        assert!(index.scarcity[1] > 1.0);
+        assert_eq!(index.scarcity[2], 1.0);
+        assert!(index.scarcity[3] < 1.0);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod country_pair_policy;
 pub mod market;
+pub mod market_index;
 pub mod negotiation;
 pub mod offer;
 pub mod pipeline;
@@ -11,6 +12,7 @@ pub mod window;
 
 pub use country_pair_policy::*;
 pub use market::*;
+pub use market_index::*;
 pub use negotiation::*;
 pub use offer::*;
 pub use pipeline::*;
@@ -3,8 +3,9 @@ use crate::transfers::offer::TransferOffer;
 use crate::utils::IntegerUtils;
 use chrono::Duration;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum NegotiationPhase {
     /// Selling club decides whether to engage (1-3 days)
     InitialApproach { started: NaiveDate },
@@ -18,7 +19,7 @@ pub enum NegotiationPhase {
     MedicalAndFinalization { started: NaiveDate },
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum NegotiationRejectionReason {
     SellerRefusedToNegotiate,
     AskingPriceTooHigh,
@@ -33,9 +34,30 @@ pub enum NegotiationRejectionReason {
     /// [`crate::transfers::TransferRoutePolicy`] route block list on the
     /// current sim date (today: Russia ↔ Ukraine after 2022-02-24).
     CountryPairRouteBlocked,
+    /// The buying country's nationality rules refuse the player — no
+    /// work permit, or no non-bloc slot left at the buyer.
+    WorkPermitRefused,
+    /// The user's club pulled its bid.
+    BuyerWithdrew,
 }
 
-#[derive(Debug, PartialEq, Clone)]
+/// Where a negotiation involving the user's club is parked. Each step
+/// hands one decision to the user (or, after a user counter, back to the
+/// AI buyer); every other phase runs exactly as it does between AI clubs.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum UserNegotiationStep {
+    /// A bid for one of the user's players waits to be accepted, rejected
+    /// or countered.
+    AwaitingUserResponse,
+    /// The user countered with `fee`; the buyer answers on its next tick.
+    AwaitingBuyer { fee: f64 },
+    /// The seller turned the user's bid down and names the fee it wants.
+    AwaitingUserBid { fee: f64 },
+    /// The player holds out for `wage` a year before agreeing terms.
+    AwaitingUserContract { wage: u32 },
+}
+
+#[derive(Debug, PartialEq, Clone, Serialize, Deserialize)]
 pub enum NegotiationStatus {
     Pending,
     Accepted,
@@ -44,7 +66,7 @@ pub enum NegotiationStatus {
     Expired,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferNegotiation {
     pub id: u32,
     pub player_id: u32,
@@ -123,6 +145,9 @@ pub struct TransferNegotiation {
     /// re-opened the loan over-accumulation hole. `None` for permanent
     /// deals and for legacy rows (the fold then falls back to the lookup).
     pub loan_target_profile: Option<(PlayerFieldPositionGroup, u8)>,
+    /// Set while the negotiation waits on (or for an answer to) the
+    /// user's club. `None` for AI-to-AI talks.
+    pub user_step: Option<UserNegotiationStep>,
 }
 
 impl TransferNegotiation {
@@ -187,6 +212,7 @@ impl TransferNegotiation {
             foreign_terms_floor_blocked: false,
             foreign_seller_importance: None,
             loan_target_profile: None,
+            user_step: None,
         }
     }
 
@@ -292,6 +318,25 @@ impl TransferNegotiation {
             .unwrap_or(current_date);
     }
 
+    /// True while the next move belongs to the user's club.
+    pub fn is_awaiting_user(&self) -> bool {
+        matches!(
+            self.user_step,
+            Some(UserNegotiationStep::AwaitingUserResponse)
+                | Some(UserNegotiationStep::AwaitingUserBid { .. })
+                | Some(UserNegotiationStep::AwaitingUserContract { .. })
+        )
+    }
+
+    /// Make the current phase resolve on the next tick — used when the
+    /// user answers and the other side should react without waiting out
+    /// a fresh phase timer.
+    pub fn resolve_next_tick(&mut self, current_date: NaiveDate) {
+        self.phase_expiry = current_date
+            .checked_add_signed(Duration::days(1))
+            .unwrap_or(current_date);
+    }
+
     pub fn reject_with_reason(&mut self, reason: NegotiationRejectionReason) {
         self.rejection_reason = Some(reason);
         self.status = NegotiationStatus::Rejected;
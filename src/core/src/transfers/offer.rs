@@ -1,6 +1,7 @@
 use crate::shared::Currency;
 use crate::shared::CurrencyValue;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 /// An offer placed in the transfer market. The base fee + optional
 /// clauses model the *club-to-club* side of the deal. The optional
@@ -8,7 +9,7 @@ use chrono::NaiveDate;
 /// installed on the player's contract once the move closes — keeping
 /// the two sides separate avoids the wage/length numbers leaking into
 /// the seller-side acceptance maths.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferOffer {
     pub base_fee: CurrencyValue,
     pub clauses: Vec<TransferClause>,
@@ -43,7 +44,7 @@ pub struct TransferOffer {
 ///
 /// Fields are intentionally `Option` where they're "use the calculator
 /// default if absent" — the execution layer fills only what is staged.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct PersonalTermsOffer {
     /// Annual salary the buyer commits to.
     pub annual_wage: Option<u32>,
@@ -69,7 +70,7 @@ pub struct PersonalTermsOffer {
 /// A subset of [`PlayerSquadStatus`] (only the roles that come up as
 /// realistic public promises) so the negotiation can't accidentally
 /// commit to internal states like `NotYetSet` or `Invalid`.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum PromisedSquadStatus {
     KeyPlayer,
     FirstTeamRegular,
@@ -77,7 +78,7 @@ pub enum PromisedSquadStatus {
     HotProspectForTheFuture,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransferClause {
     AppearanceFee(CurrencyValue, u32),  // Money after X appearances
     GoalBonus(CurrencyValue, u32),      // Money after X goals
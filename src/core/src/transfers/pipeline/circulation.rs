@@ -238,6 +238,7 @@ impl PipelineProcessor {
         let performance_lookup = LeaguePerformanceLookup::build(country);
 
         let price_level = country.settings.pricing.price_level;
+        let market_index = country.transfer_market.market_index;
 
         // ── Phase 1: collect per-player actions (immutable). ──
         let mut actions: HashMap<u32, CirculationAction> = HashMap::new();
@@ -299,7 +300,7 @@ impl PipelineProcessor {
                     let value = PlayerValuationCalculator::calculate_value_with_price_level(
                         player,
                         date,
-                        price_level,
+                        market_index.price_level(price_level, player),
                         seller_league_rep,
                         seller_club_rep,
                     )
@@ -377,7 +378,7 @@ impl PipelineProcessor {
                                 // Cheap gates pass — confirm the realism
                                 // (step-down / country-route) gates too.
                                 let inputs = TransferPlausibilityBuilder::from_clubs(
-                                    country, buyer, seller, player, value, false, true, date,
+                                    country, buyer, seller, player, value, 0.0, false, true, date,
                                 );
                                 match TransferPlausibilityEvaluator::evaluate(&inputs) {
                                     TransferPlausibilityVerdict::HardReject(pr) => {
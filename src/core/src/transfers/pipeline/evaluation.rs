@@ -551,19 +551,10 @@ impl PipelineProcessor {
 
         // Calculate budget. Clubs in FFP breach have half their buying
         // power until the losses unwind — a soft equivalent of the
-        // real-world transfer ban / spending cap.
-        let raw_budget = club
-            .finance
-            .transfer_budget
-            .as_ref()
-            .map(|b| b.amount)
-            .unwrap_or_else(|| (club.finance.balance.balance.max(0) as f64) * 0.3);
+        // real-world transfer ban / spending cap — and insolvent clubs
+        // have none.
         let ffp_breach = club.finance.is_ffp_breach(date);
-        let budget = if ffp_breach {
-            raw_budget * 0.5
-        } else {
-            raw_budget
-        };
+        let budget = club.finance.available_transfer_budget(date);
 
         if club.teams.teams.is_empty() {
             return SquadEvaluation {
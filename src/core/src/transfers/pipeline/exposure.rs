@@ -240,7 +240,9 @@ impl MarketDiscoveryDiagnosis {
         reason: TransferPlausibilityReason,
     ) -> AvailabilityBlockReason {
         match reason {
-            TransferPlausibilityReason::CountryPairBlocked => {
+            TransferPlausibilityReason::CountryPairBlocked
+            | TransferPlausibilityReason::WorkPermitRefused
+            | TransferPlausibilityReason::NonEuQuotaFull => {
                 AvailabilityBlockReason::CountryRegionBlocked
             }
             TransferPlausibilityReason::DomesticStepDownForPrimeStarter
@@ -11,14 +11,20 @@ use crate::transfers::pipeline::{
     DetailedScoutingReport, ReportRiskFlag, ScoutingRecommendation, TransferNeedReason,
     TransferRequest,
 };
-use crate::transfers::window::{PlayerValuationCalculator, TransferCalendar};
+use crate::transfers::window::{
+    AGREEMENT_PRE_OPEN_DAYS, PlayerValuationCalculator, TransferCalendar, TransferWindowManager,
+};
 use crate::utils::FormattingUtils;
 use crate::{
-    Club, Country, Person, Player, PlayerFieldPositionGroup, PlayerSquadStatus, PlayerStatusType,
-    ReputationLevel, StaffPosition, TeamType,
+    Club, Country, Person, PersonAttributes, Player, PlayerFieldPositionGroup, PlayerSquadStatus, PlayerStatusType,
+    ReputationLevel, SigningEligibility, StaffPosition, TeamType,
 };
 use chrono::Weekday;
 
+/// Deadline urgency from which the weekly market sweeps run daily —
+/// the final week of a window.
+const DEADLINE_DAILY_URGENCY: f32 = 0.5;
+
 impl PipelineProcessor {
     /// European-calendar fallback for the mid-season window bias. Kept
     /// only for the pure decision fns that carry no country context
@@ -34,7 +40,7 @@ impl PipelineProcessor {
     /// fixes" mid-season bias, which the raw month==1 check applied to
     /// the wrong month everywhere outside Europe.
     pub(super) fn is_mid_season_window_for(country: &Country, date: NaiveDate) -> bool {
-        let w = TransferCalendar::for_country(&country.code, date);
+        let w = TransferCalendar::of(country, date);
         let (s_start, s_end) = w.summer_window;
         let (w_start, w_end) = w.winter_window;
         let summer_len = (s_end - s_start).num_days();
@@ -48,38 +54,35 @@ impl PipelineProcessor {
         }
     }
 
-    /// Full plan reset at the opening of each of the COUNTRY's transfer
-    /// windows — the opening day and the day before it, mirroring the
-    /// old May 31 + June 1 double for the European calendar. The
+    /// Full plan reset when each of the COUNTRY's markets begins — the
+    /// day pre-window talks open (a fortnight before registration) and
+    /// the day before it, so deals agreed early belong to the new
+    /// window's plan rather than being wiped at the formal opening. The
     /// hard-coded European triple reset Latam plans MID-window (their
     /// Dec–Jan window spans New Year, so Jan 1 wiped requests,
     /// shortlists, and the spent/reserved ledger halfway through their
     /// market) and never reset MLS-style calendars at their real
     /// openings at all.
     pub(super) fn is_window_start_for(country: &Country, date: NaiveDate) -> bool {
-        let tomorrow = date.checked_add_signed(chrono::Duration::days(1)).unwrap_or(date);
-        // Anchor the calendar on both dates: a window opening Jan 1
-        // belongs to next year's anchor when today is Dec 31.
-        let today_cal = TransferCalendar::for_country(&country.code, date);
-        let tomorrow_cal = TransferCalendar::for_country(&country.code, tomorrow);
-        [
-            today_cal.summer_window.0,
-            today_cal.winter_window.0,
-            tomorrow_cal.summer_window.0,
-            tomorrow_cal.winter_window.0,
-        ]
-        .into_iter()
-        .any(|start| date == start || tomorrow == start)
+        TransferCalendar::next_opening(country, date)
+            .map(|open| (open - date).num_days())
+            .is_some_and(|days| {
+                days == AGREEMENT_PRE_OPEN_DAYS || days == AGREEMENT_PRE_OPEN_DAYS + 1
+            })
     }
 
-    /// Re-evaluate during the COUNTRY's transfer windows.
-    /// Daily during the first week of each window for fast pipeline
-    /// startup, then weekly (Monday) for the rest of the window. The
-    /// old hard-coded June/January cadence starved every non-European
-    /// calendar: an MLS-style Feb–Apr window got no evaluation ticks
-    /// (and no staff recommendations) for its entire duration.
+    /// Re-evaluate during the COUNTRY's markets, pre-window talks
+    /// included. Daily during the first week of talks and of each
+    /// registration window for fast pipeline startup, then weekly
+    /// (Monday). The old hard-coded June/January cadence starved every
+    /// non-European calendar: an MLS-style Feb–Apr window got no
+    /// evaluation ticks (and no staff recommendations) for its entire
+    /// duration.
     pub(super) fn should_evaluate_for(country: &Country, date: NaiveDate) -> bool {
-        let w = TransferCalendar::for_country(&country.code, date);
+        if let Some(days) = TransferCalendar::days_to_opening(country, date) {
+            return AGREEMENT_PRE_OPEN_DAYS - days < 7 || date.weekday() == Weekday::Mon;
+        }
+        let w = TransferCalendar::of(country, date);
         for (start, end) in [w.summer_window, w.winter_window] {
             if date >= start && date <= end {
                 let days_in = (date - start).num_days();
@@ -89,6 +92,17 @@ impl PipelineProcessor {
         false
     }
 
+    /// Weekly market sweeps — the unsolicited loan scan, listing
+    /// broadcasts, recruitment meetings — run on Mondays, then daily
+    /// through the final week of a window. Clubs still short after
+    /// the summer's slow weeks scramble for loans and panic buys, which
+    /// is what gives deadline day its spike.
+    pub(super) fn is_market_day(country: &Country, date: NaiveDate) -> bool {
+        date.weekday() == Weekday::Mon
+            || TransferWindowManager::for_country(country, date).deadline_urgency(country.id, date)
+                >= DEADLINE_DAILY_URGENCY
+    }
+
     pub fn transfer_need_reason_text(reason: &TransferNeedReason) -> &'static str {
         match reason {
             TransferNeedReason::FormationGap => "Formation gap — no player for required position",
@@ -264,7 +278,10 @@ impl PipelineProcessor {
         let estimated_value = PlayerValuationCalculator::calculate_value_with_price_level(
             player,
             date,
-            country.settings.pricing.price_level,
+            country
+                .transfer_market
+                .market_index
+                .price_level(country.settings.pricing.price_level, player),
             league_reputation,
             club_reputation,
         )
@@ -307,6 +324,7 @@ impl PipelineProcessor {
             country_id: country.id,
             continent_id: country.continent_id,
             region: ScoutingRegion::from_country(country.continent_id, &country.code),
+            eligibility: SigningEligibility::of(player),
             country_code: country.code.clone(),
             player_name: player.full_name.to_string(),
             club_name: club.name.clone(),
@@ -330,6 +348,7 @@ impl PipelineProcessor {
             work_rate: player.skills.mental.work_rate,
             composure: player.skills.mental.composure,
             anticipation: player.skills.mental.anticipation,
+            personality: player.attributes,
             technical_avg: player.skills.technical.average(),
             mental_avg: player.skills.mental.average(),
             physical_avg: player.skills.physical.average(),
@@ -361,6 +380,7 @@ impl PipelineProcessor {
         contract_months_remaining: i16,
         player_world_rep: i16,
         buyer_world_rep: i16,
+        personality: &PersonAttributes,
     ) -> Vec<ReportRiskFlag> {
         super::scouting_config::ScoutingConfig::default().risk_flags_for(
             is_injured,
@@ -369,6 +389,7 @@ impl PipelineProcessor {
             contract_months_remaining,
             player_world_rep,
             buyer_world_rep,
+            personality,
         )
     }
 
@@ -561,7 +582,7 @@ impl PipelineProcessor {
         let base_value = PlayerValuationCalculator::calculate_value_with_price_level(
             player,
             date,
-            price_level,
+            country.transfer_market.market_index.price_level(price_level, player),
             league_rep,
             club_rep,
         );
@@ -1103,7 +1124,7 @@ mod tier_helper_tests {
         let names = PeopleNameGeneratorData {
             first_names: vec!["Tier".to_string()],
             last_names: vec!["Tester".to_string()],
-            nicknames: Vec::new(),
+            ..Default::default()
         };
         let bd = NaiveDate::from_ymd_opt(2000, 1, 1).unwrap();
         let player =
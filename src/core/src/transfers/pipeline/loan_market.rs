@@ -1,10 +1,9 @@
-use chrono::{Datelike, NaiveDate, Weekday};
+use chrono::{Datelike, NaiveDate};
 use log::debug;
 
 use crate::club::player::behaviour_config::HappinessConfig;
 use crate::club::team::squad::{SquadAssetClass, SquadAssetContext};
 use crate::shared::{Currency, CurrencyValue};
-use crate::transfers::ScoutingRegion;
 use crate::transfers::market::{
     TransferListing, TransferListingOrigin, TransferListingStatus, TransferListingType,
 };
@@ -16,6 +15,7 @@ use crate::transfers::pipeline::plausibility::{
 use crate::transfers::pipeline::processor::{PipelineProcessor, PlayerSummary};
 use crate::transfers::pipeline::{AvailabilityBroadcast, LoanOutStatus, TransferRequestStatus};
 use crate::transfers::window::PlayerValuationCalculator;
+use crate::transfers::{ScoutingRegion, TransferWindowManager};
 use crate::utils::FormattingUtils;
 use crate::{
     ClubPhilosophy, Country, HappinessEventCause, HappinessEventContext, HappinessEventScope,
@@ -122,6 +122,8 @@ impl PipelineProcessor {
 
     pub fn scan_loan_market(country: &mut Country, date: NaiveDate) {
         let is_january = Self::is_mid_season_window_for(country, date);
+        let deadline_urgency =
+            TransferWindowManager::for_country(country, date).deadline_urgency(country.id, date);
 
         // Collect available loan listings (Pass 1 read)
         struct LoanListing {
@@ -218,12 +220,13 @@ impl PipelineProcessor {
         // player on loan even when that player has NOT been loan-listed — the
         // `Loa` badge is not a precondition for loan demand. Built once per
         // pass on a weekly (Monday) cadence so the squad-wide scan stays
-        // cheap; the listed-market scan above stays daily. Eligibility is the
+        // cheap — daily through the deadline week, when clubs scramble
+        // for loan cover; the listed-market scan above stays daily. Eligibility is the
         // central squad-asset classifier's job (see `UnsolicitedLoanTarget`):
         // young prospects and rotation players go as development loans and
         // genuine surplus goes at any age up to the loan cap — but a
         // first-team contributor is never cold-approached.
-        let scan_unsolicited = date.weekday() == Weekday::Mon;
+        let scan_unsolicited = Self::is_market_day(country, date);
         let mut unsolicited_targets: Vec<LoanListing> = Vec::new();
         if scan_unsolicited {
             for club in &country.clubs {
@@ -409,7 +412,7 @@ impl PipelineProcessor {
             let actual_active = country
                 .transfer_market
                 .active_negotiation_count_for_club(club.id);
-            if actual_active >= plan.max_concurrent_negotiations {
+            if actual_active >= plan.negotiation_cap(deadline_urgency) {
                 continue;
             }
 
@@ -882,8 +885,8 @@ impl PipelineProcessor {
     pub fn broadcast_listed_loans(country: &mut Country, date: NaiveDate) {
         // Weekly cadence — the squad-wide push is heavier than the daily
         // listed-market scan, and a placement decision needn't be revisited
-        // every day.
-        if date.weekday() != Weekday::Mon {
+        // every day until the deadline week.
+        if !Self::is_market_day(country, date) {
             return;
         }
 
@@ -1248,7 +1251,7 @@ impl PipelineProcessor {
     /// push, or the player leaves on a free.
     pub fn broadcast_listed_transfers(country: &mut Country, date: NaiveDate) {
         // Weekly cadence, mirroring the loan push.
-        if date.weekday() != Weekday::Mon {
+        if !Self::is_market_day(country, date) {
             return;
         }
 
@@ -2171,6 +2174,7 @@ impl PipelineProcessor {
         }
 
         let price_level = country.settings.pricing.price_level;
+        let market_index = country.transfer_market.market_index;
         let listing = {
             let Some(club) = country.clubs.iter().find(|c| c.id == club_id) else {
                 return false;
@@ -2204,7 +2208,7 @@ impl PipelineProcessor {
                 let full_value = PlayerValuationCalculator::calculate_value_with_price_level(
                     player,
                     date,
-                    price_level,
+                    market_index.price_level(price_level, player),
                     seller_league_rep,
                     seller_club_rep,
                 );
@@ -3163,6 +3167,8 @@ mod scan_loan_market_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
@@ -3624,7 +3630,7 @@ mod transfer_broadcast_tests {
         PlayerPositions, PlayerSkills, PlayerSquadStatus, StaffCollection, TeamBuilder,
         TeamCollection, TeamReputation, TeamType, TrainingSchedule,
     };
-    use chrono::{Duration, NaiveTime};
+    use chrono::{Duration, NaiveTime, Weekday};
 
     struct Fx;
 
@@ -3726,6 +3732,8 @@ mod transfer_broadcast_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
@@ -13,6 +13,9 @@ mod recruitment_meeting;
 mod scouting;
 pub(crate) mod scouting_config;
 mod shortlists;
+mod user_negotiations;
+
+use serde::{Deserialize, Serialize};
 
 use crate::transfers::ScoutingRegion;
 use crate::{PlayerFieldPositionGroup, PlayerPositionType, ReputationLevel};
@@ -28,13 +31,19 @@ pub use self::recruitment::{
     ScoutMonitoringSource, ScoutMonitoringStatus, ScoutPlayerMonitoring, ScoutVote,
     ScoutVoteChoice, ScoutVoteReason,
 };
+pub use self::user_negotiations::{
+    UserContractOffer, UserNegotiationResponse, UserTransferBid, UserTransferError,
+};
 use chrono::Duration;
 use std::cmp::Ordering;
 
 mod processor {
     use crate::club::team::squad::SquadAssetClass;
     use crate::transfers::ScoutingRegion;
-    use crate::{PlayerFieldPositionGroup, PlayerPositionType, PlayerSquadStatus};
+    use crate::{
+        PersonAttributes, PlayerFieldPositionGroup, PlayerPositionType, PlayerSquadStatus,
+        SigningEligibility,
+    };
     use std::collections::HashMap;
 
     /// PipelineProcessor handles all daily transfer pipeline logic.
@@ -140,6 +149,9 @@ mod processor {
         pub work_rate: f32,
         pub composure: f32,
         pub anticipation: f32,
+        /// Hidden personality. Never shown as numbers — a scout's report
+        /// surfaces it only as character risk flags.
+        pub personality: PersonAttributes,
         pub technical_avg: f32,
         pub mental_avg: f32,
         pub physical_avg: f32,
@@ -167,6 +179,9 @@ mod processor {
         /// the summary so cross-country buyers can assess a foreign target
         /// with the same rigour as a domestic one.
         pub seller_ctx: SellerPlausibilityContext,
+        /// Nationality, caps and ability as the buying country's signing
+        /// rules see them.
+        pub eligibility: SigningEligibility,
         /// Home scouting region, precomputed once at pool-build time. The
         /// cross-country loan/scout filters used to re-derive this (a
         /// country-code string match) for every foreign player on every
@@ -179,7 +194,7 @@ mod processor {
 // Transfer Need Priority & Reason
 // ============================================================
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransferNeedPriority {
     Critical,
     Important,
@@ -205,7 +220,7 @@ impl TransferNeedPriority {
 }
 
 /// Why the coach is requesting this position - derived from tactical analysis.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransferNeedReason {
     /// Formation requires this position and we have no one (e.g. 4-2-3-1 needs AMC, we have none)
     FormationGap,
@@ -254,7 +269,7 @@ impl TransferNeedReason {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum TransferRequestStatus {
     Pending,
     ScoutingActive,
@@ -299,7 +314,7 @@ impl TransferRequestStatus {
 /// staged by the country-level emergency planner with zero budget and
 /// must only ever be serviced by the free-agent matcher — the scouting,
 /// market-shortlist, and loan paths skip them.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum TransferRequestSource {
     /// Weekly evaluation / staff recommendation — full paid pipeline.
     Evaluation,
@@ -307,7 +322,7 @@ pub enum TransferRequestSource {
     EmergencyFreeAgentDepth,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferRequest {
     pub id: u32,
     pub position: PlayerPositionType,
@@ -397,7 +412,7 @@ impl TransferRequest {
 // PlayerObservation - Tracks multi-day observations per player
 // ============================================================
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PlayerObservation {
     pub player_id: u32,
     pub observation_count: u32,
@@ -472,7 +487,7 @@ impl PlayerObservation {
 // ScoutingAssignment - DoF assigns scouts to find candidates
 // ============================================================
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoutingAssignment {
     pub id: u32,
     pub transfer_request_id: u32,
@@ -493,7 +508,7 @@ pub struct ScoutingAssignment {
 /// Drives both scouting focus and shortlist scoring: a player who meets
 /// the ability bar but fails the role profile scores below a slightly
 /// lower-ability candidate who matches the profile.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct RoleProfile {
     pub min_technical_avg: f32,
     pub min_mental_avg: f32,
@@ -573,7 +588,7 @@ impl ScoutingAssignment {
 // DetailedScoutingReport - Scout's final assessment (3+ obs)
 // ============================================================
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DetailedScoutingReport {
     pub player_id: u32,
     pub assignment_id: u32,
@@ -591,7 +606,7 @@ pub struct DetailedScoutingReport {
     pub risk_flags: Vec<ReportRiskFlag>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReportRiskFlag {
     /// Currently injured — bid timing risk
     CurrentlyInjured,
@@ -603,9 +618,15 @@ pub enum ReportRiskFlag {
     ContractExpiring,
     /// Player is over 30 — age risk for long-term contracts
     AgeRisk,
+    /// Short fuse — cards, suspensions and dressing-room flashpoints
+    Temperamental,
+    /// Poor habits in training — development will lag the talent
+    Unprofessional,
+    /// Ambitious with little loyalty — will push for wages and the next move
+    FlightRisk,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ScoutingRecommendation {
     StrongBuy,
     Buy,
@@ -642,6 +663,9 @@ impl ReportRiskFlag {
             ReportRiskFlag::WageDemands => "risk_wage_demands",
             ReportRiskFlag::ContractExpiring => "risk_contract_expiring",
             ReportRiskFlag::AgeRisk => "risk_age_risk",
+            ReportRiskFlag::Temperamental => "risk_temperamental",
+            ReportRiskFlag::Unprofessional => "risk_unprofessional",
+            ReportRiskFlag::FlightRisk => "risk_flight_risk",
         }
     }
 }
@@ -650,7 +674,7 @@ impl ReportRiskFlag {
 // TransferShortlist - DoF's ranked candidate list per position
 // ============================================================
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum ShortlistCandidateStatus {
     Available,
     CurrentlyPursuing,
@@ -670,7 +694,7 @@ pub enum TransferApproach {
     Loan,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShortlistCandidate {
     pub player_id: u32,
     pub score: f32,
@@ -678,7 +702,7 @@ pub struct ShortlistCandidate {
     pub status: ShortlistCandidateStatus,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct TransferShortlist {
     pub transfer_request_id: u32,
     pub candidates: Vec<ShortlistCandidate>,
@@ -724,7 +748,7 @@ impl TransferShortlist {
 // LoanOutCandidate - Players identified for loan out
 // ============================================================
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum LoanOutReason {
     /// Young player needs regular first-team football to develop (elite/continental clubs)
     NeedsGameTime,
@@ -782,7 +806,7 @@ impl LoanOutReason {
     }
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum LoanOutStatus {
     Identified,
     Listed,
@@ -790,7 +814,7 @@ pub enum LoanOutStatus {
     Completed,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoanOutCandidate {
     pub player_id: u32,
     pub reason: LoanOutReason,
@@ -807,7 +831,7 @@ pub struct LoanOutCandidate {
 /// parents) and [`ClubTransferPlan::transfer_broadcasts`] (permanent
 /// listings gone stale); lives only while the listing is live and the
 /// player isn't already in a negotiation.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AvailabilityBroadcast {
     /// Reputation tier the player is currently being offered to. Starts at
     /// the parent club's own tier and steps down via
@@ -822,7 +846,7 @@ pub struct AvailabilityBroadcast {
 // Staff Recommendations - Proactive player identification
 // ============================================================
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RecommendationSource {
     ScoutNetwork,
     ChiefScoutReport,
@@ -831,7 +855,7 @@ pub enum RecommendationSource {
     HeadCoach,
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum RecommendationType {
     /// Contract <= 6 months
     ExpiringContract,
@@ -859,7 +883,7 @@ pub enum RecommendationType {
     YouthMatchStandout,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct StaffRecommendation {
     pub player_id: u32,
     pub recommender_staff_id: u32,
@@ -875,7 +899,7 @@ pub struct StaffRecommendation {
 /// Persistent club-level knowledge of a player. Unlike active scouting
 /// assignments, this survives transfers and loan returns, so a club can
 /// remember a foreign player who spent a few months in its league.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct KnownPlayerMemory {
     pub player_id: u32,
     pub last_known_club_id: u32,
@@ -895,7 +919,7 @@ pub struct KnownPlayerMemory {
 // ScoutMatchAssignment - Scout assigned to watch a youth/reserve match
 // ============================================================
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScoutMatchAssignment {
     pub scout_staff_id: u32,
     pub target_team_id: u32,
@@ -908,7 +932,7 @@ pub struct ScoutMatchAssignment {
 // ClubTransferPlan - Top-level state per club
 // ============================================================
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClubTransferPlan {
     pub total_budget: f64,
     pub spent: f64,
@@ -1000,7 +1024,7 @@ pub struct ClubTransferPlan {
 
 /// A scouting report preserved past its originating assignment, used to
 /// bootstrap future shortlists without discarding long-tracked targets.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ShadowReport {
     pub report: DetailedScoutingReport,
     pub position_group: PlayerFieldPositionGroup,
@@ -1088,6 +1112,25 @@ impl ClubTransferPlan {
         self.active_negotiation_count < self.max_concurrent_negotiations
     }
 
+    /// Concurrent negotiations the club may run given how close the
+    /// window is to closing (`deadline_urgency`, 0..1). A club still
+    /// missing a critical signing in the final days panics and chases
+    /// extra targets at once — up to two more on deadline day.
+    pub fn negotiation_cap(&self, deadline_urgency: f32) -> u32 {
+        let critical_gap = self.transfer_requests.iter().any(|r| {
+            r.priority == TransferNeedPriority::Critical
+                && !matches!(
+                    r.status,
+                    TransferRequestStatus::Fulfilled | TransferRequestStatus::Abandoned
+                )
+        });
+        if !critical_gap {
+            return self.max_concurrent_negotiations;
+        }
+        let panic_slots = (deadline_urgency.clamp(0.0, 1.0) * 2.0).round() as u32;
+        self.max_concurrent_negotiations + panic_slots
+    }
+
     pub fn has_pending_requests(&self) -> bool {
         self.transfer_requests
             .iter()
@@ -1644,3 +1687,28 @@ mod known_player_memory_tests {
         assert_eq!(known.last_seen, d(2026, 7, 8));
     }
 }
+
+#[cfg(test)]
+mod negotiation_cap_tests {
+    use super::*;
+
+    #[test]
+    fn critical_gap_opens_panic_slots_near_the_deadline() {
+        let mut plan = ClubTransferPlan::new();
+        plan.max_concurrent_negotiations = 2;
+        assert_eq!(plan.negotiation_cap(1.0), 2);
+
+        let id = plan.next_request_id();
+        plan.transfer_requests.push(TransferRequest::new(
+            id,
+            PlayerPositionType::DefenderCenter,
+            TransferNeedPriority::Critical,
+            TransferNeedReason::FormationGap,
+            100,
+            120,
+            1_000_000.0,
+        ));
+        assert_eq!(plan.negotiation_cap(0.0), 2);
+        assert_eq!(plan.negotiation_cap(1.0), 4);
+    }
+}
@@ -141,11 +141,13 @@ impl PipelineProcessor {
         let price_level = country.settings.pricing.price_level;
         let window_mgr = TransferWindowManager::for_country(country, date);
         let current_window = window_mgr.current_window_dates(country.id, date);
+        let deadline_urgency = window_mgr.deadline_urgency(country.id, date);
 
         for club in &country.clubs {
             let plan = &club.transfer_plan;
+            let negotiation_cap = plan.negotiation_cap(deadline_urgency);
 
-            if !plan.initialized || !plan.can_start_negotiation() {
+            if !plan.initialized || plan.active_negotiation_count >= negotiation_cap {
                 continue;
             }
 
@@ -163,7 +165,7 @@ impl PipelineProcessor {
             let actual_active = country
                 .transfer_market
                 .active_negotiation_count_for_club(club.id);
-            if actual_active >= plan.max_concurrent_negotiations {
+            if actual_active >= negotiation_cap {
                 continue;
             }
 
@@ -172,17 +174,7 @@ impl PipelineProcessor {
             // escalation strategy full spending power while the plan
             // itself was operating on half, so the two layers disagreed
             // about the same sanction.
-            let raw_budget = club
-                .finance
-                .transfer_budget
-                .as_ref()
-                .map(|b| b.amount)
-                .unwrap_or_else(|| (club.finance.balance.balance.max(0) as f64) * 0.3);
-            let budget = if club.finance.is_ffp_breach(date) {
-                raw_budget * 0.5
-            } else {
-                raw_budget
-            };
+            let budget = club.finance.available_transfer_budget(date);
 
             if club.teams.teams.is_empty() {
                 continue;
@@ -216,9 +208,7 @@ impl PipelineProcessor {
                 .as_ref()
                 .map(|t| (t.wage_budget.max(0) as f64 - committed_wages).max(0.0));
 
-            let slots_available = plan
-                .max_concurrent_negotiations
-                .saturating_sub(actual_active) as usize;
+            let slots_available = negotiation_cap.saturating_sub(actual_active) as usize;
             let mut negotiations_this_club = 0usize;
 
             for shortlist in &plan.shortlists {
@@ -648,6 +638,7 @@ impl PipelineProcessor {
                         selling_club,
                         player,
                         candidate.estimated_fee,
+                        offer.base_fee.amount,
                         is_loan,
                         true, // unsolicited at the negotiation-entry point
                         date,
@@ -886,7 +877,7 @@ impl PipelineProcessor {
     /// so a KeyPlayer is offered a KeyPlayer wage rather than the plain market
     /// figure his demand then towered over. `months_remaining` is neutral here
     /// — it only widens the acceptable band, never `expected_wage`.
-    fn buyer_wage_context(
+    pub(super) fn buyer_wage_context(
         player: &Player,
         age: u8,
         buyer_reputation_score: f32,
@@ -1819,9 +1810,12 @@ impl PipelineProcessor {
         let mut candidates: Vec<ForeignCandidate> = Vec::new();
 
         if let Some(country) = data.country(country_id) {
+            let deadline_urgency = TransferWindowManager::for_country(country, date)
+                .deadline_urgency(country_id, date);
             for club in &country.clubs {
                 let plan = &club.transfer_plan;
-                if !plan.initialized || !plan.can_start_negotiation() {
+                let negotiation_cap = plan.negotiation_cap(deadline_urgency);
+                if !plan.initialized || plan.active_negotiation_count >= negotiation_cap {
                     continue;
                 }
 
@@ -1836,7 +1830,7 @@ impl PipelineProcessor {
                 let actual_active = country
                     .transfer_market
                     .active_negotiation_count_for_club(club.id);
-                if actual_active >= plan.max_concurrent_negotiations {
+                if actual_active >= negotiation_cap {
                     continue;
                 }
 
@@ -1844,9 +1838,7 @@ impl PipelineProcessor {
                 // one-shot cap check above let a club with N shortlists
                 // open N foreign negotiations in a single tick, blowing
                 // past `max_concurrent_negotiations`.
-                let slots_available = plan
-                    .max_concurrent_negotiations
-                    .saturating_sub(actual_active) as usize;
+                let slots_available = negotiation_cap.saturating_sub(actual_active) as usize;
                 let mut negotiations_this_club = 0usize;
 
                 for shortlist in &plan.shortlists {
@@ -2160,6 +2152,7 @@ impl PipelineProcessor {
                 sell_club,
                 player,
                 asking_price.amount,
+                0.0, // no bid yet — the offer is built once this gate passes
                 is_loan,
                 true, // unsolicited — the buyer is reaching out abroad
                 date,
@@ -2565,6 +2558,8 @@ mod cleanup_tests {
                 relegation_spots: 0,
                 league_group: None,
                 split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
             },
             false,
         )
@@ -3462,6 +3457,8 @@ mod dev_pathway_cleanup_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             );
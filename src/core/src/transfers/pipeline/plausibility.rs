@@ -40,7 +40,7 @@
 //! a listing to back our bid" listings are excluded: callers populate the
 //! listing flags from the player's real status only.
 
-use crate::{PlayerFieldPositionGroup, PlayerSquadStatus};
+use crate::{PlayerFieldPositionGroup, PlayerSquadStatus, SigningRestriction};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TransferPlausibilityReason {
@@ -57,6 +57,19 @@ pub enum TransferPlausibilityReason {
     /// active rule today is Russia ↔ Ukraine from 2022-02-24 onwards;
     /// the simulation refuses these moves at every stage.
     CountryPairBlocked,
+    /// The buying country's work-permit rule refuses this player.
+    WorkPermitRefused,
+    /// The buyer has no non-bloc registration slot left for him.
+    NonEuQuotaFull,
+}
+
+impl From<SigningRestriction> for TransferPlausibilityReason {
+    fn from(restriction: SigningRestriction) -> Self {
+        match restriction {
+            SigningRestriction::WorkPermitRefused => TransferPlausibilityReason::WorkPermitRefused,
+            SigningRestriction::NonEuQuotaFull => TransferPlausibilityReason::NonEuQuotaFull,
+        }
+    }
 }
 
 // ============================================================
@@ -352,6 +365,10 @@ pub struct TransferPlausibilityInputs {
     /// by the input builders. When set, [`TransferPlausibilityEvaluator::evaluate`]
     /// short-circuits with `CountryPairBlocked` before any other gate.
     pub country_pair_blocked: bool,
+    /// The buying country's nationality rules (work permit, non-bloc
+    /// slots) applied to this player at this buyer. Same short-circuit
+    /// as a blocked route.
+    pub signing_restriction: Option<SigningRestriction>,
 
     pub buyer_transfer_budget: f64,
     pub buyer_wage_budget: u32,
@@ -765,6 +782,9 @@ impl TransferMovePlausibility {
                 Some(TransferPlausibilityReason::CountryPairBlocked),
             );
         }
+        if let Some(restriction) = inputs.signing_restriction {
+            return make(TransferMoveStage::Blocked, Some(restriction.into()));
+        }
 
         let important = importance >= thresholds::IMPORTANT;
         let prime_age =
@@ -1055,7 +1075,9 @@ impl TransferMovePlausibility {
 use crate::Player;
 use crate::club::player::calculators::WageCalculator;
 use crate::transfers::pipeline::{PipelineProcessor, PlayerSummary};
-use crate::{Club, Country, Person, PlayerStatusType, TeamType};
+use crate::{
+    Club, Country, CountryRegulations, Person, PlayerStatusType, SigningEligibility, TeamType,
+};
 use chrono::NaiveDate;
 
 /// Per-club buyer snapshot reused across plausibility lookups so the
@@ -1071,6 +1093,10 @@ pub(crate) struct BuyerPlausibilityContext {
     pub buyer_country_id: u32,
     pub buyer_country_code: String,
     pub buyer_league_id: Option<u32>,
+    pub buyer_continent_id: u32,
+    pub buyer_regulations: CountryRegulations,
+    /// Non-bloc players already in the buyer's main squad.
+    pub buyer_non_bloc_held: usize,
 }
 
 impl BuyerPlausibilityContext {
@@ -1101,6 +1127,13 @@ impl BuyerPlausibilityContext {
             .as_ref()
             .map(|b| b.amount)
             .unwrap_or(club.transfer_plan.total_budget);
+        let buyer_non_bloc_held = main_team
+            .filter(|_| country.regulations.non_eu_player_limit.is_some())
+            .map(|t| {
+                let players: Vec<&Player> = t.players.players.iter().collect();
+                CountryRegulations::non_bloc_count(&players, country.id, country.continent_id)
+            })
+            .unwrap_or(0);
         BuyerPlausibilityContext {
             buyer_rep,
             buyer_world_rep,
@@ -1111,8 +1144,24 @@ impl BuyerPlausibilityContext {
             buyer_country_id: country.id,
             buyer_country_code: country.code.clone(),
             buyer_league_id,
+            buyer_continent_id: country.continent_id,
+            buyer_regulations: country.regulations.clone(),
+            buyer_non_bloc_held,
         }
     }
+
+    /// The buyer country's nationality rules applied to `candidate`.
+    pub(crate) fn signing_restriction(
+        &self,
+        candidate: &SigningEligibility,
+    ) -> Option<SigningRestriction> {
+        self.buyer_regulations.signing_restriction(
+            candidate,
+            self.buyer_country_id,
+            self.buyer_continent_id,
+            self.buyer_non_bloc_held,
+        )
+    }
 }
 
 /// Stateless namespace for the pipeline-facing input builders. Wrapped
@@ -1159,6 +1208,7 @@ impl TransferPlausibilityBuilder {
             &buyer_ctx.buyer_country_code,
             date,
         );
+        let signing_restriction = buyer_ctx.signing_restriction(&target.eligibility);
 
         let expected_annual_wage = WageCalculator::expected_annual_wage_raw(
             player_ca,
@@ -1201,6 +1251,7 @@ impl TransferPlausibilityBuilder {
             same_country,
             same_league_or_division,
             country_pair_blocked,
+            signing_restriction,
             buyer_transfer_budget: buyer_ctx.buyer_transfer_budget,
             buyer_wage_budget: buyer_ctx.buyer_wage_budget,
             buyer_total_wages: buyer_ctx.buyer_total_wages,
@@ -1254,6 +1305,10 @@ impl TransferPlausibilityBuilder {
     /// (seller → buyer) route. When the seller context genuinely cannot be
     /// resolved the caller — not this builder — decides the fallback; this
     /// builder always returns a populated input set from the refs given.
+    ///
+    /// `offered_fee` is the bid actually on the table — the only amount a
+    /// release clause is measured against. Pass `0.0` where no bid has
+    /// been made yet (interest scans, the pre-offer gates).
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn from_global(
         buying_country: &Country,
@@ -1262,6 +1317,7 @@ impl TransferPlausibilityBuilder {
         selling_club: &Club,
         player: &Player,
         estimated_value: f64,
+        offered_fee: f64,
         is_loan: bool,
         is_unsolicited: bool,
         date: NaiveDate,
@@ -1308,13 +1364,20 @@ impl TransferPlausibilityBuilder {
             })
             .unwrap_or((PlayerSquadStatus::NotYetSet, 0, 0));
 
-        let release_clause_triggered = player
-            .contract
-            .as_ref()
-            .map(|c| c.release_clause_triggered(0.0, false).is_some())
-            .unwrap_or(false);
-
         let same_country = buying_country.id == selling_country.id;
+
+        // A release clause fires when the bid on the table meets the
+        // clause fee for this buyer's side of the border. Loans never
+        // trigger one — the clause buys the registration outright.
+        let release_clause_triggered = !is_loan
+            && player
+                .contract
+                .as_ref()
+                .map(|c| {
+                    c.release_clause_triggered(offered_fee, !same_country)
+                        .is_some()
+                })
+                .unwrap_or(false);
         let same_league_or_division = same_country
             && match (buyer_ctx.buyer_league_id, seller_league_id) {
                 (Some(a), Some(b)) => a == b,
@@ -1336,6 +1399,7 @@ impl TransferPlausibilityBuilder {
             &buying_country.code,
             date,
         );
+        let signing_restriction = buyer_ctx.signing_restriction(&SigningEligibility::of(player));
 
         TransferPlausibilityInputs {
             buyer_rep: buyer_ctx.buyer_rep,
@@ -1368,6 +1432,7 @@ impl TransferPlausibilityBuilder {
             same_country,
             same_league_or_division,
             country_pair_blocked,
+            signing_restriction,
             buyer_transfer_budget: buyer_ctx.buyer_transfer_budget,
             buyer_wage_budget: buyer_ctx.buyer_wage_budget,
             buyer_total_wages: buyer_ctx.buyer_total_wages,
@@ -1387,6 +1452,7 @@ impl TransferPlausibilityBuilder {
         selling_club: &Club,
         player: &Player,
         estimated_value: f64,
+        offered_fee: f64,
         is_loan: bool,
         is_unsolicited: bool,
         date: NaiveDate,
@@ -1398,6 +1464,7 @@ impl TransferPlausibilityBuilder {
             selling_club,
             player,
             estimated_value,
+            offered_fee,
             is_loan,
             is_unsolicited,
             date,
@@ -1425,6 +1492,7 @@ mod tests {
             player_age: 26,
             position_group: PlayerFieldPositionGroup::Goalkeeper,
             country_pair_blocked: false,
+            signing_restriction: None,
             is_listed: false,
             is_loan_listed: false,
             is_transfer_requested: false,
@@ -1468,6 +1536,7 @@ mod tests {
             player_age: 26,
             position_group: PlayerFieldPositionGroup::Midfielder,
             country_pair_blocked: false,
+            signing_restriction: None,
             is_listed: false,
             is_loan_listed: false,
             is_transfer_requested: false,
@@ -2286,6 +2355,7 @@ mod tests {
             player_age: 27,
             position_group: PlayerFieldPositionGroup::Goalkeeper,
             country_pair_blocked: false,
+            signing_restriction: None,
             is_listed: false,
             is_loan_listed: false,
             is_transfer_requested: false,
@@ -2487,3 +2557,170 @@ mod tests {
         assert!(a.blocking_reason.is_some());
     }
 }
+
+#[cfg(test)]
+mod builder_tests {
+    use super::*;
+    use crate::club::academy::ClubAcademy;
+    use crate::league::LeagueCollection;
+    use crate::shared::Location;
+    use crate::shared::fullname::FullName;
+    use crate::{
+        Club, ClubColors, ClubFacilities, ClubFinances, ClubStatus, ContractClause,
+        ContractClauseType, PersonAttributes, PlayerAttributes, PlayerBuilder, PlayerClubContract,
+        PlayerPosition, PlayerPositionType, PlayerPositions, PlayerSkills, TeamCollection,
+    };
+
+    /// Fixtures for the full-reference builder. Wrapped in a unit struct
+    /// per the project's no-free-helpers convention.
+    struct BuilderFixtures;
+
+    impl BuilderFixtures {
+        fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+            NaiveDate::from_ymd_opt(y, m, day).unwrap()
+        }
+
+        fn club(id: u32) -> Club {
+            Club::new(
+                id,
+                format!("Club {id}"),
+                Location::new(1),
+                ClubFinances::new(50_000_000, Vec::new()),
+                ClubAcademy::new(3),
+                ClubStatus::Professional,
+                ClubColors::default(),
+                TeamCollection::new(Vec::new()),
+                ClubFacilities::default(),
+            )
+        }
+
+        fn country(id: u32, code: &str) -> Country {
+            Country::builder()
+                .id(id)
+                .code(code.to_string())
+                .slug(code.to_string())
+                .name(code.to_string())
+                .continent_id(1)
+                .leagues(LeagueCollection::new(Vec::new()))
+                .clubs(Vec::new())
+                .build()
+                .unwrap()
+        }
+
+        fn player_with_clause(clause: ContractClauseType, fee: i32) -> Player {
+            let mut player = PlayerBuilder::new()
+                .id(100)
+                .full_name(FullName::new("Release".to_string(), "Clause".to_string()))
+                .birth_date(Self::d(2000, 1, 1))
+                .country_id(1)
+                .attributes(PersonAttributes::default())
+                .skills(PlayerSkills::default())
+                .positions(PlayerPositions {
+                    positions: vec![PlayerPosition {
+                        position: PlayerPositionType::Striker,
+                        level: 18,
+                    }],
+                })
+                .player_attributes(PlayerAttributes::default())
+                .build()
+                .unwrap();
+            let mut contract = PlayerClubContract::new(1_000_000, Self::d(2030, 6, 30));
+            contract.clauses.push(ContractClause::new(fee, clause));
+            player.contract = Some(contract);
+            player
+        }
+    }
+
+    #[test]
+    fn release_clause_triggers_on_a_bid_matching_the_fee() {
+        let country = BuilderFixtures::country(1, "es");
+        let (buyer, seller) = (BuilderFixtures::club(1), BuilderFixtures::club(2));
+        let player =
+            BuilderFixtures::player_with_clause(ContractClauseType::MinimumFeeRelease, 20_000_000);
+        let date = BuilderFixtures::d(2026, 7, 1);
+        // Valued well above the clause: only the bid decides whether it fires.
+        let valuation = 30_000_000.0;
+
+        let below = TransferPlausibilityBuilder::from_clubs(
+            &country,
+            &buyer,
+            &seller,
+            &player,
+            valuation,
+            19_999_999.0,
+            false,
+            true,
+            date,
+        );
+        assert!(
+            !below.release_clause_triggered,
+            "a bid under the clause must not buy it out, whatever the valuation"
+        );
+
+        for bid in [20_000_000.0, 25_000_000.0] {
+            let matching = TransferPlausibilityBuilder::from_clubs(
+                &country, &buyer, &seller, &player, valuation, bid, false, true, date,
+            );
+            assert!(matching.release_clause_triggered, "bid {bid}");
+            assert_eq!(
+                matching.availability_strength(),
+                AvailabilityStrength::Forced
+            );
+        }
+
+        let loan = TransferPlausibilityBuilder::from_clubs(
+            &country,
+            &buyer,
+            &seller,
+            &player,
+            valuation,
+            20_000_000.0,
+            true,
+            true,
+            date,
+        );
+        assert!(
+            !loan.release_clause_triggered,
+            "loans never buy out a clause"
+        );
+    }
+
+    #[test]
+    fn foreign_only_clause_ignores_domestic_bidders() {
+        let home = BuilderFixtures::country(1, "es");
+        let abroad = BuilderFixtures::country(2, "en");
+        let (buyer, seller) = (BuilderFixtures::club(1), BuilderFixtures::club(2));
+        let player = BuilderFixtures::player_with_clause(
+            ContractClauseType::MinimumFeeReleaseToForeignClubs,
+            20_000_000,
+        );
+        let date = BuilderFixtures::d(2026, 7, 1);
+
+        let domestic = TransferPlausibilityBuilder::from_clubs(
+            &home,
+            &buyer,
+            &seller,
+            &player,
+            25_000_000.0,
+            25_000_000.0,
+            false,
+            true,
+            date,
+        );
+        assert!(!domestic.release_clause_triggered);
+
+        let foreign = TransferPlausibilityBuilder::from_global(
+            &abroad,
+            &buyer,
+            &home,
+            &seller,
+            &player,
+            25_000_000.0,
+            25_000_000.0,
+            false,
+            true,
+            date,
+        );
+        assert!(foreign.release_clause_triggered);
+    }
+}
@@ -395,6 +395,7 @@ impl PipelineProcessor {
 
         let is_january = Self::is_mid_season_window_for(country, date);
         let price_level = country.settings.pricing.price_level;
+        let market_index = country.transfer_market.market_index;
         let window_mgr = TransferWindowManager::for_country(country, date);
         let current_window = window_mgr.current_window_dates(country.id, date);
 
@@ -498,7 +499,7 @@ impl PipelineProcessor {
                             let value = PlayerValuationCalculator::calculate_value_with_price_level(
                                 player,
                                 date,
-                                price_level,
+                                market_index.price_level(price_level, player),
                                 seller_league_rep,
                                 seller_club_rep,
                             );
@@ -23,7 +23,7 @@
 //! then a mutable pass writes the decision back into each club's
 //! `ClubTransferPlan`.
 
-use chrono::{Datelike, NaiveDate};
+use chrono::NaiveDate;
 use log::debug;
 
 use crate::club::staff::Staff;
@@ -40,7 +40,6 @@ use crate::transfers::pipeline::{
 };
 use crate::utils::IntegerUtils;
 use crate::{Country, StaffEventType};
-use chrono::Weekday;
 use std::cmp::Ordering;
 
 /// Captured snapshot of a scout for vote calculation. Decoupling
@@ -97,10 +96,11 @@ impl PipelineProcessor {
     /// recommendations + shadow reports matching active requests, and
     /// produces a `RecruitmentMeeting` per club.
     ///
-    /// No-op outside windows / non-Monday dates so it can be called
-    /// unconditionally from the country tick.
+    /// No-op outside windows / non-market days (Mondays, daily in the
+    /// deadline week) so it can be called unconditionally from the
+    /// country tick.
     pub fn run_recruitment_meetings(country: &mut Country, date: NaiveDate) {
-        if date.weekday() != Weekday::Mon {
+        if !Self::is_market_day(country, date) {
             return;
         }
 
@@ -840,7 +840,9 @@ fn vote_from_monitoring(
     if budget_fit > 2.0 {
         return (ScoutVoteChoice::Reject, ScoutVoteReason::TooExpensive);
     }
-    if m.risk_flags.contains(&ReportRiskFlag::PoorAttitude) && scout.discipline >= 12 {
+    let character_concern = m.risk_flags.contains(&ReportRiskFlag::PoorAttitude)
+        || m.risk_flags.contains(&ReportRiskFlag::Unprofessional);
+    if character_concern && scout.discipline >= 12 {
         return (ScoutVoteChoice::Reject, ScoutVoteReason::PoorAttitude);
     }
 
@@ -964,6 +966,22 @@ mod tests {
         assert_eq!(choice, ScoutVoteChoice::Monitor);
     }
 
+    #[test]
+    fn unprofessional_flag_rejects_with_a_disciplined_scout() {
+        let mut m = fresh_monitoring(1, 99);
+        m.risk_flags = vec![ReportRiskFlag::Unprofessional];
+
+        let (choice, reason) = vote_from_monitoring(&m, &baseline_scout(1), 0.6);
+        assert_eq!(choice, ScoutVoteChoice::Reject);
+        assert_eq!(reason, ScoutVoteReason::PoorAttitude);
+
+        // A lax scout looks past it and judges the fit.
+        let mut lax = baseline_scout(2);
+        lax.discipline = 8;
+        let (choice, _) = vote_from_monitoring(&m, &lax, 0.6);
+        assert_ne!(choice, ScoutVoteChoice::Reject);
+    }
+
     #[test]
     fn poor_role_fit_with_low_adaptability_rejects() {
         let mut scout = baseline_scout(1);
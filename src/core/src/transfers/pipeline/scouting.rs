@@ -22,7 +22,7 @@ use crate::transfers::window::PlayerValuationCalculator;
 use crate::utils::IntegerUtils;
 use crate::{
     Club, ClubPhilosophy, Country, Person, PlayerFieldPositionGroup, PlayerSquadStatus,
-    PlayerStatusType, StaffEventType, StaffPosition, TeamType,
+    PlayerStatusType, SigningEligibility, StaffEventType, StaffPosition, TeamType,
 };
 use chrono::Weekday;
 use rayon::prelude::*;
@@ -636,7 +636,10 @@ impl PipelineProcessor {
                             PlayerValuationCalculator::calculate_value_with_price_level(
                                 player,
                                 current_date,
-                                country.settings.pricing.price_level,
+                                country
+                                    .transfer_market
+                                    .market_index
+                                    .price_level(country.settings.pricing.price_level, player),
                                 target_league_rep,
                                 target_club_rep,
                             );
@@ -657,6 +660,7 @@ impl PipelineProcessor {
                             contract_months,
                             player.player_attributes.world_reputation,
                             Self::club_world_reputation(club),
+                            &player.attributes,
                         );
                         let role_fit = assignment.role_profile.fit(
                             player.skills.technical.average(),
@@ -803,6 +807,7 @@ impl PipelineProcessor {
     /// Collect player summaries from a country for cross-country scouting.
     pub fn collect_player_pool(country: &Country, date: NaiveDate) -> Vec<PlayerSummary> {
         let price_level = country.settings.pricing.price_level;
+        let market_index = country.transfer_market.market_index;
         let country_id = country.id;
         let country_reputation = country.reputation;
         // Constant across every player in this country — derive it once
@@ -839,7 +844,7 @@ impl PipelineProcessor {
                     let value = PlayerValuationCalculator::calculate_value_with_price_level(
                         player,
                         date,
-                        price_level,
+                        market_index.price_level(price_level, player),
                         seller_league_rep,
                         seller_club_rep,
                     );
@@ -866,6 +871,7 @@ impl PipelineProcessor {
                         country_id,
                         continent_id: country.continent_id,
                         region: country_region,
+                        eligibility: SigningEligibility::of(player),
                         country_code: country.code.clone(),
                         player_name: player.full_name.to_string(),
                         club_name: club.name.clone(),
@@ -891,6 +897,7 @@ impl PipelineProcessor {
                         work_rate: player.skills.mental.work_rate,
                         composure: player.skills.mental.composure,
                         anticipation: player.skills.mental.anticipation,
+                        personality: player.attributes,
                         technical_avg: player.skills.technical.average(),
                         mental_avg: player.skills.mental.average(),
                         physical_avg: player.skills.physical.average(),
@@ -1441,6 +1448,7 @@ impl PipelineProcessor {
                         target.contract_months_remaining,
                         target.world_reputation,
                         Self::club_world_reputation(club),
+                        &target.personality,
                     );
 
                     // Always update the monitoring row when a real scout
@@ -14,10 +14,10 @@
 //! per-save overrides can be plumbed in later without re-touching the
 //! call sites.
 
-use crate::PlayerSquadStatus;
 use crate::transfers::ReportRiskFlag;
 use crate::transfers::pipeline::plausibility::EffectivePlayerReputation;
 use crate::transfers::pipeline::{PlayerSummary, ScoutingRecommendation};
+use crate::{PersonAttributes, PlayerSquadStatus};
 
 // ============================================================
 // Sub-configs grouped by concern
@@ -134,6 +134,13 @@ pub struct RiskFlagConfig {
     pub contract_expiring_months_max: i16,
     /// `player_rep > buyer_rep + threshold` → WageDemands.
     pub wage_demands_rep_gap: i16,
+    /// `temperament < threshold` → Temperamental.
+    pub temperamental_temperament_max: f32,
+    /// `professionalism < threshold` → Unprofessional.
+    pub unprofessional_professionalism_max: f32,
+    /// `loyalty < threshold` (with ambition above the next one) → FlightRisk.
+    pub flight_risk_loyalty_max: f32,
+    pub flight_risk_ambition_min: f32,
 }
 
 #[derive(Debug, Clone)]
@@ -325,6 +332,10 @@ impl Default for ScoutingConfig {
                 age_risk_min: 31,
                 contract_expiring_months_max: 6,
                 wage_demands_rep_gap: 1500,
+                temperamental_temperament_max: 7.0,
+                unprofessional_professionalism_max: 7.0,
+                flight_risk_loyalty_max: 7.0,
+                flight_risk_ambition_min: 14.0,
             },
             shadow: ShadowConfig {
                 cap_per_group: 15,
@@ -552,6 +563,7 @@ impl ScoutingConfig {
     /// thresholds. Equivalent to the inline body that used to live in
     /// `helpers::evaluate_risk_flags` — kept there for ABI but delegating
     /// here for the actual policy.
+    #[allow(clippy::too_many_arguments)]
     pub fn risk_flags_for(
         &self,
         is_injured: bool,
@@ -560,6 +572,7 @@ impl ScoutingConfig {
         contract_months_remaining: i16,
         player_world_rep: i16,
         buyer_world_rep: i16,
+        personality: &PersonAttributes,
     ) -> Vec<ReportRiskFlag> {
         let r = &self.risk_flags;
         let mut flags = Vec::new();
@@ -580,6 +593,17 @@ impl ScoutingConfig {
         if player_world_rep > buyer_world_rep + r.wage_demands_rep_gap {
             flags.push(ReportRiskFlag::WageDemands);
         }
+        if personality.temperament < r.temperamental_temperament_max {
+            flags.push(ReportRiskFlag::Temperamental);
+        }
+        if personality.professionalism < r.unprofessional_professionalism_max {
+            flags.push(ReportRiskFlag::Unprofessional);
+        }
+        if personality.loyalty < r.flight_risk_loyalty_max
+            && personality.ambition > r.flight_risk_ambition_min
+        {
+            flags.push(ReportRiskFlag::FlightRisk);
+        }
         flags
     }
 
@@ -813,6 +837,7 @@ mod tests {
     use crate::PlayerFieldPositionGroup;
     use crate::PlayerPositionType;
     use crate::PlayerSquadStatus;
+    use crate::SigningEligibility;
     use crate::transfers::ScoutingRegion;
     use crate::transfers::pipeline::SellerPlausibilityContext;
 
@@ -859,6 +884,7 @@ mod tests {
                 country_id: 1,
                 continent_id: 1,
                 region: ScoutingRegion::from_country(1, "RU"),
+                eligibility: SigningEligibility::default(),
                 country_code: "RU".to_string(),
                 player_name: "Test".to_string(),
                 club_name: "Test Club".to_string(),
@@ -877,6 +903,7 @@ mod tests {
                 work_rate: 12.0,
                 composure: 12.0,
                 anticipation: 12.0,
+                personality: PersonAttributes::default(),
                 technical_avg: 12.0,
                 mental_avg: 12.0,
                 physical_avg: 12.0,
@@ -990,18 +1017,38 @@ mod tests {
     #[test]
     fn risk_flags_fire_on_thresholds() {
         let c = ScoutingConfig::default();
-        let flags = c.risk_flags_for(true, 5.0, 32, 4, 8000, 5000);
+        let personality = PersonAttributes {
+            temperament: 4.0,
+            professionalism: 5.0,
+            loyalty: 3.0,
+            ambition: 18.0,
+            ..steady_personality()
+        };
+        let flags = c.risk_flags_for(true, 5.0, 32, 4, 8000, 5000, &personality);
         assert!(flags.contains(&ReportRiskFlag::CurrentlyInjured));
         assert!(flags.contains(&ReportRiskFlag::PoorAttitude));
         assert!(flags.contains(&ReportRiskFlag::AgeRisk));
         assert!(flags.contains(&ReportRiskFlag::ContractExpiring));
         assert!(flags.contains(&ReportRiskFlag::WageDemands));
+        assert!(flags.contains(&ReportRiskFlag::Temperamental));
+        assert!(flags.contains(&ReportRiskFlag::Unprofessional));
+        assert!(flags.contains(&ReportRiskFlag::FlightRisk));
+    }
+
+    fn steady_personality() -> PersonAttributes {
+        PersonAttributes {
+            ambition: 10.0,
+            loyalty: 12.0,
+            professionalism: 14.0,
+            temperament: 12.0,
+            ..PersonAttributes::default()
+        }
     }
 
     #[test]
     fn risk_flags_quiet_on_safe_player() {
         let c = ScoutingConfig::default();
-        let flags = c.risk_flags_for(false, 15.0, 25, 24, 5000, 5000);
+        let flags = c.risk_flags_for(false, 15.0, 25, 24, 5000, 5000, &steady_personality());
         assert!(flags.is_empty());
     }
 
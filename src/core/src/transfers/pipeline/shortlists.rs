@@ -237,6 +237,9 @@ impl PipelineProcessor {
                                 ReportRiskFlag::WageDemands => 0.75,
                                 ReportRiskFlag::AgeRisk => 0.9,
                                 ReportRiskFlag::ContractExpiring => 1.05,
+                                ReportRiskFlag::Temperamental => 0.9,
+                                ReportRiskFlag::Unprofessional => 0.85,
+                                ReportRiskFlag::FlightRisk => 0.9,
                             };
                         }
 
@@ -895,6 +898,8 @@ mod market_fallback_age_tests {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 },
                 false,
             )
@@ -0,0 +1,644 @@
+//! Transfer actions taken by the user's club. Bids open ordinary
+//! negotiations that the daily resolver advances; the user only steps in
+//! where a club would make a call — answering bids for its own players,
+//! meeting or beating a seller's price, and agreeing a player's wage.
+
+use crate::club::player::calculators::ContractValuation;
+use crate::country::result::CountryResult;
+use crate::shared::{Currency, CurrencyValue};
+use crate::transfers::market::{TransferListing, TransferListingOrigin, TransferListingType};
+use crate::transfers::negotiation::{
+    NegotiationPhase, NegotiationRejectionReason, NegotiationStatus, TransferNegotiation,
+    UserNegotiationStep,
+};
+use crate::transfers::offer::{PersonalTermsOffer, TransferOffer};
+use crate::transfers::pipeline::processor::PipelineProcessor;
+use crate::transfers::{TransferCalendar, TransferWindowManager};
+use crate::utils::FormattingUtils;
+use crate::{Person, PlayerStatusType, SimulatorData};
+use serde::Deserialize;
+use std::fmt;
+
+/// A bid the user's club places for a player at another club.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserTransferBid {
+    pub player_id: u32,
+    /// Transfer fee, or loan fee when `is_loan` is set.
+    pub fee: f64,
+    #[serde(default)]
+    pub is_loan: bool,
+    /// Annual wage offered to the player. Defaults to the market wage for
+    /// the player's current role.
+    #[serde(default)]
+    pub annual_wage: Option<u32>,
+    #[serde(default)]
+    pub contract_years: Option<u8>,
+}
+
+/// The user's answer in a negotiation waiting on the user's club.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum UserNegotiationResponse {
+    /// Take the bid on the table, or meet the fee or wage demanded.
+    Accept,
+    /// Turn the bid down, or withdraw the user's own bid.
+    Reject,
+    /// Ask the buyer for `fee`, or bid `fee` against the seller's price.
+    Counter { fee: f64 },
+}
+
+/// Contract terms the user's club offers the player it is signing.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UserContractOffer {
+    pub annual_wage: u32,
+    #[serde(default)]
+    pub contract_years: Option<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserTransferError {
+    NoUserClub,
+    PlayerNotFound,
+    OwnPlayer,
+    WindowClosed,
+    AlreadyNegotiating,
+    InvalidAmount,
+    NegotiationNotFound,
+    NotParticipant,
+    NotBuyer,
+    NegotiationClosed,
+    NotAwaitingUser,
+}
+
+impl fmt::Display for UserTransferError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            UserTransferError::NoUserClub => write!(f, "the club is not managed by a user"),
+            UserTransferError::PlayerNotFound => write!(f, "player is not at a club"),
+            UserTransferError::OwnPlayer => write!(f, "player already plays for the user's club"),
+            UserTransferError::WindowClosed => write!(f, "the transfer window is closed"),
+            UserTransferError::AlreadyNegotiating => {
+                write!(f, "a negotiation for this player is already open")
+            }
+            UserTransferError::InvalidAmount => write!(f, "amount must be a positive number"),
+            UserTransferError::NegotiationNotFound => write!(f, "negotiation not found"),
+            UserTransferError::NotParticipant => {
+                write!(f, "the user's club is not part of this negotiation")
+            }
+            UserTransferError::NotBuyer => {
+                write!(f, "only the buying club can offer the player a contract")
+            }
+            UserTransferError::NegotiationClosed => write!(f, "negotiation is already closed"),
+            UserTransferError::NotAwaitingUser => {
+                write!(f, "negotiation is not waiting for the user")
+            }
+        }
+    }
+}
+
+/// Loan fee as a share of the permanent asking price, and the share of
+/// the player's wage it never drops under — the pipeline's own loan ask.
+const LOAN_FEE_RATE: f64 = 0.07;
+const LOAN_SALARY_SHARE: f64 = 0.35;
+
+/// Relocation premium a player expects on top of the market wage for a
+/// move abroad, matching foreign pipeline bids.
+const FOREIGN_WAGE_PREMIUM: f64 = 1.10;
+
+impl PipelineProcessor {
+    /// Open a negotiation for `bid` on behalf of the user's club
+    /// `user_club_id`. Returns `(country_id, negotiation_id)`: negotiation
+    /// ids are per market, and the negotiation lives in the buying club's
+    /// country.
+    pub fn open_user_negotiation(
+        data: &mut SimulatorData,
+        user_club_id: u32,
+        bid: &UserTransferBid,
+    ) -> Result<(u32, u32), UserTransferError> {
+        let date = data.date.date();
+        if !data.is_user_club(user_club_id) {
+            return Err(UserTransferError::NoUserClub);
+        }
+        if !bid.fee.is_finite() || bid.fee < 0.0 {
+            return Err(UserTransferError::InvalidAmount);
+        }
+
+        let indexes = data.indexes.as_ref().ok_or(UserTransferError::NoUserClub)?;
+        let (_, buy_country_id) = indexes
+            .get_club_location(user_club_id)
+            .ok_or(UserTransferError::NoUserClub)?;
+        let (_, sell_country_id, sell_club_id, sell_team_id) = indexes
+            .get_player_location(bid.player_id)
+            .ok_or(UserTransferError::PlayerNotFound)?;
+        if sell_club_id == user_club_id {
+            return Err(UserTransferError::OwnPlayer);
+        }
+        let is_foreign = sell_country_id != buy_country_id;
+
+        let buy_country = data
+            .country(buy_country_id)
+            .ok_or(UserTransferError::NoUserClub)?;
+        let talks_open = TransferWindowManager::for_country(buy_country, date)
+            .is_window_open(buy_country_id, date)
+            || TransferCalendar::days_to_opening(buy_country, date).is_some();
+        if !talks_open {
+            return Err(UserTransferError::WindowClosed);
+        }
+        if buy_country
+            .transfer_market
+            .has_active_negotiation_for(bid.player_id, user_club_id)
+        {
+            return Err(UserTransferError::AlreadyNegotiating);
+        }
+        let buy_team = buy_country
+            .clubs
+            .iter()
+            .find(|c| c.id == user_club_id)
+            .and_then(|c| c.teams.teams.first())
+            .ok_or(UserTransferError::NoUserClub)?;
+        let buying_rep_score = buy_team.reputation.overall_score();
+        let buying_league_reputation = buy_team
+            .league_id
+            .and_then(|lid| buy_country.leagues.leagues.iter().find(|l| l.id == lid))
+            .map(|l| l.reputation)
+            .unwrap_or(0);
+        let buying_rep = Self::get_club_reputation(buy_country, user_club_id);
+
+        let sell_country = data
+            .country(sell_country_id)
+            .ok_or(UserTransferError::PlayerNotFound)?;
+        let sell_club = sell_country
+            .clubs
+            .iter()
+            .find(|c| c.id == sell_club_id)
+            .ok_or(UserTransferError::PlayerNotFound)?;
+        let player = Self::find_player_in_country(sell_country, bid.player_id)
+            .ok_or(UserTransferError::PlayerNotFound)?;
+
+        let asking_price = Self::calculate_asking_price(
+            player,
+            sell_country,
+            sell_club,
+            date,
+            sell_country.settings.pricing.price_level,
+        );
+        let asking_price = if bid.is_loan {
+            let salary_floor = player
+                .contract
+                .as_ref()
+                .map(|c| c.salary as f64 * LOAN_SALARY_SHARE)
+                .unwrap_or(0.0);
+            CurrencyValue {
+                amount: FormattingUtils::round_fee(
+                    (asking_price.amount * LOAN_FEE_RATE).max(salary_floor),
+                ),
+                currency: asking_price.currency,
+            }
+        } else {
+            asking_price
+        };
+        let market_wage = ContractValuation::evaluate(
+            player,
+            &Self::buyer_wage_context(
+                player,
+                player.age(date),
+                buying_rep_score,
+                buying_league_reputation,
+            ),
+        )
+        .expected_wage;
+        let (player_age, player_ambition) =
+            Self::get_player_negotiation_data(sell_country, bid.player_id, date);
+        let is_listed = player.statuses.has(PlayerStatusType::Lst)
+            || player.statuses.has(PlayerStatusType::Loa);
+        let selling_rep = Self::get_club_reputation(sell_country, sell_club_id);
+        let player_name = player.full_name.to_string();
+        let selling_club_name = sell_club.name.clone();
+        let player_sold_from = player.sold_from;
+        let foreign_seller = is_foreign.then(|| {
+            (
+                sell_country.continent_id,
+                sell_country.code.clone(),
+                CountryResult::calculate_player_importance(
+                    sell_country,
+                    bid.player_id,
+                    sell_club_id,
+                ),
+            )
+        });
+
+        let annual_wage = bid.annual_wage.unwrap_or(market_wage);
+        let mut offer = TransferOffer::new(
+            CurrencyValue {
+                amount: FormattingUtils::round_fee(bid.fee),
+                currency: Currency::Usd,
+            },
+            user_club_id,
+            date,
+        )
+        .with_personal_terms(PersonalTermsOffer {
+            annual_wage: Some(annual_wage),
+            contract_years: bid.contract_years,
+            ..PersonalTermsOffer::default()
+        });
+        if let Some(years) = bid.contract_years {
+            offer = offer.with_contract_length(years);
+        }
+
+        let buy_country = data
+            .country_mut(buy_country_id)
+            .ok_or(UserTransferError::NoUserClub)?;
+        let market = &mut buy_country.transfer_market;
+        let has_listing = !is_foreign && market.get_listing_by_player(bid.player_id).is_some();
+        if !has_listing {
+            market.add_listing(TransferListing::new_with_origin(
+                bid.player_id,
+                sell_club_id,
+                if is_foreign { 0 } else { sell_team_id },
+                asking_price,
+                date,
+                if bid.is_loan {
+                    TransferListingType::Loan
+                } else {
+                    TransferListingType::Transfer
+                },
+                TransferListingOrigin::SyntheticUnsolicited,
+            ));
+        }
+
+        let negotiation_id = market
+            .start_negotiation(
+                bid.player_id,
+                user_club_id,
+                offer,
+                date,
+                selling_rep,
+                buying_rep,
+                player_age,
+                player_ambition,
+            )
+            .ok_or(UserTransferError::AlreadyNegotiating)?;
+
+        if let Some(negotiation) = market.negotiations.get_mut(&negotiation_id) {
+            negotiation.is_loan = bid.is_loan;
+            negotiation.is_unsolicited = !is_listed;
+            negotiation.reason = "Manager's request".to_string();
+            negotiation.player_name = player_name;
+            negotiation.selling_club_name = selling_club_name;
+            negotiation.player_sold_from = player_sold_from;
+            negotiation.offered_salary = Some(annual_wage);
+            negotiation.buying_league_reputation = buying_league_reputation;
+            if let Some((continent_id, country_code, importance)) = foreign_seller {
+                negotiation.selling_country_id = Some(sell_country_id);
+                negotiation.selling_continent_id = Some(continent_id);
+                negotiation.selling_country_code = country_code;
+                negotiation.staged_reservation_wage =
+                    Some((market_wage as f64 * FOREIGN_WAGE_PREMIUM) as u32);
+                negotiation.foreign_seller_importance = Some(importance);
+            }
+        }
+
+        if let Some(club) = buy_country.clubs.iter_mut().find(|c| c.id == user_club_id) {
+            club.transfer_plan.active_negotiation_count += 1;
+        }
+
+        Ok((buy_country_id, negotiation_id))
+    }
+
+    /// Apply the user's answer to a negotiation waiting on the user's club
+    /// `user_club_id`.
+    pub fn respond_to_user_negotiation(
+        data: &mut SimulatorData,
+        user_club_id: u32,
+        country_id: u32,
+        negotiation_id: u32,
+        response: UserNegotiationResponse,
+    ) -> Result<(), UserTransferError> {
+        let date = data.date.date();
+        if !data.is_user_club(user_club_id) {
+            return Err(UserTransferError::NoUserClub);
+        }
+        let invalid_counter = matches!(
+            response,
+            UserNegotiationResponse::Counter { fee } if !fee.is_finite() || fee <= 0.0
+        );
+        if invalid_counter {
+            return Err(UserTransferError::InvalidAmount);
+        }
+
+        let country = data
+            .country_mut(country_id)
+            .ok_or(UserTransferError::NegotiationNotFound)?;
+        let negotiation = Self::user_negotiation_mut(
+            &mut country.transfer_market.negotiations,
+            negotiation_id,
+            user_club_id,
+        )?;
+        let (player_id, buying_club_id) = (negotiation.player_id, negotiation.buying_club_id);
+
+        let rejection = if negotiation.selling_club_id == user_club_id {
+            Self::answer_as_seller(negotiation, response, date)?
+        } else {
+            Self::answer_as_buyer(negotiation, response, date)?
+        };
+
+        if let Some(reason) = rejection {
+            negotiation.user_step = None;
+            negotiation.reject_with_reason(reason);
+            CountryResult::reopen_listing_for_player(country, player_id);
+            Self::on_negotiation_resolved(country, buying_club_id, player_id, false);
+        }
+        Ok(())
+    }
+
+    /// Put `offer` on the table for the player the user's club
+    /// `user_club_id` is signing.
+    /// Answers a wage demand; earlier in the talks it replaces the terms
+    /// the player will be asked to agree.
+    pub fn offer_user_contract(
+        data: &mut SimulatorData,
+        user_club_id: u32,
+        country_id: u32,
+        negotiation_id: u32,
+        offer: UserContractOffer,
+    ) -> Result<(), UserTransferError> {
+        let date = data.date.date();
+        if !data.is_user_club(user_club_id) {
+            return Err(UserTransferError::NoUserClub);
+        }
+        if offer.annual_wage == 0 {
+            return Err(UserTransferError::InvalidAmount);
+        }
+
+        let country = data
+            .country_mut(country_id)
+            .ok_or(UserTransferError::NegotiationNotFound)?;
+        let negotiation = Self::user_negotiation_mut(
+            &mut country.transfer_market.negotiations,
+            negotiation_id,
+            user_club_id,
+        )?;
+        if negotiation.buying_club_id != user_club_id {
+            return Err(UserTransferError::NotBuyer);
+        }
+
+        let terms = negotiation
+            .current_offer
+            .personal_terms
+            .get_or_insert_with(PersonalTermsOffer::default);
+        if offer.contract_years.is_some() {
+            terms.contract_years = offer.contract_years;
+        }
+        negotiation.raise_offered_salary(offer.annual_wage);
+
+        if matches!(
+            negotiation.user_step,
+            Some(UserNegotiationStep::AwaitingUserContract { .. })
+        ) {
+            negotiation.user_step = None;
+            negotiation.advance_personal_terms_round(date);
+        }
+        Ok(())
+    }
+
+    /// Every open negotiation the user's club `user_club_id` is buying or
+    /// selling in, with the id of the country whose market holds it.
+    pub fn user_negotiations(
+        data: &SimulatorData,
+        user_club_id: u32,
+    ) -> Vec<(u32, &TransferNegotiation)> {
+        let mut negotiations: Vec<(u32, &TransferNegotiation)> = data
+            .continents
+            .iter()
+            .flat_map(|continent| &continent.countries)
+            .flat_map(|country| {
+                country
+                    .transfer_market
+                    .negotiations
+                    .values()
+                    .map(move |negotiation| (country.id, negotiation))
+            })
+            .filter(|(_, n)| n.buying_club_id == user_club_id || n.selling_club_id == user_club_id)
+            .filter(|(_, n)| Self::is_open(n))
+            .collect();
+        negotiations.sort_by_key(|(country_id, n)| (n.created_date, *country_id, n.id));
+        negotiations
+    }
+
+    fn is_open(negotiation: &TransferNegotiation) -> bool {
+        matches!(
+            negotiation.status,
+            NegotiationStatus::Pending | NegotiationStatus::Countered
+        )
+    }
+
+    fn user_negotiation_mut(
+        negotiations: &mut std::collections::HashMap<u32, TransferNegotiation>,
+        negotiation_id: u32,
+        user_club_id: u32,
+    ) -> Result<&mut TransferNegotiation, UserTransferError> {
+        let negotiation = negotiations
+            .get_mut(&negotiation_id)
+            .ok_or(UserTransferError::NegotiationNotFound)?;
+        if negotiation.buying_club_id != user_club_id && negotiation.selling_club_id != user_club_id
+        {
+            return Err(UserTransferError::NotParticipant);
+        }
+        if !Self::is_open(negotiation) {
+            return Err(UserTransferError::NegotiationClosed);
+        }
+        Ok(negotiation)
+    }
+
+    /// Seller side: only a bid waiting on the user can be answered.
+    /// Returns the rejection reason when the talks end here.
+    fn answer_as_seller(
+        negotiation: &mut TransferNegotiation,
+        response: UserNegotiationResponse,
+        date: chrono::NaiveDate,
+    ) -> Result<Option<NegotiationRejectionReason>, UserTransferError> {
+        if negotiation.user_step != Some(UserNegotiationStep::AwaitingUserResponse) {
+            return Err(UserTransferError::NotAwaitingUser);
+        }
+
+        match response {
+            UserNegotiationResponse::Accept => {
+                negotiation.user_step = None;
+                negotiation.advance_to_personal_terms(date);
+            }
+            UserNegotiationResponse::Reject => {
+                return Ok(Some(NegotiationRejectionReason::SellerRefusedToNegotiate));
+            }
+            UserNegotiationResponse::Counter { fee } => {
+                if fee <= negotiation.current_offer.base_fee.amount {
+                    return Err(UserTransferError::InvalidAmount);
+                }
+                if matches!(negotiation.phase, NegotiationPhase::InitialApproach { .. }) {
+                    negotiation.advance_to_club_negotiation(date);
+                }
+                negotiation.user_step = Some(UserNegotiationStep::AwaitingBuyer {
+                    fee: FormattingUtils::round_fee(fee),
+                });
+                negotiation.resolve_next_tick(date);
+            }
+        }
+        Ok(None)
+    }
+
+    /// Buyer side: withdraw at any point, or answer the seller's price or
+    /// the player's wage demand. Returns the rejection reason when the
+    /// talks end here.
+    fn answer_as_buyer(
+        negotiation: &mut TransferNegotiation,
+        response: UserNegotiationResponse,
+        date: chrono::NaiveDate,
+    ) -> Result<Option<NegotiationRejectionReason>, UserTransferError> {
+        if let UserNegotiationResponse::Reject = response {
+            return Ok(Some(NegotiationRejectionReason::BuyerWithdrew));
+        }
+
+        match (negotiation.user_step.clone(), response) {
+            (Some(UserNegotiationStep::AwaitingUserBid { fee: demanded }), response) => {
+                let fee = match response {
+                    UserNegotiationResponse::Counter { fee } => FormattingUtils::round_fee(fee),
+                    _ => demanded,
+                };
+                if fee <= negotiation.current_offer.base_fee.amount {
+                    return Err(UserTransferError::InvalidAmount);
+                }
+                let mut offer = negotiation.current_offer.clone();
+                offer.base_fee.amount = fee;
+                offer.offered_date = date;
+                negotiation.counter_offer(offer);
+                negotiation.user_step = None;
+                // Meeting the seller's own price closes the fee; anything
+                // less goes back to the seller for another round.
+                if fee >= demanded {
+                    negotiation.advance_to_personal_terms(date);
+                } else {
+                    negotiation.advance_club_negotiation_round(date);
+                }
+            }
+            (
+                Some(UserNegotiationStep::AwaitingUserContract { wage }),
+                UserNegotiationResponse::Accept,
+            ) => {
+                negotiation.raise_offered_salary(wage);
+                negotiation.user_step = None;
+                negotiation.advance_personal_terms_round(date);
+            }
+            _ => return Err(UserTransferError::NotAwaitingUser),
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::transfers::offer::TransferOffer;
+    use chrono::NaiveDate;
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 7, 10).unwrap()
+    }
+
+    fn negotiation(selling_club_id: u32, buying_club_id: u32) -> TransferNegotiation {
+        let offer = TransferOffer::new(
+            CurrencyValue::new(1_000_000.0, Currency::Usd),
+            buying_club_id,
+            date(),
+        );
+        TransferNegotiation::new(
+            1,
+            100,
+            0,
+            selling_club_id,
+            buying_club_id,
+            offer,
+            date(),
+            0.5,
+            0.5,
+            25,
+            0.5,
+        )
+    }
+
+    #[test]
+    fn seller_counter_moves_talks_to_the_buyer() {
+        let mut n = negotiation(1, 2);
+        n.user_step = Some(UserNegotiationStep::AwaitingUserResponse);
+
+        let result = PipelineProcessor::answer_as_seller(
+            &mut n,
+            UserNegotiationResponse::Counter { fee: 1_800_000.0 },
+            date(),
+        );
+
+        assert_eq!(result, Ok(None));
+        assert_eq!(
+            n.user_step,
+            Some(UserNegotiationStep::AwaitingBuyer { fee: 1_800_000.0 })
+        );
+        assert!(matches!(
+            n.phase,
+            NegotiationPhase::ClubNegotiation { round: 1, .. }
+        ));
+        assert!(n.is_phase_ready(date().succ_opt().unwrap()));
+    }
+
+    #[test]
+    fn seller_cannot_answer_a_bid_that_is_not_waiting() {
+        let mut n = negotiation(1, 2);
+
+        let result =
+            PipelineProcessor::answer_as_seller(&mut n, UserNegotiationResponse::Accept, date());
+
+        assert_eq!(result, Err(UserTransferError::NotAwaitingUser));
+    }
+
+    #[test]
+    fn buyer_meeting_the_asking_price_goes_to_personal_terms() {
+        let mut n = negotiation(1, 2);
+        n.advance_to_club_negotiation(date());
+        n.user_step = Some(UserNegotiationStep::AwaitingUserBid { fee: 1_500_000.0 });
+
+        let result =
+            PipelineProcessor::answer_as_buyer(&mut n, UserNegotiationResponse::Accept, date());
+
+        assert_eq!(result, Ok(None));
+        assert_eq!(n.current_offer.base_fee.amount, 1_500_000.0);
+        assert!(matches!(n.phase, NegotiationPhase::PersonalTerms { .. }));
+        assert!(n.user_step.is_none());
+    }
+
+    #[test]
+    fn buyer_lower_bid_returns_to_the_seller() {
+        let mut n = negotiation(1, 2);
+        n.advance_to_club_negotiation(date());
+        n.user_step = Some(UserNegotiationStep::AwaitingUserBid { fee: 1_500_000.0 });
+
+        let result = PipelineProcessor::answer_as_buyer(
+            &mut n,
+            UserNegotiationResponse::Counter { fee: 1_200_000.0 },
+            date(),
+        );
+
+        assert_eq!(result, Ok(None));
+        assert_eq!(n.counter_offers.len(), 1);
+        assert!(matches!(
+            n.phase,
+            NegotiationPhase::ClubNegotiation { round: 2, .. }
+        ));
+    }
+
+    #[test]
+    fn buyer_can_withdraw_at_any_point() {
+        let mut n = negotiation(1, 2);
+
+        let result =
+            PipelineProcessor::answer_as_buyer(&mut n, UserNegotiationResponse::Reject, date());
+
+        assert_eq!(result, Ok(Some(NegotiationRejectionReason::BuyerWithdrew)));
+    }
+}
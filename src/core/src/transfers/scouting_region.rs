@@ -1,3 +1,5 @@
+use serde::{Deserialize, Serialize};
+
 /// Geographic scouting regions that scouts can specialize in.
 /// Instead of knowing individual country IDs, scouts know whole regions —
 /// allowing a single scout covering "WestAfrica" to find players from
@@ -5,7 +7,7 @@
 ///
 /// This replaces the old `known_regions: Vec<u32>` (country IDs) system.
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum ScoutingRegion {
     /// England, France, Germany, Spain, Italy, Netherlands, Belgium, Portugal, Switzerland, Austria
     WesternEurope,
@@ -2,6 +2,7 @@ use crate::Player;
 use crate::league::Season;
 use crate::shared::CurrencyValue;
 use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
 
 pub struct PlayerTransfer {
     pub player: Player,
@@ -14,7 +15,7 @@ impl PlayerTransfer {
     }
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompletedTransfer {
     pub player_id: u32,
     pub player_name: String,
@@ -30,7 +31,7 @@ pub struct CompletedTransfer {
     pub reason: String,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum TransferType {
     Permanent,
     Loan(NaiveDate), // End date
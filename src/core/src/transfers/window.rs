@@ -1,6 +1,7 @@
 use crate::shared::{Currency, CurrencyValue};
 use crate::{Club, Country, Player, PlayerStatusType, PlayerValueCalculator};
 use chrono::{Datelike, NaiveDate};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 /// How wide the buyer/seller "talks are allowed" window is around the
@@ -9,7 +10,7 @@ use std::collections::HashMap;
 /// days after a clear deadline closes; the registration record is filed
 /// only within the formal window. Returned by
 /// [`TransferWindowManager::current_agreement_window_dates`].
-const AGREEMENT_PRE_OPEN_DAYS: i64 = 14;
+pub const AGREEMENT_PRE_OPEN_DAYS: i64 = 14;
 const AGREEMENT_POST_CLOSE_DAYS: i64 = 3;
 
 #[derive(Debug, Clone)]
@@ -37,7 +38,7 @@ impl TransferWindowManager {
     /// exactly as before. Cheap to call; no global state.
     pub fn for_country(country: &Country, date: NaiveDate) -> Self {
         let mut mgr = Self::new();
-        let window = TransferCalendar::for_country(&country.code, date);
+        let window = TransferCalendar::of(country, date);
         mgr.add_window(country.id, window.into_window(country.id));
         mgr
     }
@@ -108,6 +109,25 @@ impl TransferWindowManager {
         }
     }
 
+    /// How close the open window is to slamming shut: 0 when at least
+    /// two weeks remain (or no window is open), ramping linearly to 1.0
+    /// on deadline day. Drives both sides of a negotiation toward a deal
+    /// and the deadline-day surge in AI market activity.
+    pub fn deadline_urgency(&self, country_id: u32, date: NaiveDate) -> f32 {
+        let (_, end) = match self.current_window_dates(country_id, date) {
+            Some(w) => w,
+            None => return 0.0,
+        };
+        let days_left = (end - date).num_days();
+        if days_left >= 14 {
+            0.0
+        } else if days_left <= 1 {
+            1.0
+        } else {
+            1.0 - (days_left as f32 - 1.0) / 13.0
+        }
+    }
+
     /// Date of the next time a deal *can* be formally registered for the
     /// given country. Returns `Some` when:
     ///   - we're already inside an open registration window (today), or
@@ -196,6 +216,40 @@ impl TransferCalendar {
         calendar.windows(year, date)
     }
 
+    /// Windows for `country`: its configured [`TransferWindowRules`] when
+    /// the database sets them, otherwise the built-in table keyed by the
+    /// country code. Every country-holding caller goes through here so an
+    /// override reaches registration, evaluation cadence and deadline
+    /// urgency alike.
+    pub fn of(country: &Country, date: NaiveDate) -> CountryTransferWindow {
+        match &country.regulations.transfer_windows {
+            Some(rules) => rules.windows(date),
+            None => Self::for_country(&country.code, date),
+        }
+    }
+
+    /// Opening day of the country's next registration window, looking up
+    /// to a year ahead. A window that opened today or earlier doesn't
+    /// count — this is the date the *next* market starts filing deals.
+    pub fn next_opening(country: &Country, date: NaiveDate) -> Option<NaiveDate> {
+        let next_year = NaiveDate::from_ymd_opt(date.year() + 1, 1, 1).unwrap_or(date);
+        [date, next_year]
+            .into_iter()
+            .map(|anchor| Self::of(country, anchor))
+            .flat_map(|w| [w.summer_window.0, w.winter_window.0])
+            .filter(|open| *open > date)
+            .min()
+    }
+
+    /// Days until the next window opens when `date` sits in the pre-open
+    /// agreement band — clubs may agree deals now, registration waits for
+    /// the opening. `None` outside the band.
+    pub fn days_to_opening(country: &Country, date: NaiveDate) -> Option<i64> {
+        Self::next_opening(country, date)
+            .map(|open| (open - date).num_days())
+            .filter(|days| *days <= AGREEMENT_PRE_OPEN_DAYS)
+    }
+
     fn lookup(code: &str) -> KnownCalendar {
         match code {
             // Northern hemisphere European-style: summer + winter break.
@@ -264,6 +318,48 @@ impl CountryTransferWindow {
     }
 }
 
+/// Month/day bounds of one registration window, as configured per
+/// country. A close earlier in the year than the open wraps into the
+/// following year (a December–January window).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SeasonalWindowRule {
+    pub open: (u32, u32),
+    pub close: (u32, u32),
+}
+
+impl SeasonalWindowRule {
+    fn dates(&self, year: i32, fallback: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let mk = |year: i32, (month, day): (u32, u32)| {
+            NaiveDate::from_ymd_opt(year, month, day).unwrap_or(fallback)
+        };
+        let close_year = if self.close < self.open {
+            year + 1
+        } else {
+            year
+        };
+        (mk(year, self.open), mk(close_year, self.close))
+    }
+}
+
+/// A country's configured summer and winter windows. Set on
+/// [`CountryRegulations`](crate::CountryRegulations) from the database;
+/// countries without one fall back to [`TransferCalendar`]'s table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TransferWindowRules {
+    pub summer: SeasonalWindowRule,
+    pub winter: SeasonalWindowRule,
+}
+
+impl TransferWindowRules {
+    pub fn windows(&self, date: NaiveDate) -> CountryTransferWindow {
+        let year = date.year();
+        CountryTransferWindow {
+            summer_window: self.summer.dates(year, date),
+            winter_window: self.winter.dates(year, date),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy)]
 enum KnownCalendar {
     DefaultEuropean,
@@ -434,6 +530,94 @@ mod transfer_window_tests {
         assert!(!mgr.is_window_open(0, d(2026, 3, 1)));
     }
 
+    fn rules() -> TransferWindowRules {
+        TransferWindowRules {
+            summer: SeasonalWindowRule {
+                open: (7, 1),
+                close: (9, 1),
+            },
+            winter: SeasonalWindowRule {
+                open: (12, 15),
+                close: (2, 10),
+            },
+        }
+    }
+
+    #[test]
+    fn configured_rules_wrap_a_winter_window_across_new_year() {
+        let w = rules().windows(d(2026, 3, 1));
+        assert_eq!(w.summer_window, (d(2026, 7, 1), d(2026, 9, 1)));
+        assert_eq!(w.winter_window, (d(2026, 12, 15), d(2027, 2, 10)));
+    }
+
+    fn country(code: &str, windows: Option<TransferWindowRules>) -> Country {
+        let mut country = Country::builder()
+            .id(1)
+            .code(code.to_string())
+            .slug(code.to_string())
+            .name(code.to_string())
+            .continent_id(1)
+            .leagues(crate::league::LeagueCollection::new(Vec::new()))
+            .clubs(Vec::new())
+            .build()
+            .unwrap();
+        country.regulations.transfer_windows = windows;
+        country
+    }
+
+    #[test]
+    fn configured_windows_override_the_country_code_table() {
+        let c = country("en", Some(rules()));
+        assert_eq!(
+            TransferCalendar::of(&c, d(2026, 3, 1)).summer_window.0,
+            d(2026, 7, 1)
+        );
+        assert!(
+            TransferWindowManager::for_country(&c, d(2026, 6, 20)).windows[&1]
+                .summer_window
+                .0
+                == d(2026, 7, 1)
+        );
+        assert!(
+            !TransferWindowManager::for_country(&c, d(2026, 6, 20))
+                .is_window_open(1, d(2026, 6, 20))
+        );
+    }
+
+    #[test]
+    fn talks_open_a_fortnight_before_the_next_window() {
+        let c = country("en", None);
+        assert_eq!(
+            TransferCalendar::days_to_opening(&c, d(2026, 5, 18)),
+            Some(14)
+        );
+        assert_eq!(
+            TransferCalendar::days_to_opening(&c, d(2026, 5, 31)),
+            Some(1)
+        );
+        assert_eq!(TransferCalendar::days_to_opening(&c, d(2026, 5, 10)), None);
+        assert_eq!(TransferCalendar::days_to_opening(&c, d(2026, 6, 1)), None);
+        // December talks look ahead to next year's January window.
+        assert_eq!(
+            TransferCalendar::next_opening(&c, d(2026, 12, 20)),
+            Some(d(2027, 1, 1))
+        );
+        assert_eq!(
+            TransferCalendar::days_to_opening(&c, d(2026, 12, 20)),
+            Some(12)
+        );
+    }
+
+    #[test]
+    fn deadline_urgency_ramps_to_deadline_day() {
+        let mgr = TransferWindowManager::new();
+        assert_eq!(mgr.deadline_urgency(0, d(2026, 7, 1)), 0.0);
+        assert_eq!(mgr.deadline_urgency(0, d(2026, 8, 31)), 1.0);
+        let week_out = mgr.deadline_urgency(0, d(2026, 8, 24));
+        assert!(week_out > 0.0 && week_out < 1.0);
+        assert_eq!(mgr.deadline_urgency(0, d(2026, 9, 2)), 0.0);
+    }
+
     #[test]
     fn next_registration_open_date_returns_today_when_inside_window() {
         let mgr = TransferWindowManager::new();
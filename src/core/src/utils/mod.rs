@@ -4,6 +4,7 @@ mod estimation;
 pub mod formatting;
 mod logging;
 pub mod random;
+mod static_str;
 mod strings;
 
 pub use cpu::*;
@@ -12,4 +13,5 @@ pub use estimation::*;
 pub use formatting::*;
 pub use logging::*;
 pub use random::*;
+pub use static_str::*;
 pub use strings::*;
@@ -9,6 +9,7 @@
 //! enough for regression bisection; not a replay tool.
 
 use rand::rngs::SmallRng;
+use rand::seq::SliceRandom;
 use rand::{RngExt, SeedableRng};
 use std::cell::RefCell;
 use std::sync::atomic::{AtomicU64, Ordering};
@@ -90,6 +91,12 @@ impl RandomEngine {
         Self::with_rng(|rng| rng.random::<f64>())
     }
 
+    /// Shuffle `items` in place from the sim stream, so a pinned seed
+    /// reproduces the same order.
+    pub fn shuffle<T>(items: &mut [T]) {
+        Self::with_rng(|rng| items.shuffle(rng))
+    }
+
     fn with_rng<R>(f: impl FnOnce(&mut SmallRng) -> R) -> R {
         let id = TL_ID.with(|id| *id);
         TL_RNG.with(|cell| {
@@ -0,0 +1,61 @@
+use serde::{Deserialize, Deserializer};
+use std::collections::HashSet;
+use std::sync::{LazyLock, Mutex};
+
+/// `&'static str` under a name serde does not treat as a borrow, so a
+/// field can pair it with [`deserialize_static_str`] and still load from
+/// owned input.
+pub type StaticStr = &'static str;
+
+static INTERNED: LazyLock<Mutex<HashSet<&'static str>>> =
+    LazyLock::new(|| Mutex::new(HashSet::new()));
+
+/// Turn a loaded string back into a `&'static str`. Saved games carry
+/// i18n keys that the simulation holds as static literals; each distinct
+/// key is leaked once and shared by every later load.
+pub fn intern(value: &str) -> &'static str {
+    let mut interned = INTERNED.lock().unwrap();
+    if let Some(existing) = interned.get(value) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(value.to_owned().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+/// `deserialize_with` helper for `&'static str` fields.
+pub fn deserialize_static_str<'de, D>(deserializer: D) -> Result<&'static str, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let value = String::deserialize(deserializer)?;
+    Ok(intern(&value))
+}
+
+/// `deserialize_with` helper for `(key, value)` lists keyed by static str.
+pub fn deserialize_static_keyed<'de, D, T>(
+    deserializer: D,
+) -> Result<Vec<(&'static str, T)>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let entries = Vec::<(String, T)>::deserialize(deserializer)?;
+    Ok(entries
+        .into_iter()
+        .map(|(key, value)| (intern(&key), value))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn intern_returns_the_same_slice_for_equal_keys() {
+        let a = intern("news.injury.hamstring");
+        let b = intern(&String::from("news.injury.hamstring"));
+        assert_eq!(a, "news.injury.hamstring");
+        assert!(std::ptr::eq(a, b));
+    }
+}
@@ -0,0 +1,299 @@
+//! What differs between two game databases, table by table and record by
+//! record. Records are matched by `id`; name pools by `country_code`,
+//! domestic cups by `country_slug` and named staff by their club, team,
+//! position and name, as those tables carry no ids.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::path::Path;
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::DatabaseEntity;
+use crate::loaders::{self, DatabaseError, DirectoryLoader, Table, leaves};
+
+#[derive(Serialize)]
+pub struct DatabaseDiff {
+    /// Only the tables with differences.
+    pub tables: Vec<TableDiff>,
+}
+
+#[derive(Serialize)]
+pub struct TableDiff {
+    pub table: &'static str,
+    /// Labels (key and name) of the records only the second database has.
+    pub added: Vec<String>,
+    /// Labels of the records only the first database has.
+    pub removed: Vec<String>,
+    pub changed: Vec<RecordDiff>,
+}
+
+#[derive(Serialize)]
+pub struct RecordDiff {
+    pub record: String,
+    pub fields: Vec<FieldDiff>,
+}
+
+/// One value of a changed record, by dotted path as in a CSV header.
+/// `None` where the field is absent.
+#[derive(Serialize)]
+pub struct FieldDiff {
+    pub field: String,
+    pub before: Option<Value>,
+    pub after: Option<Value>,
+}
+
+impl DatabaseEntity {
+    /// What changes from this database to the one in `directory`. Its
+    /// tables must be readable, but references between them are not
+    /// checked, so a broken database can be compared too.
+    pub fn diff(&self, directory: &Path) -> Result<DatabaseDiff, DatabaseError> {
+        let other = DirectoryLoader::parse(directory)?;
+        Ok(DatabaseDiff::between(
+            &loaders::tables(&self.to_compiled()),
+            &loaders::tables(&other),
+        ))
+    }
+}
+
+impl DatabaseDiff {
+    pub(crate) fn between(before: &[Table], after: &[Table]) -> Self {
+        let tables = before
+            .iter()
+            .zip(after)
+            .map(|(before, after)| table_diff(before, after))
+            .filter(|diff| !diff.is_empty())
+            .collect();
+        DatabaseDiff { tables }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+}
+
+impl TableDiff {
+    fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+fn table_diff(before: &Table, after: &Table) -> TableDiff {
+    let keyed = |table: &'_ Table| -> Vec<(String, usize)> {
+        table
+            .records
+            .iter()
+            .enumerate()
+            .map(|(idx, record)| (key(table.name, record), idx))
+            .collect()
+    };
+    let before_keys = keyed(before);
+    let after_keys = keyed(after);
+    let before_by_key: HashMap<&str, usize> =
+        before_keys.iter().map(|(k, i)| (k.as_str(), *i)).collect();
+    let after_by_key: HashMap<&str, usize> =
+        after_keys.iter().map(|(k, i)| (k.as_str(), *i)).collect();
+
+    let mut diff = TableDiff {
+        table: before.name,
+        added: Vec::new(),
+        removed: Vec::new(),
+        changed: Vec::new(),
+    };
+    for (key, idx) in &before_keys {
+        let record = &before.records[*idx];
+        let Some(&other) = after_by_key.get(key.as_str()) else {
+            diff.removed.push(label(key, record));
+            continue;
+        };
+        let fields = field_diffs(record, &after.records[other]);
+        if !fields.is_empty() {
+            diff.changed.push(RecordDiff {
+                record: label(key, record),
+                fields,
+            });
+        }
+    }
+    for (key, idx) in &after_keys {
+        if !before_by_key.contains_key(key.as_str()) {
+            diff.added.push(label(key, &after.records[*idx]));
+        }
+    }
+    diff
+}
+
+fn field_diffs(before: &Value, after: &Value) -> Vec<FieldDiff> {
+    let before = leaves(before);
+    let after = leaves(after);
+    let after_by_path: HashMap<&str, &Value> =
+        after.iter().map(|(p, v)| (p.as_str(), *v)).collect();
+    let before_by_path: HashMap<&str, &Value> =
+        before.iter().map(|(p, v)| (p.as_str(), *v)).collect();
+
+    let mut fields: Vec<FieldDiff> = before
+        .iter()
+        .filter(|(path, value)| after_by_path.get(path.as_str()) != Some(value))
+        .map(|(path, value)| FieldDiff {
+            field: path.clone(),
+            before: Some((*value).clone()),
+            after: after_by_path.get(path.as_str()).map(|v| (*v).clone()),
+        })
+        .collect();
+    fields.extend(
+        after
+            .iter()
+            .filter(|(path, _)| !before_by_path.contains_key(path.as_str()))
+            .map(|(path, value)| FieldDiff {
+                field: path.clone(),
+                before: None,
+                after: Some((*value).clone()),
+            }),
+    );
+    fields
+}
+
+/// What identifies a record of `table` across databases.
+fn key(table: &str, record: &Value) -> String {
+    let text = |field: &str| match &record[field] {
+        Value::String(text) => text.clone(),
+        Value::Null => String::new(),
+        other => other.to_string(),
+    };
+    match table {
+        "names" => text("country_code"),
+        "domestic_cups" => text("country_slug"),
+        "staff" => format!(
+            "{}/{}/{}/{} {}",
+            text("club_id"),
+            text("team_type"),
+            text("position"),
+            text("first_name"),
+            text("last_name")
+        ),
+        _ => text("id"),
+    }
+}
+
+/// The key, followed by the record's name where it has one apart from it.
+fn label(key: &str, record: &Value) -> String {
+    let name = match (&record["name"], &record["first_name"], &record["last_name"]) {
+        (Value::String(name), _, _) => name.clone(),
+        (_, Value::String(first), Value::String(last)) if !key.ends_with(last.as_str()) => {
+            format!("{first} {last}")
+        }
+        _ => return key.to_string(),
+    };
+    format!("{key} {name}")
+}
+
+fn show(value: &Option<Value>) -> String {
+    value
+        .as_ref()
+        .map_or_else(|| "(none)".to_string(), Value::to_string)
+}
+
+impl fmt::Display for DatabaseDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.is_empty() {
+            return writeln!(f, "no differences");
+        }
+        for table in &self.tables {
+            writeln!(
+                f,
+                "{}: {} added, {} removed, {} changed",
+                table.table,
+                table.added.len(),
+                table.removed.len(),
+                table.changed.len()
+            )?;
+            for record in &table.added {
+                writeln!(f, "  + {record}")?;
+            }
+            for record in &table.removed {
+                writeln!(f, "  - {record}")?;
+            }
+            for record in &table.changed {
+                writeln!(f, "  ~ {}", record.record)?;
+                for field in &record.fields {
+                    writeln!(
+                        f,
+                        "      {}: {} -> {}",
+                        field.field,
+                        show(&field.before),
+                        show(&field.after)
+                    )?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TableFormat;
+    use std::fs;
+
+    fn scratch(name: &str) -> std::path::PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "open-football-export-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        directory
+    }
+
+    #[test]
+    fn an_exported_world_reads_back_unchanged() {
+        let world = loaders::fictional_world(11);
+        for format in [TableFormat::Json, TableFormat::Csv] {
+            let directory = scratch(&format.to_string());
+            loaders::write_tables(&directory, &loaders::tables(&world), format).unwrap();
+
+            let read = DirectoryLoader::read(&directory).unwrap_or_else(|e| panic!("{e}"));
+            let diff = DatabaseDiff::between(&loaders::tables(&world), &loaders::tables(&read));
+            assert!(diff.is_empty(), "{format}: {diff}");
+            if format == TableFormat::Csv {
+                assert!(directory.join("clubs.csv").is_file());
+                assert!(!directory.join("clubs.json").is_file());
+            }
+
+            let _ = fs::remove_dir_all(&directory);
+        }
+    }
+
+    #[test]
+    fn added_removed_and_changed_records_are_listed() {
+        let before = loaders::fictional_world(12);
+        let mut after = loaders::fictional_world(12);
+        let renamed = after.clubs[0].id;
+        after.clubs[0].name = "Renamed FC".to_string();
+        let gone = after.players.remove(0);
+        after.players[0].id = 999_999;
+
+        let diff = DatabaseDiff::between(&loaders::tables(&before), &loaders::tables(&after));
+        let table = |name: &str| diff.tables.iter().find(|t| t.table == name).unwrap();
+
+        let clubs = table("clubs");
+        assert_eq!(clubs.changed.len(), 1);
+        assert!(clubs.changed[0].record.starts_with(&renamed.to_string()));
+        assert_eq!(clubs.changed[0].fields[0].field, "name");
+        assert_eq!(
+            clubs.changed[0].fields[0].after,
+            Some(Value::from("Renamed FC"))
+        );
+
+        let players = table("players");
+        assert_eq!(players.removed.len(), 2);
+        assert!(players.removed[0].starts_with(&gone.id.to_string()));
+        assert_eq!(players.added.len(), 1);
+        assert!(players.added[0].starts_with("999999 "));
+        assert!(
+            diff.to_string()
+                .contains("clubs: 0 added, 0 removed, 1 changed")
+        );
+    }
+}
@@ -8,9 +8,9 @@ use core::shared::Location;
 use core::transfers::pipeline::ClubTransferPlan;
 use core::{
     Club, ClubBoard, ClubColors, ClubFacilities, ClubFinances, ClubPhilosophy, ClubStatus,
-    CountryEconomicFactors, FacilityLevel, Player, PlayerCollection, ReputationLevel,
-    SponsorPerformance, SponsorRenewalContext, StaffCollection, TacticsSelector, Team,
-    TeamCollection, TeamReputation, TeamType, TrainingSchedule,
+    CountryEconomicFactors, FacilityLevel, Player, PlayerCollection, PreseasonTour,
+    ReputationLevel, SponsorPerformance, SponsorRenewalContext, StaffCollection, TacticsSelector,
+    Team, TeamCollection, TeamReputation, TeamType, TrainingSchedule,
 };
 use rayon::prelude::*;
 use std::collections::HashMap;
@@ -88,6 +88,8 @@ impl DatabaseGenerator {
                         academy: FacilityLevel::from_str(&f.academy),
                         recruitment: FacilityLevel::from_str(&f.recruitment),
                         average_attendance: club.average_attendance.unwrap_or(0),
+                        stadium_capacity: club.stadium_capacity.unwrap_or(0),
+                        stadium_expansion: None,
                     },
                     None => ClubFacilities::default(),
                 };
@@ -132,14 +134,27 @@ impl DatabaseGenerator {
                                 odb_for_club.as_ref(),
                             ));
 
-                            let staffs = StaffCollection::new(Self::generate_staffs(
+                            let mut staffs = Self::generate_staffs(
                                 staff_generator,
                                 country_id,
                                 continent_id,
                                 country_code,
                                 team_rep,
                                 &team_type,
-                            ));
+                            );
+                            let named: Vec<_> = data
+                                .staff
+                                .iter()
+                                .filter(|s| s.club_id == club.id && s.team_type == t.team_type)
+                                .collect();
+                            Self::seat_named_staff(
+                                &mut staffs,
+                                &named,
+                                staff_generator,
+                                country_id,
+                                team_rep,
+                            );
+                            let staffs = StaffCollection::new(staffs);
 
                             let mut team = Team::builder()
                                 .id(t.id)
@@ -218,6 +233,7 @@ impl DatabaseGenerator {
                     facilities,
                     rivals: club.rivals.clone(),
                     teams,
+                    preseason: PreseasonTour::default(),
                 }
             })
             .collect()
@@ -1,8 +1,12 @@
 use crate::DatabaseEntity;
 use crate::generators::{PlayerGenerator, StaffGenerator};
-use crate::loaders::ContinentEntity;
+use crate::loaders::{ContinentEntity, TransferWindowEntity};
 use core::league::LeagueCollection;
-use core::{Country, CountryGeneratorData, CountryPricing, CountrySettings, SkinColorDistribution};
+use core::transfers::{SeasonalWindowRule, TransferWindowRules};
+use core::{
+    Country, CountryGeneratorData, CountryPricing, CountryRegulations, CountrySettings,
+    SkinColorDistribution,
+};
 use rayon::prelude::*;
 
 use super::DatabaseGenerator;
@@ -36,11 +40,7 @@ impl DatabaseGenerator {
                     .iter()
                     .find(|c| c.country_id == country.id)
                 {
-                    Some(names) => CountryGeneratorData::new(
-                        names.first_names.clone(),
-                        names.last_names.clone(),
-                        names.nicknames.clone(),
-                    ),
+                    Some(names) => CountryGeneratorData::new(names.people_names()),
                     None => CountryGeneratorData::empty(),
                 };
 
@@ -82,6 +82,26 @@ impl DatabaseGenerator {
                     },
                 };
 
+                let mut regulations = CountryRegulations::new();
+                regulations.foreign_player_limit = country.regulations.foreign_player_limit;
+                regulations.registered_squad_size = country.regulations.registered_squad_size;
+                regulations.homegrown_requirements = country.regulations.homegrown_requirements;
+                regulations.club_trained_requirements =
+                    country.regulations.club_trained_requirements;
+                regulations.work_permit_min_caps = country.regulations.work_permit_min_caps;
+                regulations.non_eu_player_limit = country.regulations.non_eu_player_limit;
+                regulations.transfer_windows =
+                    country.regulations.transfer_windows.as_ref().map(|w| {
+                        let rule = |e: &TransferWindowEntity| SeasonalWindowRule {
+                            open: (e.open_month, e.open_day),
+                            close: (e.close_month, e.close_day),
+                        };
+                        TransferWindowRules {
+                            summer: rule(&w.summer),
+                            winter: rule(&w.winter),
+                        }
+                    });
+
                 Country::builder()
                     .id(country.id)
                     .code(country.code.clone())
@@ -96,6 +116,7 @@ impl DatabaseGenerator {
                     .clubs(clubs)
                     .reputation(country.reputation)
                     .settings(settings)
+                    .regulations(regulations)
                     .generator_data(generator_data)
                     .build()
                     .expect("Failed to build Country")
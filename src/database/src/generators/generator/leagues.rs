@@ -1,7 +1,8 @@
 use crate::{CountryEntity, DatabaseEntity};
 use core::league::{
     DayMonthPeriod, DomesticCup, League, LeagueFinancials, LeagueGroup, LeaguePlayoff,
-    LeaguePlayoffConfig, LeagueSettings, PlayoffFormat, PlayoffStage,
+    LeaguePlayoffConfig, LeagueRules, LeagueSettings, PlayoffFormat, PlayoffStage, PointsSystem,
+    TieBreakPolicy, TieBreakRule,
 };
 use core::{Club, TeamType};
 use std::str::FromStr;
@@ -87,6 +88,8 @@ impl DatabaseGenerator {
                         }),
                     }),
                     split_season: league.settings.split_season,
+                    promotion_playoff_spots: league.promotion_playoff_spots,
+                    relegation_league_id: league.relegation_league_id,
                 };
 
                 let mut l = League::new(
@@ -99,6 +102,23 @@ impl DatabaseGenerator {
                     false,
                 );
                 l.financials = financials;
+                l.rules = LeagueRules {
+                    points: PointsSystem {
+                        win: league.rules.points_for_win,
+                        draw: league.rules.points_for_draw,
+                        loss: league.rules.points_for_loss,
+                    },
+                    tie_break: TieBreakPolicy::from_rules(
+                        league
+                            .rules
+                            .tiebreakers
+                            .iter()
+                            .filter_map(|s| TieBreakRule::from_str(s).ok())
+                            .collect(),
+                    ),
+                    foreign_player_limit: league.rules.foreign_player_limit,
+                    max_squad_size: league.rules.max_squad_size,
+                };
                 l
             })
             .collect()
@@ -129,6 +149,8 @@ impl DatabaseGenerator {
             relegation_spots: 0,
             league_group: None,
             split_season: false,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
         };
 
         let (slug, name, configured_rep) = match &country.domestic_cup {
@@ -217,6 +239,8 @@ impl DatabaseGenerator {
                 relegation_spots: 0,
                 league_group: None,
                 split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
             };
             let reputation = primary.reputation;
 
@@ -326,31 +350,33 @@ impl DatabaseGenerator {
                 .unwrap_or_default();
 
             for team_type in &team_types {
-                // Check if any club in this parent league has this team type
-                let has_type = clubs.iter().any(|club| {
-                    club_league_map
-                        .iter()
-                        .any(|(cid, lid)| *cid == club.id && lid == parent_id)
-                        && club.teams.teams.iter().any(|t| t.team_type == *team_type)
-                });
-
-                if !has_type {
+                // A sub-league needs at least two clubs in this parent
+                // league fielding the team type — one side has no fixtures.
+                let clubs_with_type = clubs
+                    .iter()
+                    .filter(|club| {
+                        club_league_map
+                            .iter()
+                            .any(|(cid, lid)| *cid == club.id && lid == parent_id)
+                            && club.teams.teams.iter().any(|t| t.team_type == *team_type)
+                    })
+                    .count();
+
+                if clubs_with_type < 2 {
                     continue;
                 }
 
                 // Deterministic league ID offset per team type
-                let type_offset = match team_type {
-                    TeamType::U18 => 100000,
-                    TeamType::U19 => 110000,
-                    TeamType::U20 => 120000,
-                    TeamType::U21 => 130000,
-                    TeamType::U23 => 140000,
-                    _ => continue,
+                let Some(type_offset) = team_type.sub_league_offset() else {
+                    continue;
                 };
 
                 let youth_league_id = parent_id + type_offset;
                 let youth_reputation = (parent_rep / 10).max(100);
-                let type_label = format!("{}", team_type);
+                let type_label = match team_type {
+                    TeamType::Reserve => "Reserves".to_string(),
+                    _ => format!("{}", team_type),
+                };
                 let type_slug = type_label.to_lowercase();
 
                 let youth_settings = LeagueSettings {
@@ -361,6 +387,8 @@ impl DatabaseGenerator {
                     relegation_spots: 0,
                     league_group: None,
                     split_season: false,
+                    promotion_playoff_spots: 0,
+                    relegation_league_id: None,
                 };
 
                 let youth_league = League::new(
@@ -397,11 +425,21 @@ impl DatabaseGenerator {
 #[cfg(test)]
 mod tests {
     use super::{DOMESTIC_CUP_ID_BASE, DatabaseGenerator, slugify};
-    use crate::DomesticCupEntity;
     use crate::loaders::country::{
-        CountryEntity, CountryPricingEntity, CountrySettingsEntity, SkinColorsEntity,
+        CountryEntity, CountryPricingEntity, CountryRegulationsEntity, CountrySettingsEntity,
+        SkinColorsEntity,
     };
+    use crate::loaders::{DayMonthPeriodEntity, LeagueSettingsEntity};
+    use crate::{DatabaseEntity, DomesticCupEntity, LeagueEntity};
+    use chrono::NaiveTime;
+    use core::club::academy::ClubAcademy;
     use core::league::{DayMonthPeriod, League, LeagueSettings};
+    use core::shared::Location;
+    use core::{
+        Club, ClubColors, ClubFacilities, ClubFinances, ClubStatus, PlayerCollection,
+        StaffCollection, Team, TeamBuilder, TeamCollection, TeamReputation, TeamType,
+        TrainingSchedule,
+    };
 
     fn country_entity(
         id: u32,
@@ -422,6 +460,7 @@ mod tests {
                 pricing: CountryPricingEntity { price_level: 1.0 },
             },
             skin_colors: SkinColorsEntity::default(),
+            regulations: CountryRegulationsEntity::default(),
             domestic_cup: cup,
         }
     }
@@ -441,6 +480,8 @@ mod tests {
                 relegation_spots: 3,
                 league_group: None,
                 split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
             },
             false,
         )
@@ -495,4 +536,135 @@ mod tests {
         let country = country_entity(1, "Nowhere", "nowhere", None);
         assert!(DatabaseGenerator::generate_domestic_cup(&country, &[]).is_none());
     }
+
+    fn league_entity(id: u32, sub_leagues: &[&str]) -> LeagueEntity {
+        let period = |from_month, to_month| DayMonthPeriodEntity {
+            from_day: 1,
+            from_month,
+            to_day: 28,
+            to_month,
+        };
+        LeagueEntity {
+            id,
+            enabled: true,
+            slug: "top-flight".into(),
+            name: "Top Flight".into(),
+            country_id: 1,
+            country_code: "xx".into(),
+            settings: LeagueSettingsEntity {
+                season_starting_half: period(8, 12),
+                season_ending_half: period(1, 5),
+                split_season: false,
+            },
+            reputation: 8000,
+            tier: 1,
+            promotion_spots: 0,
+            relegation_spots: 3,
+            promotion_playoff_spots: 0,
+            relegation_league_id: None,
+            foreign_players: Vec::new(),
+            sub_leagues_competitions: sub_leagues.iter().map(|s| s.to_string()).collect(),
+            league_group: None,
+            rules: Default::default(),
+        }
+    }
+
+    fn database(leagues: Vec<LeagueEntity>) -> DatabaseEntity {
+        DatabaseEntity {
+            continents: vec![],
+            countries: vec![],
+            leagues,
+            clubs: vec![],
+            national_competitions: vec![],
+            names_by_country: vec![],
+            players_odb: None,
+            staff: vec![],
+            index: std::sync::OnceLock::new(),
+        }
+    }
+
+    fn club(id: u32, league_id: u32, team_types: &[TeamType]) -> Club {
+        let teams: Vec<Team> = [TeamType::Main]
+            .iter()
+            .chain(team_types)
+            .enumerate()
+            .map(|(i, team_type)| {
+                TeamBuilder::new()
+                    .id(id * 10 + i as u32)
+                    .league_id(Some(league_id))
+                    .club_id(id)
+                    .name(format!("C{id}"))
+                    .slug(format!("c{id}-{i}"))
+                    .team_type(*team_type)
+                    .players(PlayerCollection::new(Vec::new()))
+                    .staffs(StaffCollection::new(Vec::new()))
+                    .reputation(TeamReputation::new(100, 100, 100))
+                    .training_schedule(TrainingSchedule::new(
+                        NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                        NaiveTime::from_hms_opt(15, 0, 0).unwrap(),
+                    ))
+                    .build()
+                    .unwrap()
+            })
+            .collect();
+        Club::new(
+            id,
+            format!("C{id}"),
+            Location::new(1),
+            ClubFinances::new(1_000_000, Vec::new()),
+            ClubAcademy::new(3),
+            ClubStatus::Professional,
+            ClubColors::default(),
+            TeamCollection::new(teams),
+            ClubFacilities::default(),
+        )
+    }
+
+    fn team_league(club: &Club, team_type: TeamType) -> Option<u32> {
+        club.teams
+            .teams
+            .iter()
+            .find(|t| t.team_type == team_type)
+            .and_then(|t| t.league_id)
+    }
+
+    #[test]
+    fn sub_leagues_sit_at_the_team_type_offset() {
+        let data = database(vec![league_entity(1, &["U19", "Reserve", "B"])]);
+        let types = [TeamType::U19, TeamType::Reserve, TeamType::B];
+        let mut clubs = vec![club(1, 1, &types), club(2, 1, &types)];
+        let mut leagues = vec![tier1_league(1, 1)];
+
+        DatabaseGenerator::create_subteams_leagues(1, &mut clubs, &mut leagues, &data);
+
+        let ids: Vec<u32> = leagues.iter().map(|l| l.id).collect();
+        assert_eq!(ids, [1, 110_001, 150_001]);
+        assert_eq!(leagues[2].slug, "top-flight-reserves");
+        assert!(leagues[1].friendly && leagues[2].friendly);
+        for club in &clubs {
+            assert_eq!(team_league(club, TeamType::U19), Some(110_001));
+            assert_eq!(team_league(club, TeamType::Reserve), Some(150_001));
+            // B teams play in a real division, not a sub-league.
+            assert_eq!(team_league(club, TeamType::B), Some(1));
+        }
+    }
+
+    #[test]
+    fn team_type_fielded_by_one_club_gets_no_sub_league() {
+        let data = database(vec![league_entity(1, &["U19", "U21"])]);
+        let mut clubs = vec![
+            club(1, 1, &[TeamType::U19, TeamType::U21]),
+            club(2, 1, &[TeamType::U19]),
+            // Fields a U21 but plays in another division.
+            club(3, 2, &[TeamType::U21]),
+        ];
+        let mut leagues = vec![tier1_league(1, 1)];
+
+        DatabaseGenerator::create_subteams_leagues(1, &mut clubs, &mut leagues, &data);
+
+        let ids: Vec<u32> = leagues.iter().map(|l| l.id).collect();
+        assert_eq!(ids, [1, 110_001]);
+        assert_eq!(team_league(&clubs[0], TeamType::U21), Some(1));
+        assert_eq!(team_league(&clubs[2], TeamType::U21), Some(2));
+    }
 }
@@ -19,6 +19,14 @@ use rayon::prelude::*;
 pub struct DatabaseGenerator;
 
 impl DatabaseGenerator {
+    /// Date a generated world starts on: August 1st of this year.
+    pub fn start_date() -> NaiveDateTime {
+        NaiveDateTime::new(
+            NaiveDate::from_ymd_opt(Local::now().year(), 8, 1).unwrap(),
+            NaiveTime::default(),
+        )
+    }
+
     pub fn generate(data: &DatabaseEntity) -> SimulatorData {
         // Seed the procedural id sequence past every ODB-supplied player
         // id so generated players for non-ODB clubs cannot collide with
@@ -33,10 +41,7 @@ impl DatabaseGenerator {
             seed_core_player_id_sequence(max_odb_id);
         }
 
-        let current_date = NaiveDateTime::new(
-            NaiveDate::from_ymd_opt(Local::now().year(), 8, 1).unwrap(),
-            NaiveTime::default(),
-        );
+        let current_date = Self::start_date();
 
         // Convert all national competition entities to runtime configs.
         // The compiled database predates the `team_level` field, so the
@@ -2,7 +2,6 @@ use crate::generators::player::SquadRole;
 use crate::generators::{PlayerGenerator, PositionType};
 use crate::{DatabaseEntity, ForeignPlayerEntry};
 use chrono::Local;
-use core::PeopleNameGeneratorData;
 use core::utils::IntegerUtils;
 use core::{AcademyGenerationContext, AcademyIntakeState, PlayerGenerator as CorePlayerGenerator};
 use core::{Player, TeamType};
@@ -177,16 +176,8 @@ impl DatabaseGenerator {
                 .names_by_country
                 .iter()
                 .find(|n| n.country_id == country_id)
-                .map(|n| PeopleNameGeneratorData {
-                    first_names: n.first_names.clone(),
-                    last_names: n.last_names.clone(),
-                    nicknames: n.nicknames.clone(),
-                })
-                .unwrap_or_else(|| PeopleNameGeneratorData {
-                    first_names: Vec::new(),
-                    last_names: Vec::new(),
-                    nicknames: Vec::new(),
-                });
+                .map(|n| n.people_names())
+                .unwrap_or_default();
 
             let now = Local::now().date_naive();
 
@@ -320,18 +311,7 @@ impl DatabaseGenerator {
                                 .names_by_country
                                 .iter()
                                 .find(|n| n.country_id == fp.country_id);
-                            let people_names = match names {
-                                Some(n) => PeopleNameGeneratorData {
-                                    first_names: n.first_names.clone(),
-                                    last_names: n.last_names.clone(),
-                                    nicknames: n.nicknames.clone(),
-                                },
-                                None => PeopleNameGeneratorData {
-                                    first_names: Vec::new(),
-                                    last_names: Vec::new(),
-                                    nicknames: Vec::new(),
-                                },
-                            };
+                            let people_names = names.map(|n| n.people_names()).unwrap_or_default();
                             let foreign_gen = PlayerGenerator::with_people_names(&people_names);
                             let foreign_country =
                                 data.countries.iter().find(|c| c.id == fp.country_id);
@@ -1,4 +1,6 @@
+use crate::StaffEntity;
 use crate::generators::StaffGenerator;
+use core::shared::FullName;
 use core::utils::IntegerUtils;
 use core::{Staff, StaffPosition, TeamType};
 
@@ -82,6 +84,49 @@ impl DatabaseGenerator {
         staffs
     }
 
+    /// Put the named staff of a user-built world into the generated
+    /// backroom. Each takes over the first seat of its position that isn't
+    /// named yet, keeping the generated attributes and contract; a position
+    /// the team wasn't given gets a new seat.
+    pub(super) fn seat_named_staff(
+        staffs: &mut Vec<Staff>,
+        named: &[&StaffEntity],
+        staff_generator: &StaffGenerator,
+        country_id: u32,
+        team_reputation: u16,
+    ) {
+        let mut taken = vec![false; staffs.len()];
+        for entry in named {
+            let seat = staffs.iter().zip(&taken).position(|(staff, taken)| {
+                !taken
+                    && staff
+                        .contract
+                        .as_ref()
+                        .is_some_and(|c| c.position == entry.position)
+            });
+            let idx = match seat {
+                Some(idx) => idx,
+                None => {
+                    staffs.push(staff_generator.generate(
+                        country_id,
+                        entry.position.clone(),
+                        team_reputation,
+                    ));
+                    taken.push(false);
+                    staffs.len() - 1
+                }
+            };
+            taken[idx] = true;
+
+            let staff = &mut staffs[idx];
+            staff.full_name = FullName::new(entry.first_name.clone(), entry.last_name.clone());
+            staff.country_id = entry.country_id.unwrap_or(country_id);
+            if let Some(birth_date) = entry.birth_date {
+                staff.birth_date = birth_date;
+            }
+        }
+    }
+
     /// Coaching, medical and analytics depth for a main team, scaled by
     /// reputation. Elite clubs field a full modern backroom (assistant,
     /// generalist coaches, GK + fitness specialists, a head physio leading
@@ -271,7 +316,7 @@ mod tests {
         StaffGenerator::with_people_names(&PeopleNameGeneratorData {
             first_names: vec!["Alex".into(), "Sam".into()],
             last_names: vec!["Smith".into(), "Jones".into()],
-            nicknames: vec![],
+            ..Default::default()
         })
     }
 
@@ -465,11 +465,7 @@ pub struct PlayerGenerator {
 impl PlayerGenerator {
     pub fn with_people_names(people_names: &PeopleNameGeneratorData) -> Self {
         PlayerGenerator {
-            people_names_data: PeopleNameGeneratorData {
-                first_names: people_names.first_names.clone(),
-                last_names: people_names.last_names.clone(),
-                nicknames: people_names.nicknames.clone(),
-            },
+            people_names_data: people_names.clone(),
         }
     }
 }
@@ -1563,34 +1559,24 @@ impl PlayerGenerator {
     // ── Name generation ─────────────────────────────────────────────────
 
     fn generate_nickname(&self) -> Option<String> {
-        if self.people_names_data.nicknames.is_empty() {
-            return None;
-        }
         if IntegerUtils::random(0, 9) != 0 {
             return None;
         }
-        let idx = IntegerUtils::random(0, self.people_names_data.nicknames.len() as i32) as usize;
-        Some(self.people_names_data.nicknames[idx].to_owned())
+        self.people_names_data.random_nickname().map(str::to_owned)
     }
 
     fn generate_first_name(&self) -> String {
-        let names = &self.people_names_data.first_names;
-        if names.is_empty() {
-            return String::new();
-        }
-        // random() is [min, max) — full-length bound keeps the final list
-        // entry reachable.
-        let idx = IntegerUtils::random(0, names.len() as i32) as usize;
-        names[idx].to_owned()
+        self.people_names_data
+            .random_first_name()
+            .unwrap_or_default()
+            .to_owned()
     }
 
     fn generate_last_name(&self) -> String {
-        let names = &self.people_names_data.last_names;
-        if names.is_empty() {
-            return String::new();
-        }
-        let idx = IntegerUtils::random(0, names.len() as i32) as usize;
-        names[idx].to_owned()
+        self.people_names_data
+            .random_last_name()
+            .unwrap_or_default()
+            .to_owned()
     }
 
     // ── ODB hydration ───────────────────────────────────────────────────
@@ -1761,6 +1747,11 @@ impl PotentialAbility {
         (-1, 0, 20),
     ];
 
+    /// Whether `raw` is an ability (0..=200) or one of the band codes.
+    pub fn is_known(raw: i16) -> bool {
+        (0..=200).contains(&raw) || Self::BANDS.iter().any(|(band, _, _)| *band == raw)
+    }
+
     pub fn resolve(raw: i16, rng: &mut HydrationRng) -> u8 {
         if raw >= 0 {
             return raw.min(200) as u8;
@@ -1938,7 +1929,7 @@ impl PositionType {
     }
 }
 
-fn parse_position_code(code: &str) -> Option<PlayerPositionType> {
+pub(crate) fn parse_position_code(code: &str) -> Option<PlayerPositionType> {
     Some(match code.to_ascii_uppercase().as_str() {
         "GK" => PlayerPositionType::Goalkeeper,
         "SW" => PlayerPositionType::Sweeper,
@@ -2602,7 +2593,7 @@ mod generator_validation_tests {
         PlayerGenerator::with_people_names(&PeopleNameGeneratorData {
             first_names: vec!["Alex".into(), "Sam".into(), "Jordan".into()],
             last_names: vec!["Smith".into(), "Garcia".into(), "Park".into()],
-            nicknames: vec![],
+            ..Default::default()
         })
     }
 
@@ -3600,6 +3591,7 @@ mod odb_hydration_tests {
             national_competitions: vec![],
             names_by_country: vec![],
             players_odb: None,
+            staff: vec![],
             index: std::sync::OnceLock::new(),
         }
     }
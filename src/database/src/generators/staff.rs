@@ -21,11 +21,7 @@ pub struct StaffGenerator {
 impl StaffGenerator {
     pub fn with_people_names(people_names: &PeopleNameGeneratorData) -> Self {
         StaffGenerator {
-            people_names_data: PeopleNameGeneratorData {
-                first_names: people_names.first_names.clone(),
-                last_names: people_names.last_names.clone(),
-                nicknames: people_names.nicknames.clone(),
-            },
+            people_names_data: people_names.clone(),
         }
     }
 }
@@ -150,21 +146,17 @@ impl StaffGenerator {
     }
 
     fn generate_first_name(&self) -> String {
-        let names = &self.people_names_data.first_names;
-        if names.is_empty() {
-            return String::new();
-        }
-        let idx = IntegerUtils::random(0, names.len() as i32 - 1) as usize;
-        names[idx].to_owned()
+        self.people_names_data
+            .random_first_name()
+            .unwrap_or_default()
+            .to_owned()
     }
 
     fn generate_last_name(&self) -> String {
-        let names = &self.people_names_data.last_names;
-        if names.is_empty() {
-            return String::new();
-        }
-        let idx = IntegerUtils::random(0, names.len() as i32 - 1) as usize;
-        names[idx].to_owned()
+        self.people_names_data
+            .random_last_name()
+            .unwrap_or_default()
+            .to_owned()
     }
 }
 
@@ -1,17 +1,30 @@
+mod diff;
 mod generators;
 mod loaders;
+mod overlay;
+mod progress;
+mod validation;
 
 use std::collections::HashMap;
+use std::io;
+use std::path::Path;
 use std::sync::OnceLock;
 
+use progress::StageCounter;
+
 pub use loaders::{
     ClubEntity, ClubTeamEntity, ContinentEntity, ContinentLoader, CountryEntity, CountryLoader,
-    DataTreeLoader, DomesticCupEntity, ForeignPlayerEntry, LeagueEntity, NamesByCountryEntity,
-    NationalCompetitionEntity, NationalCompetitionLoader, OdbContract, OdbLoan, OdbPlayer,
-    OdbPosition, OdbReputation, PlayersOdb,
+    DataTreeLoader, DatabaseError, DirectoryLoader, DomesticCupEntity, ForeignPlayerEntry,
+    LeagueEntity, NameEntity, NamesByCountryEntity, NationalCompetitionEntity,
+    NationalCompetitionLoader, OdbContract, OdbLoan, OdbPlayer, OdbPosition, OdbReputation,
+    PlayersOdb, StaffEntity, TableFormat,
 };
 
+pub use diff::{DatabaseDiff, FieldDiff, RecordDiff, TableDiff};
 pub use generators::DatabaseGenerator;
+pub use overlay::DatabaseOverlay;
+pub use progress::{LoadProgress, LoadStage};
+pub use validation::{Issue, Severity, ValidationReport};
 
 /// id → vector-position indexes over the loaded entity lists. Player
 /// hydration resolves clubs/teams/leagues per record (and per history row);
@@ -38,6 +51,9 @@ pub struct DatabaseEntity {
     /// is populated from this file instead of via procedural generation.
     pub players_odb: Option<PlayersOdb>,
 
+    /// Named staff of a user-built world, seated over the generated backroom.
+    pub staff: Vec<StaffEntity>,
+
     /// Lazily-built id indexes; safe to share across the parallel
     /// generation passes.
     index: OnceLock<EntityIndex>,
@@ -87,16 +103,73 @@ impl DatabaseEntity {
             .get(&id)
             .map(|&i| &self.leagues[i])
     }
+
+    /// Write this database, overlay edits included, to `directory` as
+    /// tables `DatabaseLoader::load_dir` reads. Only enabled leagues are
+    /// part of a loaded database, so only those, their clubs and the
+    /// players of those clubs are written.
+    pub fn export(&self, directory: &Path, format: TableFormat) -> io::Result<()> {
+        loaders::write_tables(directory, &loaders::tables(&self.to_compiled()), format)
+    }
+
+    fn to_compiled(&self) -> loaders::CompiledDatabase {
+        let mut players: Vec<OdbPlayer> = self
+            .players_odb
+            .iter()
+            .flat_map(|odb| odb.players())
+            .filter(|player| player.club_id == 0 || self.club_by_id(player.club_id).is_some())
+            .cloned()
+            .collect();
+        players.sort_by_key(|player| player.id);
+
+        loaders::CompiledDatabase {
+            version: loaders::SUPPORTED_VERSION.to_string(),
+            continents: self.continents.clone(),
+            countries: self.countries.clone(),
+            national_competitions: self.national_competitions.clone(),
+            domestic_cups: self
+                .countries
+                .iter()
+                .filter_map(|country| country.domestic_cup.clone())
+                .collect(),
+            leagues: self.leagues.clone(),
+            clubs: self.clubs.clone(),
+            names: self.names_by_country.clone(),
+            players,
+            staff: self.staff.clone(),
+        }
+    }
 }
 
 pub struct DatabaseLoader;
 
 impl DatabaseLoader {
+    /// Send load progress to `callback` from here on. First call wins;
+    /// install it before loading, as the embedded database is parsed on
+    /// first use.
+    pub fn on_progress(callback: impl Fn(LoadProgress) + Send + Sync + 'static) {
+        progress::install(Box::new(callback));
+    }
+
     pub fn load() -> DatabaseEntity {
+        loaders::compiled();
+        let building = StageCounter::start(LoadStage::Building, 3);
+
         let continents = ContinentLoader::load();
         let countries = CountryLoader::load();
-        let tree = DataTreeLoader::load(&countries);
-        let players_odb = PlayersOdb::load();
+        building.add(1);
+        let (tree, players_odb) = rayon::join(
+            || {
+                let tree = DataTreeLoader::load(&countries);
+                building.add(1);
+                tree
+            },
+            || {
+                let players_odb = PlayersOdb::load();
+                building.add(1);
+                players_odb
+            },
+        );
 
         DatabaseEntity {
             continents,
@@ -106,7 +179,28 @@ impl DatabaseLoader {
             national_competitions: NationalCompetitionLoader::load(),
             names_by_country: tree.names_by_country,
             players_odb,
+            staff: loaders::compiled().staff.clone(),
             index: OnceLock::new(),
         }
     }
+
+    /// Load a world from a directory of JSON/CSV tables instead of the
+    /// embedded database; see `DirectoryLoader` for the layout. Must run
+    /// before anything else reads the database.
+    pub fn load_dir(directory: &Path) -> Result<DatabaseEntity, DatabaseError> {
+        let db = DirectoryLoader::read(directory)?;
+        loaders::install(db).map_err(|problem| DatabaseError {
+            directory: directory.to_path_buf(),
+            problems: vec![problem],
+        })?;
+        Ok(Self::load())
+    }
+
+    /// Make up a fictional world from `seed` instead of loading the
+    /// embedded database; the same seed gives the same world. Must run
+    /// before anything else reads the database.
+    pub fn load_fictional(seed: u64) -> Result<DatabaseEntity, String> {
+        loaders::install(loaders::fictional_world(seed))?;
+        Ok(Self::load())
+    }
 }
@@ -5,12 +5,17 @@
 //! into a single gzip-compressed JSON document and embedded at compile time.
 //!
 //! Parsing happens exactly once per process via [`OnceLock`]; every `*Loader`
-//! reads from the cached [`CompiledDatabase`].
+//! reads from the cached [`CompiledDatabase`], whose records are parsed in
+//! parallel shards of each table. A world read from a user directory (see
+//! `directory`) is [`install`]ed in its place before the first read.
 
 use std::io::Read;
 use std::sync::OnceLock;
 
-use serde::Deserialize;
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
 
 use super::club::ClubEntity;
 use super::continent::ContinentEntity;
@@ -20,12 +25,14 @@ use super::league::LeagueEntity;
 use super::names::NamesByCountryEntity;
 use super::national::NationalCompetitionEntity;
 use super::players::OdbPlayer;
+use super::staff::StaffEntity;
+use crate::progress::{LoadStage, StageCounter};
 
 pub const SUPPORTED_VERSION: &str = "1.0";
 
 static DATABASE_BYTES: &[u8] = include_bytes!("../data/database.db");
 
-#[derive(Deserialize)]
+#[derive(Serialize, Deserialize)]
 pub struct CompiledDatabase {
     pub version: String,
     pub continents: Vec<ContinentEntity>,
@@ -39,6 +46,10 @@ pub struct CompiledDatabase {
     pub clubs: Vec<ClubEntity>,
     pub names: Vec<NamesByCountryEntity>,
     pub players: Vec<OdbPlayer>,
+    /// Named staff placed over the generated backroom. The embedded
+    /// database has none; only user-built worlds carry them.
+    #[serde(default)]
+    pub staff: Vec<StaffEntity>,
 }
 
 static DB: OnceLock<CompiledDatabase> = OnceLock::new();
@@ -54,20 +65,134 @@ pub fn compiled() -> &'static CompiledDatabase {
     })
 }
 
+/// Serve `db` instead of the embedded database. Fails once anything has
+/// already read the database, as loaders would then disagree on the world.
+pub fn install(db: CompiledDatabase) -> Result<(), String> {
+    DB.set(db)
+        .map_err(|_| "a game database is already loaded".to_string())
+}
+
+/// A fresh parse of the embedded database, independent of the installed one.
+pub fn embedded() -> Result<CompiledDatabase, String> {
+    decode(DATABASE_BYTES)
+}
+
+/// The document with each table left as raw records, so the records can
+/// be parsed in parallel shards.
+#[derive(Deserialize)]
+struct RawDatabase<'a> {
+    version: String,
+    #[serde(borrow)]
+    continents: Vec<&'a RawValue>,
+    #[serde(borrow)]
+    countries: Vec<&'a RawValue>,
+    #[serde(borrow)]
+    national_competitions: Vec<&'a RawValue>,
+    #[serde(borrow, default)]
+    domestic_cups: Vec<&'a RawValue>,
+    #[serde(borrow)]
+    leagues: Vec<&'a RawValue>,
+    #[serde(borrow)]
+    clubs: Vec<&'a RawValue>,
+    #[serde(borrow)]
+    names: Vec<&'a RawValue>,
+    #[serde(borrow)]
+    players: Vec<&'a RawValue>,
+    #[serde(borrow, default)]
+    staff: Vec<&'a RawValue>,
+}
+
 fn decode(compressed: &[u8]) -> Result<CompiledDatabase, String> {
+    let reading = StageCounter::start(LoadStage::Reading, 1);
     let mut dec = flate2::read::GzDecoder::new(compressed);
     let mut json = String::new();
     dec.read_to_string(&mut json)
         .map_err(|e| format!("gunzip: {e}"))?;
-    let parsed: CompiledDatabase =
-        serde_json::from_str(&json).map_err(|e| format!("parse: {e}"))?;
-    if parsed.version != SUPPORTED_VERSION {
+    reading.add(1);
+
+    let raw: RawDatabase = serde_json::from_str(&json).map_err(|e| format!("parse: {e}"))?;
+    if raw.version != SUPPORTED_VERSION {
         return Err(format!(
             "unsupported database.db version '{}' (expected '{}')",
-            parsed.version, SUPPORTED_VERSION
+            raw.version, SUPPORTED_VERSION
         ));
     }
-    Ok(parsed)
+
+    let parsing = StageCounter::start(
+        LoadStage::Parsing,
+        [
+            &raw.continents,
+            &raw.countries,
+            &raw.national_competitions,
+            &raw.domestic_cups,
+            &raw.leagues,
+            &raw.clubs,
+            &raw.names,
+            &raw.players,
+            &raw.staff,
+        ]
+        .iter()
+        .map(|table| table.len())
+        .sum(),
+    );
+    Ok(CompiledDatabase {
+        version: raw.version,
+        continents: parse_table("continents", &raw.continents, &parsing)?,
+        countries: parse_table("countries", &raw.countries, &parsing)?,
+        national_competitions: parse_table(
+            "national_competitions",
+            &raw.national_competitions,
+            &parsing,
+        )?,
+        domestic_cups: parse_table("domestic_cups", &raw.domestic_cups, &parsing)?,
+        leagues: parse_table("leagues", &raw.leagues, &parsing)?,
+        clubs: parse_table("clubs", &raw.clubs, &parsing)?,
+        names: parse_table("names", &raw.names, &parsing)?,
+        players: parse_table("players", &raw.players, &parsing)?,
+        staff: parse_table("staff", &raw.staff, &parsing)?,
+    })
+}
+
+fn parse_table<T: DeserializeOwned + Send>(
+    name: &str,
+    records: &[&RawValue],
+    progress: &StageCounter,
+) -> Result<Vec<T>, String> {
+    parse_sharded(records, progress, |record| {
+        serde_json::from_str(record.get())
+    })
+    .into_iter()
+    .enumerate()
+    .map(|(idx, record)| record.map_err(|e| format!("parse: {name}[{idx}]: {e}")))
+    .collect()
+}
+
+/// Records per parallel parsing task.
+const SHARD_SIZE: usize = 512;
+
+/// `parse` applied to every record, shards of them in parallel, in the
+/// records' order.
+pub(super) fn parse_sharded<R, T, E>(
+    records: &[R],
+    progress: &StageCounter,
+    parse: impl Fn(&R) -> Result<T, E> + Sync,
+) -> Vec<Result<T, E>>
+where
+    R: Sync,
+    T: Send,
+    E: Send,
+{
+    records
+        .par_chunks(SHARD_SIZE)
+        .map(|shard| {
+            let parsed: Vec<_> = shard.iter().map(&parse).collect();
+            progress.add(shard.len());
+            parsed
+        })
+        .collect::<Vec<_>>()
+        .into_iter()
+        .flatten()
+        .collect()
 }
 
 /// Resolve a country code like "mt" → its numeric id. Returns 0 when the code
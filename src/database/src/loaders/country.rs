@@ -1,9 +1,9 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 use super::compiled::compiled;
 use super::domestic_cup::DomesticCupEntity;
 
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct CountryEntity {
     pub id: u32,
     pub code: String,
@@ -16,25 +16,27 @@ pub struct CountryEntity {
     pub settings: CountrySettingsEntity,
     #[serde(default)]
     pub skin_colors: SkinColorsEntity,
+    #[serde(default)]
+    pub regulations: CountryRegulationsEntity,
     /// The country's named domestic cup, resolved from the compiled
     /// `domestic_cups` table by `CountryLoader::load`. Not present in
-    /// countries.json (hence `skip_deserializing`); `None` means the
+    /// countries.json (hence `skip`); `None` means the
     /// runtime generator falls back to a "{Country} Cup".
-    #[serde(skip_deserializing, default)]
+    #[serde(skip, default)]
     pub domestic_cup: Option<DomesticCupEntity>,
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct CountrySettingsEntity {
     pub pricing: CountryPricingEntity,
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct CountryPricingEntity {
     pub price_level: f32,
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct SkinColorsEntity {
     pub white: u8,
     pub black: u8,
@@ -51,6 +53,39 @@ impl Default for SkinColorsEntity {
     }
 }
 
+/// Squad registration rules. Every field is optional; an absent block
+/// leaves the country unregulated.
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default)]
+pub struct CountryRegulationsEntity {
+    pub foreign_player_limit: Option<u8>,
+    /// Size of the named senior list (under-21s register freely).
+    pub registered_squad_size: Option<u8>,
+    pub homegrown_requirements: Option<u8>,
+    pub club_trained_requirements: Option<u8>,
+    /// International caps a non-continental signing needs for a permit.
+    pub work_permit_min_caps: Option<u16>,
+    pub non_eu_player_limit: Option<u8>,
+    /// Federation registration windows. Absent = the built-in calendar.
+    pub transfer_windows: Option<TransferWindowsEntity>,
+}
+
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TransferWindowsEntity {
+    pub summer: TransferWindowEntity,
+    pub winter: TransferWindowEntity,
+}
+
+/// One window as month/day pairs; a close before the open runs into the
+/// next year.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct TransferWindowEntity {
+    pub open_month: u32,
+    pub open_day: u32,
+    pub close_month: u32,
+    pub close_day: u32,
+}
+
 pub struct CountryLoader;
 
 impl CountryLoader {
@@ -0,0 +1,903 @@
+//! User-built game databases.
+//!
+//! A world can be read from a directory instead of the embedded
+//! `database.db`. The directory holds one file per table — `continents`,
+//! `countries`, `leagues`, `clubs`, `players`, `staff`, `names`,
+//! `domestic_cups` and `national_competitions` — either as `<table>.json`,
+//! an array of records shaped like the compiled database's, or as
+//! `<table>.csv`. CSV headers name record fields, with dots for nested
+//! fields and numbers for list entries: `settings.pricing.price_level`,
+//! `teams.0.slug`, `positions.1.code`. An empty cell leaves the field out.
+//! A large table can be split into shards instead: a `<table>` directory
+//! of such files, e.g. `players/es.csv` and `players/pt.json`, whose
+//! records follow each other in file name order. Files are read, and
+//! records parsed, in parallel.
+//!
+//! `continents`, `countries`, `leagues` and `clubs` are required. Without
+//! `names`, countries whose code the embedded database knows borrow its
+//! name pools; the other tables may simply be left out. Every record is
+//! checked against the schema and against the other tables, and all
+//! problems are reported together.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+use core::TeamType;
+use log::warn;
+use memmap2::Mmap;
+use rayon::prelude::*;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use super::compiled::{CompiledDatabase, SUPPORTED_VERSION, embedded, parse_sharded};
+use super::{ClubEntity, CountryEntity, LeagueEntity, OdbPlayer, StaffEntity};
+use crate::generators::parse_position_code;
+use crate::progress::{LoadStage, StageCounter};
+
+/// Problems reported per table before the rest are summarised; a file in
+/// the wrong shape would otherwise fail on every row.
+const MAX_PROBLEMS_PER_TABLE: usize = 20;
+
+/// Why a database directory can't be loaded — every problem found.
+#[derive(Debug)]
+pub struct DatabaseError {
+    pub directory: PathBuf,
+    pub problems: Vec<String>,
+}
+
+impl fmt::Display for DatabaseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "database in {} has {} problem(s):",
+            self.directory.display(),
+            self.problems.len()
+        )?;
+        for problem in &self.problems {
+            write!(f, "\n  - {}", problem)?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for DatabaseError {}
+
+pub struct DirectoryLoader;
+
+impl DirectoryLoader {
+    /// Read and validate every table in `directory`.
+    pub fn read(directory: &Path) -> Result<CompiledDatabase, DatabaseError> {
+        let db = Self::parse(directory)?;
+        let checking = StageCounter::start(LoadStage::Checking, 1);
+        let mut problems = Vec::new();
+        validate(&db, &mut problems);
+        checking.add(1);
+        if problems.is_empty() {
+            Ok(db)
+        } else {
+            Err(DatabaseError {
+                directory: directory.to_path_buf(),
+                problems,
+            })
+        }
+    }
+
+    /// Read every table in `directory`, checking the records against the
+    /// schema but not against each other.
+    pub(crate) fn parse(directory: &Path) -> Result<CompiledDatabase, DatabaseError> {
+        let mut problems = Vec::new();
+        let fail = |problems| DatabaseError {
+            directory: directory.to_path_buf(),
+            problems,
+        };
+
+        if !directory.is_dir() {
+            problems.push("not a directory".to_string());
+            return Err(fail(problems));
+        }
+
+        let mut tables = Tables::read_all(directory, &mut problems);
+        let continents = tables.required("continents");
+        let countries = tables.required("countries");
+        let leagues = tables.required("leagues");
+        let clubs = tables.required("clubs");
+        let players = tables.optional("players");
+        let staff = tables.optional("staff");
+        let names = tables.read("names");
+        let domestic_cups = tables.optional("domestic_cups");
+        let national_competitions = tables.optional("national_competitions");
+        if !problems.is_empty() {
+            return Err(fail(problems));
+        }
+
+        let names = names.unwrap_or_else(|| embedded_names(&countries));
+        Ok(CompiledDatabase {
+            version: SUPPORTED_VERSION.to_string(),
+            continents,
+            countries,
+            national_competitions,
+            domestic_cups,
+            leagues,
+            clubs,
+            names,
+            players,
+            staff,
+        })
+    }
+}
+
+const TABLES: [&str; 9] = [
+    "continents",
+    "countries",
+    "leagues",
+    "clubs",
+    "players",
+    "staff",
+    "names",
+    "domestic_cups",
+    "national_competitions",
+];
+
+/// Files at least this large are memory-mapped rather than read.
+const MAP_FILE_BYTES: u64 = 1 << 20;
+
+/// The rows of every table found in a directory, read before any of them
+/// is parsed.
+struct Tables<'a> {
+    /// Rows of each table that has files, with the place to point an
+    /// error at.
+    rows: HashMap<&'static str, Vec<(String, Value)>>,
+    parsing: StageCounter,
+    problems: &'a mut Vec<String>,
+}
+
+impl<'a> Tables<'a> {
+    /// Read the files of every table, all of them in parallel.
+    fn read_all(directory: &Path, problems: &'a mut Vec<String>) -> Self {
+        let mut files = Vec::new();
+        let mut present = HashSet::new();
+        for table in TABLES {
+            match table_files(directory, table) {
+                Ok(Some(paths)) => {
+                    present.insert(table);
+                    files.extend(paths.into_iter().map(|path| (table, path)));
+                }
+                Ok(None) => {}
+                Err(problem) => {
+                    present.insert(table);
+                    problems.push(problem);
+                }
+            }
+        }
+
+        let reading = StageCounter::start(LoadStage::Reading, files.len());
+        let read: Vec<_> = files
+            .par_iter()
+            .map(|(table, path)| {
+                let name = path
+                    .strip_prefix(directory)
+                    .unwrap_or(path)
+                    .display()
+                    .to_string();
+                let rows = match path.extension().and_then(|e| e.to_str()) {
+                    Some("csv") => csv_rows(path, &name),
+                    _ => json_rows(path, &name),
+                };
+                reading.add(1);
+                (*table, rows)
+            })
+            .collect();
+
+        let mut rows: HashMap<&'static str, Vec<(String, Value)>> = present
+            .into_iter()
+            .map(|table| (table, Vec::new()))
+            .collect();
+        for (table, file_rows) in read {
+            match file_rows {
+                Ok(file_rows) => rows.entry(table).or_default().extend(file_rows),
+                Err(problem) => problems.push(problem),
+            }
+        }
+
+        let parsing = StageCounter::start(LoadStage::Parsing, rows.values().map(Vec::len).sum());
+        Tables {
+            rows,
+            parsing,
+            problems,
+        }
+    }
+
+    fn required<T: DeserializeOwned + Send>(&mut self, table: &str) -> Vec<T> {
+        let records = self.read(table);
+        if records.is_none() {
+            self.problems.push(format!(
+                "{table}: missing, expected {table}.json, {table}.csv or a {table} directory"
+            ));
+        }
+        records.unwrap_or_default()
+    }
+
+    fn optional<T: DeserializeOwned + Send>(&mut self, table: &str) -> Vec<T> {
+        self.read(table).unwrap_or_default()
+    }
+
+    /// The table's records, or `None` when it has no file.
+    fn read<T: DeserializeOwned + Send>(&mut self, table: &str) -> Option<Vec<T>> {
+        let rows = self.rows.remove(table)?;
+        let parsed = parse_sharded(&rows, &self.parsing, |(location, value)| {
+            T::deserialize(value).map_err(|err| format!("{location}: {err}"))
+        });
+
+        let mut records = Vec::with_capacity(rows.len());
+        let mut failed = 0;
+        for record in parsed {
+            match record {
+                Ok(record) => records.push(record),
+                Err(problem) => {
+                    failed += 1;
+                    if failed <= MAX_PROBLEMS_PER_TABLE {
+                        self.problems.push(problem);
+                    }
+                }
+            }
+        }
+        if failed > MAX_PROBLEMS_PER_TABLE {
+            self.problems.push(format!(
+                "{table}: {} more invalid records",
+                failed - MAX_PROBLEMS_PER_TABLE
+            ));
+        }
+        Some(records)
+    }
+}
+
+/// The files holding `table`: `<table>.json`, `<table>.csv`, or every
+/// JSON and CSV file of a `<table>` directory — one shard each, e.g. one
+/// per country — in name order. `None` when there are none of them.
+fn table_files(directory: &Path, table: &str) -> Result<Option<Vec<PathBuf>>, String> {
+    let json = directory.join(format!("{table}.json"));
+    let csv = directory.join(format!("{table}.csv"));
+    let shards = directory.join(table);
+    let found: Vec<String> = [
+        (json.is_file(), format!("{table}.json")),
+        (csv.is_file(), format!("{table}.csv")),
+        (shards.is_dir(), format!("{table}/")),
+    ]
+    .into_iter()
+    .filter_map(|(exists, name)| exists.then_some(name))
+    .collect();
+
+    match found.as_slice() {
+        [] => Ok(None),
+        [_] if json.is_file() => Ok(Some(vec![json])),
+        [_] if csv.is_file() => Ok(Some(vec![csv])),
+        [_] => {
+            let entries = fs::read_dir(&shards).map_err(|e| format!("{table}/: {e}"))?;
+            let mut paths = Vec::new();
+            for entry in entries {
+                let path = entry.map_err(|e| format!("{table}/: {e}"))?.path();
+                let is_table = path.extension().is_some_and(|e| e == "json" || e == "csv");
+                if is_table && path.is_file() {
+                    paths.push(path);
+                }
+            }
+            paths.sort();
+            Ok(Some(paths))
+        }
+        [first, second] => Err(format!(
+            "{table}: both {first} and {second} exist, keep one"
+        )),
+        [first, second, third, ..] => Err(format!(
+            "{table}: {first}, {second} and {third} exist, keep one"
+        )),
+    }
+}
+
+/// A table file's bytes, mapped into memory when the file is large.
+enum FileBytes {
+    Read(Vec<u8>),
+    Mapped(Mmap),
+}
+
+impl FileBytes {
+    fn open(path: &Path) -> std::io::Result<Self> {
+        let file = fs::File::open(path)?;
+        if file.metadata()?.len() < MAP_FILE_BYTES {
+            return fs::read(path).map(FileBytes::Read);
+        }
+        // SAFETY: the map is read-only and dropped once the file's rows
+        // are parsed; like any loader we assume the database isn't being
+        // rewritten while it is read.
+        unsafe { Mmap::map(&file) }.map(FileBytes::Mapped)
+    }
+}
+
+impl Deref for FileBytes {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            FileBytes::Read(bytes) => bytes,
+            FileBytes::Mapped(map) => map,
+        }
+    }
+}
+
+/// Records of a JSON table, each with the place to point an error at.
+fn json_rows(path: &Path, name: &str) -> Result<Vec<(String, Value)>, String> {
+    let bytes = FileBytes::open(path).map_err(|e| format!("{name}: {e}"))?;
+    match serde_json::from_slice(&bytes).map_err(|e| format!("{name}: {e}"))? {
+        Value::Array(items) => Ok(items
+            .into_iter()
+            .enumerate()
+            .map(|(idx, item)| (format!("{name}, record {}", idx + 1), item))
+            .collect()),
+        _ => Err(format!("{name}: expected an array of records")),
+    }
+}
+
+/// Records of a CSV table, turned into the JSON shape its headers describe.
+fn csv_rows(path: &Path, name: &str) -> Result<Vec<(String, Value)>, String> {
+    let bytes = FileBytes::open(path).map_err(|e| format!("{name}: {e}"))?;
+    let mut reader = csv::ReaderBuilder::new()
+        .trim(csv::Trim::All)
+        .from_reader(&bytes[..]);
+    let headers: Vec<Vec<String>> = reader
+        .headers()
+        .map_err(|e| format!("{name}: {e}"))?
+        .iter()
+        .map(|h| h.split('.').map(str::to_string).collect())
+        .collect();
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| format!("{name}: {e}"))?;
+        let line = record.position().map(|p| p.line()).unwrap_or_default();
+        let location = format!("{name}, line {line}");
+
+        let mut value = Value::Object(Map::new());
+        for (path, cell) in headers.iter().zip(record.iter()) {
+            if cell.is_empty() {
+                continue;
+            }
+            insert(&mut value, path, cell_value(cell))
+                .map_err(|e| format!("{location}: column '{}' {e}", path.join(".")))?;
+        }
+        compact(&mut value);
+        rows.push((location, value));
+    }
+    Ok(rows)
+}
+
+/// Place `value` at the dotted `path`, creating objects for names and
+/// lists for numeric segments on the way.
+pub(super) fn insert(target: &mut Value, path: &[String], value: Value) -> Result<(), String> {
+    let Some((segment, rest)) = path.split_first() else {
+        *target = value;
+        return Ok(());
+    };
+    let slot = match segment.parse::<usize>() {
+        Ok(index) => {
+            if target.is_null() {
+                *target = Value::Array(Vec::new());
+            }
+            let Value::Array(items) = target else {
+                return Err("mixes a list with a field".to_string());
+            };
+            if items.len() <= index {
+                items.resize(index + 1, Value::Null);
+            }
+            &mut items[index]
+        }
+        Err(_) => {
+            if target.is_null() {
+                *target = Value::Object(Map::new());
+            }
+            let Value::Object(fields) = target else {
+                return Err("mixes a field with a list or value".to_string());
+            };
+            fields.entry(segment.clone()).or_insert(Value::Null)
+        }
+    };
+    if rest.is_empty() && !slot.is_null() {
+        return Err("is given twice".to_string());
+    }
+    insert(slot, rest, value)
+}
+
+/// Drop the gaps left by list entries whose cells were all empty.
+pub(super) fn compact(value: &mut Value) {
+    match value {
+        Value::Array(items) => {
+            items.retain(|item| !item.is_null());
+            items.iter_mut().for_each(compact);
+        }
+        Value::Object(fields) => fields.values_mut().for_each(compact),
+        _ => {}
+    }
+}
+
+/// A cell's JSON value: booleans and numbers when the text reads as one,
+/// except zero-padded digits, which stay text.
+pub(super) fn cell_value(cell: &str) -> Value {
+    match cell {
+        "true" => return Value::Bool(true),
+        "false" => return Value::Bool(false),
+        _ => {}
+    }
+    let digits = cell.strip_prefix('-').unwrap_or(cell);
+    if digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.") {
+        return Value::String(cell.to_string());
+    }
+    if let Ok(number) = cell.parse::<i64>() {
+        return Value::from(number);
+    }
+    cell.parse::<f64>()
+        .ok()
+        .filter(|n| n.is_finite())
+        .and_then(serde_json::Number::from_f64)
+        .map(Value::Number)
+        .unwrap_or_else(|| Value::String(cell.to_string()))
+}
+
+/// Name pools of the embedded database for the countries it shares by code.
+fn embedded_names(countries: &[CountryEntity]) -> Vec<super::NamesByCountryEntity> {
+    let codes: HashSet<&str> = countries.iter().map(|c| c.code.as_str()).collect();
+    match embedded() {
+        Ok(db) => db
+            .names
+            .into_iter()
+            .filter(|n| codes.contains(n.country_code.as_str()))
+            .collect(),
+        Err(err) => {
+            warn!("no embedded name pools to fall back on: {}", err);
+            Vec::new()
+        }
+    }
+}
+
+/// Cross-table checks the schema can't express.
+fn validate(db: &CompiledDatabase, problems: &mut Vec<String>) {
+    let mut report = |message: String| problems.push(message);
+
+    duplicates(
+        "continents",
+        db.continents.iter().map(|c| c.id),
+        &mut report,
+    );
+    duplicates("countries", db.countries.iter().map(|c| c.id), &mut report);
+    duplicates("leagues", db.leagues.iter().map(|l| l.id), &mut report);
+    duplicates("clubs", db.clubs.iter().map(|c| c.id), &mut report);
+    duplicates(
+        "clubs: team",
+        db.clubs.iter().flat_map(|c| c.teams.iter().map(|t| t.id)),
+        &mut report,
+    );
+
+    let continent_ids: HashSet<u32> = db.continents.iter().map(|c| c.id).collect();
+    let country_slugs: HashSet<&str> = db.countries.iter().map(|c| c.slug.as_str()).collect();
+    let refs = References::new(&db.countries, &db.leagues, &db.clubs);
+
+    for country in &db.countries {
+        if country.code.is_empty() {
+            report(format!("countries: country {} has no code", country.id));
+        }
+        if !continent_ids.contains(&country.continent_id) {
+            report(format!(
+                "countries: country {} ({}) has unknown continent_id {}",
+                country.id, country.name, country.continent_id
+            ));
+        }
+    }
+
+    for league in &db.leagues {
+        if !refs.country_codes.contains(league.country_code.as_str()) {
+            report(format!(
+                "leagues: league {} ({}) has unknown country_code '{}'",
+                league.id, league.name, league.country_code
+            ));
+        }
+        if let Some(target) = league
+            .relegation_league_id
+            .filter(|id| !refs.league_ids.contains(id))
+        {
+            report(format!(
+                "leagues: league {} ({}) relegates into unknown league {}",
+                league.id, league.name, target
+            ));
+        }
+    }
+    if !db.leagues.iter().any(|l| l.enabled) {
+        report("leagues: no league is enabled, set \"enabled\": true".to_string());
+    }
+
+    for club in &db.clubs {
+        check_club(club, &refs, &mut |m| report(format!("clubs: {m}")));
+    }
+
+    for cup in &db.domestic_cups {
+        if !country_slugs.contains(cup.country_slug.as_str()) {
+            report(format!(
+                "domestic_cups: {} has unknown country_slug '{}'",
+                cup.name, cup.country_slug
+            ));
+        }
+    }
+
+    for player in &db.players {
+        check_player(player, &refs, &mut |m| report(format!("players: {m}")));
+    }
+
+    for member in &db.staff {
+        check_staff(member, &refs, &mut |m| report(format!("staff: {m}")));
+    }
+}
+
+/// Ids and codes records may refer to, shared by the directory checks
+/// and the overlay editor.
+pub(crate) struct References<'a> {
+    country_ids: HashSet<u32>,
+    country_codes: HashSet<&'a str>,
+    league_ids: HashSet<u32>,
+    clubs: HashMap<u32, &'a ClubEntity>,
+}
+
+impl<'a> References<'a> {
+    pub(crate) fn new(
+        countries: &'a [CountryEntity],
+        leagues: &[LeagueEntity],
+        clubs: impl IntoIterator<Item = &'a ClubEntity>,
+    ) -> Self {
+        References {
+            country_ids: countries.iter().map(|c| c.id).collect(),
+            country_codes: countries.iter().map(|c| c.code.as_str()).collect(),
+            league_ids: leagues.iter().map(|l| l.id).collect(),
+            clubs: clubs.into_iter().map(|c| (c.id, c)).collect(),
+        }
+    }
+}
+
+pub(crate) fn check_club(club: &ClubEntity, refs: &References, report: &mut impl FnMut(String)) {
+    let name = format!("club {} ({})", club.id, club.name);
+    if !refs.country_codes.contains(club.country_code.as_str()) {
+        report(format!(
+            "{name} has unknown country_code '{}'",
+            club.country_code
+        ));
+    }
+    let mains = club.teams.iter().filter(|t| t.team_type == "Main").count();
+    if mains != 1 {
+        report(format!("{name} needs exactly one Main team, has {mains}"));
+    }
+    for team in &club.teams {
+        if TeamType::from_str(&team.team_type).is_err() {
+            report(format!(
+                "{name}: team {} has unknown team_type '{}'",
+                team.id, team.team_type
+            ));
+        }
+        match team.league_id {
+            Some(id) if !refs.league_ids.contains(&id) => report(format!(
+                "{name}: team {} has unknown league_id {}",
+                team.id, id
+            )),
+            None if team.team_type == "Main" => {
+                report(format!("{name}: Main team {} needs a league_id", team.id))
+            }
+            _ => {}
+        }
+    }
+    for rival in &club.rivals {
+        if !refs.clubs.contains_key(rival) {
+            report(format!("{name} has unknown rival {rival}"));
+        }
+    }
+}
+
+pub(crate) fn check_player(player: &OdbPlayer, refs: &References, report: &mut impl FnMut(String)) {
+    let name = format!(
+        "player {} ({} {})",
+        player.id, player.first_name, player.last_name
+    );
+    if !refs.country_ids.contains(&player.country_id) {
+        report(format!(
+            "{name} has unknown country_id {}",
+            player.country_id
+        ));
+    }
+    if player.club_id != 0 {
+        if !refs.clubs.contains_key(&player.club_id) {
+            report(format!("{name} has unknown club_id {}", player.club_id));
+        }
+        if player.contract.is_none() {
+            report(format!("{name} plays for a club but has no contract"));
+        }
+    }
+    if let Some(loan) = player
+        .loan
+        .as_ref()
+        .filter(|l| !refs.clubs.contains_key(&l.to_club_id))
+    {
+        report(format!(
+            "{name} is loaned to unknown club {}",
+            loan.to_club_id
+        ));
+    }
+    if player.positions.is_empty() {
+        report(format!("{name} has no positions"));
+    }
+    for position in &player.positions {
+        if parse_position_code(&position.code).is_none() {
+            report(format!(
+                "{name} has unknown position code '{}'",
+                position.code
+            ));
+        }
+        if !(1..=20).contains(&position.level) {
+            report(format!(
+                "{name}: position {} level {} is outside 1..=20",
+                position.code, position.level
+            ));
+        }
+    }
+    if !(1..=200).contains(&player.current_ability) {
+        report(format!(
+            "{name}: current_ability {} is outside 1..=200",
+            player.current_ability
+        ));
+    }
+    if player.potential_ability > 200 {
+        report(format!(
+            "{name}: potential_ability {} is above 200",
+            player.potential_ability
+        ));
+    }
+}
+
+pub(crate) fn check_staff(
+    member: &StaffEntity,
+    refs: &References,
+    report: &mut impl FnMut(String),
+) {
+    let name = format!("{} {}", member.first_name, member.last_name);
+    match refs.clubs.get(&member.club_id) {
+        None => report(format!("{name} has unknown club_id {}", member.club_id)),
+        Some(club) if !club.teams.iter().any(|t| t.team_type == member.team_type) => report(
+            format!("{name}: club {} has no {} team", club.id, member.team_type),
+        ),
+        Some(_) => {}
+    }
+    if let Some(country_id) = member
+        .country_id
+        .filter(|id| !refs.country_ids.contains(id))
+    {
+        report(format!("{name} has unknown country_id {country_id}"));
+    }
+}
+
+fn duplicates(table: &str, ids: impl Iterator<Item = u32>, report: &mut impl FnMut(String)) {
+    let mut seen = HashSet::new();
+    let mut reported = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) && reported.insert(id) {
+            report(format!("{table}: id {id} is used more than once"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONTINENTS: &str = "id,name\n1,Atlantis\n";
+
+    const COUNTRIES: &str = r##"[{
+        "id": 900, "code": "at", "slug": "atlantis", "name": "Atlantis",
+        "background_color": "#003399", "foreground_color": "#ffffff",
+        "continent_id": 1, "reputation": 4000,
+        "settings": { "pricing": { "price_level": 1.0 } }
+    }]"##;
+
+    const LEAGUES: &str = r#"[{
+        "id": 90, "enabled": true, "slug": "atlantis-premier", "name": "Premier",
+        "country_code": "at", "reputation": 3000, "tier": 1,
+        "settings": {
+            "season_starting_half": { "from_day": 1, "from_month": 8, "to_day": 31, "to_month": 12 },
+            "season_ending_half": { "from_day": 1, "from_month": 1, "to_day": 31, "to_month": 5 }
+        }
+    }]"#;
+
+    const CLUBS: &str = "\
+id,name,country_code,location.city_id,finance.balance,colors.background,colors.foreground,teams.0.id,teams.0.name,teams.0.slug,teams.0.team_type,teams.0.league_id,teams.0.reputation.home,teams.0.reputation.national,teams.0.reputation.world,teams.1.id,teams.1.name,teams.1.slug,teams.1.team_type,teams.1.reputation.home,teams.1.reputation.national,teams.1.reputation.world
+9001,Rovers,at,1,1000000,#c8102e,#ffffff,9101,Rovers,rovers,Main,90,3000,2500,1500,9102,Rovers,rovers-u19,U19,1000,800,500
+9002,Harbour,at,2,500000,#00529f,#ffffff,9201,Harbour,harbour,Main,90,2500,2000,1000,,,,,,,
+";
+
+    const PLAYERS: &str = "\
+id,first_name,last_name,birth_date,country_id,club_id,positions.0.code,positions.0.level,positions.1.code,positions.1.level,current_ability,potential_ability,contract.expiration
+1,Ana,Sousa,1998-04-02,900,9001,ST,20,AMC,15,120,140,2028-06-30
+2,Rui,Costa,2001-09-12,900,9002,GK,20,,,100,-8,2027-06-30
+";
+
+    const STAFF: &str = "club_id,position,first_name,last_name\n9001,Manager,Marta,Reis\n";
+
+    fn world(name: &str, files: &[(&str, String)]) -> PathBuf {
+        let directory = std::env::temp_dir().join(format!(
+            "open-football-world-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        fs::create_dir_all(&directory).unwrap();
+        for (file, content) in files {
+            let path = directory.join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, content).unwrap();
+        }
+        directory
+    }
+
+    fn valid_files() -> Vec<(&'static str, String)> {
+        [
+            ("continents.csv", CONTINENTS),
+            ("countries.json", COUNTRIES),
+            ("leagues.json", LEAGUES),
+            ("clubs.csv", CLUBS),
+            ("players.csv", PLAYERS),
+            ("staff.csv", STAFF),
+            ("names.json", "[]"),
+        ]
+        .into_iter()
+        .map(|(file, content)| (file, content.to_string()))
+        .collect()
+    }
+
+    #[test]
+    fn reads_a_world_from_json_and_csv_tables() {
+        let directory = world("valid", &valid_files());
+        let db = DirectoryLoader::read(&directory).unwrap_or_else(|e| panic!("{e}"));
+
+        assert_eq!(db.version, SUPPORTED_VERSION);
+        assert_eq!(db.continents[0].name, "Atlantis");
+        assert_eq!(db.clubs.len(), 2);
+        // Trailing empty team columns leave Harbour with its Main team only.
+        assert_eq!(db.clubs[0].teams.len(), 2);
+        assert_eq!(db.clubs[1].teams.len(), 1);
+        assert_eq!(db.clubs[0].teams[0].league_id, Some(90));
+        assert_eq!(db.clubs[0].colors.background, "#c8102e");
+        assert_eq!(db.players[0].positions.len(), 2);
+        assert_eq!(db.players[1].positions.len(), 1);
+        assert_eq!(db.players[1].potential_ability, -8);
+        assert_eq!(db.staff[0].team_type, "Main");
+        assert!(db.national_competitions.is_empty());
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn a_table_can_be_split_into_shards() {
+        let mut lines = PLAYERS.lines();
+        let header = lines.next().unwrap();
+        let (ana, rui) = (lines.next().unwrap(), lines.next().unwrap());
+        let files = with(valid_files(), "players.csv", None);
+        let files = with(
+            files,
+            "players/2-rui.csv",
+            Some(format!("{header}\n{rui}\n")),
+        );
+        let files = with(
+            files,
+            "players/1-ana.csv",
+            Some(format!("{header}\n{ana}\n")),
+        );
+        let files = with(files, "players/notes.txt", Some("not a table".to_string()));
+
+        let directory = world("shards", &files);
+        let db = DirectoryLoader::read(&directory).unwrap_or_else(|e| panic!("{e}"));
+        let names: Vec<&str> = db.players.iter().map(|p| p.first_name.as_str()).collect();
+        assert_eq!(names, ["Ana", "Rui"]);
+
+        fs::write(directory.join("players.json"), "[]").unwrap();
+        let text = DirectoryLoader::read(&directory)
+            .err()
+            .expect("world should be rejected")
+            .to_string();
+        assert!(
+            text.contains("both players.json and players/ exist"),
+            "{text}"
+        );
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    /// The valid world with `file` replaced, or dropped when `content` is `None`.
+    fn with(
+        mut files: Vec<(&'static str, String)>,
+        file: &'static str,
+        content: Option<String>,
+    ) -> Vec<(&'static str, String)> {
+        files.retain(|(f, _)| *f != file);
+        if let Some(content) = content {
+            files.push((file, content));
+        }
+        files
+    }
+
+    #[test]
+    fn every_problem_is_reported_with_its_location() {
+        let files = with(valid_files(), "continents.csv", None);
+        let files = with(files, "countries.csv", Some("id\n1\n".to_string()));
+        let files = with(
+            files,
+            "players.csv",
+            Some(PLAYERS.replace("2001-09-12", "not-a-date")),
+        );
+
+        let directory = world("broken", &files);
+        let text = DirectoryLoader::read(&directory)
+            .err()
+            .expect("world should be rejected")
+            .to_string();
+
+        assert!(text.contains("continents: missing"), "{text}");
+        assert!(
+            text.contains("both countries.json and countries.csv"),
+            "{text}"
+        );
+        assert!(text.contains("players.csv, line 3:"), "{text}");
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn references_between_tables_are_checked() {
+        let files = with(
+            valid_files(),
+            "clubs.csv",
+            Some(CLUBS.replace("Main,90,2500", "Main,91,2500")),
+        );
+        let files = with(
+            files,
+            "players.csv",
+            Some(PLAYERS.replace("GK,20", "XX,25")),
+        );
+        let files = with(
+            files,
+            "staff.csv",
+            Some(STAFF.replace("9001,Manager", "9009,Manager")),
+        );
+
+        let directory = world("references", &files);
+        let problems = DirectoryLoader::read(&directory)
+            .err()
+            .expect("world should be rejected")
+            .problems;
+
+        assert_eq!(
+            problems,
+            vec![
+                "clubs: club 9002 (Harbour): team 9201 has unknown league_id 91",
+                "players: player 2 (Rui Costa) has unknown position code 'XX'",
+                "players: player 2 (Rui Costa): position XX level 25 is outside 1..=20",
+                "staff: Marta Reis has unknown club_id 9009",
+            ]
+        );
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+
+    #[test]
+    fn cells_read_as_numbers_only_when_they_look_like_one() {
+        assert_eq!(cell_value("42"), Value::from(42));
+        assert_eq!(cell_value("-8"), Value::from(-8));
+        assert_eq!(cell_value("1.5"), Value::from(1.5));
+        assert_eq!(cell_value("true"), Value::Bool(true));
+        assert_eq!(cell_value("007"), Value::from("007"));
+        assert_eq!(cell_value("#c8102e"), Value::from("#c8102e"));
+        assert_eq!(cell_value("2028-06-30"), Value::from("2028-06-30"));
+    }
+}
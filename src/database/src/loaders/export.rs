@@ -0,0 +1,275 @@
+//! Writing a game database back out as a directory of tables, in the
+//! layout `DirectoryLoader` reads (see `directory`).
+//!
+//! Records are written the way the loader would take them in: fields it
+//! fills in itself (`country_id` beside a `country_code`) and empty
+//! optional fields are left out. In CSV, nested fields become dotted
+//! headers; a table whose records don't come back unchanged from flat
+//! cells — empty text, text that reads as a number — is written as JSON
+//! instead.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
+use std::str::FromStr;
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Number, Value};
+
+use super::compiled::CompiledDatabase;
+use super::directory::{cell_value, compact, insert};
+
+/// The file format tables are exported in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TableFormat {
+    #[default]
+    Json,
+    Csv,
+}
+
+impl FromStr for TableFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "json" => Ok(TableFormat::Json),
+            "csv" => Ok(TableFormat::Csv),
+            _ => Err("expected json or csv".to_string()),
+        }
+    }
+}
+
+impl fmt::Display for TableFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            TableFormat::Json => "json",
+            TableFormat::Csv => "csv",
+        })
+    }
+}
+
+/// One table of a database as the records its file holds.
+pub(crate) struct Table {
+    pub name: &'static str,
+    pub records: Vec<Value>,
+    /// Whether every record reads back unchanged from a CSV row.
+    csv_safe: bool,
+}
+
+/// Every table of `db`, in the order `DirectoryLoader` reads them.
+pub(crate) fn tables(db: &CompiledDatabase) -> Vec<Table> {
+    vec![
+        table("continents", &db.continents),
+        table("countries", &db.countries),
+        table("leagues", &db.leagues),
+        table("clubs", &db.clubs),
+        table("players", &db.players),
+        table("staff", &db.staff),
+        table("names", &db.names),
+        table("domestic_cups", &db.domestic_cups),
+        table("national_competitions", &db.national_competitions),
+    ]
+}
+
+fn table<T: Serialize + DeserializeOwned>(name: &'static str, items: &[T]) -> Table {
+    let records: Vec<Value> = items.iter().map(record).collect();
+    let csv_safe = records.iter().all(|r| {
+        serde_json::from_value::<T>(from_cells(&cells(r))).is_ok_and(|back| record(&back) == *r)
+    });
+    Table {
+        name,
+        records,
+        csv_safe,
+    }
+}
+
+fn record<T: Serialize>(item: &T) -> Value {
+    let mut value = serde_json::to_value(item).unwrap_or(Value::Null);
+    tidy(&mut value);
+    let derived = value
+        .as_object_mut()
+        .filter(|fields| fields.get("country_code").is_some_and(Value::is_string));
+    if let Some(fields) = derived {
+        fields.remove("country_id");
+    }
+    value
+}
+
+/// Drop null fields, and write single-precision numbers the way they
+/// were typed (`0.85`, not `0.8500000238418579`).
+fn tidy(value: &mut Value) {
+    match value {
+        Value::Object(fields) => {
+            fields.retain(|_, field| !field.is_null());
+            fields.values_mut().for_each(tidy);
+        }
+        Value::Array(items) => items.iter_mut().for_each(tidy),
+        Value::Number(number) if number.is_f64() => {
+            let Some(wide) = number.as_f64() else {
+                return;
+            };
+            let narrow = wide as f32;
+            let short = Some(narrow)
+                .filter(|n| f64::from(*n) == wide)
+                .and_then(|n| n.to_string().parse().ok())
+                .and_then(Number::from_f64);
+            if let Some(short) = short {
+                *number = short;
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The record's values by dotted path (`teams.0.slug`), in field order.
+pub(crate) fn leaves(record: &Value) -> Vec<(String, &Value)> {
+    fn walk<'a>(value: &'a Value, path: &str, out: &mut Vec<(String, &'a Value)>) {
+        let join = |key: &str| {
+            if path.is_empty() {
+                key.to_string()
+            } else {
+                format!("{path}.{key}")
+            }
+        };
+        match value {
+            Value::Object(fields) => {
+                for (key, field) in fields {
+                    walk(field, &join(key), out);
+                }
+            }
+            Value::Array(items) => {
+                for (idx, item) in items.iter().enumerate() {
+                    walk(item, &join(&idx.to_string()), out);
+                }
+            }
+            Value::Null => {}
+            leaf => out.push((path.to_string(), leaf)),
+        }
+    }
+
+    let mut out = Vec::new();
+    walk(record, "", &mut out);
+    out
+}
+
+/// The record as (dotted header, cell) pairs.
+fn cells(record: &Value) -> Vec<(String, String)> {
+    leaves(record)
+        .into_iter()
+        .map(|(path, leaf)| {
+            let cell = match leaf {
+                Value::String(text) => text.clone(),
+                other => other.to_string(),
+            };
+            (path, cell)
+        })
+        .collect()
+}
+
+/// The record a CSV row of `cells` reads back as.
+fn from_cells(cells: &[(String, String)]) -> Value {
+    let mut value = Value::Object(Map::new());
+    for (header, cell) in cells {
+        let cell = cell.trim();
+        if cell.is_empty() {
+            continue;
+        }
+        let path: Vec<String> = header.split('.').map(str::to_string).collect();
+        if insert(&mut value, &path, cell_value(cell)).is_err() {
+            return Value::Null;
+        }
+    }
+    compact(&mut value);
+    value
+}
+
+/// Write `tables` into `directory`, replacing any earlier file of the same
+/// table in either format. Empty tables are written as an empty JSON list.
+pub(crate) fn write_tables(
+    directory: &Path,
+    tables: &[Table],
+    format: TableFormat,
+) -> io::Result<()> {
+    fs::create_dir_all(directory)?;
+    for table in tables {
+        let as_csv = format == TableFormat::Csv && table.csv_safe && !table.records.is_empty();
+        let (written, stale) = if as_csv {
+            ("csv", "json")
+        } else {
+            ("json", "csv")
+        };
+        let stale = directory.join(format!("{}.{stale}", table.name));
+        if stale.is_file() {
+            fs::remove_file(stale)?;
+        }
+
+        let path = directory.join(format!("{}.{written}", table.name));
+        if as_csv {
+            write_csv(&path, &table.records)?;
+        } else {
+            let mut out = BufWriter::new(fs::File::create(path)?);
+            serde_json::to_writer_pretty(&mut out, &table.records)?;
+            writeln!(out)?;
+            out.flush()?;
+        }
+    }
+    Ok(())
+}
+
+fn write_csv(path: &Path, records: &[Value]) -> io::Result<()> {
+    let rows: Vec<Vec<(String, String)>> = records.iter().map(cells).collect();
+    let mut seen = HashSet::new();
+    let headers: Vec<&str> = rows
+        .iter()
+        .flatten()
+        .map(|(header, _)| header.as_str())
+        .filter(|header| seen.insert(*header))
+        .collect();
+
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(&headers)?;
+    for row in &rows {
+        let row: HashMap<&str, &str> = row.iter().map(|(h, c)| (h.as_str(), c.as_str())).collect();
+        writer.write_record(
+            headers
+                .iter()
+                .map(|h| row.get(h).copied().unwrap_or_default()),
+        )?;
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn cells_read_back_as_the_record() {
+        let record = json!({
+            "id": 7,
+            "name": "Rovers",
+            "price_level": 0.85,
+            "teams": [{ "slug": "rovers", "league_id": 90 }, { "slug": "rovers-b" }]
+        });
+        let cells = cells(&record);
+
+        assert!(cells.contains(&("teams.1.slug".to_string(), "rovers-b".to_string())));
+        assert_eq!(from_cells(&cells), record);
+    }
+
+    #[test]
+    fn derived_and_empty_fields_are_left_out() {
+        let value = record(&json!({
+            "country_id": 900,
+            "country_code": "at",
+            "nickname": null,
+            "factor": f64::from(0.85f32)
+        }));
+
+        assert_eq!(value, json!({ "country_code": "at", "factor": 0.85 }));
+    }
+}
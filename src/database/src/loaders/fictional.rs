@@ -0,0 +1,629 @@
+//! Fictional worlds.
+//!
+//! Instead of the embedded `database.db`, a whole world can be made up from
+//! a seed: one continent of invented countries, each with a league pyramid
+//! of one to three tiers linked by promotion and relegation, clubs with
+//! names, colors, finances and reputations, name pools for the people of
+//! every country, and a full squad record for every team. The records are
+//! hydrated into players by `PlayerGenerator::generate_from_odb` like any
+//! other database's, whose streams are seeded by player id, so the same
+//! seed always gives the same world.
+//!
+//! Names are strung together from syllables, each country drawing on its
+//! own subset so its people and towns sound alike. Country codes come from
+//! the ISO 3166 user-assigned ranges (`xa`–`xz`, `qm`–`qz`) and never
+//! match a real country's.
+
+use std::collections::HashSet;
+
+use chrono::{Datelike, NaiveDate};
+
+use super::compiled::{CompiledDatabase, SUPPORTED_VERSION};
+use super::{
+    ClubColorsEntity, ClubEntity, ClubFacilitiesEntity, ClubFinanceEntity, ClubLocationEntity,
+    ClubReputationEntity, ClubTeamEntity, ContinentEntity, CountryEntity, CountryPricingEntity,
+    CountrySettingsEntity, DayMonthPeriodEntity, LeagueEntity, LeagueSettingsEntity, NameEntity,
+    NamesByCountryEntity, OdbContract, OdbPlayer, OdbPosition, SkinColorsEntity,
+};
+use crate::generators::DatabaseGenerator;
+use crate::generators::rng::HydrationRng;
+
+/// The continent id the generator hosts its club competitions on.
+const CONTINENT_ID: u32 = 1;
+
+const ONSETS: &[&str] = &[
+    "b", "br", "c", "ch", "d", "dr", "f", "g", "gr", "h", "j", "k", "kr", "l", "m", "n", "p", "r",
+    "s", "sh", "st", "t", "tr", "v", "z", "th", "w", "y", "sk", "pl",
+];
+const VOWELS: &[&str] = &[
+    "a", "e", "i", "o", "u", "ai", "ei", "ou", "ia", "ea", "io", "y",
+];
+const CODAS: &[&str] = &[
+    "", "", "", "n", "r", "l", "s", "k", "m", "nd", "rt", "st", "x", "v", "sh", "ck",
+];
+const COUNTRY_ENDINGS: &[&str] = &[
+    "ia", "land", "or", "ana", "mark", "ovia", "ene", "ar", "istan",
+];
+
+/// `{}` is replaced by a town name.
+const CLUB_PATTERNS: &[&str] = &[
+    "{}",
+    "FC {}",
+    "{} United",
+    "{} City",
+    "Athletic {}",
+    "Sporting {}",
+    "{} Rovers",
+    "Dynamo {}",
+    "{} Wanderers",
+    "Racing {}",
+    "{} Town",
+    "Olympic {}",
+];
+const TIER_NAMES: &[&str] = &["Premier Division", "First Division", "Second Division"];
+const FACILITIES: &[&str] = &[
+    "Poor",
+    "Basic",
+    "Below Average",
+    "Average",
+    "Adequate",
+    "Good",
+    "Great",
+    "Excellent",
+    "Superb",
+];
+/// Kit and flag colors; `text_color` picks the lettering on each.
+const COLORS: &[&str] = &[
+    "#c8102e", "#003399", "#006a4e", "#ffd700", "#ff6600", "#6a0dad", "#000000", "#ffffff",
+    "#87ceeb", "#800000", "#1e90ff", "#228b22", "#708090", "#ff1493", "#004d40", "#b8860b",
+];
+
+/// Primary positions of a senior squad, and of the two youth squads.
+const SENIOR_SQUAD: &[&str] = &[
+    "GK", "GK", "GK", "DR", "DR", "DC", "DC", "DC", "DC", "DL", "DL", "DM", "DM", "MC", "MC", "MC",
+    "MR", "ML", "AMR", "AML", "AMC", "ST", "ST", "ST",
+];
+const YOUTH_SQUAD: &[&str] = &[
+    "GK", "GK", "DR", "DC", "DC", "DC", "DL", "DM", "MC", "MC", "MR", "ML", "AMC", "AMR", "ST",
+    "ST",
+];
+
+/// How one country's people and places sound.
+struct Phonology {
+    onsets: Vec<&'static str>,
+    vowels: Vec<&'static str>,
+    codas: Vec<&'static str>,
+}
+
+impl Phonology {
+    fn new(rng: &mut HydrationRng) -> Self {
+        Phonology {
+            onsets: pick_some(ONSETS, 12, rng),
+            vowels: pick_some(VOWELS, 6, rng),
+            codas: pick_some(CODAS, 8, rng),
+        }
+    }
+
+    fn word(&self, syllables: i32, rng: &mut HydrationRng) -> String {
+        let mut word = String::new();
+        for _ in 0..syllables {
+            word.push_str(pick::<&str>(&self.onsets, rng));
+            word.push_str(pick::<&str>(&self.vowels, rng));
+        }
+        word.push_str(pick::<&str>(&self.codas, rng));
+        capitalized(&word)
+    }
+}
+
+/// Everything about a country the later passes need.
+struct Country {
+    id: u32,
+    code: String,
+    slug: String,
+    name: String,
+    reputation: u16,
+    phonology: Phonology,
+}
+
+/// Generate a whole world from `seed`.
+pub(crate) fn generate(seed: u64) -> CompiledDatabase {
+    let mut rng = HydrationRng::from_seed(seed);
+    let start = DatabaseGenerator::start_date().date();
+    let mut used_names = HashSet::new();
+
+    let continent = ContinentEntity {
+        id: CONTINENT_ID,
+        name: Phonology::new(&mut rng).word(3, &mut rng),
+    };
+
+    let countries = countries(&mut rng, &mut used_names);
+
+    let mut leagues = Vec::new();
+    let mut clubs = Vec::new();
+    let mut players = Vec::new();
+    let mut names = Vec::new();
+    for country in &countries {
+        names.push(name_pool(country, &mut rng));
+    }
+
+    for country in &countries {
+        let tiers = 1 + u8::from(country.reputation > 4500) + u8::from(country.reputation > 6500);
+        let mut links = Vec::new();
+        for tier in 0..tiers {
+            let id = 100 + leagues.len() as u32;
+            let size = if tier == 0 {
+                16 + 2 * rng.int_range(0, 3) as u32
+            } else {
+                18 + 2 * rng.int_range(0, 4) as u32
+            };
+            let reputation =
+                (f32::from(country.reputation) * [0.9, 0.65, 0.45][tier as usize]) as u16;
+            let league = league(country, id, tier, reputation);
+
+            let first = clubs.len();
+            for _ in 0..size {
+                let club = club(
+                    1000 + clubs.len() as u32,
+                    country,
+                    id,
+                    reputation,
+                    &mut used_names,
+                    &mut rng,
+                );
+                players.extend(squad(&club, &countries, &names, start, &mut rng));
+                clubs.push(club);
+            }
+            pair_rivals(&mut clubs[first..], &mut rng);
+
+            links.push(rng.int_range(2, 4) as u8);
+            leagues.push(league);
+        }
+
+        // Tiers swap the same number of clubs each way.
+        let pyramid = leagues.len() - tiers as usize;
+        for tier in 0..tiers as usize - 1 {
+            let spots = links[tier];
+            leagues[pyramid + tier].relegation_spots = spots;
+            leagues[pyramid + tier + 1].promotion_spots = spots;
+        }
+    }
+
+    for (id, player) in (1..).zip(&mut players) {
+        player.id = id;
+    }
+
+    CompiledDatabase {
+        version: SUPPORTED_VERSION.to_string(),
+        continents: vec![continent],
+        countries: countries.iter().map(country_entity(&mut rng)).collect(),
+        national_competitions: Vec::new(),
+        domestic_cups: Vec::new(),
+        leagues,
+        clubs,
+        names,
+        players,
+        staff: Vec::new(),
+    }
+}
+
+fn countries(rng: &mut HydrationRng, used_names: &mut HashSet<String>) -> Vec<Country> {
+    let mut codes: Vec<String> = ('a'..='z')
+        .map(|c| format!("x{c}"))
+        .chain(('m'..='z').map(|c| format!("q{c}")))
+        .collect();
+    let count = rng.int_range(6, 11);
+
+    let mut countries: Vec<Country> = (0..count)
+        .map(|_| {
+            let phonology = Phonology::new(rng);
+            let name = loop {
+                let stem = phonology.word(rng.int_range(1, 3), rng);
+                let name = format!("{}{}", stem, pick(COUNTRY_ENDINGS, rng));
+                if used_names.insert(name.clone()) {
+                    break name;
+                }
+            };
+            let code = codes.remove(rng.int_range(0, codes.len() as i32) as usize);
+            Country {
+                id: 0,
+                code,
+                slug: slug(&name),
+                name,
+                reputation: rng.int_range(2500, 8600) as u16,
+                phonology,
+            }
+        })
+        .collect();
+
+    // The strongest football nation gets the first id, as in a ranking.
+    countries.sort_by_key(|c| std::cmp::Reverse(c.reputation));
+    for (id, country) in (1..).zip(&mut countries) {
+        country.id = id;
+    }
+    countries
+}
+
+fn country_entity(rng: &mut HydrationRng) -> impl FnMut(&Country) -> CountryEntity + '_ {
+    move |country| {
+        let white = rng.int_range(10, 90) as u8;
+        let black = rng.int_range(0, (100 - white) as i32 + 1) as u8;
+        let background = pick(COLORS, rng).to_string();
+        CountryEntity {
+            id: country.id,
+            code: country.code.clone(),
+            slug: country.slug.clone(),
+            name: country.name.clone(),
+            foreground_color: text_color(&background).to_string(),
+            background_color: background,
+            continent_id: CONTINENT_ID,
+            reputation: country.reputation,
+            settings: CountrySettingsEntity {
+                pricing: CountryPricingEntity {
+                    price_level: 0.4 + f32::from(country.reputation) / 10_000.0 * 0.7,
+                },
+            },
+            skin_colors: SkinColorsEntity {
+                white,
+                black,
+                metis: 100 - white - black,
+            },
+            regulations: Default::default(),
+            domestic_cup: None,
+        }
+    }
+}
+
+fn name_pool(country: &Country, rng: &mut HydrationRng) -> NamesByCountryEntity {
+    let words = |count: usize, syllables: (i32, i32), rng: &mut HydrationRng| {
+        let mut seen = HashSet::new();
+        while seen.len() < count {
+            seen.insert(
+                country
+                    .phonology
+                    .word(rng.int_range(syllables.0, syllables.1), rng),
+            );
+        }
+        let mut words: Vec<String> = seen.into_iter().collect();
+        words.sort();
+        // A few names are common, most are rare.
+        words
+            .into_iter()
+            .map(|name| NameEntity {
+                name,
+                weight: rng.int_range(1, 6).pow(2) as u16,
+            })
+            .collect::<Vec<_>>()
+    };
+    NamesByCountryEntity {
+        country_id: country.id,
+        country_code: country.code.clone(),
+        first_names: words(60, (1, 3), rng),
+        last_names: words(120, (2, 4), rng),
+        nicknames: Vec::new(),
+    }
+}
+
+fn league(country: &Country, id: u32, tier: u8, reputation: u16) -> LeagueEntity {
+    let name = format!("{} {}", country.name, TIER_NAMES[tier as usize]);
+    LeagueEntity {
+        id,
+        enabled: true,
+        slug: slug(&name),
+        name,
+        country_id: country.id,
+        country_code: country.code.clone(),
+        settings: LeagueSettingsEntity {
+            season_starting_half: DayMonthPeriodEntity {
+                from_day: 8,
+                from_month: 8,
+                to_day: 20,
+                to_month: 12,
+            },
+            season_ending_half: DayMonthPeriodEntity {
+                from_day: 17,
+                from_month: 1,
+                to_day: 24,
+                to_month: 5,
+            },
+            split_season: false,
+        },
+        reputation,
+        tier: tier + 1,
+        promotion_spots: 0,
+        relegation_spots: 0,
+        promotion_playoff_spots: 0,
+        relegation_league_id: None,
+        foreign_players: Vec::new(),
+        sub_leagues_competitions: Vec::new(),
+        league_group: None,
+        rules: Default::default(),
+    }
+}
+
+fn club(
+    id: u32,
+    country: &Country,
+    league_id: u32,
+    league_reputation: u16,
+    used_names: &mut HashSet<String>,
+    rng: &mut HydrationRng,
+) -> ClubEntity {
+    let name = loop {
+        let town = country.phonology.word(rng.int_range(2, 4), rng);
+        let name = pick(CLUB_PATTERNS, rng).replace("{}", &town);
+        if used_names.insert(name.clone()) {
+            break name;
+        }
+    };
+    let slug = slug(&name);
+
+    let reputation = (f32::from(league_reputation) * rng.float_range(0.75, 1.2)).min(10_000.0);
+    let strength = reputation / 10_000.0;
+    let team = |id: u32, team_type: &str, share: f32, league_id: Option<u32>| {
+        let reputation = (reputation * share) as u16;
+        ClubTeamEntity {
+            id,
+            name: name.clone(),
+            slug: match team_type {
+                "Main" => slug.clone(),
+                _ => format!("{}-{}", slug, team_type.to_ascii_lowercase()),
+            },
+            team_type: team_type.to_string(),
+            league_id,
+            finance: None,
+            reputation: ClubReputationEntity {
+                home: reputation,
+                national: reputation,
+                world: reputation,
+            },
+        }
+    };
+    // Youth teams take ids well clear of the clubs'.
+    let teams = vec![
+        team(id, "Main", 1.0, Some(league_id)),
+        team(10_000_000 + 2 * id, "U20", 0.3, None),
+        team(10_000_000 + 2 * id + 1, "U18", 0.2, None),
+    ];
+
+    let facility = |rng: &mut HydrationRng| {
+        let level = strength * FACILITIES.len() as f32 + rng.float_range(-1.5, 1.5);
+        FACILITIES[(level.max(0.0) as usize).min(FACILITIES.len() - 1)].to_string()
+    };
+    let background = pick(COLORS, rng).to_string();
+    let attendance = (strength * strength * 60_000.0 * rng.float_range(0.7, 1.3)) as u32 + 500;
+
+    ClubEntity {
+        id,
+        country_id: country.id,
+        country_code: country.code.clone(),
+        location: ClubLocationEntity { city_id: id },
+        finance: ClubFinanceEntity {
+            balance: (strength * strength * 150_000_000.0 * rng.float_range(0.5, 1.5)) as i32,
+        },
+        colors: ClubColorsEntity {
+            foreground: text_color(&background).to_string(),
+            background,
+        },
+        teams,
+        rivals: Vec::new(),
+        philosophy: None,
+        facilities: Some(ClubFacilitiesEntity {
+            training: facility(rng),
+            youth: facility(rng),
+            academy: facility(rng),
+            recruitment: facility(rng),
+        }),
+        average_attendance: Some(attendance),
+        stadium_capacity: Some(attendance + attendance / 3),
+        parent_club: None,
+        name,
+    }
+}
+
+/// Make clubs of one league rivals two by two.
+fn pair_rivals(clubs: &mut [ClubEntity], rng: &mut HydrationRng) {
+    let mut order: Vec<usize> = (0..clubs.len()).collect();
+    for i in (1..order.len()).rev() {
+        order.swap(i, rng.int_range(0, i as i32 + 1) as usize);
+    }
+    for pair in order.chunks_exact(2) {
+        let (a, b) = (pair[0], pair[1]);
+        clubs[a].rivals.push(clubs[b].id);
+        clubs[b].rivals.push(clubs[a].id);
+    }
+}
+
+/// Player records for every team of `club`.
+fn squad(
+    club: &ClubEntity,
+    countries: &[Country],
+    names: &[NamesByCountryEntity],
+    start: NaiveDate,
+    rng: &mut HydrationRng,
+) -> Vec<OdbPlayer> {
+    let main_reputation = club.teams[0].reputation.world;
+    // Ability the club's regular starters sit around.
+    let level = 30.0 + f32::from(main_reputation) / 10_000.0 * 120.0;
+
+    let mut players = Vec::new();
+    for (team_type, positions, ages, ability) in [
+        ("Main", SENIOR_SQUAD, (19, 34), 1.0),
+        ("U20", YOUTH_SQUAD, (18, 21), 0.7),
+        ("U18", YOUTH_SQUAD, (15, 18), 0.5),
+    ] {
+        for &position in positions {
+            let age = if team_type == "Main" {
+                ages.0 + rng.int_range(0, 8) + rng.int_range(0, 8)
+            } else {
+                rng.int_range(ages.0, ages.1)
+            };
+
+            // Mostly home-grown, with the odd import from elsewhere.
+            let country = if rng.f32() < 0.85 {
+                countries.iter().find(|c| c.id == club.country_id).unwrap()
+            } else {
+                pick(countries, rng)
+            };
+            let pool = names.iter().find(|n| n.country_id == country.id).unwrap();
+
+            let current = (level * ability + rng.normal() * 12.0).clamp(20.0, 190.0) as u8;
+            let growth = match age {
+                ..=21 => rng.int_range(10, 60),
+                22..=25 => rng.int_range(0, 25),
+                _ => rng.int_range(0, 8),
+            };
+            let born = NaiveDate::from_ymd_opt(
+                start.year() - age,
+                rng.int_range(1, 13) as u32,
+                rng.int_range(1, 29) as u32,
+            )
+            .unwrap();
+            let expires = NaiveDate::from_ymd_opt(start.year() + rng.int_range(1, 6), 6, 30);
+
+            players.push(OdbPlayer {
+                id: 0,
+                first_name: weighted_pick(&pool.first_names, rng).name.clone(),
+                last_name: weighted_pick(&pool.last_names, rng).name.clone(),
+                middle_name: None,
+                nickname: None,
+                birth_date: born,
+                country_id: country.id,
+                club_id: club.id,
+                positions: vec![OdbPosition {
+                    code: position.to_string(),
+                    level: 20,
+                }],
+                preferred_foot: Some(if rng.f32() < 0.25 { "Left" } else { "Right" }.to_string()),
+                foots: None,
+                height: None,
+                weight: None,
+                current_ability: current,
+                potential_ability: (i16::from(current) + growth as i16).min(200),
+                value: None,
+                reputation: None,
+                contract: expires.map(|expiration| OdbContract {
+                    salary: None,
+                    expiration,
+                    started: None,
+                    contract_type: (team_type == "U18").then(|| "Youth".to_string()),
+                    shirt_number: None,
+                    squad_status: None,
+                }),
+                loan: None,
+                history: Vec::new(),
+                team_type_hint: Some(team_type.to_string()),
+            });
+        }
+    }
+    players
+}
+
+fn pick<'a, T>(items: &'a [T], rng: &mut HydrationRng) -> &'a T {
+    &items[rng.int_range(0, items.len() as i32) as usize]
+}
+
+fn weighted_pick<'a>(names: &'a [NameEntity], rng: &mut HydrationRng) -> &'a NameEntity {
+    let total: i32 = names.iter().map(|n| i32::from(n.weight)).sum();
+    let mut roll = rng.int_range(0, total);
+    names
+        .iter()
+        .find(|n| {
+            roll -= i32::from(n.weight);
+            roll < 0
+        })
+        .unwrap_or(&names[0])
+}
+
+/// `count` distinct entries of `items`, in their original order.
+fn pick_some<T: Copy>(items: &[T], count: usize, rng: &mut HydrationRng) -> Vec<T> {
+    let mut indexes: Vec<usize> = (0..items.len()).collect();
+    for i in (1..indexes.len()).rev() {
+        indexes.swap(i, rng.int_range(0, i as i32 + 1) as usize);
+    }
+    indexes.truncate(count);
+    indexes.sort();
+    indexes.into_iter().map(|i| items[i]).collect()
+}
+
+fn capitalized(word: &str) -> String {
+    let mut chars = word.chars();
+    chars.next().map_or_else(String::new, |first| {
+        first.to_ascii_uppercase().to_string() + chars.as_str()
+    })
+}
+
+fn slug(name: &str) -> String {
+    name.to_ascii_lowercase().replace(' ', "-")
+}
+
+/// White lettering, or black on the light colors.
+fn text_color(background: &str) -> &'static str {
+    match background {
+        "#ffd700" | "#ffffff" | "#87ceeb" | "#ff6600" => "#000000",
+        _ => "#ffffff",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{DatabaseEntity, PlayersOdb};
+    use std::sync::OnceLock;
+
+    fn database(world: CompiledDatabase) -> DatabaseEntity {
+        DatabaseEntity {
+            continents: world.continents,
+            countries: world.countries,
+            leagues: world.leagues,
+            clubs: world.clubs,
+            national_competitions: world.national_competitions,
+            names_by_country: world.names,
+            players_odb: Some(PlayersOdb::from_players(world.players)),
+            staff: world.staff,
+            index: OnceLock::new(),
+        }
+    }
+
+    fn club_names(world: &CompiledDatabase) -> Vec<&str> {
+        world.clubs.iter().map(|c| c.name.as_str()).collect()
+    }
+
+    #[test]
+    fn same_seed_makes_the_same_world() {
+        let (a, b, other) = (generate(7), generate(7), generate(8));
+        assert_eq!(club_names(&a), club_names(&b));
+        assert_ne!(club_names(&a), club_names(&other));
+
+        let players = |world: &CompiledDatabase| -> Vec<(String, u8, NaiveDate)> {
+            world
+                .players
+                .iter()
+                .map(|p| (p.last_name.clone(), p.current_ability, p.birth_date))
+                .collect()
+        };
+        assert_eq!(players(&a), players(&b));
+    }
+
+    #[test]
+    fn world_passes_the_database_check() {
+        let world = generate(42);
+        assert!(
+            world
+                .countries
+                .iter()
+                .all(|c| c.code.starts_with(['x', 'q']))
+        );
+        for club in &world.clubs {
+            let squad = world
+                .players
+                .iter()
+                .filter(|p| p.club_id == club.id)
+                .count();
+            assert_eq!(squad, SENIOR_SQUAD.len() + 2 * YOUTH_SQUAD.len());
+        }
+
+        let database = database(world);
+        let report = database.validate(DatabaseGenerator::start_date().date());
+        assert_eq!(report.errors + report.warnings, 0, "{:?}", report.issues);
+    }
+}
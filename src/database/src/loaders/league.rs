@@ -1,12 +1,12 @@
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct ForeignPlayerEntry {
     pub country_id: u32,
     pub weight: u16,
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct LeagueEntity {
     pub id: u32,
     /// Whether this league is active in the simulation. Set to false to skip.
@@ -29,6 +29,14 @@ pub struct LeagueEntity {
     pub promotion_spots: u8,
     #[serde(default)]
     pub relegation_spots: u8,
+    /// Places below the automatic promotion spots that play off for the
+    /// last promotion spot (e.g. 4 for a Championship-style 3rd–6th).
+    #[serde(default)]
+    pub promotion_playoff_spots: u8,
+    /// League id this division relegates into. Unset pairs it with the
+    /// next tier down.
+    #[serde(default)]
+    pub relegation_league_id: Option<u32>,
     #[serde(default)]
     pub foreign_players: Vec<ForeignPlayerEntry>,
     #[serde(default)]
@@ -38,9 +46,41 @@ pub struct LeagueEntity {
     /// within the same competition.
     #[serde(default)]
     pub league_group: Option<LeagueGroupEntity>,
+    /// Scoring, tie-break and squad rules. Unset keeps three points for
+    /// a win, the FIFA tie-break chain and no squad restrictions.
+    #[serde(default)]
+    pub rules: LeagueRulesEntity,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+#[serde(default)]
+pub struct LeagueRulesEntity {
+    pub points_for_win: u8,
+    pub points_for_draw: u8,
+    pub points_for_loss: u8,
+    /// Tie-break keys in order: "points", "head_to_head",
+    /// "goal_difference", "goals_scored", "wins". Empty = FIFA default.
+    pub tiebreakers: Vec<String>,
+    /// Most foreign nationals a club may name in a matchday squad.
+    pub foreign_player_limit: Option<u8>,
+    /// Senior squad ceiling for every club in the division.
+    pub max_squad_size: Option<u8>,
+}
+
+impl Default for LeagueRulesEntity {
+    fn default() -> Self {
+        LeagueRulesEntity {
+            points_for_win: 3,
+            points_for_draw: 1,
+            points_for_loss: 0,
+            tiebreakers: Vec::new(),
+            foreign_player_limit: None,
+            max_squad_size: None,
+        }
+    }
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct LeagueGroupEntity {
     /// Display name of the group (e.g. "A", "B", "C", "North", "South")
     pub name: String,
@@ -55,7 +95,7 @@ pub struct LeagueGroupEntity {
     pub playoff: Option<PlayoffConfigEntity>,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct PlayoffConfigEntity {
     /// Top N of each group's table that enter the knockout bracket
     /// (e.g. 9 for MLS: seven direct + the two wild-card sides).
@@ -78,7 +118,7 @@ fn default_enabled() -> bool {
     false
 }
 
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct LeagueSettingsEntity {
     pub season_starting_half: DayMonthPeriodEntity,
     pub season_ending_half: DayMonthPeriodEntity,
@@ -89,7 +129,7 @@ pub struct LeagueSettingsEntity {
     pub split_season: bool,
 }
 
-#[derive(Debug, Deserialize, Clone)]
+#[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct DayMonthPeriodEntity {
     pub from_day: u8,
     pub from_month: u8,
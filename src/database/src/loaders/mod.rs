@@ -3,20 +3,31 @@ mod compiled;
 mod continent;
 pub mod country;
 mod data_tree;
+mod directory;
 mod domestic_cup;
+mod export;
+mod fictional;
 mod league;
 mod names;
 pub mod national;
 pub mod players;
+mod staff;
 
 pub use club::*;
+pub(crate) use compiled::{CompiledDatabase, SUPPORTED_VERSION, compiled, install};
 pub use continent::*;
 pub use country::*;
 pub use data_tree::*;
+pub use directory::{DatabaseError, DirectoryLoader};
+pub(crate) use directory::{References, check_club, check_player, check_staff};
 pub use domestic_cup::*;
+pub use export::TableFormat;
+pub(crate) use export::{Table, leaves, tables, write_tables};
+pub(crate) use fictional::generate as fictional_world;
 pub use league::*;
 pub use names::*;
 pub use national::*;
 pub use players::{
     OdbContract, OdbHistoryItem, OdbLoan, OdbPlayer, OdbPosition, OdbReputation, PlayersOdb,
 };
+pub use staff::*;
@@ -1,6 +1,7 @@
-use serde::Deserialize;
+use core::PeopleNameGeneratorData;
+use serde::{Deserialize, Serialize};
 
-#[derive(Deserialize, Clone)]
+#[derive(Serialize, Deserialize, Clone)]
 pub struct NamesByCountryEntity {
     /// Resolved from `country_code` by the loader; zero-default in JSON.
     #[serde(default)]
@@ -8,8 +9,114 @@ pub struct NamesByCountryEntity {
     /// Baked in by the compiler from the enclosing directory.
     #[serde(default)]
     pub country_code: String,
-    pub first_names: Vec<String>,
-    pub last_names: Vec<String>,
+    pub first_names: Vec<NameEntity>,
+    pub last_names: Vec<NameEntity>,
     #[serde(default)]
     pub nicknames: Vec<String>,
 }
+
+impl NamesByCountryEntity {
+    /// The pools the player and staff generators draw from. Pools where
+    /// every name is equally common carry no weights.
+    pub fn people_names(&self) -> PeopleNameGeneratorData {
+        let (first_names, first_name_weights) = split(&self.first_names);
+        let (last_names, last_name_weights) = split(&self.last_names);
+        PeopleNameGeneratorData {
+            first_names,
+            last_names,
+            nicknames: self.nicknames.clone(),
+            first_name_weights,
+            last_name_weights,
+        }
+    }
+}
+
+/// One name of a pool, written either as the bare name or as
+/// `{"name": "Jiří", "weight": 40}` to draw it more or less often than
+/// the names of weight 1.
+#[derive(Serialize, Deserialize, Clone)]
+#[serde(from = "NameRecord", into = "NameRecord")]
+pub struct NameEntity {
+    pub name: String,
+    pub weight: u16,
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum NameRecord {
+    Plain(String),
+    Weighted {
+        name: String,
+        #[serde(default = "default_weight")]
+        weight: u16,
+    },
+}
+
+fn default_weight() -> u16 {
+    1
+}
+
+impl From<NameRecord> for NameEntity {
+    fn from(record: NameRecord) -> Self {
+        let (name, weight) = match record {
+            NameRecord::Plain(name) => (name, 1),
+            NameRecord::Weighted { name, weight } => (name, weight),
+        };
+        NameEntity {
+            name: name.trim().to_string(),
+            weight,
+        }
+    }
+}
+
+impl From<NameEntity> for NameRecord {
+    fn from(entity: NameEntity) -> Self {
+        match entity.weight {
+            1 => NameRecord::Plain(entity.name),
+            weight => NameRecord::Weighted {
+                name: entity.name,
+                weight,
+            },
+        }
+    }
+}
+
+impl From<&str> for NameEntity {
+    fn from(name: &str) -> Self {
+        NameEntity {
+            name: name.to_string(),
+            weight: 1,
+        }
+    }
+}
+
+fn split(names: &[NameEntity]) -> (Vec<String>, Vec<u16>) {
+    let weights = if names.iter().all(|n| n.weight == 1) {
+        Vec::new()
+    } else {
+        names.iter().map(|n| n.weight).collect()
+    };
+    (names.iter().map(|n| n.name.clone()).collect(), weights)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn names_are_plain_or_weighted() {
+        let names: NamesByCountryEntity = serde_json::from_value(json!({
+            "country_code": "cz",
+            "first_names": ["Jan", { "name": "Jiří", "weight": 40 }, { "name": " Tomáš " }],
+            "last_names": ["Novák", "Černý"]
+        }))
+        .unwrap();
+
+        let pools = names.people_names();
+        assert_eq!(pools.first_names, ["Jan", "Jiří", "Tomáš"]);
+        assert_eq!(pools.first_name_weights, [1, 40, 1]);
+        assert_eq!(pools.last_names, ["Novák", "Černý"]);
+        assert!(pools.last_name_weights.is_empty());
+    }
+}
@@ -0,0 +1,27 @@
+use chrono::NaiveDate;
+use core::StaffPosition;
+use serde::{Deserialize, Serialize};
+
+/// A named member of a club's backroom. The generator still builds the
+/// full staff; a named entry takes over the first generated seat with the
+/// same position (or adds one), keeping its generated attributes.
+#[derive(Serialize, Deserialize, Clone)]
+pub struct StaffEntity {
+    pub club_id: u32,
+    /// Team of the club the seat belongs to, as in `ClubTeamEntity`.
+    #[serde(default = "default_team_type")]
+    pub team_type: String,
+    pub position: StaffPosition,
+    pub first_name: String,
+    pub last_name: String,
+    /// Generated when absent.
+    #[serde(default)]
+    pub birth_date: Option<NaiveDate>,
+    /// Defaults to the club's country.
+    #[serde(default)]
+    pub country_id: Option<u32>,
+}
+
+fn default_team_type() -> String {
+    "Main".to_string()
+}
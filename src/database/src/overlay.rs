@@ -0,0 +1,510 @@
+//! Pre-game edits kept apart from the database they change.
+//!
+//! A [`DatabaseOverlay`] holds whole club and player records that replace
+//! (or add to) the base database's, deletions, and per-club named staff
+//! lists. It is stored as one hand-editable JSON file:
+//!
+//! ```json
+//! {
+//!   "clubs": { "1021": { "id": 1021, "name": "...", ... }, "77": null },
+//!   "players": { "5521": { "id": 5521, "current_ability": 150, ... } },
+//!   "staff": { "1021": [ { "club_id": 1021, "position": "Manager", ... } ] }
+//! }
+//! ```
+//!
+//! `null` deletes the record. Edits arrive as JSON merge patches
+//! (RFC 7396) against the current record, are checked like a user-built
+//! world's records and stored whole, so later changes to the base
+//! database never half-apply. The world is generated from
+//! [`DatabaseEntity::with_overlay`]; players of a deleted club are left
+//! out of it unless they are moved first.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::loaders::{References, check_club, check_player, check_staff};
+use crate::{ClubEntity, DatabaseEntity, OdbPlayer, PlayersOdb, StaffEntity};
+
+#[derive(Serialize, Deserialize, Clone, Default)]
+#[serde(default, deny_unknown_fields)]
+pub struct DatabaseOverlay {
+    /// Replaced or added clubs by id; `None` deletes the club.
+    pub clubs: BTreeMap<u32, Option<ClubEntity>>,
+    /// Replaced or added players by id; `None` deletes the player.
+    pub players: BTreeMap<u32, Option<OdbPlayer>>,
+    /// Named staff by club id, replacing the base database's list.
+    pub staff: BTreeMap<u32, Vec<StaffEntity>>,
+}
+
+impl DatabaseOverlay {
+    /// Read an overlay file; a missing file is an empty overlay.
+    pub fn read(path: &Path) -> Result<Self, String> {
+        match fs::read(path) {
+            Ok(bytes) => {
+                serde_json::from_slice(&bytes).map_err(|e| format!("{}: {}", path.display(), e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    /// Write the overlay next to its final path first, so a failed write
+    /// keeps the previous edits.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_vec_pretty(self).map_err(io::Error::other)?;
+        let partial = path.with_extension("json.partial");
+        fs::write(&partial, json)?;
+        fs::rename(&partial, path)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.clubs.is_empty() && self.players.is_empty() && self.staff.is_empty()
+    }
+
+    /// The club as the world would be generated with it.
+    pub fn club(&self, base: &DatabaseEntity, id: u32) -> Option<ClubEntity> {
+        match self.clubs.get(&id) {
+            Some(club) => club.clone(),
+            None => base.club_by_id(id).cloned(),
+        }
+    }
+
+    pub fn player(&self, base: &DatabaseEntity, id: u32) -> Option<OdbPlayer> {
+        match self.players.get(&id) {
+            Some(player) => player.clone(),
+            None => base_players(base).find(|p| p.id == id).cloned(),
+        }
+    }
+
+    /// Named staff of a club; empty when it keeps only generated staff.
+    pub fn staff(&self, base: &DatabaseEntity, club_id: u32) -> Vec<StaffEntity> {
+        match self.staff.get(&club_id) {
+            Some(staff) => staff.clone(),
+            None => base
+                .staff
+                .iter()
+                .filter(|s| s.club_id == club_id)
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Apply `patch` to the club (or create it from the patch alone) and
+    /// keep the result if it checks out.
+    pub fn edit_club(
+        &mut self,
+        base: &DatabaseEntity,
+        id: u32,
+        patch: &Value,
+    ) -> Result<ClubEntity, Vec<String>> {
+        let mut club: ClubEntity = patched(self.club(base, id).as_ref(), patch, id, "club")?;
+        club.country_id = base
+            .countries
+            .iter()
+            .find(|c| c.code == club.country_code)
+            .map_or(0, |c| c.id);
+
+        let mut problems = Vec::new();
+        let clubs = self.clubs_except(base, id);
+        for other in &clubs {
+            for team in other
+                .teams
+                .iter()
+                .filter(|t| club.teams.iter().any(|o| o.id == t.id))
+            {
+                problems.push(format!(
+                    "club {}: team id {} already belongs to club {}",
+                    id, team.id, other.id
+                ));
+            }
+        }
+        let refs = References::new(
+            &base.countries,
+            &base.leagues,
+            clubs.into_iter().chain([&club]),
+        );
+        check_club(&club, &refs, &mut |m| problems.push(m));
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+
+        self.clubs.insert(id, Some(club.clone()));
+        Ok(club)
+    }
+
+    /// Returns false when there is no such club.
+    pub fn delete_club(&mut self, base: &DatabaseEntity, id: u32) -> bool {
+        if self.club(base, id).is_none() {
+            return false;
+        }
+        if base.club_by_id(id).is_some() {
+            self.clubs.insert(id, None);
+        } else {
+            self.clubs.remove(&id);
+        }
+        self.staff.remove(&id);
+        true
+    }
+
+    pub fn edit_player(
+        &mut self,
+        base: &DatabaseEntity,
+        id: u32,
+        patch: &Value,
+    ) -> Result<OdbPlayer, Vec<String>> {
+        let player: OdbPlayer = patched(self.player(base, id).as_ref(), patch, id, "player")?;
+
+        let mut problems = Vec::new();
+        let refs = References::new(&base.countries, &base.leagues, self.clubs_except(base, 0));
+        check_player(&player, &refs, &mut |m| problems.push(m));
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+
+        self.players.insert(id, Some(player.clone()));
+        Ok(player)
+    }
+
+    pub fn delete_player(&mut self, base: &DatabaseEntity, id: u32) -> bool {
+        if self.player(base, id).is_none() {
+            return false;
+        }
+        if base_players(base).any(|p| p.id == id) {
+            self.players.insert(id, None);
+        } else {
+            self.players.remove(&id);
+        }
+        true
+    }
+
+    /// Replace a club's named staff with `staff`, a JSON array of staff
+    /// records; their `club_id` may be left out.
+    pub fn set_staff(
+        &mut self,
+        base: &DatabaseEntity,
+        club_id: u32,
+        staff: &Value,
+    ) -> Result<Vec<StaffEntity>, Vec<String>> {
+        let Some(entries) = staff.as_array() else {
+            return Err(vec!["staff must be a JSON array".to_string()]);
+        };
+        if self.club(base, club_id).is_none() {
+            return Err(vec![format!("club {} does not exist", club_id)]);
+        }
+
+        let mut problems = Vec::new();
+        let mut members = Vec::with_capacity(entries.len());
+        for (index, entry) in entries.iter().enumerate() {
+            let mut entry = entry.clone();
+            if let Some(fields) = entry.as_object_mut() {
+                fields
+                    .entry("club_id")
+                    .or_insert_with(|| Value::from(club_id));
+            }
+            match serde_json::from_value::<StaffEntity>(entry) {
+                Ok(member) if member.club_id != club_id => problems.push(format!(
+                    "staff {}: belongs to club {}, not {}",
+                    index, member.club_id, club_id
+                )),
+                Ok(member) => members.push(member),
+                Err(e) => problems.push(format!("staff {}: {}", index, e)),
+            }
+        }
+
+        let refs = References::new(&base.countries, &base.leagues, self.clubs_except(base, 0));
+        for member in &members {
+            check_staff(member, &refs, &mut |m| problems.push(m));
+        }
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+
+        self.staff.insert(club_id, members.clone());
+        Ok(members)
+    }
+
+    /// Every club of the edited world but `skip`.
+    fn clubs_except<'a>(&'a self, base: &'a DatabaseEntity, skip: u32) -> Vec<&'a ClubEntity> {
+        base.clubs
+            .iter()
+            .filter(|c| !self.clubs.contains_key(&c.id))
+            .chain(self.clubs.values().flatten())
+            .filter(|c| c.id != skip)
+            .collect()
+    }
+}
+
+impl DatabaseEntity {
+    /// A copy of this database with `overlay` applied, after checking its
+    /// records against the edited world, e.g. for an overlay file edited
+    /// by hand.
+    pub fn with_overlay(&self, overlay: &DatabaseOverlay) -> Result<DatabaseEntity, Vec<String>> {
+        let clubs = overlay.clubs_except(self, 0).into_iter().cloned().collect();
+
+        let mut players: HashMap<u32, OdbPlayer> = base_players(self)
+            .filter(|p| !overlay.players.contains_key(&p.id))
+            .map(|p| (p.id, p.clone()))
+            .collect();
+        for player in overlay.players.values().flatten() {
+            players.insert(player.id, player.clone());
+        }
+        let players_odb = (self.players_odb.is_some() || !players.is_empty())
+            .then(|| PlayersOdb::from_players(players.into_values().collect()));
+
+        let staff = self
+            .staff
+            .iter()
+            .filter(|s| !overlay.staff.contains_key(&s.club_id))
+            .chain(overlay.staff.values().flatten())
+            .cloned()
+            .collect();
+
+        let edited = DatabaseEntity {
+            continents: self.continents.clone(),
+            countries: self.countries.clone(),
+            leagues: self.leagues.clone(),
+            clubs,
+            national_competitions: self.national_competitions.clone(),
+            names_by_country: self.names_by_country.clone(),
+            players_odb,
+            staff,
+            index: OnceLock::new(),
+        };
+
+        let mut problems = Vec::new();
+        let refs = References::new(&edited.countries, &edited.leagues, &edited.clubs);
+        let mut team_owners = HashMap::new();
+        for club in &edited.clubs {
+            for team in &club.teams {
+                let Some(owner) = team_owners.insert(team.id, club.id) else {
+                    continue;
+                };
+                if overlay.clubs.contains_key(&club.id) || overlay.clubs.contains_key(&owner) {
+                    problems.push(format!(
+                        "clubs: team id {} belongs to clubs {} and {}",
+                        team.id, owner, club.id
+                    ));
+                }
+            }
+        }
+        for club in overlay.clubs.values().flatten() {
+            check_club(club, &refs, &mut |m| problems.push(format!("clubs: {m}")));
+        }
+        for player in overlay.players.values().flatten() {
+            check_player(player, &refs, &mut |m| {
+                problems.push(format!("players: {m}"))
+            });
+        }
+        for member in overlay.staff.values().flatten() {
+            check_staff(member, &refs, &mut |m| problems.push(format!("staff: {m}")));
+        }
+        if !problems.is_empty() {
+            return Err(problems);
+        }
+        Ok(edited)
+    }
+}
+
+fn base_players(base: &DatabaseEntity) -> impl Iterator<Item = &OdbPlayer> {
+    base.players_odb.iter().flat_map(|odb| odb.players())
+}
+
+/// `current` with `patch` merged in, read back as a record whose id is
+/// `id`. A record that doesn't exist yet is built from the patch alone.
+fn patched<T: Serialize + DeserializeOwned>(
+    current: Option<&T>,
+    patch: &Value,
+    id: u32,
+    kind: &str,
+) -> Result<T, Vec<String>> {
+    if !patch.is_object() {
+        return Err(vec![format!("{} {}: edit must be a JSON object", kind, id)]);
+    }
+    if patch
+        .get("id")
+        .is_some_and(|v| v.as_u64() != Some(u64::from(id)))
+    {
+        return Err(vec![format!("{} {}: id can't be changed", kind, id)]);
+    }
+
+    let mut record = match current {
+        Some(current) => serde_json::to_value(current).map_err(|e| vec![e.to_string()])?,
+        None => Value::Object(Default::default()),
+    };
+    merge_patch(&mut record, patch);
+    record["id"] = Value::from(id);
+    serde_json::from_value(record).map_err(|e| vec![format!("{} {}: {}", kind, id, e)])
+}
+
+/// RFC 7396: objects merge key by key, `null` removes a key and anything
+/// else replaces the target.
+fn merge_patch(target: &mut Value, patch: &Value) {
+    let Value::Object(patch) = patch else {
+        *target = patch.clone();
+        return;
+    };
+    if !target.is_object() {
+        *target = Value::Object(Default::default());
+    }
+    let Value::Object(target) = target else {
+        return;
+    };
+    for (key, value) in patch {
+        if value.is_null() {
+            target.remove(key);
+        } else {
+            merge_patch(target.entry(key.as_str()).or_insert(Value::Null), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn merge_patch_follows_rfc_7396() {
+        let mut target = json!({ "a": "b", "c": { "d": "e", "f": "g" } });
+        merge_patch(&mut target, &json!({ "a": "z", "c": { "f": null } }));
+        assert_eq!(target, json!({ "a": "z", "c": { "d": "e" } }));
+
+        let mut target = json!({ "a": [1, 2] });
+        merge_patch(&mut target, &json!({ "a": [3], "b": { "c": 1 } }));
+        assert_eq!(target, json!({ "a": [3], "b": { "c": 1 } }));
+    }
+
+    fn base() -> DatabaseEntity {
+        DatabaseEntity {
+            continents: Vec::new(),
+            countries: serde_json::from_value(json!([{
+                "id": 900, "code": "at", "slug": "atlantis", "name": "Atlantis",
+                "background_color": "#003399", "foreground_color": "#ffffff",
+                "continent_id": 1, "reputation": 4000,
+                "settings": { "pricing": { "price_level": 1.0 } }
+            }]))
+            .unwrap(),
+            leagues: serde_json::from_value(json!([{
+                "id": 90, "enabled": true, "slug": "atlantis-premier", "name": "Premier",
+                "country_code": "at", "reputation": 3000, "tier": 1,
+                "settings": {
+                    "season_starting_half": { "from_day": 1, "from_month": 8, "to_day": 31, "to_month": 12 },
+                    "season_ending_half": { "from_day": 1, "from_month": 1, "to_day": 31, "to_month": 5 }
+                }
+            }]))
+            .unwrap(),
+            clubs: vec![club(1, 10)],
+            national_competitions: Vec::new(),
+            names_by_country: Vec::new(),
+            players_odb: None,
+            staff: Vec::new(),
+            index: OnceLock::new(),
+        }
+    }
+
+    fn club(id: u32, team_id: u32) -> ClubEntity {
+        serde_json::from_value(json!({
+            "id": id, "name": format!("Club {id}"), "country_code": "at",
+            "location": { "city_id": 1 }, "finance": { "balance": 1000 },
+            "colors": { "background": "#000000", "foreground": "#ffffff" },
+            "teams": [{
+                "id": team_id, "name": format!("Club {id}"), "slug": format!("club-{id}"),
+                "team_type": "Main", "league_id": 90, "finance": null,
+                "reputation": { "home": 100, "national": 100, "world": 100 }
+            }]
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn edits_patch_the_current_record_and_are_checked() {
+        let base = base();
+        let mut overlay = DatabaseOverlay::default();
+
+        let edited = overlay
+            .edit_club(&base, 1, &json!({ "finance": { "balance": 5_000_000 } }))
+            .unwrap();
+        assert_eq!(edited.finance.balance, 5_000_000);
+        assert_eq!(edited.name, "Club 1");
+        assert_eq!(edited.country_id, 900);
+
+        let problems = overlay
+            .edit_club(&base, 1, &json!({ "country_code": "zz" }))
+            .err()
+            .expect("edit should be rejected");
+        assert!(
+            problems[0].contains("unknown country_code 'zz'"),
+            "{problems:?}"
+        );
+        assert_eq!(overlay.club(&base, 1).unwrap().country_code, "at");
+
+        let mut new_club = serde_json::to_value(club(2, 10)).unwrap();
+        let problems = overlay
+            .edit_club(&base, 2, &new_club)
+            .err()
+            .expect("edit should be rejected");
+        assert!(
+            problems[0].contains("already belongs to club 1"),
+            "{problems:?}"
+        );
+        new_club["teams"][0]["id"] = json!(20);
+        overlay.edit_club(&base, 2, &new_club).unwrap();
+
+        let problems = overlay
+            .set_staff(
+                &base,
+                2,
+                &json!([{ "position": "Manager", "first_name": "A" }]),
+            )
+            .err()
+            .expect("edit should be rejected");
+        assert!(problems[0].contains("last_name"), "{problems:?}");
+        overlay
+            .set_staff(
+                &base,
+                2,
+                &json!([{ "position": "Manager", "first_name": "A", "last_name": "B" }]),
+            )
+            .unwrap();
+
+        assert!(overlay.delete_club(&base, 1));
+        assert!(!overlay.delete_club(&base, 1));
+
+        let world = base.with_overlay(&overlay).unwrap();
+        let ids: Vec<u32> = world.clubs.iter().map(|c| c.id).collect();
+        assert_eq!(ids, [2]);
+        assert_eq!(world.staff.len(), 1);
+        assert_eq!(world.staff[0].club_id, 2);
+    }
+
+    #[test]
+    fn overlay_files_round_trip() {
+        let base = base();
+        let mut overlay = DatabaseOverlay::default();
+        overlay
+            .edit_club(&base, 1, &json!({ "name": "Renamed" }))
+            .unwrap();
+        overlay.delete_club(&base, 1);
+        overlay
+            .edit_club(&base, 1, &serde_json::to_value(club(1, 10)).unwrap())
+            .unwrap();
+
+        let path = std::env::temp_dir().join(format!("of-overlay-{}.json", std::process::id()));
+        overlay.write(&path).unwrap();
+        let read = DatabaseOverlay::read(&path).unwrap();
+        assert_eq!(read.club(&base, 1).unwrap().name, "Club 1");
+        fs::remove_file(&path).unwrap();
+
+        assert!(DatabaseOverlay::read(&path).unwrap().is_empty());
+    }
+}
@@ -0,0 +1,80 @@
+//! Database load progress. Loaders report how far each stage has got to
+//! the callback installed with [`DatabaseLoader::on_progress`], so the
+//! binary or a dev tool can show it while a large database loads.
+//!
+//! [`DatabaseLoader::on_progress`]: crate::DatabaseLoader::on_progress
+
+use std::fmt;
+use std::sync::OnceLock;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoadStage {
+    /// Decompressing the embedded database or reading table files; counted
+    /// in files.
+    Reading,
+    /// Turning records into entities; counted in records.
+    Parsing,
+    /// Checking a user-built world's tables against each other.
+    Checking,
+    /// Assembling countries, leagues, clubs and players; counted in steps.
+    Building,
+}
+
+impl fmt::Display for LoadStage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            LoadStage::Reading => "reading",
+            LoadStage::Parsing => "parsing",
+            LoadStage::Checking => "checking",
+            LoadStage::Building => "building",
+        })
+    }
+}
+
+/// `done` of `total` units of `stage` are finished. Parsing runs on
+/// several threads, so reports of one stage may arrive out of order.
+#[derive(Clone, Copy, Debug)]
+pub struct LoadProgress {
+    pub stage: LoadStage,
+    pub done: usize,
+    pub total: usize,
+}
+
+type Callback = Box<dyn Fn(LoadProgress) + Send + Sync>;
+
+static CALLBACK: OnceLock<Callback> = OnceLock::new();
+
+/// First call wins, as with the match dispatcher.
+pub(crate) fn install(callback: Callback) {
+    let _ = CALLBACK.set(callback);
+}
+
+/// Work of one stage, counted from any thread.
+pub(crate) struct StageCounter {
+    stage: LoadStage,
+    done: AtomicUsize,
+    total: usize,
+}
+
+impl StageCounter {
+    pub(crate) fn start(stage: LoadStage, total: usize) -> Self {
+        report(stage, 0, total);
+        StageCounter {
+            stage,
+            done: AtomicUsize::new(0),
+            total,
+        }
+    }
+
+    pub(crate) fn add(&self, units: usize) {
+        let done = self.done.fetch_add(units, Ordering::Relaxed) + units;
+        report(self.stage, done, self.total);
+    }
+}
+
+fn report(stage: LoadStage, done: usize, total: usize) {
+    if let Some(callback) = CALLBACK.get() {
+        callback(LoadProgress { stage, done, total });
+    }
+}
@@ -0,0 +1,652 @@
+//! Integrity checks over a loaded database, run before a world is
+//! generated from it.
+//!
+//! Every problem becomes an [`Issue`] in a [`ValidationReport`] instead of
+//! a panic somewhere in the simulation. Errors are data the simulator
+//! can't run with — an enabled league that can't be scheduled, a season
+//! or transfer window on a date that doesn't exist, ids used twice.
+//! Warnings are records the generator repairs or leaves out: players of
+//! clubs that don't exist, abilities out of range, contracts that ended
+//! before the world starts, implausible dates.
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use chrono::{Datelike, NaiveDate};
+use serde::Serialize;
+
+use crate::generators::{PotentialAbility, parse_position_code};
+use crate::loaders::DayMonthPeriodEntity;
+use crate::{DatabaseEntity, OdbPlayer};
+
+/// Youngest and oldest a listed player may be when the world starts.
+const PLAYER_AGES: std::ops::RangeInclusive<i32> = 14..=50;
+const STAFF_AGES: std::ops::RangeInclusive<i32> = 16..=90;
+const HEIGHTS_CM: std::ops::RangeInclusive<u8> = 140..=215;
+const WEIGHTS_KG: std::ops::RangeInclusive<u8> = 40..=130;
+const MAX_REPUTATION: u16 = 10_000;
+
+/// A record's id and, for tables reached by URL, its slug.
+type IdAndSlug<'a> = (u32, Option<&'a str>);
+
+#[derive(Serialize, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct Issue {
+    pub severity: Severity,
+    /// Stable name of the check, e.g. `unknown_club`.
+    pub check: &'static str,
+    pub table: &'static str,
+    /// Id of the offending record, when it has one.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<u32>,
+    pub message: String,
+}
+
+#[derive(Serialize, Default, Debug)]
+pub struct ValidationReport {
+    pub errors: usize,
+    pub warnings: usize,
+    pub issues: Vec<Issue>,
+}
+
+impl ValidationReport {
+    pub fn has_errors(&self) -> bool {
+        self.errors > 0
+    }
+
+    /// Issues per check, errors first, then the most frequent.
+    pub fn counts(&self) -> Vec<(Severity, &'static str, usize)> {
+        let mut counts: BTreeMap<(Severity, &'static str), usize> = BTreeMap::new();
+        for issue in &self.issues {
+            *counts.entry((issue.severity, issue.check)).or_default() += 1;
+        }
+        let mut counts: Vec<_> = counts
+            .into_iter()
+            .map(|((severity, check), count)| (severity, check, count))
+            .collect();
+        counts.sort_by(|a, b| b.0.cmp(&a.0).then(b.2.cmp(&a.2)));
+        counts
+    }
+
+    /// Write the report as pretty JSON.
+    pub fn write(&self, path: &Path) -> io::Result<()> {
+        if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let json = serde_json::to_vec_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, json)
+    }
+
+    fn push(
+        &mut self,
+        severity: Severity,
+        check: &'static str,
+        table: &'static str,
+        id: Option<u32>,
+        message: String,
+    ) {
+        match severity {
+            Severity::Error => self.errors += 1,
+            Severity::Warning => self.warnings += 1,
+        }
+        self.issues.push(Issue {
+            severity,
+            check,
+            table,
+            id,
+            message,
+        });
+    }
+
+    fn error(&mut self, check: &'static str, table: &'static str, id: u32, message: String) {
+        self.push(Severity::Error, check, table, Some(id), message);
+    }
+
+    fn warn(&mut self, check: &'static str, table: &'static str, id: u32, message: String) {
+        self.push(Severity::Warning, check, table, Some(id), message);
+    }
+}
+
+impl DatabaseEntity {
+    /// Check the database for a world starting on `start`. Errors come
+    /// first in the report, each group in table order.
+    pub fn validate(&self, start: NaiveDate) -> ValidationReport {
+        let mut report = ValidationReport::default();
+        self.check_ids(&mut report);
+        self.check_leagues(&mut report);
+        self.check_countries(&mut report);
+        self.check_clubs(&mut report);
+        self.check_players(start, &mut report);
+        self.check_staff(start, &mut report);
+        report.issues.sort_by_key(|issue| std::cmp::Reverse(issue.severity));
+        report
+    }
+
+    fn check_ids(&self, report: &mut ValidationReport) {
+        let tables: [(&'static str, Vec<IdAndSlug>); 4] = [
+            (
+                "countries",
+                self.countries
+                    .iter()
+                    .map(|c| (c.id, Some(c.slug.as_str())))
+                    .collect(),
+            ),
+            (
+                "leagues",
+                self.leagues
+                    .iter()
+                    .map(|l| (l.id, Some(l.slug.as_str())))
+                    .collect(),
+            ),
+            ("clubs", self.clubs.iter().map(|c| (c.id, None)).collect()),
+            (
+                "teams",
+                self.clubs
+                    .iter()
+                    .flat_map(|c| &c.teams)
+                    .map(|t| (t.id, Some(t.slug.as_str())))
+                    .collect(),
+            ),
+        ];
+
+        for (table, records) in &tables {
+            let mut ids = HashSet::new();
+            let mut slugs = HashMap::new();
+            for &(id, slug) in records {
+                if !ids.insert(id) {
+                    report.error(
+                        "duplicate_id",
+                        table,
+                        id,
+                        format!("{} id {} is used more than once", table, id),
+                    );
+                }
+                // Clubs are reached through their teams' slugs.
+                let Some(slug) = slug else {
+                    continue;
+                };
+                if let Some(first) = slugs.insert(slug, id).filter(|first| *first != id) {
+                    report.warn(
+                        "duplicate_slug",
+                        table,
+                        id,
+                        format!(
+                            "{} slug '{}' of {} is already used by {}; pages show only one",
+                            table, slug, id, first
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    fn check_leagues(&self, report: &mut ValidationReport) {
+        let mut teams_per_league: HashMap<u32, usize> = HashMap::new();
+        for team in self.clubs.iter().flat_map(|c| &c.teams) {
+            if let Some(league_id) = team.league_id {
+                *teams_per_league.entry(league_id).or_default() += 1;
+            }
+        }
+
+        for league in &self.leagues {
+            let name = format!("league {} ({})", league.id, league.name);
+            let settings = &league.settings;
+            for (half, period) in [
+                ("season_starting_half", &settings.season_starting_half),
+                ("season_ending_half", &settings.season_ending_half),
+            ] {
+                if let Some(problem) = period_problem(period) {
+                    report.error(
+                        "invalid_date",
+                        "leagues",
+                        league.id,
+                        format!("{name}: {half} {problem}"),
+                    );
+                }
+            }
+
+            let teams = teams_per_league.get(&league.id).copied().unwrap_or(0);
+            if league.enabled && teams < 2 {
+                report.error(
+                    "league_without_schedule",
+                    "leagues",
+                    league.id,
+                    format!("{name} is enabled but has {teams} team(s), a schedule needs two"),
+                );
+            }
+            if league.country_id == 0 {
+                report.warn(
+                    "unknown_country",
+                    "leagues",
+                    league.id,
+                    format!("{name} has unknown country_code '{}'", league.country_code),
+                );
+            }
+            if let Some(target) = league
+                .relegation_league_id
+                .filter(|id| self.league_by_id(*id).is_none())
+            {
+                report.warn(
+                    "unknown_league",
+                    "leagues",
+                    league.id,
+                    format!("{name} relegates into unknown league {target}"),
+                );
+            }
+            if league.reputation > MAX_REPUTATION {
+                report.warn(
+                    "out_of_range",
+                    "leagues",
+                    league.id,
+                    format!(
+                        "{name}: reputation {} is above {MAX_REPUTATION}",
+                        league.reputation
+                    ),
+                );
+            }
+        }
+    }
+
+    fn check_countries(&self, report: &mut ValidationReport) {
+        for country in &self.countries {
+            let Some(windows) = &country.regulations.transfer_windows else {
+                continue;
+            };
+            for (name, window) in [("summer", &windows.summer), ("winter", &windows.winter)] {
+                for (edge, month, day) in [
+                    ("opens", window.open_month, window.open_day),
+                    ("closes", window.close_month, window.close_day),
+                ] {
+                    if !is_day_of_year(month, day) {
+                        report.error(
+                            "invalid_date",
+                            "countries",
+                            country.id,
+                            format!(
+                                "country {} ({}): {name} window {edge} on {day}/{month}, which doesn't exist",
+                                country.id, country.name
+                            ),
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn check_clubs(&self, report: &mut ValidationReport) {
+        for club in &self.clubs {
+            let name = format!("club {} ({})", club.id, club.name);
+            if club.country_id == 0 {
+                report.warn(
+                    "unknown_country",
+                    "clubs",
+                    club.id,
+                    format!("{name} has unknown country_code '{}'", club.country_code),
+                );
+            }
+            for team in &club.teams {
+                match team.league_id {
+                    Some(id) if self.league_by_id(id).is_none() => report.warn(
+                        "unknown_league",
+                        "clubs",
+                        club.id,
+                        format!("{name}: team {} plays in unknown league {id}", team.id),
+                    ),
+                    None if team.team_type == "Main" => report.warn(
+                        "unknown_league",
+                        "clubs",
+                        club.id,
+                        format!("{name}: Main team {} has no league", team.id),
+                    ),
+                    _ => {}
+                }
+                let reputation = &team.reputation;
+                let highest = reputation
+                    .home
+                    .max(reputation.national)
+                    .max(reputation.world);
+                if highest > MAX_REPUTATION {
+                    report.warn(
+                        "out_of_range",
+                        "clubs",
+                        club.id,
+                        format!(
+                            "{name}: team {} reputation {highest} is above {MAX_REPUTATION}",
+                            team.id
+                        ),
+                    );
+                }
+            }
+            for rival in club
+                .rivals
+                .iter()
+                .filter(|id| self.club_by_id(**id).is_none())
+            {
+                report.warn(
+                    "unknown_club",
+                    "clubs",
+                    club.id,
+                    format!("{name} has unknown rival {rival}"),
+                );
+            }
+        }
+    }
+
+    fn check_players(&self, start: NaiveDate, report: &mut ValidationReport) {
+        let Some(odb) = &self.players_odb else {
+            return;
+        };
+        let country_ids: HashSet<u32> = self.countries.iter().map(|c| c.id).collect();
+
+        let mut players: Vec<&OdbPlayer> = odb.players().collect();
+        players.sort_by_key(|p| p.id);
+        for player in players {
+            check_player(self, player, &country_ids, start, report);
+        }
+    }
+
+    fn check_staff(&self, start: NaiveDate, report: &mut ValidationReport) {
+        for member in &self.staff {
+            let name = format!("{} {}", member.first_name, member.last_name);
+            if self.club_by_id(member.club_id).is_none() {
+                report.warn(
+                    "unknown_club",
+                    "staff",
+                    member.club_id,
+                    format!("{name} has unknown club_id {}", member.club_id),
+                );
+            }
+            if let Some(age) = member
+                .birth_date
+                .map(|born| age_on(born, start))
+                .filter(|age| !STAFF_AGES.contains(age))
+            {
+                report.warn(
+                    "implausible_date",
+                    "staff",
+                    member.club_id,
+                    format!("{name} would be {age} years old"),
+                );
+            }
+        }
+    }
+}
+
+fn check_player(
+    db: &DatabaseEntity,
+    player: &OdbPlayer,
+    country_ids: &HashSet<u32>,
+    start: NaiveDate,
+    report: &mut ValidationReport,
+) {
+    let id = player.id;
+    let name = format!(
+        "player {} ({} {})",
+        player.id, player.first_name, player.last_name
+    );
+    let mut warn = |check: &'static str, message: String| {
+        report.warn(check, "players", id, format!("{name} {message}"))
+    };
+
+    if !country_ids.contains(&player.country_id) {
+        warn(
+            "unknown_country",
+            format!("has unknown country_id {}", player.country_id),
+        );
+    }
+    if player.club_id != 0 && db.club_by_id(player.club_id).is_none() {
+        warn(
+            "unknown_club",
+            format!("has unknown club_id {} and is left out", player.club_id),
+        );
+    }
+    if let Some(loan) = &player.loan {
+        if db.club_by_id(loan.to_club_id).is_none() {
+            warn(
+                "unknown_club",
+                format!("is loaned to unknown club {}", loan.to_club_id),
+            );
+        }
+        if loan.expiration < start {
+            warn(
+                "expired_contract",
+                format!("has a loan that ended on {}", loan.expiration),
+            );
+        }
+    }
+    match &player.contract {
+        None if player.club_id != 0 => warn(
+            "missing_contract",
+            "plays for a club without a contract".to_string(),
+        ),
+        Some(contract) if contract.expiration < start => warn(
+            "expired_contract",
+            format!("has a contract that expired on {}", contract.expiration),
+        ),
+        Some(contract) if contract.started.is_some_and(|s| s > contract.expiration) => warn(
+            "implausible_date",
+            "has a contract that starts after it expires".to_string(),
+        ),
+        _ => {}
+    }
+
+    let age = age_on(player.birth_date, start);
+    if !PLAYER_AGES.contains(&age) {
+        warn("implausible_date", format!("would be {age} years old"));
+    }
+    if let Some(season) = player
+        .history
+        .iter()
+        .map(|h| i32::from(h.season))
+        .find(|season| *season > start.year())
+    {
+        warn(
+            "implausible_date",
+            format!("has history for the future {season} season"),
+        );
+    }
+
+    if !(1..=200).contains(&player.current_ability) {
+        warn(
+            "out_of_range",
+            format!(
+                "has current_ability {}, outside 1..=200",
+                player.current_ability
+            ),
+        );
+    }
+    if !PotentialAbility::is_known(player.potential_ability) {
+        warn(
+            "out_of_range",
+            format!(
+                "has potential_ability {}, neither 0..=200 nor a band code",
+                player.potential_ability
+            ),
+        );
+    }
+    if player.positions.is_empty() {
+        warn("out_of_range", "has no positions".to_string());
+    }
+    for position in &player.positions {
+        if parse_position_code(&position.code).is_none() {
+            warn(
+                "out_of_range",
+                format!("has unknown position code '{}'", position.code),
+            );
+        } else if !(1..=20).contains(&position.level) {
+            warn(
+                "out_of_range",
+                format!(
+                    "plays {} at level {}, outside 1..=20",
+                    position.code, position.level
+                ),
+            );
+        }
+    }
+    if let Some(height) = player.height.filter(|h| !HEIGHTS_CM.contains(h)) {
+        warn("out_of_range", format!("is {height} cm tall"));
+    }
+    if let Some(weight) = player.weight.filter(|w| !WEIGHTS_KG.contains(w)) {
+        warn("out_of_range", format!("weighs {weight} kg"));
+    }
+    if let Some(foots) = player
+        .foots
+        .as_ref()
+        .filter(|f| f.left > 100 || f.right > 100)
+    {
+        warn(
+            "out_of_range",
+            format!("has foot levels {}/{}, above 100", foots.left, foots.right),
+        );
+    }
+}
+
+/// Why `period` can't be placed in a year, if it can't.
+fn period_problem(period: &DayMonthPeriodEntity) -> Option<String> {
+    [
+        ("starts", period.from_month, period.from_day),
+        ("ends", period.to_month, period.to_day),
+    ]
+    .into_iter()
+    .find(|(_, month, day)| !is_day_of_year(u32::from(*month), u32::from(*day)))
+    .map(|(edge, month, day)| format!("{edge} on {day}/{month}, which doesn't exist"))
+}
+
+/// Whether `day`/`month` exists in a leap year.
+fn is_day_of_year(month: u32, day: u32) -> bool {
+    NaiveDate::from_ymd_opt(2024, month, day).is_some()
+}
+
+fn age_on(born: NaiveDate, date: NaiveDate) -> i32 {
+    date.years_since(born)
+        .map_or(-((born.year() - date.year()).abs()), |years| years as i32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PlayersOdb;
+    use serde_json::json;
+    use std::sync::OnceLock;
+
+    fn league(id: u32, enabled: bool, from_month: u8) -> serde_json::Value {
+        json!({
+            "id": id, "enabled": enabled, "slug": format!("league-{id}"), "name": "League",
+            "country_id": 900, "country_code": "at", "reputation": 3000, "tier": 1,
+            "settings": {
+                "season_starting_half": { "from_day": 1, "from_month": from_month, "to_day": 31, "to_month": 12 },
+                "season_ending_half": { "from_day": 1, "from_month": 1, "to_day": 31, "to_month": 5 }
+            }
+        })
+    }
+
+    fn club(id: u32, slug: &str, league_id: u32) -> serde_json::Value {
+        json!({
+            "id": id, "name": format!("Club {id}"), "country_id": 900, "country_code": "at",
+            "location": { "city_id": 1 }, "finance": { "balance": 1000 },
+            "colors": { "background": "#000000", "foreground": "#ffffff" },
+            "teams": [{
+                "id": id, "name": format!("Club {id}"), "slug": slug,
+                "team_type": "Main", "league_id": league_id, "finance": null,
+                "reputation": { "home": 100, "national": 100, "world": 100 }
+            }]
+        })
+    }
+
+    fn player(id: u32, club_id: u32, born: &str, ability: u8) -> OdbPlayer {
+        serde_json::from_value(json!({
+            "id": id, "first_name": "A", "last_name": "B", "birth_date": born,
+            "country_id": 900, "club_id": club_id,
+            "positions": [{ "code": "ST", "level": 20 }],
+            "current_ability": ability, "potential_ability": -9,
+            "contract": { "expiration": "2030-06-30" }
+        }))
+        .unwrap()
+    }
+
+    fn database() -> DatabaseEntity {
+        DatabaseEntity {
+            continents: Vec::new(),
+            countries: serde_json::from_value(json!([{
+                "id": 900, "code": "at", "slug": "atlantis", "name": "Atlantis",
+                "background_color": "#003399", "foreground_color": "#ffffff",
+                "continent_id": 1, "reputation": 4000,
+                "settings": { "pricing": { "price_level": 1.0 } }
+            }]))
+            .unwrap(),
+            leagues: serde_json::from_value(json!([
+                league(90, true, 8),
+                league(91, true, 13),
+                league(92, false, 8)
+            ]))
+            .unwrap(),
+            clubs: serde_json::from_value(json!([
+                club(1, "first", 90),
+                club(2, "second", 90),
+                club(3, "second", 91)
+            ]))
+            .unwrap(),
+            national_competitions: Vec::new(),
+            names_by_country: Vec::new(),
+            players_odb: Some(PlayersOdb::from_players(vec![
+                player(10, 1, "2000-01-01", 120),
+                player(11, 7, "2000-01-01", 120),
+                player(12, 2, "1960-01-01", 0),
+            ])),
+            staff: Vec::new(),
+            index: OnceLock::new(),
+        }
+    }
+
+    fn checks(report: &ValidationReport, severity: Severity) -> Vec<(&'static str, Option<u32>)> {
+        report
+            .issues
+            .iter()
+            .filter(|i| i.severity == severity)
+            .map(|i| (i.check, i.id))
+            .collect()
+    }
+
+    #[test]
+    fn unschedulable_leagues_and_impossible_dates_are_errors() {
+        let report = database().validate(NaiveDate::from_ymd_opt(2026, 8, 1).unwrap());
+
+        assert_eq!(
+            checks(&report, Severity::Error),
+            [
+                ("invalid_date", Some(91)),
+                ("league_without_schedule", Some(91))
+            ]
+        );
+        assert!(report.has_errors());
+        assert_eq!(report.errors, 2);
+    }
+
+    #[test]
+    fn repairable_records_are_warnings() {
+        let report = database().validate(NaiveDate::from_ymd_opt(2026, 8, 1).unwrap());
+
+        assert_eq!(
+            checks(&report, Severity::Warning),
+            [
+                ("duplicate_slug", Some(3)),
+                ("unknown_club", Some(11)),
+                ("implausible_date", Some(12)),
+                ("out_of_range", Some(12)),
+            ]
+        );
+        assert_eq!(report.counts()[0], (Severity::Error, "invalid_date", 1));
+
+        let json = serde_json::to_value(&report).unwrap();
+        assert_eq!(json["issues"][0]["severity"], "error");
+        assert_eq!(json["warnings"], 4);
+    }
+}
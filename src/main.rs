@@ -16,31 +16,153 @@ static GLOBAL: Jemalloc = Jemalloc;
 #[global_allocator]
 static GLOBAL: MiMalloc = MiMalloc;
 
-use database::{DatabaseGenerator, DatabaseLoader};
+use chrono::{Months, NaiveDate};
+use database::{DatabaseGenerator, DatabaseLoader, DatabaseOverlay, Severity, ValidationReport};
 use env_logger::Env;
-use log::info;
-use simulator_core::r#match::MatchDispatcherRegistry;
+use log::{error, info, warn};
+use simulator_core::r#match::{
+    MatchDispatcherRegistry, PassSuccessModel, StateCloningModel, TrainingData, read_pass_samples,
+    state_actions, write_state_actions,
+};
 use simulator_core::utils::TimeEstimation;
+use simulator_core::{FootballSimulator, SimulatorData};
+use std::path::Path;
 use std::sync::Arc;
 use tokio::sync::{Mutex, RwLock};
 use web::{
-    AiConfig, AiJobs, DistributedDispatcher, FootballSimulatorServer, GameAppData, I18nManager,
-    Settings, WorkerRegistry, WorkerServer,
+    AiConfig, AiJobs, DatabaseEditor, DistributedDispatcher, FootballSimulatorServer, GameAppData,
+    I18nManager, MatchStore, Metrics, ProcessProgress, SaveStore, Settings, SimulationControl,
+    WorkerRegistry, WorkerServer, export_results,
 };
 
+/// Fit a pass model on the training-data rows in `data` and write it to
+/// `path`. Exits with status 1 when either file can't be used.
+fn train_pass_model(data: &Path, path: &Path) {
+    let samples = match read_pass_samples(data) {
+        Ok(samples) if !samples.is_empty() => samples,
+        Ok(_) => {
+            error!("pass model: {} has no passes", data.display());
+            std::process::exit(1);
+        }
+        Err(err) => {
+            error!("pass model: {}: {}", data.display(), err);
+            std::process::exit(1);
+        }
+    };
+    let model = PassSuccessModel::train(&samples);
+    let fit = model.fit(&samples);
+    info!(
+        "pass model: {} passes ({:.1}% completed), log loss {:.4}, accuracy {:.1}%",
+        fit.samples,
+        fit.base_rate * 100.0,
+        fit.log_loss,
+        fit.accuracy * 100.0
+    );
+    if let Err(err) = model.write(path) {
+        error!("pass model: {}: {}", path.display(), err);
+        std::process::exit(1);
+    }
+    info!("pass model written to {}", path.display());
+}
+
+/// Turn the replays under `replays` into state-action pairs, fit a
+/// state-cloning model on them and write `state_actions.csv` and
+/// `state-model.json` to `output`. Replays without player states are
+/// skipped; exits with status 1 when nothing usable is left or a file
+/// can't be written.
+fn build_imitation_data(replays: &Path, output: &Path) {
+    let files = match MatchStore::replay_files(replays) {
+        Ok(files) => files,
+        Err(err) => {
+            error!("imitation: {}: {}", replays.display(), err);
+            std::process::exit(1);
+        }
+    };
+    let mut pairs = Vec::new();
+    for file in &files {
+        let read = MatchStore::read_replay(file).map_err(|e| e.to_string());
+        match read.and_then(|replay| state_actions(&replay)) {
+            Ok(found) => pairs.extend(found),
+            Err(err) => warn!("imitation: skipping {}: {}", file.display(), err),
+        }
+    }
+    if pairs.is_empty() {
+        error!(
+            "imitation: no state-action pairs in {} replay(s) under {}",
+            files.len(),
+            replays.display()
+        );
+        std::process::exit(1);
+    }
+
+    let model = StateCloningModel::train(&pairs);
+    let fit = model.fit(&pairs);
+    info!(
+        "imitation: {} pairs from {} replay(s), {} states, accuracy {:.1}% (most common state per role {:.1}%)",
+        fit.samples,
+        files.len(),
+        fit.states,
+        fit.accuracy * 100.0,
+        fit.baseline * 100.0
+    );
+    let written = std::fs::create_dir_all(output)
+        .and_then(|_| write_state_actions(&output.join("state_actions.csv"), &pairs))
+        .and_then(|_| model.write(&output.join("state-model.json")));
+    if let Err(err) = written {
+        error!("imitation: {}: {}", output.display(), err);
+        std::process::exit(1);
+    }
+    info!("imitation data written to {}", output.display());
+}
+
+/// Slot the world is written to at the end of a one-shot run.
+const ONE_SHOT_SLOT: &str = "one-shot";
+
 #[tokio::main]
 async fn main() {
     color_eyre::install().unwrap();
 
-    env_logger::Builder::from_env(Env::default().default_filter_or("debug")).init();
+    let settings = match Settings::from_env() {
+        Ok(settings) => settings,
+        Err(err) => {
+            eprintln!("configuration error: {}", err);
+            std::process::exit(2);
+        }
+    };
 
-    info!("SIMD: {}", simulator_core::utils::cpu::simd_kernel_name());
+    env_logger::Builder::from_env(Env::default().default_filter_or(settings.log_level.as_str()))
+        .init();
 
-    let settings = Settings::from_env();
+    info!("SIMD: {}", simulator_core::utils::cpu::simd_kernel_name());
 
     settings.apply();
     settings.log();
 
+    // The directory is named in the settings log just above.
+    if let Some(Err(err)) = settings.training_data.as_deref().map(TrainingData::start) {
+        error!("training data: {}", err);
+        std::process::exit(2);
+    }
+
+    if let (Some(data), Some(path)) = (&settings.train_pass_model, &settings.pass_model) {
+        train_pass_model(data, path);
+        return;
+    }
+    if let (Some(replays), Some(output)) = (&settings.imitation_replays, &settings.imitation_output)
+    {
+        build_imitation_data(replays, output);
+        return;
+    }
+    if let Some(path) = &settings.pass_model {
+        match PassSuccessModel::read(path) {
+            Ok(model) => PassSuccessModel::install(model),
+            Err(err) => {
+                error!("pass model {}: {}", path.display(), err);
+                std::process::exit(2);
+            }
+        }
+    }
+
     // Worker mode: skip DB load + UI, just serve match RPCs.
     if settings.worker_mode {
         WorkerServer::new(settings.worker_port).run().await;
@@ -64,17 +186,130 @@ async fn main() {
         settings.match_threads,
     )));
 
-    let (database, estimated) = TimeEstimation::estimate(DatabaseLoader::load);
+    DatabaseLoader::on_progress(|progress| {
+        if progress.done == progress.total {
+            info!(
+                "database load: {} {}/{}",
+                progress.stage, progress.done, progress.total
+            );
+        }
+    });
+    let (database, estimated) = match (&settings.database_dir, settings.fictional_world) {
+        (Some(dir), _) => {
+            let (loaded, estimated) = TimeEstimation::estimate(|| DatabaseLoader::load_dir(dir));
+            match loaded {
+                Ok(database) => (database, estimated),
+                Err(err) => {
+                    error!("{}", err);
+                    std::process::exit(2);
+                }
+            }
+        }
+        (None, Some(seed)) => {
+            let (made, estimated) =
+                TimeEstimation::estimate(|| DatabaseLoader::load_fictional(seed));
+            match made {
+                Ok(database) => (database, estimated),
+                Err(err) => {
+                    error!("fictional world: {}", err);
+                    std::process::exit(2);
+                }
+            }
+        }
+        (None, None) => TimeEstimation::estimate(DatabaseLoader::load),
+    };
 
-    let (game_data, gen_ms) = TimeEstimation::estimate(|| DatabaseGenerator::generate(&database));
+    let overlay = match DatabaseOverlay::read(&settings.database_overlay) {
+        Ok(overlay) => overlay,
+        Err(err) => {
+            error!("database overlay: {}", err);
+            std::process::exit(2);
+        }
+    };
+
+    let edited = if overlay.is_empty() {
+        None
+    } else {
+        info!(
+            "Database overlay: {} club(s), {} player(s) and {} staff list(s) edited",
+            overlay.clubs.len(),
+            overlay.players.len(),
+            overlay.staff.len()
+        );
+        match database.with_overlay(&overlay) {
+            Ok(edited) => Some(edited),
+            Err(problems) => {
+                error!(
+                    "database overlay {} has {} problem(s):\n  - {}",
+                    settings.database_overlay.display(),
+                    problems.len(),
+                    problems.join("\n  - ")
+                );
+                std::process::exit(2);
+            }
+        }
+    };
+    let world_database = edited.as_ref().unwrap_or(&database);
+
+    if let Some(dir) = &settings.export_database {
+        match world_database.export(dir, settings.export_format) {
+            Ok(()) => info!("database exported to {}", dir.display()),
+            Err(err) => {
+                error!("database export to {} failed: {}", dir.display(), err);
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+    if let Some(dir) = &settings.diff_database {
+        match world_database.diff(dir) {
+            Ok(diff) => {
+                print!("{}", diff);
+                // Like diff(1): 1 when the databases differ.
+                std::process::exit(i32::from(!diff.is_empty()));
+            }
+            Err(err) => {
+                error!("{}", err);
+                std::process::exit(2);
+            }
+        }
+    }
+
+    let report = world_database.validate(DatabaseGenerator::start_date().date());
+    log_database_report(&report, settings.database_report.as_deref());
+    if report.has_errors() {
+        std::process::exit(2);
+    }
+
+    let (game_data, gen_ms) =
+        TimeEstimation::estimate(|| DatabaseGenerator::generate(world_database));
+    drop(edited);
 
     info!(
         "database loaded: {} ms, generated: {} ms",
         estimated, gen_ms
     );
 
+    let saves = settings.save_store();
+
+    let one_shot_days = settings
+        .one_shot_seasons
+        .map(|seasons| days_in_seasons(game_data.date.date(), seasons))
+        .or(settings.one_shot_days);
+    if let Some(days) = one_shot_days {
+        run_one_shot(game_data, days, &saves, settings.one_shot_output.as_deref()).await;
+        return;
+    }
+
     let i18n = Arc::new(I18nManager::new());
     i18n.set_date(game_data.date);
+    let packs = i18n.load_locales_dir(&settings.locales_dir);
+    if !packs.is_empty() {
+        info!("Translation packs loaded: {}", packs.join(", "));
+    }
+    if settings.locales_reload {
+        I18nManager::watch_locales_dir(Arc::clone(&i18n), settings.locales_dir.clone());
+    }
 
     let data = GameAppData {
         database: Arc::new(database),
@@ -85,27 +320,118 @@ async fn main() {
         workers,
         ai: AiConfig::new(),
         ai_jobs: AiJobs::new(),
+        progress: ProcessProgress::new(),
+        saves,
+        metrics: Metrics::new(),
+        control: SimulationControl::new(settings.admin_token.clone()),
+        portraits: settings.portrait_store(),
+        editor: DatabaseEditor::new(overlay, settings.database_overlay.clone()),
     };
 
+    let url = settings.local_url();
+
     // Open browser
     #[cfg(target_os = "windows")]
     {
         let _ = std::process::Command::new("cmd")
-            .args(["/C", "start", "http://localhost:18000"])
+            .args(["/C", "start", &url])
             .spawn();
     }
     #[cfg(target_os = "macos")]
     {
-        let _ = std::process::Command::new("open")
-            .arg("http://localhost:18000")
-            .spawn();
+        let _ = std::process::Command::new("open").arg(&url).spawn();
     }
     #[cfg(target_os = "linux")]
     {
-        let _ = std::process::Command::new("xdg-open")
-            .arg("http://localhost:18000")
-            .spawn();
+        let _ = std::process::Command::new("xdg-open").arg(&url).spawn();
+    }
+
+    FootballSimulatorServer::new(data, settings.listen_address(), settings.request_limits())
+        .run()
+        .await;
+}
+
+/// Summarise the database check in the log, every error in full, and
+/// write the whole report to `path` when one is configured.
+fn log_database_report(report: &ValidationReport, path: Option<&Path>) {
+    if let Some(path) = path {
+        match report.write(path) {
+            Ok(()) => info!("database report written to {}", path.display()),
+            Err(err) => error!("database report {}: {}", path.display(), err),
+        }
+    }
+    if report.issues.is_empty() {
+        return;
     }
 
-    FootballSimulatorServer::new(data).run().await;
+    info!(
+        "database check: {} error(s), {} warning(s)",
+        report.errors, report.warnings
+    );
+    for (severity, check, count) in report.counts() {
+        match severity {
+            Severity::Error => error!("  {}: {}", check, count),
+            Severity::Warning => warn!("  {}: {}", check, count),
+        }
+    }
+    for issue in report
+        .issues
+        .iter()
+        .filter(|i| i.severity == Severity::Error)
+    {
+        error!("{}: {}", issue.table, issue.message);
+    }
+}
+
+/// Days from `start` to the same date `seasons` years on.
+fn days_in_seasons(start: NaiveDate, seasons: u32) -> u32 {
+    let end = start
+        .checked_add_months(Months::new(seasons.saturating_mul(12)))
+        .unwrap_or(NaiveDate::MAX);
+    (end - start).num_days().try_into().unwrap_or(u32::MAX)
+}
+
+/// Simulate `days` days without the web server, save the result and,
+/// when `output` is set, export tables, transfers and statistics there.
+async fn run_one_shot(
+    mut game_data: SimulatorData,
+    days: u32,
+    saves: &SaveStore,
+    output: Option<&Path>,
+) {
+    let started = std::time::Instant::now();
+    for _ in 0..days {
+        FootballSimulator::simulate(&mut game_data).await;
+    }
+    info!(
+        "one-shot: simulated {} days in {} ms, now {}",
+        days,
+        started.elapsed().as_millis(),
+        game_data.date.date()
+    );
+
+    match saves.save(ONE_SHOT_SLOT, &game_data) {
+        Ok(info) => info!("one-shot: saved to slot '{}'", info.slot),
+        Err(err) => {
+            error!("one-shot: saving failed: {}", err);
+            std::process::exit(1);
+        }
+    }
+
+    let Some(output) = output else {
+        return;
+    };
+    match export_results(&game_data, output) {
+        Ok(summary) => info!(
+            "one-shot: exported {} tables, {} transfers and {} player seasons to {}",
+            summary.tables,
+            summary.transfers,
+            summary.player_seasons,
+            output.display()
+        ),
+        Err(err) => {
+            error!("one-shot: export to {} failed: {}", output.display(), err);
+            std::process::exit(1);
+        }
+    }
 }
@@ -107,6 +107,39 @@ fn main() {
         css_hash, short_version
     );
     fs::write(&hash_file, hash_content).expect("Failed to write css_hash.rs");
+
+    generate_i18n_keys(Path::new(&manifest_dir), Path::new(&out_dir));
+}
+
+/// One constant per key of the English bundle, so keys used from Rust are
+/// checked at compile time: `i18n.t(keys::N_SEASONS)` rather than a bare
+/// string that silently renders as the key when misspelled.
+fn generate_i18n_keys(manifest_dir: &Path, out_dir: &Path) {
+    let en_path = manifest_dir.join("assets").join("i18n").join("en.json");
+    println!("cargo:rerun-if-changed={}", en_path.display());
+
+    let text = fs::read_to_string(&en_path).expect("Failed to read i18n/en.json");
+    let text = text.strip_prefix('\u{feff}').unwrap_or(&text);
+    let bundle: serde_json::Map<String, serde_json::Value> =
+        serde_json::from_str(text).expect("Invalid JSON in i18n/en.json");
+
+    let mut keys: Vec<(&String, &serde_json::Value)> = bundle.iter().collect();
+    keys.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut content = String::from("// Auto-generated by build.rs - DO NOT EDIT\n");
+    for (key, value) in keys {
+        let mut name = key.to_ascii_uppercase();
+        if name.starts_with(|c: char| c.is_ascii_digit()) {
+            name.insert(0, '_');
+        }
+        content.push_str(&format!(
+            "#[doc = {:?}]\npub const {}: &str = {:?};\n",
+            value.as_str().unwrap_or_default(),
+            name,
+            key
+        ));
+    }
+    fs::write(out_dir.join("i18n_keys.rs"), content).expect("Failed to write i18n_keys.rs");
 }
 
 /// Simple CSS minification function
@@ -0,0 +1,195 @@
+//! Operator controls for a long-running server under `/admin`: pause and
+//! resume the world clock, set how many days one `/api/game/process`
+//! request advances, and step the date immediately. Every endpoint needs
+//! `Authorization: Bearer <admin_token>`; without a configured token the
+//! group answers 404, so a default install exposes nothing.
+
+pub mod routes;
+
+use crate::game::start_processing;
+use crate::{ApiError, ApiJson, ApiQuery, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{FromRequestParts, State};
+use axum::http::StatusCode;
+use axum::http::header::AUTHORIZATION;
+use axum::http::request::Parts;
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+pub fn admin_routes() -> axum::Router<GameAppData> {
+    routes::routes()
+}
+
+/// Upper bound for the days-per-request setting: a simulated season.
+const MAX_DAYS_PER_REQUEST: u32 = 365;
+
+/// Simulation clock settings shared by the processing endpoints and the
+/// admin group. Cheap to clone; clones share the same state.
+#[derive(Clone)]
+pub struct SimulationControl {
+    paused: Arc<AtomicBool>,
+    days_per_request: Arc<AtomicU32>,
+    token: Option<Arc<str>>,
+}
+
+impl SimulationControl {
+    /// `token` enables the admin endpoints; `None` keeps them disabled.
+    pub fn new(token: Option<String>) -> Self {
+        SimulationControl {
+            paused: Arc::new(AtomicBool::new(false)),
+            days_per_request: Arc::new(AtomicU32::new(1)),
+            token: token.filter(|t| !t.is_empty()).map(Arc::from),
+        }
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::SeqCst)
+    }
+
+    /// Days a process request advances when it doesn't ask for a number.
+    pub fn days_per_request(&self) -> u32 {
+        self.days_per_request.load(Ordering::SeqCst)
+    }
+
+    fn accepts(&self, presented: &str) -> bool {
+        self.token
+            .as_deref()
+            .is_some_and(|token| constant_time_eq(token.as_bytes(), presented.as_bytes()))
+    }
+}
+
+/// Compare without an early exit, so response timing doesn't reveal how
+/// much of a guessed token was right.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// Proof that the request carried the admin token.
+pub struct AdminAuth;
+
+impl FromRequestParts<GameAppData> for AdminAuth {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &GameAppData,
+    ) -> Result<Self, Self::Rejection> {
+        if state.control.token.is_none() {
+            return Err(ApiError::NotFound(
+                "Admin endpoints are disabled".to_string(),
+            ));
+        }
+        let presented = parts
+            .headers
+            .get(AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .unwrap_or_default();
+        if state.control.accepts(presented.trim()) {
+            Ok(AdminAuth)
+        } else {
+            Err(ApiError::Unauthorized("Invalid admin token".to_string()))
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct AdminStatusDto {
+    pub paused: bool,
+    pub days_per_request: u32,
+    pub processing: bool,
+    pub date: Option<NaiveDate>,
+}
+
+/// `GET /admin/status`
+pub async fn admin_status_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+) -> Json<AdminStatusDto> {
+    let date = state.data.read().await.as_ref().map(|sim| sim.date.date());
+    Json(AdminStatusDto {
+        paused: state.control.is_paused(),
+        days_per_request: state.control.days_per_request(),
+        processing: state.process_lock.try_lock().is_err(),
+        date,
+    })
+}
+
+/// `POST /admin/pause` — new process requests are refused and a running
+/// multi-day run holds before its next day.
+pub async fn admin_pause_action(
+    auth: AdminAuth,
+    State(state): State<GameAppData>,
+) -> Json<AdminStatusDto> {
+    state.control.paused.store(true, Ordering::SeqCst);
+    admin_status_action(auth, State(state)).await
+}
+
+/// `POST /admin/resume`
+pub async fn admin_resume_action(
+    auth: AdminAuth,
+    State(state): State<GameAppData>,
+) -> Json<AdminStatusDto> {
+    state.control.paused.store(false, Ordering::SeqCst);
+    admin_status_action(auth, State(state)).await
+}
+
+#[derive(Deserialize)]
+pub struct SpeedRequest {
+    pub days_per_request: u32,
+}
+
+/// `POST /admin/speed` — `{"days_per_request": 7}`
+pub async fn admin_speed_action(
+    auth: AdminAuth,
+    State(state): State<GameAppData>,
+    ApiJson(request): ApiJson<SpeedRequest>,
+) -> ApiResult<Json<AdminStatusDto>> {
+    if !(1..=MAX_DAYS_PER_REQUEST).contains(&request.days_per_request) {
+        return Err(ApiError::invalid(
+            "days_per_request",
+            format!("must be between 1 and {}", MAX_DAYS_PER_REQUEST),
+        ));
+    }
+    state
+        .control
+        .days_per_request
+        .store(request.days_per_request, Ordering::SeqCst);
+    Ok(admin_status_action(auth, State(state)).await)
+}
+
+#[derive(Deserialize)]
+pub struct StepQuery {
+    pub days: Option<u32>,
+}
+
+/// `POST /admin/step?days=N` — advance the world now, paused or not.
+/// Answers 409 while another run is in progress.
+pub async fn admin_step_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    ApiQuery(query): ApiQuery<StepQuery>,
+) -> StatusCode {
+    let days = query.days.unwrap_or(1).clamp(1, MAX_DAYS_PER_REQUEST);
+    start_processing(&state, days, false)
+        .await
+        .unwrap_or(StatusCode::CONFLICT)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_the_configured_token_is_accepted() {
+        let control = SimulationControl::new(Some("s3cret".to_string()));
+        assert!(control.accepts("s3cret"));
+        assert!(!control.accepts("s3cre"));
+        assert!(!control.accepts(""));
+
+        let disabled = SimulationControl::new(Some(String::new()));
+        assert!(!disabled.accepts(""));
+    }
+}
@@ -0,0 +1,13 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::{get, post};
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route("/admin/status", get(super::admin_status_action))
+        .route("/admin/pause", post(super::admin_pause_action))
+        .route("/admin/resume", post(super::admin_resume_action))
+        .route("/admin/speed", post(super::admin_speed_action))
+        .route("/admin/step", post(super::admin_step_action))
+        .route("/admin/load", post(crate::game::game_load_action))
+}
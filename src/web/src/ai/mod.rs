@@ -6,9 +6,9 @@ mod tools;
 
 pub use jobs::AiJobs;
 
-use crate::GameAppData;
+use crate::{ApiJson, ApiQuery, GameAppData};
 use axum::Json;
-use axum::extract::{Query, State};
+use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use serde::{Deserialize, Serialize};
@@ -119,7 +119,7 @@ pub async fn ai_config_get_action(State(state): State<GameAppData>) -> impl Into
 /// need one). Once saved, the home badge flips to ON.
 pub async fn ai_config_save_action(
     State(state): State<GameAppData>,
-    Json(body): Json<SaveAiRequest>,
+    ApiJson(body): ApiJson<SaveAiRequest>,
 ) -> impl IntoResponse {
     let base_url = body.base_url.trim().to_string();
     let model = body.model.trim().to_string();
@@ -168,7 +168,7 @@ pub struct ProgressQuery {
 /// speaks in job ids, so per-page start handlers own their own prompts.
 pub async fn ai_progress_action(
     State(state): State<GameAppData>,
-    Query(query): Query<ProgressQuery>,
+    ApiQuery(query): ApiQuery<ProgressQuery>,
 ) -> impl IntoResponse {
     match state.ai_jobs.wait(query.job_id, query.cursor).await {
         Some(snapshot) => Json(snapshot).into_response(),
@@ -0,0 +1,329 @@
+//! Versioned JSON API under `/api/v1`.
+//!
+//! Serves the same view-models the HTML pages render, built by the
+//! page modules' shared builders, so external frontends and tools read
+//! exactly what the site shows. The country list and player profiles
+//! carry localized labels, which follow the optional `?lang=` query and
+//! fall back to English; the other endpoints have none to translate.
+
+pub mod routes;
+
+use crate::common::slug::{find_player, parse_slug_id};
+use crate::countries::get::{CompetitionGroupDto, build_competition_sections};
+use crate::countries::list::{ContinentDto, build_continents};
+use crate::i18n::DEFAULT_LANGUAGE;
+use crate::leagues::get::{
+    LeagueTableRow, TourSchedule, build_current_tour_schedule, build_table_rows,
+};
+use crate::r#match::get::{
+    GoalEventDisplay, MatchPlayer, build_goal_events, build_squad, find_match,
+};
+use crate::player::get::{PlayerViewModel, build_player_view_model};
+use crate::teams::get::{TeamPlayer, build_team_players};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData, I18n};
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use core::{SimulatorData, Team};
+use serde::{Deserialize, Serialize};
+
+pub fn api_routes() -> axum::Router<GameAppData> {
+    routes::routes()
+}
+
+#[derive(Deserialize, Default)]
+pub struct LangQuery {
+    #[serde(default)]
+    pub lang: Option<String>,
+}
+
+impl LangQuery {
+    fn i18n(&self, state: &GameAppData) -> I18n {
+        state
+            .i18n
+            .for_lang(self.lang.as_deref().unwrap_or(DEFAULT_LANGUAGE))
+    }
+}
+
+#[derive(Serialize)]
+pub struct CountryApiDto {
+    pub slug: String,
+    pub code: String,
+    pub name: String,
+    pub continent: String,
+    pub sections: Vec<CompetitionGroupDto>,
+}
+
+#[derive(Serialize)]
+pub struct LeagueApiDto {
+    pub slug: String,
+    pub name: String,
+    pub country_slug: String,
+    pub is_cup: bool,
+    pub table: Vec<LeagueTableRow>,
+    pub current_tour: Vec<TourSchedule>,
+}
+
+#[derive(Serialize)]
+pub struct TeamApiDto {
+    pub slug: String,
+    pub name: String,
+    pub club_id: u32,
+    pub league_slug: Option<String>,
+    pub players: Vec<TeamPlayer>,
+}
+
+impl TeamApiDto {
+    fn new(simulator_data: &SimulatorData, team: &Team) -> Self {
+        TeamApiDto {
+            slug: team.slug.clone(),
+            name: team.name.clone(),
+            club_id: team.club_id,
+            league_slug: team
+                .league_id
+                .and_then(|id| simulator_data.league(id))
+                .map(|l| l.slug.clone()),
+            players: build_team_players(simulator_data, team),
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct PlayerApiDto {
+    pub slug: String,
+    pub first_name: String,
+    pub last_name: String,
+    pub position: String,
+    pub team_slug: Option<String>,
+    pub profile: PlayerViewModel,
+}
+
+#[derive(Serialize)]
+pub struct MatchApiDto {
+    pub id: String,
+    pub league_slug: String,
+    pub friendly: bool,
+    pub attendance: u32,
+    pub home: MatchSideDto,
+    pub away: MatchSideDto,
+    pub player_of_the_match_id: Option<u32>,
+}
+
+#[derive(Serialize)]
+pub struct MatchSideDto {
+    pub name: String,
+    pub slug: String,
+    pub goals: u8,
+    pub goal_events: Vec<GoalEventDisplay>,
+    pub squad_main: Vec<MatchPlayer>,
+    pub squad_subs: Vec<MatchPlayer>,
+}
+
+pub async fn countries_action(
+    State(state): State<GameAppData>,
+    ApiQuery(query): ApiQuery<LangQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let i18n = query.i18n(&state);
+    let stored = state.saves.stored_countries().await;
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let continents: Vec<ContinentDto> = build_continents(simulator_data, &stored, &i18n);
+    Ok(Json(continents))
+}
+
+pub async fn country_action(
+    State(state): State<GameAppData>,
+    Path(country_slug): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let country = simulator_data
+        .indexes
+        .as_ref()
+        .and_then(|i| i.slug_indexes.get_country_by_slug(&country_slug))
+        .and_then(|id| simulator_data.country(id))
+        .ok_or_else(|| ApiError::NotFound(format!("Country '{}' not found", country_slug)))?;
+
+    Ok(Json(CountryApiDto {
+        slug: country.slug.clone(),
+        code: country.code.clone(),
+        name: country.name.clone(),
+        continent: simulator_data
+            .continent(country.continent_id)
+            .map(|c| c.name.clone())
+            .unwrap_or_default(),
+        sections: build_competition_sections(country),
+    }))
+}
+
+pub async fn league_action(
+    State(state): State<GameAppData>,
+    Path(league_slug): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let league = simulator_data
+        .indexes
+        .as_ref()
+        .and_then(|i| i.slug_indexes.get_league_by_slug(&league_slug))
+        .and_then(|id| simulator_data.league(id))
+        .ok_or_else(|| ApiError::NotFound(format!("League '{}' not found", league_slug)))?;
+
+    Ok(Json(LeagueApiDto {
+        slug: league.slug.clone(),
+        name: league.name.clone(),
+        country_slug: simulator_data
+            .country(league.country_id)
+            .map(|c| c.slug.clone())
+            .unwrap_or_default(),
+        is_cup: league.is_cup,
+        table: build_table_rows(simulator_data, league.table.get()),
+        current_tour: build_current_tour_schedule(simulator_data, league),
+    }))
+}
+
+pub async fn team_action(
+    State(state): State<GameAppData>,
+    Path(team_slug): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let team = simulator_data
+        .indexes
+        .as_ref()
+        .and_then(|i| i.slug_indexes.get_team_by_slug(&team_slug))
+        .and_then(|id| simulator_data.team(id))
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", team_slug)))?;
+
+    Ok(Json(TeamApiDto::new(simulator_data, team)))
+}
+
+/// Accepts the canonical `{id}-{name}` slug or a bare id; unlike the
+/// HTML page, a stale slug is served rather than redirected.
+pub async fn player_action(
+    State(state): State<GameAppData>,
+    Path(player_slug): Path<String>,
+    ApiQuery(query): ApiQuery<LangQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let i18n = query.i18n(&state);
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let (player, team) = parse_slug_id(&player_slug)
+        .and_then(|id| find_player(simulator_data, id))
+        .ok_or_else(|| ApiError::NotFound(format!("Player '{}' not found", player_slug)))?;
+
+    Ok(Json(PlayerApiDto {
+        slug: player.slug(),
+        first_name: player.full_name.display_first_name().to_string(),
+        last_name: player.full_name.display_last_name().to_string(),
+        position: player.position().get_short_name().to_string(),
+        team_slug: team.map(|t| t.slug.clone()),
+        profile: build_player_view_model(simulator_data, &i18n, player, team),
+    }))
+}
+
+pub async fn match_action(
+    State(state): State<GameAppData>,
+    Path(match_id): Path<String>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let match_result = find_match(simulator_data, &match_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Match '{}' not found", match_id)))?;
+    let details = match_result
+        .details
+        .as_ref()
+        .ok_or_else(|| ApiError::NotFound("Match details not available".to_string()))?;
+
+    // International fixtures carry country ids in the team slots.
+    let international = match_result.league_slug == "international";
+    let side = |team_id: u32, home: bool| {
+        let (name, slug) = if international {
+            simulator_data
+                .country(team_id)
+                .map(|c| (c.name.clone(), c.slug.clone()))
+        } else {
+            simulator_data
+                .team(team_id)
+                .map(|t| (t.name.clone(), t.slug.clone()))
+        }
+        .unwrap_or_default();
+        let goals = details
+            .score
+            .as_ref()
+            .map(|s| {
+                if home {
+                    s.home_team.get()
+                } else {
+                    s.away_team.get()
+                }
+            })
+            .unwrap_or(0);
+        let (squad_main, squad_subs) = build_squad(simulator_data, details, home);
+        MatchSideDto {
+            name,
+            slug,
+            goals,
+            goal_events: build_goal_events(simulator_data, details, home),
+            squad_main,
+            squad_subs,
+        }
+    };
+
+    Ok(Json(MatchApiDto {
+        id: match_result.id.clone(),
+        league_slug: match_result.league_slug.clone(),
+        friendly: match_result.friendly,
+        attendance: match_result.attendance,
+        home: side(match_result.home_team_id, true),
+        away: side(match_result.away_team_id, false),
+        player_of_the_match_id: details.player_of_the_match_id,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::fixture::WorldFixture;
+    use chrono::NaiveDate;
+    use core::{PlayerAttributes, PlayerPositionType};
+
+    #[test]
+    fn team_json_carries_the_league_and_the_roster() {
+        let sim = WorldFixture::world(
+            WorldFixture::player(PlayerPositionType::Striker, PlayerAttributes::default()),
+            NaiveDate::from_ymd_opt(2026, 8, 1).unwrap(),
+        );
+        let team = sim.team(10).unwrap();
+
+        let json = serde_json::to_value(TeamApiDto::new(&sim, team)).unwrap();
+
+        assert_eq!(json["slug"], "main");
+        assert_eq!(json["club_id"], 100);
+        assert_eq!(json["league_slug"], "premier");
+        let players = json["players"].as_array().unwrap();
+        assert_eq!(players.len(), 1);
+        assert_eq!(players[0]["id"], 1);
+        assert_eq!(players[0]["country_slug"], "england");
+        // Sort keys stay server-side.
+        assert!(players[0].get("position_sort").is_none());
+    }
+}
@@ -0,0 +1,16 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route("/api/v1/countries", get(super::countries_action))
+        .route(
+            "/api/v1/countries/{country_slug}",
+            get(super::country_action),
+        )
+        .route("/api/v1/leagues/{league_slug}", get(super::league_action))
+        .route("/api/v1/teams/{team_slug}", get(super::team_action))
+        .route("/api/v1/players/{player_slug}", get(super::player_action))
+        .route("/api/v1/matches/{match_id}", get(super::match_action))
+}
@@ -1,9 +1,12 @@
-use crate::i18n::{DEFAULT_LANGUAGE, I18nManager};
-use axum::http::{StatusCode, header};
+use crate::common::http_cache::{is_not_modified, not_modified};
+use crate::i18n::{I18nManager, localized_path, preferred_language};
+use axum::http::{HeaderMap, HeaderValue, StatusCode, header};
 use axum::response::{IntoResponse, Redirect};
 
 use rust_embed::RustEmbed;
-use std::sync::LazyLock;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{LazyLock, OnceLock};
+use std::time::UNIX_EPOCH;
 use sysinfo::{CpuRefreshKind, RefreshKind, System};
 
 // Include the CSS hash to force recompilation when CSS files change
@@ -40,9 +43,37 @@ pub static CPU_BRAND: LazyLock<String> = LazyLock::new(|| {
 #[folder = "assets/"]
 pub struct Assets;
 
-fn cache_control_for(path: &str) -> &'static str {
+const IMMUTABLE: &str = "public, max-age=31536000, immutable";
+
+/// Directory whose files are served in place of the embedded assets,
+/// set once at startup from the `assets_dir` setting.
+static ASSET_OVERRIDES: OnceLock<PathBuf> = OnceLock::new();
+
+pub fn set_asset_overrides(directory: PathBuf) {
+    let _ = ASSET_OVERRIDES.set(directory);
+}
+
+/// `path` under `root` when such a file exists. Only plain relative paths
+/// qualify, so a request can't climb out of the directory.
+fn override_file(root: &Path, path: &str) -> Option<PathBuf> {
+    let relative = Path::new(path);
+    let plain = relative
+        .components()
+        .all(|c| matches!(c, Component::Normal(_)));
+    if path.is_empty() || !plain {
+        return None;
+    }
+    let file = root.join(relative);
+    file.is_file().then_some(file)
+}
+
+fn cache_control_for(path: &str, query: Option<&str>) -> &'static str {
+    // `?v=` carries the build's CSS hash, so a versioned URL never changes
+    if query.is_some_and(|q| q == format!("v={}", CSS_VERSION)) {
+        return IMMUTABLE;
+    }
     match path.rsplit('.').next() {
-        Some("woff2" | "woff" | "ttf" | "otf") => "public, max-age=31536000, immutable",
+        Some("woff2" | "woff" | "ttf" | "otf") => IMMUTABLE,
         Some("png" | "jpg" | "jpeg" | "gif" | "webp" | "svg" | "ico") => "public, max-age=86400",
         Some("css" | "js") => "public, max-age=3600",
         _ => "public, max-age=3600",
@@ -50,33 +81,62 @@ fn cache_control_for(path: &str) -> &'static str {
 }
 
 /// Serves static files from the embedded assets, or redirects lang-less page routes
-pub async fn default_handler(uri: axum::http::Uri) -> axum::response::Response {
+pub async fn default_handler(
+    uri: axum::http::Uri,
+    request_headers: HeaderMap,
+) -> axum::response::Response {
     let path_str = uri.path().trim_start_matches('/');
 
-    // Try serving as static asset first
+    // An on-disk override wins over the embedded copy. It is served
+    // uncached, so edits show up on the next reload.
+    if let Some(file) = ASSET_OVERRIDES
+        .get()
+        .and_then(|root| override_file(root, path_str))
+    {
+        let metadata = tokio::fs::metadata(&file).await;
+        if let (Ok(metadata), Ok(data)) = (metadata, tokio::fs::read(&file).await) {
+            let modified = metadata
+                .modified()
+                .ok()
+                .and_then(|m| m.duration_since(UNIX_EPOCH).ok())
+                .map(|d| d.as_secs())
+                .unwrap_or_default();
+            let etag = HeaderValue::from_str(&format!("W/\"{:x}-{:x}\"", metadata.len(), modified))
+                .expect("hex etag is a valid header value");
+            let cache_control = HeaderValue::from_static("no-cache");
+            return asset_response(path_str, &request_headers, etag, cache_control, data);
+        }
+    }
+
+    // Then the assets embedded in the binary
     if let Some(content) = Assets::get(path_str) {
-        let mime = mime_guess::from_path(path_str).first_or_octet_stream();
-        return (
-            StatusCode::OK,
-            [
-                (header::CONTENT_TYPE, mime.to_string()),
-                (
-                    header::CACHE_CONTROL,
-                    cache_control_for(path_str).to_string(),
-                ),
-            ],
+        let hash = content.metadata.sha256_hash();
+        let etag = HeaderValue::from_str(&format!(
+            "W/\"{}\"",
+            hash[..8]
+                .iter()
+                .map(|b| format!("{:02x}", b))
+                .collect::<String>()
+        ))
+        .expect("hex etag is a valid header value");
+        let cache_control = HeaderValue::from_static(cache_control_for(path_str, uri.query()));
+        return asset_response(
+            path_str,
+            &request_headers,
+            etag,
+            cache_control,
             content.data,
-        )
-            .into_response();
+        );
     }
 
-    // Check if path is missing a language prefix — redirect to default language
+    // Check if path is missing a language prefix — redirect to the preferred
+    // language. Temporary, since the target depends on the visitor.
     let first_segment = path_str.split('/').next().unwrap_or("");
     let has_lang_prefix = I18nManager::is_supported_language(first_segment);
 
     if !has_lang_prefix && !path_str.is_empty() {
-        let redirect_url = format!("/{}/{}", DEFAULT_LANGUAGE, path_str);
-        return Redirect::permanent(&redirect_url).into_response();
+        let redirect_url = localized_path(uri.path(), &preferred_language(&request_headers));
+        return Redirect::temporary(&redirect_url).into_response();
     }
 
     (
@@ -89,3 +149,57 @@ pub async fn default_handler(uri: axum::http::Uri) -> axum::response::Response {
     )
         .into_response()
 }
+
+fn asset_response(
+    path: &str,
+    request_headers: &HeaderMap,
+    etag: HeaderValue,
+    cache_control: HeaderValue,
+    body: impl IntoResponse,
+) -> axum::response::Response {
+    if is_not_modified(request_headers, &etag) {
+        return not_modified(etag, cache_control);
+    }
+
+    let mime = mime_guess::from_path(path).first_or_octet_stream();
+    (
+        StatusCode::OK,
+        [
+            (
+                header::CONTENT_TYPE,
+                HeaderValue::from_str(mime.as_ref())
+                    .unwrap_or(HeaderValue::from_static("application/octet-stream")),
+            ),
+            (header::CACHE_CONTROL, cache_control),
+            (header::ETAG, etag),
+        ],
+        body,
+    )
+        .into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn overrides_resolve_only_plain_relative_paths() {
+        let root =
+            std::env::temp_dir().join(format!("open-football-assets-{}", std::process::id()));
+        fs::create_dir_all(root.join("static/css")).unwrap();
+        fs::write(root.join("static/css/styles.min.css"), "body{}").unwrap();
+
+        assert_eq!(
+            override_file(&root, "static/css/styles.min.css"),
+            Some(root.join("static/css/styles.min.css"))
+        );
+        assert_eq!(override_file(&root, "static/css/missing.css"), None);
+        assert_eq!(override_file(&root, "static/css"), None);
+        assert_eq!(override_file(&root, "static/../../etc/passwd"), None);
+        assert_eq!(override_file(&root, "/etc/passwd"), None);
+        assert_eq!(override_file(&root, ""), None);
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,111 @@
+//! World fixture shared by the view, API and action tests.
+
+use chrono::{NaiveDate, NaiveTime};
+use core::club::ClubAcademy;
+use core::club::player::builder::PlayerBuilder;
+use core::competitions::global::GlobalCompetitions;
+use core::continent::Continent;
+use core::league::{DayMonthPeriod, League, LeagueCollection, LeagueSettings};
+use core::shared::Location;
+use core::shared::fullname::FullName;
+use core::{
+    Club, ClubColors, ClubFacilities, ClubFinances, ClubStatus, Country, PersonAttributes, Player,
+    PlayerAttributes, PlayerCollection, PlayerPosition, PlayerPositionType, PlayerPositions,
+    PlayerSkills, SimulatorData, StaffCollection, TeamBuilder, TeamCollection, TeamReputation,
+    TeamType, TrainingSchedule,
+};
+
+/// One country (id 1, `england`) with one league (id 1, `premier`) and
+/// one club (id 100) whose Main team (id 10, `main`) rosters a single
+/// player. Wrapped in a unit struct per the project's no-free-helpers
+/// convention.
+pub struct WorldFixture;
+
+impl WorldFixture {
+    /// Player 1, born 1996, playing `position` with the given attributes
+    /// and no contract.
+    pub fn player(position: PlayerPositionType, player_attributes: PlayerAttributes) -> Player {
+        PlayerBuilder::new()
+            .id(1)
+            .full_name(FullName::new("Test".to_string(), "Player".to_string()))
+            .birth_date(NaiveDate::from_ymd_opt(1996, 1, 1).unwrap())
+            .country_id(1)
+            .attributes(PersonAttributes::default())
+            .skills(PlayerSkills::default())
+            .positions(PlayerPositions {
+                positions: vec![PlayerPosition {
+                    position,
+                    level: 20,
+                }],
+            })
+            .player_attributes(player_attributes)
+            .build()
+            .unwrap()
+    }
+
+    /// The fixture world at noon on `date`, with `player` on the Main team.
+    pub fn world(player: Player, date: NaiveDate) -> SimulatorData {
+        let team = TeamBuilder::new()
+            .id(10)
+            .league_id(Some(1))
+            .club_id(100)
+            .name("Main".to_string())
+            .slug("main".to_string())
+            .team_type(TeamType::Main)
+            .players(PlayerCollection::new(vec![player]))
+            .staffs(StaffCollection::new(Vec::new()))
+            .reputation(TeamReputation::new(500, 500, 4_000))
+            .training_schedule(TrainingSchedule::new(
+                NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+                NaiveTime::from_hms_opt(15, 0, 0).unwrap(),
+            ))
+            .build()
+            .unwrap();
+        let club = Club::new(
+            100,
+            "Club".to_string(),
+            Location::new(1),
+            ClubFinances::new(1_000_000, Vec::new()),
+            ClubAcademy::new(3),
+            ClubStatus::Professional,
+            ClubColors::default(),
+            TeamCollection::new(vec![team]),
+            ClubFacilities::default(),
+        );
+        let league = League::new(
+            1,
+            "Premier".to_string(),
+            "premier".to_string(),
+            1,
+            500,
+            LeagueSettings {
+                season_starting_half: DayMonthPeriod::new(1, 8, 31, 12),
+                season_ending_half: DayMonthPeriod::new(1, 1, 31, 5),
+                tier: 1,
+                promotion_spots: 0,
+                relegation_spots: 0,
+                league_group: None,
+                split_season: false,
+                promotion_playoff_spots: 0,
+                relegation_league_id: None,
+            },
+            false,
+        );
+        let country = Country::builder()
+            .id(1)
+            .code("EN".to_string())
+            .slug("england".to_string())
+            .name("England".to_string())
+            .continent_id(1)
+            .leagues(LeagueCollection::new(vec![league]))
+            .clubs(vec![club])
+            .build()
+            .unwrap();
+        let continent = Continent::new(1, "Europe".to_string(), vec![country], Vec::new());
+        SimulatorData::new(
+            date.and_hms_opt(12, 0, 0).unwrap(),
+            vec![continent],
+            GlobalCompetitions::new(Vec::new()),
+        )
+    }
+}
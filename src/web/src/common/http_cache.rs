@@ -0,0 +1,152 @@
+use axum::body::{Body, to_bytes};
+use axum::extract::Request;
+use axum::http::{HeaderMap, HeaderValue, Method, StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+/// Pages reflect the current game date, so browsers keep them but must
+/// revalidate on every visit; an unchanged page then costs a bare 304.
+const VIEW_CACHE_CONTROL: &str = "private, no-cache";
+
+/// Weak validator for a response body. Weak because the compression layer
+/// may re-encode the same content.
+pub fn etag_for(bytes: &[u8]) -> HeaderValue {
+    let mut hasher = DefaultHasher::new();
+    bytes.hash(&mut hasher);
+    HeaderValue::from_str(&format!("W/\"{:016x}\"", hasher.finish()))
+        .expect("hex etag is a valid header value")
+}
+
+/// True when the request's `If-None-Match` lists `etag` (or `*`).
+pub fn is_not_modified(request_headers: &HeaderMap, etag: &HeaderValue) -> bool {
+    let Some(etag) = etag.to_str().ok().map(opaque_tag) else {
+        return false;
+    };
+    request_headers
+        .get_all(header::IF_NONE_MATCH)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .flat_map(|value| value.split(','))
+        .map(str::trim)
+        .any(|candidate| candidate == "*" || opaque_tag(candidate) == etag)
+}
+
+/// `If-None-Match` uses the weak comparison, so `W/"x"` matches `"x"`.
+fn opaque_tag(tag: &str) -> &str {
+    tag.strip_prefix("W/").unwrap_or(tag)
+}
+
+/// Empty 304 carrying the validator and caching headers of the full
+/// response it stands in for.
+pub fn not_modified(etag: HeaderValue, cache_control: HeaderValue) -> Response {
+    (
+        StatusCode::NOT_MODIFIED,
+        [(header::ETAG, etag), (header::CACHE_CONTROL, cache_control)],
+    )
+        .into_response()
+}
+
+/// Middleware adding an `ETag` and `Cache-Control` to rendered HTML views
+/// and answering matching conditional requests with 304.
+pub async fn cache_views(request: Request, next: Next) -> Response {
+    if request.method() != Method::GET {
+        return next.run(request).await;
+    }
+    let request_headers = request.headers().clone();
+
+    let response = next.run(request).await;
+    let is_html = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .is_some_and(|value| value.starts_with("text/html"));
+    if response.status() != StatusCode::OK
+        || !is_html
+        || response.headers().contains_key(header::ETAG)
+    {
+        return response;
+    }
+
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = to_bytes(body, usize::MAX).await else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+
+    let etag = etag_for(&bytes);
+    let cache_control = HeaderValue::from_static(VIEW_CACHE_CONTROL);
+    if is_not_modified(&request_headers, &etag) {
+        return not_modified(etag, cache_control);
+    }
+
+    parts.headers.insert(header::ETAG, etag);
+    parts.headers.insert(header::CACHE_CONTROL, cache_control);
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::Router;
+    use axum::response::Html;
+    use axum::routing::get;
+    use tower::ServiceExt;
+
+    fn app() -> Router {
+        Router::new()
+            .route("/page", get(|| async { Html("<p>league table</p>") }))
+            .route("/api", get(|| async { "plain" }))
+            .layer(axum::middleware::from_fn(cache_views))
+    }
+
+    fn get_with(uri: &str, if_none_match: Option<&HeaderValue>) -> Response {
+        let mut request = Request::get(uri);
+        if let Some(tag) = if_none_match {
+            request = request.header(header::IF_NONE_MATCH, tag);
+        }
+        tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap()
+            .block_on(app().oneshot(request.body(Body::empty()).unwrap()))
+            .unwrap()
+    }
+
+    #[test]
+    fn views_revalidate_with_etag() {
+        let first = get_with("/page", None);
+        assert_eq!(first.status(), StatusCode::OK);
+        assert_eq!(first.headers()[header::CACHE_CONTROL], VIEW_CACHE_CONTROL);
+        let etag = first.headers()[header::ETAG].clone();
+
+        let second = get_with("/page", Some(&etag));
+        assert_eq!(second.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(second.headers()[header::ETAG], etag);
+
+        let stale = HeaderValue::from_static("W/\"0\"");
+        assert_eq!(get_with("/page", Some(&stale)).status(), StatusCode::OK);
+    }
+
+    #[test]
+    fn non_html_responses_are_left_alone() {
+        let response = get_with("/api", None);
+        assert!(!response.headers().contains_key(header::ETAG));
+    }
+
+    #[test]
+    fn weak_and_strong_tags_compare_equal() {
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            header::IF_NONE_MATCH,
+            HeaderValue::from_static("\"a\", \"b\""),
+        );
+        assert!(is_not_modified(
+            &headers,
+            &HeaderValue::from_static("W/\"b\"")
+        ));
+        assert!(!is_not_modified(
+            &headers,
+            &HeaderValue::from_static("W/\"c\"")
+        ));
+    }
+}
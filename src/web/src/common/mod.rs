@@ -1,5 +1,9 @@
 pub mod default_handler;
+#[cfg(test)]
+pub mod fixture;
 pub mod friendly_source;
+pub mod http_cache;
 pub mod potential_stars;
+pub mod rate_limit;
 pub mod routes;
 pub mod slug;
@@ -2,12 +2,13 @@ use chrono::NaiveDate;
 use core::Player;
 use core::Staff;
 use core::club::staff::perception::{AbilityEstimator, EstimationContext, PotentialEstimator};
+use serde::Serialize;
 
 /// Star rating on a half-star scale — 0..=10 halves render as 0..=5 stars.
 /// Precomputed into full/half/empty segment counts so templates stay a
 /// dumb loop with no arithmetic. Field order carries the derived `Ord`:
 /// more full stars ranks higher, a half star breaks the tie.
-#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct StarRating {
     pub full: u8,
     pub half: bool,
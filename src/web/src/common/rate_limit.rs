@@ -0,0 +1,152 @@
+use axum::extract::{ConnectInfo, Request, State};
+use axum::http::{StatusCode, header};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::sync::{Arc, Mutex};
+use std::time::Instant;
+
+/// Paths never counted against the limit: long-lived streams (processing
+/// progress, match replay chunks fetched back-to-back while a match
+/// plays), the static assets every page pulls in, and orchestrator probes.
+const EXEMPT_PREFIXES: &[&str] = &[
+    "/static/",
+    "/api/game/progress",
+    "/api/match/",
+    "/healthz",
+    "/readyz",
+];
+
+/// Past this many tracked clients, refilled buckets are dropped.
+const PRUNE_THRESHOLD: usize = 10_000;
+
+/// Request limits applied to every route of the web server.
+#[derive(Clone, Copy)]
+pub struct RequestLimits {
+    /// Requests one client IP may make per minute, bursting up to the same
+    /// amount. Zero disables rate limiting.
+    pub rate_limit_per_minute: u32,
+    /// Largest request body accepted by the JSON and form extractors.
+    pub max_body_bytes: usize,
+}
+
+struct Bucket {
+    tokens: f64,
+    updated: Instant,
+}
+
+/// Per-IP token bucket. Cheap to clone; clones share the buckets.
+#[derive(Clone)]
+pub struct RateLimiter {
+    per_minute: u32,
+    buckets: Arc<Mutex<HashMap<IpAddr, Bucket>>>,
+}
+
+impl RateLimiter {
+    pub fn new(per_minute: u32) -> Self {
+        RateLimiter {
+            per_minute,
+            buckets: Arc::new(Mutex::new(HashMap::new())),
+        }
+    }
+
+    fn capacity(&self) -> f64 {
+        self.per_minute as f64
+    }
+
+    fn refill_per_sec(&self) -> f64 {
+        self.per_minute as f64 / 60.0
+    }
+
+    /// Take one token for `ip`. `Err` carries the seconds until the next
+    /// token is available.
+    fn acquire(&self, ip: IpAddr, now: Instant) -> Result<(), u64> {
+        let Ok(mut buckets) = self.buckets.lock() else {
+            return Ok(());
+        };
+
+        if buckets.len() > PRUNE_THRESHOLD {
+            let (capacity, refill) = (self.capacity(), self.refill_per_sec());
+            buckets.retain(|_, b| {
+                b.tokens + now.duration_since(b.updated).as_secs_f64() * refill < capacity
+            });
+        }
+
+        let bucket = buckets.entry(ip).or_insert(Bucket {
+            tokens: self.capacity(),
+            updated: now,
+        });
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.refill_per_sec()).min(self.capacity());
+        bucket.updated = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            let wait = (1.0 - bucket.tokens) / self.refill_per_sec();
+            Err(wait.ceil() as u64)
+        }
+    }
+}
+
+/// Middleware answering 429 with `Retry-After` once a client IP runs out
+/// of tokens. Requests without connection info (e.g. in-process tests)
+/// are not limited.
+pub async fn rate_limit(
+    State(limiter): State<RateLimiter>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let path = request.uri().path();
+    if limiter.per_minute == 0 || EXEMPT_PREFIXES.iter().any(|p| path.starts_with(p)) {
+        return next.run(request).await;
+    }
+    let Some(ConnectInfo(addr)) = request.extensions().get::<ConnectInfo<SocketAddr>>() else {
+        return next.run(request).await;
+    };
+
+    match limiter.acquire(addr.ip(), Instant::now()) {
+        Ok(()) => next.run(request).await,
+        Err(retry_after) => (
+            StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, retry_after.to_string())],
+            "Too many requests",
+        )
+            .into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+    use std::time::Duration;
+
+    #[test]
+    fn bucket_allows_a_burst_then_refills() {
+        let limiter = RateLimiter::new(60);
+        let client = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 1));
+        let other = IpAddr::V4(Ipv4Addr::new(10, 0, 0, 2));
+        let start = Instant::now();
+
+        for _ in 0..60 {
+            assert!(limiter.acquire(client, start).is_ok());
+        }
+        assert_eq!(limiter.acquire(client, start), Err(1));
+        assert!(limiter.acquire(other, start).is_ok());
+
+        // One token per second at 60 a minute
+        assert!(
+            limiter
+                .acquire(client, start + Duration::from_secs(1))
+                .is_ok()
+        );
+        assert!(
+            limiter
+                .acquire(client, start + Duration::from_secs(1))
+                .is_err()
+        );
+    }
+}
@@ -59,18 +59,8 @@ pub fn resolve_player_page<'a>(
     let player_id = parse_slug_id(slug)
         .ok_or_else(|| ApiError::NotFound(format!("Player slug {} is malformed", slug)))?;
 
-    let (player, team) = if let Some((p, t)) = data.player_with_team(player_id) {
-        (p, Some(t))
-    } else if let Some(p) = data.free_agents.iter().find(|p| p.id == player_id) {
-        (p, None)
-    } else if let Some(p) = data.retired_player(player_id) {
-        (p, None)
-    } else {
-        return Err(ApiError::NotFound(format!(
-            "Player with ID {} not found",
-            player_id
-        )));
-    };
+    let (player, team) = find_player(data, player_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Player with ID {} not found", player_id)))?;
 
     let canonical_slug = player.slug();
     if slug != canonical_slug {
@@ -87,6 +77,18 @@ pub fn resolve_player_page<'a>(
     })
 }
 
+/// Any player by id: rostered players with their team, then free agents
+/// and retired players (no team).
+pub fn find_player(data: &SimulatorData, player_id: u32) -> Option<(&Player, Option<&Team>)> {
+    if let Some((p, t)) = data.player_with_team(player_id) {
+        Some((p, Some(t)))
+    } else if let Some(p) = data.free_agents.iter().find(|p| p.id == player_id) {
+        Some((p, None))
+    } else {
+        data.retired_player(player_id).map(|p| (p, None))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -7,7 +7,7 @@ use askama::Template;
 use axum::extract::{Path, State};
 use axum::response::IntoResponse;
 use core::Country;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize)]
 pub struct CountryGetRequest {
@@ -41,6 +41,7 @@ pub struct CountryGetTemplate {
     pub sections: Vec<CompetitionGroupDto>,
 }
 
+#[derive(Serialize)]
 pub struct LeagueDto {
     pub slug: String,
     pub name: String,
@@ -50,12 +51,14 @@ pub struct LeagueDto {
 /// zone/conference leagues + playoff links such as Torneo
 /// Apertura/Clausura or MLS Cup Playoffs), or — with an empty name —
 /// a run of ungrouped divisions.
+#[derive(Serialize)]
 pub struct CompetitionGroupDto {
     pub name: String,
     pub leagues: Vec<LeagueDto>,
     pub playoffs: Vec<PlayoffLinkDto>,
 }
 
+#[derive(Serialize)]
 pub struct PlayoffLinkDto {
     pub slug: String,
     pub name: String,
@@ -105,53 +108,7 @@ pub async fn country_get_action(
             ))
         })?;
 
-    // Divisions render in tier order top to bottom: a grouped competition
-    // (Primera Division zones, MLS conferences) becomes a headed section
-    // at its tier's position, consecutive ungrouped divisions share an
-    // unnamed one.
-    let mut ordered: Vec<_> = country
-        .leagues
-        .leagues
-        .iter()
-        .filter(|l| !l.friendly)
-        .collect();
-    ordered.sort_by_key(|l| l.settings.tier);
-    let mut sections: Vec<CompetitionGroupDto> = Vec::new();
-    for l in ordered {
-        let dto = LeagueDto {
-            slug: l.slug.clone(),
-            name: l.name.clone(),
-        };
-        match &l.settings.league_group {
-            Some(group) => match sections.iter_mut().find(|s| s.name == group.competition) {
-                Some(section) => section.leagues.push(dto),
-                None => sections.push(CompetitionGroupDto {
-                    name: group.competition.clone(),
-                    leagues: vec![dto],
-                    playoffs: Vec::new(),
-                }),
-            },
-            None => match sections.last_mut().filter(|s| s.name.is_empty()) {
-                Some(section) => section.leagues.push(dto),
-                None => sections.push(CompetitionGroupDto {
-                    name: String::new(),
-                    leagues: vec![dto],
-                    playoffs: Vec::new(),
-                }),
-            },
-        }
-    }
-    for section in sections.iter_mut().filter(|s| !s.name.is_empty()) {
-        section.playoffs = country
-            .playoffs
-            .iter()
-            .filter(|p| p.competition == section.name)
-            .map(|p| PlayoffLinkDto {
-                slug: p.league.slug.clone(),
-                name: p.league.name.clone(),
-            })
-            .collect();
-    }
+    let sections = build_competition_sections(country);
 
     let current_path = format!(
         "/{}/countries/{}/leagues",
@@ -209,3 +166,56 @@ pub async fn country_get_action(
         sections,
     })
 }
+
+/// League sections of `country` in tier order, with the playoff links of
+/// each grouped competition. Shared by the country page and the JSON API.
+pub fn build_competition_sections(country: &Country) -> Vec<CompetitionGroupDto> {
+    // Divisions render in tier order top to bottom: a grouped competition
+    // (Primera Division zones, MLS conferences) becomes a headed section
+    // at its tier's position, consecutive ungrouped divisions share an
+    // unnamed one.
+    let mut ordered: Vec<_> = country
+        .leagues
+        .leagues
+        .iter()
+        .filter(|l| !l.friendly)
+        .collect();
+    ordered.sort_by_key(|l| l.settings.tier);
+    let mut sections: Vec<CompetitionGroupDto> = Vec::new();
+    for l in ordered {
+        let dto = LeagueDto {
+            slug: l.slug.clone(),
+            name: l.name.clone(),
+        };
+        match &l.settings.league_group {
+            Some(group) => match sections.iter_mut().find(|s| s.name == group.competition) {
+                Some(section) => section.leagues.push(dto),
+                None => sections.push(CompetitionGroupDto {
+                    name: group.competition.clone(),
+                    leagues: vec![dto],
+                    playoffs: Vec::new(),
+                }),
+            },
+            None => match sections.last_mut().filter(|s| s.name.is_empty()) {
+                Some(section) => section.leagues.push(dto),
+                None => sections.push(CompetitionGroupDto {
+                    name: String::new(),
+                    leagues: vec![dto],
+                    playoffs: Vec::new(),
+                }),
+            },
+        }
+    }
+    for section in sections.iter_mut().filter(|s| !s.name.is_empty()) {
+        section.playoffs = country
+            .playoffs
+            .iter()
+            .filter(|p| p.competition == section.name)
+            .map(|p| PlayoffLinkDto {
+                slug: p.league.slug.clone(),
+                name: p.league.name.clone(),
+            })
+            .collect();
+    }
+    sections
+}
@@ -1,6 +1,7 @@
 pub mod routes;
 
 use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
+use crate::game::StoredCountry;
 use crate::views::MenuSection;
 use crate::worker::WorkerStatus;
 use crate::{ApiError, ApiResult, GameAppData, I18n, LlmSettings};
@@ -9,7 +10,8 @@ use axum::extract::{Path, State};
 use axum::http::HeaderMap;
 use axum::http::header::HOST;
 use axum::response::IntoResponse;
-use serde::Deserialize;
+use core::SimulatorData;
+use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize)]
 pub struct CountryListRequest {
@@ -57,6 +59,7 @@ pub struct CountryListTemplate {
     pub ai_api_key: String,
 }
 
+#[derive(Serialize)]
 pub struct ContinentDto {
     pub name: String,
     pub countries: Vec<CountryDto>,
@@ -84,6 +87,7 @@ fn continent_i18n_key(name: &str) -> String {
     out.trim_end_matches('_').to_string()
 }
 
+#[derive(Serialize)]
 pub struct CountryDto {
     pub slug: String,
     pub code: String,
@@ -96,44 +100,24 @@ pub async fn country_list_action(
     Path(route_params): Path<CountryListRequest>,
 ) -> ApiResult<impl IntoResponse> {
     let i18n = state.i18n.for_lang(&route_params.lang);
+    let stored = state.saves.stored_countries().await;
     let guard = state.data.read().await;
 
     let simulator_data = guard
         .as_ref()
         .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
 
-    let continents: Vec<ContinentDto> = simulator_data
-        .continents
-        .iter()
-        .map(|continent| {
-            let mut countries: Vec<CountryDto> = continent
-                .countries
-                .iter()
-                .filter(|c| !c.leagues.leagues.is_empty())
-                .map(|country| CountryDto {
-                    slug: country.slug.clone(),
-                    code: country.code.clone(),
-                    name: country.name.clone(),
-                })
-                .collect();
-            countries.sort_by(|a, b| a.slug.cmp(&b.slug));
-            let key = continent_i18n_key(&continent.name);
-            let translated = i18n.t(&key);
-            let localized = if translated == key {
-                continent.name.clone()
-            } else {
-                translated.to_string()
-            };
-            ContinentDto {
-                name: localized,
-                countries,
-            }
-        })
-        .collect();
+    let continents = build_continents(simulator_data, &stored, &i18n);
 
     let total_countries = continents.iter().map(|c| c.countries.len()).sum();
     let mut total_clubs = 0usize;
     let mut total_players = 0usize;
+    for country in &stored {
+        if simulator_data.country(country.country_id).is_none() {
+            total_clubs += country.clubs as usize;
+            total_players += country.players as usize;
+        }
+    }
     for continent in &simulator_data.continents {
         for country in &continent.countries {
             total_clubs += country.clubs.len();
@@ -192,3 +176,56 @@ pub async fn country_list_action(
         ai_api_key: ai_settings.api_key,
     })
 }
+
+/// Continents with their playable countries (those running at least one
+/// league), countries sorted by slug and continent names localized.
+/// Shared by the country list page and the JSON API.
+/// Countries with leagues by continent. `stored` countries, still on disk
+/// after a load, are listed from `country_info` unless read back since.
+pub fn build_continents(
+    simulator_data: &SimulatorData,
+    stored: &[StoredCountry],
+    i18n: &I18n,
+) -> Vec<ContinentDto> {
+    simulator_data
+        .continents
+        .iter()
+        .map(|continent| {
+            let mut countries: Vec<CountryDto> = continent
+                .countries
+                .iter()
+                .filter(|c| !c.leagues.leagues.is_empty())
+                .map(|country| CountryDto {
+                    slug: country.slug.clone(),
+                    code: country.code.clone(),
+                    name: country.name.clone(),
+                })
+                .collect();
+            countries.extend(
+                stored
+                    .iter()
+                    .filter(|s| s.leagues > 0)
+                    .filter(|s| !continent.countries.iter().any(|c| c.id == s.country_id))
+                    .filter_map(|s| simulator_data.country_info.get(&s.country_id))
+                    .filter(|info| info.continent_id == continent.id)
+                    .map(|info| CountryDto {
+                        slug: info.slug.clone(),
+                        code: info.code.clone(),
+                        name: info.name.clone(),
+                    }),
+            );
+            countries.sort_by(|a, b| a.slug.cmp(&b.slug));
+            let key = continent_i18n_key(&continent.name);
+            let translated = i18n.t(&key);
+            let localized = if translated == key {
+                continent.name.clone()
+            } else {
+                translated.to_string()
+            };
+            ContinentDto {
+                name: localized,
+                countries,
+            }
+        })
+        .collect()
+}
@@ -334,3 +334,81 @@ fn build_squad_dtos(
 fn get_conditions(player: &core::Player) -> u8 {
     (100f32 * ((player.player_attributes.condition as f32) / 10000.0)) as u8
 }
+
+#[cfg(all(test, feature = "sqlite"))]
+mod tests {
+    use super::*;
+    use crate::SaveStore;
+    use crate::common::fixture::WorldFixture;
+    use crate::game::World;
+    use chrono::NaiveDate;
+    use core::competitions::GlobalCompetitions;
+    use core::league::LeagueCollection;
+    use core::{NationalSquadPlayer, PlayerAttributes, SimulatorData};
+    use tokio::sync::RwLock;
+
+    #[test]
+    fn squad_of_a_sqlite_slot_keeps_players_based_abroad() {
+        let date = NaiveDate::from_ymd_opt(2026, 10, 1).unwrap();
+        let player = WorldFixture::player(PlayerPositionType::Striker, PlayerAttributes::default());
+        let mut data = WorldFixture::world(player, date);
+        // Player 1 plays for club 100 in England but is called up by Scotland.
+        let mut scotland = Country::builder()
+            .id(2)
+            .code("SC".to_string())
+            .slug("scotland".to_string())
+            .name("Scotland".to_string())
+            .continent_id(1)
+            .leagues(LeagueCollection::new(Vec::new()))
+            .clubs(Vec::new())
+            .build()
+            .unwrap();
+        scotland.national_team.squad.push(NationalSquadPlayer {
+            player_id: 1,
+            club_id: 100,
+            team_id: 10,
+            primary_reason: CallUpReason::KeyPlayer,
+            secondary_reasons: Vec::new(),
+        });
+        data.continents[0].countries.push(scotland);
+        let data = SimulatorData::new(
+            data.date,
+            std::mem::take(&mut data.continents),
+            GlobalCompetitions::new(Vec::new()),
+        );
+
+        let path =
+            std::env::temp_dir().join(format!("open-football-squad-{}.sqlite", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let store = SaveStore::sqlite(&path);
+        store.save("career", &data).unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let world: World = RwLock::new(None);
+        runtime.block_on(async {
+            store.open("career", &world).await.unwrap();
+            store
+                .hydrate_route(
+                    &world,
+                    Some("/{lang}/countries/{country_slug}"),
+                    "/en/countries/scotland",
+                )
+                .await
+                .unwrap();
+        });
+
+        let guard = world.blocking_read();
+        let sim = guard.as_ref().unwrap();
+        let scotland = sim.country(2).unwrap();
+        let squad = build_squad_dtos(sim, &scotland.national_team, date);
+        let picked: Vec<(&str, &str)> = squad
+            .iter()
+            .map(|p| (p.last_name.as_str(), p.club_name.as_str()))
+            .collect();
+        assert!(picked.contains(&("Player", "Club")), "{picked:?}");
+
+        let _ = std::fs::remove_file(&path);
+    }
+}
@@ -59,7 +59,7 @@ pub struct CupHistoryRow {
 /// campaigns (the end month falls on or before the start month) wrap into
 /// the next year and render as `2025/26`; calendar-year competitions show
 /// the single year.
-fn season_label(settings: &LeagueSettings, start_year: i32) -> String {
+pub(crate) fn season_label(settings: &LeagueSettings, start_year: i32) -> String {
     let wraps = settings.season_ending_half.to_month <= settings.season_starting_half.from_month;
     if wraps {
         format!("{}/{:02}", start_year, (start_year + 1).rem_euclid(100))
@@ -145,7 +145,7 @@ pub async fn cup_history_action(
     let editions = rows.len();
     let distinct_winners = winners.len();
 
-    let title = views::league_display_name(&league, &i18n, simulator_data);
+    let title = views::league_display_name(league, &i18n, simulator_data);
     let current_path = format!("/{}/cups/{}", &route_params.lang, &league.slug);
     let country_leagues: Vec<(&str, &str)> = country
         .leagues
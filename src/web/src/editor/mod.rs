@@ -0,0 +1,290 @@
+//! Pre-game database editor under `/api/editor`: create, change and
+//! delete clubs, players and named staff before a world is generated.
+//! Edits are kept in a `database::DatabaseOverlay` saved to the
+//! `database_overlay` file after every change; the base database is never
+//! touched. They shape the world generated at the next start, or at once
+//! through `POST /api/editor/generate`, which replaces the running game.
+//!
+//! Like `/admin`, every endpoint needs `Authorization: Bearer
+//! <admin_token>` and the group answers 404 when no token is configured.
+//!
+//! Clubs and players are edited with JSON merge patches: send only the
+//! fields to change, `null` to clear one, or a whole record for an id
+//! that doesn't exist yet.
+
+pub mod routes;
+
+use crate::admin::AdminAuth;
+use crate::{ApiError, ApiJson, ApiResult, FieldError, GameAppData};
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use database::{DatabaseEntity, DatabaseGenerator, DatabaseOverlay, Severity};
+use log::info;
+use serde::Serialize;
+use serde_json::Value;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use tokio::task::spawn_blocking;
+
+pub fn editor_routes() -> axum::Router<GameAppData> {
+    routes::routes()
+}
+
+/// The edits and the file they are kept in. Cheap to clone; clones share
+/// the same overlay.
+#[derive(Clone)]
+pub struct DatabaseEditor {
+    overlay: Arc<Mutex<DatabaseOverlay>>,
+    path: Arc<PathBuf>,
+}
+
+impl DatabaseEditor {
+    pub fn new(overlay: DatabaseOverlay, path: PathBuf) -> Self {
+        DatabaseEditor {
+            overlay: Arc::new(Mutex::new(overlay)),
+            path: Arc::new(path),
+        }
+    }
+
+    fn overlay(&self) -> DatabaseOverlay {
+        self.overlay.lock().unwrap().clone()
+    }
+
+    /// Run `change` on a copy of the overlay and keep it only once the
+    /// copy is on disk.
+    fn change<T>(
+        &self,
+        change: impl FnOnce(&mut DatabaseOverlay) -> Result<T, ApiError>,
+    ) -> ApiResult<T> {
+        let mut overlay = self.overlay.lock().unwrap();
+        let mut edited = overlay.clone();
+        let result = change(&mut edited)?;
+        edited.write(&self.path).map_err(|e| {
+            ApiError::InternalError(format!("writing {}: {}", self.path.display(), e))
+        })?;
+        *overlay = edited;
+        Ok(result)
+    }
+}
+
+fn rejected(problems: Vec<String>) -> ApiError {
+    ApiError::Validation(
+        problems
+            .into_iter()
+            .map(|message| FieldError {
+                field: "body".to_string(),
+                message,
+            })
+            .collect(),
+    )
+}
+
+fn missing(kind: &str, id: u32) -> ApiError {
+    ApiError::NotFound(format!("{} {} not found", kind, id))
+}
+
+/// `GET /api/editor` — every pending edit.
+pub async fn editor_overlay_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+) -> Json<DatabaseOverlay> {
+    Json(state.editor.overlay())
+}
+
+/// `DELETE /api/editor` — drop every edit.
+pub async fn editor_reset_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+) -> ApiResult<StatusCode> {
+    state.editor.change(|overlay| {
+        *overlay = DatabaseOverlay::default();
+        Ok(())
+    })?;
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn editor_club_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    Path(id): Path<u32>,
+) -> ApiResult<impl IntoResponse> {
+    let club = state.editor.overlay().club(&state.database, id);
+    club.map(Json).ok_or_else(|| missing("club", id))
+}
+
+/// `PATCH /api/editor/clubs/{id}` — change or create a club, e.g. its
+/// budget with `{"finance": {"balance": 50000000}}`.
+pub async fn editor_edit_club_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    Path(id): Path<u32>,
+    ApiJson(patch): ApiJson<Value>,
+) -> ApiResult<impl IntoResponse> {
+    let club = state.editor.change(|overlay| {
+        overlay
+            .edit_club(&state.database, id, &patch)
+            .map_err(rejected)
+    })?;
+    info!("editor: club {} ({}) saved", club.id, club.name);
+    Ok(Json(club))
+}
+
+pub async fn editor_delete_club_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    Path(id): Path<u32>,
+) -> ApiResult<StatusCode> {
+    state.editor.change(|overlay| {
+        overlay
+            .delete_club(&state.database, id)
+            .then_some(())
+            .ok_or_else(|| missing("club", id))
+    })?;
+    info!("editor: club {} deleted", id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+pub async fn editor_staff_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    Path(id): Path<u32>,
+) -> ApiResult<impl IntoResponse> {
+    let overlay = state.editor.overlay();
+    if overlay.club(&state.database, id).is_none() {
+        return Err(missing("club", id));
+    }
+    Ok(Json(overlay.staff(&state.database, id)))
+}
+
+/// `PUT /api/editor/clubs/{id}/staff` — replace the club's named staff;
+/// `[]` leaves it to generated staff only.
+pub async fn editor_set_staff_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    Path(id): Path<u32>,
+    ApiJson(staff): ApiJson<Value>,
+) -> ApiResult<impl IntoResponse> {
+    let staff = state.editor.change(|overlay| {
+        if overlay.club(&state.database, id).is_none() {
+            return Err(missing("club", id));
+        }
+        overlay
+            .set_staff(&state.database, id, &staff)
+            .map_err(rejected)
+    })?;
+    info!("editor: {} named staff saved for club {}", staff.len(), id);
+    Ok(Json(staff))
+}
+
+pub async fn editor_player_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    Path(id): Path<u32>,
+) -> ApiResult<impl IntoResponse> {
+    let player = state.editor.overlay().player(&state.database, id);
+    player.map(Json).ok_or_else(|| missing("player", id))
+}
+
+/// `PATCH /api/editor/players/{id}` — change or create a player: their
+/// abilities, positions, or squad through `club_id` and `contract`.
+pub async fn editor_edit_player_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    Path(id): Path<u32>,
+    ApiJson(patch): ApiJson<Value>,
+) -> ApiResult<impl IntoResponse> {
+    let player = state.editor.change(|overlay| {
+        overlay
+            .edit_player(&state.database, id, &patch)
+            .map_err(rejected)
+    })?;
+    info!(
+        "editor: player {} ({} {}) saved",
+        player.id, player.first_name, player.last_name
+    );
+    Ok(Json(player))
+}
+
+pub async fn editor_delete_player_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    Path(id): Path<u32>,
+) -> ApiResult<StatusCode> {
+    state.editor.change(|overlay| {
+        overlay
+            .delete_player(&state.database, id)
+            .then_some(())
+            .ok_or_else(|| missing("player", id))
+    })?;
+    info!("editor: player {} deleted", id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+#[derive(Serialize)]
+pub struct GeneratedWorld {
+    pub date: String,
+    pub clubs: usize,
+}
+
+/// `POST /api/editor/generate` — start a new game from the edited
+/// database, replacing the running one.
+pub async fn editor_generate_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+) -> ApiResult<Json<GeneratedWorld>> {
+    let _process_guard = Arc::clone(&state.process_lock)
+        .try_lock_owned()
+        .map_err(|_| ApiError::Conflict("Game is processing".to_string()))?;
+
+    let overlay = state.editor.overlay();
+    let database = Arc::clone(&state.database);
+    let data = spawn_blocking(move || generate(&database, &overlay))
+        .await
+        .map_err(|e| ApiError::InternalError(format!("generation failed: {}", e)))??;
+
+    let world = GeneratedWorld {
+        date: data.date.to_string(),
+        clubs: data
+            .continents
+            .iter()
+            .flat_map(|c| &c.countries)
+            .map(|c| c.clubs.len())
+            .sum(),
+    };
+
+    state.i18n.set_date(data.date);
+    let previous = state.saves.replace_world(&state.data, data).await;
+    spawn_blocking(move || drop(previous));
+
+    info!("editor: new world generated with {} clubs", world.clubs);
+    Ok(Json(world))
+}
+
+/// Generate a world from the edited database unless the database check
+/// finds errors in it.
+fn generate(
+    database: &DatabaseEntity,
+    overlay: &DatabaseOverlay,
+) -> ApiResult<core::SimulatorData> {
+    let edited = if overlay.is_empty() {
+        None
+    } else {
+        Some(database.with_overlay(overlay).map_err(rejected)?)
+    };
+    let database = edited.as_ref().unwrap_or(database);
+
+    let report = database.validate(DatabaseGenerator::start_date().date());
+    if report.has_errors() {
+        return Err(rejected(
+            report
+                .issues
+                .into_iter()
+                .filter(|issue| issue.severity == Severity::Error)
+                .map(|issue| issue.message)
+                .collect(),
+        ));
+    }
+    Ok(DatabaseGenerator::generate(database))
+}
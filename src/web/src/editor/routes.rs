@@ -0,0 +1,28 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::{get, post};
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route(
+            "/api/editor",
+            get(super::editor_overlay_action).delete(super::editor_reset_action),
+        )
+        .route(
+            "/api/editor/clubs/{id}",
+            get(super::editor_club_action)
+                .patch(super::editor_edit_club_action)
+                .delete(super::editor_delete_club_action),
+        )
+        .route(
+            "/api/editor/clubs/{id}/staff",
+            get(super::editor_staff_action).put(super::editor_set_staff_action),
+        )
+        .route(
+            "/api/editor/players/{id}",
+            get(super::editor_player_action)
+                .patch(super::editor_edit_player_action)
+                .delete(super::editor_delete_player_action),
+        )
+        .route("/api/editor/generate", post(super::editor_generate_action))
+}
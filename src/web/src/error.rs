@@ -1,7 +1,16 @@
 use axum::Json;
-use axum::http::StatusCode;
+use axum::http::{HeaderName, HeaderValue, StatusCode, header};
 use axum::response::{IntoResponse, Response};
-use serde_json::json;
+use log::error;
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Media type of every error body, see RFC 9457.
+const PROBLEM_JSON: &str = "application/problem+json";
+
+/// Echoes the id of a failure that was logged server-side.
+pub const CORRELATION_ID_HEADER: HeaderName = HeaderName::from_static("x-correlation-id");
 
 /// Custom error type for API handlers
 #[derive(Debug)]
@@ -9,24 +18,180 @@ pub enum ApiError {
     NotFound(String),
     InternalError(String),
     BadRequest(String),
+    /// No valid session cookie.
+    Unauthorized(String),
+    /// Signed in, but not allowed to act on the resource.
+    Forbidden(String),
+    /// The request is fine but clashes with the game's current state,
+    /// e.g. a save while a day is being simulated.
+    Conflict(String),
+    /// One or more request fields were rejected.
+    Validation(Vec<FieldError>),
+}
+
+/// A rejected request field and why.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct FieldError {
+    pub field: String,
+    pub message: String,
+}
+
+impl ApiError {
+    /// A validation failure of a single field.
+    pub fn invalid(field: &str, message: impl Into<String>) -> Self {
+        ApiError::Validation(vec![FieldError {
+            field: field.to_string(),
+            message: message.into(),
+        }])
+    }
+
+    pub fn status(&self) -> StatusCode {
+        match self {
+            ApiError::NotFound(_) => StatusCode::NOT_FOUND,
+            ApiError::InternalError(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            ApiError::BadRequest(_) => StatusCode::BAD_REQUEST,
+            ApiError::Unauthorized(_) => StatusCode::UNAUTHORIZED,
+            ApiError::Forbidden(_) => StatusCode::FORBIDDEN,
+            ApiError::Conflict(_) => StatusCode::CONFLICT,
+            ApiError::Validation(_) => StatusCode::UNPROCESSABLE_ENTITY,
+        }
+    }
+
+    /// Stable, machine-readable name of the failure; clients branch on
+    /// this rather than on the wording of `detail`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ApiError::NotFound(_) => "not_found",
+            ApiError::InternalError(_) => "internal_error",
+            ApiError::BadRequest(_) => "bad_request",
+            ApiError::Unauthorized(_) => "unauthorized",
+            ApiError::Forbidden(_) => "forbidden",
+            ApiError::Conflict(_) => "conflict",
+            ApiError::Validation(_) => "validation_failed",
+        }
+    }
+
+    fn into_problem(self) -> Problem {
+        let status = self.status();
+        let code = self.code();
+        let (detail, errors) = match self {
+            ApiError::NotFound(msg)
+            | ApiError::InternalError(msg)
+            | ApiError::BadRequest(msg)
+            | ApiError::Unauthorized(msg)
+            | ApiError::Forbidden(msg)
+            | ApiError::Conflict(msg) => (msg, Vec::new()),
+            ApiError::Validation(errors) => {
+                let detail = errors
+                    .iter()
+                    .map(|e| format!("{}: {}", e.field, e.message))
+                    .collect::<Vec<_>>()
+                    .join("; ");
+                (detail, errors)
+            }
+        };
+        Problem::new(status, code, detail, errors)
+    }
+}
+
+/// Error body shared by every JSON endpoint: the RFC 9457 members plus
+/// `code`, the field errors of a validation failure and, for server-side
+/// failures, the id under which the details were logged.
+#[derive(Debug, Serialize)]
+pub struct Problem {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub title: &'static str,
+    pub status: u16,
+    pub detail: String,
+    pub code: &'static str,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<FieldError>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub correlation_id: Option<String>,
+}
+
+impl Problem {
+    fn new(
+        status: StatusCode,
+        code: &'static str,
+        detail: String,
+        errors: Vec<FieldError>,
+    ) -> Self {
+        Problem {
+            kind: format!("/problems/{}", code),
+            title: status.canonical_reason().unwrap_or("Error"),
+            status: status.as_u16(),
+            detail,
+            code,
+            errors,
+            correlation_id: None,
+        }
+    }
+
+    /// The 500 answered for a handler that panicked. The panic message
+    /// stays in the log, next to the id handed to the client.
+    pub fn from_panic(message: &str) -> Response {
+        let mut problem = Problem::new(
+            StatusCode::INTERNAL_SERVER_ERROR,
+            "internal_error",
+            "Internal server error - handler panicked".to_string(),
+            Vec::new(),
+        );
+        let id = correlation_id();
+        error!("[{}] handler panicked: {}", id, message);
+        problem.correlation_id = Some(id);
+        problem.into_response()
+    }
+}
+
+impl IntoResponse for Problem {
+    fn into_response(self) -> Response {
+        let status = StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let correlation = self
+            .correlation_id
+            .as_deref()
+            .and_then(|id| HeaderValue::from_str(id).ok());
+
+        let mut response = (status, Json(self)).into_response();
+        let headers = response.headers_mut();
+        headers.insert(header::CONTENT_TYPE, HeaderValue::from_static(PROBLEM_JSON));
+        if let Some(id) = correlation {
+            headers.insert(CORRELATION_ID_HEADER, id);
+        }
+        response
+    }
 }
 
 impl IntoResponse for ApiError {
     fn into_response(self) -> Response {
-        let (status, error_message) = match self {
-            ApiError::NotFound(msg) => (StatusCode::NOT_FOUND, msg),
-            ApiError::InternalError(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg),
-            ApiError::BadRequest(msg) => (StatusCode::BAD_REQUEST, msg),
-        };
+        let mut problem = self.into_problem();
 
-        let body = Json(json!({
-            "error": error_message,
-        }));
+        // Internal messages can carry IO or parser detail meant for the
+        // operator; log them under an id the client can quote and answer
+        // with a generic detail instead.
+        if problem.status == StatusCode::INTERNAL_SERVER_ERROR.as_u16() {
+            let id = correlation_id();
+            error!("[{}] {}", id, problem.detail);
+            problem.detail = "Internal server error".to_string();
+            problem.correlation_id = Some(id);
+        }
 
-        (status, body).into_response()
+        problem.into_response()
     }
 }
 
+/// Short, process-unique id: the current time plus a sequence number.
+fn correlation_id() -> String {
+    static NEXT: AtomicU64 = AtomicU64::new(0);
+    let seq = NEXT.fetch_add(1, Ordering::Relaxed);
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis())
+        .unwrap_or_default();
+    format!("{:x}-{:04x}", now, seq)
+}
+
 impl From<std::io::Error> for ApiError {
     fn from(err: std::io::Error) -> Self {
         ApiError::InternalError(format!("IO error: {}", err))
@@ -41,3 +206,64 @@ impl From<serde_json::Error> for ApiError {
 
 /// Helper type for handler results
 pub type ApiResult<T> = Result<T, ApiError>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::to_bytes;
+    use serde_json::Value;
+
+    fn body(response: Response) -> Value {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let bytes = runtime
+            .block_on(to_bytes(response.into_body(), usize::MAX))
+            .unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[test]
+    fn validation_errors_list_every_field() {
+        let response = ApiError::Validation(vec![
+            FieldError {
+                field: "name".to_string(),
+                message: "must not be empty".to_string(),
+            },
+            FieldError {
+                field: "days".to_string(),
+                message: "must be at most 365".to_string(),
+            },
+        ])
+        .into_response();
+
+        assert_eq!(response.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        assert_eq!(response.headers()[header::CONTENT_TYPE], PROBLEM_JSON);
+        let json = body(response);
+        assert_eq!(json["code"], "validation_failed");
+        assert_eq!(json["type"], "/problems/validation_failed");
+        assert_eq!(json["errors"][1]["field"], "days");
+        assert_eq!(
+            json["detail"],
+            "name: must not be empty; days: must be at most 365"
+        );
+        assert!(json.get("correlation_id").is_none());
+    }
+
+    #[test]
+    fn server_failures_carry_a_correlation_id() {
+        let response = ApiError::InternalError("IO error: disk full".to_string()).into_response();
+        let header_id = response.headers()[CORRELATION_ID_HEADER]
+            .to_str()
+            .unwrap()
+            .to_string();
+        let json = body(response);
+        assert_eq!(json["status"], 500);
+        assert_eq!(json["detail"], "Internal server error");
+        assert_eq!(json["correlation_id"], header_id.as_str());
+
+        let panic = body(Problem::from_panic("boom"));
+        assert_eq!(panic["code"], "internal_error");
+        assert_ne!(panic["correlation_id"], header_id.as_str());
+    }
+}
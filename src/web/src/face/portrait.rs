@@ -0,0 +1,221 @@
+use super::FACE_VERSION;
+use super::generator::{generate_face_svg, skin_distribution_for_country};
+use core::utils::DateUtils;
+use core::{Player, SimulatorData};
+use log::warn;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Everything a portrait is drawn from. The generator is seeded by the
+/// player id, so equal inputs always give the same picture.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Portrait {
+    pub player_id: u32,
+    pub age: u8,
+    /// Nationality — picks the skin, hair and eye distribution.
+    pub country_code: String,
+    /// Weight-for-height deviation, see `generate_face_svg`.
+    pub heft: f32,
+    /// 0..1, from temperament and dirtiness.
+    pub aggression: f32,
+    /// Club shirt color; `None` draws a per-player hue.
+    pub jersey: Option<String>,
+}
+
+impl Portrait {
+    pub fn of(simulator_data: &SimulatorData, player: &Player, kit_colors: bool) -> Self {
+        let age = DateUtils::age(player.birth_date, simulator_data.date.date());
+
+        let country_code = simulator_data
+            .country(player.country_id)
+            .map(|c| c.code.clone())
+            .or_else(|| {
+                simulator_data
+                    .country_info
+                    .get(&player.country_id)
+                    .map(|i| i.code.clone())
+            })
+            .unwrap_or_default();
+
+        // Weight-for-height drives facial fullness; fall back to an average
+        // build when the record carries no plausible body data
+        let height_cm = if player.player_attributes.height >= 150 {
+            player.player_attributes.height as f32
+        } else {
+            180.0
+        };
+        let weight_kg = if player.player_attributes.weight >= 45 {
+            player.player_attributes.weight as f32
+        } else {
+            75.0
+        };
+        let athletic_kg = 23.0 * (height_cm / 100.0) * (height_cm / 100.0);
+        let heft = (weight_kg - athletic_kg) / 6.0;
+
+        // Expression: short fuse (low temperament) + dirty tackling read as a
+        // harder face; both attributes are on the 0..20 scale
+        let aggression = (((20.0 - player.attributes.temperament) * 0.6
+            + player.attributes.dirtiness * 0.4)
+            / 20.0)
+            .clamp(0.0, 1.0);
+
+        // Real club shirt color; free agents keep the per-player fallback hue
+        let jersey = if kit_colors {
+            simulator_data
+                .indexes
+                .as_ref()
+                .and_then(|idx| idx.get_player_location(player.id))
+                .and_then(|(_, _, club_id, _)| simulator_data.club(club_id))
+                .map(|club| club.colors.background.clone())
+        } else {
+            None
+        };
+
+        Portrait {
+            player_id: player.id,
+            age,
+            country_code,
+            heft,
+            aggression,
+            jersey,
+        }
+    }
+
+    pub fn render(&self) -> String {
+        generate_face_svg(
+            self.player_id,
+            self.age,
+            skin_distribution_for_country(&self.country_code),
+            self.heft,
+            self.aggression,
+            self.jersey.as_deref(),
+        )
+    }
+
+    /// First line of the cached file. A portrait whose inputs or generator
+    /// version changed no longer matches and is drawn again.
+    fn cache_key(&self) -> String {
+        format!(
+            "<!-- face v{} {} {} {} {:?} {:?} {} -->",
+            FACE_VERSION,
+            self.player_id,
+            self.age,
+            self.country_code,
+            self.heft,
+            self.aggression,
+            self.jersey.as_deref().unwrap_or("-"),
+        )
+    }
+}
+
+/// Draws portraits and keeps them on disk, one file per player, so a
+/// squad page doesn't regenerate thirty faces on every visit.
+#[derive(Clone)]
+pub struct PortraitStore {
+    /// `None` renders every request afresh.
+    directory: Option<PathBuf>,
+    /// Dress players in their club's shirt color.
+    pub kit_colors: bool,
+}
+
+impl PortraitStore {
+    pub fn new(directory: Option<PathBuf>, kit_colors: bool) -> Self {
+        PortraitStore {
+            directory,
+            kit_colors,
+        }
+    }
+
+    /// The SVG for `portrait`, from the cache when its inputs are unchanged.
+    /// A cache that can't be written only costs the next request a redraw.
+    pub fn svg(&self, portrait: &Portrait) -> String {
+        let Some(directory) = &self.directory else {
+            return portrait.render();
+        };
+        let path = directory.join(format!("{}.svg", portrait.player_id));
+        let key = portrait.cache_key();
+
+        let cached = fs::read_to_string(&path).ok();
+        let hit = cached.as_deref().and_then(|text| {
+            text.strip_prefix(key.as_str())
+                .and_then(|rest| rest.strip_prefix('\n'))
+        });
+        if let Some(svg) = hit {
+            return svg.to_string();
+        }
+
+        let svg = portrait.render();
+        if let Err(err) = self.write(&path, &key, &svg) {
+            warn!("Cannot cache portrait {}: {}", path.display(), err);
+        }
+        svg
+    }
+
+    /// Written next to the target and renamed over it, so a concurrent
+    /// reader never sees half a file.
+    fn write(&self, path: &Path, key: &str, svg: &str) -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let partial = path.with_extension("svg.partial");
+        fs::write(&partial, format!("{}\n{}", key, svg))?;
+        fs::rename(&partial, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn portrait(age: u8) -> Portrait {
+        Portrait {
+            player_id: 4242,
+            age,
+            country_code: String::new(),
+            heft: 0.5,
+            aggression: 0.25,
+            jersey: Some("#c8102e".to_string()),
+        }
+    }
+
+    fn store(name: &str) -> (PortraitStore, PathBuf) {
+        let directory = std::env::temp_dir().join(format!(
+            "open-football-faces-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        (PortraitStore::new(Some(directory.clone()), true), directory)
+    }
+
+    #[test]
+    fn same_inputs_draw_the_same_portrait() {
+        assert_eq!(portrait(24).render(), portrait(24).render());
+        assert_ne!(portrait(24).render(), portrait(34).render());
+    }
+
+    #[test]
+    fn cached_portrait_is_served_until_its_inputs_change() {
+        let (store, directory) = store("cache");
+        let fresh = store.svg(&portrait(24));
+        assert_eq!(fresh, portrait(24).render());
+        assert!(fresh.starts_with("<svg"));
+
+        let path = directory.join("4242.svg");
+        let cached = fs::read_to_string(&path).unwrap();
+        fs::write(&path, cached.replacen("<svg", "<svg data-cached", 1)).unwrap();
+        assert!(store.svg(&portrait(24)).starts_with("<svg data-cached"));
+
+        // A birthday changes the key, so the file is drawn again.
+        let older = store.svg(&portrait(25));
+        assert_eq!(older, portrait(25).render());
+        assert!(
+            fs::read_to_string(&path)
+                .unwrap()
+                .starts_with(&portrait(25).cache_key())
+        );
+
+        let _ = fs::remove_dir_all(&directory);
+    }
+}
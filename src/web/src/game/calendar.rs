@@ -0,0 +1,369 @@
+use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
+use crate::session::{SessionToken, current_manager, require_managed_club};
+use crate::views::{self, MenuSection};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData, I18n};
+use askama::Template;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use chrono::{Datelike, Duration, NaiveDate};
+use core::transfers::TransferCalendar;
+use core::{NationalTeam, SimulatorData};
+use serde::{Deserialize, Serialize};
+
+/// How far ahead the calendar looks when the request doesn't say.
+const DEFAULT_HORIZON_DAYS: u32 = 60;
+const MAX_HORIZON_DAYS: u32 = 365;
+
+#[derive(Deserialize)]
+pub struct CalendarQuery {
+    pub days: Option<u32>,
+}
+
+impl CalendarQuery {
+    fn horizon(&self) -> u32 {
+        self.days
+            .unwrap_or(DEFAULT_HORIZON_DAYS)
+            .clamp(1, MAX_HORIZON_DAYS)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CalendarEventKind {
+    Fixture,
+    TransferWindowOpens,
+    TransferWindowCloses,
+    InternationalBreakStarts,
+    InternationalBreakEnds,
+}
+
+impl CalendarEventKind {
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            CalendarEventKind::Fixture => "calendar_fixture",
+            CalendarEventKind::TransferWindowOpens => "calendar_window_opens",
+            CalendarEventKind::TransferWindowCloses => "calendar_window_closes",
+            CalendarEventKind::InternationalBreakStarts => "calendar_break_starts",
+            CalendarEventKind::InternationalBreakEnds => "calendar_break_ends",
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct CalendarFixtureDto {
+    pub time: String,
+    pub competition_name: String,
+    pub opponent_name: String,
+    pub opponent_slug: String,
+    pub is_home: bool,
+}
+
+#[derive(Serialize)]
+pub struct CalendarEventDto {
+    pub date: NaiveDate,
+    pub kind: CalendarEventKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fixture: Option<CalendarFixtureDto>,
+}
+
+#[derive(Serialize)]
+pub struct CalendarDto {
+    pub date: NaiveDate,
+    pub until: NaiveDate,
+    pub club_id: u32,
+    pub club_name: String,
+    pub events: Vec<CalendarEventDto>,
+}
+
+/// What is coming up for `club_id` from today through `horizon_days`:
+/// unplayed fixtures in every competition the senior team is in, transfer
+/// window boundaries of the club's country and international breaks.
+pub fn club_calendar(
+    sim: &SimulatorData,
+    club_id: u32,
+    horizon_days: u32,
+) -> ApiResult<CalendarDto> {
+    let club = sim
+        .club(club_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Club with ID {} not found", club_id)))?;
+    let from = sim.date.date();
+    let until = from + Duration::days(i64::from(horizon_days));
+    let in_range = |date: NaiveDate| date >= from && date <= until;
+
+    let mut events: Vec<(NaiveDate, String, CalendarEventDto)> = Vec::new();
+
+    if let Some(team) = club.teams.main_team_id().and_then(|id| sim.team(id)) {
+        let league = team.league_id.and_then(|id| sim.league(id));
+        let cup = sim
+            .country_by_club(club_id)
+            .and_then(|country| country.domestic_cup.as_ref())
+            .map(|cup| &cup.league);
+        for competition in league.into_iter().chain(cup) {
+            for item in competition.schedule.get_matches_for_team(team.id) {
+                if item.result.is_some() || !in_range(item.date.date()) {
+                    continue;
+                }
+                let is_home = item.home_team_id == team.id;
+                let opponent_id = if is_home {
+                    item.away_team_id
+                } else {
+                    item.home_team_id
+                };
+                let Some(opponent) = sim.team_data(opponent_id) else {
+                    continue;
+                };
+                let time = item.date.format("%H:%M").to_string();
+                events.push((
+                    item.date.date(),
+                    time.clone(),
+                    CalendarEventDto {
+                        date: item.date.date(),
+                        kind: CalendarEventKind::Fixture,
+                        fixture: Some(CalendarFixtureDto {
+                            time,
+                            competition_name: competition.name.clone(),
+                            opponent_name: opponent.name.clone(),
+                            opponent_slug: opponent.slug.clone(),
+                            is_home,
+                        }),
+                    },
+                ));
+            }
+        }
+    }
+
+    for (competition, home, away, date, _, result) in sim.continental_matches_for_club(club_id) {
+        if result.is_some() || !in_range(date) {
+            continue;
+        }
+        let is_home = home == club_id;
+        let opponent = sim
+            .club(if is_home { away } else { home })
+            .and_then(|c| c.teams.main_team_id())
+            .and_then(|id| sim.team_data(id));
+        let Some(opponent) = opponent else {
+            continue;
+        };
+        events.push((
+            date,
+            "20:00".to_string(),
+            CalendarEventDto {
+                date,
+                kind: CalendarEventKind::Fixture,
+                fixture: Some(CalendarFixtureDto {
+                    time: "20:00".to_string(),
+                    competition_name: competition.to_string(),
+                    opponent_name: opponent.name.clone(),
+                    opponent_slug: opponent.slug.clone(),
+                    is_home,
+                }),
+            },
+        ));
+    }
+
+    if let Some(country) = sim.country_by_club(club_id) {
+        for (opens, closes) in transfer_windows(from, until, |anchor| {
+            let windows = TransferCalendar::of(country, anchor);
+            [windows.summer_window, windows.winter_window]
+        }) {
+            events.extend(boundary_events(
+                (opens, CalendarEventKind::TransferWindowOpens),
+                (closes, CalendarEventKind::TransferWindowCloses),
+                in_range,
+            ));
+        }
+    }
+
+    for (starts, ends) in NationalTeam::breaks_between(from, until) {
+        events.extend(boundary_events(
+            (starts, CalendarEventKind::InternationalBreakStarts),
+            (ends, CalendarEventKind::InternationalBreakEnds),
+            in_range,
+        ));
+    }
+
+    events.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
+
+    Ok(CalendarDto {
+        date: from,
+        until,
+        club_id,
+        club_name: club.name.clone(),
+        events: events.into_iter().map(|(_, _, event)| event).collect(),
+    })
+}
+
+/// Distinct windows overlapping `from..=until`. The calendar hands out one
+/// season's pair per anchor date, so ask for every year in the range.
+fn transfer_windows(
+    from: NaiveDate,
+    until: NaiveDate,
+    windows_at: impl Fn(NaiveDate) -> [(NaiveDate, NaiveDate); 2],
+) -> Vec<(NaiveDate, NaiveDate)> {
+    let anchors = std::iter::once(from).chain(
+        (from.year() + 1..=until.year()).filter_map(|year| NaiveDate::from_ymd_opt(year, 1, 1)),
+    );
+    let mut windows: Vec<(NaiveDate, NaiveDate)> = anchors
+        .flat_map(windows_at)
+        .filter(|(opens, closes)| *closes >= from && *opens <= until)
+        .collect();
+    windows.sort();
+    windows.dedup();
+    windows
+}
+
+/// The start and end markers of a period that fall inside the range. Both
+/// sort ahead of the day's fixtures.
+fn boundary_events(
+    start: (NaiveDate, CalendarEventKind),
+    end: (NaiveDate, CalendarEventKind),
+    in_range: impl Fn(NaiveDate) -> bool,
+) -> impl Iterator<Item = (NaiveDate, String, CalendarEventDto)> {
+    [start, end]
+        .into_iter()
+        .filter(move |(date, _)| in_range(*date))
+        .map(|(date, kind)| {
+            (
+                date,
+                String::new(),
+                CalendarEventDto {
+                    date,
+                    kind,
+                    fixture: None,
+                },
+            )
+        })
+}
+
+/// `GET /api/v1/game/calendar?days=N` — the signed-in manager's club calendar.
+pub async fn game_calendar_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    ApiQuery(query): ApiQuery<CalendarQuery>,
+) -> ApiResult<Json<CalendarDto>> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let club_id = require_managed_club(simulator_data, &session)?;
+    Ok(Json(club_calendar(
+        simulator_data,
+        club_id,
+        query.horizon(),
+    )?))
+}
+
+#[derive(Deserialize)]
+pub struct CalendarPageRequest {
+    pub lang: String,
+}
+
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "game/calendar.html")]
+pub struct CalendarPageTemplate {
+    pub css_version: &'static str,
+    pub computer_name: &'static str,
+    pub cpu_brand: &'static str,
+    pub cores_count: usize,
+    pub i18n: I18n,
+    pub lang: String,
+    pub title: String,
+    pub sub_title_prefix: String,
+    pub sub_title_suffix: String,
+    pub sub_title: String,
+    pub sub_title_link: String,
+    pub sub_title_country_code: String,
+    pub header_color: String,
+    pub foreground_color: String,
+    pub menu_sections: Vec<MenuSection>,
+    /// `None` when no manager with a club is signed in.
+    pub calendar: Option<CalendarDto>,
+}
+
+pub async fn game_calendar_page_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    Path(route_params): Path<CalendarPageRequest>,
+    ApiQuery(query): ApiQuery<CalendarQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let i18n = state.i18n.for_lang(&route_params.lang);
+    let current_path = format!("/{}/game/calendar", &route_params.lang);
+
+    let club_id = current_manager(simulator_data, &session).and_then(|m| m.club_id);
+    let calendar = club_id
+        .map(|id| club_calendar(simulator_data, id, query.horizon()))
+        .transpose()?;
+    let (header_color, foreground_color) = club_id
+        .and_then(|id| simulator_data.club(id))
+        .map(|c| (c.colors.background.clone(), c.colors.foreground.clone()))
+        .unwrap_or_default();
+
+    Ok(CalendarPageTemplate {
+        css_version: CSS_VERSION,
+        computer_name: &COMPUTER_NAME,
+        cpu_brand: &CPU_BRAND,
+        cores_count: *CPU_CORES,
+        title: i18n.t("calendar").to_string(),
+        sub_title_prefix: String::new(),
+        sub_title_suffix: String::new(),
+        sub_title: calendar
+            .as_ref()
+            .map(|c| c.club_name.clone())
+            .unwrap_or_default(),
+        sub_title_link: String::new(),
+        sub_title_country_code: String::new(),
+        header_color,
+        foreground_color,
+        menu_sections: views::calendar_menu(&i18n, &route_params.lang, &current_path),
+        i18n,
+        lang: route_params.lang.clone(),
+        calendar,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn d(y: i32, m: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, day).unwrap()
+    }
+
+    #[test]
+    fn windows_across_the_new_year_are_listed_once() {
+        let windows = transfer_windows(d(2026, 11, 1), d(2027, 7, 1), |anchor| {
+            let year = anchor.year();
+            [
+                (d(year, 6, 1), d(year, 8, 31)),
+                (d(year, 1, 1), d(year, 1, 31)),
+            ]
+        });
+        assert_eq!(
+            windows,
+            [
+                (d(2027, 1, 1), d(2027, 1, 31)),
+                (d(2027, 6, 1), d(2027, 8, 31))
+            ]
+        );
+    }
+
+    #[test]
+    fn breaks_cover_the_range_in_order() {
+        let breaks = NationalTeam::breaks_between(d(2026, 10, 12), d(2027, 3, 20));
+        assert_eq!(
+            breaks,
+            [
+                (d(2026, 10, 9), d(2026, 10, 17)),
+                (d(2026, 11, 13), d(2026, 11, 21)),
+                (d(2027, 3, 20), d(2027, 3, 28)),
+            ]
+        );
+    }
+}
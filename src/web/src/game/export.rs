@@ -0,0 +1,330 @@
+//! Results of a headless run, written to a directory for batch experiments
+//! and CI regression checks: league tables, completed transfers and
+//! per-season player statistics, each as JSON and CSV, plus a summary.
+
+use crate::player::get::player_season_rows;
+use crate::transfers::history::all_transfers;
+use chrono::NaiveDate;
+use core::league::LeagueTableRow;
+use core::{PlayerStatistics, SimulatorData};
+use serde::Serialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+#[derive(Serialize)]
+pub struct ExportedStanding {
+    pub position: usize,
+    pub team_name: String,
+    pub team_slug: String,
+    pub played: u8,
+    pub won: u8,
+    pub drawn: u8,
+    pub lost: u8,
+    pub goals_for: i32,
+    pub goals_against: i32,
+    pub points: u8,
+}
+
+#[derive(Serialize)]
+pub struct ExportedTable {
+    pub country: String,
+    pub league: String,
+    pub league_slug: String,
+    /// Start year of an archived season; `None` for the table in play.
+    pub season_start_year: Option<i32>,
+    pub rows: Vec<ExportedStanding>,
+}
+
+#[derive(Serialize)]
+pub struct ExportedPlayerSeason {
+    pub player_id: u32,
+    pub player_name: String,
+    pub season: String,
+    pub team_name: String,
+    pub league_name: String,
+    pub is_loan: bool,
+    pub played: u16,
+    pub played_subs: u16,
+    pub goals: u16,
+    pub assists: u16,
+    pub yellow_cards: u8,
+    pub red_cards: u8,
+    pub average_rating: String,
+}
+
+#[derive(Serialize)]
+pub struct ExportSummary {
+    pub date: NaiveDate,
+    pub tables: usize,
+    pub transfers: usize,
+    pub player_seasons: usize,
+}
+
+/// Write every export file into `directory`, creating it when missing.
+pub fn export_results(sim: &SimulatorData, directory: &Path) -> io::Result<ExportSummary> {
+    fs::create_dir_all(directory)?;
+
+    let tables = league_tables(sim);
+    write_json(&directory.join("tables.json"), &tables)?;
+    write_csv(
+        &directory.join("tables.csv"),
+        &[
+            "country",
+            "league",
+            "season",
+            "position",
+            "team",
+            "played",
+            "won",
+            "drawn",
+            "lost",
+            "goals_for",
+            "goals_against",
+            "points",
+        ],
+        tables.iter().flat_map(|table| {
+            table.rows.iter().map(move |row| {
+                vec![
+                    table.country.clone(),
+                    table.league.clone(),
+                    table
+                        .season_start_year
+                        .map(|year| year.to_string())
+                        .unwrap_or_else(|| "current".to_string()),
+                    row.position.to_string(),
+                    row.team_name.clone(),
+                    row.played.to_string(),
+                    row.won.to_string(),
+                    row.drawn.to_string(),
+                    row.lost.to_string(),
+                    row.goals_for.to_string(),
+                    row.goals_against.to_string(),
+                    row.points.to_string(),
+                ]
+            })
+        }),
+    )?;
+
+    let transfers = all_transfers(sim).map_err(|err| io::Error::other(format!("{:?}", err)))?;
+    write_json(&directory.join("transfers.json"), &transfers)?;
+    write_csv(
+        &directory.join("transfers.csv"),
+        &[
+            "date",
+            "season",
+            "player_id",
+            "player",
+            "from",
+            "to",
+            "country",
+            "type",
+            "fee",
+            "window",
+        ],
+        transfers.iter().map(|t| {
+            vec![
+                t.date.to_string(),
+                t.season.to_string(),
+                t.player_id.to_string(),
+                t.player_name.clone(),
+                t.from_team_name.clone(),
+                t.to_team_name.clone(),
+                t.country_slug.clone(),
+                to_label(&t.kind),
+                t.fee.to_string(),
+                t.window.as_ref().map(to_label).unwrap_or_default(),
+            ]
+        }),
+    )?;
+
+    let players = player_seasons(sim);
+    write_json(&directory.join("players.json"), &players)?;
+    write_csv(
+        &directory.join("players.csv"),
+        &[
+            "player_id",
+            "player",
+            "season",
+            "team",
+            "league",
+            "loan",
+            "apps",
+            "sub_apps",
+            "goals",
+            "assists",
+            "yellow_cards",
+            "red_cards",
+            "average_rating",
+        ],
+        players.iter().map(|p| {
+            vec![
+                p.player_id.to_string(),
+                p.player_name.clone(),
+                p.season.clone(),
+                p.team_name.clone(),
+                p.league_name.clone(),
+                p.is_loan.to_string(),
+                p.played.to_string(),
+                p.played_subs.to_string(),
+                p.goals.to_string(),
+                p.assists.to_string(),
+                p.yellow_cards.to_string(),
+                p.red_cards.to_string(),
+                p.average_rating.clone(),
+            ]
+        }),
+    )?;
+
+    let summary = ExportSummary {
+        date: sim.date.date(),
+        tables: tables.len(),
+        transfers: transfers.len(),
+        player_seasons: players.len(),
+    };
+    write_json(&directory.join("summary.json"), &summary)?;
+    Ok(summary)
+}
+
+/// Archived final tables of every league, oldest first, followed by the
+/// table in play. Friendly and cup competitions have no standings.
+fn league_tables(sim: &SimulatorData) -> Vec<ExportedTable> {
+    let mut tables = Vec::new();
+    for country in sim.continents.iter().flat_map(|c| &c.countries) {
+        for league in &country.leagues.leagues {
+            if league.friendly || league.is_cup {
+                continue;
+            }
+            let seasons = league
+                .history
+                .iter()
+                .map(|record| (Some(record.season_start_year), record.table.as_slice()))
+                .chain(std::iter::once((None, league.table.get())));
+            for (season_start_year, rows) in seasons {
+                tables.push(ExportedTable {
+                    country: country.name.clone(),
+                    league: league.name.clone(),
+                    league_slug: league.slug.clone(),
+                    season_start_year,
+                    rows: standings(sim, rows),
+                });
+            }
+        }
+    }
+    tables
+}
+
+fn standings(sim: &SimulatorData, rows: &[LeagueTableRow]) -> Vec<ExportedStanding> {
+    rows.iter()
+        .enumerate()
+        .map(|(idx, row)| {
+            let team = sim.team_data(row.team_id);
+            ExportedStanding {
+                position: idx + 1,
+                team_name: team.map(|t| t.name.clone()).unwrap_or_default(),
+                team_slug: team.map(|t| t.slug.clone()).unwrap_or_default(),
+                played: row.played,
+                won: row.win,
+                drawn: row.draft,
+                lost: row.lost,
+                goals_for: row.goal_scored,
+                goals_against: row.goal_concerned,
+                points: row.effective_points(),
+            }
+        })
+        .collect()
+}
+
+/// Career rows of every player at a club, one per season and club.
+fn player_seasons(sim: &SimulatorData) -> Vec<ExportedPlayerSeason> {
+    let mut seasons = Vec::new();
+    for club in sim
+        .continents
+        .iter()
+        .flat_map(|c| &c.countries)
+        .flat_map(|c| &c.clubs)
+    {
+        for team in club.teams.iter() {
+            for player in team.players.players() {
+                let name = player.full_name.to_string();
+                for row in player_season_rows(sim, player, Some(team))
+                    .into_iter()
+                    .rev()
+                {
+                    seasons.push(ExportedPlayerSeason {
+                        player_id: player.id,
+                        player_name: name.clone(),
+                        season: row.season.display,
+                        team_name: row.team_name,
+                        league_name: row.league_name,
+                        is_loan: row.is_loan,
+                        played: row.statistics.played,
+                        played_subs: row.statistics.played_subs,
+                        goals: row.statistics.goals,
+                        assists: row.statistics.assists,
+                        yellow_cards: row.statistics.yellow_cards,
+                        red_cards: row.statistics.red_cards,
+                        average_rating: PlayerStatistics::format_rating(
+                            row.statistics.average_rating,
+                        ),
+                    });
+                }
+            }
+        }
+    }
+    seasons
+}
+
+/// The snake_case name a value serializes to.
+fn to_label<T: Serialize>(value: &T) -> String {
+    serde_json::to_value(value)
+        .ok()
+        .and_then(|v| v.as_str().map(str::to_string))
+        .unwrap_or_default()
+}
+
+fn write_json<T: Serialize + ?Sized>(path: &Path, value: &T) -> io::Result<()> {
+    let text = serde_json::to_string_pretty(value)?;
+    fs::write(path, text)
+}
+
+fn write_csv(
+    path: &Path,
+    header: &[&str],
+    rows: impl Iterator<Item = Vec<String>>,
+) -> io::Result<()> {
+    let mut writer = csv::Writer::from_path(path)?;
+    writer.write_record(header)?;
+    for row in rows {
+        writer.write_record(&row)?;
+    }
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_fields_are_quoted_only_when_needed() {
+        let path =
+            std::env::temp_dir().join(format!("open-football-export-{}.csv", std::process::id()));
+        write_csv(
+            &path,
+            &["team", "nickname"],
+            [vec![
+                "Brighton, Hove".to_string(),
+                "Nick \"The Wall\"".to_string(),
+            ]]
+            .into_iter(),
+        )
+        .unwrap();
+
+        let text = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(
+            text,
+            "team,nickname\n\"Brighton, Hove\",\"Nick \"\"The Wall\"\"\"\n"
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Countries loaded on demand. A slot loaded from the SQLite store comes
+//! back without its countries (see `save_sqlite`); this middleware reads
+//! one back for a route that only ever shows the country it names, and
+//! all of the rest before any other route that reads the world. Country
+//! pages listing people from elsewhere, such as national squads with
+//! players abroad, count as the latter. Routes that never look at a
+//! country, or that replace the world outright, leave them on disk. With
+//! file saves every world is whole and the middleware does nothing.
+
+use super::save::{SaveInfo, SaveStore};
+use crate::{ApiError, GameAppData};
+use axum::extract::{MatchedPath, Request, State};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use core::SimulatorData;
+use std::io;
+use std::sync::Arc;
+use tokio::sync::RwLock;
+use tokio::task::spawn_blocking;
+
+/// Routes that read nothing of a country, list countries from their
+/// stored counts, or replace the world.
+const COUNTRYLESS_ROUTES: &[&str] = &[
+    "/",
+    "/{lang}",
+    "/{lang}/countries",
+    "/api/v1/countries",
+    "/healthz",
+    "/readyz",
+    "/metrics",
+    "/api/date",
+    "/settings/language",
+    "/api/game/create",
+    "/api/game/progress",
+    "/api/game/processing",
+    "/api/game/cancel",
+    "/admin/status",
+    "/admin/pause",
+    "/admin/speed",
+    "/admin/load",
+    "/api/v1/game/saves",
+    "/api/workers/status",
+    "/api/workers/add",
+    "/api/workers/remove",
+    "/api/ai/progress",
+    "/api/ai/config",
+    "/api/editor",
+    "/api/editor/generate",
+    "/api/editor/clubs/{id}",
+    "/api/editor/clubs/{id}/staff",
+    "/api/editor/players/{id}",
+];
+
+/// Routes reading nothing of the world but the country named by their
+/// `{country_slug}` segment.
+const SINGLE_COUNTRY_ROUTES: &[&str] = &[
+    "/api/v1/countries/{country_slug}",
+    "/{lang}/countries/{country_slug}/leagues",
+    "/{lang}/countries/{country_slug}/free-agents",
+];
+
+const COUNTRY_SLUG: &str = "{country_slug}";
+
+/// The shared world slot, as held by `GameAppData::data`.
+pub type World = RwLock<Option<Arc<SimulatorData>>>;
+
+/// Counts of a country still on disk, enough to list it without reading
+/// it back.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StoredCountry {
+    pub country_id: u32,
+    pub leagues: u32,
+    pub clubs: u32,
+    pub players: u32,
+}
+
+/// Which countries a request needs in memory.
+#[derive(Debug, PartialEq)]
+enum CountryNeed {
+    None,
+    Country(String),
+    All,
+}
+
+impl CountryNeed {
+    /// What the route `template` needs when requested as `path`. Unmatched
+    /// requests are static files.
+    fn of(template: Option<&str>, path: &str) -> Self {
+        let Some(template) = template else {
+            return CountryNeed::None;
+        };
+        if COUNTRYLESS_ROUTES.contains(&template) {
+            return CountryNeed::None;
+        }
+        if !SINGLE_COUNTRY_ROUTES.contains(&template) {
+            return CountryNeed::All;
+        }
+        match template
+            .split('/')
+            .position(|segment| segment == COUNTRY_SLUG)
+        {
+            Some(index) => path
+                .split('/')
+                .nth(index)
+                .map_or(CountryNeed::None, |slug| {
+                    CountryNeed::Country(slug.to_string())
+                }),
+            None => CountryNeed::None,
+        }
+    }
+}
+
+/// Middleware reading back the countries a request needs before it is
+/// handled.
+pub async fn hydrate_countries(
+    State(state): State<GameAppData>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(MatchedPath::as_str);
+    let hydrated = state
+        .saves
+        .hydrate_route(&state.data, route, request.uri().path())
+        .await;
+    if let Err(err) = hydrated {
+        return ApiError::InternalError(format!("reading countries from the save failed: {}", err))
+            .into_response();
+    }
+    next.run(request).await
+}
+
+impl SaveStore {
+    /// Load `slot` and make it the current world, handing back the save's
+    /// header and the outgoing world. From the SQLite store the countries
+    /// stay on disk until hydrated.
+    pub async fn open(
+        &self,
+        slot: &str,
+        world: &World,
+    ) -> io::Result<(SaveInfo, Option<Arc<SimulatorData>>)> {
+        #[cfg(feature = "sqlite")]
+        if let Some(database) = self.database.clone() {
+            super::save::validate_slot(slot)?;
+            // Held across the swap so no hydration mixes the two slots.
+            let mut stored = self.stored.lock().await;
+            let slot = slot.to_string();
+            let (info, mut data, countries) =
+                spawn_blocking(move || super::save_sqlite::load_world(&database, &slot))
+                    .await
+                    .map_err(io::Error::other)??;
+            data.restore_indexes();
+            let previous = world.write().await.replace(Arc::new(data));
+            *stored = Some(countries);
+            return Ok((info, previous));
+        }
+
+        let store = self.clone();
+        let slot = slot.to_string();
+        let (info, data) = spawn_blocking(move || store.load(&slot))
+            .await
+            .map_err(io::Error::other)??;
+        Ok((info, self.replace_world(world, data).await))
+    }
+
+    /// Make `next` the current world, forgetting any countries of a
+    /// previously loaded slot still on disk. Returns the outgoing world.
+    pub async fn replace_world(
+        &self,
+        world: &World,
+        next: SimulatorData,
+    ) -> Option<Arc<SimulatorData>> {
+        #[cfg(feature = "sqlite")]
+        let mut stored = self.stored.lock().await;
+        let previous = world.write().await.replace(Arc::new(next));
+        #[cfg(feature = "sqlite")]
+        {
+            *stored = None;
+        }
+        previous
+    }
+
+    /// Read back what the route `template`, requested as `path`, reads of
+    /// the world.
+    pub async fn hydrate_route(
+        &self,
+        world: &World,
+        template: Option<&str>,
+        path: &str,
+    ) -> io::Result<()> {
+        match CountryNeed::of(template, path) {
+            CountryNeed::None => Ok(()),
+            CountryNeed::Country(slug) => self.hydrate_country(world, &slug).await,
+            CountryNeed::All => self.hydrate_all(world).await,
+        }
+    }
+
+    /// Read back the country with `slug` if it is still on disk.
+    pub async fn hydrate_country(&self, world: &World, slug: &str) -> io::Result<()> {
+        #[cfg(feature = "sqlite")]
+        return self.hydrate(world, Some(slug)).await;
+        #[cfg(not(feature = "sqlite"))]
+        {
+            let _ = (world, slug);
+            Ok(())
+        }
+    }
+
+    /// Read back every country still on disk, for runs and saves that
+    /// need the whole world.
+    pub async fn hydrate_all(&self, world: &World) -> io::Result<()> {
+        #[cfg(feature = "sqlite")]
+        return self.hydrate(world, None).await;
+        #[cfg(not(feature = "sqlite"))]
+        {
+            let _ = world;
+            Ok(())
+        }
+    }
+
+    /// Countries of the current world still on disk.
+    pub async fn stored_countries(&self) -> Vec<StoredCountry> {
+        #[cfg(feature = "sqlite")]
+        if let Some(stored) = self.stored.lock().await.as_ref() {
+            return stored.pending().copied().collect();
+        }
+        Vec::new()
+    }
+
+    /// Read back the country with `slug`, or every pending one for `None`.
+    #[cfg(feature = "sqlite")]
+    async fn hydrate(&self, world: &World, slug: Option<&str>) -> io::Result<()> {
+        let mut guard = self.stored.lock().await;
+        let Some(stored) = guard.as_mut() else {
+            return Ok(());
+        };
+        let country_ids = match slug {
+            Some(slug) => world
+                .read()
+                .await
+                .as_ref()
+                .and_then(|data| data.country_info.values().find(|c| c.slug == slug))
+                .map(|c| c.id)
+                .filter(|id| stored.is_pending(*id))
+                .into_iter()
+                .collect(),
+            None => stored.pending_ids(),
+        };
+        if country_ids.is_empty() {
+            return Ok(());
+        }
+
+        let path = stored.path().to_path_buf();
+        let slot = stored.slot().to_string();
+        let countries =
+            spawn_blocking(move || super::save_sqlite::read_countries(&path, &slot, &country_ids))
+                .await
+                .map_err(io::Error::other)??;
+
+        if let Some(data) = world.write().await.as_mut() {
+            stored.restore(Arc::make_mut(data), countries);
+        }
+        if stored.is_empty() {
+            *guard = None;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn routes_need_their_country_everything_or_nothing() {
+        assert_eq!(
+            CountryNeed::of(
+                Some("/{lang}/countries/{country_slug}/leagues"),
+                "/en/countries/spain/leagues"
+            ),
+            CountryNeed::Country("spain".to_string())
+        );
+        // National squads and staff come from clubs and countries abroad.
+        assert_eq!(
+            CountryNeed::of(
+                Some("/{lang}/countries/{country_slug}/u21"),
+                "/en/countries/spain/u21"
+            ),
+            CountryNeed::All
+        );
+        assert_eq!(
+            CountryNeed::of(
+                Some("/api/v1/countries/{country_slug}"),
+                "/api/v1/countries/italy"
+            ),
+            CountryNeed::Country("italy".to_string())
+        );
+        assert_eq!(
+            CountryNeed::of(Some("/{lang}/teams/{team_slug}"), "/en/teams/arsenal"),
+            CountryNeed::All
+        );
+        assert_eq!(
+            CountryNeed::of(Some("/api/v1/game/advance"), "/api/v1/game/advance"),
+            CountryNeed::All
+        );
+        assert_eq!(CountryNeed::of(Some("/{lang}"), "/en"), CountryNeed::None);
+        assert_eq!(CountryNeed::of(None, "/static/app.css"), CountryNeed::None);
+    }
+}
@@ -1,7 +1,19 @@
+mod calendar;
 mod create;
+mod export;
+mod hydrate;
 mod process;
+mod progress;
 pub mod routes;
+mod save;
+#[cfg(feature = "sqlite")]
+mod save_sqlite;
 
+pub use calendar::*;
 pub use create::*;
+pub use export::*;
+pub use hydrate::*;
 pub use process::*;
+pub use progress::*;
 pub use routes::*;
+pub use save::*;
@@ -1,10 +1,17 @@
+use crate::ApiError;
+use crate::ApiQuery;
 use crate::GameAppData;
 use crate::I18nManager;
+use crate::Metrics;
+use crate::admin::{AdminAuth, SimulationControl};
+use crate::game::ProcessProgress;
 use crate::r#match::stores::MatchStore;
+use crate::session::{SessionToken, current_manager};
 use axum::Json;
-use axum::extract::{Query, State};
+use axum::extract::State;
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
+use chrono::NaiveDate;
 use core::FootballSimulator;
 use core::MatchRuntime;
 use core::SimulationResult;
@@ -13,7 +20,7 @@ use log::{debug, error};
 use serde::{Deserialize, Serialize};
 use std::sync::Arc;
 use std::sync::atomic::{AtomicBool, Ordering};
-use std::time::Instant;
+use std::time::{Duration, Instant};
 use tokio::runtime::Handle;
 use tokio::sync::{RwLock, Semaphore};
 use tokio::task::{JoinSet, spawn_blocking};
@@ -23,17 +30,111 @@ pub struct ProcessQuery {
     pub days: Option<u32>,
 }
 
+/// Longest advance one request may ask for: a simulated season.
+const MAX_ADVANCE_DAYS: u32 = 365;
+
+/// How often a run held by a pause checks whether it may go on.
+const PAUSE_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// Everyone plays in the same world, so only the admin token or a
+/// signed-in manager may move its clock or write it to a save slot.
+pub(super) async fn may_advance(
+    state: &GameAppData,
+    admin: &Result<AdminAuth, ApiError>,
+    session: &SessionToken,
+) -> bool {
+    admin.is_ok()
+        || state
+            .data
+            .read()
+            .await
+            .as_ref()
+            .is_some_and(|sim| current_manager(sim, session).is_some())
+}
+
+/// `POST /api/game/process` — fire-and-wait form of
+/// [`game_advance_action`], kept for scripts that only look at the status.
 pub async fn game_process_action(
+    admin: Result<AdminAuth, ApiError>,
+    session: SessionToken,
     State(state): State<GameAppData>,
-    Query(query): Query<ProcessQuery>,
+    ApiQuery(query): ApiQuery<ProcessQuery>,
 ) -> impl IntoResponse {
-    let days = query.days.unwrap_or(1);
+    if !may_advance(&state, &admin, &session).await {
+        return StatusCode::UNAUTHORIZED;
+    }
+    if state.control.is_paused() {
+        return StatusCode::CONFLICT;
+    }
+    let days = query
+        .days
+        .unwrap_or_else(|| state.control.days_per_request())
+        .clamp(1, MAX_ADVANCE_DAYS);
 
     // If already processing, return immediately
-    let process_guard = match Arc::clone(&state.process_lock).try_lock_owned() {
-        Ok(guard) => guard,
-        Err(_) => return StatusCode::OK,
-    };
+    start_processing(&state, days, true)
+        .await
+        .unwrap_or(StatusCode::OK)
+}
+
+#[derive(Serialize)]
+pub struct AdvanceDto {
+    pub days: u32,
+    pub from: NaiveDate,
+    pub to: NaiveDate,
+}
+
+/// `POST /api/v1/game/advance?days=N` — simulate N days (the configured
+/// days-per-request when omitted) and report the dates the world moved
+/// between. 401 without the admin token or a manager session, 409 while
+/// paused or while another run is in progress.
+pub async fn game_advance_action(
+    admin: Result<AdminAuth, ApiError>,
+    session: SessionToken,
+    State(state): State<GameAppData>,
+    ApiQuery(query): ApiQuery<ProcessQuery>,
+) -> Result<Json<AdvanceDto>, StatusCode> {
+    if !may_advance(&state, &admin, &session).await {
+        return Err(StatusCode::UNAUTHORIZED);
+    }
+    if state.control.is_paused() {
+        return Err(StatusCode::CONFLICT);
+    }
+    let days = query
+        .days
+        .unwrap_or_else(|| state.control.days_per_request())
+        .clamp(1, MAX_ADVANCE_DAYS);
+
+    let from = current_date(&state).await.ok_or(StatusCode::NOT_FOUND)?;
+    match start_processing(&state, days, true).await {
+        Some(StatusCode::OK) => {}
+        Some(status) => return Err(status),
+        None => return Err(StatusCode::CONFLICT),
+    }
+    let to = current_date(&state).await.ok_or(StatusCode::NOT_FOUND)?;
+
+    Ok(Json(AdvanceDto { days, from, to }))
+}
+
+async fn current_date(state: &GameAppData) -> Option<NaiveDate> {
+    state.data.read().await.as_ref().map(|sim| sim.date.date())
+}
+
+/// Simulate `days` days and wait for them to finish. `None` when another
+/// run holds the process lock. A run that `honors_pause` holds before each
+/// day while the admin pause is on.
+pub async fn start_processing(
+    state: &GameAppData,
+    days: u32,
+    honors_pause: bool,
+) -> Option<StatusCode> {
+    let process_guard = Arc::clone(&state.process_lock).try_lock_owned().ok()?;
+
+    // Every country is simulated each day.
+    if let Err(err) = state.saves.hydrate_all(&state.data).await {
+        error!("reading countries from the save failed: {err}");
+        return Some(StatusCode::INTERNAL_SERVER_ERROR);
+    }
 
     // Reset cancel flag at start
     state.cancel_flag.store(false, Ordering::SeqCst);
@@ -44,11 +145,22 @@ pub async fn game_process_action(
         Arc::clone(guard.as_ref().unwrap())
     };
 
+    let leagues_per_day = data_arc
+        .continents
+        .iter()
+        .flat_map(|c| &c.countries)
+        .map(|c| c.leagues.leagues.len() as u32)
+        .sum();
+    state.progress.start(days, data_arc.date, leagues_per_day);
+
     let run = ProcessingRun {
         handle: Handle::current(),
         data: Arc::clone(&state.data),
         i18n: Arc::clone(&state.i18n),
         cancel_flag: Arc::clone(&state.cancel_flag),
+        progress: state.progress.clone(),
+        metrics: state.metrics.clone(),
+        control: honors_pause.then(|| state.control.clone()),
     };
 
     // Run CPU-bound simulation on the blocking thread pool so tokio worker
@@ -63,15 +175,17 @@ pub async fn game_process_action(
     })
     .await;
 
+    state.progress.finish();
+
     if let Err(err) = join_result {
         error!("game process task failed: {err}");
-        return StatusCode::INTERNAL_SERVER_ERROR;
+        return Some(StatusCode::INTERNAL_SERVER_ERROR);
     }
 
-    StatusCode::OK
+    Some(StatusCode::OK)
 }
 
-/// One processing run behind `POST /api/game/process`: simulates an owned
+/// One processing run behind `POST /api/v1/game/advance`: simulates an owned
 /// deep copy of the world on a blocking thread and publishes snapshots
 /// into the shared slot without stalling readers.
 struct ProcessingRun {
@@ -79,6 +193,10 @@ struct ProcessingRun {
     data: Arc<RwLock<Option<Arc<SimulatorData>>>>,
     i18n: Arc<I18nManager>,
     cancel_flag: Arc<AtomicBool>,
+    progress: ProcessProgress,
+    metrics: Metrics,
+    /// Set for runs the admin pause applies to.
+    control: Option<SimulationControl>,
 }
 
 impl ProcessingRun {
@@ -88,17 +206,23 @@ impl ProcessingRun {
         let mut days_since_sync: u32 = 0;
 
         for _ in 0..days {
+            self.wait_while_paused();
+
             // Check cancellation before each day
             if self.cancel_flag.load(Ordering::SeqCst) {
                 break;
             }
 
+            let started = Instant::now();
             let result = self
                 .handle
                 .block_on(FootballSimulator::simulate(&mut simulator_data));
+            self.metrics
+                .record_day(started.elapsed(), result.match_results.len());
             if result.has_match_results() && MatchRuntime::recordings_mode() {
                 self.handle.block_on(Self::write_match_results(result));
             }
+            self.progress.day_done(simulator_data.date);
 
             // During multi-day runs (e.g. holiday), publish progress every
             // simulated week so the UI and readers observe intermediate state
@@ -114,6 +238,16 @@ impl ProcessingRun {
         self.publish_final(simulator_data);
     }
 
+    /// Block while the admin pause is on; cancelling ends the wait.
+    fn wait_while_paused(&self) {
+        let Some(control) = &self.control else {
+            return;
+        };
+        while control.is_paused() && !self.cancel_flag.load(Ordering::SeqCst) {
+            std::thread::sleep(PAUSE_POLL_INTERVAL);
+        }
+    }
+
     /// Publish an intermediate snapshot and hand back an owned working copy.
     ///
     /// The working copy is swapped in first and the outgoing world freed
@@ -0,0 +1,145 @@
+use crate::GameAppData;
+use axum::extract::State;
+use axum::response::sse::{Event, KeepAlive, Sse};
+use chrono::NaiveDateTime;
+use core::{ProgressCounters, SimulationProgress};
+use futures_util::Stream;
+use futures_util::stream;
+use serde::Serialize;
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+/// How often the SSE stream samples the running process.
+const POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// State of the current (or last) processing run, written by the blocking
+/// simulation thread and sampled by `/api/game/progress`. Cloneable,
+/// Arc-backed (mirrors `AiJobs`).
+#[derive(Clone, Default)]
+pub struct ProcessProgress {
+    inner: Arc<Mutex<RunState>>,
+}
+
+#[derive(Default)]
+struct RunState {
+    running: bool,
+    days_total: u32,
+    days_done: u32,
+    /// League passes per simulated day, for the in-day share of `percent`.
+    leagues_per_day: u32,
+    date: Option<NaiveDateTime>,
+    baseline: ProgressCounters,
+}
+
+#[derive(Serialize, Clone, PartialEq)]
+pub struct ProgressEvent {
+    pub running: bool,
+    pub days_total: u32,
+    pub days_done: u32,
+    /// Simulation date of the day being processed, `YYYY-MM-DD`.
+    pub date: String,
+    pub leagues_processed: u64,
+    pub matches_simulated: u64,
+    /// Whole days done plus the league share of the day in progress.
+    pub percent: u8,
+}
+
+impl ProcessProgress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Begin a run of `days` days starting at `date`.
+    pub fn start(&self, days: u32, date: NaiveDateTime, leagues_per_day: u32) {
+        let mut state = self.inner.lock().unwrap();
+        *state = RunState {
+            running: true,
+            days_total: days,
+            days_done: 0,
+            leagues_per_day,
+            date: Some(date),
+            baseline: SimulationProgress::counters(),
+        };
+    }
+
+    /// One day finished; `date` is the next day to simulate.
+    pub fn day_done(&self, date: NaiveDateTime) {
+        let mut state = self.inner.lock().unwrap();
+        state.days_done += 1;
+        state.date = Some(date);
+    }
+
+    pub fn finish(&self) {
+        self.inner.lock().unwrap().running = false;
+    }
+
+    pub fn snapshot(&self) -> ProgressEvent {
+        let state = self.inner.lock().unwrap();
+        let done = SimulationProgress::counters().since(state.baseline);
+        ProgressEvent {
+            running: state.running,
+            days_total: state.days_total,
+            days_done: state.days_done,
+            date: state
+                .date
+                .map(|d| d.format("%Y-%m-%d").to_string())
+                .unwrap_or_default(),
+            leagues_processed: done.leagues_processed,
+            matches_simulated: done.matches_simulated,
+            percent: state.percent(done.leagues_processed),
+        }
+    }
+}
+
+impl RunState {
+    fn percent(&self, leagues_processed: u64) -> u8 {
+        if self.days_total == 0 {
+            return 0;
+        }
+        let in_day = if self.leagues_per_day > 0 {
+            let today = leagues_processed
+                .saturating_sub(self.days_done as u64 * self.leagues_per_day as u64);
+            (today as f32 / self.leagues_per_day as f32).min(1.0)
+        } else {
+            0.0
+        };
+        let days = (self.days_done as f32 + in_day).min(self.days_total as f32);
+        (days / self.days_total as f32 * 100.0).round() as u8
+    }
+}
+
+/// `GET /api/game/progress` — server-sent `progress` events while a
+/// processing run is active, then one `done` event and the stream closes.
+/// Connecting while idle yields the `done` event straight away.
+pub async fn game_progress_action(
+    State(state): State<GameAppData>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let progress = state.progress.clone();
+
+    let events = stream::unfold(
+        (progress, None::<ProgressEvent>, false),
+        |(progress, last, finished)| async move {
+            if finished {
+                return None;
+            }
+            loop {
+                let current = progress.snapshot();
+                if !current.running {
+                    let event = Event::default().event("done").json_data(&current).ok()?;
+                    return Some((Ok(event), (progress, Some(current), true)));
+                }
+                if last.as_ref() != Some(&current) {
+                    let event = Event::default()
+                        .event("progress")
+                        .json_data(&current)
+                        .ok()?;
+                    return Some((Ok(event), (progress, Some(current), false)));
+                }
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        },
+    );
+
+    Sse::new(events).keep_alive(KeepAlive::default())
+}
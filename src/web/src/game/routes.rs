@@ -1,6 +1,8 @@
 use crate::GameAppData;
 use crate::game::{
-    game_cancel_action, game_create_action, game_process_action, game_processing_status_action,
+    game_advance_action, game_calendar_action, game_calendar_page_action, game_cancel_action,
+    game_create_action, game_process_action, game_processing_status_action, game_progress_action,
+    game_save_action, game_saves_action,
 };
 use axum::Router;
 use axum::routing::{get, post};
@@ -8,7 +10,13 @@ use axum::routing::{get, post};
 pub fn game_routes() -> Router<GameAppData> {
     Router::new()
         .route("/api/game/create", get(game_create_action))
+        .route("/api/v1/game/advance", post(game_advance_action))
         .route("/api/game/process", post(game_process_action))
+        .route("/api/v1/game/calendar", get(game_calendar_action))
+        .route("/{lang}/game/calendar", get(game_calendar_page_action))
         .route("/api/game/processing", get(game_processing_status_action))
+        .route("/api/game/progress", get(game_progress_action))
         .route("/api/game/cancel", post(game_cancel_action))
+        .route("/api/v1/game/save", post(game_save_action))
+        .route("/api/v1/game/saves", get(game_saves_action))
 }
@@ -0,0 +1,541 @@
+//! Named save slots. Each slot is one file in the saves directory
+//! (`saves/` unless configured otherwise):
+//!
+//! ```text
+//! [magic "OFSV"] [u32 format version, little-endian]
+//! [u32 header length, little-endian] [MessagePack SaveInfo]
+//! [gzip(MessagePack SimulatorData)]
+//! ```
+//!
+//! The header sits outside the compressed body so `/api/v1/game/saves` can
+//! list slots without inflating whole worlds. Lookup indexes are not
+//! stored; they are rebuilt after a load.
+//!
+//! Structs are written as maps keyed by field name and enum variants by
+//! name, so the layout can grow without breaking saves: a field added
+//! with `#[serde(default)]` reads as its default from an older save, and
+//! fields an older build doesn't know are skipped. Saves whose format
+//! version differs from `SAVE_FORMAT_VERSION` are refused.
+
+use super::process::may_advance;
+use crate::admin::AdminAuth;
+use crate::session::SessionToken;
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use chrono::{Local, NaiveDateTime};
+use core::SimulatorData;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use log::info;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::task::spawn_blocking;
+
+const SAVES_DIRECTORY: &str = "saves";
+const SAVE_EXTENSION: &str = "ofs";
+const SAVE_MAGIC: &[u8; 4] = b"OFSV";
+
+/// Saves in any other format are refused up-front instead of failing
+/// half-way through the body.
+pub const SAVE_FORMAT_VERSION: u32 = 1;
+
+/// Guards against allocating for a garbage length in a damaged file.
+const MAX_HEADER_LENGTH: usize = 64 * 1024;
+
+const MAX_SLOT_LENGTH: usize = 64;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SaveInfo {
+    pub slot: String,
+    pub format_version: u32,
+    /// Version of the build that wrote the save.
+    pub app_version: String,
+    /// In-game date of the saved world.
+    pub game_date: NaiveDateTime,
+    /// Wall-clock time the save was written.
+    pub saved_at: NaiveDateTime,
+}
+
+/// Save slot files in one directory, or with the `sqlite` feature, slots
+/// in one SQLite database (see `save_sqlite`).
+#[derive(Clone)]
+pub struct SaveStore {
+    directory: PathBuf,
+    #[cfg(feature = "sqlite")]
+    pub(super) database: Option<PathBuf>,
+    /// Countries of the slot last opened from `database` not yet read
+    /// back, shared by every clone of the store.
+    #[cfg(feature = "sqlite")]
+    pub(super) stored: Arc<tokio::sync::Mutex<Option<super::save_sqlite::StoredCountries>>>,
+}
+
+impl SaveStore {
+    pub fn new(directory: impl Into<PathBuf>) -> Self {
+        SaveStore {
+            directory: directory.into(),
+            #[cfg(feature = "sqlite")]
+            database: None,
+            #[cfg(feature = "sqlite")]
+            stored: Arc::default(),
+        }
+    }
+
+    /// Slots kept in the SQLite database at `path`, created on first save.
+    #[cfg(feature = "sqlite")]
+    pub fn sqlite(path: impl Into<PathBuf>) -> Self {
+        SaveStore {
+            directory: PathBuf::new(),
+            database: Some(path.into()),
+            stored: Arc::default(),
+        }
+    }
+
+    /// Write `data` to `slot`, replacing an existing save of that name.
+    /// The file is written next to the target and renamed over it, so a
+    /// failed write never destroys the previous save.
+    pub fn save(&self, slot: &str, data: &SimulatorData) -> io::Result<SaveInfo> {
+        validate_slot(slot)?;
+
+        let info = SaveInfo {
+            slot: slot.to_string(),
+            format_version: SAVE_FORMAT_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").to_string(),
+            game_date: data.date,
+            saved_at: Local::now().naive_local(),
+        };
+
+        #[cfg(feature = "sqlite")]
+        if let Some(database) = &self.database {
+            super::save_sqlite::save(database, &info, data)?;
+            return Ok(info);
+        }
+
+        fs::create_dir_all(&self.directory)?;
+        let path = self.slot_path(slot);
+        let partial = path.with_extension(format!("{}.partial", SAVE_EXTENSION));
+
+        // A failed save leaves no partial file behind.
+        if let Err(err) =
+            write_save(&partial, &info, data).and_then(|()| fs::rename(&partial, &path))
+        {
+            let _ = fs::remove_file(&partial);
+            return Err(err);
+        }
+        Ok(info)
+    }
+
+    /// Read the world saved in `slot`, with its lookup indexes rebuilt.
+    pub fn load(&self, slot: &str) -> io::Result<(SaveInfo, SimulatorData)> {
+        validate_slot(slot)?;
+
+        #[cfg(feature = "sqlite")]
+        if let Some(database) = &self.database {
+            return super::save_sqlite::load(database, slot);
+        }
+
+        let mut reader = BufReader::new(File::open(self.slot_path(slot))?);
+        let info = read_header(&mut reader)?;
+
+        let mut decoder = BufReader::new(GzDecoder::new(reader));
+        let mut data: SimulatorData =
+            rmp_serde::decode::from_read(&mut decoder).map_err(invalid_data)?;
+        data.restore_indexes();
+
+        Ok((info, data))
+    }
+
+    /// Headers of every readable save, newest first. Files that are not
+    /// saves, or were written by a newer build, are skipped.
+    pub fn list(&self) -> io::Result<Vec<SaveInfo>> {
+        #[cfg(feature = "sqlite")]
+        if let Some(database) = &self.database {
+            return super::save_sqlite::list(database);
+        }
+
+        let entries = match fs::read_dir(&self.directory) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(err) => return Err(err),
+        };
+
+        let mut saves: Vec<SaveInfo> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.extension().is_some_and(|ext| ext == SAVE_EXTENSION))
+            .filter_map(|path| {
+                let mut reader = BufReader::new(File::open(path).ok()?);
+                read_header(&mut reader).ok()
+            })
+            .collect();
+
+        saves.sort_by_key(|save| std::cmp::Reverse(save.saved_at));
+        Ok(saves)
+    }
+
+    fn slot_path(&self, slot: &str) -> PathBuf {
+        self.directory.join(format!("{}.{}", slot, SAVE_EXTENSION))
+    }
+}
+
+impl Default for SaveStore {
+    fn default() -> Self {
+        SaveStore::new(SAVES_DIRECTORY)
+    }
+}
+
+/// Slot names become file names: letters, digits, `-` and `_` only.
+pub(super) fn validate_slot(slot: &str) -> io::Result<()> {
+    let valid = !slot.is_empty()
+        && slot.len() <= MAX_SLOT_LENGTH
+        && slot
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+
+    if valid {
+        Ok(())
+    } else {
+        Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "invalid slot name '{}': use up to {} letters, digits, '-' or '_'",
+                slot, MAX_SLOT_LENGTH
+            ),
+        ))
+    }
+}
+
+/// The header of a save, leaving `reader` at the body.
+/// Write the header and the gzipped world of a file save to `path`.
+fn write_save(path: &Path, info: &SaveInfo, data: &SimulatorData) -> io::Result<()> {
+    let header = rmp_serde::to_vec_named(info).map_err(invalid_data)?;
+
+    let mut writer = BufWriter::new(File::create(path)?);
+    writer.write_all(SAVE_MAGIC)?;
+    writer.write_all(&SAVE_FORMAT_VERSION.to_le_bytes())?;
+    writer.write_all(&(header.len() as u32).to_le_bytes())?;
+    writer.write_all(&header)?;
+
+    let mut encoder = BufWriter::new(GzEncoder::new(writer, Compression::default()));
+    rmp_serde::encode::write_named(&mut encoder, data).map_err(invalid_data)?;
+    encoder
+        .into_inner()
+        .map_err(|e| e.into_error())?
+        .finish()?
+        .flush()
+}
+
+fn read_header<R: Read>(reader: &mut R) -> io::Result<SaveInfo> {
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if &magic != SAVE_MAGIC {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "not an open-football save",
+        ));
+    }
+
+    let mut version = [0u8; 4];
+    reader.read_exact(&mut version)?;
+    let version = u32::from_le_bytes(version);
+    if version != SAVE_FORMAT_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "save format {} is not supported (this build reads {})",
+                version, SAVE_FORMAT_VERSION
+            ),
+        ));
+    }
+
+    let mut length = [0u8; 4];
+    reader.read_exact(&mut length)?;
+    let length = u32::from_le_bytes(length) as usize;
+    if length > MAX_HEADER_LENGTH {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("save header of {} bytes is too long", length),
+        ));
+    }
+    let mut header = vec![0u8; length];
+    reader.read_exact(&mut header)?;
+    rmp_serde::from_slice(&header).map_err(invalid_data)
+}
+
+pub(super) fn invalid_data<E: Into<Box<dyn std::error::Error + Send + Sync>>>(err: E) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, err)
+}
+
+fn save_error(err: io::Error) -> ApiError {
+    match err.kind() {
+        io::ErrorKind::NotFound => ApiError::NotFound("Save slot not found".to_string()),
+        io::ErrorKind::InvalidInput => ApiError::invalid("slot", err.to_string()),
+        io::ErrorKind::InvalidData => ApiError::BadRequest(err.to_string()),
+        _ => ApiError::from(err),
+    }
+}
+
+fn join_error(err: tokio::task::JoinError) -> ApiError {
+    ApiError::InternalError(format!("save task failed: {}", err))
+}
+
+#[derive(Deserialize)]
+pub struct SlotQuery {
+    pub slot: String,
+}
+
+/// `POST /api/v1/game/save?slot=` — write the current world to a slot.
+/// It can overwrite any slot, so it takes the admin token or a manager
+/// session, as advancing the clock does.
+pub async fn game_save_action(
+    admin: Result<AdminAuth, ApiError>,
+    session: SessionToken,
+    State(state): State<GameAppData>,
+    ApiQuery(query): ApiQuery<SlotQuery>,
+) -> ApiResult<impl IntoResponse> {
+    if !may_advance(&state, &admin, &session).await {
+        return Err(ApiError::Unauthorized(
+            "Saving takes the admin token or a manager session".to_string(),
+        ));
+    }
+
+    // Held for the whole write so a processing run can't start mid-save.
+    let _process_guard = Arc::clone(&state.process_lock)
+        .try_lock_owned()
+        .map_err(|_| ApiError::Conflict("Game is processing".to_string()))?;
+
+    // A save holds every country, including those not read back yet.
+    state.saves.hydrate_all(&state.data).await?;
+    let data = {
+        let guard = state.data.read().await;
+        Arc::clone(
+            guard
+                .as_ref()
+                .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?,
+        )
+    };
+
+    let store = state.saves.clone();
+    let info = spawn_blocking(move || store.save(&query.slot, &data))
+        .await
+        .map_err(join_error)?
+        .map_err(save_error)?;
+
+    info!("game saved to slot '{}' ({})", info.slot, info.game_date);
+    Ok(Json(info))
+}
+
+/// `POST /admin/load?slot=` — replace the current world with a saved one.
+/// Admin only: it swaps the world every manager is playing in.
+pub async fn game_load_action(
+    _: AdminAuth,
+    State(state): State<GameAppData>,
+    ApiQuery(query): ApiQuery<SlotQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let _process_guard = Arc::clone(&state.process_lock)
+        .try_lock_owned()
+        .map_err(|_| ApiError::Conflict("Game is processing".to_string()))?;
+
+    let (info, previous) = state
+        .saves
+        .open(&query.slot, &state.data)
+        .await
+        .map_err(save_error)?;
+
+    state.i18n.set_date(info.game_date);
+    // Free the outgoing world off-lock and off the async workers.
+    spawn_blocking(move || drop(previous));
+
+    info!("game loaded from slot '{}' ({})", info.slot, info.game_date);
+    Ok(Json(info))
+}
+
+/// `GET /api/v1/game/saves` — every save slot on disk, newest first.
+pub async fn game_saves_action(State(state): State<GameAppData>) -> ApiResult<impl IntoResponse> {
+    let store = state.saves.clone();
+    let saves = spawn_blocking(move || store.list())
+        .await
+        .map_err(join_error)??;
+
+    Ok(Json(saves))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::fixture::WorldFixture;
+    use chrono::NaiveDate;
+    use core::competitions::GlobalCompetitions;
+    use core::{PlayerAttributes, PlayerClubContract, PlayerPositionType, WatchlistEntry};
+
+    fn store(name: &str) -> SaveStore {
+        let directory = std::env::temp_dir().join(format!(
+            "open-football-saves-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&directory);
+        SaveStore::new(directory)
+    }
+
+    fn world() -> SimulatorData {
+        SimulatorData::new(
+            NaiveDate::from_ymd_opt(2026, 8, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+            Vec::new(),
+            GlobalCompetitions::new(Vec::new()),
+        )
+    }
+
+    #[test]
+    fn saved_world_loads_back_and_is_listed() {
+        let store = store("roundtrip");
+        let mut data = world();
+        data.watchlist.add(WatchlistEntry {
+            player_id: 42,
+            added_on: data.date.date(),
+            value_when_added: 1_000_000.0,
+            contract_expiry_when_added: None,
+            salary_when_added: None,
+        });
+
+        let saved = store.save("career-1", &data).unwrap();
+        let (loaded_info, loaded) = store.load("career-1").unwrap();
+
+        assert_eq!(saved, loaded_info);
+        assert_eq!(loaded.date, data.date);
+        assert_eq!(loaded.watchlist.entries(), data.watchlist.entries());
+        assert!(loaded.indexes.is_some());
+        assert_eq!(store.list().unwrap(), vec![saved]);
+    }
+
+    #[test]
+    fn populated_world_keeps_its_country_club_and_player() {
+        let store = store("populated");
+        let mut player = WorldFixture::player(
+            PlayerPositionType::Striker,
+            PlayerAttributes {
+                current_ability: 120,
+                potential_ability: 150,
+                ..PlayerAttributes::default()
+            },
+        );
+        player.contract = Some(PlayerClubContract::new(
+            250_000,
+            NaiveDate::from_ymd_opt(2029, 6, 30).unwrap(),
+        ));
+        let data = WorldFixture::world(player, NaiveDate::from_ymd_opt(2026, 8, 1).unwrap());
+
+        store.save("populated", &data).unwrap();
+        let (_, loaded) = store.load("populated").unwrap();
+
+        let country = &loaded.continents[0].countries[0];
+        assert_eq!(country.slug, "england");
+        assert_eq!(country.leagues.leagues[0].slug, "premier");
+        assert_eq!(loaded.club(100).unwrap().name, "Club");
+        let team = loaded.team(10).unwrap();
+        assert_eq!(team.slug, "main");
+
+        // Found through the rebuilt indexes, not just present in the tree.
+        let player = loaded.player(1).unwrap();
+        assert_eq!(
+            player.full_name.to_string(),
+            data.player(1).unwrap().full_name.to_string()
+        );
+        assert_eq!(player.player_attributes.current_ability, 120);
+        assert_eq!(player.player_attributes.potential_ability, 150);
+        assert_eq!(player.contract.as_ref().unwrap().salary, 250_000);
+        assert_eq!(loaded.player_with_team(1).map(|(_, t)| t.id), Some(10));
+    }
+
+    #[test]
+    fn rejects_unsafe_slot_names_and_foreign_files() {
+        let store = store("invalid");
+        let data = world();
+
+        for slot in ["", "../escape", "with space", &"x".repeat(65)] {
+            let err = store.save(slot, &data).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        }
+
+        fs::create_dir_all(&store.directory).unwrap();
+        fs::write(store.slot_path("junk"), b"not a save").unwrap();
+        let err = store.load("junk").map(|_| ()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(store.list().unwrap().is_empty());
+
+        let err = store.load("missing").map(|_| ()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn failed_save_leaves_no_partial_file() {
+        let store = store("partial");
+        // A directory in the slot's place makes the final rename fail.
+        fs::create_dir_all(store.slot_path("blocked")).unwrap();
+
+        assert!(store.save("blocked", &world()).is_err());
+        let partial = store
+            .slot_path("blocked")
+            .with_extension(format!("{}.partial", SAVE_EXTENSION));
+        assert!(!partial.exists());
+    }
+
+    /// Writes a slot file by hand: `header` after the magic and version,
+    /// then `body` gzip-compressed.
+    fn write_raw(store: &SaveStore, slot: &str, version: u32, header: &[u8], body: &[u8]) {
+        fs::create_dir_all(&store.directory).unwrap();
+        let mut bytes = SAVE_MAGIC.to_vec();
+        bytes.extend_from_slice(&version.to_le_bytes());
+        bytes.extend_from_slice(header);
+        let mut encoder = GzEncoder::new(bytes, Compression::default());
+        encoder.write_all(body).unwrap();
+        fs::write(store.slot_path(slot), encoder.finish().unwrap()).unwrap();
+    }
+
+    #[test]
+    fn unknown_header_fields_are_skipped_and_other_formats_refused() {
+        #[derive(Serialize)]
+        struct NewerSaveInfo {
+            slot: String,
+            format_version: u32,
+            app_version: String,
+            game_date: NaiveDateTime,
+            saved_at: NaiveDateTime,
+            autosave: bool,
+        }
+
+        let store = store("forward");
+        let data = world();
+        let header = rmp_serde::to_vec_named(&NewerSaveInfo {
+            slot: "grown".to_string(),
+            format_version: SAVE_FORMAT_VERSION,
+            app_version: "9.9.9".to_string(),
+            game_date: data.date,
+            saved_at: data.date,
+            autosave: true,
+        })
+        .unwrap();
+        let mut framed = (header.len() as u32).to_le_bytes().to_vec();
+        framed.extend_from_slice(&header);
+        let body = rmp_serde::to_vec_named(&data).unwrap();
+
+        write_raw(&store, "grown", SAVE_FORMAT_VERSION, &framed, &body);
+        let (info, _) = store.load("grown").unwrap();
+        assert_eq!(info.app_version, "9.9.9");
+
+        for (slot, version) in [("future", SAVE_FORMAT_VERSION + 1), ("unknown", 0)] {
+            write_raw(&store, slot, version, &framed, &body);
+            let err = store.load(slot).map(|_| ()).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        }
+        assert_eq!(store.list().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,475 @@
+//! Save slots in a single SQLite database, enabled by the `sqlite` feature
+//! and `--saves-db=`. A slot is one row for the world without its
+//! countries plus one row per country, keyed by its id:
+//!
+//! ```text
+//! saves          (slot, format_version, info, world)
+//! save_countries (slot, country_id, position, leagues, clubs, players, body)
+//! ```
+//!
+//! `info` is MessagePack like a save file header; `world` and every
+//! country `body` are gzip-compressed MessagePack. Countries, which hold
+//! nearly all of a world, are encoded and decoded in parallel, and a slot
+//! is replaced inside one transaction, so a failed save keeps the previous
+//! one.
+//!
+//! Loading a slot for play decodes `world` only. Its countries stay on
+//! disk as [`StoredCountries`] until a page asks for one; the `leagues`,
+//! `clubs` and `players` counts let the country list show them meanwhile.
+//! Advancing the clock or saving again needs every country, so both
+//! hydrate the rest first (see `hydrate`).
+
+use super::hydrate::StoredCountry;
+use super::save::{SAVE_FORMAT_VERSION, SaveInfo, invalid_data};
+use core::SimulatorData;
+use core::country::Country;
+use flate2::Compression;
+use flate2::read::GzDecoder;
+use flate2::write::GzEncoder;
+use rayon::prelude::*;
+use rusqlite::{Connection, OptionalExtension, params};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::HashMap;
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS saves (
+        slot TEXT PRIMARY KEY,
+        format_version INTEGER NOT NULL,
+        info BLOB NOT NULL,
+        world BLOB NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS save_countries (
+        slot TEXT NOT NULL,
+        country_id INTEGER NOT NULL,
+        position INTEGER NOT NULL,
+        leagues INTEGER NOT NULL,
+        clubs INTEGER NOT NULL,
+        players INTEGER NOT NULL,
+        body BLOB NOT NULL,
+        PRIMARY KEY (slot, country_id)
+    );";
+
+/// Countries of a loaded slot that are still on disk, and where each
+/// country of the slot goes back in its continent.
+pub(super) struct StoredCountries {
+    path: PathBuf,
+    slot: String,
+    /// Save order of every country of the slot, hydrated or not.
+    positions: HashMap<u32, u32>,
+    pending: HashMap<u32, StoredCountry>,
+}
+
+impl StoredCountries {
+    pub(super) fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub(super) fn slot(&self) -> &str {
+        &self.slot
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+
+    pub(super) fn is_pending(&self, country_id: u32) -> bool {
+        self.pending.contains_key(&country_id)
+    }
+
+    /// Ids of the countries still on disk, in save order.
+    pub(super) fn pending_ids(&self) -> Vec<u32> {
+        let mut ids: Vec<u32> = self.pending.keys().copied().collect();
+        ids.sort_by_key(|id| self.positions[id]);
+        ids
+    }
+
+    pub(super) fn pending(&self) -> impl Iterator<Item = &StoredCountry> {
+        self.pending.values()
+    }
+
+    /// Put `countries`, read back with [`read_countries`], into `data` at
+    /// their saved place among the countries already there, and rebuild
+    /// the lookup indexes.
+    pub(super) fn restore(&mut self, data: &mut SimulatorData, countries: Vec<Country>) {
+        for country in countries {
+            self.pending.remove(&country.id);
+            let position = self.positions.get(&country.id).copied();
+            let Some(continent) = data
+                .continents
+                .iter_mut()
+                .find(|c| c.id == country.continent_id)
+            else {
+                continue;
+            };
+            let index = continent
+                .countries
+                .iter()
+                .position(|c| self.positions.get(&c.id).copied() > position)
+                .unwrap_or(continent.countries.len());
+            continent.countries.insert(index, country);
+        }
+        data.restore_indexes();
+    }
+}
+
+fn open(path: &Path) -> io::Result<Connection> {
+    if let Some(parent) = path.parent().filter(|p| !p.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)?;
+    }
+    let connection = Connection::open(path).map_err(io::Error::other)?;
+    connection.execute_batch(SCHEMA).map_err(io::Error::other)?;
+    Ok(connection)
+}
+
+pub(super) fn save(path: &Path, info: &SaveInfo, data: &SimulatorData) -> io::Result<()> {
+    let header = rmp_serde::to_vec_named(info).map_err(invalid_data)?;
+    let world = encode(&data.without_countries())?;
+    let countries = data
+        .continents
+        .iter()
+        .flat_map(|c| &c.countries)
+        .collect::<Vec<_>>()
+        .into_par_iter()
+        .map(|country| encode(country).map(|body| (summary(country), body)))
+        .collect::<io::Result<Vec<_>>>()?;
+
+    let mut connection = open(path)?;
+    let transaction = connection.transaction().map_err(io::Error::other)?;
+    transaction
+        .execute("DELETE FROM save_countries WHERE slot = ?1", [&info.slot])
+        .map_err(io::Error::other)?;
+    transaction
+        .execute(
+            "INSERT OR REPLACE INTO saves (slot, format_version, info, world)
+             VALUES (?1, ?2, ?3, ?4)",
+            params![info.slot, info.format_version, header, world],
+        )
+        .map_err(io::Error::other)?;
+    {
+        let mut insert = transaction
+            .prepare(
+                "INSERT INTO save_countries
+                 (slot, country_id, position, leagues, clubs, players, body)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )
+            .map_err(io::Error::other)?;
+        for (position, (country, body)) in (0u32..).zip(&countries) {
+            insert
+                .execute(params![
+                    info.slot,
+                    country.country_id,
+                    position,
+                    country.leagues,
+                    country.clubs,
+                    country.players,
+                    body
+                ])
+                .map_err(io::Error::other)?;
+        }
+    }
+    transaction.commit().map_err(io::Error::other)
+}
+
+/// The world saved in `slot`, whole, with its lookup indexes rebuilt.
+pub(super) fn load(path: &Path, slot: &str) -> io::Result<(SaveInfo, SimulatorData)> {
+    let (info, mut data, stored) = load_world(path, slot)?;
+    let countries = read_countries(path, slot, &stored.pending_ids())?;
+    data.restore_countries(countries);
+    data.restore_indexes();
+    Ok((info, data))
+}
+
+/// The world saved in `slot` without its countries, which are left on
+/// disk. Lookup indexes are not built.
+pub(super) fn load_world(
+    path: &Path,
+    slot: &str,
+) -> io::Result<(SaveInfo, SimulatorData, StoredCountries)> {
+    let connection = open(path)?;
+    let row: Option<(u32, Vec<u8>, Vec<u8>)> = connection
+        .query_row(
+            "SELECT format_version, info, world FROM saves WHERE slot = ?1",
+            [slot],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()
+        .map_err(io::Error::other)?;
+    let Some((version, header, world)) = row else {
+        return Err(io::Error::new(io::ErrorKind::NotFound, "no such slot"));
+    };
+    check_version(version)?;
+
+    let info: SaveInfo = rmp_serde::from_slice(&header).map_err(invalid_data)?;
+    let data: SimulatorData = decode(&world)?;
+
+    let mut query = connection
+        .prepare(
+            "SELECT country_id, position, leagues, clubs, players
+             FROM save_countries WHERE slot = ?1",
+        )
+        .map_err(io::Error::other)?;
+    let rows = query
+        .query_map([slot], |row| {
+            let country = StoredCountry {
+                country_id: row.get(0)?,
+                leagues: row.get(2)?,
+                clubs: row.get(3)?,
+                players: row.get(4)?,
+            };
+            Ok((row.get::<_, u32>(1)?, country))
+        })
+        .map_err(io::Error::other)?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(io::Error::other)?;
+
+    let stored = StoredCountries {
+        path: path.to_path_buf(),
+        slot: slot.to_string(),
+        positions: rows
+            .iter()
+            .map(|(position, country)| (country.country_id, *position))
+            .collect(),
+        pending: rows
+            .into_iter()
+            .map(|(_, country)| (country.country_id, country))
+            .collect(),
+    };
+    Ok((info, data, stored))
+}
+
+/// The countries `country_ids` of `slot`, in the order asked for.
+pub(super) fn read_countries(
+    path: &Path,
+    slot: &str,
+    country_ids: &[u32],
+) -> io::Result<Vec<Country>> {
+    let connection = open(path)?;
+    let mut query = connection
+        .prepare("SELECT body FROM save_countries WHERE slot = ?1 AND country_id = ?2")
+        .map_err(io::Error::other)?;
+    let bodies = country_ids
+        .iter()
+        .map(|id| {
+            query
+                .query_row(params![slot, id], |row| row.get::<_, Vec<u8>>(0))
+                .map_err(io::Error::other)
+        })
+        .collect::<io::Result<Vec<_>>>()?;
+
+    bodies
+        .par_iter()
+        .map(|body| decode::<Country>(body))
+        .collect()
+}
+
+/// Row counts of `country` for the country list.
+fn summary(country: &Country) -> StoredCountry {
+    StoredCountry {
+        country_id: country.id,
+        leagues: country.leagues.leagues.len() as u32,
+        clubs: country.clubs.len() as u32,
+        players: country
+            .clubs
+            .iter()
+            .flat_map(|club| &club.teams.teams)
+            .map(|team| team.players.players.len() as u32)
+            .sum(),
+    }
+}
+
+/// Headers of every slot this build can read, newest first.
+pub(super) fn list(path: &Path) -> io::Result<Vec<SaveInfo>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let connection = open(path)?;
+    let mut query = connection
+        .prepare("SELECT format_version, info FROM saves")
+        .map_err(io::Error::other)?;
+    let rows = query
+        .query_map([], |row| {
+            Ok((row.get::<_, u32>(0)?, row.get::<_, Vec<u8>>(1)?))
+        })
+        .map_err(io::Error::other)?;
+
+    let mut saves: Vec<SaveInfo> = rows
+        .filter_map(|row| row.ok())
+        .filter(|(version, _)| check_version(*version).is_ok())
+        .filter_map(|(_, header)| rmp_serde::from_slice(&header).ok())
+        .collect();
+    saves.sort_by_key(|save| std::cmp::Reverse(save.saved_at));
+    Ok(saves)
+}
+
+fn check_version(version: u32) -> io::Result<()> {
+    if version != SAVE_FORMAT_VERSION {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!(
+                "save format {} is not supported (this build reads {})",
+                version, SAVE_FORMAT_VERSION
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn encode<T: Serialize + ?Sized>(value: &T) -> io::Result<Vec<u8>> {
+    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+    rmp_serde::encode::write_named(&mut encoder, value).map_err(invalid_data)?;
+    encoder.flush()?;
+    encoder.finish()
+}
+
+fn decode<T: DeserializeOwned>(bytes: &[u8]) -> io::Result<T> {
+    rmp_serde::decode::from_read(GzDecoder::new(bytes)).map_err(invalid_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::SaveStore;
+    use crate::game::World;
+    use chrono::NaiveDate;
+    use core::competitions::GlobalCompetitions;
+    use core::continent::Continent;
+    use core::league::LeagueCollection;
+    use tokio::sync::RwLock;
+
+    fn country(id: u32, slug: &str) -> Country {
+        Country::builder()
+            .id(id)
+            .code(slug[..2].to_string())
+            .slug(slug.to_string())
+            .name(slug.to_string())
+            .continent_id(1)
+            .reputation(5000)
+            .leagues(LeagueCollection::new(Vec::new()))
+            .clubs(Vec::new())
+            .build()
+            .unwrap()
+    }
+
+    fn world() -> SimulatorData {
+        let continent = Continent::new(
+            1,
+            "Europe".to_string(),
+            vec![country(7, "england"), country(3, "spain")],
+            Vec::new(),
+        );
+        SimulatorData::new(
+            NaiveDate::from_ymd_opt(2026, 8, 1)
+                .unwrap()
+                .and_hms_opt(12, 0, 0)
+                .unwrap(),
+            vec![continent],
+            GlobalCompetitions::new(Vec::new()),
+        )
+    }
+
+    fn slugs(world: &World) -> Vec<String> {
+        world.blocking_read().as_ref().unwrap().continents[0]
+            .countries
+            .iter()
+            .map(|c| c.slug.clone())
+            .collect()
+    }
+
+    #[test]
+    fn countries_are_stored_apart_and_loaded_back_in_order() {
+        let path =
+            std::env::temp_dir().join(format!("open-football-saves-{}.sqlite", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let store = SaveStore::sqlite(&path);
+        let data = world();
+
+        let saved = store.save("career", &data).unwrap();
+        // Saving again replaces the slot rather than adding rows.
+        store.save("career", &data).unwrap();
+        let stored: u32 = open(&path)
+            .unwrap()
+            .query_row("SELECT COUNT(*) FROM save_countries", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(stored, 2);
+
+        let (info, loaded) = store.load("career").unwrap();
+        assert_eq!(info.slot, saved.slot);
+        let slugs: Vec<&str> = loaded.continents[0]
+            .countries
+            .iter()
+            .map(|c| c.slug.as_str())
+            .collect();
+        assert_eq!(slugs, ["england", "spain"]);
+        assert!(loaded.indexes.is_some());
+        assert_eq!(store.list().unwrap().len(), 1);
+
+        let err = store.load("missing").map(|_| ()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::NotFound);
+
+        // A slot in any other format is refused and left out of the list.
+        open(&path)
+            .unwrap()
+            .execute(
+                "UPDATE saves SET format_version = ?1 WHERE slot = 'career'",
+                [SAVE_FORMAT_VERSION + 1],
+            )
+            .unwrap();
+        let err = store.load("career").map(|_| ()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(store.list().unwrap().is_empty());
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn countries_stay_on_disk_until_a_page_needs_them() {
+        let path =
+            std::env::temp_dir().join(format!("open-football-lazy-{}.sqlite", std::process::id()));
+        let _ = fs::remove_file(&path);
+        let store = SaveStore::sqlite(&path);
+        store.save("career", &world()).unwrap();
+
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .build()
+            .unwrap();
+        let opened: World = RwLock::new(None);
+        let stored_ids = || {
+            let mut ids: Vec<u32> = runtime
+                .block_on(store.stored_countries())
+                .iter()
+                .map(|c| c.country_id)
+                .collect();
+            ids.sort();
+            ids
+        };
+
+        runtime.block_on(store.open("career", &opened)).unwrap();
+        assert!(slugs(&opened).is_empty());
+        assert_eq!(stored_ids(), [3, 7]);
+
+        // Read back on its own, then put in front of it by the rest.
+        runtime
+            .block_on(store.hydrate_country(&opened, "spain"))
+            .unwrap();
+        assert_eq!(slugs(&opened), ["spain"]);
+        assert_eq!(stored_ids(), [7]);
+
+        runtime.block_on(store.hydrate_all(&opened)).unwrap();
+        assert_eq!(slugs(&opened), ["england", "spain"]);
+        assert!(stored_ids().is_empty());
+        let data = opened.blocking_read();
+        let indexes = data.as_ref().unwrap().indexes.as_ref().unwrap();
+        assert!(
+            indexes
+                .slug_indexes
+                .get_country_by_slug("england")
+                .is_some()
+        );
+
+        let _ = fs::remove_file(&path);
+    }
+}
@@ -0,0 +1,129 @@
+//! Probes for orchestrated deployments. `/healthz` (liveness) fails only
+//! when the world lock can't be taken in time, i.e. the process is wedged
+//! and should be restarted; a long processing run does not fail it, since
+//! readers are never locked out for more than a snapshot swap. `/readyz`
+//! (readiness) additionally requires a loaded world with its indexes
+//! built, so traffic is only routed once pages can be served.
+
+pub mod routes;
+
+use crate::GameAppData;
+use axum::Json;
+use axum::extract::State;
+use axum::http::StatusCode;
+use axum::response::IntoResponse;
+use chrono::NaiveDate;
+use core::SimulatorData;
+use serde::Serialize;
+use std::time::Duration;
+use tokio::time::timeout;
+
+pub fn health_routes() -> axum::Router<GameAppData> {
+    routes::routes()
+}
+
+/// How long a probe waits for the world lock before reporting the
+/// process as stuck.
+const LOCK_TIMEOUT: Duration = Duration::from_secs(5);
+
+#[derive(Serialize)]
+pub struct HealthDto {
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'static str>,
+}
+
+#[derive(Serialize)]
+pub struct ReadinessDto {
+    pub status: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<&'static str>,
+    pub game_date: Option<NaiveDate>,
+    /// A processing run is simulating days right now.
+    pub processing: bool,
+}
+
+/// `GET /healthz` — 200 while the world lock can be read, 503 otherwise.
+pub async fn liveness_action(State(state): State<GameAppData>) -> impl IntoResponse {
+    match timeout(LOCK_TIMEOUT, state.data.read()).await {
+        Ok(_) => (
+            StatusCode::OK,
+            Json(HealthDto {
+                status: "ok",
+                reason: None,
+            }),
+        ),
+        Err(_) => (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(HealthDto {
+                status: "unavailable",
+                reason: Some("simulator data lock timed out"),
+            }),
+        ),
+    }
+}
+
+/// `GET /readyz` — 200 once a world is loaded and indexed, 503 otherwise.
+pub async fn readiness_action(State(state): State<GameAppData>) -> impl IntoResponse {
+    let processing = state.process_lock.try_lock().is_err();
+    let not_ready = |reason| {
+        (
+            StatusCode::SERVICE_UNAVAILABLE,
+            Json(ReadinessDto {
+                status: "not_ready",
+                reason: Some(reason),
+                game_date: None,
+                processing,
+            }),
+        )
+    };
+
+    let Ok(guard) = timeout(LOCK_TIMEOUT, state.data.read()).await else {
+        return not_ready("simulator data lock timed out");
+    };
+    let game_date = match ready_date(guard.as_deref()) {
+        Ok(date) => date,
+        Err(reason) => return not_ready(reason),
+    };
+
+    (
+        StatusCode::OK,
+        Json(ReadinessDto {
+            status: "ready",
+            reason: None,
+            game_date: Some(game_date),
+            processing,
+        }),
+    )
+}
+
+/// The game date of a world that can serve pages, or why it can't yet.
+fn ready_date(sim: Option<&SimulatorData>) -> Result<NaiveDate, &'static str> {
+    let sim = sim.ok_or("simulator data not loaded")?;
+    if sim.indexes.is_none() {
+        return Err("indexes not built");
+    }
+    Ok(sim.date.date())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::competitions::global::GlobalCompetitions;
+
+    #[test]
+    fn ready_only_once_a_world_is_loaded_and_indexed() {
+        assert_eq!(ready_date(None), Err("simulator data not loaded"));
+
+        let date = NaiveDate::from_ymd_opt(2026, 8, 1).unwrap();
+        let mut sim = SimulatorData::new(
+            date.and_hms_opt(12, 0, 0).unwrap(),
+            Vec::new(),
+            GlobalCompetitions::new(Vec::new()),
+        );
+        assert_eq!(ready_date(Some(&sim)), Ok(date));
+
+        sim.indexes = None;
+        assert_eq!(ready_date(Some(&sim)), Err("indexes not built"));
+    }
+}
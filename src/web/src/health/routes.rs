@@ -0,0 +1,9 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route("/healthz", get(super::liveness_action))
+        .route("/readyz", get(super::readiness_action))
+}
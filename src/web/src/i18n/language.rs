@@ -0,0 +1,118 @@
+use crate::i18n::{DEFAULT_LANGUAGE, I18nManager, detect_language};
+use crate::session::cookie_value;
+use crate::{ApiError, ApiQuery, ApiResult};
+use axum::http::HeaderMap;
+use axum::http::header::{ACCEPT_LANGUAGE, COOKIE, SET_COOKIE};
+use axum::response::{IntoResponse, Redirect, Response};
+use serde::Deserialize;
+
+/// Cookie remembering the language picked in the switcher. It wins over
+/// `Accept-Language` wherever the URL doesn't carry a language already;
+/// a `/{lang}/...` URL always renders in that language, so links stay
+/// shareable.
+pub const LANGUAGE_COOKIE: &str = "of_lang";
+
+const LANGUAGE_MAX_AGE_SECS: u32 = 365 * 24 * 60 * 60;
+
+/// The request's preferred language: the language cookie when it names a
+/// supported language, else `Accept-Language` negotiation.
+pub fn preferred_language(headers: &HeaderMap) -> String {
+    let chosen = headers
+        .get_all(COOKIE)
+        .iter()
+        .filter_map(|value| value.to_str().ok())
+        .find_map(|header| cookie_value(header, LANGUAGE_COOKIE))
+        .filter(|lang| I18nManager::is_supported_language(lang));
+    chosen.unwrap_or_else(|| {
+        let accept_language = headers
+            .get(ACCEPT_LANGUAGE)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or(DEFAULT_LANGUAGE);
+        detect_language(accept_language)
+    })
+}
+
+/// `path` (with an optional query) moved to `lang`: its language segment is
+/// replaced, or `lang` is prefixed when it has none.
+pub fn localized_path(path: &str, lang: &str) -> String {
+    let (path, query) = match path.split_once('?') {
+        Some((path, query)) => (path, Some(query)),
+        None => (path, None),
+    };
+    let rest = path.trim_start_matches('/');
+    let rest = match rest.split_once('/') {
+        Some((first, tail)) if I18nManager::is_supported_language(first) => tail,
+        None if I18nManager::is_supported_language(rest) => "",
+        _ => rest,
+    };
+
+    let mut localized = format!("/{}", lang);
+    if !rest.is_empty() {
+        localized.push('/');
+        localized.push_str(rest);
+    }
+    if let Some(query) = query {
+        localized.push('?');
+        localized.push_str(query);
+    }
+    localized
+}
+
+#[derive(Deserialize)]
+pub struct LanguageQuery {
+    pub lang: String,
+    /// Page to come back to, in any language.
+    #[serde(rename = "return")]
+    pub return_to: Option<String>,
+}
+
+/// `GET /settings/language?lang=fr&return=/en/teams/x` — remember the
+/// language and go back to the page in it.
+pub async fn language_action(ApiQuery(query): ApiQuery<LanguageQuery>) -> ApiResult<Response> {
+    if !I18nManager::is_supported_language(&query.lang) {
+        return Err(ApiError::invalid(
+            "lang",
+            format!("Unsupported language '{}'", query.lang),
+        ));
+    }
+
+    // Only same-site paths, so the endpoint can't be used as an open redirect
+    let target = query
+        .return_to
+        .filter(|path| path.starts_with('/') && !path.starts_with("//"))
+        .map(|path| localized_path(&path, &query.lang))
+        .unwrap_or_else(|| format!("/{}", query.lang));
+
+    let cookie = format!(
+        "{}={}; Path=/; SameSite=Lax; Max-Age={}",
+        LANGUAGE_COOKIE, query.lang, LANGUAGE_MAX_AGE_SECS
+    );
+    Ok(([(SET_COOKIE, cookie)], Redirect::to(&target)).into_response())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::HeaderValue;
+
+    #[test]
+    fn cookie_overrides_accept_language() {
+        let mut headers = HeaderMap::new();
+        headers.insert(ACCEPT_LANGUAGE, HeaderValue::from_static("de-DE,de;q=0.9"));
+        assert_eq!(preferred_language(&headers), "de");
+
+        headers.insert(COOKIE, HeaderValue::from_static("of_lang=ja"));
+        assert_eq!(preferred_language(&headers), "ja");
+
+        headers.insert(COOKIE, HeaderValue::from_static("of_lang=xx"));
+        assert_eq!(preferred_language(&headers), "de");
+    }
+
+    #[test]
+    fn localized_path_swaps_or_adds_the_language() {
+        assert_eq!(localized_path("/en/teams/x", "fr"), "/fr/teams/x");
+        assert_eq!(localized_path("/en", "fr"), "/fr");
+        assert_eq!(localized_path("/", "fr"), "/fr");
+        assert_eq!(localized_path("/teams/x?tab=2", "fr"), "/fr/teams/x?tab=2");
+    }
+}
@@ -0,0 +1,237 @@
+//! Translation packs loaded at runtime from a locales directory, so
+//! community translations don't need a rebuild. The directory mirrors the
+//! embedded `assets/i18n` layout:
+//!
+//! ```text
+//! locales/
+//!   fr.json            overrides and additions for a built-in language
+//!   it.json            a new language
+//!   countries/it.json  its country names (optional)
+//! ```
+//!
+//! A pack for a built-in language is laid over the embedded bundle; a pack
+//! for a new language is laid over nothing and falls back to English key by
+//! key. A new language names itself with the `language_name` and
+//! `language_flag` keys; without them the code is used for both.
+
+use super::{I18nManager, SUPPORTED_LANGUAGES};
+use log::{info, warn};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, Mutex, RwLock};
+use std::time::{Duration, SystemTime};
+
+pub const LANGUAGE_NAME_KEY: &str = "language_name";
+pub const LANGUAGE_FLAG_KEY: &str = "language_flag";
+
+/// How often a hot-reloading server looks at the locales directory.
+pub const RELOAD_INTERVAL: Duration = Duration::from_secs(5);
+
+pub type Bundle = HashMap<String, String>;
+
+/// (lang_code, flag_code, display_name)
+pub type Language = (&'static str, &'static str, &'static str);
+
+/// Every language the server offers: the built-in ones first, then the
+/// loaded packs.
+static LANGUAGES: LazyLock<RwLock<Vec<Language>>> =
+    LazyLock::new(|| RwLock::new(SUPPORTED_LANGUAGES.to_vec()));
+
+pub fn languages() -> Vec<Language> {
+    LANGUAGES.read().map(|l| l.clone()).unwrap_or_default()
+}
+
+/// Display name of a registered language.
+pub fn language_name(code: &str) -> Option<&'static str> {
+    LANGUAGES
+        .read()
+        .ok()?
+        .iter()
+        .find(|(c, _, _)| *c == code)
+        .map(|(_, _, name)| *name)
+}
+
+pub fn is_language(code: &str) -> bool {
+    LANGUAGES
+        .read()
+        .is_ok_and(|l| l.iter().any(|(c, _, _)| *c == code))
+}
+
+/// Registry strings live for the whole process; interning keeps a reload
+/// from leaking a fresh copy of every name.
+fn intern(value: &str) -> &'static str {
+    static INTERNED: LazyLock<Mutex<HashSet<&'static str>>> =
+        LazyLock::new(|| Mutex::new(HashSet::new()));
+    let mut interned = INTERNED.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(existing) = interned.get(value) {
+        return existing;
+    }
+    let leaked: &'static str = Box::leak(value.to_string().into_boxed_str());
+    interned.insert(leaked);
+    leaked
+}
+
+/// Pack codes are what appears as the first URL segment: two or three
+/// lowercase letters.
+fn is_valid_code(code: &str) -> bool {
+    (2..=3).contains(&code.len()) && code.bytes().all(|b| b.is_ascii_lowercase())
+}
+
+/// Parse a bundle file, tolerating a UTF-8 BOM like the embedded loader.
+pub fn parse_bundle(text: &str) -> Result<Bundle, serde_json::Error> {
+    serde_json::from_str(text.strip_prefix('\u{feff}').unwrap_or(text))
+}
+
+/// `<dir>/*.json` bundles by language code. Unreadable or invalid files
+/// are reported and skipped; a broken community file must not take the
+/// server down.
+fn read_bundles(dir: &Path) -> Vec<(String, Bundle)> {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Vec::new();
+    };
+    let mut bundles = Vec::new();
+    for path in entries.flatten().map(|e| e.path()) {
+        if path.extension().is_none_or(|ext| ext != "json") {
+            continue;
+        }
+        let Some(code) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        if !is_valid_code(code) {
+            warn!(
+                "Skipping locale file {}: not a language code",
+                path.display()
+            );
+            continue;
+        }
+        match fs::read_to_string(&path).map(|text| parse_bundle(&text)) {
+            Ok(Ok(bundle)) => bundles.push((code.to_string(), bundle)),
+            Ok(Err(err)) => warn!("Skipping locale file {}: {}", path.display(), err),
+            Err(err) => warn!("Cannot read locale file {}: {}", path.display(), err),
+        }
+    }
+    bundles.sort_by(|a, b| a.0.cmp(&b.0));
+    bundles
+}
+
+/// `base` with `pack` laid over it.
+fn overlay(base: Option<&Arc<Bundle>>, pack: Bundle) -> Arc<Bundle> {
+    let mut merged = base.map(|b| (**b).clone()).unwrap_or_default();
+    merged.extend(pack);
+    Arc::new(merged)
+}
+
+impl I18nManager {
+    /// Rebuild the effective bundles from the embedded ones and the packs
+    /// in `dir`, and register any new languages. Returns the codes of the
+    /// packs applied. A missing directory just leaves the embedded bundles.
+    pub fn load_locales_dir(&self, dir: &Path) -> Vec<String> {
+        let mut translations = self.embedded_translations.clone();
+        let mut country_names = self.embedded_country_names.clone();
+        let mut registry = SUPPORTED_LANGUAGES.to_vec();
+        let mut loaded = Vec::new();
+
+        for (code, mut pack) in read_bundles(dir) {
+            let name = pack.remove(LANGUAGE_NAME_KEY);
+            let flag = pack.remove(LANGUAGE_FLAG_KEY);
+            if !registry.iter().any(|(c, _, _)| *c == code) {
+                registry.push((
+                    intern(&code),
+                    intern(flag.as_deref().unwrap_or(&code)),
+                    intern(name.as_deref().unwrap_or(&code)),
+                ));
+            }
+            let merged = overlay(translations.get(&code), pack);
+            translations.insert(code.clone(), merged);
+            loaded.push(code);
+        }
+        for (code, pack) in read_bundles(&dir.join("countries")) {
+            if registry.iter().any(|(c, _, _)| *c == code) {
+                let merged = overlay(country_names.get(&code), pack);
+                country_names.insert(code, merged);
+            }
+        }
+
+        if let Ok(mut effective) = self.translations.write() {
+            *effective = translations;
+        }
+        if let Ok(mut effective) = self.country_names.write() {
+            *effective = country_names;
+        }
+        if let Ok(mut languages) = LANGUAGES.write() {
+            *languages = registry;
+        }
+        loaded
+    }
+
+    /// Reload the packs whenever a file in `dir` changes, checking every
+    /// [`RELOAD_INTERVAL`]. Runs for the life of the process.
+    pub fn watch_locales_dir(manager: Arc<I18nManager>, dir: PathBuf) {
+        tokio::spawn(async move {
+            let mut last = fingerprint(&dir);
+            loop {
+                tokio::time::sleep(RELOAD_INTERVAL).await;
+                let current = fingerprint(&dir);
+                if current != last {
+                    let loaded = manager.load_locales_dir(&dir);
+                    info!("Reloaded translation packs: {}", loaded.join(", "));
+                    last = current;
+                }
+            }
+        });
+    }
+}
+
+/// Name, size and modification time of every bundle file, to spot edits.
+fn fingerprint(dir: &Path) -> Vec<(PathBuf, u64, Option<SystemTime>)> {
+    let mut files: Vec<_> = [dir.to_path_buf(), dir.join("countries")]
+        .iter()
+        .filter_map(|d| fs::read_dir(d).ok())
+        .flat_map(|entries| entries.flatten())
+        .filter_map(|entry| {
+            let metadata = entry.metadata().ok()?;
+            Some((entry.path(), metadata.len(), metadata.modified().ok()))
+        })
+        .collect();
+    files.sort();
+    files
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn packs_override_builtins_and_add_languages() {
+        let dir = std::env::temp_dir().join(format!("of_locales_{}", std::process::id()));
+        fs::create_dir_all(dir.join("countries")).unwrap();
+        fs::write(dir.join("fr.json"), r#"{"inbox": "Boîte"}"#).unwrap();
+        fs::write(
+            dir.join("it.json"),
+            r#"{"language_name": "Italiano", "language_flag": "it", "inbox": "Posta"}"#,
+        )
+        .unwrap();
+        fs::write(dir.join("countries").join("it.json"), r#"{"IT": "Italia"}"#).unwrap();
+        fs::write(dir.join("es.json"), "{").unwrap();
+        fs::write(dir.join("notes.json"), "{}").unwrap();
+
+        let manager = I18nManager::new();
+        let loaded = manager.load_locales_dir(&dir);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(loaded, ["fr", "it"]);
+        assert!(is_language("it"));
+
+        let italian = manager.for_lang("it");
+        assert_eq!(italian.t("inbox"), "Posta");
+        assert_eq!(italian.country("IT"), "Italia");
+        assert_eq!(italian.current_name(), "Italiano");
+        // Keys the pack lacks fall back to English
+        assert_eq!(italian.t("search"), manager.for_lang("en").t("search"));
+
+        let french = manager.for_lang("fr");
+        assert_eq!(french.t("inbox"), "Boîte");
+        assert_ne!(french.t("search"), "search");
+    }
+}
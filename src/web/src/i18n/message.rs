@@ -0,0 +1,156 @@
+//! Message formatting on top of the raw bundles: `{name}` arguments and
+//! plural forms. A plural message lists its forms separated by `|`, in
+//! the order of the language's categories (see [`plural_form`]), e.g.
+//! `"{count} goal|{count} goals"` in English and
+//! `"{count} гол|{count} гола|{count} голов"` in Russian. Messages with
+//! fewer forms than the language needs reuse the last one.
+
+/// Index of the plural form `count` takes in `lang`, following the CLDR
+/// cardinal rules for the supported languages:
+///
+/// - `en`, `de`, `es`, `tr`: one (1) | other
+/// - `fr`, `pt`: one (0, 1) | other
+/// - `ru`: one (1, 21, 31…) | few (2–4, 22–24…) | many
+/// - `ja`, `zh`: a single form
+pub fn plural_form(lang: &str, count: i64) -> usize {
+    let n = count.unsigned_abs();
+    match lang {
+        "ja" | "zh" => 0,
+        "fr" | "pt" => usize::from(n > 1),
+        "ru" => {
+            let (n10, n100) = (n % 10, n % 100);
+            if n10 == 1 && n100 != 11 {
+                0
+            } else if (2..=4).contains(&n10) && !(12..=14).contains(&n100) {
+                1
+            } else {
+                2
+            }
+        }
+        _ => usize::from(n != 1),
+    }
+}
+
+/// The form of a `|`-separated plural message for `count`.
+pub fn select_plural<'a>(message: &'a str, lang: &str, count: i64) -> &'a str {
+    let index = plural_form(lang, count);
+    let mut forms = message.split('|');
+    let first = forms.next().unwrap_or_default();
+    std::iter::once(first)
+        .chain(forms)
+        .take(index + 1)
+        .last()
+        .unwrap_or(first)
+}
+
+/// Replace every `{name}` in `template` with its argument. Unknown
+/// placeholders are left as they are, so a missing argument shows up in
+/// the page instead of vanishing.
+pub fn interpolate(template: &str, args: &[(&str, String)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        out.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        let arg = after.find('}').and_then(|end| {
+            let name = &after[..end];
+            args.iter()
+                .find(|(arg, _)| *arg == name)
+                .map(|(_, value)| (end, value))
+        });
+        match arg {
+            Some((end, value)) => {
+                out.push_str(value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                out.push('{');
+                rest = after;
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::i18n::{DEFAULT_LANGUAGE, I18nManager};
+    use std::collections::BTreeSet;
+
+    fn placeholders(message: &str) -> BTreeSet<&str> {
+        message
+            .split('{')
+            .skip(1)
+            .filter_map(|part| part.split_once('}').map(|(name, _)| name))
+            .collect()
+    }
+
+    #[test]
+    fn bundles_agree_with_english_placeholders_and_plural_forms() {
+        let manager = I18nManager::new();
+        let translations = manager.translations.read().unwrap();
+        let english = &translations[DEFAULT_LANGUAGE];
+        for (lang, bundle) in translations.iter() {
+            let max_forms = [0, 1, 2, 5]
+                .iter()
+                .map(|&n| plural_form(lang, n))
+                .max()
+                .unwrap_or(0)
+                + 1;
+            for (key, message) in bundle.iter() {
+                let forms = message.split('|').count();
+                assert!(forms <= max_forms, "{lang}/{key}: {forms} plural forms");
+                if let Some(source) = english.get(key) {
+                    assert!(
+                        placeholders(message).is_subset(&placeholders(source)),
+                        "{lang}/{key}: placeholder not in the English message"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn russian_uses_three_forms() {
+        let message = "{count} гол|{count} гола|{count} голов";
+        let forms: Vec<&str> = [1, 3, 5, 11, 21, 22, 112]
+            .iter()
+            .map(|&n| select_plural(message, "ru", n))
+            .collect();
+        assert_eq!(
+            forms,
+            [
+                "{count} гол",
+                "{count} гола",
+                "{count} голов",
+                "{count} голов",
+                "{count} гол",
+                "{count} гола",
+                "{count} голов"
+            ]
+        );
+    }
+
+    #[test]
+    fn zero_is_singular_only_in_french_and_portuguese() {
+        assert_eq!(select_plural("goal|goals", "en", 0), "goals");
+        assert_eq!(select_plural("but|buts", "fr", 0), "but");
+        assert_eq!(select_plural("gol|gols", "pt", 1), "gol");
+        // Fewer forms than categories: the last one is reused
+        assert_eq!(select_plural("Gol", "tr", 7), "Gol");
+    }
+
+    #[test]
+    fn interpolation_fills_known_placeholders_only() {
+        assert_eq!(
+            interpolate(
+                "{caps} caps / {goals} goals",
+                &[("caps", 12.to_string()), ("goals", 3.to_string())]
+            ),
+            "12 caps / 3 goals"
+        );
+        assert_eq!(interpolate("{missing} {", &[]), "{missing} {");
+    }
+}
@@ -1,9 +1,22 @@
+pub mod language;
+pub mod locales;
+pub mod message;
+pub mod routes;
+
+/// Compile-time-checked translation keys, one constant per key of the
+/// English bundle (e.g. `keys::N_SEASONS`), generated by `build.rs`.
+pub mod keys {
+    include!(concat!(env!("OUT_DIR"), "/i18n_keys.rs"));
+}
+
+use crate::GameAppData;
 use crate::common::default_handler::Assets;
 use chrono::{Datelike, NaiveDate, NaiveDateTime};
 use std::collections::HashMap;
 use std::sync::{Arc, RwLock};
 
-/// (lang_code, flag_code, display_name)
+/// (lang_code, flag_code, display_name) of the embedded languages. Packs
+/// in the locales directory can add more; see [`locales::languages`].
 pub const SUPPORTED_LANGUAGES: &[(&str, &str, &str)] = &[
     ("en", "us", "English"),
     ("es", "es", "Español"),
@@ -20,28 +33,43 @@ pub const SUPPORTED_LANG_CODES: &[&str] = &["en", "es", "fr", "de", "pt", "ru",
 
 pub const DEFAULT_LANGUAGE: &str = "en";
 
+pub use language::{localized_path, preferred_language};
+
+pub fn language_routes() -> axum::Router<GameAppData> {
+    routes::routes()
+}
+
 const MONTH_KEYS: &[&str] = &[
-    "month_jan",
-    "month_feb",
-    "month_mar",
-    "month_apr",
-    "month_may",
-    "month_jun",
-    "month_jul",
-    "month_aug",
-    "month_sep",
-    "month_oct",
-    "month_nov",
-    "month_dec",
+    keys::MONTH_JAN,
+    keys::MONTH_FEB,
+    keys::MONTH_MAR,
+    keys::MONTH_APR,
+    keys::MONTH_MAY,
+    keys::MONTH_JUN,
+    keys::MONTH_JUL,
+    keys::MONTH_AUG,
+    keys::MONTH_SEP,
+    keys::MONTH_OCT,
+    keys::MONTH_NOV,
+    keys::MONTH_DEC,
 ];
 
 const DAY_KEYS: &[&str] = &[
-    "day_mon", "day_tue", "day_wed", "day_thu", "day_fri", "day_sat", "day_sun",
+    keys::DAY_MON,
+    keys::DAY_TUE,
+    keys::DAY_WED,
+    keys::DAY_THU,
+    keys::DAY_FRI,
+    keys::DAY_SAT,
+    keys::DAY_SUN,
 ];
 
 pub struct I18nManager {
-    translations: HashMap<String, Arc<HashMap<String, String>>>,
-    country_names: HashMap<String, Arc<HashMap<String, String>>>,
+    embedded_translations: HashMap<String, Arc<HashMap<String, String>>>,
+    embedded_country_names: HashMap<String, Arc<HashMap<String, String>>>,
+    /// Embedded bundles with the locales directory's packs laid over them.
+    translations: RwLock<HashMap<String, Arc<HashMap<String, String>>>>,
+    country_names: RwLock<HashMap<String, Arc<HashMap<String, String>>>>,
     date: RwLock<NaiveDateTime>,
 }
 
@@ -76,8 +104,10 @@ impl I18nManager {
         }
 
         I18nManager {
-            translations,
-            country_names,
+            translations: RwLock::new(translations.clone()),
+            country_names: RwLock::new(country_names.clone()),
+            embedded_translations: translations,
+            embedded_country_names: country_names,
             date: RwLock::new(NaiveDateTime::default()),
         }
     }
@@ -87,19 +117,19 @@ impl I18nManager {
     }
 
     pub fn for_lang(&self, lang: &str) -> I18n {
-        let lang_key = if self.translations.contains_key(lang) {
+        let all_translations = self.translations.read().unwrap();
+        let lang_key = if all_translations.contains_key(lang) {
             lang
         } else {
             DEFAULT_LANGUAGE
         };
 
-        let translations = self
-            .translations
+        let translations = all_translations
             .get(lang_key)
             .cloned()
             .unwrap_or_else(|| Arc::new(HashMap::new()));
         let fallback = if lang_key != DEFAULT_LANGUAGE {
-            self.translations
+            all_translations
                 .get(DEFAULT_LANGUAGE)
                 .cloned()
                 .unwrap_or_else(|| Arc::new(HashMap::new()))
@@ -122,13 +152,13 @@ impl I18nManager {
         let date_main = format!("{} {} {}", date.day(), t(month_key), date.year());
         let date_sub = t(day_key);
 
-        let country_names = self
-            .country_names
+        let all_country_names = self.country_names.read().unwrap();
+        let country_names = all_country_names
             .get(lang_key)
             .cloned()
             .unwrap_or_else(|| Arc::new(HashMap::new()));
         let country_names_fallback = if lang_key != DEFAULT_LANGUAGE {
-            self.country_names
+            all_country_names
                 .get(DEFAULT_LANGUAGE)
                 .cloned()
                 .unwrap_or_else(|| Arc::new(HashMap::new()))
@@ -148,7 +178,7 @@ impl I18nManager {
     }
 
     pub fn is_supported_language(lang: &str) -> bool {
-        SUPPORTED_LANG_CODES.contains(&lang)
+        locales::is_language(lang)
     }
 }
 
@@ -211,6 +241,20 @@ impl I18n {
             .unwrap_or(key)
     }
 
+    /// The message for `key` with its `{name}` placeholders filled in.
+    pub fn format(&self, key: &str, args: &[(&str, String)]) -> String {
+        message::interpolate(self.t(key), args)
+    }
+
+    /// The plural form of `key` for `count`, with `{count}` and the other
+    /// placeholders filled in.
+    pub fn plural(&self, key: &str, count: i64, args: &[(&str, String)]) -> String {
+        let form = message::select_plural(self.t(key), &self.lang, count);
+        let mut all_args = vec![("count", count.to_string())];
+        all_args.extend_from_slice(args);
+        message::interpolate(form, &all_args)
+    }
+
     pub fn country<'a>(&'a self, code: &'a str) -> &'a str {
         self.country_names
             .get(code)
@@ -227,24 +271,24 @@ impl I18n {
     }
 
     pub fn current_flag(&self) -> &'static str {
-        SUPPORTED_LANGUAGES
-            .iter()
+        locales::languages()
+            .into_iter()
             .find(|(code, _, _)| *code == self.lang)
-            .map(|(_, flag, _)| *flag)
+            .map(|(_, flag, _)| flag)
             .unwrap_or("us")
     }
 
     pub fn current_name(&self) -> &'static str {
-        SUPPORTED_LANGUAGES
-            .iter()
+        locales::languages()
+            .into_iter()
             .find(|(code, _, _)| *code == self.lang)
-            .map(|(_, _, name)| *name)
+            .map(|(_, _, name)| name)
             .unwrap_or("English")
     }
 
     pub fn languages(&self) -> Vec<LangOption> {
-        SUPPORTED_LANGUAGES
-            .iter()
+        locales::languages()
+            .into_iter()
             .map(|(code, flag, name)| LangOption { code, flag, name })
             .collect()
     }
@@ -255,6 +299,7 @@ impl I18n {
 /// Respects quality weights (e.g. `fr;q=0.9, de;q=0.8, en;q=0.5`).
 /// Falls back to `DEFAULT_LANGUAGE` if nothing matches.
 pub fn detect_language(accept_language: &str) -> String {
+    let languages = locales::languages();
     let mut candidates: Vec<(&str, f32)> = Vec::new();
 
     for part in accept_language.split(',') {
@@ -270,9 +315,9 @@ pub fn detect_language(accept_language: &str) -> String {
             })
             .unwrap_or(1.0);
 
-        if let Some(&code) = SUPPORTED_LANG_CODES
+        if let Some((code, _, _)) = languages
             .iter()
-            .find(|&&c| c.eq_ignore_ascii_case(lang_prefix))
+            .find(|(c, _, _)| c.eq_ignore_ascii_case(lang_prefix))
         {
             candidates.push((code, quality));
         }
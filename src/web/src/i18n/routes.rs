@@ -0,0 +1,8 @@
+use crate::GameAppData;
+use crate::i18n::language::language_action;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new().route("/settings/language", get(language_action))
+}
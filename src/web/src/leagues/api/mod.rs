@@ -0,0 +1,374 @@
+//! JSON feeds behind the league page, for external widgets: the table
+//! with home/away splits and last-5 form, upcoming fixtures and past
+//! results grouped by round.
+
+pub mod routes;
+
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use chrono::NaiveDateTime;
+use core::SimulatorData;
+use core::league::{League, PointsSystem, ScheduleItem, ScheduleTour};
+use core::r#match::Score;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+const FORM_LENGTH: usize = 5;
+
+#[derive(Deserialize)]
+pub struct LeagueApiPathParams {
+    pub league_slug: String,
+}
+
+#[derive(Deserialize, Default)]
+pub struct RoundQuery {
+    /// Only this round (tour number) when set.
+    #[serde(default)]
+    pub round: Option<u8>,
+}
+
+#[derive(Serialize, Default, Clone, Copy, PartialEq, Debug)]
+pub struct StandingRecordDto {
+    pub played: u8,
+    pub win: u8,
+    pub draw: u8,
+    pub lost: u8,
+    pub goals_for: i32,
+    pub goals_against: i32,
+    pub goal_difference: i32,
+    pub points: u8,
+}
+
+impl StandingRecordDto {
+    fn add(&mut self, scored: u8, conceded: u8, points: &PointsSystem) {
+        self.played += 1;
+        self.goals_for += scored as i32;
+        self.goals_against += conceded as i32;
+        self.goal_difference = self.goals_for - self.goals_against;
+        match scored.cmp(&conceded) {
+            std::cmp::Ordering::Greater => {
+                self.win += 1;
+                self.points += points.win;
+            }
+            std::cmp::Ordering::Equal => {
+                self.draw += 1;
+                self.points += points.draw;
+            }
+            std::cmp::Ordering::Less => {
+                self.lost += 1;
+                self.points += points.loss;
+            }
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Copy, PartialEq, Debug)]
+pub enum FormResult {
+    W,
+    D,
+    L,
+}
+
+#[derive(Serialize)]
+pub struct StandingDto {
+    pub position: u8,
+    pub team_name: String,
+    pub team_slug: String,
+    pub overall: StandingRecordDto,
+    pub points_deduction: u8,
+    pub home: StandingRecordDto,
+    pub away: StandingRecordDto,
+    /// Last five league results, oldest first.
+    pub form: Vec<FormResult>,
+}
+
+#[derive(Serialize)]
+pub struct LeagueTableApiDto {
+    pub league_slug: String,
+    pub league_name: String,
+    pub rows: Vec<StandingDto>,
+}
+
+#[derive(Serialize)]
+pub struct FixtureDto {
+    pub match_id: String,
+    pub date: NaiveDateTime,
+    pub home_team_name: String,
+    pub home_team_slug: String,
+    pub away_team_name: String,
+    pub away_team_slug: String,
+    pub home_goals: Option<u8>,
+    pub away_goals: Option<u8>,
+}
+
+#[derive(Serialize)]
+pub struct RoundDto {
+    pub round: u8,
+    pub matches: Vec<FixtureDto>,
+}
+
+#[derive(Default)]
+struct TeamSplits {
+    home: StandingRecordDto,
+    away: StandingRecordDto,
+    form: Vec<FormResult>,
+}
+
+/// Goals of the fixture's home and away side. A result's sides are
+/// matched by team id rather than trusted to follow the fixture order.
+fn fixture_goals(item: &ScheduleItem, result: &Score) -> (u8, u8) {
+    if item.home_team_id == result.home_team.team_id {
+        (result.home_team.get(), result.away_team.get())
+    } else {
+        (result.away_team.get(), result.home_team.get())
+    }
+}
+
+/// Home/away records and the last-five form of every team, from the
+/// played fixtures of `tours`.
+fn team_splits(tours: &[ScheduleTour], points: &PointsSystem) -> HashMap<u32, TeamSplits> {
+    let mut played: Vec<(&ScheduleItem, &Score)> = tours
+        .iter()
+        .flat_map(|t| &t.items)
+        .filter_map(|item| item.result.as_ref().map(|r| (item, r)))
+        .collect();
+    played.sort_by_key(|(item, _)| item.date);
+
+    let mut splits: HashMap<u32, TeamSplits> = HashMap::new();
+    for (item, result) in played {
+        let (home_goals, away_goals) = fixture_goals(item, result);
+
+        let home = splits.entry(item.home_team_id).or_default();
+        home.home.add(home_goals, away_goals, points);
+        home.form.push(form_result(home_goals, away_goals));
+
+        let away = splits.entry(item.away_team_id).or_default();
+        away.away.add(away_goals, home_goals, points);
+        away.form.push(form_result(away_goals, home_goals));
+    }
+
+    for team in splits.values_mut() {
+        let skip = team.form.len().saturating_sub(FORM_LENGTH);
+        team.form.drain(..skip);
+    }
+    splits
+}
+
+fn form_result(scored: u8, conceded: u8) -> FormResult {
+    match scored.cmp(&conceded) {
+        std::cmp::Ordering::Greater => FormResult::W,
+        std::cmp::Ordering::Equal => FormResult::D,
+        std::cmp::Ordering::Less => FormResult::L,
+    }
+}
+
+fn fixture_dto(sim: &SimulatorData, item: &ScheduleItem) -> Option<FixtureDto> {
+    let home = sim.team_data(item.home_team_id)?;
+    let away = sim.team_data(item.away_team_id)?;
+    let goals = item.result.as_ref().map(|r| fixture_goals(item, r));
+
+    Some(FixtureDto {
+        match_id: item.id.clone(),
+        date: item.date,
+        home_team_name: home.name.clone(),
+        home_team_slug: home.slug.clone(),
+        away_team_name: away.name.clone(),
+        away_team_slug: away.slug.clone(),
+        home_goals: goals.map(|g| g.0),
+        away_goals: goals.map(|g| g.1),
+    })
+}
+
+/// Rounds holding at least one fixture that passes `keep`, each trimmed
+/// to those fixtures.
+fn rounds<'a, F>(
+    sim: &SimulatorData,
+    tours: impl Iterator<Item = &'a ScheduleTour>,
+    round: Option<u8>,
+    keep: F,
+) -> Vec<RoundDto>
+where
+    F: Fn(&ScheduleItem) -> bool,
+{
+    tours
+        .filter(|t| round.is_none_or(|r| t.num == r))
+        .filter_map(|tour| {
+            let mut items: Vec<&ScheduleItem> = tour.items.iter().filter(|i| keep(i)).collect();
+            if items.is_empty() {
+                return None;
+            }
+            items.sort_by_key(|i| i.date);
+            Some(RoundDto {
+                round: tour.num,
+                matches: items
+                    .into_iter()
+                    .filter_map(|i| fixture_dto(sim, i))
+                    .collect(),
+            })
+        })
+        .collect()
+}
+
+fn find_league<'a>(sim: &'a SimulatorData, league_slug: &str) -> ApiResult<&'a League> {
+    sim.indexes
+        .as_ref()
+        .and_then(|i| i.slug_indexes.get_league_by_slug(league_slug))
+        .and_then(|id| sim.league(id))
+        .ok_or_else(|| ApiError::NotFound(format!("League '{}' not found", league_slug)))
+}
+
+/// `GET /api/v1/leagues/{league_slug}/table` — standings with home/away
+/// splits and last-5 form.
+pub async fn league_table_api_action(
+    State(state): State<GameAppData>,
+    Path(params): Path<LeagueApiPathParams>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let sim = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+    let league = find_league(sim, &params.league_slug)?;
+
+    let mut splits = team_splits(&league.schedule.tours, &league.table.points_system);
+    let rows = league
+        .table
+        .get()
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, row)| {
+            let team = sim.team_data(row.team_id)?;
+            let team_splits = splits.remove(&row.team_id).unwrap_or_default();
+            Some(StandingDto {
+                position: idx as u8 + 1,
+                team_name: team.name.clone(),
+                team_slug: team.slug.clone(),
+                overall: StandingRecordDto {
+                    played: row.played,
+                    win: row.win,
+                    draw: row.draft,
+                    lost: row.lost,
+                    goals_for: row.goal_scored,
+                    goals_against: row.goal_concerned,
+                    goal_difference: row.goal_difference(),
+                    points: row.effective_points(),
+                },
+                points_deduction: row.points_deduction,
+                home: team_splits.home,
+                away: team_splits.away,
+                form: team_splits.form,
+            })
+        })
+        .collect();
+
+    Ok(Json(LeagueTableApiDto {
+        league_slug: league.slug.clone(),
+        league_name: league.name.clone(),
+        rows,
+    }))
+}
+
+/// `GET /api/v1/leagues/{league_slug}/fixtures?round=` — unplayed fixtures by
+/// round, soonest first.
+pub async fn league_fixtures_api_action(
+    State(state): State<GameAppData>,
+    Path(params): Path<LeagueApiPathParams>,
+    ApiQuery(query): ApiQuery<RoundQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let sim = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+    let league = find_league(sim, &params.league_slug)?;
+
+    Ok(Json(rounds(
+        sim,
+        league.schedule.tours.iter(),
+        query.round,
+        |item| item.result.is_none(),
+    )))
+}
+
+/// `GET /api/v1/leagues/{league_slug}/results?round=` — played fixtures by
+/// round, latest round first.
+pub async fn league_results_api_action(
+    State(state): State<GameAppData>,
+    Path(params): Path<LeagueApiPathParams>,
+    ApiQuery(query): ApiQuery<RoundQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let sim = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+    let league = find_league(sim, &params.league_slug)?;
+
+    Ok(Json(rounds(
+        sim,
+        league.schedule.tours.iter().rev(),
+        query.round,
+        |item| item.result.is_some(),
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use core::r#match::TeamScore;
+
+    fn played(day: u32, home: u32, away: u32, home_goals: u8, away_goals: u8) -> ScheduleItem {
+        let date = NaiveDate::from_ymd_opt(2026, 8, day)
+            .unwrap()
+            .and_hms_opt(15, 0, 0)
+            .unwrap();
+        let score = Score {
+            home_team: TeamScore::new_with_score(home, home_goals),
+            away_team: TeamScore::new_with_score(away, away_goals),
+            details: Vec::new(),
+            home_shootout: 0,
+            away_shootout: 0,
+        };
+        ScheduleItem::new(1, "l".to_string(), home, away, date, Some(score))
+    }
+
+    #[test]
+    fn splits_separate_home_and_away_and_keep_last_five() {
+        let mut tour = ScheduleTour::new(1, 0);
+        tour.items = vec![
+            played(1, 1, 2, 2, 0),
+            played(2, 2, 1, 1, 1),
+            played(3, 1, 3, 0, 1),
+            played(4, 3, 1, 0, 3),
+            played(5, 1, 2, 1, 0),
+            played(6, 2, 1, 2, 0),
+        ];
+
+        let splits = team_splits(&[tour], &PointsSystem::default());
+        let team = &splits[&1];
+
+        assert_eq!(team.home.played, 3);
+        assert_eq!((team.home.win, team.home.lost, team.home.points), (2, 1, 6));
+        assert_eq!(team.away.played, 3);
+        assert_eq!(
+            (
+                team.away.win,
+                team.away.draw,
+                team.away.lost,
+                team.away.points
+            ),
+            (1, 1, 1, 4)
+        );
+        assert_eq!(team.away.goal_difference, 1);
+        assert_eq!(
+            team.form,
+            vec![
+                FormResult::D,
+                FormResult::L,
+                FormResult::W,
+                FormResult::W,
+                FormResult::L
+            ]
+        );
+    }
+}
@@ -0,0 +1,19 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route(
+            "/api/v1/leagues/{league_slug}/table",
+            get(super::league_table_api_action),
+        )
+        .route(
+            "/api/v1/leagues/{league_slug}/fixtures",
+            get(super::league_fixtures_api_action),
+        )
+        .route(
+            "/api/v1/leagues/{league_slug}/results",
+            get(super::league_results_api_action),
+        )
+}
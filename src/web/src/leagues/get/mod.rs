@@ -8,13 +8,14 @@ use askama::Template;
 use axum::extract::{Path, State};
 use axum::response::IntoResponse;
 use chrono::Duration;
+use core::SimulatorData;
 use core::league::{
-    LeagueTableRow as CoreTableRow, PlayoffStage, ScheduleItem, ScheduleTour, TieBreakPolicy,
+    League, LeagueTableRow as CoreTableRow, PlayoffStage, ScheduleItem, ScheduleTour,
 };
 use core::r#match::GoalDetail;
 use core::r#match::player::statistics::MatchStatisticType;
 use itertools::*;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
 #[derive(Deserialize)]
@@ -75,11 +76,13 @@ pub struct CompetitionReputationItem {
     pub country_code: String,
 }
 
+#[derive(Serialize)]
 pub struct TourSchedule {
     pub date: String,
     pub matches: Vec<LeagueScheduleItem>,
 }
 
+#[derive(Serialize)]
 pub struct LeagueScheduleItem {
     pub match_id: String,
     pub home_team_name: String,
@@ -89,7 +92,7 @@ pub struct LeagueScheduleItem {
     pub result: Option<LeagueScheduleItemResult>,
 }
 
-#[allow(dead_code)]
+#[derive(Serialize)]
 pub struct LeagueScheduleItemResult {
     pub home_goals: u8,
     pub home_goalscorers: Vec<LeagueTableGoalscorer>,
@@ -97,7 +100,7 @@ pub struct LeagueScheduleItemResult {
     pub away_goalscorers: Vec<LeagueTableGoalscorer>,
 }
 
-#[allow(dead_code)]
+#[derive(Serialize)]
 pub struct LeagueTableGoalscorer {
     pub id: u32,
     pub name: String,
@@ -105,6 +108,7 @@ pub struct LeagueTableGoalscorer {
     pub auto_goal: bool,
 }
 
+#[derive(Serialize)]
 pub struct LeagueTableRow {
     pub team_name: String,
     pub team_slug: String,
@@ -161,23 +165,7 @@ pub async fn league_get_action(
 
     let league_table = league.table.get();
 
-    let table_rows: Vec<LeagueTableRow> = league_table
-        .iter()
-        .map(|t| {
-            let team_data = simulator_data.team_data(t.team_id).unwrap();
-            LeagueTableRow {
-                team_name: team_data.name.clone(),
-                team_slug: team_data.slug.clone(),
-                played: t.played,
-                win: t.win,
-                draft: t.draft,
-                lost: t.lost,
-                goal_scored: t.goal_scored,
-                goal_concerned: t.goal_concerned,
-                points: t.points,
-            }
-        })
-        .collect();
+    let table_rows = build_table_rows(simulator_data, league_table);
 
     // Split-season context: label the live standings with the current
     // tournament (Apertura until the flip, Clausura after) and build the
@@ -219,136 +207,16 @@ pub async fn league_get_action(
             })
             .flat_map(|l| l.annual_table_rows())
             .collect();
-        let policy = TieBreakPolicy::fifa_default();
+        let policy = league.table.tie_break.clone();
         annual.sort_by(|a, b| policy.compare(a, b));
 
-        let annual_rows: Vec<LeagueTableRow> = annual
-            .iter()
-            .filter_map(|t| {
-                simulator_data
-                    .team_data(t.team_id)
-                    .map(|team_data| LeagueTableRow {
-                        team_name: team_data.name.clone(),
-                        team_slug: team_data.slug.clone(),
-                        played: t.played,
-                        win: t.win,
-                        draft: t.draft,
-                        lost: t.lost,
-                        goal_scored: t.goal_scored,
-                        goal_concerned: t.goal_concerned,
-                        points: t.points,
-                    })
-            })
-            .collect();
+        let annual_rows = build_table_rows(simulator_data, &annual);
         (tournament_label, annual_rows)
     } else {
         (String::new(), Vec::new())
     };
 
-    // Build a single fixture view-item for the current tour.
-    let map_item = |item: &ScheduleItem| -> LeagueScheduleItem {
-        let home_team_data = simulator_data.team_data(item.home_team_id).unwrap();
-        let home_team = simulator_data.team(item.home_team_id).unwrap();
-        let away_team_data = simulator_data.team_data(item.away_team_id).unwrap();
-        let away_team = simulator_data.team(item.away_team_id).unwrap();
-
-        LeagueScheduleItem {
-            match_id: item.id.clone(),
-            result: item.result.as_ref().map(|res| {
-                let details: Vec<&GoalDetail> = res
-                    .details
-                    .iter()
-                    .filter(|detail| detail.stat_type == MatchStatisticType::Goal)
-                    .collect();
-
-                LeagueScheduleItemResult {
-                    home_goals: if item.home_team_id == res.home_team.team_id {
-                        res.home_team.get()
-                    } else {
-                        res.away_team.get()
-                    },
-                    home_goalscorers: details
-                        .iter()
-                        .filter_map(|detail| {
-                            let player = simulator_data.player(detail.player_id)?;
-                            if home_team.players.contains(player.id) {
-                                Some(LeagueTableGoalscorer {
-                                    id: detail.player_id,
-                                    name: player.full_name.to_string(),
-                                    time: format!(
-                                        "('{})",
-                                        Duration::new((detail.time / 1000) as i64, 0)
-                                            .unwrap()
-                                            .num_minutes()
-                                    ),
-                                    auto_goal: detail.is_auto_goal,
-                                })
-                            } else {
-                                None
-                            }
-                        })
-                        .collect(),
-                    away_goals: if item.away_team_id == res.away_team.team_id {
-                        res.away_team.get()
-                    } else {
-                        res.home_team.get()
-                    },
-                    away_goalscorers: details
-                        .iter()
-                        .filter_map(|detail| {
-                            let player = simulator_data.player(detail.player_id)?;
-                            if away_team.players.contains(player.id) {
-                                Some(LeagueTableGoalscorer {
-                                    id: detail.player_id,
-                                    name: player.full_name.to_string(),
-                                    time: format!(
-                                        "('{})",
-                                        Duration::new((detail.time / 1000) as i64, 0)
-                                            .unwrap()
-                                            .num_minutes()
-                                    ),
-                                    auto_goal: detail.is_auto_goal,
-                                })
-                            } else {
-                                None
-                            }
-                        })
-                        .collect(),
-                }
-            }),
-            home_team_name: home_team_data.name.clone(),
-            home_team_slug: home_team_data.slug.clone(),
-            away_team_name: away_team_data.name.clone(),
-            away_team_slug: away_team_data.slug.clone(),
-        }
-    };
-
-    let mut current_tour_schedule = Vec::new();
-
-    let now = simulator_data.date.date() + Duration::days(3);
-
-    let mut current_tour: Option<&ScheduleTour> = None;
-    for tour in league.schedule.tours.iter() {
-        if now >= tour.start_date() && now <= tour.end_date() {
-            current_tour = Some(tour);
-        }
-    }
-    if current_tour.is_none() {
-        for tour in league.schedule.tours.iter() {
-            if now >= tour.end_date() {
-                current_tour = Some(tour);
-            }
-        }
-    }
-
-    if let Some(tour) = current_tour {
-        for (key, group) in &tour.items.iter().chunk_by(|t| t.date.date()) {
-            current_tour_schedule.push(TourSchedule {
-                date: key.format("%d.%m.%Y").to_string(),
-                matches: group.map(|item| map_item(item)).collect(),
-            });
-        }
-    }
+    let current_tour_schedule = build_current_tour_schedule(simulator_data, league);
 
     let mut reputation_data: Vec<(u16, String, String, String, String)> = simulator_data
         .continents
@@ -660,3 +528,177 @@ pub async fn league_get_action(
     }
     .into_response())
 }
+
+/// Standings rows alone, rendered by the same markup the league page
+/// includes, so the page can swap its table body while a run advances.
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "leagues/get/table_rows.html")]
+pub struct LeagueTableFragmentTemplate {
+    pub lang: String,
+    pub table_rows: Vec<LeagueTableRow>,
+}
+
+/// `GET /fragments/{lang}/leagues/{league_slug}/table`
+pub async fn league_table_fragment_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<LeagueGetRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let league = simulator_data
+        .indexes
+        .as_ref()
+        .and_then(|i| i.slug_indexes.get_league_by_slug(&route_params.league_slug))
+        .and_then(|id| simulator_data.league(id))
+        .ok_or_else(|| {
+            ApiError::NotFound(format!(
+                "League with slug {} not found",
+                route_params.league_slug
+            ))
+        })?;
+
+    Ok(LeagueTableFragmentTemplate {
+        table_rows: build_table_rows(simulator_data, league.table.get()),
+        lang: route_params.lang,
+    })
+}
+
+/// Standings rows resolved to team names and slugs, in the given order.
+pub fn build_table_rows(
+    simulator_data: &SimulatorData,
+    rows: &[CoreTableRow],
+) -> Vec<LeagueTableRow> {
+    rows.iter()
+        .filter_map(|t| {
+            simulator_data
+                .team_data(t.team_id)
+                .map(|team_data| LeagueTableRow {
+                    team_name: team_data.name.clone(),
+                    team_slug: team_data.slug.clone(),
+                    played: t.played,
+                    win: t.win,
+                    draft: t.draft,
+                    lost: t.lost,
+                    goal_scored: t.goal_scored,
+                    goal_concerned: t.goal_concerned,
+                    points: t.points,
+                })
+        })
+        .collect()
+}
+
+/// Fixtures of the tour in progress (or the last one played), grouped by
+/// match day. Shared by the league page and the JSON API.
+pub fn build_current_tour_schedule(
+    simulator_data: &SimulatorData,
+    league: &League,
+) -> Vec<TourSchedule> {
+    // Build a single fixture view-item for the current tour.
+    let map_item = |item: &ScheduleItem| -> LeagueScheduleItem {
+        let home_team_data = simulator_data.team_data(item.home_team_id).unwrap();
+        let home_team = simulator_data.team(item.home_team_id).unwrap();
+        let away_team_data = simulator_data.team_data(item.away_team_id).unwrap();
+        let away_team = simulator_data.team(item.away_team_id).unwrap();
+
+        LeagueScheduleItem {
+            match_id: item.id.clone(),
+            result: item.result.as_ref().map(|res| {
+                let details: Vec<&GoalDetail> = res
+                    .details
+                    .iter()
+                    .filter(|detail| detail.stat_type == MatchStatisticType::Goal)
+                    .collect();
+
+                LeagueScheduleItemResult {
+                    home_goals: if item.home_team_id == res.home_team.team_id {
+                        res.home_team.get()
+                    } else {
+                        res.away_team.get()
+                    },
+                    home_goalscorers: details
+                        .iter()
+                        .filter_map(|detail| {
+                            let player = simulator_data.player(detail.player_id)?;
+                            if home_team.players.contains(player.id) {
+                                Some(LeagueTableGoalscorer {
+                                    id: detail.player_id,
+                                    name: player.full_name.to_string(),
+                                    time: format!(
+                                        "('{})",
+                                        Duration::new((detail.time / 1000) as i64, 0)
+                                            .unwrap()
+                                            .num_minutes()
+                                    ),
+                                    auto_goal: detail.is_auto_goal,
+                                })
+                            } else {
+                                None
+                            }
+                        })
+                        .collect(),
+                    away_goals: if item.away_team_id == res.away_team.team_id {
+                        res.away_team.get()
+                    } else {
+                        res.home_team.get()
+                    },
+                    away_goalscorers: details
+                        .iter()
+                        .filter_map(|detail| {
+                            let player = simulator_data.player(detail.player_id)?;
+                            if away_team.players.contains(player.id) {
+                                Some(LeagueTableGoalscorer {
+                                    id: detail.player_id,
+                                    name: player.full_name.to_string(),
+                                    time: format!(
+                                        "('{})",
+                                        Duration::new((detail.time / 1000) as i64, 0)
+                                            .unwrap()
+                                            .num_minutes()
+                                    ),
+                                    auto_goal: detail.is_auto_goal,
+                                })
+                            } else {
+                                None
+                            }
+                        })
+                        .collect(),
+                }
+            }),
+            home_team_name: home_team_data.name.clone(),
+            home_team_slug: home_team_data.slug.clone(),
+            away_team_name: away_team_data.name.clone(),
+            away_team_slug: away_team_data.slug.clone(),
+        }
+    };
+
+    let now = simulator_data.date.date() + Duration::days(3);
+
+    let mut current_tour: Option<&ScheduleTour> = None;
+    for tour in league.schedule.tours.iter() {
+        if now >= tour.start_date() && now <= tour.end_date() {
+            current_tour = Some(tour);
+        }
+    }
+    if current_tour.is_none() {
+        for tour in league.schedule.tours.iter() {
+            if now >= tour.end_date() {
+                current_tour = Some(tour);
+            }
+        }
+    }
+
+    let mut current_tour_schedule = Vec::new();
+    if let Some(tour) = current_tour {
+        for (key, group) in &tour.items.iter().chunk_by(|t| t.date.date()) {
+            current_tour_schedule.push(TourSchedule {
+                date: key.format("%d.%m.%Y").to_string(),
+                matches: group.map(|item| map_item(item)).collect(),
+            });
+        }
+    }
+
+    current_tour_schedule
+}
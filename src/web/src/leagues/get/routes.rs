@@ -3,8 +3,13 @@ use axum::Router;
 use axum::routing::get;
 
 pub fn routes() -> Router<GameAppData> {
-    Router::new().route(
-        "/{lang}/leagues/{league_slug}",
-        get(super::league_get_action),
-    )
+    Router::new()
+        .route(
+            "/{lang}/leagues/{league_slug}",
+            get(super::league_get_action),
+        )
+        .route(
+            "/fragments/{lang}/leagues/{league_slug}/table",
+            get(super::league_table_fragment_action),
+        )
 }
@@ -0,0 +1,217 @@
+pub mod routes;
+
+use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
+use crate::common::slug::player_history_slug;
+use crate::cups::history::season_label;
+use crate::views::{self, MenuSection};
+use crate::{ApiError, ApiResult, GameAppData, I18n};
+use askama::Template;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use core::SimulatorData;
+use serde::Deserialize;
+
+#[derive(Deserialize)]
+pub struct LeagueHistoryRequest {
+    lang: String,
+    league_slug: String,
+}
+
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "leagues/history/index.html")]
+pub struct LeagueHistoryTemplate {
+    pub css_version: &'static str,
+    pub computer_name: &'static str,
+    pub cpu_brand: &'static str,
+    pub cores_count: usize,
+    pub title: String,
+    pub sub_title_prefix: String,
+    pub sub_title_suffix: String,
+    pub sub_title: String,
+    pub sub_title_link: String,
+    pub sub_title_country_code: String,
+    pub header_color: String,
+    pub foreground_color: String,
+    pub menu_sections: Vec<MenuSection>,
+    pub i18n: I18n,
+    pub lang: String,
+    pub league_slug: String,
+    /// One entry per archived season, most recent first.
+    pub seasons: Vec<LeagueHistorySeason>,
+}
+
+pub struct LeagueHistorySeason {
+    pub season_label: String,
+    pub champion: HistoryTeam,
+    pub runner_up: Option<HistoryTeam>,
+    pub top_scorer: Option<HistoryScorer>,
+    pub table: Vec<HistoryTableRow>,
+}
+
+pub struct HistoryTeam {
+    pub name: String,
+    pub slug: String,
+}
+
+pub struct HistoryScorer {
+    pub player_slug: String,
+    pub player_name: String,
+    pub goals: u16,
+}
+
+pub struct HistoryTableRow {
+    pub position: usize,
+    pub team: HistoryTeam,
+    pub played: u8,
+    pub win: u8,
+    pub draft: u8,
+    pub lost: u8,
+    pub goal_scored: i32,
+    pub goal_concerned: i32,
+    pub points: u8,
+}
+
+pub async fn league_history_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<LeagueHistoryRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let i18n = state.i18n.for_lang(&route_params.lang);
+    let guard = state.data.read().await;
+
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let indexes = simulator_data
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?;
+
+    let league_id = indexes
+        .slug_indexes
+        .get_league_by_slug(&route_params.league_slug)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("League '{}' not found", route_params.league_slug))
+        })?;
+
+    let league = simulator_data
+        .league(league_id)
+        .ok_or_else(|| ApiError::NotFound(format!("League with ID {} not found", league_id)))?;
+
+    let country = simulator_data.country(league.country_id).ok_or_else(|| {
+        ApiError::NotFound(format!("Country with ID {} not found", league.country_id))
+    })?;
+
+    let seasons: Vec<LeagueHistorySeason> = league
+        .history
+        .iter()
+        .rev()
+        .filter_map(|record| {
+            let champion = history_team(simulator_data, record.champion_team_id()?);
+            let top_scorer = record.top_scorer.map(|(player_id, goals)| {
+                let player_name = simulator_data
+                    .player(player_id)
+                    .or_else(|| simulator_data.retired_player(player_id))
+                    .map(|p| p.full_name.to_string())
+                    .unwrap_or_default();
+                HistoryScorer {
+                    player_slug: player_history_slug(simulator_data, player_id, &player_name),
+                    player_name,
+                    goals,
+                }
+            });
+            let table = record
+                .table
+                .iter()
+                .enumerate()
+                .map(|(idx, row)| HistoryTableRow {
+                    position: idx + 1,
+                    team: history_team(simulator_data, row.team_id),
+                    played: row.played,
+                    win: row.win,
+                    draft: row.draft,
+                    lost: row.lost,
+                    goal_scored: row.goal_scored,
+                    goal_concerned: row.goal_concerned,
+                    points: row.effective_points(),
+                })
+                .collect();
+            Some(LeagueHistorySeason {
+                season_label: season_label(&league.settings, record.season_start_year),
+                champion,
+                runner_up: record
+                    .runner_up_team_id()
+                    .map(|id| history_team(simulator_data, id)),
+                top_scorer,
+                table,
+            })
+        })
+        .collect();
+
+    let league_title = views::league_display_name(league, &i18n, simulator_data);
+
+    Ok(LeagueHistoryTemplate {
+        css_version: CSS_VERSION,
+        computer_name: &COMPUTER_NAME,
+        cpu_brand: &CPU_BRAND,
+        cores_count: *CPU_CORES,
+        title: format!("{} — {}", league_title, i18n.t("history")),
+        sub_title_prefix: String::new(),
+        sub_title_suffix: String::new(),
+        sub_title: country.name.clone(),
+        sub_title_link: format!("/{}/countries/{}", &route_params.lang, &country.slug),
+        sub_title_country_code: country.code.clone(),
+        header_color: country.background_color.clone(),
+        foreground_color: country.foreground_color.clone(),
+        menu_sections: {
+            let mut cl: Vec<(u32, &str, &str)> = country
+                .leagues
+                .leagues
+                .iter()
+                .filter(|l| !l.friendly)
+                .map(|l| (l.id, l.name.as_str(), l.slug.as_str()))
+                .collect();
+            cl.sort_by_key(|(id, _, _)| *id);
+            let cl_refs: Vec<(&str, &str)> = cl.iter().map(|(_, n, s)| (*n, *s)).collect();
+            let current_path = format!("/{}/leagues/{}/history", &route_params.lang, &league.slug);
+            let mp = views::MenuParams {
+                i18n: &i18n,
+                lang: &route_params.lang,
+                current_path: &current_path,
+                country_name: &country.name,
+                country_slug: &country.slug,
+            };
+            views::league_menu(
+                &mp,
+                &cl_refs,
+                country
+                    .domestic_cup
+                    .as_ref()
+                    .map(|c| (c.league.name.as_str(), c.league.slug.as_str())),
+                &country
+                    .playoffs
+                    .iter()
+                    .map(|p| (p.league.name.as_str(), p.league.slug.as_str()))
+                    .collect::<Vec<_>>(),
+            )
+        },
+        league_slug: league.slug.clone(),
+        seasons,
+        lang: route_params.lang,
+        i18n,
+    })
+}
+
+/// Name and slug of an archived team. Reads the team index, so a club
+/// that has since dropped out of the league still resolves.
+fn history_team(data: &SimulatorData, team_id: u32) -> HistoryTeam {
+    data.team_data(team_id)
+        .map(|d| HistoryTeam {
+            name: d.name.clone(),
+            slug: d.slug.clone(),
+        })
+        .unwrap_or(HistoryTeam {
+            name: String::new(),
+            slug: String::new(),
+        })
+}
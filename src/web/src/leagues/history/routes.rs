@@ -0,0 +1,10 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new().route(
+        "/{lang}/leagues/{league_slug}/history",
+        get(super::league_history_action),
+    )
+}
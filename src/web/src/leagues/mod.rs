@@ -1,5 +1,7 @@
+pub mod api;
 pub mod awards;
 pub mod get;
+pub mod history;
 pub mod transfers;
 
 use crate::GameAppData;
@@ -10,4 +12,6 @@ pub fn league_routes() -> Router<GameAppData> {
         .merge(get::routes::routes())
         .merge(transfers::routes::routes())
         .merge(awards::routes::routes())
+        .merge(history::routes::routes())
+        .merge(api::routes::routes())
 }
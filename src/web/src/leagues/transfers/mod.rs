@@ -3,9 +3,9 @@ pub mod routes;
 use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
 use crate::common::slug::player_history_slug;
 use crate::views::{self, MenuSection};
-use crate::{ApiError, ApiResult, GameAppData, I18n};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData, I18n};
 use askama::Template;
-use axum::extract::{Path, Query, State};
+use axum::extract::{Path, State};
 use axum::response::IntoResponse;
 use chrono::Datelike;
 use core::PlayerPositionType;
@@ -43,6 +43,7 @@ pub struct LeagueTransfersTemplate {
     pub i18n: I18n,
     pub lang: String,
     pub league_slug: String,
+    pub country_slug: String,
     pub completed_transfers: Vec<CompletedTransferItem>,
     pub has_permanent_transfers: bool,
     pub has_loan_transfers: bool,
@@ -88,7 +89,7 @@ pub struct NegotiationItem {
 pub async fn league_transfers_action(
     State(state): State<GameAppData>,
     Path(route_params): Path<LeagueTransfersRequest>,
-    Query(query): Query<SeasonQuery>,
+    ApiQuery(query): ApiQuery<SeasonQuery>,
 ) -> ApiResult<impl IntoResponse> {
     let i18n = state.i18n.for_lang(&route_params.lang);
     let guard = state.data.read().await;
@@ -333,6 +334,7 @@ pub async fn league_transfers_action(
             )
         },
         league_slug: league.slug.clone(),
+        country_slug: country.slug.clone(),
         has_permanent_transfers: completed_transfers.iter().any(|t| !t.is_loan),
         has_loan_transfers: completed_transfers.iter().any(|t| t.is_loan),
         completed_transfers,
@@ -1,4 +1,6 @@
+mod admin;
 mod ai;
+mod api;
 mod champions_league;
 mod common;
 mod conference_league;
@@ -6,21 +8,28 @@ mod copa_libertadores;
 mod countries;
 mod cups;
 mod date;
+mod editor;
 mod error;
+mod extract;
 mod europa_league;
 mod face;
 mod game;
+mod health;
 pub mod i18n;
 mod leagues;
 mod r#match;
+mod metrics;
 mod national_competitions;
+mod news;
 mod player;
 mod playoffs;
 mod routes;
 mod search;
+mod session;
 pub mod settings;
 mod staff;
 mod teams;
+mod transfers;
 mod views;
 mod watchlist;
 pub mod worker;
@@ -28,18 +37,27 @@ mod workers;
 
 pub use settings::Settings;
 
+pub use admin::SimulationControl;
 pub use ai::{AiConfig, AiJobs, LlmSettings};
-pub use error::{ApiError, ApiResult};
+pub use common::rate_limit::RequestLimits;
+pub use editor::DatabaseEditor;
+pub use error::{ApiError, ApiResult, FieldError, Problem};
+pub use extract::{ApiJson, ApiQuery};
+pub use face::PortraitStore;
+pub use game::{ProcessProgress, SaveStore, export_results};
 pub use i18n::{I18n, I18nManager};
+pub use r#match::stores::MatchStore;
+pub use metrics::Metrics;
 pub use worker::{
     DistributedDispatcher, WorkerRegistry, WorkerServer, WorkerSnapshot, WorkerStatus,
 };
 
+use crate::common::http_cache::cache_views;
 use crate::routes::ServerRoutes;
-use axum::response::IntoResponse;
 use core::SimulatorData;
 use database::DatabaseEntity;
 use log::{error, info};
+use std::any::Any;
 use std::net::SocketAddr;
 use std::sync::Arc;
 use std::sync::atomic::AtomicBool;
@@ -47,30 +65,46 @@ use tokio::net::TcpListener;
 use tokio::sync::{Mutex, RwLock};
 use tower::ServiceBuilder;
 use tower_http::catch_panic::CatchPanicLayer;
+use tower_http::compression::CompressionLayer;
 
 pub struct FootballSimulatorServer {
     data: GameAppData,
+    addr: SocketAddr,
+    limits: RequestLimits,
 }
 
 impl FootballSimulatorServer {
-    pub fn new(data: GameAppData) -> Self {
-        FootballSimulatorServer { data }
+    pub fn new(data: GameAppData, addr: SocketAddr, limits: RequestLimits) -> Self {
+        FootballSimulatorServer { data, addr, limits }
     }
 
     pub async fn run(&self) {
-        let app = ServerRoutes::create()
+        let app = ServerRoutes::create(self.limits)
+            .layer(axum::middleware::from_fn(cache_views))
+            .layer(axum::middleware::from_fn_with_state(
+                self.data.clone(),
+                game::hydrate_countries,
+            ))
+            .layer(axum::middleware::from_fn_with_state(
+                self.data.clone(),
+                metrics::track_http_metrics,
+            ))
             .layer(ServiceBuilder::new()
+                    // Compress outside the view cache, so ETags are computed
+                    // over the uncompressed page
+                    .layer(CompressionLayer::new())
                     // Catch panics in handlers and convert them to 500 errors
-                    .layer(CatchPanicLayer::custom(|_err| {
-                        (
-                            axum::http::StatusCode::INTERNAL_SERVER_ERROR,
-                            "Internal server error - handler panicked".to_string(),
-                        )
-                            .into_response()
+                    .layer(CatchPanicLayer::custom(|err: Box<dyn Any + Send>| {
+                        let message = err
+                            .downcast_ref::<String>()
+                            .map(String::as_str)
+                            .or_else(|| err.downcast_ref::<&str>().copied())
+                            .unwrap_or("unknown panic");
+                        Problem::from_panic(message)
                     })))
             .with_state(self.data.clone());
 
-        let addr = SocketAddr::from(([0, 0, 0, 0], 18000));
+        let addr = self.addr;
 
         let listener = match TcpListener::bind(addr).await {
             Ok(listener) => listener,
@@ -80,9 +114,14 @@ impl FootballSimulatorServer {
             }
         };
 
-        info!("listen at: http://localhost:18000");
+        info!("listen at: http://{}", addr);
 
-        if let Err(e) = axum::serve(listener, app).await {
+        if let Err(e) = axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        {
             error!("Server error: {}", e);
             error!("Server stopped unexpectedly, but not crashing the process");
         }
@@ -104,6 +143,19 @@ pub struct GameAppData {
     /// In-flight AI agent runs, polled by the per-page report dialogs so
     /// tool calls stream in live.
     pub ai_jobs: AiJobs,
+    /// Progress of the running `/api/game/process` call, streamed to the
+    /// UI over `/api/game/progress`.
+    pub progress: ProcessProgress,
+    /// Save slots, in the configured saves directory or database.
+    pub saves: SaveStore,
+    /// HTTP and simulation metrics served on `/metrics`.
+    pub metrics: Metrics,
+    /// Pause and speed of the simulation clock, driven from `/admin`.
+    pub control: SimulationControl,
+    /// Generated player portraits and their on-disk cache.
+    pub portraits: PortraitStore,
+    /// Pre-game edits of `database`, see `/api/editor`.
+    pub editor: DatabaseEditor,
 }
 
 impl Clone for GameAppData {
@@ -117,6 +169,12 @@ impl Clone for GameAppData {
             workers: self.workers.clone(),
             ai: self.ai.clone(),
             ai_jobs: self.ai_jobs.clone(),
+            progress: self.progress.clone(),
+            saves: self.saves.clone(),
+            metrics: self.metrics.clone(),
+            control: self.control.clone(),
+            portraits: self.portraits.clone(),
+            editor: self.editor.clone(),
         }
     }
 }
@@ -1,3 +1,4 @@
+pub mod report;
 pub mod routes;
 
 use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
@@ -9,8 +10,9 @@ use axum::extract::{Path, State};
 use axum::response::IntoResponse;
 use core::MatchRuntime;
 use core::SimulatorData;
-use core::r#match::MatchResultRaw;
 use core::r#match::player::statistics::MatchStatisticType;
+use core::r#match::{MatchResult, MatchResultRaw};
+use report::{MatchStatRow, TimelineEvent};
 use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize)]
@@ -63,8 +65,11 @@ pub struct MatchGetTemplate {
     pub player_of_the_match_slug: String,
     pub player_of_the_match_name: String,
     pub match_recordings_enabled: bool,
+    pub team_stats: Vec<MatchStatRow>,
+    pub timeline: Vec<TimelineEvent>,
 }
 
+#[derive(Serialize)]
 pub struct GoalEventDisplay {
     pub player_slug: String,
     pub player_name: String,
@@ -72,6 +77,7 @@ pub struct GoalEventDisplay {
     pub is_auto_goal: bool,
 }
 
+#[derive(Serialize)]
 pub struct MatchPlayer {
     pub slug: String,
     pub last_name: String,
@@ -110,36 +116,9 @@ pub async fn match_get_action(
         .as_ref()
         .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
 
-    // Look up match from global store, then fall back to scanning leagues
-    let match_result = simulator_data
-        .match_store
-        .get(&route_params.match_id)
-        .or_else(|| {
-            // Fall back: scan each country's per-league match stores. The
-            // domestic cup lives on `Country::domestic_cup`, outside the
-            // `leagues` collection, so scan its inner league too — otherwise
-            // cup ties linked from the bracket would 404.
-            simulator_data
-                .continents
-                .iter()
-                .flat_map(|c| &c.countries)
-                .find_map(|country| {
-                    country
-                        .leagues
-                        .leagues
-                        .iter()
-                        .find_map(|l| l.matches.get(&route_params.match_id))
-                        .or_else(|| {
-                            country
-                                .domestic_cup
-                                .as_ref()
-                                .and_then(|cup| cup.league.matches.get(&route_params.match_id))
-                        })
-                })
-        })
-        .ok_or_else(|| {
-            ApiError::NotFound(format!("Match '{}' not found", route_params.match_id))
-        })?;
+    let match_result = find_match(simulator_data, &route_params.match_id).ok_or_else(|| {
+        ApiError::NotFound(format!("Match '{}' not found", route_params.match_id))
+    })?;
 
     let league = simulator_data.league(match_result.league_id);
 
@@ -267,90 +246,10 @@ pub async fn match_get_action(
     let home_goals = score.home_team.get();
     let away_goals = score.away_team.get();
 
-    let home_goal_events: Vec<GoalEventDisplay> = score
-        .detail()
-        .iter()
-        .filter(|g| g.stat_type == MatchStatisticType::Goal)
-        .filter(|g| {
-            let is_home_player = result_details.left_team_players.main.contains(&g.player_id)
-                || result_details
-                    .left_team_players
-                    .substitutes
-                    .contains(&g.player_id);
-            if g.is_auto_goal {
-                !is_home_player
-            } else {
-                is_home_player
-            }
-        })
-        .map(|g| {
-            let player_name = simulator_data
-                .player(g.player_id)
-                .map(|p| {
-                    format!(
-                        "{} {}",
-                        p.full_name.display_first_name(),
-                        p.full_name.display_last_name()
-                    )
-                })
-                .unwrap_or_else(|| "Unknown".to_string());
-            let minute = if result_details.match_time_ms > 0 {
-                (g.time * 90 / result_details.match_time_ms) as u32
-            } else {
-                0
-            };
-            GoalEventDisplay {
-                player_slug: player_history_slug(simulator_data, g.player_id, &player_name),
-                player_name,
-                minute,
-                is_auto_goal: g.is_auto_goal,
-            }
-        })
-        .collect();
-
-    let away_goal_events: Vec<GoalEventDisplay> = score
-        .detail()
-        .iter()
-        .filter(|g| g.stat_type == MatchStatisticType::Goal)
-        .filter(|g| {
-            let is_away_player = result_details
-                .right_team_players
-                .main
-                .contains(&g.player_id)
-                || result_details
-                    .right_team_players
-                    .substitutes
-                    .contains(&g.player_id);
-            if g.is_auto_goal {
-                !is_away_player
-            } else {
-                is_away_player
-            }
-        })
-        .map(|g| {
-            let player_name = simulator_data
-                .player(g.player_id)
-                .map(|p| {
-                    format!(
-                        "{} {}",
-                        p.full_name.display_first_name(),
-                        p.full_name.display_last_name()
-                    )
-                })
-                .unwrap_or_else(|| "Unknown".to_string());
-            let minute = if result_details.match_time_ms > 0 {
-                (g.time * 90 / result_details.match_time_ms) as u32
-            } else {
-                0
-            };
-            GoalEventDisplay {
-                player_slug: player_history_slug(simulator_data, g.player_id, &player_name),
-                player_name,
-                minute,
-                is_auto_goal: g.is_auto_goal,
-            }
-        })
-        .collect();
+    let home_goal_events = build_goal_events(simulator_data, result_details, true);
+    let away_goal_events = build_goal_events(simulator_data, result_details, false);
+    let (home_squad_main, home_squad_subs) = build_squad(simulator_data, result_details, true);
+    let (away_squad_main, away_squad_subs) = build_squad(simulator_data, result_details, false);
 
     let motm_id = result_details.player_of_the_match_id;
     let motm_name = motm_id
@@ -417,108 +316,14 @@ pub async fn match_get_action(
         home_team_slug: home_team_slug.clone(),
         home_goals,
         home_goal_events,
-        home_squad_main: result_details
-            .left_team_players
-            .main
-            .iter()
-            .filter_map(|pid| {
-                let mut p = to_match_player(*pid, simulator_data, motm_id, result_details)?;
-                if let Some(sub) = result_details
-                    .substitutions
-                    .iter()
-                    .find(|s| s.player_out_id == *pid)
-                {
-                    p.subbed_off_minute = Some(sub_time_to_minute(
-                        sub.match_time_ms,
-                        result_details.match_time_ms,
-                    ));
-                }
-                Some(p)
-            })
-            .collect(),
-        home_squad_subs: result_details
-            .left_team_players
-            .substitutes
-            .iter()
-            .filter_map(|pid| {
-                let mut p = to_match_player(*pid, simulator_data, motm_id, result_details)?;
-                if let Some(sub) = result_details
-                    .substitutions
-                    .iter()
-                    .find(|s| s.player_in_id == *pid)
-                {
-                    p.sub_minute = Some(sub_time_to_minute(
-                        sub.match_time_ms,
-                        result_details.match_time_ms,
-                    ));
-                }
-                // Check if this sub was also later subbed off (sub-of-sub)
-                if let Some(sub_off) = result_details
-                    .substitutions
-                    .iter()
-                    .find(|s| s.player_out_id == *pid)
-                {
-                    p.subbed_off_minute = Some(sub_time_to_minute(
-                        sub_off.match_time_ms,
-                        result_details.match_time_ms,
-                    ));
-                }
-                Some(p)
-            })
-            .collect(),
+        home_squad_main,
+        home_squad_subs,
         away_team_name: away_team_name.clone(),
         away_team_slug: away_team_slug.clone(),
         away_goals,
         away_goal_events,
-        away_squad_main: result_details
-            .right_team_players
-            .main
-            .iter()
-            .filter_map(|pid| {
-                let mut p = to_match_player(*pid, simulator_data, motm_id, result_details)?;
-                if let Some(sub) = result_details
-                    .substitutions
-                    .iter()
-                    .find(|s| s.player_out_id == *pid)
-                {
-                    p.subbed_off_minute = Some(sub_time_to_minute(
-                        sub.match_time_ms,
-                        result_details.match_time_ms,
-                    ));
-                }
-                Some(p)
-            })
-            .collect(),
-        away_squad_subs: result_details
-            .right_team_players
-            .substitutes
-            .iter()
-            .filter_map(|pid| {
-                let mut p = to_match_player(*pid, simulator_data, motm_id, result_details)?;
-                if let Some(sub) = result_details
-                    .substitutions
-                    .iter()
-                    .find(|s| s.player_in_id == *pid)
-                {
-                    p.sub_minute = Some(sub_time_to_minute(
-                        sub.match_time_ms,
-                        result_details.match_time_ms,
-                    ));
-                }
-                // Check if this sub was also later subbed off (sub-of-sub)
-                if let Some(sub_off) = result_details
-                    .substitutions
-                    .iter()
-                    .find(|s| s.player_out_id == *pid)
-                {
-                    p.subbed_off_minute = Some(sub_time_to_minute(
-                        sub_off.match_time_ms,
-                        result_details.match_time_ms,
-                    ));
-                }
-                Some(p)
-            })
-            .collect(),
+        away_squad_main,
+        away_squad_subs,
         match_time_ms: result_details.match_time_ms,
         goals_json: serde_json::to_string(&goals_json).unwrap_or_else(|_| "[]".to_string()),
         players_json: serde_json::to_string(&players_json).unwrap_or_else(|_| "[]".to_string()),
@@ -559,9 +364,145 @@ pub async fn match_get_action(
         player_of_the_match_name: motm_name,
         match_recordings_enabled: MatchRuntime::recordings_mode()
             && league.is_some_and(|l| !l.friendly),
+        team_stats: report::build_team_stats(result_details),
+        timeline: report::build_timeline(simulator_data, result_details),
     })
 }
 
+/// Looks `match_id` up in the global match store, falling back to each
+/// country's per-league stores and its domestic cup.
+pub fn find_match<'a>(
+    simulator_data: &'a SimulatorData,
+    match_id: &str,
+) -> Option<&'a MatchResult> {
+    simulator_data.match_store.get(match_id).or_else(|| {
+        // The domestic cup lives on `Country::domestic_cup`, outside the
+        // `leagues` collection, so scan its inner league too — otherwise
+        // cup ties linked from the bracket would 404.
+        simulator_data
+            .continents
+            .iter()
+            .flat_map(|c| &c.countries)
+            .find_map(|country| {
+                country
+                    .leagues
+                    .leagues
+                    .iter()
+                    .find_map(|l| l.matches.get(match_id))
+                    .or_else(|| {
+                        country
+                            .domestic_cup
+                            .as_ref()
+                            .and_then(|cup| cup.league.matches.get(match_id))
+                    })
+            })
+    })
+}
+
+/// Goals credited to one side: its own players' goals plus the other
+/// side's own goals.
+pub fn build_goal_events(
+    simulator_data: &SimulatorData,
+    result_details: &MatchResultRaw,
+    home: bool,
+) -> Vec<GoalEventDisplay> {
+    let Some(score) = result_details.score.as_ref() else {
+        return Vec::new();
+    };
+    let squad = if home {
+        &result_details.left_team_players
+    } else {
+        &result_details.right_team_players
+    };
+
+    score
+        .detail()
+        .iter()
+        .filter(|g| g.stat_type == MatchStatisticType::Goal)
+        .filter(|g| {
+            let is_side_player =
+                squad.main.contains(&g.player_id) || squad.substitutes.contains(&g.player_id);
+            if g.is_auto_goal {
+                !is_side_player
+            } else {
+                is_side_player
+            }
+        })
+        .map(|g| {
+            let player_name = simulator_data
+                .player(g.player_id)
+                .map(|p| {
+                    format!(
+                        "{} {}",
+                        p.full_name.display_first_name(),
+                        p.full_name.display_last_name()
+                    )
+                })
+                .unwrap_or_else(|| "Unknown".to_string());
+            let minute = if result_details.match_time_ms > 0 {
+                (g.time * 90 / result_details.match_time_ms) as u32
+            } else {
+                0
+            };
+            GoalEventDisplay {
+                player_slug: player_history_slug(simulator_data, g.player_id, &player_name),
+                player_name,
+                minute,
+                is_auto_goal: g.is_auto_goal,
+            }
+        })
+        .collect()
+}
+
+/// One side's starting XI and bench, with substitution minutes filled
+/// in.
+pub fn build_squad(
+    simulator_data: &SimulatorData,
+    result_details: &MatchResultRaw,
+    home: bool,
+) -> (Vec<MatchPlayer>, Vec<MatchPlayer>) {
+    let squad = if home {
+        &result_details.left_team_players
+    } else {
+        &result_details.right_team_players
+    };
+    let motm_id = result_details.player_of_the_match_id;
+    let minute_of = |sub_time_ms| sub_time_to_minute(sub_time_ms, result_details.match_time_ms);
+    let subbed_off = |pid: u32| {
+        result_details
+            .substitutions
+            .iter()
+            .find(|s| s.player_out_id == pid)
+            .map(|s| minute_of(s.match_time_ms))
+    };
+
+    let main = squad
+        .main
+        .iter()
+        .filter_map(|pid| {
+            let mut p = to_match_player(*pid, simulator_data, motm_id, result_details)?;
+            p.subbed_off_minute = subbed_off(*pid);
+            Some(p)
+        })
+        .collect();
+    let subs = squad
+        .substitutes
+        .iter()
+        .filter_map(|pid| {
+            let mut p = to_match_player(*pid, simulator_data, motm_id, result_details)?;
+            p.sub_minute = result_details
+                .substitutions
+                .iter()
+                .find(|s| s.player_in_id == *pid)
+                .map(|s| minute_of(s.match_time_ms));
+            // A substitute can be subbed off again later (sub-of-sub).
+            p.subbed_off_minute = subbed_off(*pid);
+            Some(p)
+        })
+        .collect();
+    (main, subs)
+}
+
 fn to_match_player(
     player_id: u32,
     simulator_data: &SimulatorData,
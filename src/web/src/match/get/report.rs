@@ -0,0 +1,347 @@
+//! Post-match report blocks under the replay: the side-by-side team
+//! statistics panel and the typed event timeline. Both are read straight
+//! off the persisted `MatchResultRaw`, so they survive a save/load.
+
+use crate::common::slug::player_history_slug;
+use core::SimulatorData;
+use core::r#match::player::statistics::MatchStatisticType;
+use core::r#match::{FieldSquad, MatchResultRaw};
+
+#[derive(Debug, PartialEq)]
+pub struct MatchStatRow {
+    pub label_key: &'static str,
+    pub home: String,
+    pub away: String,
+    /// Home side's share of the bar, 0-100.
+    pub home_share: u32,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum TimelineEventKind {
+    Goal,
+    OwnGoal,
+    YellowCard,
+    RedCard,
+    Substitution,
+}
+
+impl TimelineEventKind {
+    pub fn css_class(&self) -> &'static str {
+        match self {
+            TimelineEventKind::Goal => "goal",
+            TimelineEventKind::OwnGoal => "own-goal",
+            TimelineEventKind::YellowCard => "yellow-card",
+            TimelineEventKind::RedCard => "red-card",
+            TimelineEventKind::Substitution => "substitution",
+        }
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            TimelineEventKind::Goal => "match_event_goal",
+            TimelineEventKind::OwnGoal => "match_event_own_goal",
+            TimelineEventKind::YellowCard => "match_event_yellow_card",
+            TimelineEventKind::RedCard => "match_event_red_card",
+            TimelineEventKind::Substitution => "match_event_substitution",
+        }
+    }
+}
+
+pub struct TimelineEvent {
+    pub minute: u32,
+    pub kind: TimelineEventKind,
+    pub is_home: bool,
+    pub player_name: String,
+    pub player_slug: String,
+    /// The player coming off for a substitution; empty otherwise.
+    pub other_player_name: String,
+    pub other_player_slug: String,
+}
+
+#[derive(Default)]
+struct SideTotals {
+    shots: u32,
+    shots_on_target: u32,
+    xg: f32,
+    passes_attempted: u32,
+    passes_completed: u32,
+    fouls: u32,
+    yellow_cards: u32,
+    red_cards: u32,
+}
+
+impl SideTotals {
+    fn collect(result: &MatchResultRaw, squad: &FieldSquad) -> Self {
+        let mut totals = SideTotals::default();
+        for stats in squad
+            .main
+            .iter()
+            .chain(&squad.substitutes)
+            .filter_map(|id| result.player_stats.get(id))
+        {
+            totals.shots += stats.shots_total as u32;
+            totals.shots_on_target += stats.shots_on_target as u32;
+            totals.xg += stats.xg;
+            totals.passes_attempted += stats.passes_attempted as u32;
+            totals.passes_completed += stats.passes_completed as u32;
+            totals.fouls += stats.fouls as u32;
+            totals.yellow_cards += stats.yellow_cards as u32;
+            totals.red_cards += stats.red_cards as u32;
+        }
+        totals
+    }
+
+    fn pass_accuracy(&self) -> u32 {
+        if self.passes_attempted == 0 {
+            return 0;
+        }
+        (self.passes_completed as f32 / self.passes_attempted as f32 * 100.0).round() as u32
+    }
+}
+
+fn share(home: f32, away: f32) -> u32 {
+    let total = home + away;
+    if total <= 0.0 {
+        return 50;
+    }
+    (home / total * 100.0).round() as u32
+}
+
+fn count_row(label_key: &'static str, home: u32, away: u32) -> MatchStatRow {
+    MatchStatRow {
+        label_key,
+        home: home.to_string(),
+        away: away.to_string(),
+        home_share: share(home as f32, away as f32),
+    }
+}
+
+fn percent_row(label_key: &'static str, home: u32, away: u32) -> MatchStatRow {
+    MatchStatRow {
+        label_key,
+        home: format!("{}%", home),
+        away: format!("{}%", away),
+        home_share: share(home as f32, away as f32),
+    }
+}
+
+/// Team statistics rows, home on the left. Empty for matches that carry
+/// no per-player stats (e.g. results saved before they were recorded).
+pub fn build_team_stats(result: &MatchResultRaw) -> Vec<MatchStatRow> {
+    if result.player_stats.is_empty() {
+        return Vec::new();
+    }
+    let home = SideTotals::collect(result, &result.left_team_players);
+    let away = SideTotals::collect(result, &result.right_team_players);
+
+    let mut rows = Vec::with_capacity(9);
+    if let Some(home_share) = result.home_possession_share() {
+        let home_pct = (home_share * 100.0).round() as u32;
+        rows.push(percent_row(
+            "match_stat_possession",
+            home_pct,
+            100 - home_pct,
+        ));
+    }
+    rows.push(count_row("match_stat_shots", home.shots, away.shots));
+    rows.push(count_row(
+        "match_stat_shots_on_target",
+        home.shots_on_target,
+        away.shots_on_target,
+    ));
+    rows.push(MatchStatRow {
+        label_key: "match_stat_xg",
+        home: format!("{:.2}", home.xg),
+        away: format!("{:.2}", away.xg),
+        home_share: share(home.xg, away.xg),
+    });
+    rows.push(count_row(
+        "match_stat_passes",
+        home.passes_attempted,
+        away.passes_attempted,
+    ));
+    rows.push(percent_row(
+        "match_stat_pass_accuracy",
+        home.pass_accuracy(),
+        away.pass_accuracy(),
+    ));
+    rows.push(count_row("match_stat_fouls", home.fouls, away.fouls));
+    rows.push(count_row(
+        "match_stat_yellow_cards",
+        home.yellow_cards,
+        away.yellow_cards,
+    ));
+    rows.push(count_row(
+        "match_stat_red_cards",
+        home.red_cards,
+        away.red_cards,
+    ));
+    rows
+}
+
+fn player_link(simulator_data: &SimulatorData, player_id: u32) -> (String, String) {
+    let name = simulator_data
+        .player(player_id)
+        .map(|p| {
+            format!(
+                "{} {}",
+                p.full_name.display_first_name(),
+                p.full_name.display_last_name()
+            )
+        })
+        .unwrap_or_else(|| "Unknown".to_string());
+    let slug = player_history_slug(simulator_data, player_id, &name);
+    (name, slug)
+}
+
+/// Goals, cards and substitutions in match order. An own goal is shown
+/// on the side it was credited to.
+pub fn build_timeline(
+    simulator_data: &SimulatorData,
+    result: &MatchResultRaw,
+) -> Vec<TimelineEvent> {
+    let minute_of = |time_ms: u64| {
+        (time_ms * 90)
+            .checked_div(result.match_time_ms)
+            .unwrap_or(0) as u32
+    };
+    let is_home_player = |id: u32| {
+        let squad = &result.left_team_players;
+        squad.main.contains(&id) || squad.substitutes.contains(&id)
+    };
+
+    let mut events: Vec<(u64, TimelineEvent)> = Vec::new();
+
+    if let Some(score) = result.score.as_ref() {
+        for detail in score.detail() {
+            let kind = match detail.stat_type {
+                MatchStatisticType::Goal if detail.is_auto_goal => TimelineEventKind::OwnGoal,
+                MatchStatisticType::Goal => TimelineEventKind::Goal,
+                MatchStatisticType::YellowCard => TimelineEventKind::YellowCard,
+                MatchStatisticType::RedCard => TimelineEventKind::RedCard,
+                _ => continue,
+            };
+            let (player_name, player_slug) = player_link(simulator_data, detail.player_id);
+            let is_home = is_home_player(detail.player_id) != (kind == TimelineEventKind::OwnGoal);
+            events.push((
+                detail.time,
+                TimelineEvent {
+                    minute: minute_of(detail.time),
+                    kind,
+                    is_home,
+                    player_name,
+                    player_slug,
+                    other_player_name: String::new(),
+                    other_player_slug: String::new(),
+                },
+            ));
+        }
+    }
+
+    for sub in &result.substitutions {
+        let (player_name, player_slug) = player_link(simulator_data, sub.player_in_id);
+        let (other_player_name, other_player_slug) = player_link(simulator_data, sub.player_out_id);
+        events.push((
+            sub.match_time_ms,
+            TimelineEvent {
+                minute: minute_of(sub.match_time_ms),
+                kind: TimelineEventKind::Substitution,
+                is_home: is_home_player(sub.player_out_id),
+                player_name,
+                player_slug,
+                other_player_name,
+                other_player_slug,
+            },
+        ));
+    }
+
+    events.sort_by_key(|(time, _)| *time);
+    events.into_iter().map(|(_, event)| event).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::PlayerFieldPositionGroup;
+    use core::r#match::PlayerMatchEndStats;
+
+    fn stats(shots: u16, passes: u16, completed: u16, xg: f32) -> PlayerMatchEndStats {
+        PlayerMatchEndStats {
+            shots_on_target: 0,
+            shots_total: shots,
+            passes_attempted: passes,
+            passes_completed: completed,
+            tackles: 0,
+            interceptions: 0,
+            saves: 0,
+            shots_faced: 0,
+            goals: 0,
+            assists: 0,
+            match_rating: 6.5,
+            raw_match_rating: 6.5,
+            xg,
+            position_group: PlayerFieldPositionGroup::Midfielder,
+            fouls: 0,
+            yellow_cards: 0,
+            red_cards: 0,
+            minutes_played: 90,
+            key_passes: 0,
+            progressive_passes: 0,
+            progressive_carries: 0,
+            successful_dribbles: 0,
+            attempted_dribbles: 0,
+            successful_pressures: 0,
+            pressures: 0,
+            blocks: 0,
+            clearances: 0,
+            passes_into_box: 0,
+            crosses_attempted: 0,
+            crosses_completed: 0,
+            xg_chain: 0.0,
+            xg_buildup: 0.0,
+            miscontrols: 0,
+            heavy_touches: 0,
+            carry_distance: 0,
+            errors_leading_to_shot: 0,
+            errors_leading_to_goal: 0,
+            xg_prevented: 0.0,
+            offsides: 0,
+            own_goals: 0,
+            zone_stats: Default::default(),
+        }
+    }
+
+    #[test]
+    fn team_stats_sum_each_side_and_split_possession() {
+        let mut result = MatchResultRaw::with_match_time(90 * 60 * 1000);
+        result.left_team_players.main = vec![1];
+        result.left_team_players.substitutes = vec![2];
+        result.right_team_players.main = vec![3];
+        result.player_stats.insert(1, stats(3, 40, 30, 0.5));
+        result.player_stats.insert(2, stats(1, 10, 10, 0.25));
+        result.player_stats.insert(3, stats(2, 50, 25, 0.4));
+        result.home_possession_ticks = 600;
+        result.away_possession_ticks = 400;
+
+        let rows = build_team_stats(&result);
+        let row = |key: &str| rows.iter().find(|r| r.label_key == key).unwrap();
+
+        assert_eq!(row("match_stat_possession").home, "60%");
+        assert_eq!(row("match_stat_possession").away, "40%");
+        assert_eq!(row("match_stat_shots").home, "4");
+        assert_eq!(row("match_stat_shots").home_share, 67);
+        assert_eq!(row("match_stat_xg").home, "0.75");
+        assert_eq!(row("match_stat_pass_accuracy").home, "80%");
+        assert_eq!(row("match_stat_pass_accuracy").away, "50%");
+    }
+
+    #[test]
+    fn team_stats_skip_possession_when_not_recorded() {
+        let mut result = MatchResultRaw::with_match_time(90 * 60 * 1000);
+        result.left_team_players.main = vec![1];
+        result.player_stats.insert(1, stats(1, 1, 1, 0.1));
+
+        let rows = build_team_stats(&result);
+        assert!(rows.iter().all(|r| r.label_key != "match_stat_possession"));
+    }
+}
@@ -1,7 +1,9 @@
 use async_compression::tokio::write::GzipEncoder;
 use core::r#match::{MatchResult, ResultMatchPositionData};
+use flate2::read::GzDecoder;
 use log::debug;
-use std::path::PathBuf;
+use std::io;
+use std::path::{Path, PathBuf};
 use tokio::fs::File;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 
@@ -78,6 +80,35 @@ impl MatchStore {
         Some(metadata)
     }
 
+    /// Full replays (not chunks) under `dir` — the store writes them to
+    /// `match_results/<league>/` — in path order.
+    pub fn replay_files(dir: &Path) -> io::Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        let mut pending = vec![dir.to_path_buf()];
+        while let Some(dir) = pending.pop() {
+            for entry in std::fs::read_dir(&dir)? {
+                let path = entry?.path();
+                let name = path
+                    .file_name()
+                    .and_then(|n| n.to_str())
+                    .unwrap_or_default();
+                if path.is_dir() {
+                    pending.push(path);
+                } else if name.ends_with(".json.gz") && !name.contains("_chunk_") {
+                    files.push(path);
+                }
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// One replay written by `store`, decompressed and parsed.
+    pub fn read_replay(path: &Path) -> io::Result<serde_json::Value> {
+        let file = std::fs::File::open(path)?;
+        serde_json::from_reader(io::BufReader::new(GzDecoder::new(file))).map_err(io::Error::other)
+    }
+
     pub async fn store(result: MatchResult) {
         let out_dir = PathBuf::from(MATCH_DIRECTORY).join(&result.league_slug);
 
@@ -0,0 +1,446 @@
+//! `GET /metrics` in the Prometheus text format, for operators watching a
+//! long-running server: HTTP latencies by route, the duration and match
+//! count of every simulated day, cumulative per-league processing time,
+//! learned-model inference cost, process memory and the size of the
+//! loaded world.
+
+pub mod routes;
+
+use crate::GameAppData;
+use axum::extract::{MatchedPath, Request, State};
+use axum::http::header::CONTENT_TYPE;
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use core::r#match::{HistogramSnapshot, InferenceTelemetry};
+use core::{SimulationProgress, SimulatorData};
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System};
+
+pub fn metrics_routes() -> axum::Router<GameAppData> {
+    routes::routes()
+}
+
+const LATENCY_BUCKETS: &[f64] = &[
+    0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0,
+];
+const DAY_DURATION_BUCKETS: &[f64] = &[0.5, 1.0, 2.5, 5.0, 10.0, 20.0, 40.0, 80.0, 160.0];
+const DAY_MATCHES_BUCKETS: &[f64] = &[0.0, 10.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2000.0, 5000.0];
+
+/// Cumulative histogram with fixed upper bounds, rendered with the
+/// implicit `+Inf` bucket.
+struct Histogram {
+    bounds: &'static [f64],
+    counts: Vec<u64>,
+    sum: f64,
+    count: u64,
+}
+
+impl Histogram {
+    fn new(bounds: &'static [f64]) -> Self {
+        Histogram {
+            bounds,
+            counts: vec![0; bounds.len()],
+            sum: 0.0,
+            count: 0,
+        }
+    }
+
+    fn observe(&mut self, value: f64) {
+        if let Some(idx) = self.bounds.iter().position(|b| value <= *b) {
+            self.counts[idx] += 1;
+        }
+        self.sum += value;
+        self.count += 1;
+    }
+
+    fn from_snapshot(snapshot: HistogramSnapshot) -> Self {
+        Histogram {
+            bounds: snapshot.bounds,
+            counts: snapshot.counts,
+            sum: snapshot.sum,
+            count: snapshot.count,
+        }
+    }
+
+    /// `labels` is the rendered label list without braces, possibly empty.
+    fn render(&self, out: &mut String, name: &str, labels: &str) {
+        let sep = if labels.is_empty() { "" } else { "," };
+        let mut cumulative = 0;
+        for (bound, count) in self.bounds.iter().zip(&self.counts) {
+            cumulative += count;
+            let _ = writeln!(
+                out,
+                "{}_bucket{{{}{}le=\"{}\"}} {}",
+                name, labels, sep, bound, cumulative
+            );
+        }
+        let _ = writeln!(
+            out,
+            "{}_bucket{{{}{}le=\"+Inf\"}} {}",
+            name, labels, sep, self.count
+        );
+        let braced = if labels.is_empty() {
+            String::new()
+        } else {
+            format!("{{{}}}", labels)
+        };
+        let _ = writeln!(out, "{}_sum{} {}", name, braced, self.sum);
+        let _ = writeln!(out, "{}_count{} {}", name, braced, self.count);
+    }
+}
+
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct HttpKey {
+    method: String,
+    route: String,
+    status: u16,
+}
+
+struct Registry {
+    http: BTreeMap<HttpKey, Histogram>,
+    day_duration: Histogram,
+    day_matches: Histogram,
+}
+
+/// Metrics recorded by the web layer. Cheap to clone; every clone feeds
+/// the same registry.
+#[derive(Clone)]
+pub struct Metrics {
+    registry: Arc<Mutex<Registry>>,
+}
+
+impl Default for Metrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Metrics {
+    pub fn new() -> Self {
+        Metrics {
+            registry: Arc::new(Mutex::new(Registry {
+                http: BTreeMap::new(),
+                day_duration: Histogram::new(DAY_DURATION_BUCKETS),
+                day_matches: Histogram::new(DAY_MATCHES_BUCKETS),
+            })),
+        }
+    }
+
+    fn record_request(&self, method: &str, route: &str, status: u16, elapsed: Duration) {
+        let Ok(mut registry) = self.registry.lock() else {
+            return;
+        };
+        let key = HttpKey {
+            method: method.to_string(),
+            route: route.to_string(),
+            status,
+        };
+        registry
+            .http
+            .entry(key)
+            .or_insert_with(|| Histogram::new(LATENCY_BUCKETS))
+            .observe(elapsed.as_secs_f64());
+    }
+
+    /// Record one simulated day: how long the tick took and how many
+    /// matches it played.
+    pub fn record_day(&self, elapsed: Duration, matches: usize) {
+        let Ok(mut registry) = self.registry.lock() else {
+            return;
+        };
+        registry.day_duration.observe(elapsed.as_secs_f64());
+        registry.day_matches.observe(matches as f64);
+    }
+
+    fn render(&self, out: &mut String) {
+        let Ok(registry) = self.registry.lock() else {
+            return;
+        };
+
+        header(
+            out,
+            "of_http_request_duration_seconds",
+            "HTTP request latency by route.",
+            "histogram",
+        );
+        for (key, histogram) in &registry.http {
+            let labels = format!(
+                "method=\"{}\",route=\"{}\",status=\"{}\"",
+                escape(&key.method),
+                escape(&key.route),
+                key.status
+            );
+            histogram.render(out, "of_http_request_duration_seconds", &labels);
+        }
+
+        header(
+            out,
+            "of_simulation_day_duration_seconds",
+            "Wall time of one simulated day.",
+            "histogram",
+        );
+        registry
+            .day_duration
+            .render(out, "of_simulation_day_duration_seconds", "");
+
+        header(
+            out,
+            "of_simulation_day_matches",
+            "Matches played in one simulated day.",
+            "histogram",
+        );
+        registry
+            .day_matches
+            .render(out, "of_simulation_day_matches", "");
+    }
+}
+
+fn header(out: &mut String, name: &str, help: &str, kind: &str) {
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} {}", name, kind);
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+fn render_simulation(out: &mut String) {
+    let counters = SimulationProgress::counters();
+    header(
+        out,
+        "of_matches_simulated_total",
+        "Matches played by the engine since start.",
+        "counter",
+    );
+    let _ = writeln!(
+        out,
+        "of_matches_simulated_total {}",
+        counters.matches_simulated
+    );
+
+    let timings = SimulationProgress::league_timings();
+    header(
+        out,
+        "of_league_processing_seconds_total",
+        "Time spent in each league's daily passes, match engine excluded.",
+        "counter",
+    );
+    for timing in &timings {
+        let _ = writeln!(
+            out,
+            "of_league_processing_seconds_total{{league=\"{}\"}} {}",
+            escape(&timing.slug),
+            timing.total.as_secs_f64()
+        );
+    }
+    header(
+        out,
+        "of_league_passes_total",
+        "Daily passes run for each league.",
+        "counter",
+    );
+    for timing in &timings {
+        let _ = writeln!(
+            out,
+            "of_league_passes_total{{league=\"{}\"}} {}",
+            escape(&timing.slug),
+            timing.passes
+        );
+    }
+}
+
+/// Per-call latency and per-tick call count of each learned model the
+/// engine consults; absent until a model is installed.
+fn render_inference(out: &mut String) {
+    if !InferenceTelemetry::is_enabled() {
+        return;
+    }
+    let models = InferenceTelemetry::snapshot();
+    header(
+        out,
+        "of_inference_duration_seconds",
+        "Time one call of a learned model takes.",
+        "histogram",
+    );
+    for model in &models {
+        let labels = format!("model=\"{}\"", model.model.name());
+        Histogram::from_snapshot(model.latency.clone()).render(
+            out,
+            "of_inference_duration_seconds",
+            &labels,
+        );
+    }
+    header(
+        out,
+        "of_inference_calls_per_tick",
+        "Calls of a learned model during one match engine tick.",
+        "histogram",
+    );
+    for model in models {
+        let labels = format!("model=\"{}\"", model.model.name());
+        Histogram::from_snapshot(model.calls_per_tick).render(
+            out,
+            "of_inference_calls_per_tick",
+            &labels,
+        );
+    }
+}
+
+fn render_process_memory(out: &mut String) {
+    let Ok(pid) = sysinfo::get_current_pid() else {
+        return;
+    };
+    let mut system = System::new();
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing().with_memory(),
+    );
+    let Some(process) = system.process(pid) else {
+        return;
+    };
+    header(
+        out,
+        "of_process_resident_memory_bytes",
+        "Resident memory of the server process, dominated by the loaded world.",
+        "gauge",
+    );
+    let _ = writeln!(out, "of_process_resident_memory_bytes {}", process.memory());
+}
+
+fn render_world(out: &mut String, sim: &SimulatorData) {
+    let countries = sim.continents.iter().flat_map(|c| &c.countries);
+    let clubs: usize = countries.clone().map(|c| c.clubs.len()).sum();
+    let leagues: usize = countries.clone().map(|c| c.leagues.leagues.len()).sum();
+    let players: usize = countries
+        .flat_map(|c| &c.clubs)
+        .flat_map(|c| &c.teams.teams)
+        .map(|t| t.players.players.len())
+        .sum();
+
+    header(
+        out,
+        "of_world_entities",
+        "Entities in the loaded world.",
+        "gauge",
+    );
+    for (kind, count) in [
+        ("clubs", clubs),
+        ("leagues", leagues),
+        ("players", players),
+        ("free_agents", sim.free_agents.len()),
+    ] {
+        let _ = writeln!(out, "of_world_entities{{kind=\"{}\"}} {}", kind, count);
+    }
+}
+
+/// Middleware timing every request against its route template, so
+/// `/api/players/{player_id}` is one series rather than one per player.
+pub async fn track_http_metrics(
+    State(state): State<GameAppData>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let method = request.method().to_string();
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|p| p.as_str().to_string())
+        .unwrap_or_else(|| "unmatched".to_string());
+
+    let started = Instant::now();
+    let response = next.run(request).await;
+    state.metrics.record_request(
+        &method,
+        &route,
+        response.status().as_u16(),
+        started.elapsed(),
+    );
+    response
+}
+
+/// `GET /metrics` — Prometheus text exposition.
+pub async fn metrics_action(State(state): State<GameAppData>) -> impl IntoResponse {
+    let mut out = String::new();
+    state.metrics.render(&mut out);
+    render_simulation(&mut out);
+    render_inference(&mut out);
+    render_process_memory(&mut out);
+    {
+        let guard = state.data.read().await;
+        if let Some(sim) = guard.as_ref() {
+            render_world(&mut out, sim);
+        }
+    }
+
+    (
+        [(CONTENT_TYPE, "text/plain; version=0.0.4; charset=utf-8")],
+        out,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn histogram_renders_cumulative_buckets() {
+        let mut histogram = Histogram::new(&[0.1, 1.0]);
+        histogram.observe(0.05);
+        histogram.observe(0.5);
+        histogram.observe(3.0);
+
+        let mut out = String::new();
+        histogram.render(&mut out, "x", "route=\"/a\"");
+
+        assert_eq!(
+            out,
+            "x_bucket{route=\"/a\",le=\"0.1\"} 1\n\
+             x_bucket{route=\"/a\",le=\"1\"} 2\n\
+             x_bucket{route=\"/a\",le=\"+Inf\"} 3\n\
+             x_sum{route=\"/a\"} 3.55\n\
+             x_count{route=\"/a\"} 3\n"
+        );
+    }
+
+    #[test]
+    fn inference_histograms_are_labelled_by_model() {
+        use core::r#match::InferenceModel;
+
+        InferenceTelemetry::enable();
+        InferenceTelemetry::time(InferenceModel::PassSuccess, || ());
+
+        let mut out = String::new();
+        render_inference(&mut out);
+
+        assert!(out.contains("# TYPE of_inference_duration_seconds histogram"));
+        assert!(out.contains("of_inference_duration_seconds_count{model=\"pass_success\"}"));
+        assert!(
+            out.contains("of_inference_calls_per_tick_bucket{model=\"pass_success\",le=\"0\"}")
+        );
+    }
+
+    #[test]
+    fn requests_are_grouped_by_route_and_status() {
+        let metrics = Metrics::new();
+        metrics.record_request("GET", "/api/x/{id}", 200, Duration::from_millis(3));
+        metrics.record_request("GET", "/api/x/{id}", 200, Duration::from_millis(30));
+        metrics.record_request("GET", "/api/x/{id}", 404, Duration::from_millis(1));
+
+        let mut out = String::new();
+        metrics.render(&mut out);
+
+        assert!(out.contains(
+            "of_http_request_duration_seconds_count{method=\"GET\",route=\"/api/x/{id}\",status=\"200\"} 2"
+        ));
+        assert!(out.contains(
+            "of_http_request_duration_seconds_count{method=\"GET\",route=\"/api/x/{id}\",status=\"404\"} 1"
+        ));
+    }
+}
@@ -0,0 +1,7 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new().route("/metrics", get(super::metrics_action))
+}
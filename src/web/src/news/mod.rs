@@ -0,0 +1,298 @@
+pub mod routes;
+
+use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
+use crate::views::{self, MenuSection};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData, I18n};
+use askama::Template;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use core::utils::FormattingUtils;
+use core::{NewsArg, NewsItem, SimulatorData};
+use serde::{Deserialize, Serialize};
+
+pub fn news_routes() -> axum::Router<GameAppData> {
+    routes::routes()
+}
+
+/// Stories shown on the inbox page.
+const INBOX_PAGE_SIZE: usize = 200;
+
+/// Default and maximum page size of the JSON feed.
+const DEFAULT_FEED_LIMIT: usize = 50;
+const MAX_FEED_LIMIT: usize = 500;
+
+#[derive(Serialize)]
+pub struct NewsItemDto {
+    pub id: u32,
+    pub date: String,
+    pub kind: &'static str,
+    pub headline: String,
+    pub team_name: String,
+    pub team_slug: String,
+    pub player_slug: String,
+}
+
+#[derive(Deserialize)]
+pub struct InboxPageRequest {
+    pub lang: String,
+}
+
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "news/index.html")]
+pub struct InboxPageTemplate {
+    pub css_version: &'static str,
+    pub computer_name: &'static str,
+    pub cpu_brand: &'static str,
+    pub cores_count: usize,
+    pub i18n: I18n,
+    pub lang: String,
+    pub title: String,
+    pub sub_title_prefix: String,
+    pub sub_title_suffix: String,
+    pub sub_title: String,
+    pub sub_title_link: String,
+    pub sub_title_country_code: String,
+    pub header_color: String,
+    pub foreground_color: String,
+    pub menu_sections: Vec<MenuSection>,
+    pub items: Vec<NewsItemDto>,
+}
+
+pub async fn inbox_page_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<InboxPageRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let i18n = state.i18n.for_lang(&route_params.lang);
+    let current_path = format!("/{}/inbox", &route_params.lang);
+
+    let items = inbox_items(simulator_data, &i18n);
+
+    let menu_sections = views::inbox_menu(&i18n, &route_params.lang, &current_path);
+
+    Ok(InboxPageTemplate {
+        css_version: CSS_VERSION,
+        computer_name: &COMPUTER_NAME,
+        cpu_brand: &CPU_BRAND,
+        cores_count: *CPU_CORES,
+        title: i18n.t("inbox").to_string(),
+        i18n,
+        lang: route_params.lang.clone(),
+        sub_title_prefix: String::new(),
+        sub_title_suffix: String::new(),
+        sub_title: String::new(),
+        sub_title_link: String::new(),
+        sub_title_country_code: String::new(),
+        header_color: String::new(),
+        foreground_color: String::new(),
+        menu_sections,
+        items,
+    })
+}
+
+/// Inbox rows alone, rendered by the same markup the inbox page includes.
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "news/items.html")]
+pub struct InboxFragmentTemplate {
+    pub lang: String,
+    pub items: Vec<NewsItemDto>,
+}
+
+/// `GET /fragments/{lang}/inbox`
+pub async fn inbox_fragment_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<InboxPageRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let i18n = state.i18n.for_lang(&route_params.lang);
+
+    Ok(InboxFragmentTemplate {
+        items: inbox_items(simulator_data, &i18n),
+        lang: route_params.lang,
+    })
+}
+
+fn inbox_items(simulator_data: &SimulatorData, i18n: &I18n) -> Vec<NewsItemDto> {
+    simulator_data
+        .news
+        .latest()
+        .take(INBOX_PAGE_SIZE)
+        .map(|item| news_item_dto(item, simulator_data, i18n))
+        .collect()
+}
+
+#[derive(Deserialize)]
+pub struct NewsApiQuery {
+    #[serde(default)]
+    pub lang: Option<String>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+    /// Only stories about this club.
+    #[serde(default)]
+    pub club: Option<u32>,
+}
+
+pub async fn news_api_action(
+    State(state): State<GameAppData>,
+    ApiQuery(query): ApiQuery<NewsApiQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let i18n = state.i18n.for_lang(query.lang.as_deref().unwrap_or("en"));
+    let limit = query
+        .limit
+        .unwrap_or(DEFAULT_FEED_LIMIT)
+        .min(MAX_FEED_LIMIT);
+
+    let items: Vec<NewsItemDto> = simulator_data
+        .news
+        .latest()
+        .filter(|item| query.club.is_none() || item.club_id == query.club)
+        .take(limit)
+        .map(|item| news_item_dto(item, simulator_data, &i18n))
+        .collect();
+
+    Ok(Json(items))
+}
+
+fn news_item_dto(item: &NewsItem, simulator_data: &SimulatorData, i18n: &I18n) -> NewsItemDto {
+    let team = item
+        .club_id
+        .and_then(|id| simulator_data.club(id))
+        .and_then(|club| club.teams.main());
+    let player_slug = item
+        .player_id
+        .and_then(|id| {
+            simulator_data
+                .player(id)
+                .or_else(|| simulator_data.retired_player(id))
+        })
+        .map(|p| p.slug())
+        .unwrap_or_default();
+
+    NewsItemDto {
+        id: item.id,
+        date: i18n.format_date(item.date),
+        kind: item.kind.as_str(),
+        headline: render_headline(item, i18n),
+        team_name: team.map(|t| t.name.clone()).unwrap_or_default(),
+        team_slug: team.map(|t| t.slug.clone()).unwrap_or_default(),
+        player_slug,
+    }
+}
+
+/// Fill the kind's headline template with the item's arguments in the
+/// reader's language. A headline with plural forms takes its form from
+/// the item's first number argument.
+fn render_headline(item: &NewsItem, i18n: &I18n) -> String {
+    let args: Vec<(&str, String)> = item
+        .args
+        .iter()
+        .map(|(name, arg)| {
+            let value = match arg {
+                NewsArg::Text(text) => text.clone(),
+                NewsArg::I18n(key) => i18n.t(key).to_string(),
+                NewsArg::Money(amount) => FormattingUtils::format_money(*amount),
+                NewsArg::Number(n) => n.to_string(),
+            };
+            (*name, value)
+        })
+        .collect();
+    let count = item.args.iter().find_map(|(_, arg)| match arg {
+        NewsArg::Number(n) => Some(i64::from(*n)),
+        _ => None,
+    });
+
+    let key = item.kind.as_i18n_key();
+    match count {
+        Some(count) => i18n.plural(key, count, &args),
+        None => i18n.format(key, &args),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+    use core::NewsKind;
+    use std::collections::HashMap;
+
+    #[test]
+    fn headline_fills_every_argument_kind() {
+        let mut map = HashMap::new();
+        map.insert(
+            "news_injury".to_string(),
+            "{player} ({club}) out {weeks} week: {injury}|{player} ({club}) out {weeks} weeks: {injury}"
+                .to_string(),
+        );
+        map.insert(
+            "injury_type_broken_leg".to_string(),
+            "Broken leg".to_string(),
+        );
+        let i18n = I18n::for_test(map);
+
+        let mut item = NewsItem {
+            id: 1,
+            date: NaiveDate::from_ymd_opt(2030, 3, 1).unwrap(),
+            kind: NewsKind::Injury,
+            country_id: 1,
+            club_id: None,
+            player_id: None,
+            args: vec![
+                ("player", NewsArg::Text("Joe Bloggs".to_string())),
+                ("club", NewsArg::Text("Town".to_string())),
+                ("injury", NewsArg::I18n("injury_type_broken_leg")),
+                ("weeks", NewsArg::Number(12)),
+            ],
+        };
+
+        assert_eq!(
+            render_headline(&item, &i18n),
+            "Joe Bloggs (Town) out 12 weeks: Broken leg"
+        );
+
+        item.args[3].1 = NewsArg::Number(1);
+        assert_eq!(
+            render_headline(&item, &i18n),
+            "Joe Bloggs (Town) out 1 week: Broken leg"
+        );
+    }
+    #[test]
+    fn inbox_fragment_renders_rows_without_the_layout() {
+        let html = InboxFragmentTemplate {
+            lang: "en".to_string(),
+            items: vec![NewsItemDto {
+                id: 7,
+                date: "01.03.2030".to_string(),
+                kind: "transfer",
+                headline: "Joe Bloggs joins Town".to_string(),
+                team_name: "Town".to_string(),
+                team_slug: "town".to_string(),
+                player_slug: "joe-bloggs".to_string(),
+            }],
+        }
+        .render()
+        .unwrap();
+
+        assert!(
+            html.trim_start()
+                .starts_with("<tr class=\"news-transfer\">")
+        );
+        assert!(html.contains("href=\"/en/players/joe-bloggs\""));
+        assert!(html.contains("href=\"/en/teams/town\""));
+        assert!(!html.contains("<html"));
+    }
+}
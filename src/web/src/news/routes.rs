@@ -0,0 +1,10 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route("/{lang}/inbox", get(super::inbox_page_action))
+        .route("/fragments/{lang}/inbox", get(super::inbox_fragment_action))
+        .route("/api/v1/news", get(super::news_api_action))
+}
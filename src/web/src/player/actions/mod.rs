@@ -1,10 +1,10 @@
 pub mod routes;
 
-use crate::GameAppData;
+use crate::session::{SessionToken, require_managed_club};
+use crate::{ApiError, ApiJson, ApiQuery, ApiResult, GameAppData};
 use axum::Json;
-use axum::extract::{Path, Query, State};
+use axum::extract::{Path, State};
 use axum::http::StatusCode;
-use axum::response::IntoResponse;
 use chrono::Datelike;
 use core::club::player::calculators::WageCalculator;
 use core::club::player::transfer::ReleaseContext;
@@ -127,6 +127,64 @@ fn signing_reputation_inputs(
     (club_world_rep, league_rep)
 }
 
+/// Club the player belongs to for the player actions: the club they are
+/// registered at, plus the parent club when they are there on loan.
+/// `None` for free agents and unknown players.
+fn player_clubs(sim: &SimulatorData, player_id: u32) -> Option<(u32, Option<u32>)> {
+    let (ci, coi, cli, ti) = sim.find_player_position(player_id)?;
+    let club = &sim.continents[ci].countries[coi].clubs[cli];
+    let parent_club_id = club.teams.teams[ti]
+        .players
+        .players
+        .iter()
+        .find(|p| p.id == player_id)
+        .and_then(|p| p.contract_loan.as_ref())
+        .and_then(|c| c.loan_from_club_id);
+    Some((club.id, parent_club_id))
+}
+
+/// The player actions rewrite a club's squad, so they are only open to
+/// the signed-in manager of the player's club (or, for a loanee, of the
+/// parent club).
+fn authorize_player_action(
+    sim: &SimulatorData,
+    session: &SessionToken,
+    player_id: u32,
+) -> ApiResult<()> {
+    let managed_club_id = require_managed_club(sim, session)?;
+    match player_clubs(sim, player_id) {
+        Some((club_id, parent_club_id))
+            if club_id == managed_club_id || parent_club_id == Some(managed_club_id) =>
+        {
+            Ok(())
+        }
+        _ => Err(ApiError::Forbidden(
+            "The player is not at your club".to_string(),
+        )),
+    }
+}
+
+/// As [`authorize_player_action`]; a free agent has no club, so signing
+/// one is open to the manager of the club they would join.
+fn authorize_transfer_action(
+    sim: &SimulatorData,
+    session: &SessionToken,
+    player_id: u32,
+    to_club_id: u32,
+) -> ApiResult<()> {
+    if player_clubs(sim, player_id).is_none() && sim.free_agents.iter().any(|p| p.id == player_id) {
+        let managed_club_id = require_managed_club(sim, session)?;
+        return if managed_club_id == to_club_id {
+            Ok(())
+        } else {
+            Err(ApiError::Forbidden(
+                "Free agents can only be signed to your club".to_string(),
+            ))
+        };
+    }
+    authorize_player_action(sim, session, player_id)
+}
+
 // ── Move on free ───────────────────────────────────────────────
 
 /// Direct editor override: releases the player to the global free-agent
@@ -277,103 +335,113 @@ pub(crate) fn execute_move_on_free(sim: &mut SimulatorData, player_id: u32) -> b
 
 pub async fn move_on_free_action(
     State(state): State<GameAppData>,
+    session: SessionToken,
     Path(params): Path<PlayerPathParam>,
-) -> impl IntoResponse {
+) -> ApiResult<StatusCode> {
     let data = Arc::clone(&state.data);
     let mut guard = data.write().await;
 
     if let Some(ref mut arc_data) = *guard {
+        authorize_player_action(arc_data, &session, params.player_id)?;
         let sim = Arc::make_mut(arc_data);
         if execute_move_on_free(sim, params.player_id) {
-            return StatusCode::OK;
+            return Ok(StatusCode::OK);
         }
     }
 
-    StatusCode::NOT_FOUND
+    Ok(StatusCode::NOT_FOUND)
 }
 
 // ── Clear unhappy ──────────────────────────────────────────────
 
 pub async fn clear_unhappy_action(
     State(state): State<GameAppData>,
+    session: SessionToken,
     Path(params): Path<PlayerPathParam>,
-) -> impl IntoResponse {
+) -> ApiResult<StatusCode> {
     let data = Arc::clone(&state.data);
     let mut guard = data.write().await;
 
     if let Some(ref mut arc_data) = *guard {
+        authorize_player_action(arc_data, &session, params.player_id)?;
         let sim = Arc::make_mut(arc_data);
 
         if let Some(player) = sim.player_mut(params.player_id) {
             player.statuses.remove(core::PlayerStatusType::Unh);
             player.happiness.clear();
-            return StatusCode::OK;
+            return Ok(StatusCode::OK);
         }
     }
 
-    StatusCode::NOT_FOUND
+    Ok(StatusCode::NOT_FOUND)
 }
 
 // ── Toggle force match selection ───────────────────────────────
 
 pub async fn toggle_force_match_selection_action(
     State(state): State<GameAppData>,
+    session: SessionToken,
     Path(params): Path<PlayerPathParam>,
-) -> impl IntoResponse {
+) -> ApiResult<StatusCode> {
     let data = Arc::clone(&state.data);
     let mut guard = data.write().await;
 
     if let Some(ref mut arc_data) = *guard {
+        authorize_player_action(arc_data, &session, params.player_id)?;
         let sim = Arc::make_mut(arc_data);
         if let Some(player) = sim.player_mut(params.player_id) {
             player.is_force_match_selection = !player.is_force_match_selection;
-            return StatusCode::OK;
+            return Ok(StatusCode::OK);
         }
     }
 
-    StatusCode::NOT_FOUND
+    Ok(StatusCode::NOT_FOUND)
 }
 
 // ── Clear injury ────────────────────────────────────────────────
 
 pub async fn clear_injury_action(
     State(state): State<GameAppData>,
+    session: SessionToken,
     Path(params): Path<PlayerPathParam>,
-) -> impl IntoResponse {
+) -> ApiResult<StatusCode> {
     let data = Arc::clone(&state.data);
     let mut guard = data.write().await;
 
     if let Some(ref mut arc_data) = *guard {
+        authorize_player_action(arc_data, &session, params.player_id)?;
         let sim = Arc::make_mut(arc_data);
         if let Some(player) = sim.player_mut(params.player_id) {
             player.player_attributes.is_injured = false;
             player.player_attributes.injury_days_remaining = 0;
             player.player_attributes.injury_type = None;
             player.player_attributes.recovery_days_remaining = 0;
-            return StatusCode::OK;
+            return Ok(StatusCode::OK);
         }
     }
 
-    StatusCode::NOT_FOUND
+    Ok(StatusCode::NOT_FOUND)
 }
 
 // ── Cancel loan ─────────────────────────────────────────────────
 
 pub async fn cancel_loan_action(
     State(state): State<GameAppData>,
+    session: SessionToken,
     Path(params): Path<PlayerPathParam>,
-) -> impl IntoResponse {
+) -> ApiResult<StatusCode> {
     let data = Arc::clone(&state.data);
     let mut guard = data.write().await;
 
     if let Some(ref mut arc_data) = *guard {
+        authorize_player_action(arc_data, &session, params.player_id)?;
         let sim = Arc::make_mut(arc_data);
         let date = sim.date.date();
 
         // Find player and validate loan
         let (ci, coi, cli, ti) = match sim.find_player_position(params.player_id) {
             Some(pos) => pos,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         let parent_club_id = {
@@ -384,23 +452,23 @@ pub async fn cancel_loan_action(
                 .find(|p| p.id == params.player_id);
             match player.and_then(|p| p.contract_loan.as_ref()) {
                 Some(c) => c.loan_from_club_id,
-                _ => return StatusCode::NOT_FOUND,
+                _ => return Ok(StatusCode::NOT_FOUND),
             }
         };
 
         let parent_club_id = match parent_club_id {
             Some(id) => id,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         let borrowing = match get_team_info(sim, ci, coi, cli) {
             Some(t) => t,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         let parent = match get_team_info_by_club_id(sim, parent_club_id) {
             Some(t) => t,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         // Take player
@@ -409,7 +477,7 @@ pub async fn cancel_loan_action(
             .take_player(&params.player_id)
         {
             Some(p) => p,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         player.on_cancel_loan(&borrowing.info, &parent.info, date);
@@ -417,17 +485,17 @@ pub async fn cancel_loan_action(
 
         let (dci, dcoi, dcli, dti) = match sim.find_club_main_team(parent_club_id) {
             Some(pos) => pos,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
         sim.continents[dci].countries[dcoi].clubs[dcli].teams.teams[dti]
             .players
             .add(player);
 
         sim.rebuild_indexes();
-        return StatusCode::OK;
+        return Ok(StatusCode::OK);
     }
 
-    StatusCode::NOT_FOUND
+    Ok(StatusCode::NOT_FOUND)
 }
 
 // ── Transfer ────────────────────────────────────────────────────
@@ -440,25 +508,27 @@ pub struct TransferRequest {
 
 pub async fn transfer_action(
     State(state): State<GameAppData>,
+    session: SessionToken,
     Path(params): Path<PlayerPathParam>,
-    Json(body): Json<TransferRequest>,
-) -> impl IntoResponse {
+    ApiJson(body): ApiJson<TransferRequest>,
+) -> ApiResult<StatusCode> {
     let data = Arc::clone(&state.data);
     let mut guard = data.write().await;
 
     if let Some(ref mut arc_data) = *guard {
+        authorize_transfer_action(arc_data, &session, params.player_id, body.to_club_id)?;
         let sim = Arc::make_mut(arc_data);
         let date = sim.date.date();
         let fee = body.fee.unwrap_or(0) as f64;
 
         let (dci, dcoi, dcli, dti) = match sim.find_club_main_team(body.to_club_id) {
             Some(pos) => pos,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         let dest = match get_team_info(sim, dci, dcoi, dcli) {
             Some(t) => t,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         // Try to take player from a team, or from the free agents pool
@@ -473,7 +543,7 @@ pub async fn transfer_action(
         let (mut player, source_info) = if let Some((ci, coi, cli, ti)) = from_team {
             let source = match get_team_info(sim, ci, coi, cli) {
                 Some(t) => t,
-                None => return StatusCode::NOT_FOUND,
+                None => return Ok(StatusCode::NOT_FOUND),
             };
 
             let p = match sim.continents[ci].countries[coi].clubs[cli].teams.teams[ti]
@@ -481,7 +551,7 @@ pub async fn transfer_action(
                 .take_player(&params.player_id)
             {
                 Some(p) => p,
-                None => return StatusCode::NOT_FOUND,
+                None => return Ok(StatusCode::NOT_FOUND),
             };
 
             (p, Some((ci, coi, source)))
@@ -493,7 +563,7 @@ pub async fn transfer_action(
                 .position(|p| p.id == params.player_id)
             {
                 Some(i) => i,
-                None => return StatusCode::NOT_FOUND,
+                None => return Ok(StatusCode::NOT_FOUND),
             };
             let p = sim.free_agents.swap_remove(idx);
             (p, None)
@@ -669,10 +739,10 @@ pub async fn transfer_action(
         }
 
         sim.rebuild_indexes();
-        return StatusCode::OK;
+        return Ok(StatusCode::OK);
     }
 
-    StatusCode::NOT_FOUND
+    Ok(StatusCode::NOT_FOUND)
 }
 
 // ── Loan ────────────────────────────────────────────────────────
@@ -685,19 +755,21 @@ pub struct LoanRequest {
 
 pub async fn loan_action(
     State(state): State<GameAppData>,
+    session: SessionToken,
     Path(params): Path<PlayerPathParam>,
-    Json(body): Json<LoanRequest>,
-) -> impl IntoResponse {
+    ApiJson(body): ApiJson<LoanRequest>,
+) -> ApiResult<StatusCode> {
     let data = Arc::clone(&state.data);
     let mut guard = data.write().await;
 
     if let Some(ref mut arc_data) = *guard {
+        authorize_player_action(arc_data, &session, params.player_id)?;
         let sim = Arc::make_mut(arc_data);
         let date = sim.date.date();
 
         let (ci, coi, cli, ti) = match sim.find_player_position(params.player_id) {
             Some(pos) => pos,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         let source_club_id = sim.continents[ci].countries[coi].clubs[cli].id;
@@ -723,7 +795,7 @@ pub async fn loan_action(
 
         let source = match get_team_info(sim, ci, coi, cli) {
             Some(t) => t,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         // History identity of the squad the player is actually loaned out OF
@@ -733,22 +805,22 @@ pub async fn loan_action(
         // History page.
         let source_history = match get_source_history_info(sim, ci, coi, cli, ti) {
             Some(t) => t,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         let parent = match get_team_info_by_club_id(sim, parent_club_id) {
             Some(t) => t,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         let dest_pos = match sim.find_club_main_team(body.to_club_id) {
             Some(pos) => pos,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         let dest = match get_team_info(sim, dest_pos.0, dest_pos.1, dest_pos.2) {
             Some(t) => t,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         // Get player name before taking
@@ -765,7 +837,7 @@ pub async fn loan_action(
             .take_player(&params.player_id)
         {
             Some(p) => p,
-            None => return StatusCode::NOT_FOUND,
+            None => return Ok(StatusCode::NOT_FOUND),
         };
 
         // Capture source-side reps + dest position depth BEFORE clearing
@@ -918,10 +990,10 @@ pub async fn loan_action(
             .push(completed);
 
         sim.rebuild_indexes();
-        return StatusCode::OK;
+        return Ok(StatusCode::OK);
     }
 
-    StatusCode::NOT_FOUND
+    Ok(StatusCode::NOT_FOUND)
 }
 
 // ── List clubs (for dropdowns) ──────────────────────────────────
@@ -941,7 +1013,7 @@ pub struct ClubsQuery {
 
 pub async fn list_clubs_action(
     State(state): State<GameAppData>,
-    Query(query): Query<ClubsQuery>,
+    ApiQuery(query): ApiQuery<ClubsQuery>,
 ) -> Json<Vec<ClubListItem>> {
     let guard = state.data.read().await;
 
@@ -975,26 +1047,14 @@ pub async fn list_clubs_action(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use chrono::{NaiveDate, NaiveTime};
-    use core::club::ClubAcademy;
-    use core::club::player::builder::PlayerBuilder;
-    use core::competitions::global::GlobalCompetitions;
-    use core::continent::Continent;
-    use core::league::{DayMonthPeriod, League, LeagueCollection, LeagueSettings};
-    use core::shared::Location;
-    use core::shared::fullname::FullName;
+    use crate::common::fixture::WorldFixture;
+    use chrono::NaiveDate;
     use core::transfers::{TransferListing, TransferListingStatus, TransferListingType};
-    use core::{
-        Club, ClubColors, ClubFacilities, ClubFinances, ClubStatus, Country, PersonAttributes,
-        PlayerAttributes, PlayerCollection, PlayerPosition, PlayerPositionType, PlayerPositions,
-        PlayerSkills, StaffCollection, TeamBuilder, TeamCollection, TeamReputation, TeamType,
-        TrainingSchedule, TransferItem,
-    };
+    use core::{PlayerAttributes, PlayerPositionType, TransferItem};
 
-    /// World fixture for the manual-release action: one country (id 1)
-    /// with one league (id 1), one club (id 100) whose Main team (id 10)
-    /// rosters a single contracted player (id 1, salary 80k,
-    /// MainBackupPlayer).
+    /// World fixture for the manual-release action: the shared
+    /// [`WorldFixture`] world whose single player (id 1) is contracted
+    /// (salary 80k, MainBackupPlayer).
     struct Fixture;
 
     impl Fixture {
@@ -1009,83 +1069,9 @@ mod tests {
             let mut contract =
                 PlayerClubContract::new(80_000, NaiveDate::from_ymd_opt(2027, 6, 30).unwrap());
             contract.squad_status = PlayerSquadStatus::MainBackupPlayer;
-            let player = PlayerBuilder::new()
-                .id(1)
-                .full_name(FullName::new("Test".to_string(), "Player".to_string()))
-                .birth_date(NaiveDate::from_ymd_opt(1996, 1, 1).unwrap())
-                .country_id(1)
-                .attributes(PersonAttributes::default())
-                .skills(PlayerSkills::default())
-                .positions(PlayerPositions {
-                    positions: vec![PlayerPosition {
-                        position: PlayerPositionType::MidfielderCenter,
-                        level: 20,
-                    }],
-                })
-                .player_attributes(attrs)
-                .contract(Some(contract))
-                .build()
-                .unwrap();
-            let team = TeamBuilder::new()
-                .id(10)
-                .league_id(Some(1))
-                .club_id(100)
-                .name("Main".to_string())
-                .slug("main".to_string())
-                .team_type(TeamType::Main)
-                .players(PlayerCollection::new(vec![player]))
-                .staffs(StaffCollection::new(Vec::new()))
-                .reputation(TeamReputation::new(500, 500, 4_000))
-                .training_schedule(TrainingSchedule::new(
-                    NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
-                    NaiveTime::from_hms_opt(15, 0, 0).unwrap(),
-                ))
-                .build()
-                .unwrap();
-            let club = Club::new(
-                100,
-                "Club".to_string(),
-                Location::new(1),
-                ClubFinances::new(1_000_000, Vec::new()),
-                ClubAcademy::new(3),
-                ClubStatus::Professional,
-                ClubColors::default(),
-                TeamCollection::new(vec![team]),
-                ClubFacilities::default(),
-            );
-            let league = League::new(
-                1,
-                "L".to_string(),
-                "l".to_string(),
-                1,
-                500,
-                LeagueSettings {
-                    season_starting_half: DayMonthPeriod::new(1, 8, 31, 12),
-                    season_ending_half: DayMonthPeriod::new(1, 1, 31, 5),
-                    tier: 1,
-                    promotion_spots: 0,
-                    relegation_spots: 0,
-                    league_group: None,
-                    split_season: false,
-                },
-                false,
-            );
-            let country = Country::builder()
-                .id(1)
-                .code("EN".to_string())
-                .slug("en".to_string())
-                .name("England".to_string())
-                .continent_id(1)
-                .leagues(LeagueCollection::new(vec![league]))
-                .clubs(vec![club])
-                .build()
-                .unwrap();
-            let continent = Continent::new(1, "Europe".to_string(), vec![country], Vec::new());
-            SimulatorData::new(
-                Self::date().and_hms_opt(12, 0, 0).unwrap(),
-                vec![continent],
-                GlobalCompetitions::new(Vec::new()),
-            )
+            let mut player = WorldFixture::player(PlayerPositionType::MidfielderCenter, attrs);
+            player.contract = Some(contract);
+            WorldFixture::world(player, Self::date())
         }
     }
 
@@ -1170,4 +1156,28 @@ mod tests {
         assert!(!execute_move_on_free(&mut sim, 999));
         assert!(sim.free_agents.is_empty());
     }
+
+    #[test]
+    fn player_actions_are_open_only_to_the_players_club_manager() {
+        let mut sim = Fixture::sim();
+        let owner = sim.register_manager("Owner").clone();
+        sim.set_manager_club(owner.id, Some(100)).unwrap();
+        let clubless = sim.register_manager("Clubless").token.clone();
+        let session = |token: &str| SessionToken(Some(token.to_string()));
+
+        assert!(authorize_player_action(&sim, &session(&owner.token), 1).is_ok());
+        assert!(matches!(
+            authorize_player_action(&sim, &SessionToken(None), 1),
+            Err(ApiError::Unauthorized(_))
+        ));
+        assert!(matches!(
+            authorize_player_action(&sim, &session(&clubless), 1),
+            Err(ApiError::Forbidden(_))
+        ));
+        // A player outside the manager's club is off limits.
+        assert!(matches!(
+            authorize_player_action(&sim, &session(&owner.token), 999),
+            Err(ApiError::Forbidden(_))
+        ));
+    }
 }
@@ -244,7 +244,7 @@ pub async fn player_awards_action(
         is_injured: player.player_attributes.is_injured,
         is_unhappy: player.statuses.get().contains(&PlayerStatusType::Unh),
         is_force_match_selection: player.is_force_match_selection,
-        is_on_watchlist: simulator_data.watchlist.contains(&player.id),
+        is_on_watchlist: simulator_data.watchlist.contains(player.id),
         events_count: PlayerEventsCounter::count(player),
         decisions_count: PlayerDecisionsCounter::count_recent(player, simulator_data.date.date()),
         interested_clubs_count: simulator_data.clubs_interested_in_player(player.id).len(),
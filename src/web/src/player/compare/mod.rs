@@ -0,0 +1,370 @@
+//! Side-by-side comparison of two to four players: an overlaid attribute
+//! radar, league statistics and contract/value data. Everything per
+//! player comes from the overview's `PlayerViewModel`, so the numbers
+//! match what each player page shows.
+
+pub mod routes;
+
+use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
+use crate::common::slug::{find_player, parse_slug_id};
+use crate::i18n::DEFAULT_LANGUAGE;
+use crate::player::get::{
+    CompetitionStatisticsRow, PlayerSkillsDto, PlayerViewModel, build_player_view_model,
+};
+use crate::views::{self, MenuSection};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData, I18n};
+use askama::Template;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use core::SimulatorData;
+use serde::{Deserialize, Serialize};
+
+const MIN_COMPARED_PLAYERS: usize = 2;
+const MAX_COMPARED_PLAYERS: usize = 4;
+
+// Radar geometry, in the same 400x280 viewBox as the personality radar.
+const RADAR_CX: f32 = 200.0;
+const RADAR_CY: f32 = 140.0;
+const RADAR_R: f32 = 100.0;
+const RADAR_LABEL_R: f32 = 112.0;
+
+const OUTFIELD_AXES: [&str; 8] = [
+    "pace",
+    "finishing",
+    "passing",
+    "technique",
+    "compare_axis_defending",
+    "heading",
+    "physical",
+    "mental",
+];
+
+const GOALKEEPER_AXES: [&str; 8] = [
+    "gk_handling",
+    "gk_reflexes",
+    "gk_one_on_ones",
+    "gk_aerial_reach",
+    "gk_command_of_area",
+    "gk_kicking",
+    "gk_communication",
+    "gk_rushing_out",
+];
+
+#[derive(Deserialize)]
+pub struct PlayerComparePageRequest {
+    pub lang: String,
+}
+
+#[derive(Deserialize, Default)]
+pub struct PlayerCompareQuery {
+    /// Comma-separated player ids or slugs.
+    #[serde(default)]
+    pub ids: String,
+    #[serde(default)]
+    pub lang: Option<String>,
+}
+
+#[derive(Serialize)]
+pub struct RadarAxisDto {
+    pub key: &'static str,
+    pub x2: f32,
+    pub y2: f32,
+    pub label_x: f32,
+    pub label_y: f32,
+    pub anchor: &'static str,
+}
+
+#[derive(Serialize)]
+pub struct RadarValueDto {
+    pub key: &'static str,
+    pub value: u8,
+}
+
+#[derive(Serialize)]
+pub struct ComparedPlayerDto {
+    pub slug: String,
+    pub name: String,
+    pub position: String,
+    pub is_goalkeeper: bool,
+    pub radar: Vec<RadarValueDto>,
+    #[serde(skip)]
+    pub radar_points: String,
+    /// The League row of the overview Statistics panel.
+    pub league_statistics: Option<CompetitionStatisticsRow>,
+    pub profile: PlayerViewModel,
+}
+
+#[derive(Serialize)]
+pub struct PlayerCompareDto {
+    pub axes: Vec<RadarAxisDto>,
+    #[serde(skip)]
+    pub grid: Vec<String>,
+    pub players: Vec<ComparedPlayerDto>,
+}
+
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "player/compare/index.html")]
+pub struct PlayerCompareTemplate {
+    pub css_version: &'static str,
+    pub computer_name: &'static str,
+    pub cpu_brand: &'static str,
+    pub cores_count: usize,
+    pub title: String,
+    pub sub_title_prefix: String,
+    pub sub_title_suffix: String,
+    pub sub_title: String,
+    pub sub_title_link: String,
+    pub sub_title_country_code: String,
+    pub header_color: String,
+    pub foreground_color: String,
+    pub menu_sections: Vec<MenuSection>,
+    pub i18n: I18n,
+    pub lang: String,
+    pub compare: PlayerCompareDto,
+}
+
+fn average(values: &[u8]) -> u8 {
+    let sum: u32 = values.iter().map(|v| *v as u32).sum();
+    (sum as f32 / values.len() as f32).round() as u8
+}
+
+/// Radar values on the 1-20 attribute scale, in `OUTFIELD_AXES` or
+/// `GOALKEEPER_AXES` order. Outfield axes blend the attributes that
+/// drive each facet of play.
+fn radar_values(skills: &PlayerSkillsDto, goalkeeper_axes: bool) -> [u8; 8] {
+    if goalkeeper_axes {
+        let gk = &skills.goalkeeping;
+        return [
+            gk.handling,
+            gk.reflexes,
+            gk.one_on_ones,
+            gk.aerial_reach,
+            gk.command_of_area,
+            gk.kicking,
+            gk.communication,
+            gk.rushing_out,
+        ];
+    }
+    let (t, m, p) = (&skills.technical, &skills.mental, &skills.physical);
+    [
+        average(&[p.pace, p.acceleration]),
+        average(&[t.finishing, t.long_shots]),
+        average(&[t.passing, m.vision, t.crossing]),
+        average(&[t.technique, t.first_touch]),
+        average(&[t.tackling, t.marking, m.positioning]),
+        average(&[t.heading, p.jumping_reach]),
+        average(&[p.strength, p.stamina, p.agility]),
+        average(&[m.decisions, m.composure, m.anticipation, m.concentration]),
+    ]
+}
+
+fn angle_at(i: usize, n: usize) -> f32 {
+    std::f32::consts::PI * 2.0 * (i as f32) / (n as f32) - std::f32::consts::FRAC_PI_2
+}
+
+fn radar_polygon(ratios: impl Iterator<Item = f32>, n: usize) -> String {
+    ratios
+        .enumerate()
+        .map(|(i, ratio)| {
+            let a = angle_at(i, n);
+            format!(
+                "{:.1},{:.1}",
+                RADAR_CX + RADAR_R * ratio * a.cos(),
+                RADAR_CY + RADAR_R * ratio * a.sin()
+            )
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn radar_axes(keys: &[&'static str]) -> Vec<RadarAxisDto> {
+    let n = keys.len();
+    keys.iter()
+        .enumerate()
+        .map(|(i, key)| {
+            let a = angle_at(i, n);
+            let anchor = if a.cos().abs() < 0.01 {
+                "middle"
+            } else if a.cos() > 0.0 {
+                "start"
+            } else {
+                "end"
+            };
+            RadarAxisDto {
+                key,
+                x2: RADAR_CX + RADAR_R * a.cos(),
+                y2: RADAR_CY + RADAR_R * a.sin(),
+                label_x: RADAR_CX + RADAR_LABEL_R * a.cos(),
+                label_y: RADAR_CY + RADAR_LABEL_R * a.sin(),
+                anchor,
+            }
+        })
+        .collect()
+}
+
+/// Player ids from `?ids=`, in the given order with repeats dropped.
+fn parse_ids(ids: &str) -> ApiResult<Vec<u32>> {
+    let mut parsed: Vec<u32> = Vec::new();
+    for part in ids.split(',').map(str::trim).filter(|p| !p.is_empty()) {
+        let id = parse_slug_id(part)
+            .ok_or_else(|| ApiError::invalid("ids", format!("Invalid player id '{}'", part)))?;
+        if !parsed.contains(&id) {
+            parsed.push(id);
+        }
+    }
+    if !(MIN_COMPARED_PLAYERS..=MAX_COMPARED_PLAYERS).contains(&parsed.len()) {
+        return Err(ApiError::invalid(
+            "ids",
+            format!(
+                "Compare takes {} to {} players",
+                MIN_COMPARED_PLAYERS, MAX_COMPARED_PLAYERS
+            ),
+        ));
+    }
+    Ok(parsed)
+}
+
+/// Comparison of `ids`. Goalkeeper axes are used only when every player
+/// is a goalkeeper; a mixed line-up reads best on the outfield radar.
+pub fn build_player_compare(
+    simulator_data: &SimulatorData,
+    i18n: &I18n,
+    ids: &[u32],
+) -> ApiResult<PlayerCompareDto> {
+    let found = ids
+        .iter()
+        .map(|id| {
+            find_player(simulator_data, *id)
+                .ok_or_else(|| ApiError::NotFound(format!("Player with ID {} not found", id)))
+        })
+        .collect::<ApiResult<Vec<_>>>()?;
+
+    let goalkeeper_axes = found
+        .iter()
+        .all(|(player, _)| player.position().is_goalkeeper());
+    let keys = if goalkeeper_axes {
+        GOALKEEPER_AXES
+    } else {
+        OUTFIELD_AXES
+    };
+
+    let players = found
+        .into_iter()
+        .map(|(player, team)| {
+            let mut profile = build_player_view_model(simulator_data, i18n, player, team);
+            let values = radar_values(&profile.skills, goalkeeper_axes);
+            ComparedPlayerDto {
+                slug: player.slug(),
+                name: format!(
+                    "{} {}",
+                    player.full_name.display_first_name(),
+                    player.full_name.display_last_name()
+                ),
+                position: player.position().get_short_name().to_string(),
+                is_goalkeeper: player.position().is_goalkeeper(),
+                radar: keys
+                    .iter()
+                    .zip(values)
+                    .map(|(key, value)| RadarValueDto { key, value })
+                    .collect(),
+                radar_points: radar_polygon(values.iter().map(|v| *v as f32 / 20.0), keys.len()),
+                // The overview always ends with the League row.
+                league_statistics: profile.statistics_rows.pop(),
+                profile,
+            }
+        })
+        .collect();
+
+    Ok(PlayerCompareDto {
+        axes: radar_axes(&keys),
+        grid: [1.0, 0.75, 0.5, 0.25]
+            .iter()
+            .map(|r| radar_polygon(std::iter::repeat_n(*r, keys.len()), keys.len()))
+            .collect(),
+        players,
+    })
+}
+
+/// `GET /{lang}/players/compare?ids=1,2` — the comparison page.
+pub async fn player_compare_page_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<PlayerComparePageRequest>,
+    ApiQuery(query): ApiQuery<PlayerCompareQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let i18n = state.i18n.for_lang(&route_params.lang);
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let ids = parse_ids(&query.ids)?;
+    let compare = build_player_compare(simulator_data, &i18n, &ids)?;
+
+    let current_path = format!("/{}/search", &route_params.lang);
+    let menu_sections = views::search_menu(&i18n, &route_params.lang, &current_path);
+    let title = i18n.t("compare_players").to_string();
+
+    Ok(PlayerCompareTemplate {
+        css_version: CSS_VERSION,
+        computer_name: &COMPUTER_NAME,
+        cpu_brand: &CPU_BRAND,
+        cores_count: *CPU_CORES,
+        title,
+        sub_title_prefix: String::new(),
+        sub_title_suffix: String::new(),
+        sub_title: String::new(),
+        sub_title_link: String::new(),
+        sub_title_country_code: String::new(),
+        header_color: String::new(),
+        foreground_color: String::new(),
+        menu_sections,
+        i18n,
+        lang: route_params.lang,
+        compare,
+    })
+}
+
+/// `GET /api/v1/players/compare?ids=1,2&lang=en` — the same comparison as
+/// JSON.
+pub async fn player_compare_api_action(
+    State(state): State<GameAppData>,
+    ApiQuery(query): ApiQuery<PlayerCompareQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let i18n = state
+        .i18n
+        .for_lang(query.lang.as_deref().unwrap_or(DEFAULT_LANGUAGE));
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let ids = parse_ids(&query.ids)?;
+    Ok(Json(build_player_compare(simulator_data, &i18n, &ids)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_ids_accepts_ids_and_slugs_and_drops_repeats() {
+        assert_eq!(parse_ids("12, 34-john-smith,12").unwrap(), vec![12, 34]);
+    }
+
+    #[test]
+    fn parse_ids_rejects_bad_counts_and_garbage() {
+        for ids in ["12", "1,2,3,4,5", "1,abc"] {
+            match parse_ids(ids) {
+                Err(ApiError::Validation(errors)) => assert_eq!(errors[0].field, "ids", "{ids}"),
+                other => panic!("expected a validation failure for '{ids}', got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn radar_polygon_puts_full_values_on_the_outer_ring() {
+        let outer = radar_polygon(std::iter::repeat_n(1.0, 4), 4);
+        assert_eq!(outer, "200.0,40.0 300.0,140.0 200.0,240.0 100.0,140.0");
+    }
+}
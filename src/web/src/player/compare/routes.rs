@@ -0,0 +1,15 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route(
+            "/{lang}/players/compare",
+            get(super::player_compare_page_action),
+        )
+        .route(
+            "/api/v1/players/compare",
+            get(super::player_compare_api_action),
+        )
+}
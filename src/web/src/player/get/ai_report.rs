@@ -1,6 +1,7 @@
+use crate::ApiJson;
 use crate::GameAppData;
 use crate::ai::agent::AiAgent;
-use crate::i18n::SUPPORTED_LANGUAGES;
+use crate::i18n::locales;
 use axum::Json;
 use axum::extract::State;
 use axum::response::IntoResponse;
@@ -35,7 +36,7 @@ pub struct PlayerReportStart {
 /// `/api/ai/progress` to render tool calls live and finally the dossier.
 pub async fn player_ai_report_action(
     State(state): State<GameAppData>,
-    Json(body): Json<PlayerReportRequest>,
+    ApiJson(body): ApiJson<PlayerReportRequest>,
 ) -> impl IntoResponse {
     let Some(settings) = state.ai.get().await else {
         return Json(PlayerReportStart {
@@ -61,11 +62,7 @@ pub async fn player_ai_report_action(
         .player(body.player_id)
         .map(|player| player.full_name.to_string())
         .unwrap_or_default();
-    let language = SUPPORTED_LANGUAGES
-        .iter()
-        .find(|(code, _, _)| *code == body.lang)
-        .map(|(_, _, name)| *name)
-        .unwrap_or("English");
+    let language = locales::language_name(&body.lang).unwrap_or("English");
 
     let system = format!(
         "{PLAYER_REPORT_PROMPT}\n\n## Response language\nWrite your entire final dossier in {language}."
@@ -8,17 +8,17 @@ use crate::common::slug::{PlayerPage, resolve_player_page};
 use crate::player::decisions::PlayerDecisionsCounter;
 use crate::player::events::PlayerEventsCounter;
 use crate::views::{self, MenuSection};
-use crate::{ApiError, ApiResult, GameAppData, I18n};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData, I18n};
 use askama::Template;
-use axum::extract::{Path, Query, State};
+use axum::extract::{Path, State};
 use axum::response::{IntoResponse, Response};
 use core::utils::FormattingUtils;
 use core::{
-    DomesticCupOverride, LiveCupSlice, Person, Player, PlayerLiveStatsInput, PlayerPositionType,
-    PlayerSquadStatus, PlayerStatCompetitionKind, PlayerStatisticsProjection, PlayerStatusType,
-    SimulatorData, Team, TeamType,
+    DomesticCupOverride, LiveCupSlice, Person, Player, PlayerHistoryRow, PlayerLiveStatsInput,
+    PlayerPositionType, PlayerSquadStatus, PlayerStatCompetitionKind, PlayerStatisticsProjection,
+    PlayerStatusType, SimulatorData, Team, TeamType,
 };
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize)]
 pub struct PlayerGetRequest {
@@ -113,6 +113,7 @@ pub struct PlayerDebugDto {
     pub statuses: String,
 }
 
+#[derive(Serialize)]
 pub struct PlayerViewModel {
     pub id: u32,
     pub contract: Option<PlayerContractDto>,
@@ -132,20 +133,28 @@ pub struct PlayerViewModel {
     /// The Statistics panel's rows, already in display order:
     /// League → domestic cup(s) → continental cup(s) → Friendly.
     pub statistics_rows: Vec<CompetitionStatisticsRow>,
+    /// The most recent seasons of the career, newest first; the History
+    /// tab has the full table.
+    pub career: Vec<CareerSeasonDto>,
     #[allow(dead_code)]
+    #[serde(skip)]
     pub status: PlayerStatusDto,
     pub position_map: PositionMapDto,
     pub loan_status: Option<PlayerLoanDto>,
     pub injury_days: Option<u16>,
+    pub injury_type_key: Option<&'static str>,
+    pub recovery_days: Option<u16>,
     pub generated: bool,
 }
 
+#[derive(Serialize)]
 pub struct PlayerLoanDto {
     pub is_loan_in: bool,
     pub club_name: String,
     pub club_slug: String,
 }
 
+#[derive(Serialize)]
 pub struct PositionMapDto {
     pub gk: bool,
     pub sw: bool,
@@ -172,6 +181,7 @@ pub struct PositionMapDto {
     pub primary: String,
 }
 
+#[derive(Serialize)]
 pub struct PlayerStatistics {
     pub played: u16,
     pub played_subs: u16,
@@ -192,17 +202,20 @@ pub struct PlayerStatistics {
 /// A single row of the player overview's Statistics panel: the resolved,
 /// localized competition label plus that competition's stats. Covers the
 /// aggregated League and Friendly lines as well as each cup.
+#[derive(Serialize)]
 pub struct CompetitionStatisticsRow {
     pub competition_name: String,
     pub stats: PlayerStatistics,
 }
 
+#[derive(Serialize)]
 pub struct PlayerContractDto {
     pub salary: String,
     pub expiration: String,
     pub squad_status: String,
 }
 
+#[derive(Serialize)]
 pub struct PlayerSkillsDto {
     pub technical: TechnicalDto,
     pub mental: MentalDto,
@@ -211,6 +224,7 @@ pub struct PlayerSkillsDto {
     pub is_goalkeeper: bool,
 }
 
+#[derive(Serialize)]
 pub struct TechnicalDto {
     pub corners: u8,
     pub crossing: u8,
@@ -227,6 +241,7 @@ pub struct TechnicalDto {
     pub technique: u8,
 }
 
+#[derive(Serialize)]
 pub struct MentalDto {
     pub aggression: u8,
     pub anticipation: u8,
@@ -243,6 +258,7 @@ pub struct MentalDto {
     pub work_rate: u8,
 }
 
+#[derive(Serialize)]
 pub struct PhysicalDto {
     pub acceleration: u8,
     pub agility: u8,
@@ -253,6 +269,7 @@ pub struct PhysicalDto {
     pub strength: u8,
 }
 
+#[derive(Serialize)]
 pub struct GoalkeepingDto {
     pub aerial_reach: u8,
     pub command_of_area: u8,
@@ -269,6 +286,7 @@ pub struct GoalkeepingDto {
     pub throwing: u8,
 }
 
+#[derive(Serialize)]
 pub struct PlayerAttributesDto {
     pub international_apps: u16,
     pub international_goals: u16,
@@ -295,7 +313,7 @@ impl PlayerStatusDto {
 pub async fn player_get_action(
     State(state): State<GameAppData>,
     Path(route_params): Path<PlayerGetRequest>,
-    Query(query): Query<PlayerGetQuery>,
+    ApiQuery(query): ApiQuery<PlayerGetQuery>,
 ) -> ApiResult<Response> {
     let i18n = state.i18n.for_lang(&route_params.lang);
     let guard = state.data.read().await;
@@ -320,25 +338,9 @@ pub async fn player_get_action(
         PlayerPage::Redirect(r) => return Ok(r),
     };
 
-    let now = simulator_data.date.date();
-
     // Active player branch (team is Some) uses the full country+neighbors flow;
     // retired players (team is None) follow the compact flow below.
     if let Some(team) = team_opt {
-        // Resolve country: try simulation participant first, fall back to country_info map
-        let (country_slug, country_code, country_name) =
-            if let Some(country) = simulator_data.country(player.country_id) {
-                (
-                    country.slug.clone(),
-                    country.code.clone(),
-                    country.name.clone(),
-                )
-            } else if let Some(info) = simulator_data.country_info.get(&player.country_id) {
-                (info.slug.clone(), info.code.clone(), info.name.clone())
-            } else {
-                (String::new(), String::new(), String::new())
-            };
-
         let (neighbor_teams, country_leagues) =
             get_neighbor_teams(team.club_id, simulator_data, &i18n)?;
         let neighbor_refs: Vec<(&str, &str)> = neighbor_teams
@@ -350,77 +352,18 @@ pub async fn player_get_action(
             .map(|(n, s)| (n.as_str(), s.as_str()))
             .collect();
 
-        let contract = player.contract.as_ref().map(|c| PlayerContractDto {
-            salary: format_salary(c.salary),
-            expiration: c.expiration.format("%d.%m.%Y").to_string(),
-            squad_status: format_squad_status(&c.squad_status),
-        });
-
         let title = format!(
             "{} {}",
             player.full_name.display_first_name(),
             player.full_name.display_last_name()
         );
-        let loan_status = get_loan_status(player, team, simulator_data);
-
-        let head_coach = team.staffs.head_coach();
-
-        let (main_team_name, main_team_slug) = simulator_data
-            .club(team.club_id)
-            .and_then(|c| c.teams.teams.iter().find(|t| t.team_type == TeamType::Main))
-            .map(|t| (t.name.clone(), t.slug.clone()))
-            .unwrap_or_else(|| (team.name.clone(), team.slug.clone()));
-
-        let statistics_rows =
-            PlayerOverviewStatsBuilder::new(simulator_data, &i18n).build(player, Some(team));
-
-        let player_vm = PlayerViewModel {
-            id: player.id,
-            contract,
-            age: player.age(now),
-            team_slug: main_team_slug,
-            team_name: main_team_name,
-            country_slug,
-            country_code,
-            country_name,
-            skills: get_skills(player),
-            conditions: get_conditions(player),
-            current_ability: PotentialStarsView::current(player),
-            potential_ability: PotentialStarsView::potential_by_staff(
-                player,
-                head_coach,
-                team.team_type == TeamType::Main,
-                now,
-            ),
-            value: FormattingUtils::format_money(
-                player.value(
-                    now,
-                    team.league_id
-                        .and_then(|lid| simulator_data.league(lid))
-                        .map(|l| l.reputation)
-                        .unwrap_or(0),
-                    team.reputation.market_value_score(),
-                ),
-            ),
-            preferred_foot: player.preferred_foot_str().to_string(),
-            player_attributes: get_attributes(player),
-            statistics_rows,
-            status: PlayerStatusDto::new(player.statuses.get()),
-            position_map: get_position_map(player),
-            loan_status,
-            injury_days: if player.player_attributes.is_injured {
-                Some(player.player_attributes.injury_days_remaining)
-            } else {
-                None
-            },
-            generated: player.is_generated(),
-        };
+        let player_vm = build_player_view_model(simulator_data, &i18n, player, Some(team));
 
         let is_goalkeeper = player.position().is_goalkeeper();
         let is_on_loan = player.is_on_loan();
         let is_injured = player.player_attributes.is_injured;
         let is_unhappy = player.statuses.get().contains(&PlayerStatusType::Unh);
-        let is_on_watchlist = simulator_data.watchlist.contains(&player.id);
+        let is_on_watchlist = simulator_data.watchlist.contains(player.id);
         let debug = if debug_enabled {
             Some(build_debug_dto(player))
         } else {
@@ -485,51 +428,13 @@ pub async fn player_get_action(
     }
 
     // Retired player branch (team is None)
-    let (country_slug, country_code, country_name) =
-        if let Some(country) = simulator_data.country(player.country_id) {
-            (
-                country.slug.clone(),
-                country.code.clone(),
-                country.name.clone(),
-            )
-        } else if let Some(info) = simulator_data.country_info.get(&player.country_id) {
-            (info.slug.clone(), info.code.clone(), info.name.clone())
-        } else {
-            (String::new(), String::new(), String::new())
-        };
-
     let title = format!(
         "{} {}",
         player.full_name.display_first_name(),
         player.full_name.display_last_name()
     );
 
-    let statistics_rows =
-        PlayerOverviewStatsBuilder::new(simulator_data, &i18n).build(player, None);
-
-    let player_vm = PlayerViewModel {
-        id: player.id,
-        contract: None,
-        age: player.age(now),
-        team_slug: String::new(),
-        team_name: String::new(),
-        country_slug,
-        country_code,
-        country_name,
-        skills: get_skills(player),
-        conditions: get_conditions(player),
-        current_ability: PotentialStarsView::current(player),
-        potential_ability: PotentialStarsView::potential_absolute(player, now),
-        value: String::from("-"),
-        preferred_foot: player.preferred_foot_str().to_string(),
-        player_attributes: get_attributes(player),
-        statistics_rows,
-        status: PlayerStatusDto::new(player.statuses.get()),
-        position_map: get_position_map(player),
-        loan_status: None,
-        injury_days: None,
-        generated: player.is_generated(),
-    };
+    let player_vm = build_player_view_model(simulator_data, &i18n, player, None);
 
     let is_goalkeeper = player.position().is_goalkeeper();
     let sub_title = if player.is_retired() {
@@ -570,7 +475,7 @@ pub async fn player_get_action(
         is_injured: false,
         is_unhappy: false,
         is_force_match_selection: player.is_force_match_selection,
-        is_on_watchlist: simulator_data.watchlist.contains(&player.id),
+        is_on_watchlist: simulator_data.watchlist.contains(player.id),
         events_count: PlayerEventsCounter::count(player),
         decisions_count: PlayerDecisionsCounter::count_recent(player, simulator_data.date.date()),
         interested_clubs_count: simulator_data.clubs_interested_in_player(player.id).len(),
@@ -580,6 +485,210 @@ pub async fn player_get_action(
     .into_response())
 }
 
+/// Seasons shown in the overview's career panel.
+const CAREER_OVERVIEW_SEASONS: usize = 5;
+
+#[derive(Serialize)]
+pub struct CareerSeasonDto {
+    pub season: String,
+    pub team_name: String,
+    pub team_slug: String,
+    pub is_loan: bool,
+    pub played: u16,
+    pub played_subs: u16,
+    pub goals: u16,
+    pub assists: u16,
+    pub average_rating: String,
+}
+
+/// Latest career rows from the same projection as the History tab, so the
+/// two never disagree. Free agents and retired players have no live spell.
+fn career_timeline(
+    simulator_data: &SimulatorData,
+    player: &Player,
+    team: Option<&Team>,
+) -> Vec<CareerSeasonDto> {
+    player_season_rows(simulator_data, player, team)
+        .into_iter()
+        .take(CAREER_OVERVIEW_SEASONS)
+        .map(|row| CareerSeasonDto {
+            season: row.season.display,
+            team_name: row.team_name,
+            team_slug: row.team_slug,
+            is_loan: row.is_loan,
+            played: row.statistics.played,
+            played_subs: row.statistics.played_subs,
+            goals: row.statistics.goals,
+            assists: row.statistics.assists,
+            average_rating: core::PlayerStatistics::format_rating(row.statistics.average_rating),
+        })
+        .collect()
+}
+
+/// One row per season and club of `player`'s career, newest first,
+/// including the season in progress.
+pub fn player_season_rows(
+    simulator_data: &SimulatorData,
+    player: &Player,
+    team: Option<&Team>,
+) -> Vec<PlayerHistoryRow> {
+    let empty_live = core::PlayerStatistics::default();
+    let live_cups: Vec<LiveCupSlice<'_>> = player
+        .cup_statistics_by_competition
+        .iter()
+        .map(|c| LiveCupSlice {
+            competition_slug: c.competition_slug.as_str(),
+            competition_name: String::new(),
+            statistics: &c.statistics,
+        })
+        .collect();
+    let friendly_source_slug = FriendlySourceSlug::resolve(player, team, simulator_data);
+    let live_input = PlayerLiveStatsInput {
+        league: if team.is_some() {
+            &player.statistics
+        } else {
+            &empty_live
+        },
+        friendly: &player.friendly_statistics,
+        cups: &live_cups,
+        friendly_source_slug: &friendly_source_slug,
+    };
+
+    PlayerStatisticsProjection::player_history_rows(
+        &player.statistics_history,
+        &live_input,
+        simulator_data.date.date(),
+    )
+}
+
+/// Overview view-model for `player`. `team` is the club side the player
+/// is registered with; `None` for retired players and free agents, which
+/// carry no contract, value or loan. Shared by the player page and the
+/// JSON API.
+pub fn build_player_view_model(
+    simulator_data: &SimulatorData,
+    i18n: &I18n,
+    player: &Player,
+    team: Option<&Team>,
+) -> PlayerViewModel {
+    let now = simulator_data.date.date();
+
+    // Resolve country: try simulation participant first, fall back to country_info map
+    let (country_slug, country_code, country_name) =
+        if let Some(country) = simulator_data.country(player.country_id) {
+            (
+                country.slug.clone(),
+                country.code.clone(),
+                country.name.clone(),
+            )
+        } else if let Some(info) = simulator_data.country_info.get(&player.country_id) {
+            (info.slug.clone(), info.code.clone(), info.name.clone())
+        } else {
+            (String::new(), String::new(), String::new())
+        };
+
+    let statistics_rows = PlayerOverviewStatsBuilder::new(simulator_data, i18n).build(player, team);
+    let career = career_timeline(simulator_data, player, team);
+
+    let Some(team) = team else {
+        return PlayerViewModel {
+            id: player.id,
+            contract: None,
+            age: player.age(now),
+            team_slug: String::new(),
+            team_name: String::new(),
+            country_slug,
+            country_code,
+            country_name,
+            skills: get_skills(player),
+            conditions: get_conditions(player),
+            current_ability: PotentialStarsView::current(player),
+            potential_ability: PotentialStarsView::potential_absolute(player, now),
+            value: String::from("-"),
+            preferred_foot: player.preferred_foot_str().to_string(),
+            player_attributes: get_attributes(player),
+            statistics_rows,
+            career,
+            status: PlayerStatusDto::new(player.statuses.get()),
+            position_map: get_position_map(player),
+            loan_status: None,
+            injury_days: None,
+            injury_type_key: None,
+            recovery_days: None,
+            generated: player.is_generated(),
+        };
+    };
+
+    let contract = player.contract.as_ref().map(|c| PlayerContractDto {
+        salary: format_salary(c.salary),
+        expiration: c.expiration.format("%d.%m.%Y").to_string(),
+        squad_status: format_squad_status(&c.squad_status),
+    });
+
+    let loan_status = get_loan_status(player, team, simulator_data);
+
+    let head_coach = team.staffs.head_coach();
+
+    let (main_team_name, main_team_slug) = simulator_data
+        .club(team.club_id)
+        .and_then(|c| c.teams.teams.iter().find(|t| t.team_type == TeamType::Main))
+        .map(|t| (t.name.clone(), t.slug.clone()))
+        .unwrap_or_else(|| (team.name.clone(), team.slug.clone()));
+
+    PlayerViewModel {
+        id: player.id,
+        contract,
+        age: player.age(now),
+        team_slug: main_team_slug,
+        team_name: main_team_name,
+        country_slug,
+        country_code,
+        country_name,
+        skills: get_skills(player),
+        conditions: get_conditions(player),
+        current_ability: PotentialStarsView::current(player),
+        potential_ability: PotentialStarsView::potential_by_staff(
+            player,
+            head_coach,
+            team.team_type == TeamType::Main,
+            now,
+        ),
+        value: FormattingUtils::format_money(
+            player.value(
+                now,
+                team.league_id
+                    .and_then(|lid| simulator_data.league(lid))
+                    .map(|l| l.reputation)
+                    .unwrap_or(0),
+                team.reputation.market_value_score(),
+            ),
+        ),
+        preferred_foot: player.preferred_foot_str().to_string(),
+        player_attributes: get_attributes(player),
+        statistics_rows,
+        career,
+        status: PlayerStatusDto::new(player.statuses.get()),
+        position_map: get_position_map(player),
+        loan_status,
+        injury_days: if player.player_attributes.is_injured {
+            Some(player.player_attributes.injury_days_remaining)
+        } else {
+            None
+        },
+        injury_type_key: player
+            .player_attributes
+            .injury_type
+            .filter(|_| player.player_attributes.is_injured)
+            .map(|injury| injury.as_i18n_key()),
+        recovery_days: if player.player_attributes.is_in_recovery() {
+            Some(player.player_attributes.recovery_days_remaining)
+        } else {
+            None
+        },
+        generated: player.is_generated(),
+    }
+}
+
 fn build_debug_dto(player: &Player) -> PlayerDebugDto {
     let attrs = &player.player_attributes;
     let load = &player.load;
@@ -1088,3 +1197,109 @@ fn get_position_map(player: &Player) -> PositionMapDto {
         primary,
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::fixture::WorldFixture;
+    use chrono::NaiveDate;
+    use core::league::Season;
+    use core::{
+        InjuryType, PlayerAttributes, PlayerStatisticsHistory, PlayerStatisticsHistoryItem,
+        TeamInfo,
+    };
+    use std::collections::HashMap;
+
+    /// The shared [`WorldFixture`] world in October 2026, with the
+    /// player's view model built the way the player page builds it.
+    struct Fixture;
+
+    impl Fixture {
+        fn world(player_attributes: PlayerAttributes) -> SimulatorData {
+            WorldFixture::world(
+                WorldFixture::player(PlayerPositionType::Striker, player_attributes),
+                NaiveDate::from_ymd_opt(2026, 10, 1).unwrap(),
+            )
+        }
+
+        fn view_model(sim: &SimulatorData) -> PlayerViewModel {
+            let team = sim.team(10).unwrap();
+            let player = team.players.find(1).unwrap();
+            build_player_view_model(sim, &I18n::for_test(HashMap::new()), player, Some(team))
+        }
+    }
+
+    #[test]
+    fn diagnosis_shows_while_injured_and_recovery_after() {
+        let injured = PlayerAttributes {
+            is_injured: true,
+            injury_days_remaining: 12,
+            injury_type: Some(InjuryType::HamstringStrain),
+            ..PlayerAttributes::default()
+        };
+        let model = Fixture::view_model(&Fixture::world(injured));
+        assert_eq!(model.injury_days, Some(12));
+        assert_eq!(model.injury_type_key, Some("injury_type_hamstring_strain"));
+        assert_eq!(model.recovery_days, None);
+
+        let recovering = PlayerAttributes {
+            is_injured: false,
+            injury_days_remaining: 0,
+            recovery_days_remaining: 5,
+            ..injured
+        };
+        let model = Fixture::view_model(&Fixture::world(recovering));
+        assert_eq!(model.injury_days, None);
+        assert_eq!(model.injury_type_key, None);
+        assert_eq!(model.recovery_days, Some(5));
+    }
+
+    #[test]
+    fn career_panel_keeps_the_five_newest_seasons_with_the_live_one_first() {
+        let mut sim = Fixture::world(PlayerAttributes::default());
+        let player = sim.player_mut(1).unwrap();
+        player.statistics.played = 3;
+        player.statistics_history = PlayerStatisticsHistory::from_items(
+            (2020..2026)
+                .enumerate()
+                .map(|(seq_id, year)| PlayerStatisticsHistoryItem {
+                    season: Season::new(year),
+                    team_name: "Old".to_string(),
+                    team_slug: "old".to_string(),
+                    team_reputation: 400,
+                    league_name: "L".to_string(),
+                    league_slug: "l".to_string(),
+                    is_loan: false,
+                    transfer_fee: None,
+                    statistics: core::PlayerStatistics {
+                        played: 20,
+                        ..core::PlayerStatistics::default()
+                    },
+                    seq_id: seq_id as u32,
+                })
+                .collect(),
+        );
+        player.statistics_history.seed_initial_team(
+            &TeamInfo {
+                name: "Main".to_string(),
+                slug: "main".to_string(),
+                reputation: 500,
+                league_name: "L".to_string(),
+                league_slug: "l".to_string(),
+            },
+            NaiveDate::from_ymd_opt(2026, 8, 1).unwrap(),
+            false,
+        );
+
+        let career = Fixture::view_model(&sim).career;
+        let seasons: Vec<&str> = career.iter().map(|s| s.season.as_str()).collect();
+        assert_eq!(
+            seasons,
+            ["2026/27", "2025/26", "2024/25", "2023/24", "2022/23"]
+        );
+        assert_eq!(career[0].team_name, "Main");
+        assert_eq!(career[0].played, 3);
+        assert_eq!(career[1].team_name, "Old");
+        assert_eq!(career[1].played, 20);
+    }
+}
@@ -1,5 +1,6 @@
 pub mod actions;
 pub mod awards;
+pub mod compare;
 pub mod contract;
 pub mod decisions;
 pub mod events;
@@ -18,6 +19,7 @@ use axum::Router;
 pub fn player_routes() -> Router<GameAppData> {
     Router::new()
         .merge(get::routes::routes())
+        .merge(compare::routes::routes())
         .merge(contract::routes::routes())
         .merge(personal::routes::routes())
         .merge(events::routes::routes())
@@ -1,41 +1,44 @@
 use crate::GameAppData;
+use crate::admin::admin_routes;
 use crate::ai::routes::ai_routes;
+use crate::api::api_routes;
 use crate::champions_league::champions_league_routes;
 use crate::common::default_handler::default_handler;
+use crate::common::rate_limit::{RateLimiter, RequestLimits, rate_limit};
 use crate::conference_league::conference_league_routes;
 use crate::copa_libertadores::copa_libertadores_routes;
 use crate::countries::country_routes;
 use crate::cups::cup_routes;
 use crate::date::current_date_routes;
+use crate::editor::editor_routes;
 use crate::europa_league::europa_league_routes;
 use crate::face::face_routes;
 use crate::game::game_routes;
-use crate::i18n::{SUPPORTED_LANG_CODES, detect_language};
+use crate::health::health_routes;
+use crate::i18n::{I18nManager, language_routes, locales, preferred_language};
 use crate::leagues::league_routes;
 use crate::r#match::routes::match_routes;
+use crate::metrics::metrics_routes;
 use crate::national_competitions::national_competitions_routes;
+use crate::news::news_routes;
 use crate::player::player_routes;
 use crate::playoffs::playoff_routes;
 use crate::search::search_routes;
+use crate::session::session_routes;
 use crate::staff::staff_routes;
 use crate::teams::team_routes;
+use crate::transfers::transfer_routes;
 use crate::watchlist::watchlist_routes;
 use crate::workers::routes::workers_routes;
 use axum::Router;
-use axum::extract::{Request, State};
+use axum::extract::{DefaultBodyLimit, Request, State};
 use axum::http::HeaderMap;
-use axum::http::header::ACCEPT_LANGUAGE;
 use axum::middleware::Next;
 use axum::response::{IntoResponse, Redirect, Response};
 use axum::routing::get;
 
 async fn root_redirect(headers: HeaderMap) -> impl IntoResponse {
-    let accept_language = headers
-        .get(ACCEPT_LANGUAGE)
-        .and_then(|v| v.to_str().ok())
-        .unwrap_or("en");
-    let lang = detect_language(accept_language);
-    Redirect::temporary(&format!("/{}", lang))
+    Redirect::temporary(&format!("/{}", preferred_language(&headers)))
 }
 
 async fn sitemap_xml(State(state): State<GameAppData>) -> impl IntoResponse {
@@ -46,8 +49,10 @@ async fn sitemap_xml(State(state): State<GameAppData>) -> impl IntoResponse {
         <urlset xmlns=\"http://www.sitemaps.org/schemas/sitemap/0.9\">\n",
     );
 
+    let languages = locales::languages();
+
     // Language root pages — monthly
-    for lang in SUPPORTED_LANG_CODES {
+    for (lang, _, _) in &languages {
         xml.push_str(&format!(
             "  <url>\n    <loc>https://open-football.org/{}</loc>\n    <lastmod>{}</lastmod>\n    <changefreq>monthly</changefreq>\n  </url>\n",
             lang, date
@@ -64,7 +69,7 @@ async fn sitemap_xml(State(state): State<GameAppData>) -> impl IntoResponse {
                         if team.team_type != core::TeamType::Main {
                             continue;
                         }
-                        for lang in SUPPORTED_LANG_CODES {
+                        for (lang, _, _) in &languages {
                             xml.push_str(&format!(
                                 "  <url>\n    <loc>https://open-football.org/{}/teams/{}</loc>\n    <lastmod>{}</lastmod>\n    <changefreq>daily</changefreq>\n  </url>\n",
                                 lang, team.slug, date
@@ -88,8 +93,12 @@ async fn sitemap_xml(State(state): State<GameAppData>) -> impl IntoResponse {
 async fn redirect_on_error(request: Request, next: Next) -> Response {
     let path = request.uri().path();
 
-    // Never redirect API endpoints or static assets
-    if path.starts_with("/api/") || path.starts_with("/static/") {
+    // Never redirect API endpoints, HTML fragments, admin, settings or
+    // static assets
+    if ["/api/", "/fragments/", "/admin/", "/settings/", "/static/"]
+        .iter()
+        .any(|prefix| path.starts_with(prefix))
+    {
         return next.run(request).await;
     }
 
@@ -99,7 +108,7 @@ async fn redirect_on_error(request: Request, next: Next) -> Response {
     // Only validate paths that look like /{lang}/... (skip `/`, `/sitemap.xml`, assets)
     if !first_segment.is_empty()
         && path.matches('/').count() > 1
-        && !SUPPORTED_LANG_CODES.contains(&first_segment)
+        && !I18nManager::is_supported_language(first_segment)
     {
         return Redirect::temporary("/").into_response();
     }
@@ -116,7 +125,7 @@ async fn redirect_on_error(request: Request, next: Next) -> Response {
 pub struct ServerRoutes;
 
 impl ServerRoutes {
-    pub fn create() -> Router<GameAppData> {
+    pub fn create(limits: RequestLimits) -> Router<GameAppData> {
         Router::<GameAppData>::new()
             .route("/", get(root_redirect))
             .route("/sitemap.xml", get(sitemap_xml))
@@ -131,16 +140,31 @@ impl ServerRoutes {
             .merge(game_routes())
             .merge(league_routes())
             .merge(team_routes())
+            .merge(transfer_routes())
             .merge(player_routes())
             .merge(staff_routes())
             .merge(match_routes())
             .merge(current_date_routes())
             .merge(face_routes())
             .merge(watchlist_routes())
+            .merge(news_routes())
             .merge(search_routes())
+            .merge(session_routes())
             .merge(workers_routes())
             .merge(ai_routes())
+            .merge(api_routes())
+            .merge(metrics_routes())
+            .merge(health_routes())
+            .merge(language_routes())
+            .merge(admin_routes())
+            .merge(editor_routes())
             .fallback(default_handler)
             .layer(axum::middleware::from_fn(redirect_on_error))
+            .layer(DefaultBodyLimit::max(limits.max_body_bytes))
+            // Outside the error redirect, so a 429 reaches the client as is
+            .layer(axum::middleware::from_fn_with_state(
+                RateLimiter::new(limits.rate_limit_per_minute),
+                rate_limit,
+            ))
     }
 }
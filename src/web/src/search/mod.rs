@@ -1,11 +1,12 @@
+pub mod players;
 pub mod routes;
 
 use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
 use crate::views::{self, MenuSection};
-use crate::{ApiError, ApiResult, GameAppData, I18n};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData, I18n};
 use askama::Template;
 use axum::Json;
-use axum::extract::{Path, Query, State};
+use axum::extract::{Path, State};
 use axum::response::IntoResponse;
 use serde::{Deserialize, Serialize};
 
@@ -107,14 +108,14 @@ const MAX_RESULTS_PER_KIND: usize = 15;
 
 pub async fn search_api_action(
     State(state): State<GameAppData>,
-    Query(query): Query<SearchApiQuery>,
+    ApiQuery(query): ApiQuery<SearchApiQuery>,
 ) -> ApiResult<impl IntoResponse> {
     let guard = state.data.read().await;
     let simulator_data = guard
         .as_ref()
         .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
 
-    let needle = query.q.trim().to_lowercase();
+    let needle = folded(query.q.trim());
 
     if needle.len() < 4 {
         return Ok(Json(SearchResultsDto {
@@ -132,7 +133,7 @@ pub async fn search_api_action(
 
     for continent in &simulator_data.continents {
         for country in &continent.countries {
-            if country.name.to_lowercase().contains(&needle) {
+            if folded(&country.name).contains(&needle) {
                 countries.push(SearchCountryDto {
                     name: country.name.clone(),
                     slug: country.slug.clone(),
@@ -141,16 +142,16 @@ pub async fn search_api_action(
             }
 
             for club in &country.clubs {
-                if club.name.to_lowercase().contains(&needle) {
-                    if let Some(main) = club.teams.main() {
-                        clubs.push((
-                            main.reputation.world,
-                            SearchClubDto {
-                                name: club.name.clone(),
-                                team_slug: main.slug.clone(),
-                            },
-                        ));
-                    }
+                if folded(&club.name).contains(&needle)
+                    && let Some(main) = club.teams.main()
+                {
+                    clubs.push((
+                        main.reputation.world,
+                        SearchClubDto {
+                            name: club.name.clone(),
+                            team_slug: main.slug.clone(),
+                        },
+                    ));
                 }
 
                 for team in &club.teams.teams {
@@ -158,7 +159,7 @@ pub async fn search_api_action(
                         let first = player.full_name.display_first_name();
                         let last = player.full_name.display_last_name();
                         let full = format!("{} {}", first, last);
-                        if full.to_lowercase().contains(&needle) {
+                        if folded(&full).contains(&needle) {
                             let country_code = simulator_data
                                 .country(player.country_id)
                                 .map(|c| c.code.clone())
@@ -193,7 +194,7 @@ pub async fn search_api_action(
         let first = player.full_name.display_first_name();
         let last = player.full_name.display_last_name();
         let full = format!("{} {}", first, last);
-        if full.to_lowercase().contains(&needle) {
+        if folded(&full).contains(&needle) {
             let country_code = simulator_data
                 .country(player.country_id)
                 .map(|c| c.code.clone())
@@ -242,3 +243,21 @@ pub async fn search_api_action(
         players,
     }))
 }
+
+/// Lowercase ASCII form of `text`, so "Muller" finds "Müller" and
+/// "cerny" finds "Černý".
+fn folded(text: &str) -> String {
+    deunicode::deunicode(text).to_lowercase()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::folded;
+
+    #[test]
+    fn search_ignores_diacritics() {
+        assert!(folded("Tomáš Černý").contains(&folded("cerny")));
+        assert!(folded("Thomas Müller").contains(&folded("MÜLLER")));
+        assert!(folded("Thomas Müller").contains(&folded("muller")));
+    }
+}
@@ -0,0 +1,233 @@
+//! `GET /api/v1/players/search` — filter, sort and page through every player
+//! in the world. The heavy lifting is the pre-sorted search index kept in
+//! `SimulatorData.indexes`; this handler only parses the query and dresses
+//! the rows of one page.
+
+use crate::common::potential_stars::{PotentialStarsView, StarRating};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::State;
+use axum::response::IntoResponse;
+use chrono::NaiveDate;
+use core::shared::{PlayerSearchCursor, PlayerSearchQuery, PlayerSearchRow, PlayerSearchSort};
+use core::utils::DateUtils;
+use core::{PlayerPositionType, SimulatorData};
+use serde::{Deserialize, Serialize};
+
+const DEFAULT_PAGE_SIZE: usize = 50;
+const MAX_PAGE_SIZE: usize = 100;
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerSearchSortParam {
+    Ability,
+    Value,
+    Age,
+    ContractExpiry,
+}
+
+#[derive(Deserialize, Clone, Copy)]
+#[serde(rename_all = "snake_case")]
+pub enum PlayerSearchOrderParam {
+    Asc,
+    Desc,
+}
+
+#[derive(Deserialize, Default)]
+pub struct PlayerSearchApiQuery {
+    /// Short position name, e.g. `ST` or `DC`.
+    #[serde(default)]
+    pub position: Option<String>,
+    #[serde(default)]
+    pub min_age: Option<u8>,
+    #[serde(default)]
+    pub max_age: Option<u8>,
+    /// Country code or slug.
+    #[serde(default)]
+    pub nationality: Option<String>,
+    #[serde(default)]
+    pub min_value: Option<f64>,
+    #[serde(default)]
+    pub max_value: Option<f64>,
+    #[serde(default)]
+    pub contract_expires_before: Option<NaiveDate>,
+    /// 0 to 5 in half-star steps.
+    #[serde(default)]
+    pub min_stars: Option<f32>,
+    #[serde(default)]
+    pub max_stars: Option<f32>,
+    /// Defaults to `ability`.
+    #[serde(default)]
+    pub sort: Option<PlayerSearchSortParam>,
+    /// Defaults to `desc` for ability and value, `asc` for age and
+    /// contract expiry.
+    #[serde(default)]
+    pub order: Option<PlayerSearchOrderParam>,
+    /// `next_cursor` of the previous page.
+    #[serde(default)]
+    pub cursor: Option<String>,
+    #[serde(default)]
+    pub limit: Option<usize>,
+}
+
+#[derive(Serialize)]
+pub struct PlayerSearchResultDto {
+    pub id: u32,
+    pub slug: String,
+    pub name: String,
+    pub position: String,
+    pub age: u8,
+    pub country_code: String,
+    pub club_id: Option<u32>,
+    pub team_name: String,
+    pub team_slug: String,
+    pub value: f64,
+    pub contract_expiry: Option<NaiveDate>,
+    pub ability: StarRating,
+    pub is_free_agent: bool,
+}
+
+#[derive(Serialize)]
+pub struct PlayerSearchResponseDto {
+    pub players: Vec<PlayerSearchResultDto>,
+    pub next_cursor: Option<String>,
+}
+
+fn half_stars(field: &str, stars: f32) -> ApiResult<u8> {
+    if !(0.0..=5.0).contains(&stars) {
+        return Err(ApiError::invalid(
+            field,
+            format!("Star rating {} is outside 0-5", stars),
+        ));
+    }
+    Ok((stars * 2.0).round() as u8)
+}
+
+fn nationality_id(sim: &SimulatorData, nationality: &str) -> ApiResult<u32> {
+    sim.country_info
+        .values()
+        .find(|c| c.code.eq_ignore_ascii_case(nationality) || c.slug == nationality)
+        .map(|c| c.id)
+        .ok_or_else(|| ApiError::NotFound(format!("Country '{}' not found", nationality)))
+}
+
+fn search_query(sim: &SimulatorData, params: PlayerSearchApiQuery) -> ApiResult<PlayerSearchQuery> {
+    let position = match params.position.as_deref() {
+        Some(name) => Some(PlayerPositionType::from_short_name(name).ok_or_else(|| {
+            ApiError::invalid("position", format!("Unknown position '{}'", name))
+        })?),
+        None => None,
+    };
+    let nationality_id = match params.nationality.as_deref() {
+        Some(nationality) => Some(nationality_id(sim, nationality)?),
+        None => None,
+    };
+    let cursor = match params.cursor.as_deref() {
+        Some(cursor) => Some(
+            cursor
+                .parse::<PlayerSearchCursor>()
+                .map_err(|_| ApiError::invalid("cursor", "Invalid cursor"))?,
+        ),
+        None => None,
+    };
+    let sort = match params.sort.unwrap_or(PlayerSearchSortParam::Ability) {
+        PlayerSearchSortParam::Ability => PlayerSearchSort::Ability,
+        PlayerSearchSortParam::Value => PlayerSearchSort::Value,
+        PlayerSearchSortParam::Age => PlayerSearchSort::Age,
+        PlayerSearchSortParam::ContractExpiry => PlayerSearchSort::ContractExpiry,
+    };
+    let descending = match params.order {
+        Some(order) => matches!(order, PlayerSearchOrderParam::Desc),
+        None => matches!(sort, PlayerSearchSort::Ability | PlayerSearchSort::Value),
+    };
+
+    Ok(PlayerSearchQuery {
+        position,
+        min_age: params.min_age,
+        max_age: params.max_age,
+        nationality_id,
+        min_value: params.min_value,
+        max_value: params.max_value,
+        contract_expires_before: params.contract_expires_before,
+        min_half_stars: params
+            .min_stars
+            .map(|stars| half_stars("min_stars", stars))
+            .transpose()?,
+        max_half_stars: params
+            .max_stars
+            .map(|stars| half_stars("max_stars", stars))
+            .transpose()?,
+        sort,
+        descending,
+        cursor,
+        limit: params
+            .limit
+            .unwrap_or(DEFAULT_PAGE_SIZE)
+            .clamp(1, MAX_PAGE_SIZE),
+    })
+}
+
+fn result_dto(
+    sim: &SimulatorData,
+    row: &PlayerSearchRow,
+    today: NaiveDate,
+) -> Option<PlayerSearchResultDto> {
+    let (player, team) = match row.team_id {
+        Some(_) => {
+            let (player, team) = sim.player_with_team(row.player_id)?;
+            (player, Some(team))
+        }
+        None => (sim.player(row.player_id)?, None),
+    };
+    let country_code = sim
+        .country_info
+        .get(&player.country_id)
+        .map(|c| c.code.clone())
+        .unwrap_or_default();
+
+    Some(PlayerSearchResultDto {
+        id: player.id,
+        slug: player.slug(),
+        name: player.full_name.to_string(),
+        position: player.position().get_short_name().to_string(),
+        age: DateUtils::age(player.birth_date, today),
+        country_code,
+        club_id: team.map(|t| t.club_id),
+        team_name: team.map(|t| t.name.clone()).unwrap_or_default(),
+        team_slug: team.map(|t| t.slug.clone()).unwrap_or_default(),
+        value: row.value,
+        contract_expiry: row.contract_expiry,
+        ability: PotentialStarsView::current(player),
+        is_free_agent: team.is_none(),
+    })
+}
+
+/// `GET /api/v1/players/search?position=ST&max_age=23&min_stars=3&sort=value`
+/// — one page of players matching every given filter. Pass the returned
+/// `next_cursor` back as `cursor` for the following page.
+pub async fn player_search_api_action(
+    State(state): State<GameAppData>,
+    ApiQuery(params): ApiQuery<PlayerSearchApiQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let sim = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+    let indexes = sim
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not built".to_string()))?;
+    let today = sim.date.date();
+
+    let query = search_query(sim, params)?;
+    let page = indexes.player_search.search(&query, today);
+
+    Ok(Json(PlayerSearchResponseDto {
+        players: page
+            .rows
+            .iter()
+            .filter_map(|row| result_dto(sim, row, today))
+            .collect(),
+        next_cursor: page.next_cursor.map(|c| c.to_string()),
+    }))
+}
@@ -6,4 +6,8 @@ pub fn routes() -> Router<GameAppData> {
     Router::new()
         .route("/{lang}/search", get(super::search_page_action))
         .route("/api/search", get(super::search_api_action))
+        .route(
+            "/api/v1/players/search",
+            get(super::players::player_search_api_action),
+        )
 }
@@ -0,0 +1,213 @@
+//! Lightweight sign-in for human managers. Signing in registers a manager
+//! in the world and stores their session token in a cookie; the team
+//! management, transfer and player action endpoints resolve the cookie to
+//! the manager and only act for the club that manager controls. There are no passwords:
+//! the token is the identity, and it is saved with the world.
+
+pub mod routes;
+
+use crate::{ApiError, ApiJson, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{FromRequestParts, State};
+use axum::http::header::{COOKIE, SET_COOKIE};
+use axum::http::request::Parts;
+use axum::response::IntoResponse;
+use core::{Manager, SimulatorData};
+use serde::{Deserialize, Serialize};
+use std::convert::Infallible;
+use std::sync::Arc;
+
+pub fn session_routes() -> axum::Router<GameAppData> {
+    routes::routes()
+}
+
+const SESSION_COOKIE: &str = "of_session";
+const MAX_NAME_LENGTH: usize = 40;
+/// One year; the session lasts as long as the manager keeps playing.
+const SESSION_MAX_AGE_SECS: u32 = 365 * 24 * 60 * 60;
+
+/// The session token from the request's cookie, if any. Resolve it with
+/// [`current_manager`] while holding the simulator data lock.
+pub struct SessionToken(pub Option<String>);
+
+impl<S: Send + Sync> FromRequestParts<S> for SessionToken {
+    type Rejection = Infallible;
+
+    async fn from_request_parts(parts: &mut Parts, _state: &S) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get_all(COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .find_map(|header| cookie_value(header, SESSION_COOKIE));
+        Ok(SessionToken(token))
+    }
+}
+
+/// The value of cookie `cookie` in a `Cookie` header.
+pub fn cookie_value(header: &str, cookie: &str) -> Option<String> {
+    header.split(';').find_map(|pair| {
+        let (name, value) = pair.trim().split_once('=')?;
+        (name == cookie && !value.is_empty()).then(|| value.to_string())
+    })
+}
+
+fn session_cookie(token: &str, max_age: u32) -> String {
+    format!(
+        "{}={}; Path=/; HttpOnly; SameSite=Lax; Max-Age={}",
+        SESSION_COOKIE, token, max_age
+    )
+}
+
+/// The signed-in manager, if the session token belongs to one.
+pub fn current_manager<'a>(sim: &'a SimulatorData, session: &SessionToken) -> Option<&'a Manager> {
+    session
+        .0
+        .as_deref()
+        .and_then(|token| sim.managers.by_token(token))
+}
+
+/// The signed-in manager, or 401.
+pub fn require_manager<'a>(
+    sim: &'a SimulatorData,
+    session: &SessionToken,
+) -> ApiResult<&'a Manager> {
+    current_manager(sim, session)
+        .ok_or_else(|| ApiError::Unauthorized("Sign in as a manager first".to_string()))
+}
+
+/// The club of the signed-in manager: 401 without a session, 403 when the
+/// manager has not taken over a club yet.
+pub fn require_managed_club(sim: &SimulatorData, session: &SessionToken) -> ApiResult<u32> {
+    require_manager(sim, session)?
+        .club_id
+        .ok_or_else(|| ApiError::Forbidden("The manager has no club".to_string()))
+}
+
+#[derive(Deserialize)]
+pub struct SignInRequest {
+    pub name: String,
+}
+
+#[derive(Serialize)]
+pub struct ManagerDto {
+    pub id: u32,
+    pub name: String,
+    pub club_id: Option<u32>,
+    pub club_name: Option<String>,
+}
+
+impl ManagerDto {
+    fn new(sim: &SimulatorData, manager: &Manager) -> Self {
+        ManagerDto {
+            id: manager.id,
+            name: manager.name.clone(),
+            club_id: manager.club_id,
+            club_name: manager
+                .club_id
+                .and_then(|id| sim.club(id))
+                .map(|c| c.name.clone()),
+        }
+    }
+}
+
+/// `POST /api/v1/session` — register a manager, e.g. `{"name": "Alex"}`, and
+/// sign in as them.
+pub async fn sign_in_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    ApiJson(request): ApiJson<SignInRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let name = request.name.trim();
+    if name.is_empty() || name.chars().count() > MAX_NAME_LENGTH {
+        return Err(ApiError::invalid(
+            "name",
+            format!("must be 1 to {} characters", MAX_NAME_LENGTH),
+        ));
+    }
+
+    let data = Arc::clone(&state.data);
+    let mut guard = data.write().await;
+    let arc_data = guard
+        .as_mut()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+    if let Some(manager) = current_manager(arc_data, &session) {
+        return Err(ApiError::Conflict(format!(
+            "Already signed in as {}",
+            manager.name
+        )));
+    }
+    let sim = Arc::make_mut(arc_data);
+
+    let manager = sim.register_manager(name).clone();
+    Ok((
+        [(
+            SET_COOKIE,
+            session_cookie(&manager.token, SESSION_MAX_AGE_SECS),
+        )],
+        Json(ManagerDto::new(sim, &manager)),
+    ))
+}
+
+/// `GET /api/v1/session` — the signed-in manager.
+pub async fn session_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let sim = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let manager = require_manager(sim, &session)?;
+    Ok(Json(ManagerDto::new(sim, manager)))
+}
+
+/// `DELETE /api/v1/session` — leave the game: the manager is removed and
+/// their club goes back to the AI.
+pub async fn sign_out_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+) -> ApiResult<impl IntoResponse> {
+    let data = Arc::clone(&state.data);
+    let mut guard = data.write().await;
+    let arc_data = guard
+        .as_mut()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+    let manager_id = require_manager(arc_data, &session)?.id;
+    let sim = Arc::make_mut(arc_data);
+
+    sim.remove_manager(manager_id)
+        .map_err(|err| ApiError::NotFound(err.to_string()))?;
+    Ok([(SET_COOKIE, session_cookie("", 0))])
+}
+
+/// `GET /api/v1/managers` — every manager in the world and their club.
+pub async fn managers_action(State(state): State<GameAppData>) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let sim = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let managers: Vec<ManagerDto> = sim
+        .managers
+        .iter()
+        .map(|m| ManagerDto::new(sim, m))
+        .collect();
+    Ok(Json(managers))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cookie_value_finds_the_session_among_other_cookies() {
+        assert_eq!(
+            cookie_value("theme=dark; of_session=abc123; lang=en", SESSION_COOKIE),
+            Some("abc123".to_string())
+        );
+        assert_eq!(cookie_value("of_session=", SESSION_COOKIE), None);
+        assert_eq!(cookie_value("other_session=abc", SESSION_COOKIE), None);
+    }
+}
@@ -0,0 +1,14 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route(
+            "/api/v1/session",
+            get(super::session_action)
+                .post(super::sign_in_action)
+                .delete(super::sign_out_action),
+        )
+        .route("/api/v1/managers", get(super::managers_action))
+}
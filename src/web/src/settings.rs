@@ -1,67 +1,469 @@
+//! Process settings, layered from lowest to highest precedence: built-in
+//! defaults, an optional TOML config file, `OF_*` environment variables
+//! and `--flag=value` command-line arguments.
+//!
+//! The config file is `open_football.toml` in the working directory, or
+//! the path given by `--config=` / `OF_CONFIG`. Every key is optional:
+//!
+//! ```toml
+//! bind_address = "127.0.0.1"
+//! port = 18000
+//! log_level = "info"
+//! saves_dir = "saves"
+//! saves_db = "saves/saves.sqlite"
+//! database_dir = "my-world"
+//! fictional_world = 42
+//! database_overlay = "database-overlay.json"
+//! database_report = "database-report.json"
+//! locales_dir = "locales"
+//! locales_reload = false
+//! faces_dir = "faces"
+//! face_kit_colors = true
+//! assets_dir = "assets"
+//! admin_token = "change-me"
+//! rate_limit_per_minute = 600
+//! max_body_bytes = 1048576
+//! match_threads = 8
+//! match_store_threads = 4
+//! training_data = "training"
+//! pass_model = "pass-model.json"
+//! worker_port = 18001
+//! ```
+
+use crate::common::default_handler::set_asset_overrides;
+use crate::{PortraitStore, RequestLimits, SaveStore};
 use core::MatchRuntime;
+use database::TableFormat;
 use log::info;
+use serde::Deserialize;
 use std::env;
+use std::fmt::Display;
+use std::fs;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+use std::path::PathBuf;
+use std::str::FromStr;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const DEFAULT_CONFIG_FILE: &str = "open_football.toml";
+const DEFAULT_PORT: u16 = 18000;
+const DEFAULT_WORKER_PORT: u16 = 18001;
+const DEFAULT_LOG_LEVEL: &str = "debug";
+const DEFAULT_SAVES_DIR: &str = "saves";
+const DEFAULT_DATABASE_OVERLAY: &str = "database-overlay.json";
+const DEFAULT_LOCALES_DIR: &str = "locales";
+const DEFAULT_FACES_DIR: &str = "faces";
+const DEFAULT_STORE_THREADS: usize = 4;
+const DEFAULT_MAX_BODY_BYTES: usize = 1024 * 1024;
 
 pub struct Settings {
     pub match_events: bool,
     pub match_recordings: bool,
     pub match_threads: usize,
     pub match_store_threads: usize,
+    /// Append labelled pass and shot decisions of every simulated match
+    /// to CSV files here, see `core::TrainingData`.
+    pub training_data: Option<PathBuf>,
+    /// Learned pass-success model blended into pass evaluation, see
+    /// `core::PassSuccessModel`.
+    pub pass_model: Option<PathBuf>,
+    /// `--train-pass-model=CSV`: fit a pass model on a training-data
+    /// `passes.csv`, write it to `pass_model` and exit.
+    pub train_pass_model: Option<PathBuf>,
+    /// `--imitation-replays=DIR`: turn the match replays under DIR into
+    /// state-action pairs, fit a state-cloning model on them, write both
+    /// to `imitation_output` and exit. See `core::StateCloningModel`.
+    pub imitation_replays: Option<PathBuf>,
+    pub imitation_output: Option<PathBuf>,
     /// True when the binary was invoked with `--worker`. In that mode
     /// the process skips DB load and the HTTP web UI and listens for
     /// match-batch RPCs on `worker_port`.
     pub worker_mode: bool,
     pub worker_port: u16,
+    pub bind_address: IpAddr,
+    pub port: u16,
+    /// Default `env_logger` filter, e.g. `info` or `web=debug,core=warn`.
+    /// `RUST_LOG` still wins when set.
+    pub log_level: String,
+    /// Directory holding the save slot files.
+    pub saves_dir: PathBuf,
+    /// Keep save slots in this SQLite database instead of `saves_dir`;
+    /// needs a build with the `sqlite` feature.
+    pub saves_db: Option<PathBuf>,
+    /// A user-built world of JSON/CSV tables loaded instead of the
+    /// embedded database, see `database::DirectoryLoader`.
+    pub database_dir: Option<PathBuf>,
+    /// Seed of a made-up world played instead of the embedded database;
+    /// `--fictional-world` alone picks one at random.
+    pub fictional_world: Option<u64>,
+    /// Pre-game edits of the database, see `editor`.
+    pub database_overlay: PathBuf,
+    /// Where to write the JSON report of the database check at startup.
+    pub database_report: Option<PathBuf>,
+    /// Directory of runtime translation packs, see `i18n::locales`.
+    pub locales_dir: PathBuf,
+    /// Watch `locales_dir` and reload the packs when they change.
+    pub locales_reload: bool,
+    /// Cache of generated player portraits; an empty path turns it off.
+    pub faces_dir: PathBuf,
+    /// Draw portraits in the player's club shirt color.
+    pub face_kit_colors: bool,
+    /// Files here replace the embedded static assets of the same path;
+    /// unset serves only what is compiled into the binary.
+    pub assets_dir: Option<PathBuf>,
+    /// Bearer token for the `/admin` endpoints; unset disables them.
+    pub admin_token: Option<String>,
+    /// `--one-shot[=DAYS]`: simulate this many days without starting the
+    /// web server, save the world to the `one-shot` slot and exit.
+    pub one_shot_days: Option<u32>,
+    /// `--one-shot-seasons=N`: a one-shot run of N whole years instead.
+    pub one_shot_seasons: Option<u32>,
+    /// Where a one-shot run writes its tables, transfers and statistics.
+    pub one_shot_output: Option<PathBuf>,
+    /// `--export-database=DIR`: write the loaded database, overlay edits
+    /// included, to DIR as JSON/CSV tables and exit.
+    pub export_database: Option<PathBuf>,
+    pub export_format: TableFormat,
+    /// `--diff-database=DIR`: print what differs between the loaded
+    /// database and the one in DIR, and exit.
+    pub diff_database: Option<PathBuf>,
+    /// Requests per minute allowed from one client IP; 0 (the default)
+    /// leaves the server unlimited, as suits a local single-user run.
+    pub rate_limit_per_minute: u32,
+    pub max_body_bytes: usize,
+}
+
+/// Keys of the optional config file; all of them may be left out.
+#[derive(Deserialize, Default)]
+#[serde(deny_unknown_fields)]
+struct FileSettings {
+    bind_address: Option<IpAddr>,
+    port: Option<u16>,
+    log_level: Option<String>,
+    saves_dir: Option<PathBuf>,
+    saves_db: Option<PathBuf>,
+    database_dir: Option<PathBuf>,
+    fictional_world: Option<u64>,
+    database_overlay: Option<PathBuf>,
+    database_report: Option<PathBuf>,
+    locales_dir: Option<PathBuf>,
+    locales_reload: Option<bool>,
+    faces_dir: Option<PathBuf>,
+    face_kit_colors: Option<bool>,
+    assets_dir: Option<PathBuf>,
+    admin_token: Option<String>,
+    match_threads: Option<usize>,
+    match_store_threads: Option<usize>,
+    match_recordings: Option<bool>,
+    training_data: Option<PathBuf>,
+    pass_model: Option<PathBuf>,
+    worker_port: Option<u16>,
+    rate_limit_per_minute: Option<u32>,
+    max_body_bytes: Option<usize>,
+}
+
+/// Command-line arguments and environment of one process start.
+struct Sources {
+    args: Vec<String>,
+}
+
+impl Sources {
+    fn flag(&self, name: &str) -> bool {
+        self.args.iter().any(|arg| arg == name)
+    }
+
+    fn arg(&self, name: &str) -> Option<&str> {
+        let prefix = format!("{}=", name);
+        self.args.iter().find_map(|arg| arg.strip_prefix(&prefix))
+    }
+
+    /// `--name=value`, else the `env_key` variable, parsed as `T`.
+    fn value<T>(&self, name: &str, env_key: &str) -> Result<Option<T>, String>
+    where
+        T: FromStr,
+        T::Err: Display,
+    {
+        let (raw, source) = match self.arg(name) {
+            Some(raw) => (raw.to_string(), name),
+            None => match env::var(env_key) {
+                Ok(raw) => (raw, env_key),
+                Err(_) => return Ok(None),
+            },
+        };
+        raw.trim()
+            .parse()
+            .map(Some)
+            .map_err(|err| format!("invalid {} '{}': {}", source, raw, err))
+    }
+}
+
+impl FileSettings {
+    /// The file named by `--config=` / `OF_CONFIG`, which must exist, or
+    /// the default file when it is present.
+    fn load(sources: &Sources) -> Result<Self, String> {
+        let explicit = sources
+            .arg("--config")
+            .map(str::to_string)
+            .or_else(|| env::var("OF_CONFIG").ok());
+        let path = match explicit {
+            Some(path) => PathBuf::from(path),
+            None if fs::metadata(DEFAULT_CONFIG_FILE).is_ok() => PathBuf::from(DEFAULT_CONFIG_FILE),
+            None => return Ok(FileSettings::default()),
+        };
+
+        let text = fs::read_to_string(&path)
+            .map_err(|err| format!("cannot read config file {}: {}", path.display(), err))?;
+        Self::parse(&text).map_err(|err| format!("config file {}: {}", path.display(), err))
+    }
+
+    fn parse(text: &str) -> Result<Self, String> {
+        toml::from_str(text).map_err(|err| err.to_string())
+    }
 }
 
 impl Settings {
-    pub fn from_env() -> Self {
-        let args: Vec<String> = env::args().collect();
+    pub fn from_env() -> Result<Self, String> {
+        let sources = Sources {
+            args: env::args().collect(),
+        };
+        let file = FileSettings::load(&sources)?;
+        Self::resolve(&sources, file)
+    }
 
-        let match_events = args.iter().any(|arg| arg == "--match-events");
+    fn resolve(sources: &Sources, file: FileSettings) -> Result<Self, String> {
+        let match_events = sources.flag("--match-events");
 
-        let match_recordings = args.iter().any(|arg| arg == "--match-recording-enabled")
+        let match_recordings = sources.flag("--match-recording-enabled")
             || env::var("MATCH_RECORDING_ENABLED")
                 .map(|v| v == "true")
-                .unwrap_or(false);
+                .unwrap_or(false)
+            || file.match_recordings.unwrap_or(false);
 
-        let match_threads = args
-            .iter()
-            .find(|arg| arg.starts_with("--match-threads="))
-            .and_then(|arg| arg.strip_prefix("--match-threads="))
-            .and_then(|v| v.parse().ok())
-            .or_else(|| {
-                env::var("MATCH_PLAY_POOL_MAX_THREADS")
-                    .ok()
-                    .and_then(|v| v.parse().ok())
-            })
+        let match_threads = sources
+            .value("--match-threads", "MATCH_PLAY_POOL_MAX_THREADS")?
+            .or(file.match_threads)
             .unwrap_or_else(|| {
                 std::thread::available_parallelism()
                     .map(|n| n.get())
                     .unwrap_or(4)
             });
 
-        let match_store_threads = env::var("MATCH_STORE_POOL_MAX_THREADS")
-            .ok()
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(4);
+        let match_store_threads = sources
+            .value("--match-store-threads", "MATCH_STORE_POOL_MAX_THREADS")?
+            .or(file.match_store_threads)
+            .unwrap_or(DEFAULT_STORE_THREADS);
+
+        let worker_mode = sources.flag("--worker");
 
-        let worker_mode = args.iter().any(|arg| arg == "--worker");
+        let worker_port = sources
+            .value("--worker-port", "OF_WORKER_PORT")?
+            .or(file.worker_port)
+            .unwrap_or(DEFAULT_WORKER_PORT);
 
-        let worker_port = args
-            .iter()
-            .find(|arg| arg.starts_with("--worker-port="))
-            .and_then(|arg| arg.strip_prefix("--worker-port="))
-            .and_then(|v| v.parse().ok())
-            .unwrap_or(18001);
+        let bind_address = sources
+            .value("--bind", "OF_BIND_ADDRESS")?
+            .or(file.bind_address)
+            .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
 
-        Settings {
+        let port = sources
+            .value("--port", "OF_PORT")?
+            .or(file.port)
+            .unwrap_or(DEFAULT_PORT);
+
+        let log_level = sources
+            .value("--log-level", "OF_LOG_LEVEL")?
+            .or(file.log_level)
+            .unwrap_or_else(|| DEFAULT_LOG_LEVEL.to_string());
+
+        let saves_dir = sources
+            .value("--saves-dir", "OF_SAVES_DIR")?
+            .or(file.saves_dir)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_SAVES_DIR));
+
+        let saves_db: Option<PathBuf> = sources
+            .value("--saves-db", "OF_SAVES_DB")?
+            .or(file.saves_db);
+        if saves_db.is_some() && !cfg!(feature = "sqlite") {
+            return Err("--saves-db needs a build with the `sqlite` feature".to_string());
+        }
+
+        let database_dir = sources
+            .value("--database-dir", "OF_DATABASE_DIR")?
+            .or(file.database_dir);
+
+        let fictional_world = if sources.flag("--fictional-world") {
+            Some(
+                SystemTime::now()
+                    .duration_since(UNIX_EPOCH)
+                    .map_or(0, |elapsed| elapsed.as_nanos() as u64),
+            )
+        } else {
+            sources
+                .value("--fictional-world", "OF_FICTIONAL_WORLD")?
+                .or(file.fictional_world)
+        };
+        if fictional_world.is_some() && database_dir.is_some() {
+            return Err("--fictional-world and --database-dir can't be used together".to_string());
+        }
+
+        let database_overlay = sources
+            .value("--database-overlay", "OF_DATABASE_OVERLAY")?
+            .or(file.database_overlay)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_DATABASE_OVERLAY));
+
+        let database_report = sources
+            .value("--database-report", "OF_DATABASE_REPORT")?
+            .or(file.database_report);
+
+        let locales_dir = sources
+            .value("--locales-dir", "OF_LOCALES_DIR")?
+            .or(file.locales_dir)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_LOCALES_DIR));
+
+        let locales_reload = sources.flag("--locales-reload")
+            || sources
+                .value("--locales-reload", "OF_LOCALES_RELOAD")?
+                .or(file.locales_reload)
+                .unwrap_or(false);
+
+        let faces_dir = sources
+            .value("--faces-dir", "OF_FACES_DIR")?
+            .or(file.faces_dir)
+            .unwrap_or_else(|| PathBuf::from(DEFAULT_FACES_DIR));
+
+        let face_kit_colors = sources
+            .value("--face-kit-colors", "OF_FACE_KIT_COLORS")?
+            .or(file.face_kit_colors)
+            .unwrap_or(true);
+
+        let assets_dir = sources
+            .value("--assets-dir", "OF_ASSETS_DIR")?
+            .or(file.assets_dir);
+
+        let admin_token = sources
+            .value("--admin-token", "OF_ADMIN_TOKEN")?
+            .or(file.admin_token);
+
+        let one_shot_days = if sources.flag("--one-shot") {
+            Some(1)
+        } else {
+            sources.value("--one-shot", "OF_ONE_SHOT_DAYS")?
+        };
+
+        let one_shot_seasons = sources.value("--one-shot-seasons", "OF_ONE_SHOT_SEASONS")?;
+        let one_shot_output = sources.value("--one-shot-output", "OF_ONE_SHOT_OUTPUT")?;
+
+        let export_database = sources.value("--export-database", "OF_EXPORT_DATABASE")?;
+        let export_format = sources
+            .value("--export-format", "OF_EXPORT_FORMAT")?
+            .unwrap_or_default();
+        let diff_database = sources.value("--diff-database", "OF_DIFF_DATABASE")?;
+        if export_database.is_some() && diff_database.is_some() {
+            return Err("--export-database and --diff-database can't be used together".to_string());
+        }
+
+        let training_data = sources
+            .value("--training-data", "OF_TRAINING_DATA")?
+            .or(file.training_data);
+
+        let pass_model = sources
+            .value("--pass-model", "OF_PASS_MODEL")?
+            .or(file.pass_model);
+        let train_pass_model = sources.value("--train-pass-model", "OF_TRAIN_PASS_MODEL")?;
+        if train_pass_model.is_some() && pass_model.is_none() {
+            return Err("--train-pass-model needs --pass-model= to write the model to".to_string());
+        }
+
+        let imitation_replays = sources.value("--imitation-replays", "OF_IMITATION_REPLAYS")?;
+        let imitation_output = sources.value("--imitation-output", "OF_IMITATION_OUTPUT")?;
+        if imitation_replays.is_some() && imitation_output.is_none() {
+            return Err(
+                "--imitation-replays needs --imitation-output= to write the pairs and model to"
+                    .to_string(),
+            );
+        }
+
+        let rate_limit_per_minute = sources
+            .value("--rate-limit", "OF_RATE_LIMIT")?
+            .or(file.rate_limit_per_minute)
+            .unwrap_or(0);
+
+        let max_body_bytes = sources
+            .value("--max-body-bytes", "OF_MAX_BODY_BYTES")?
+            .or(file.max_body_bytes)
+            .unwrap_or(DEFAULT_MAX_BODY_BYTES);
+
+        Ok(Settings {
             match_events,
             match_recordings,
             match_threads,
             match_store_threads,
+            training_data,
+            pass_model,
+            train_pass_model,
+            imitation_replays,
+            imitation_output,
             worker_mode,
             worker_port,
+            bind_address,
+            port,
+            log_level,
+            saves_dir,
+            saves_db,
+            database_dir,
+            fictional_world,
+            database_overlay,
+            database_report,
+            locales_dir,
+            locales_reload,
+            faces_dir,
+            face_kit_colors,
+            assets_dir,
+            admin_token,
+            one_shot_days,
+            one_shot_seasons,
+            one_shot_output,
+            export_database,
+            export_format,
+            diff_database,
+            rate_limit_per_minute,
+            max_body_bytes,
+        })
+    }
+
+    /// Address the web server listens on.
+    pub fn listen_address(&self) -> SocketAddr {
+        SocketAddr::new(self.bind_address, self.port)
+    }
+
+    /// URL to open in the browser: the bound address, or `localhost`
+    /// when listening on every interface.
+    pub fn local_url(&self) -> String {
+        if self.bind_address.is_unspecified() {
+            format!("http://localhost:{}", self.port)
+        } else {
+            format!("http://{}", self.listen_address())
+        }
+    }
+
+    /// Portrait generator configured from `faces_dir` and `face_kit_colors`.
+    pub fn portrait_store(&self) -> PortraitStore {
+        let directory = Some(self.faces_dir.clone()).filter(|dir| !dir.as_os_str().is_empty());
+        PortraitStore::new(directory, self.face_kit_colors)
+    }
+
+    /// Save slots in `saves_db` when set, otherwise files in `saves_dir`.
+    pub fn save_store(&self) -> SaveStore {
+        #[cfg(feature = "sqlite")]
+        if let Some(path) = &self.saves_db {
+            return SaveStore::sqlite(path);
+        }
+        SaveStore::new(&self.saves_dir)
+    }
+
+    pub fn request_limits(&self) -> RequestLimits {
+        RequestLimits {
+            rate_limit_per_minute: self.rate_limit_per_minute,
+            max_body_bytes: self.max_body_bytes,
         }
     }
 
@@ -70,6 +472,9 @@ impl Settings {
         MatchRuntime::set_recordings_mode(self.match_recordings);
         MatchRuntime::init_engine_pool(self.match_threads);
         MatchRuntime::set_store_max_threads(self.match_store_threads);
+        if let Some(dir) = &self.assets_dir {
+            set_asset_overrides(dir.clone());
+        }
     }
 
     pub fn log(&self) {
@@ -83,8 +488,235 @@ impl Settings {
             "Match engine: {} threads, store: {} threads",
             self.match_threads, self.match_store_threads
         );
+        if let Some(dir) = &self.training_data {
+            info!("Training data: {}", dir.display());
+        }
+        match (&self.train_pass_model, &self.pass_model) {
+            (Some(data), Some(model)) => info!(
+                "Training a pass model on {} into {}",
+                data.display(),
+                model.display()
+            ),
+            (None, Some(model)) => info!("Pass model: {}", model.display()),
+            _ => {}
+        }
+        if let (Some(replays), Some(output)) = (&self.imitation_replays, &self.imitation_output) {
+            info!(
+                "Imitation data from the replays in {} into {}",
+                replays.display(),
+                output.display()
+            );
+        }
+        match &self.saves_db {
+            Some(path) => info!("Saves database: {}", path.display()),
+            None => info!("Saves directory: {}", self.saves_dir.display()),
+        }
+        if let Some(dir) = &self.database_dir {
+            info!("Game database: {}", dir.display());
+        }
+        if let Some(seed) = self.fictional_world {
+            info!("Fictional world, seed {}", seed);
+        }
+        if !self.faces_dir.as_os_str().is_empty() {
+            info!("Portrait cache: {}", self.faces_dir.display());
+        }
         if self.worker_mode {
             info!("Worker mode on, listening port {}", self.worker_port);
         }
+        if self.rate_limit_per_minute > 0 {
+            info!(
+                "Rate limit: {} requests per minute per IP",
+                self.rate_limit_per_minute
+            );
+        }
+        if let Some(dir) = &self.assets_dir {
+            info!("Asset overrides: {}", dir.display());
+        }
+        if self.admin_token.is_some() {
+            info!("Admin endpoints enabled");
+        }
+        if let Some(seasons) = self.one_shot_seasons {
+            info!("One-shot mode: simulating {} seasons", seasons);
+        } else if let Some(days) = self.one_shot_days {
+            info!("One-shot mode: simulating {} days", days);
+        }
+        if let Some(dir) = &self.one_shot_output {
+            info!("One-shot results: {}", dir.display());
+        }
+        if let Some(dir) = &self.export_database {
+            info!(
+                "Exporting the database to {} as {}",
+                dir.display(),
+                self.export_format
+            );
+        }
+        if let Some(dir) = &self.diff_database {
+            info!("Comparing the database with {}", dir.display());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sources(args: &[&str]) -> Sources {
+        Sources {
+            args: args.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn arguments_override_the_config_file() {
+        let file =
+            FileSettings::parse("port = 9000\nbind_address = \"127.0.0.1\"\nlog_level = \"warn\"")
+                .unwrap();
+        let settings = Settings::resolve(&sources(&["of", "--port=9100"]), file).unwrap();
+
+        assert_eq!(settings.port, 9100);
+        assert_eq!(settings.log_level, "warn");
+        assert_eq!(settings.local_url(), "http://127.0.0.1:9100");
+        assert_eq!(settings.rate_limit_per_minute, 0);
+        assert_eq!(settings.faces_dir, PathBuf::from("faces"));
+        assert!(settings.face_kit_colors);
+        assert!(settings.database_dir.is_none());
+    }
+
+    #[test]
+    fn one_shot_defaults_to_a_single_day() {
+        let settings =
+            Settings::resolve(&sources(&["of", "--one-shot"]), FileSettings::default()).unwrap();
+        assert_eq!(settings.one_shot_days, Some(1));
+
+        let settings =
+            Settings::resolve(&sources(&["of", "--one-shot=30"]), FileSettings::default()).unwrap();
+        assert_eq!(settings.one_shot_days, Some(30));
+
+        let settings = Settings::resolve(
+            &sources(&["of", "--one-shot-seasons=3", "--one-shot-output=out"]),
+            FileSettings::default(),
+        )
+        .unwrap();
+        assert_eq!(settings.one_shot_days, None);
+        assert_eq!(settings.one_shot_seasons, Some(3));
+        assert_eq!(settings.one_shot_output, Some(PathBuf::from("out")));
+    }
+
+    #[test]
+    fn training_data_flag_overrides_the_file() {
+        let file = FileSettings {
+            training_data: Some(PathBuf::from("from-file")),
+            ..FileSettings::default()
+        };
+        let settings = Settings::resolve(&sources(&["of"]), file).unwrap();
+        assert_eq!(settings.training_data, Some(PathBuf::from("from-file")));
+
+        let file = FileSettings {
+            training_data: Some(PathBuf::from("from-file")),
+            ..FileSettings::default()
+        };
+        let settings = Settings::resolve(&sources(&["of", "--training-data=runs"]), file).unwrap();
+        assert_eq!(settings.training_data, Some(PathBuf::from("runs")));
+    }
+
+    #[test]
+    fn training_a_pass_model_needs_its_output_path() {
+        assert!(
+            Settings::resolve(
+                &sources(&["of", "--train-pass-model=passes.csv"]),
+                FileSettings::default()
+            )
+            .is_err()
+        );
+
+        let file = FileSettings {
+            pass_model: Some(PathBuf::from("model.json")),
+            ..FileSettings::default()
+        };
+        let settings =
+            Settings::resolve(&sources(&["of", "--train-pass-model=passes.csv"]), file).unwrap();
+        assert_eq!(settings.train_pass_model, Some(PathBuf::from("passes.csv")));
+        assert_eq!(settings.pass_model, Some(PathBuf::from("model.json")));
+    }
+
+    #[test]
+    fn imitation_replays_need_an_output_directory() {
+        let only_replays = sources(&["of", "--imitation-replays=match_results"]);
+        assert!(Settings::resolve(&only_replays, FileSettings::default()).is_err());
+
+        let settings = Settings::resolve(
+            &sources(&[
+                "of",
+                "--imitation-replays=match_results",
+                "--imitation-output=imitation",
+            ]),
+            FileSettings::default(),
+        )
+        .unwrap();
+        assert_eq!(
+            settings.imitation_replays,
+            Some(PathBuf::from("match_results"))
+        );
+        assert_eq!(settings.imitation_output, Some(PathBuf::from("imitation")));
+    }
+
+    #[test]
+    fn database_tools_take_a_directory() {
+        let settings = Settings::resolve(
+            &sources(&["of", "--export-database=out", "--export-format=CSV"]),
+            FileSettings::default(),
+        )
+        .unwrap();
+        assert_eq!(settings.export_database, Some(PathBuf::from("out")));
+        assert_eq!(settings.export_format, TableFormat::Csv);
+
+        let settings = Settings::resolve(
+            &sources(&["of", "--diff-database=old"]),
+            FileSettings::default(),
+        )
+        .unwrap();
+        assert_eq!(settings.diff_database, Some(PathBuf::from("old")));
+        assert_eq!(settings.export_format, TableFormat::Json);
+
+        assert!(
+            Settings::resolve(
+                &sources(&["of", "--export-format=xml"]),
+                FileSettings::default()
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn bad_values_and_unknown_keys_are_reported() {
+        assert!(
+            Settings::resolve(&sources(&["of", "--port=abc"]), FileSettings::default()).is_err()
+        );
+        assert!(FileSettings::parse("prot = 1").is_err());
+    }
+
+    #[test]
+    fn fictional_world_takes_a_seed_but_not_a_database_dir() {
+        let settings = Settings::resolve(
+            &sources(&["of", "--fictional-world=42"]),
+            FileSettings::default(),
+        )
+        .unwrap();
+        assert_eq!(settings.fictional_world, Some(42));
+
+        let settings = Settings::resolve(
+            &sources(&["of", "--fictional-world"]),
+            FileSettings::default(),
+        )
+        .unwrap();
+        assert!(settings.fictional_world.is_some());
+
+        assert!(
+            Settings::resolve(
+                &sources(&["of", "--fictional-world=1", "--database-dir=world"]),
+                FileSettings::default()
+            )
+            .is_err()
+        );
     }
 }
@@ -76,6 +76,7 @@ pub struct TeamFinancesTemplate {
 
 pub struct SponsorDto {
     pub name: String,
+    pub kind_key: &'static str,
     pub annual_income: String,
 }
 
@@ -198,6 +199,7 @@ pub async fn team_finances_get_action(
         .iter()
         .map(|c| SponsorDto {
             name: c.sponsor_name.clone(),
+            kind_key: c.kind.as_i18n_key(),
             annual_income: format_currency(c.wage as i64),
         })
         .collect();
@@ -1,6 +1,7 @@
+use crate::ApiJson;
 use crate::GameAppData;
 use crate::ai::agent::AiAgent;
-use crate::i18n::SUPPORTED_LANGUAGES;
+use crate::i18n::locales;
 use axum::Json;
 use axum::extract::State;
 use axum::response::IntoResponse;
@@ -36,7 +37,7 @@ pub struct TeamReportStart {
 /// render tool calls live and finally the report text.
 pub async fn team_ai_report_action(
     State(state): State<GameAppData>,
-    Json(body): Json<TeamReportRequest>,
+    ApiJson(body): ApiJson<TeamReportRequest>,
 ) -> impl IntoResponse {
     let Some(settings) = state.ai.get().await else {
         return Json(TeamReportStart {
@@ -62,11 +63,7 @@ pub async fn team_ai_report_action(
         .club(body.club_id)
         .map(|club| club.name.clone())
         .unwrap_or_default();
-    let language = SUPPORTED_LANGUAGES
-        .iter()
-        .find(|(code, _, _)| *code == body.lang)
-        .map(|(_, _, name)| *name)
-        .unwrap_or("English");
+    let language = locales::language_name(&body.lang).unwrap_or("English");
 
     let system = format!(
         "{TEAM_REPORT_PROMPT}\n\n## Response language\nWrite your entire final report in {language}."
@@ -15,7 +15,7 @@ use core::PlayerPositionType;
 use core::PlayerStatusType;
 use core::utils::{DateUtils, FormattingUtils};
 use core::{SimulatorData, Team, TeamType};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
 #[derive(Deserialize)]
 pub struct TeamGetRequest {
@@ -53,6 +53,7 @@ pub struct TeamGetTemplate {
     pub players: Vec<TeamPlayer>,
 }
 
+#[derive(Serialize)]
 pub struct TeamPlayer {
     pub id: u32,
     pub slug: String,
@@ -60,6 +61,7 @@ pub struct TeamPlayer {
     pub first_name: String,
     pub behaviour: String,
     pub position: String,
+    #[serde(skip)]
     pub position_sort: PlayerPositionType,
     pub value: String,
     pub injured: bool,
@@ -85,6 +87,7 @@ pub struct TeamPlayer {
     pub is_captain: bool,
     pub is_vice_captain: bool,
     #[allow(dead_code)]
+    #[serde(skip)]
     pub status: PlayerStatusDto,
 }
 
@@ -116,6 +119,115 @@ pub async fn team_get_action(
         .team(team_id)
         .ok_or_else(|| ApiError::NotFound(format!("Team with ID {} not found", team_id)))?;
 
+    let players = build_team_players(simulator_data, team);
+    let league = team.league_id.and_then(|id| simulator_data.league(id));
+
+    let (neighbor_teams, country_leagues) =
+        get_neighbor_teams(team.club_id, simulator_data, &i18n)?;
+    let neighbor_refs: Vec<(&str, &str)> = neighbor_teams
+        .iter()
+        .map(|(n, s)| (n.as_str(), s.as_str()))
+        .collect();
+    let league_refs: Vec<(&str, &str)> = country_leagues
+        .iter()
+        .map(|(n, s)| (n.as_str(), s.as_str()))
+        .collect();
+
+    let (cn, cs) = views::club_country_info(simulator_data, team.club_id);
+    let current_path = format!("/{}/teams/{}", &route_params.lang, &team.slug);
+    let menu_params = views::MenuParams {
+        i18n: &i18n,
+        lang: &route_params.lang,
+        current_path: &current_path,
+        country_name: cn,
+        country_slug: cs,
+    };
+    let menu_sections = views::team_menu(&menu_params, &neighbor_refs, &league_refs);
+    let title = team.name.clone();
+
+    let league_title = league
+        .map(|l| views::league_display_name(l, &i18n, simulator_data))
+        .unwrap_or_default();
+
+    let club_id = team.club_id;
+    // The AI team report is a club-level feature surfaced once, on the Main
+    // team page only — not on B / reserve / youth (U18…) squads.
+    let ai_enabled =
+        team.team_type == TeamType::Main && state.ai.is_configured().await;
+
+    Ok(TeamGetTemplate {
+        css_version: CSS_VERSION,
+        computer_name: &COMPUTER_NAME,
+        cpu_brand: &CPU_BRAND,
+        cores_count: *CPU_CORES,
+        i18n,
+        lang: route_params.lang.clone(),
+        title,
+        sub_title_prefix: String::new(),
+        sub_title_suffix: String::new(),
+        sub_title: league_title,
+        sub_title_link: league
+            .map(|l| format!("/{}/leagues/{}", &route_params.lang, &l.slug))
+            .unwrap_or_default(),
+        sub_title_country_code: String::new(),
+        header_color: simulator_data
+            .club(team.club_id)
+            .map(|c| c.colors.background.clone())
+            .unwrap_or_default(),
+        foreground_color: simulator_data
+            .club(team.club_id)
+            .map(|c| c.colors.foreground.clone())
+            .unwrap_or_default(),
+        menu_sections,
+        team_slug: team.slug.clone(),
+        club_id,
+        ai_enabled,
+        active_tab: "squad",
+        show_finances_tab: team.team_type.is_own_team(),
+        show_academy_tab: team.team_type == TeamType::Main
+            || team.team_type == TeamType::U18,
+        players,
+    })
+}
+
+/// Squad rows alone, rendered by the same markup the squad page includes.
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "teams/get/squad_rows.html")]
+pub struct TeamSquadFragmentTemplate {
+    pub i18n: I18n,
+    pub lang: String,
+    pub players: Vec<TeamPlayer>,
+}
+
+/// `GET /fragments/{lang}/teams/{team_slug}/squad`
+pub async fn team_squad_fragment_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<TeamGetRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let team = simulator_data
+        .indexes
+        .as_ref()
+        .and_then(|i| i.slug_indexes.get_team_by_slug(&route_params.team_slug))
+        .and_then(|id| simulator_data.team(id))
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("Team '{}' not found", route_params.team_slug))
+        })?;
+
+    Ok(TeamSquadFragmentTemplate {
+        i18n: state.i18n.for_lang(&route_params.lang),
+        players: build_team_players(simulator_data, team),
+        lang: route_params.lang,
+    })
+}
+
+/// Squad rows for `team` — the roster plus players it has loaned out —
+/// ordered by position. Shared by the squad page and the JSON API.
+pub fn build_team_players(simulator_data: &SimulatorData, team: &Team) -> Vec<TeamPlayer> {
     let league = team.league_id.and_then(|id| simulator_data.league(id));
     let league_rep = league.map(|l| l.reputation).unwrap_or(0);
     let club_rep = team.reputation.market_value_score();
@@ -283,72 +395,7 @@ pub async fn team_get_action(
             .unwrap_or(std::cmp::Ordering::Equal)
     });
 
-    let (neighbor_teams, country_leagues) =
-        get_neighbor_teams(team.club_id, simulator_data, &i18n)?;
-    let neighbor_refs: Vec<(&str, &str)> = neighbor_teams
-        .iter()
-        .map(|(n, s)| (n.as_str(), s.as_str()))
-        .collect();
-    let league_refs: Vec<(&str, &str)> = country_leagues
-        .iter()
-        .map(|(n, s)| (n.as_str(), s.as_str()))
-        .collect();
-
-    let (cn, cs) = views::club_country_info(simulator_data, team.club_id);
-    let current_path = format!("/{}/teams/{}", &route_params.lang, &team.slug);
-    let menu_params = views::MenuParams {
-        i18n: &i18n,
-        lang: &route_params.lang,
-        current_path: &current_path,
-        country_name: cn,
-        country_slug: cs,
-    };
-    let menu_sections = views::team_menu(&menu_params, &neighbor_refs, &league_refs);
-    let title = team.name.clone();
-
-    let league_title = league
-        .map(|l| views::league_display_name(l, &i18n, simulator_data))
-        .unwrap_or_default();
-
-    let club_id = team.club_id;
-    // The AI team report is a club-level feature surfaced once, on the Main
-    // team page only — not on B / reserve / youth (U18…) squads.
-    let ai_enabled =
-        team.team_type == TeamType::Main && state.ai.is_configured().await;
-
-    Ok(TeamGetTemplate {
-        css_version: CSS_VERSION,
-        computer_name: &COMPUTER_NAME,
-        cpu_brand: &CPU_BRAND,
-        cores_count: *CPU_CORES,
-        i18n,
-        lang: route_params.lang.clone(),
-        title,
-        sub_title_prefix: String::new(),
-        sub_title_suffix: String::new(),
-        sub_title: league_title,
-        sub_title_link: league
-            .map(|l| format!("/{}/leagues/{}", &route_params.lang, &l.slug))
-            .unwrap_or_default(),
-        sub_title_country_code: String::new(),
-        header_color: simulator_data
-            .club(team.club_id)
-            .map(|c| c.colors.background.clone())
-            .unwrap_or_default(),
-        foreground_color: simulator_data
-            .club(team.club_id)
-            .map(|c| c.colors.foreground.clone())
-            .unwrap_or_default(),
-        menu_sections,
-        team_slug: team.slug.clone(),
-        club_id,
-        ai_enabled,
-        active_tab: "squad",
-        show_finances_tab: team.team_type.is_own_team(),
-        show_academy_tab: team.team_type == TeamType::Main
-            || team.team_type == TeamType::U18,
-        players,
-    })
+    players
 }
 
 fn get_neighbor_teams(
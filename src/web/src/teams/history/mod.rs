@@ -0,0 +1,338 @@
+pub mod routes;
+
+use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
+use crate::common::slug::player_history_slug;
+use crate::cups::history::season_label;
+use crate::views::{self, MenuSection};
+use crate::{ApiError, ApiResult, GameAppData, I18n};
+use askama::Template;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use core::transfers::{CompletedTransfer, TransferType};
+use core::utils::FormattingUtils;
+use core::{Player, SimulatorData, Team};
+use serde::Deserialize;
+
+/// Club legends listed on the Hall of Fame.
+const MAX_LEGENDS: usize = 15;
+
+#[derive(Deserialize)]
+pub struct TeamHistoryRequest {
+    lang: String,
+    team_slug: String,
+}
+
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "teams/history/index.html")]
+pub struct TeamHistoryTemplate {
+    pub css_version: &'static str,
+    pub computer_name: &'static str,
+    pub cpu_brand: &'static str,
+    pub cores_count: usize,
+    pub i18n: I18n,
+    pub lang: String,
+    pub title: String,
+    pub sub_title_prefix: String,
+    pub sub_title_suffix: String,
+    pub sub_title: String,
+    pub sub_title_link: String,
+    pub sub_title_country_code: String,
+    pub header_color: String,
+    pub foreground_color: String,
+    pub menu_sections: Vec<MenuSection>,
+    pub team_slug: String,
+    pub active_tab: &'static str,
+    pub show_finances_tab: bool,
+    pub show_academy_tab: bool,
+    /// League titles and cup wins, most recent first.
+    pub honours: Vec<Honour>,
+    pub legends: Vec<ClubLegend>,
+    pub record_signing: Option<RecordTransfer>,
+    pub record_sale: Option<RecordTransfer>,
+}
+
+pub struct Honour {
+    pub season_label: String,
+    pub competition_name: String,
+    pub competition_link: String,
+    /// Sort key: the season's start year.
+    season_start_year: i32,
+}
+
+pub struct ClubLegend {
+    pub player_slug: String,
+    pub player_name: String,
+    pub apps: u32,
+    pub goals: u32,
+    pub retired: bool,
+}
+
+pub struct RecordTransfer {
+    pub player_slug: String,
+    pub player_name: String,
+    pub other_club: String,
+    pub fee: String,
+    pub date: String,
+}
+
+pub async fn team_history_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<TeamHistoryRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let i18n = state.i18n.for_lang(&route_params.lang);
+
+    let team_id = simulator_data
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?
+        .slug_indexes
+        .get_team_by_slug(&route_params.team_slug)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("Team '{}' not found", route_params.team_slug))
+        })?;
+
+    let team = simulator_data
+        .team(team_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Team with ID {} not found", team_id)))?;
+
+    let league = team.league_id.and_then(|id| simulator_data.league(id));
+
+    let (neighbor_teams, country_leagues) =
+        get_neighbor_teams(team.club_id, simulator_data, &i18n)?;
+    let neighbor_refs: Vec<(&str, &str)> = neighbor_teams
+        .iter()
+        .map(|(n, s)| (n.as_str(), s.as_str()))
+        .collect();
+    let league_refs: Vec<(&str, &str)> = country_leagues
+        .iter()
+        .map(|(n, s)| (n.as_str(), s.as_str()))
+        .collect();
+
+    let honours = honours(team, simulator_data, &route_params.lang);
+    let legends = legends(team, simulator_data);
+    let (record_signing, record_sale) = record_transfers(team.club_id, simulator_data);
+
+    let (cn, cs) = views::club_country_info(simulator_data, team.club_id);
+    let current_path = format!("/{}/teams/{}/history", &route_params.lang, &team.slug);
+    let menu_params = views::MenuParams {
+        i18n: &i18n,
+        lang: &route_params.lang,
+        current_path: &current_path,
+        country_name: cn,
+        country_slug: cs,
+    };
+    let menu_sections = views::team_menu(&menu_params, &neighbor_refs, &league_refs);
+    let title = team.name.clone();
+    let league_title = league
+        .map(|l| views::league_display_name(l, &i18n, simulator_data))
+        .unwrap_or_default();
+
+    Ok(TeamHistoryTemplate {
+        css_version: CSS_VERSION,
+        computer_name: &COMPUTER_NAME,
+        cpu_brand: &CPU_BRAND,
+        cores_count: *CPU_CORES,
+        i18n,
+        lang: route_params.lang.clone(),
+        title,
+        sub_title_prefix: String::new(),
+        sub_title_suffix: String::new(),
+        sub_title: league_title,
+        sub_title_link: league
+            .map(|l| format!("/{}/leagues/{}", &route_params.lang, &l.slug))
+            .unwrap_or_default(),
+        sub_title_country_code: String::new(),
+        header_color: simulator_data
+            .club(team.club_id)
+            .map(|c| c.colors.background.clone())
+            .unwrap_or_default(),
+        foreground_color: simulator_data
+            .club(team.club_id)
+            .map(|c| c.colors.foreground.clone())
+            .unwrap_or_default(),
+        menu_sections,
+        team_slug: team.slug.clone(),
+        active_tab: "history",
+        show_finances_tab: team.team_type.is_own_team(),
+        show_academy_tab: team.team_type == core::TeamType::Main
+            || team.team_type == core::TeamType::U18,
+        honours,
+        legends,
+        record_signing,
+        record_sale,
+    })
+}
+
+/// League titles from each league's season archive and domestic cup wins
+/// from the cup's roll of honour, in the team's own country.
+fn honours(team: &Team, data: &SimulatorData, lang: &str) -> Vec<Honour> {
+    let Some(country) = data.country_by_club(team.club_id) else {
+        return Vec::new();
+    };
+
+    let mut honours: Vec<Honour> = Vec::new();
+    for league in country.leagues.leagues.iter().filter(|l| !l.friendly) {
+        for record in &league.history {
+            if record.champion_team_id() == Some(team.id) {
+                honours.push(Honour {
+                    season_label: season_label(&league.settings, record.season_start_year),
+                    competition_name: league.name.clone(),
+                    competition_link: format!("/{}/leagues/{}/history", lang, league.slug),
+                    season_start_year: record.season_start_year,
+                });
+            }
+        }
+    }
+    if let Some(cup) = &country.domestic_cup {
+        for entry in &cup.past_champions {
+            if entry.champion_team_id == team.id {
+                honours.push(Honour {
+                    season_label: season_label(&cup.league.settings, entry.season_start_year),
+                    competition_name: cup.league.name.clone(),
+                    competition_link: format!("/{}/cups/{}/history", lang, cup.league.slug),
+                    season_start_year: entry.season_start_year,
+                });
+            }
+        }
+    }
+
+    honours.sort_by_key(|h| std::cmp::Reverse(h.season_start_year));
+    honours
+}
+
+/// The team's all-time appearance leaders: every player who ever turned
+/// out for it — current squads, free agents and the retired — ranked by
+/// league appearances for this team (frozen seasons plus the live one).
+fn legends(team: &Team, data: &SimulatorData) -> Vec<ClubLegend> {
+    let mut legends: Vec<ClubLegend> = Vec::new();
+    let mut consider = |player: &Player, current_team_id: Option<u32>, retired: bool| {
+        let mut apps: u32 = 0;
+        let mut goals: u32 = 0;
+        for item in &player.statistics_history.items {
+            if item.team_slug == team.slug {
+                apps += (item.statistics.played + item.statistics.played_subs) as u32;
+                goals += item.statistics.goals as u32;
+            }
+        }
+        if current_team_id == Some(team.id) {
+            apps += (player.statistics.played + player.statistics.played_subs) as u32;
+            goals += player.statistics.goals as u32;
+        }
+        if apps == 0 {
+            return;
+        }
+        legends.push(ClubLegend {
+            player_slug: player.slug(),
+            player_name: format!(
+                "{} {}",
+                player.full_name.display_first_name(),
+                player.full_name.display_last_name()
+            ),
+            apps,
+            goals,
+            retired,
+        });
+    };
+
+    for continent in &data.continents {
+        for country in &continent.countries {
+            for club in &country.clubs {
+                for squad in &club.teams.teams {
+                    for player in squad.players.players() {
+                        consider(player, Some(squad.id), false);
+                    }
+                }
+            }
+            for player in &country.retired_players {
+                consider(player, None, true);
+            }
+        }
+    }
+    for player in &data.free_agents {
+        consider(player, None, false);
+    }
+
+    legends.sort_by_key(|l| std::cmp::Reverse((l.apps, l.goals)));
+    legends.truncate(MAX_LEGENDS);
+    legends
+}
+
+/// Biggest fee the club has paid and received, across every country's
+/// transfer log (a sale abroad is filed under the buyer's country).
+fn record_transfers(
+    club_id: u32,
+    data: &SimulatorData,
+) -> (Option<RecordTransfer>, Option<RecordTransfer>) {
+    let mut signing: Option<&CompletedTransfer> = None;
+    let mut sale: Option<&CompletedTransfer> = None;
+
+    let transfers = data
+        .continents
+        .iter()
+        .flat_map(|c| c.countries.iter())
+        .flat_map(|c| c.transfer_market.transfer_history.iter())
+        .filter(|t| !matches!(t.transfer_type, TransferType::Loan(_)) && t.fee.amount > 0.0);
+    for t in transfers {
+        if t.to_club_id == club_id && signing.is_none_or(|s| t.fee.amount > s.fee.amount) {
+            signing = Some(t);
+        }
+        if t.from_club_id == club_id && sale.is_none_or(|s| t.fee.amount > s.fee.amount) {
+            sale = Some(t);
+        }
+    }
+
+    let record = |t: &CompletedTransfer, other_club: &str| RecordTransfer {
+        player_slug: player_history_slug(data, t.player_id, &t.player_name),
+        player_name: t.player_name.clone(),
+        other_club: other_club.to_string(),
+        fee: FormattingUtils::format_money(t.fee.amount),
+        date: t.transfer_date.format("%d.%m.%Y").to_string(),
+    };
+    (
+        signing.map(|t| record(t, &t.from_team_name)),
+        sale.map(|t| record(t, &t.to_team_name)),
+    )
+}
+
+/// The club's other teams and its country's leagues, as (name, slug) pairs.
+type NeighborTeams = (Vec<(String, String)>, Vec<(String, String)>);
+
+fn get_neighbor_teams(
+    club_id: u32,
+    data: &SimulatorData,
+    i18n: &I18n,
+) -> Result<NeighborTeams, ApiError> {
+    let club = data
+        .club(club_id)
+        .ok_or_else(|| ApiError::InternalError(format!("Club with ID {} not found", club_id)))?;
+
+    let teams = views::neighbor_teams(club, i18n);
+
+    let mut country_leagues: Vec<(u32, String, String)> = data
+        .country_by_club(club_id)
+        .map(|country| {
+            country
+                .leagues
+                .leagues
+                .iter()
+                .filter(|l| !l.friendly)
+                .map(|l| (l.id, l.name.clone(), l.slug.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    country_leagues.sort_by_key(|(id, _, _)| *id);
+
+    Ok((
+        teams,
+        country_leagues
+            .into_iter()
+            .map(|(_, name, slug)| (name, slug))
+            .collect(),
+    ))
+}
@@ -0,0 +1,249 @@
+//! Team management for manager-controlled clubs: a signed-in manager
+//! takes over a club, then sets its starting XI, bench, captain, set-piece
+//! takers and formation. The plan is stored on the team and used by its
+//! next competitive matches until it is changed or reset. Only the club's
+//! own manager may change it.
+
+pub mod routes;
+
+use crate::session::{SessionToken, current_manager, require_manager};
+use crate::{ApiError, ApiJson, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use core::{
+    LineupError, ManagerError, MatchTacticType, MatchdayLineup, MatchdayPlan, SimulatorData, Team,
+};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+pub struct TeamPathParam {
+    pub team_slug: String,
+}
+
+#[derive(Deserialize)]
+pub struct LineupRequest {
+    pub starting_eleven: Vec<u32>,
+    #[serde(default)]
+    pub substitutes: Vec<u32>,
+    #[serde(default)]
+    pub captain_id: Option<u32>,
+    #[serde(default)]
+    pub penalty_taker_id: Option<u32>,
+    #[serde(default)]
+    pub free_kick_taker_id: Option<u32>,
+}
+
+#[derive(Deserialize)]
+pub struct TacticRequest {
+    pub tactic: MatchTacticType,
+}
+
+#[derive(Serialize)]
+pub struct MatchdayPlanDto {
+    pub team_slug: String,
+    pub club_id: u32,
+    /// Name of the manager controlling the club; `None` for an AI club.
+    pub manager_name: Option<String>,
+    /// Whether the signed-in manager controls the club.
+    pub managed: bool,
+    pub plan: MatchdayPlan,
+}
+
+impl MatchdayPlanDto {
+    fn new(sim: &SimulatorData, team: &Team, session: &SessionToken) -> Self {
+        let manager = sim.managers.of_club(team.club_id);
+        let signed_in = current_manager(sim, session);
+        MatchdayPlanDto {
+            team_slug: team.slug.clone(),
+            club_id: team.club_id,
+            manager_name: manager.map(|m| m.name.clone()),
+            managed: manager.is_some_and(|m| signed_in.is_some_and(|s| s.id == m.id)),
+            plan: team.matchday_plan.clone(),
+        }
+    }
+}
+
+fn team_id(sim: &SimulatorData, team_slug: &str) -> ApiResult<u32> {
+    sim.indexes
+        .as_ref()
+        .and_then(|i| i.slug_indexes.get_team_by_slug(team_slug))
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", team_slug)))
+}
+
+/// Run `edit` on a team of the signed-in manager's club and return its
+/// updated plan.
+async fn edit_managed_team<F>(
+    state: &GameAppData,
+    session: &SessionToken,
+    team_slug: &str,
+    edit: F,
+) -> ApiResult<Json<MatchdayPlanDto>>
+where
+    F: FnOnce(&mut Team) -> ApiResult<()>,
+{
+    let data = Arc::clone(&state.data);
+    let mut guard = data.write().await;
+    let arc_data = guard
+        .as_mut()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let managed_club_id = require_manager(arc_data, session)?.club_id;
+    let id = team_id(arc_data, team_slug)?;
+    let club_id = arc_data
+        .team(id)
+        .map(|t| t.club_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", team_slug)))?;
+    if managed_club_id != Some(club_id) {
+        return Err(ApiError::Forbidden(format!(
+            "Team '{}' is not managed by the signed-in manager",
+            team_slug
+        )));
+    }
+
+    let sim = Arc::make_mut(arc_data);
+    let team = sim
+        .team_mut(id)
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", team_slug)))?;
+    edit(team)?;
+
+    let team = sim
+        .team(id)
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", team_slug)))?;
+    Ok(Json(MatchdayPlanDto::new(sim, team, session)))
+}
+
+/// `POST /api/v1/teams/{team_slug}/manage` — hand the team's club to the
+/// signed-in manager. Refused while another manager controls it. Plans
+/// left on the manager's previous club are cleared so it goes back to its
+/// own coach.
+pub async fn team_manage_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    Path(params): Path<TeamPathParam>,
+) -> ApiResult<impl IntoResponse> {
+    let data = Arc::clone(&state.data);
+    let mut guard = data.write().await;
+    let arc_data = guard
+        .as_mut()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let manager_id = require_manager(arc_data, &session)?.id;
+    let id = team_id(arc_data, &params.team_slug)?;
+    let club_id = arc_data
+        .team(id)
+        .map(|t| t.club_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", params.team_slug)))?;
+
+    let sim = Arc::make_mut(arc_data);
+    sim.set_manager_club(manager_id, Some(club_id))
+        .map_err(|err| match err {
+            ManagerError::ClubTaken => ApiError::Forbidden(err.to_string()),
+            _ => ApiError::NotFound(err.to_string()),
+        })?;
+
+    let team = sim
+        .team(id)
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", params.team_slug)))?;
+    Ok(Json(MatchdayPlanDto::new(sim, team, &session)))
+}
+
+/// `GET /api/v1/teams/{team_slug}/matchday-plan` — the team's current plan.
+pub async fn team_matchday_plan_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    Path(params): Path<TeamPathParam>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let sim = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let team = sim
+        .team(team_id(sim, &params.team_slug)?)
+        .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", params.team_slug)))?;
+    Ok(Json(MatchdayPlanDto::new(sim, team, &session)))
+}
+
+/// `POST /api/v1/teams/{team_slug}/lineup` — set the starting XI (in
+/// formation-slot order, goalkeeper first), bench, captain and set-piece
+/// takers. Rejected with the broken squad rule when invalid.
+pub async fn team_lineup_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    Path(params): Path<TeamPathParam>,
+    ApiJson(request): ApiJson<LineupRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let lineup = MatchdayLineup {
+        starting_eleven: request.starting_eleven,
+        substitutes: request.substitutes,
+        captain_id: request.captain_id,
+        penalty_taker_id: request.penalty_taker_id,
+        free_kick_taker_id: request.free_kick_taker_id,
+    };
+
+    edit_managed_team(&state, &session, &params.team_slug, |team| {
+        team.set_matchday_lineup(lineup.clone())
+            .map_err(|err| lineup_error(&lineup, err))
+    })
+    .await
+}
+
+/// The rejected lineup as a validation failure of the request field the
+/// rule applies to.
+fn lineup_error(lineup: &MatchdayLineup, err: LineupError) -> ApiError {
+    let field = match err {
+        LineupError::StartingElevenSize(_) | LineupError::NoGoalkeeper => "starting_eleven",
+        LineupError::BenchTooLarge(_) => "substitutes",
+        LineupError::DuplicatePlayer(id)
+        | LineupError::NotInSquad(id)
+        | LineupError::NotRegistered(id)
+        | LineupError::Unavailable(id) => {
+            if lineup.starting_eleven.contains(&id) {
+                "starting_eleven"
+            } else {
+                "substitutes"
+            }
+        }
+        LineupError::NotStarting(id) => {
+            if lineup.captain_id == Some(id) {
+                "captain_id"
+            } else if lineup.penalty_taker_id == Some(id) {
+                "penalty_taker_id"
+            } else {
+                "free_kick_taker_id"
+            }
+        }
+    };
+    ApiError::invalid(field, err.to_string())
+}
+
+/// `POST /api/v1/teams/{team_slug}/tactics` — set the formation, e.g.
+/// `{"tactic": "T433"}`.
+pub async fn team_tactic_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    Path(params): Path<TeamPathParam>,
+    ApiJson(request): ApiJson<TacticRequest>,
+) -> ApiResult<impl IntoResponse> {
+    edit_managed_team(&state, &session, &params.team_slug, |team| {
+        team.set_matchday_tactic(request.tactic);
+        Ok(())
+    })
+    .await
+}
+
+/// `POST /api/v1/teams/{team_slug}/matchday-plan/reset` — hand selection
+/// back to the head coach.
+pub async fn team_matchday_plan_reset_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    Path(params): Path<TeamPathParam>,
+) -> ApiResult<impl IntoResponse> {
+    edit_managed_team(&state, &session, &params.team_slug, |team| {
+        team.clear_matchday_plan();
+        Ok(())
+    })
+    .await
+}
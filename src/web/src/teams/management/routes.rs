@@ -0,0 +1,27 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::{get, post};
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route(
+            "/api/v1/teams/{team_slug}/manage",
+            post(super::team_manage_action),
+        )
+        .route(
+            "/api/v1/teams/{team_slug}/matchday-plan",
+            get(super::team_matchday_plan_action),
+        )
+        .route(
+            "/api/v1/teams/{team_slug}/lineup",
+            post(super::team_lineup_action),
+        )
+        .route(
+            "/api/v1/teams/{team_slug}/tactics",
+            post(super::team_tactic_action),
+        )
+        .route(
+            "/api/v1/teams/{team_slug}/matchday-plan/reset",
+            post(super::team_matchday_plan_reset_action),
+        )
+}
@@ -1,12 +1,15 @@
 pub mod academy;
 pub mod finances;
 pub mod get;
+pub mod history;
+pub mod management;
 pub mod relations;
 pub mod schedule;
 pub mod scouting;
 pub mod staff;
 pub mod stats;
 pub mod tactics;
+pub mod training;
 pub mod transfers;
 
 use crate::GameAppData;
@@ -22,6 +25,9 @@ pub fn team_routes() -> Router<GameAppData> {
         .merge(scouting::routes::routes())
         .merge(academy::routes::routes())
         .merge(schedule::routes::routes())
+        .merge(training::routes::routes())
         .merge(stats::routes::routes())
         .merge(transfers::routes::routes())
+        .merge(history::routes::routes())
+        .merge(management::routes::routes())
 }
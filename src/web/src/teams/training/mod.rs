@@ -0,0 +1,222 @@
+pub mod routes;
+
+use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
+use crate::views::{self, MenuSection};
+use crate::{ApiError, ApiResult, GameAppData, I18n};
+use askama::Template;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use chrono::{Datelike, Duration, Weekday};
+use core::{SimulatorData, TeamTraining};
+use serde::Deserialize;
+
+/// Days laid out on the training page, starting with today.
+const TRAINING_DAYS_AHEAD: i64 = 7;
+
+#[derive(Deserialize)]
+pub struct TeamTrainingGetRequest {
+    lang: String,
+    team_slug: String,
+}
+
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "teams/training/index.html")]
+pub struct TeamTrainingTemplate {
+    pub css_version: &'static str,
+    pub computer_name: &'static str,
+    pub cpu_brand: &'static str,
+    pub cores_count: usize,
+    pub i18n: I18n,
+    pub lang: String,
+    pub title: String,
+    pub sub_title_prefix: String,
+    pub sub_title_suffix: String,
+    pub sub_title: String,
+    pub sub_title_link: String,
+    pub sub_title_country_code: String,
+    pub header_color: String,
+    pub foreground_color: String,
+    pub menu_sections: Vec<MenuSection>,
+    pub team_slug: String,
+    pub active_tab: &'static str,
+    pub show_finances_tab: bool,
+    pub show_academy_tab: bool,
+    pub phase_key: &'static str,
+    pub days: Vec<TeamTrainingDay>,
+}
+
+pub struct TeamTrainingDay {
+    pub date: String,
+    pub day_key: &'static str,
+    pub is_today: bool,
+    pub is_match_day: bool,
+    pub sessions: Vec<TeamTrainingSession>,
+}
+
+pub struct TeamTrainingSession {
+    pub type_key: &'static str,
+    pub intensity_key: &'static str,
+    pub duration_minutes: u16,
+}
+
+pub async fn team_training_get_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<TeamTrainingGetRequest>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let i18n = state.i18n.for_lang(&route_params.lang);
+
+    let team_id = simulator_data
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?
+        .slug_indexes
+        .get_team_by_slug(&route_params.team_slug)
+        .ok_or_else(|| {
+            ApiError::NotFound(format!("Team '{}' not found", route_params.team_slug))
+        })?;
+
+    let team = simulator_data
+        .team(team_id)
+        .ok_or_else(|| ApiError::NotFound(format!("Team with ID {} not found", team_id)))?;
+
+    let league = team.league_id.and_then(|id| simulator_data.league(id));
+
+    let (neighbor_teams, country_leagues) =
+        get_neighbor_teams(team.club_id, simulator_data, &i18n)?;
+    let neighbor_refs: Vec<(&str, &str)> = neighbor_teams
+        .iter()
+        .map(|(n, s)| (n.as_str(), s.as_str()))
+        .collect();
+    let league_refs: Vec<(&str, &str)> = country_leagues
+        .iter()
+        .map(|(n, s)| (n.as_str(), s.as_str()))
+        .collect();
+
+    let now = simulator_data.date;
+    let phase_key = TeamTraining::weekly_plan(team, now)
+        .periodization_phase
+        .as_i18n_key();
+
+    let days: Vec<TeamTrainingDay> = (0..TRAINING_DAYS_AHEAD)
+        .map(|offset| {
+            let date = now + Duration::days(offset);
+            TeamTrainingDay {
+                date: date.format("%d.%m.%Y").to_string(),
+                day_key: day_key(date.weekday()),
+                is_today: offset == 0,
+                is_match_day: team.fixture_window.upcoming.contains(&date.date()),
+                sessions: TeamTraining::planned_sessions_on(team, date)
+                    .iter()
+                    .map(|session| TeamTrainingSession {
+                        type_key: session.session_type.as_i18n_key(),
+                        intensity_key: session.intensity.as_i18n_key(),
+                        duration_minutes: session.duration_minutes,
+                    })
+                    .collect(),
+            }
+        })
+        .collect();
+
+    let (cn, cs) = views::club_country_info(simulator_data, team.club_id);
+    let current_path = format!("/{}/teams/{}/training", &route_params.lang, &team.slug);
+    let menu_params = views::MenuParams {
+        i18n: &i18n,
+        lang: &route_params.lang,
+        current_path: &current_path,
+        country_name: cn,
+        country_slug: cs,
+    };
+    let menu_sections = views::team_menu(&menu_params, &neighbor_refs, &league_refs);
+    let title = team.name.clone();
+    let league_title = league
+        .map(|l| views::league_display_name(l, &i18n, simulator_data))
+        .unwrap_or_default();
+
+    Ok(TeamTrainingTemplate {
+        css_version: CSS_VERSION,
+        computer_name: &COMPUTER_NAME,
+        cpu_brand: &CPU_BRAND,
+        cores_count: *CPU_CORES,
+        i18n,
+        lang: route_params.lang.clone(),
+        title,
+        sub_title_prefix: String::new(),
+        sub_title_suffix: String::new(),
+        sub_title: league_title,
+        sub_title_link: league
+            .map(|l| format!("/{}/leagues/{}", &route_params.lang, &l.slug))
+            .unwrap_or_default(),
+        sub_title_country_code: String::new(),
+        header_color: simulator_data
+            .club(team.club_id)
+            .map(|c| c.colors.background.clone())
+            .unwrap_or_default(),
+        foreground_color: simulator_data
+            .club(team.club_id)
+            .map(|c| c.colors.foreground.clone())
+            .unwrap_or_default(),
+        menu_sections,
+        team_slug: team.slug.clone(),
+        active_tab: "training",
+        show_finances_tab: team.team_type.is_own_team(),
+        show_academy_tab: team.team_type == core::TeamType::Main
+            || team.team_type == core::TeamType::U18,
+        phase_key,
+        days,
+    })
+}
+
+fn day_key(weekday: Weekday) -> &'static str {
+    match weekday {
+        Weekday::Mon => "day_mon",
+        Weekday::Tue => "day_tue",
+        Weekday::Wed => "day_wed",
+        Weekday::Thu => "day_thu",
+        Weekday::Fri => "day_fri",
+        Weekday::Sat => "day_sat",
+        Weekday::Sun => "day_sun",
+    }
+}
+
+/// The club's other teams and its country's leagues, as (name, slug) pairs.
+type NeighborTeams = (Vec<(String, String)>, Vec<(String, String)>);
+
+fn get_neighbor_teams(
+    club_id: u32,
+    data: &SimulatorData,
+    i18n: &I18n,
+) -> Result<NeighborTeams, ApiError> {
+    let club = data
+        .club(club_id)
+        .ok_or_else(|| ApiError::InternalError(format!("Club with ID {} not found", club_id)))?;
+
+    let teams = views::neighbor_teams(club, i18n);
+
+    let mut country_leagues: Vec<(u32, String, String)> = data
+        .country_by_club(club_id)
+        .map(|country| {
+            country
+                .leagues
+                .leagues
+                .iter()
+                .filter(|l| !l.friendly)
+                .map(|l| (l.id, l.name.clone(), l.slug.clone()))
+                .collect()
+        })
+        .unwrap_or_default();
+    country_leagues.sort_by_key(|(id, _, _)| *id);
+
+    Ok((
+        teams,
+        country_leagues
+            .into_iter()
+            .map(|(_, name, slug)| (name, slug))
+            .collect(),
+    ))
+}
@@ -0,0 +1,10 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new().route(
+        "/{lang}/teams/{team_slug}/training",
+        get(super::team_training_get_action),
+    )
+}
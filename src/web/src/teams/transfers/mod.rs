@@ -3,9 +3,9 @@ pub mod routes;
 use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
 use crate::common::slug::player_history_slug;
 use crate::views::{self, MenuSection};
-use crate::{ApiError, ApiResult, GameAppData, I18n};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData, I18n};
 use askama::Template;
-use axum::extract::{Path, Query, State};
+use axum::extract::{Path, State};
 use axum::response::IntoResponse;
 use chrono::Datelike;
 use core::SimulatorData;
@@ -88,7 +88,7 @@ pub struct LoanHistoryItem {
 pub async fn team_transfers_action(
     State(state): State<GameAppData>,
     Path(route_params): Path<TeamTransfersRequest>,
-    Query(query): Query<SeasonQuery>,
+    ApiQuery(query): ApiQuery<SeasonQuery>,
 ) -> ApiResult<impl IntoResponse> {
     let guard = state.data.read().await;
 
@@ -0,0 +1,539 @@
+//! Browse every completed transfer in the world: by country, club, season,
+//! window, type, fee and date. Reads each country's
+//! `transfer_market.transfer_history`, the same log the team and league
+//! transfer tabs show a slice of.
+
+pub mod routes;
+
+use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
+use crate::common::slug::player_history_slug;
+use crate::views::{self, MenuSection};
+use crate::{ApiError, ApiQuery, ApiResult, GameAppData, I18n};
+use askama::Template;
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use chrono::NaiveDate;
+use core::transfers::{CompletedTransfer, TransferCalendar, TransferType};
+use core::utils::FormattingUtils;
+use core::{Country, SimulatorData};
+use serde::de::{self, Deserializer};
+use serde::{Deserialize, Serialize};
+use std::cmp::Reverse;
+use std::fmt::Display;
+use std::str::FromStr;
+
+const DEFAULT_LIMIT: usize = 200;
+const MAX_LIMIT: usize = 1000;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferKind {
+    Permanent,
+    Loan,
+    Free,
+}
+
+impl TransferKind {
+    fn of(transfer_type: &TransferType) -> Self {
+        match transfer_type {
+            TransferType::Permanent => TransferKind::Permanent,
+            TransferType::Loan(_) => TransferKind::Loan,
+            TransferType::Free => TransferKind::Free,
+        }
+    }
+
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            TransferKind::Permanent => "transfer_type_permanent",
+            TransferKind::Loan => "transfer_type_loan",
+            TransferKind::Free => "transfer_type_free",
+        }
+    }
+}
+
+impl FromStr for TransferKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "permanent" => Ok(TransferKind::Permanent),
+            "loan" => Ok(TransferKind::Loan),
+            "free" => Ok(TransferKind::Free),
+            other => Err(format!("unknown transfer type '{}'", other)),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TransferWindowKind {
+    Summer,
+    Winter,
+}
+
+impl TransferWindowKind {
+    /// The registration window of `country` that `date` falls in; `None`
+    /// for deals done outside both (free agents, emergency signings).
+    fn of(country: &Country, date: NaiveDate) -> Option<Self> {
+        let windows = TransferCalendar::of(country, date);
+        let within = |(opens, closes): (NaiveDate, NaiveDate)| date >= opens && date <= closes;
+        if within(windows.summer_window) {
+            Some(TransferWindowKind::Summer)
+        } else if within(windows.winter_window) {
+            Some(TransferWindowKind::Winter)
+        } else {
+            None
+        }
+    }
+}
+
+impl FromStr for TransferWindowKind {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "summer" => Ok(TransferWindowKind::Summer),
+            "winter" => Ok(TransferWindowKind::Winter),
+            other => Err(format!("unknown transfer window '{}'", other)),
+        }
+    }
+}
+
+/// Query-string filters shared by the page and the API. Empty values, as a
+/// GET form submits for untouched fields, count as "any".
+#[derive(Deserialize, Default)]
+pub struct TransferHistoryQuery {
+    /// Country slug; the country whose market recorded the deal.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub country: Option<String>,
+    /// Team slug; matches deals on either side.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub club: Option<String>,
+    /// Season start year, e.g. `2026` for 2026/27.
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub season: Option<u16>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub window: Option<TransferWindowKind>,
+    #[serde(default, rename = "type", deserialize_with = "empty_as_none")]
+    pub kind: Option<TransferKind>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub min_fee: Option<f64>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub max_fee: Option<f64>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub from: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub to: Option<NaiveDate>,
+    #[serde(default, deserialize_with = "empty_as_none")]
+    pub limit: Option<usize>,
+}
+
+fn empty_as_none<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    match Option::<String>::deserialize(deserializer)?
+        .as_deref()
+        .map(str::trim)
+    {
+        None | Some("") => Ok(None),
+        Some(value) => value.parse().map(Some).map_err(de::Error::custom),
+    }
+}
+
+impl TransferHistoryQuery {
+    fn matches(&self, transfer: &CompletedTransfer, window: Option<TransferWindowKind>) -> bool {
+        self.season.is_none_or(|s| transfer.season_year == s)
+            && self.window.is_none_or(|w| window == Some(w))
+            && self
+                .kind
+                .is_none_or(|k| TransferKind::of(&transfer.transfer_type) == k)
+            && self.min_fee.is_none_or(|min| transfer.fee.amount >= min)
+            && self.max_fee.is_none_or(|max| transfer.fee.amount <= max)
+            && self.from.is_none_or(|from| transfer.transfer_date >= from)
+            && self.to.is_none_or(|to| transfer.transfer_date <= to)
+    }
+
+    fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_LIMIT).clamp(1, MAX_LIMIT)
+    }
+}
+
+#[derive(Serialize)]
+pub struct TransferHistoryItemDto {
+    pub date: NaiveDate,
+    pub season: u16,
+    pub player_id: u32,
+    pub player_name: String,
+    pub player_slug: String,
+    pub from_club_id: u32,
+    pub from_team_name: String,
+    pub from_team_slug: String,
+    pub to_club_id: u32,
+    pub to_team_name: String,
+    pub to_team_slug: String,
+    pub country_slug: String,
+    pub fee: f64,
+    #[serde(rename = "type")]
+    pub kind: TransferKind,
+    pub loan_end: Option<NaiveDate>,
+    pub window: Option<TransferWindowKind>,
+}
+
+impl TransferHistoryItemDto {
+    pub fn fee_display(&self) -> String {
+        if self.fee > 0.0 {
+            FormattingUtils::format_money(self.fee)
+        } else {
+            "-".to_string()
+        }
+    }
+}
+
+#[derive(Serialize)]
+pub struct TransferHistoryDto {
+    /// Matching transfers before the limit was applied.
+    pub total: usize,
+    pub transfers: Vec<TransferHistoryItemDto>,
+}
+
+/// Matching transfers, newest first.
+pub fn transfer_history(
+    sim: &SimulatorData,
+    query: &TransferHistoryQuery,
+) -> ApiResult<TransferHistoryDto> {
+    collect_transfers(sim, query, query.limit())
+}
+
+/// Every completed transfer in the world, newest first.
+pub fn all_transfers(sim: &SimulatorData) -> ApiResult<Vec<TransferHistoryItemDto>> {
+    collect_transfers(sim, &TransferHistoryQuery::default(), usize::MAX).map(|h| h.transfers)
+}
+
+fn collect_transfers(
+    sim: &SimulatorData,
+    query: &TransferHistoryQuery,
+    limit: usize,
+) -> ApiResult<TransferHistoryDto> {
+    let indexes = sim
+        .indexes
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Indexes not available".to_string()))?;
+
+    let country_id = query
+        .country
+        .as_deref()
+        .map(|slug| {
+            indexes
+                .slug_indexes
+                .get_country_by_slug(slug)
+                .ok_or_else(|| ApiError::NotFound(format!("Country '{}' not found", slug)))
+        })
+        .transpose()?;
+    let club_id = query
+        .club
+        .as_deref()
+        .map(|slug| {
+            indexes
+                .slug_indexes
+                .get_team_by_slug(slug)
+                .and_then(|id| sim.team(id))
+                .map(|team| team.club_id)
+                .ok_or_else(|| ApiError::NotFound(format!("Team '{}' not found", slug)))
+        })
+        .transpose()?;
+
+    let mut matching: Vec<(&Country, &CompletedTransfer, Option<TransferWindowKind>)> = sim
+        .continents
+        .iter()
+        .flat_map(|continent| &continent.countries)
+        .filter(|country| country_id.is_none_or(|id| country.id == id))
+        .flat_map(|country| {
+            country
+                .transfer_market
+                .transfer_history
+                .iter()
+                .map(move |t| (country, t))
+        })
+        .filter(|(_, t)| club_id.is_none_or(|id| t.from_club_id == id || t.to_club_id == id))
+        .map(|(country, t)| (country, t, TransferWindowKind::of(country, t.transfer_date)))
+        .filter(|(_, t, window)| query.matches(t, *window))
+        .collect();
+    matching.sort_by_key(|(_, t, _)| Reverse(t.transfer_date));
+
+    let total = matching.len();
+    let transfers = matching
+        .into_iter()
+        .take(limit)
+        .map(|(country, t, window)| TransferHistoryItemDto {
+            date: t.transfer_date,
+            season: t.season_year,
+            player_id: t.player_id,
+            player_name: t.player_name.clone(),
+            player_slug: player_history_slug(sim, t.player_id, &t.player_name),
+            from_club_id: t.from_club_id,
+            from_team_name: t.from_team_name.clone(),
+            from_team_slug: main_team_slug(sim, t.from_club_id),
+            to_club_id: t.to_club_id,
+            to_team_name: t.to_team_name.clone(),
+            to_team_slug: main_team_slug(sim, t.to_club_id),
+            country_slug: country.slug.clone(),
+            fee: t.fee.amount,
+            kind: TransferKind::of(&t.transfer_type),
+            loan_end: match t.transfer_type {
+                TransferType::Loan(end) => Some(end),
+                _ => None,
+            },
+            window,
+        })
+        .collect();
+
+    Ok(TransferHistoryDto { total, transfers })
+}
+
+fn main_team_slug(sim: &SimulatorData, club_id: u32) -> String {
+    sim.club(club_id)
+        .and_then(|club| club.teams.main_team_id())
+        .and_then(|id| sim.team_data(id))
+        .map(|team| team.slug.clone())
+        .unwrap_or_default()
+}
+
+/// `GET /api/v1/transfers/history`
+pub async fn transfer_history_action(
+    State(state): State<GameAppData>,
+    ApiQuery(query): ApiQuery<TransferHistoryQuery>,
+) -> ApiResult<Json<TransferHistoryDto>> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    Ok(Json(transfer_history(simulator_data, &query)?))
+}
+
+#[derive(Deserialize)]
+pub struct TransferHistoryPageRequest {
+    pub lang: String,
+}
+
+pub struct FilterOption {
+    pub value: String,
+    pub display: String,
+    pub selected: bool,
+}
+
+#[derive(Template, askama_web::WebTemplate)]
+#[template(path = "transfers/history/index.html")]
+pub struct TransferHistoryTemplate {
+    pub css_version: &'static str,
+    pub computer_name: &'static str,
+    pub cpu_brand: &'static str,
+    pub cores_count: usize,
+    pub i18n: I18n,
+    pub lang: String,
+    pub title: String,
+    pub sub_title_prefix: String,
+    pub sub_title_suffix: String,
+    pub sub_title: String,
+    pub sub_title_link: String,
+    pub sub_title_country_code: String,
+    pub header_color: String,
+    pub foreground_color: String,
+    pub menu_sections: Vec<MenuSection>,
+    pub countries: Vec<FilterOption>,
+    pub seasons: Vec<FilterOption>,
+    pub windows: Vec<FilterOption>,
+    pub kinds: Vec<FilterOption>,
+    pub club: String,
+    pub min_fee: String,
+    pub max_fee: String,
+    pub date_from: String,
+    pub date_to: String,
+    pub history: TransferHistoryDto,
+}
+
+pub async fn transfer_history_page_action(
+    State(state): State<GameAppData>,
+    Path(route_params): Path<TransferHistoryPageRequest>,
+    ApiQuery(query): ApiQuery<TransferHistoryQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let simulator_data = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+
+    let i18n = state.i18n.for_lang(&route_params.lang);
+    let history = transfer_history(simulator_data, &query)?;
+
+    let all_countries = || {
+        simulator_data
+            .continents
+            .iter()
+            .flat_map(|continent| &continent.countries)
+            .filter(|country| !country.transfer_market.transfer_history.is_empty())
+    };
+    let mut countries: Vec<FilterOption> = all_countries()
+        .map(|country| FilterOption {
+            selected: query.country.as_deref() == Some(country.slug.as_str()),
+            value: country.slug.clone(),
+            display: i18n.country(&country.code).to_string(),
+        })
+        .collect();
+    countries.sort_by(|a, b| a.display.cmp(&b.display));
+
+    let mut season_years: Vec<u16> = all_countries()
+        .flat_map(|country| &country.transfer_market.transfer_history)
+        .map(|t| t.season_year)
+        .collect();
+    season_years.sort_unstable_by(|a, b| b.cmp(a));
+    season_years.dedup();
+    let seasons = season_years
+        .into_iter()
+        .map(|year| FilterOption {
+            value: year.to_string(),
+            display: format!("{}/{}", year, (year + 1) % 100),
+            selected: query.season == Some(year),
+        })
+        .collect();
+
+    let windows = [
+        (
+            TransferWindowKind::Summer,
+            "summer",
+            "transfer_window_summer",
+        ),
+        (
+            TransferWindowKind::Winter,
+            "winter",
+            "transfer_window_winter",
+        ),
+    ]
+    .into_iter()
+    .map(|(window, value, key)| FilterOption {
+        value: value.to_string(),
+        display: i18n.t(key).to_string(),
+        selected: query.window == Some(window),
+    })
+    .collect();
+    let kinds = [
+        (TransferKind::Permanent, "permanent"),
+        (TransferKind::Loan, "loan"),
+        (TransferKind::Free, "free"),
+    ]
+    .into_iter()
+    .map(|(kind, value)| FilterOption {
+        value: value.to_string(),
+        display: i18n.t(kind.i18n_key()).to_string(),
+        selected: query.kind == Some(kind),
+    })
+    .collect();
+
+    let current_path = format!("/{}/transfers", &route_params.lang);
+    let format_opt = |value: Option<f64>| value.map(|v| v.to_string()).unwrap_or_default();
+    let format_date = |value: Option<NaiveDate>| value.map(|d| d.to_string()).unwrap_or_default();
+
+    Ok(TransferHistoryTemplate {
+        css_version: CSS_VERSION,
+        computer_name: &COMPUTER_NAME,
+        cpu_brand: &CPU_BRAND,
+        cores_count: *CPU_CORES,
+        title: i18n.t("transfer_history").to_string(),
+        sub_title_prefix: String::new(),
+        sub_title_suffix: String::new(),
+        sub_title: String::new(),
+        sub_title_link: String::new(),
+        sub_title_country_code: String::new(),
+        header_color: String::new(),
+        foreground_color: String::new(),
+        menu_sections: views::search_menu(&i18n, &route_params.lang, &current_path),
+        i18n,
+        lang: route_params.lang.clone(),
+        countries,
+        seasons,
+        windows,
+        kinds,
+        club: query.club.clone().unwrap_or_default(),
+        min_fee: format_opt(query.min_fee),
+        max_fee: format_opt(query.max_fee),
+        date_from: format_date(query.from),
+        date_to: format_date(query.to),
+        history,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::extract::Query;
+    use core::shared::{Currency, CurrencyValue};
+
+    fn transfer(date: NaiveDate, fee: f64, transfer_type: TransferType) -> CompletedTransfer {
+        CompletedTransfer::new(
+            1,
+            "Player".to_string(),
+            10,
+            100,
+            "From".to_string(),
+            20,
+            "To".to_string(),
+            date,
+            CurrencyValue::new(fee, Currency::Usd),
+            transfer_type,
+        )
+    }
+
+    fn parse(params: &str) -> Option<TransferHistoryQuery> {
+        let uri: axum::http::Uri = format!("/api/v1/transfers/history?{}", params)
+            .parse()
+            .unwrap();
+        Query::try_from_uri(&uri).ok().map(|Query(q)| q)
+    }
+
+    fn query(params: &str) -> TransferHistoryQuery {
+        parse(params).unwrap()
+    }
+
+    #[test]
+    fn empty_form_fields_mean_any() {
+        let q = query("country=&season=&type=&min_fee=&from=&window=");
+        let t = transfer(
+            NaiveDate::from_ymd_opt(2026, 7, 1).unwrap(),
+            0.0,
+            TransferType::Free,
+        );
+        assert!(q.matches(&t, None));
+        assert_eq!(q.limit(), DEFAULT_LIMIT);
+    }
+
+    #[test]
+    fn filters_combine() {
+        let q = query("season=2026&type=loan&window=summer&min_fee=1000&to=2026-08-31");
+        let date = NaiveDate::from_ymd_opt(2026, 8, 10).unwrap();
+        let loan = transfer(
+            date,
+            5000.0,
+            TransferType::Loan(NaiveDate::from_ymd_opt(2027, 6, 30).unwrap()),
+        );
+        assert!(q.matches(&loan, Some(TransferWindowKind::Summer)));
+        assert!(!q.matches(&loan, Some(TransferWindowKind::Winter)));
+        assert!(!q.matches(&loan, None));
+        assert!(!q.matches(
+            &transfer(date, 5000.0, TransferType::Permanent),
+            Some(TransferWindowKind::Summer)
+        ));
+        assert!(!q.matches(
+            &transfer(date, 500.0, TransferType::Loan(date)),
+            Some(TransferWindowKind::Summer)
+        ));
+    }
+
+    #[test]
+    fn unknown_filter_values_are_rejected() {
+        assert!(parse("type=swap").is_none());
+        assert!(parse("from=yesterday").is_none());
+    }
+}
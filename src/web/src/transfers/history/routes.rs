@@ -0,0 +1,15 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::get;
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route(
+            "/{lang}/transfers",
+            get(super::transfer_history_page_action),
+        )
+        .route(
+            "/api/v1/transfers/history",
+            get(super::transfer_history_action),
+        )
+}
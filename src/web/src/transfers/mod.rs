@@ -0,0 +1,344 @@
+//! Transfer actions for the signed-in manager's club: browse players
+//! other clubs have listed, bid for them, answer bids for the club's own
+//! players and agree contracts. Every action feeds the same negotiation
+//! engine the AI clubs use, so talks advance as simulated days pass and
+//! stop only where the manager has a decision to make.
+
+pub mod history;
+pub mod routes;
+
+use crate::session::{SessionToken, current_manager, require_managed_club};
+use crate::{ApiError, ApiJson, ApiQuery, ApiResult, GameAppData};
+use axum::Json;
+use axum::extract::{Path, State};
+use axum::response::IntoResponse;
+use chrono::NaiveDate;
+use core::SimulatorData;
+use core::transfers::{
+    NegotiationPhase, NegotiationRejectionReason, NegotiationStatus, PipelineProcessor,
+    TransferListingStatus, TransferListingType, TransferNegotiation, UserContractOffer,
+    UserNegotiationResponse, UserNegotiationStep, UserTransferBid, UserTransferError,
+};
+use core::utils::DateUtils;
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+pub fn transfer_routes() -> axum::Router<GameAppData> {
+    routes::routes().merge(history::routes::routes())
+}
+
+const MAX_TARGETS: usize = 200;
+
+#[derive(Deserialize)]
+pub struct NegotiationPathParams {
+    pub country_id: u32,
+    pub negotiation_id: u32,
+}
+
+#[derive(Deserialize, Default)]
+pub struct TargetsQuery {
+    /// Short position name, e.g. `ST` or `DC`.
+    #[serde(default)]
+    pub position: Option<String>,
+    #[serde(default)]
+    pub max_fee: Option<f64>,
+    /// Only loan listings when `true`, only permanent ones when `false`.
+    #[serde(default)]
+    pub loan: Option<bool>,
+}
+
+#[derive(Serialize)]
+pub struct TransferTargetDto {
+    pub player_id: u32,
+    pub player_slug: String,
+    pub player_name: String,
+    pub position: String,
+    pub age: u8,
+    pub current_ability: u8,
+    pub club_id: u32,
+    pub club_name: String,
+    pub asking_price: f64,
+    pub is_loan: bool,
+    pub listed_date: NaiveDate,
+}
+
+#[derive(Serialize)]
+pub struct NegotiationDto {
+    pub country_id: u32,
+    pub id: u32,
+    pub player_id: u32,
+    pub player_name: String,
+    /// `buying` or `selling`, from the user's side.
+    pub user_side: &'static str,
+    pub buying_club_id: u32,
+    pub buying_club_name: String,
+    pub selling_club_id: u32,
+    pub selling_club_name: String,
+    pub fee: f64,
+    pub is_loan: bool,
+    pub offered_wage: Option<u32>,
+    pub contract_years: Option<u8>,
+    pub phase: &'static str,
+    pub round: u8,
+    pub status: NegotiationStatus,
+    pub rejection_reason: Option<NegotiationRejectionReason>,
+    /// Set while the talks wait on the user or on an answer to the user.
+    pub user_step: Option<UserNegotiationStep>,
+    pub awaiting_user: bool,
+    pub expiry_date: NaiveDate,
+}
+
+impl NegotiationDto {
+    fn new(
+        sim: &SimulatorData,
+        user_club_id: u32,
+        country_id: u32,
+        negotiation: &TransferNegotiation,
+    ) -> Self {
+        let club_name = |id: u32| sim.club(id).map(|c| c.name.clone()).unwrap_or_default();
+        let player_name = if negotiation.player_name.is_empty() {
+            sim.player(negotiation.player_id)
+                .map(|p| p.full_name.to_string())
+                .unwrap_or_default()
+        } else {
+            negotiation.player_name.clone()
+        };
+        let (phase, round) = match negotiation.phase {
+            NegotiationPhase::InitialApproach { .. } => ("initial_approach", 0),
+            NegotiationPhase::ClubNegotiation { round, .. } => ("club_negotiation", round),
+            NegotiationPhase::PersonalTerms { round, .. } => ("personal_terms", round),
+            NegotiationPhase::MedicalAndFinalization { .. } => ("medical", 0),
+        };
+
+        NegotiationDto {
+            country_id,
+            id: negotiation.id,
+            player_id: negotiation.player_id,
+            player_name,
+            user_side: if negotiation.selling_club_id == user_club_id {
+                "selling"
+            } else {
+                "buying"
+            },
+            buying_club_id: negotiation.buying_club_id,
+            buying_club_name: club_name(negotiation.buying_club_id),
+            selling_club_id: negotiation.selling_club_id,
+            selling_club_name: club_name(negotiation.selling_club_id),
+            fee: negotiation.current_offer.base_fee.amount,
+            is_loan: negotiation.is_loan,
+            offered_wage: negotiation.offered_salary,
+            contract_years: negotiation.current_offer.resolved_contract_years(),
+            phase,
+            round,
+            status: negotiation.status.clone(),
+            rejection_reason: negotiation.rejection_reason.clone(),
+            user_step: negotiation.user_step.clone(),
+            awaiting_user: negotiation.is_awaiting_user(),
+            expiry_date: negotiation.expiry_date,
+        }
+    }
+}
+
+/// `amount_field` names the request field an `InvalidAmount` refers to.
+fn transfer_error(err: UserTransferError, amount_field: &str) -> ApiError {
+    match err {
+        UserTransferError::PlayerNotFound | UserTransferError::NegotiationNotFound => {
+            ApiError::NotFound(err.to_string())
+        }
+        UserTransferError::NoUserClub | UserTransferError::NotParticipant => {
+            ApiError::Forbidden(err.to_string())
+        }
+        UserTransferError::InvalidAmount => ApiError::invalid(amount_field, err.to_string()),
+        _ => ApiError::BadRequest(err.to_string()),
+    }
+}
+
+fn negotiation_dto(
+    sim: &SimulatorData,
+    user_club_id: u32,
+    country_id: u32,
+    negotiation_id: u32,
+) -> ApiResult<Json<NegotiationDto>> {
+    sim.country(country_id)
+        .and_then(|c| c.transfer_market.negotiations.get(&negotiation_id))
+        .map(|n| Json(NegotiationDto::new(sim, user_club_id, country_id, n)))
+        .ok_or_else(|| ApiError::NotFound("Negotiation not found".to_string()))
+}
+
+/// Run `action` for the signed-in manager's club and return the
+/// negotiation it touched. A rejected amount is reported against
+/// `amount_field`.
+async fn edit_negotiations<F>(
+    state: &GameAppData,
+    session: &SessionToken,
+    amount_field: &str,
+    action: F,
+) -> ApiResult<Json<NegotiationDto>>
+where
+    F: FnOnce(&mut SimulatorData, u32) -> Result<(u32, u32), UserTransferError>,
+{
+    let data = Arc::clone(&state.data);
+    let mut guard = data.write().await;
+    let arc_data = guard
+        .as_mut()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+    let user_club_id = require_managed_club(arc_data, session)?;
+    let sim = Arc::make_mut(arc_data);
+
+    let (country_id, negotiation_id) =
+        action(sim, user_club_id).map_err(|err| transfer_error(err, amount_field))?;
+    negotiation_dto(sim, user_club_id, country_id, negotiation_id)
+}
+
+/// `GET /api/v1/transfers/targets` — players other clubs have listed, with
+/// optional `position`, `max_fee` and `loan` filters. The signed-in
+/// manager's own players are left out.
+pub async fn transfer_targets_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    ApiQuery(query): ApiQuery<TargetsQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let sim = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+    let today = sim.date.date();
+    let own_club_id = current_manager(sim, &session).and_then(|m| m.club_id);
+
+    let mut targets: Vec<TransferTargetDto> = sim
+        .continents
+        .iter()
+        .flat_map(|continent| &continent.countries)
+        .flat_map(|country| {
+            country
+                .transfer_market
+                .listings
+                .iter()
+                .filter_map(move |listing| {
+                    let open = listing.status == TransferListingStatus::Available
+                        || listing.status == TransferListingStatus::InNegotiation;
+                    let is_loan = match listing.listing_type {
+                        TransferListingType::Transfer => false,
+                        TransferListingType::Loan => true,
+                        TransferListingType::EndOfContract => return None,
+                    };
+                    if !open || !listing.is_seller_advertised() {
+                        return None;
+                    }
+                    let club = country.clubs.iter().find(|c| c.id == listing.club_id)?;
+                    let player = club
+                        .teams
+                        .iter()
+                        .flat_map(|t| t.players.players.iter())
+                        .find(|p| p.id == listing.player_id)?;
+                    Some(TransferTargetDto {
+                        player_id: player.id,
+                        player_slug: player.slug(),
+                        player_name: player.full_name.to_string(),
+                        position: player.position().get_short_name().to_string(),
+                        age: DateUtils::age(player.birth_date, today),
+                        current_ability: player.player_attributes.current_ability,
+                        club_id: club.id,
+                        club_name: club.name.clone(),
+                        asking_price: listing.asking_price.amount,
+                        is_loan,
+                        listed_date: listing.listed_date,
+                    })
+                })
+        })
+        .filter(|t| own_club_id != Some(t.club_id))
+        .filter(|t| {
+            query
+                .position
+                .as_ref()
+                .is_none_or(|p| t.position.eq_ignore_ascii_case(p))
+        })
+        .filter(|t| query.max_fee.is_none_or(|max| t.asking_price <= max))
+        .filter(|t| query.loan.is_none_or(|loan| t.is_loan == loan))
+        .collect();
+
+    targets.sort_by(|a, b| {
+        b.current_ability
+            .cmp(&a.current_ability)
+            .then_with(|| a.asking_price.total_cmp(&b.asking_price))
+    });
+    targets.truncate(MAX_TARGETS);
+
+    Ok(Json(targets))
+}
+
+/// `POST /api/v1/transfers/bids` — bid for a player on behalf of the signed-in
+/// manager's club, e.g.
+/// `{"player_id": 42, "fee": 2500000, "annual_wage": 900000}`.
+pub async fn transfer_bid_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    ApiJson(bid): ApiJson<UserTransferBid>,
+) -> ApiResult<impl IntoResponse> {
+    edit_negotiations(&state, &session, "fee", |sim, club_id| {
+        PipelineProcessor::open_user_negotiation(sim, club_id, &bid)
+    })
+    .await
+}
+
+/// `GET /api/v1/transfers/negotiations` — open talks the signed-in manager's
+/// club is buying or selling in, oldest first.
+pub async fn transfer_negotiations_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+) -> ApiResult<impl IntoResponse> {
+    let guard = state.data.read().await;
+    let sim = guard
+        .as_ref()
+        .ok_or_else(|| ApiError::InternalError("Simulator data not loaded".to_string()))?;
+    let club_id = require_managed_club(sim, &session)?;
+
+    let negotiations: Vec<NegotiationDto> = PipelineProcessor::user_negotiations(sim, club_id)
+        .into_iter()
+        .map(|(country_id, negotiation)| NegotiationDto::new(sim, club_id, country_id, negotiation))
+        .collect();
+    Ok(Json(negotiations))
+}
+
+/// `POST /api/v1/transfers/negotiations/{country_id}/{negotiation_id}/respond`
+/// — `{"action": "accept"}`, `{"action": "reject"}` or
+/// `{"action": "counter", "fee": 3000000}`.
+pub async fn transfer_respond_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    Path(params): Path<NegotiationPathParams>,
+    ApiJson(response): ApiJson<UserNegotiationResponse>,
+) -> ApiResult<impl IntoResponse> {
+    edit_negotiations(&state, &session, "fee", |sim, club_id| {
+        PipelineProcessor::respond_to_user_negotiation(
+            sim,
+            club_id,
+            params.country_id,
+            params.negotiation_id,
+            response,
+        )
+        .map(|_| (params.country_id, params.negotiation_id))
+    })
+    .await
+}
+
+/// `POST /api/v1/transfers/negotiations/{country_id}/{negotiation_id}/contract`
+/// — offer the player `{"annual_wage": 1200000, "contract_years": 4}`.
+pub async fn transfer_contract_action(
+    State(state): State<GameAppData>,
+    session: SessionToken,
+    Path(params): Path<NegotiationPathParams>,
+    ApiJson(offer): ApiJson<UserContractOffer>,
+) -> ApiResult<impl IntoResponse> {
+    edit_negotiations(&state, &session, "annual_wage", |sim, club_id| {
+        PipelineProcessor::offer_user_contract(
+            sim,
+            club_id,
+            params.country_id,
+            params.negotiation_id,
+            offer,
+        )
+        .map(|_| (params.country_id, params.negotiation_id))
+    })
+    .await
+}
@@ -0,0 +1,24 @@
+use crate::GameAppData;
+use axum::Router;
+use axum::routing::{get, post};
+
+pub fn routes() -> Router<GameAppData> {
+    Router::new()
+        .route(
+            "/api/v1/transfers/targets",
+            get(super::transfer_targets_action),
+        )
+        .route("/api/v1/transfers/bids", post(super::transfer_bid_action))
+        .route(
+            "/api/v1/transfers/negotiations",
+            get(super::transfer_negotiations_action),
+        )
+        .route(
+            "/api/v1/transfers/negotiations/{country_id}/{negotiation_id}/respond",
+            post(super::transfer_respond_action),
+        )
+        .route(
+            "/api/v1/transfers/negotiations/{country_id}/{negotiation_id}/contract",
+            post(super::transfer_contract_action),
+        )
+}
@@ -161,7 +161,29 @@ pub fn watchlist_menu(i18n: &I18n, lang: &str, current_path: &str) -> Vec<MenuSe
     vec![
         home_section(i18n, lang),
         search_section(i18n, lang, current_path),
+        calendar_section(i18n, lang, current_path),
         watchlist_section(i18n, lang, current_path),
+        inbox_section(i18n, lang, current_path),
+    ]
+}
+
+pub fn calendar_menu(i18n: &I18n, lang: &str, current_path: &str) -> Vec<MenuSection> {
+    vec![
+        home_section(i18n, lang),
+        search_section(i18n, lang, current_path),
+        calendar_section(i18n, lang, current_path),
+        watchlist_section(i18n, lang, current_path),
+        inbox_section(i18n, lang, current_path),
+    ]
+}
+
+pub fn inbox_menu(i18n: &I18n, lang: &str, current_path: &str) -> Vec<MenuSection> {
+    vec![
+        home_section(i18n, lang),
+        search_section(i18n, lang, current_path),
+        calendar_section(i18n, lang, current_path),
+        watchlist_section(i18n, lang, current_path),
+        inbox_section(i18n, lang, current_path),
     ]
 }
 
@@ -224,6 +246,16 @@ fn national_section(i18n: &I18n, lang: &str, current_path: &str) -> MenuSection
     }])
 }
 
+fn calendar_section(i18n: &I18n, lang: &str, current_path: &str) -> MenuSection {
+    let calendar_url = format!("/{}/game/calendar", lang);
+    MenuSection::plain(vec![MenuItem {
+        active: current_path == calendar_url,
+        title: i18n.t("calendar").to_string(),
+        url: calendar_url,
+        icon: "fa-calendar".to_string(),
+    }])
+}
+
 fn watchlist_section(i18n: &I18n, lang: &str, current_path: &str) -> MenuSection {
     let watchlist_url = format!("/{}/watchlist", lang);
     MenuSection::plain(vec![MenuItem {
@@ -234,6 +266,16 @@ fn watchlist_section(i18n: &I18n, lang: &str, current_path: &str) -> MenuSection
     }])
 }
 
+fn inbox_section(i18n: &I18n, lang: &str, current_path: &str) -> MenuSection {
+    let inbox_url = format!("/{}/inbox", lang);
+    MenuSection::plain(vec![MenuItem {
+        active: current_path == inbox_url,
+        title: i18n.t("inbox").to_string(),
+        url: inbox_url,
+        icon: "fa-newspaper".to_string(),
+    }])
+}
+
 /// Build the country's league-pyramid menu section: each league links to
 /// `/leagues/{slug}`, collapsing to the first two with a toggle when there
 /// are more. Active state is derived from `current_path`, so the same
@@ -318,6 +360,8 @@ pub fn league_menu(
     }
 
     sections.push(watchlist_section(p.i18n, p.lang, p.current_path));
+
+    sections.push(inbox_section(p.i18n, p.lang, p.current_path));
     sections
 }
 
@@ -346,6 +390,7 @@ pub fn cup_menu(
     }
     sections.push(national_section(p.i18n, p.lang, p.current_path));
     sections.push(watchlist_section(p.i18n, p.lang, p.current_path));
+    sections.push(inbox_section(p.i18n, p.lang, p.current_path));
     sections
 }
 
@@ -374,6 +419,7 @@ pub fn playoff_menu(
     }
     sections.push(national_section(p.i18n, p.lang, p.current_path));
     sections.push(watchlist_section(p.i18n, p.lang, p.current_path));
+    sections.push(inbox_section(p.i18n, p.lang, p.current_path));
     sections
 }
 
@@ -422,6 +468,8 @@ pub fn team_menu(
 
     sections.push(watchlist_section(p.i18n, p.lang, p.current_path));
 
+    sections.push(inbox_section(p.i18n, p.lang, p.current_path));
+
     sections
 }
 
@@ -449,6 +497,7 @@ pub fn country_menu(
     }
     sections.push(national_section(p.i18n, p.lang, p.current_path));
     sections.push(watchlist_section(p.i18n, p.lang, p.current_path));
+    sections.push(inbox_section(p.i18n, p.lang, p.current_path));
 
     sections
 }
@@ -458,6 +507,7 @@ pub fn match_menu(i18n: &I18n, lang: &str, current_path: &str) -> Vec<MenuSectio
     let mut sections = vec![home_section(i18n, lang)];
     sections.push(search_section(i18n, lang, current_path));
     sections.push(watchlist_section(i18n, lang, current_path));
+    sections.push(inbox_section(i18n, lang, current_path));
     sections
 }
 
@@ -504,6 +554,7 @@ fn continental_competitions_menu(i18n: &I18n, lang: &str, current_path: &str) ->
         club_competitions_section(i18n, lang, current_path),
         national_section(i18n, lang, current_path),
         watchlist_section(i18n, lang, current_path),
+        inbox_section(i18n, lang, current_path),
     ]
 }
 
@@ -565,5 +616,7 @@ pub fn national_competitions_menu(
     }
 
     sections.push(watchlist_section(i18n, lang, current_path));
+
+    sections.push(inbox_section(i18n, lang, current_path));
     sections
 }
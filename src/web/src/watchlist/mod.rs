@@ -2,6 +2,7 @@ pub mod routes;
 
 use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
 use crate::common::potential_stars::{PotentialStarsView, StarRating};
+use crate::session::{SessionToken, current_manager};
 use crate::views::{self, MenuSection};
 use crate::{ApiError, ApiResult, GameAppData, I18n};
 use askama::Template;
@@ -14,6 +15,7 @@ use core::PlayerPositionType;
 use core::PlayerStatusType;
 use core::SimulatorData;
 use core::TeamType;
+use core::WatchlistEntry;
 use core::utils::{DateUtils, FormattingUtils};
 use serde::Deserialize;
 use std::sync::Arc;
@@ -47,6 +49,27 @@ pub struct WatchlistPlayerDto {
     pub unhappy: bool,
     pub transfer_listed: bool,
     pub retired: bool,
+    pub added_on: String,
+    pub value_when_added: String,
+    /// Percent change of the market value since the player was added;
+    /// `None` when either value is unknown.
+    pub value_change_pct: Option<i32>,
+    pub contract_expiry: String,
+    /// Contract expiry on the day the player was added, when it has
+    /// changed since (renewal or transfer); empty otherwise.
+    pub contract_expiry_when_added: String,
+    pub scout: Option<WatchlistScoutDto>,
+}
+
+/// What the signed-in manager's scouts know about a watched player: the
+/// latest report (this window's or one carried over in the shadow squad),
+/// or failing that their standing memory of the player.
+pub struct WatchlistScoutDto {
+    pub recommendation_key: Option<&'static str>,
+    pub assessed_ability: u8,
+    pub assessed_potential: u8,
+    pub confidence_pct: u8,
+    pub last_seen: String,
 }
 
 #[derive(Deserialize)]
@@ -77,6 +100,7 @@ pub struct WatchlistPageTemplate {
 
 pub async fn watchlist_page_action(
     State(state): State<GameAppData>,
+    session: SessionToken,
     Path(route_params): Path<WatchlistPageRequest>,
 ) -> ApiResult<impl IntoResponse> {
     let guard = state.data.read().await;
@@ -89,11 +113,14 @@ pub async fn watchlist_page_action(
 
     let now = simulator_data.date.date();
     let current_path = format!("/{}/watchlist", &route_params.lang);
+    let scout_club_id = current_manager(simulator_data, &session).and_then(|m| m.club_id);
 
     let mut players: Vec<WatchlistPlayerDto> = simulator_data
         .watchlist
+        .entries()
         .iter()
-        .filter_map(|&player_id| {
+        .filter_map(|entry| {
+            let player_id = entry.player_id;
             if let Some((player, team)) = simulator_data.player_with_team(player_id) {
                 let league = team.league_id.and_then(|id| simulator_data.league(id));
                 let head_coach = team.staffs.head_coach();
@@ -109,21 +136,16 @@ pub async fn watchlist_page_action(
                     team_slug: team.slug.clone(),
                     league_name: league.map(|l| l.name.clone()).unwrap_or_default(),
                     league_slug: league.map(|l| l.slug.clone()).unwrap_or_default(),
-                    value: FormattingUtils::format_money(player.value(
-                        now,
-                        league.map(|l| l.reputation).unwrap_or(0),
-                        team.reputation.market_value_score(),
-                    )),
                     unhappy: !player.happiness.is_happy(),
                     transfer_listed: player.statuses.get().contains(&PlayerStatusType::Lst),
-                    ..base_watchlist_dto(player, simulator_data, now)
+                    ..base_watchlist_dto(player, entry, simulator_data, scout_club_id, now)
                 })
             } else if let Some(player) = simulator_data.retired_player(player_id) {
                 Some(WatchlistPlayerDto {
                     conditions: 0,
                     team_name: i18n.t("retired").to_string(),
                     retired: true,
-                    ..base_watchlist_dto(player, simulator_data, now)
+                    ..base_watchlist_dto(player, entry, simulator_data, scout_club_id, now)
                 })
             } else if let Some(player) = simulator_data
                 .free_agents
@@ -135,7 +157,7 @@ pub async fn watchlist_page_action(
                 // this branch the watchlist silently drops him on next render.
                 Some(WatchlistPlayerDto {
                     team_name: i18n.t("free_agent").to_string(),
-                    ..base_watchlist_dto(player, simulator_data, now)
+                    ..base_watchlist_dto(player, entry, simulator_data, scout_club_id, now)
                 })
             } else {
                 None
@@ -176,22 +198,27 @@ pub struct WatchlistModifyRequest {
     pub player_id: u32,
 }
 
+/// `POST /api/watchlist/add/{player_id}` — start tracking an active player
+/// or free agent from today's value and contract.
 pub async fn watchlist_add_action(
     State(state): State<GameAppData>,
     Path(route_params): Path<WatchlistModifyRequest>,
-) -> impl IntoResponse {
+) -> ApiResult<StatusCode> {
     let data = Arc::clone(&state.data);
     let mut guard = data.write().await;
 
     if let Some(ref mut arc_data) = *guard {
         let player_id = route_params.player_id;
         let simulator_data = Arc::make_mut(arc_data);
-        if !simulator_data.watchlist.contains(&player_id) {
-            simulator_data.watchlist.push(player_id);
+        if !simulator_data.add_to_watchlist(player_id) {
+            return Err(ApiError::NotFound(format!(
+                "Player with ID {} not found",
+                player_id
+            )));
         }
     }
 
-    StatusCode::OK
+    Ok(StatusCode::OK)
 }
 
 pub async fn watchlist_remove_action(
@@ -203,9 +230,7 @@ pub async fn watchlist_remove_action(
 
     if let Some(ref mut arc_data) = *guard {
         let simulator_data = Arc::make_mut(arc_data);
-        simulator_data
-            .watchlist
-            .retain(|&id| id != route_params.player_id);
+        simulator_data.watchlist.remove(route_params.player_id);
     }
 
     StatusCode::OK
@@ -215,9 +240,22 @@ pub async fn watchlist_remove_action(
 /// Caller overrides team/league/value/potential/etc via struct-update syntax.
 fn base_watchlist_dto(
     player: &Player,
+    entry: &WatchlistEntry,
     simulator_data: &SimulatorData,
+    scout_club_id: Option<u32>,
     now: NaiveDate,
 ) -> WatchlistPlayerDto {
+    let value = simulator_data.player_market_value(player.id);
+    let value_change_pct = value
+        .filter(|_| entry.value_when_added > 0.0)
+        .map(|v| ((v / entry.value_when_added - 1.0) * 100.0).round() as i32);
+    let contract_expiry = player.contract.as_ref().map(|c| c.expiration);
+    let contract_expiry_when_added = if contract_expiry != entry.contract_expiry_when_added {
+        format_date(entry.contract_expiry_when_added)
+    } else {
+        String::new()
+    };
+
     let (country_code, country_name, country_slug) = simulator_data
         .country(player.country_id)
         .map(|c| (c.code.clone(), c.name.clone(), c.slug.clone()))
@@ -249,12 +287,70 @@ fn base_watchlist_dto(
         league_slug: String::new(),
         played: player.statistics.played,
         played_subs: player.statistics.played_subs,
-        value: "-".to_string(),
+        value: value
+            .map(FormattingUtils::format_money)
+            .unwrap_or_else(|| "-".to_string()),
         injured: player.player_attributes.is_injured,
         unhappy: false,
         transfer_listed: false,
         retired: false,
+        added_on: entry.added_on.format("%d.%m.%Y").to_string(),
+        value_when_added: FormattingUtils::format_money(entry.value_when_added),
+        value_change_pct,
+        contract_expiry: format_date(contract_expiry),
+        contract_expiry_when_added,
+        scout: scout_club_id.and_then(|id| club_scout_view(simulator_data, id, player.id)),
+    }
+}
+
+fn format_date(date: Option<NaiveDate>) -> String {
+    date.map(|d| d.format("%d.%m.%Y").to_string())
+        .unwrap_or_else(|| "-".to_string())
+}
+
+/// What the scouts of `club_id` know about `player_id`, if they have seen
+/// the player.
+fn club_scout_view(
+    simulator_data: &SimulatorData,
+    club_id: u32,
+    player_id: u32,
+) -> Option<WatchlistScoutDto> {
+    let plan = &simulator_data.club(club_id)?.transfer_plan;
+    let known = plan.known_players.iter().find(|k| k.player_id == player_id);
+    let last_seen = known
+        .map(|k| k.last_seen.format("%d.%m.%Y").to_string())
+        .unwrap_or_default();
+
+    let report = plan
+        .scouting_reports
+        .iter()
+        .find(|r| r.player_id == player_id)
+        .or_else(|| {
+            plan.shadow_reports
+                .iter()
+                .map(|s| &s.report)
+                .find(|r| r.player_id == player_id)
+        });
+    if let Some(report) = report {
+        return Some(WatchlistScoutDto {
+            recommendation_key: Some(report.recommendation.as_i18n_key()),
+            assessed_ability: report.assessed_ability,
+            assessed_potential: report.assessed_potential,
+            confidence_pct: confidence_pct(report.confidence),
+            last_seen,
+        });
     }
+    known.map(|k| WatchlistScoutDto {
+        recommendation_key: None,
+        assessed_ability: k.assessed_ability,
+        assessed_potential: k.assessed_potential,
+        confidence_pct: confidence_pct(k.confidence),
+        last_seen,
+    })
+}
+
+fn confidence_pct(confidence: f32) -> u8 {
+    (confidence * 100.0).round().clamp(0.0, 100.0) as u8
 }
 
 fn get_conditions(player: &Player) -> u8 {
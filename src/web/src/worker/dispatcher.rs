@@ -43,8 +43,8 @@ use crate::worker::protocol::{MatchEnvelope, MatchOutcome, Request, Response};
 use crate::worker::registry::{BatchOutcome, LatencyTimer, ReadyWorker, WorkerRegistry};
 use crate::worker::transport::Frame;
 use crate::worker::wire::{LeagueMatchWire, SquadFixtureWire, SquadWire};
-use core::MatchRuntime;
 use core::r#match::{Match, MatchDispatcher, MatchResult, MatchResultRaw, MatchSquad, Score};
+use core::{MatchRuntime, SimulationProgress};
 use log::{info, warn};
 use std::collections::VecDeque;
 use std::future::Future;
@@ -425,6 +425,7 @@ impl DistributedDispatcher {
                 registry
                     .record_batch(&worker.address, count, latency, BatchOutcome::Ok)
                     .await;
+                SimulationProgress::record_matches(count);
                 Ok(items
                     .into_iter()
                     .filter_map(|o| match o {
@@ -608,6 +609,7 @@ impl DistributedDispatcher {
                 registry
                     .record_batch(&worker.address, count, latency, BatchOutcome::Ok)
                     .await;
+                SimulationProgress::record_matches(count);
                 Ok(items
                     .into_iter()
                     .filter_map(|o| match o {
@@ -659,6 +661,7 @@ impl DistributedDispatcher {
             score: Score::new(0, 0),
             details: None,
             friendly: false,
+            attendance: 0,
         }
     }
 }
@@ -268,6 +268,7 @@ impl WorkerConnection {
                         score: Score::new(0, 0),
                         details: None,
                         friendly: false,
+                        attendance: 0,
                     })
                 })
             })
@@ -12,7 +12,9 @@
 
 use core::club::player::traits::PlayerTrait;
 use core::r#match::{Match, MatchPlayer, MatchSquad, OmittedPlayer, PlayerSide};
-use core::{PersonAttributes, PlayerAttributes, PlayerPositionType, PlayerSkills, Tactics};
+use core::{
+    PersonAttributes, PlayerAttributes, PlayerPositionType, PlayerSkills, SquadCohesion, Tactics,
+};
 use serde::{Deserialize, Serialize};
 
 /// Wire image of a `MatchSquad`. Captain / vice / penalty-taker /
@@ -32,6 +34,10 @@ pub struct SquadWire {
     pub penalty_taker_id: Option<u32>,
     pub free_kick_taker_id: Option<u32>,
     pub selection_omissions: Vec<OmittedPlayer>,
+    #[serde(default)]
+    pub opposition_report: f32,
+    #[serde(default)]
+    pub cohesion: Option<SquadCohesion>,
 }
 
 /// Wire image of a `MatchPlayer`. Only fields that the engine reads at
@@ -68,6 +74,8 @@ pub struct LeagueMatchWire {
     pub league_slug: String,
     pub is_friendly: bool,
     pub is_knockout: bool,
+    #[serde(default)]
+    pub is_derby: bool,
     pub home: SquadWire,
     pub away: SquadWire,
 }
@@ -155,6 +163,8 @@ impl SquadWire {
             penalty_taker_id: s.penalty_taker_id.as_ref().map(|p| p.id),
             free_kick_taker_id: s.free_kick_taker_id.as_ref().map(|p| p.id),
             selection_omissions: s.selection_omissions.clone(),
+            opposition_report: s.opposition_report,
+            cohesion: s.cohesion.clone(),
         }
     }
 
@@ -170,6 +180,8 @@ impl SquadWire {
             penalty_taker_id,
             free_kick_taker_id,
             selection_omissions,
+            opposition_report,
+            cohesion,
         } = self;
 
         let main: Vec<MatchPlayer> = main_squad
@@ -199,6 +211,8 @@ impl SquadWire {
             // worker wire format — distributed match runs fall back
             // to the legacy memory-less substitution scoring.
             coach_snapshot: None,
+            opposition_report,
+            cohesion,
         }
     }
 }
@@ -216,6 +230,7 @@ impl LeagueMatchWire {
             league_slug: m.league_slug().to_string(),
             is_friendly: m.is_friendly,
             is_knockout: m.is_knockout,
+            is_derby: m.is_derby,
             home: SquadWire::from_squad(&m.home_squad),
             away: SquadWire::from_squad(&m.away_squad),
         }
@@ -228,13 +243,16 @@ impl LeagueMatchWire {
             league_slug,
             is_friendly,
             is_knockout,
+            is_derby,
             home,
             away,
         } = self;
         let home = home.into_squad();
         let away = away.into_squad();
         if is_knockout {
-            Match::make_knockout(id, league_id, &league_slug, home, away)
+            let mut m = Match::make_knockout(id, league_id, &league_slug, home, away);
+            m.is_derby = is_derby;
+            m
         } else {
             let mut m = Match::make(id, league_id, &league_slug, home, away, is_friendly);
             // `Match::make` always sets is_knockout = false; respect the
@@ -242,6 +260,7 @@ impl LeagueMatchWire {
             // (defensive — shouldn't happen, but keeps round-tripping
             // semantically exact).
             m.is_knockout = is_knockout;
+            m.is_derby = is_derby;
             m
         }
     }
@@ -3,7 +3,7 @@ pub mod routes;
 use crate::common::default_handler::{COMPUTER_NAME, CPU_BRAND, CPU_CORES, CSS_VERSION};
 use crate::views::{self, MenuSection};
 use crate::worker::{WorkerSnapshot, WorkerStatus};
-use crate::{ApiResult, GameAppData, I18n};
+use crate::{ApiJson, ApiResult, GameAppData, I18n};
 use askama::Template;
 use axum::Json;
 use axum::extract::{Path, State};
@@ -197,7 +197,7 @@ pub struct AddWorkerRequest {
 /// failure reason without a page reload.
 pub async fn workers_add_action(
     State(state): State<GameAppData>,
-    Json(body): Json<AddWorkerRequest>,
+    ApiJson(body): ApiJson<AddWorkerRequest>,
 ) -> impl IntoResponse {
     let address = format!("{}:{}", body.host.trim(), body.port);
     Json(state.workers.add_worker(address).await)
@@ -318,7 +318,7 @@ pub struct RemoveWorkerResponse {
 /// has no registry entry, so it can never be removed this way.
 pub async fn workers_remove_action(
     State(state): State<GameAppData>,
-    Json(body): Json<RemoveWorkerRequest>,
+    ApiJson(body): ApiJson<RemoveWorkerRequest>,
 ) -> impl IntoResponse {
     let removed = state.workers.remove_worker(body.address.trim()).await;
     Json(RemoveWorkerResponse { removed })